@@ -1,5 +1,5 @@
 export type Yevefi = {
-	version: "0.3.0";
+	version: "0.2.0";
 	name: "yevefi";
 	instructions: [
 		{
@@ -90,38 +90,6 @@ export type Yevefi = {
 					name: "yevefi";
 					isMut: true;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "yevefi";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "YevefisConfig";
-								path: "yevefis_config";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "Mint";
-								path: "token_mint_a";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "Mint";
-								path: "token_mint_b";
-							},
-							{
-								kind: "arg";
-								type: "u16";
-								path: "tick_spacing";
-							},
-						];
-					};
 				},
 				{
 					name: "tokenVaultA";
@@ -137,7 +105,6 @@ export type Yevefi = {
 					name: "feeTier";
 					isMut: false;
 					isSigner: false;
-					relations: ["yevefis_config"];
 				},
 				{
 					name: "tokenProgram";
@@ -200,26 +167,6 @@ export type Yevefi = {
 					name: "tickArray";
 					isMut: true;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "tick_array";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "Yevefi";
-								path: "yevefi";
-							},
-							{
-								kind: "arg";
-								type: "i32";
-								path: "start_tick_index";
-							},
-						];
-					};
 				},
 				{
 					name: "systemProgram";
@@ -260,26 +207,6 @@ export type Yevefi = {
 					name: "feeTier";
 					isMut: true;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "fee_tier";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "YevefisConfig";
-								path: "config";
-							},
-							{
-								kind: "arg";
-								type: "u16";
-								path: "tick_spacing";
-							},
-						];
-					};
 				},
 				{
 					name: "funder";
@@ -378,6 +305,8 @@ export type Yevefi = {
 			name: "setRewardEmissions";
 			docs: [
 				"Set the reward emissions for a reward in a Yevefi.",
+				"The emission schedule of the reward, if any, is replaced by the constant emission rate.",
+				"The reward vault funds the emissions from now on, which stop once the funds run out.",
 				"",
 				"### Authority",
 				'- "reward_authority" - assigned authority by the reward_super_authority for the specified',
@@ -452,21 +381,6 @@ export type Yevefi = {
 					name: "position";
 					isMut: true;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "position";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "Mint";
-								path: "position_mint";
-							},
-						];
-					};
 				},
 				{
 					name: "positionMint";
@@ -551,21 +465,6 @@ export type Yevefi = {
 					name: "position";
 					isMut: true;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "position";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "Mint";
-								path: "position_mint";
-							},
-						];
-					};
 				},
 				{
 					name: "positionMint";
@@ -655,6 +554,11 @@ export type Yevefi = {
 				"- `LiquidityZero` - Provided liquidity amount is zero.",
 				"- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
 				"- `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.",
+				"- `InvalidOracle` - The oracle account is missing or is not the oracle PDA of the Yevefi.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
 			];
 			accounts: [
 				{
@@ -676,7 +580,6 @@ export type Yevefi = {
 					name: "position";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 				{
 					name: "positionTokenAccount";
@@ -707,13 +610,11 @@ export type Yevefi = {
 					name: "tickArrayLower";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 				{
 					name: "tickArrayUpper";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 			];
 			args: [
@@ -748,6 +649,11 @@ export type Yevefi = {
 				"- `LiquidityZero` - Provided liquidity amount is zero.",
 				"- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
 				"- `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.",
+				"- `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.",
+				"- `InvalidPositionLock` - The PositionLock PDA of the position was not passed.",
+				"- `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.",
+				"- `InvalidOracle` - The oracle account is missing or is not the oracle PDA of the Yevefi.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
 			];
 			accounts: [
 				{
@@ -769,7 +675,6 @@ export type Yevefi = {
 					name: "position";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 				{
 					name: "positionTokenAccount";
@@ -800,13 +705,11 @@ export type Yevefi = {
 					name: "tickArrayLower";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 				{
 					name: "tickArrayUpper";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 			];
 			args: [
@@ -843,19 +746,16 @@ export type Yevefi = {
 					name: "position";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 				{
 					name: "tickArrayLower";
 					isMut: false;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 				{
 					name: "tickArrayUpper";
 					isMut: false;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 			];
 			args: [];
@@ -883,7 +783,6 @@ export type Yevefi = {
 					name: "position";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 				{
 					name: "positionTokenAccount";
@@ -918,6 +817,68 @@ export type Yevefi = {
 			];
 			args: [];
 		},
+		{
+			name: "compoundPositionFees";
+			docs: [
+				"Reinvest the fees accrued for this position as liquidity of the same position.",
+				"The maximum liquidity that the fees owed can fund at the current price is added,",
+				"and the remainder stays owed to the position. No tokens are transferred.",
+				"",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"",
+				"#### Special Errors",
+				"- `TickNotFound` - Provided tick array account does not contain the tick for this position.",
+				"- `LiquidityZero` - Position has zero liquidity and therefore accrues no fees.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "positionAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "position";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "positionTokenAccount";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayLower";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayUpper";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "oracle";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "yevefisConfigExtension";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [];
+		},
 		{
 			name: "collectReward";
 			docs: [
@@ -941,7 +902,6 @@ export type Yevefi = {
 					name: "position";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 				{
 					name: "positionTokenAccount";
@@ -989,7 +949,6 @@ export type Yevefi = {
 					name: "yevefi";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefis_config"];
 				},
 				{
 					name: "collectProtocolFeesAuthority";
@@ -1048,6 +1007,10 @@ export type Yevefi = {
 				"- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
 				"- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
 				"- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
 			];
 			accounts: [
 				{
@@ -1089,39 +1052,21 @@ export type Yevefi = {
 					name: "tickArray0";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 				{
 					name: "tickArray1";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 				{
 					name: "tickArray2";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 				{
 					name: "oracle";
 					isMut: false;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "oracle";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "Yevefi";
-								path: "yevefi";
-							},
-						];
-					};
 				},
 			];
 			args: [
@@ -1151,12 +1096,15 @@ export type Yevefi = {
 			name: "closePosition";
 			docs: [
 				"Close a position in a Yevefi. Burns the position token in the owner's wallet.",
+				"The expired PositionLock of the position, if any, is closed as well.",
 				"",
 				"### Authority",
 				'- "position_authority" - The authority that owns the position token.',
 				"",
 				"#### Special Errors",
 				"- `ClosePositionNotEmpty` - The provided position account is not empty.",
+				"- `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.",
+				"- `InvalidPositionLock` - The PositionLock PDA of the position was not passed.",
 			];
 			accounts: [
 				{
@@ -1173,21 +1121,6 @@ export type Yevefi = {
 					name: "position";
 					isMut: true;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "position";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "Mint";
-								path: "position_mint";
-							},
-						];
-					};
 				},
 				{
 					name: "positionMint";
@@ -1233,7 +1166,6 @@ export type Yevefi = {
 					name: "feeTier";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefis_config"];
 				},
 				{
 					name: "feeAuthority";
@@ -1309,7 +1241,6 @@ export type Yevefi = {
 					name: "yevefi";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefis_config"];
 				},
 				{
 					name: "feeAuthority";
@@ -1350,7 +1281,6 @@ export type Yevefi = {
 					name: "yevefi";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefis_config"];
 				},
 				{
 					name: "feeAuthority";
@@ -1365,13 +1295,127 @@ export type Yevefi = {
 				},
 			];
 		},
+		{
+			name: "setReferralFeeRate";
+			docs: [
+				"Sets the referral fee rate for a YevefiConfig.",
+				"Referral fee rate is represented as a basis point of the LP fee, and is paid to the",
+				"Referrer registered with `initialize_referrer` of swaps that supply referral accounts.",
+				"Only the current fee authority has permission to invoke this instruction.",
+				"",
+				"### Authority",
+				'- "fee_authority" - Set authority that can modify pool fees in the YevefiConfig',
+				"",
+				"### Parameters",
+				"- `referral_fee_rate` - The rate that swaps on Yevefis using this config will pay to referrers.",
+				"",
+				"#### Special Errors",
+				"- `ReferralFeeRateMaxExceeded` - If the provided referral_fee_rate exceeds MAX_REFERRAL_FEE_RATE.",
+			];
+			accounts: [
+				{
+					name: "yevefisConfig";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "feeAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+			];
+			args: [
+				{
+					name: "referralFeeRate";
+					type: "u16";
+				},
+			];
+		},
+		{
+			name: "initializeReferrer";
+			docs: [
+				"Registers a referrer of a YevefiConfig. Swaps on Yevefis using this config can only pay",
+				"referral fees to token accounts owned by a registered referrer_authority.",
+				"Only the current fee authority has permission to invoke this instruction.",
+				"",
+				"### Authority",
+				'- "fee_authority" - Set authority that can modify pool fees in the YevefiConfig',
+			];
+			accounts: [
+				{
+					name: "yevefisConfig";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "feeAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "referrerAuthority";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "referrer";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "funder";
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: "systemProgram";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [];
+		},
+		{
+			name: "deleteReferrer";
+			docs: [
+				"Removes a referrer of a YevefiConfig, so that it no longer receives referral fees.",
+				"Only the current fee authority has permission to invoke this instruction.",
+				"",
+				"### Authority",
+				'- "fee_authority" - Set authority that can modify pool fees in the YevefiConfig',
+			];
+			accounts: [
+				{
+					name: "yevefisConfig";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "feeAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "referrer";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "receiver";
+					isMut: true;
+					isSigner: false;
+				},
+			];
+			args: [];
+		},
 		{
 			name: "setFeeAuthority";
 			docs: [
-				"Sets the fee authority for a YevefiConfig.",
-				"The fee authority can set the fee & protocol fee rate for individual pools or",
-				"set the default fee rate for newly minted pools.",
+				"Proposes a new fee authority for a YevefiConfig, which takes over once it signs",
+				"`accept_fee_authority`. The fee authority can set the fee & protocol fee rate for",
+				"individual pools or set the default fee rate for newly minted pools.",
 				"Only the current fee authority has permission to invoke this instruction.",
+				"Proposing the default Pubkey cancels a pending transfer.",
 				"",
 				"### Authority",
 				'- "fee_authority" - Set authority that can modify pool fees in the YevefiConfig',
@@ -1395,11 +1439,35 @@ export type Yevefi = {
 			];
 			args: [];
 		},
+		{
+			name: "acceptFeeAuthority";
+			docs: [
+				"Accepts the fee authority proposed by `set_fee_authority` for a YevefiConfig.",
+				"",
+				"### Authority",
+				'- "new_fee_authority" - The pending fee authority in the YevefiConfig',
+			];
+			accounts: [
+				{
+					name: "yevefisConfig";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "newFeeAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+			];
+			args: [];
+		},
 		{
 			name: "setCollectProtocolFeesAuthority";
 			docs: [
-				"Sets the fee authority to collect protocol fees for a YevefiConfig.",
+				"Proposes a new authority to collect protocol fees for a YevefiConfig, which takes over once",
+				"it signs `accept_collect_protocol_fees_authority`.",
 				"Only the current collect protocol fee authority has permission to invoke this instruction.",
+				"Proposing the default Pubkey cancels a pending transfer.",
 				"",
 				"### Authority",
 				'- "fee_authority" - Set authority that can collect protocol fees in the YevefiConfig',
@@ -1423,11 +1491,36 @@ export type Yevefi = {
 			];
 			args: [];
 		},
+		{
+			name: "acceptCollectProtocolFeesAuthority";
+			docs: [
+				"Accepts the collect protocol fees authority proposed by `set_collect_protocol_fees_authority`",
+				"for a YevefiConfig.",
+				"",
+				"### Authority",
+				'- "new_collect_protocol_fees_authority" - The pending collect protocol fees authority in the YevefiConfig',
+			];
+			accounts: [
+				{
+					name: "yevefisConfig";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "newCollectProtocolFeesAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+			];
+			args: [];
+		},
 		{
 			name: "setRewardAuthority";
 			docs: [
-				"Set the yevefi reward authority at the provided `reward_index`.",
+				"Propose a new yevefi reward authority at the provided `reward_index`, which takes over once",
+				"it signs `accept_reward_authority`.",
 				"Only the current reward authority for this reward index has permission to invoke this instruction.",
+				"Proposing the default Pubkey cancels a pending transfer.",
 				"",
 				"### Authority",
 				'- "reward_authority" - Set authority that can control reward emission for this particular reward.',
@@ -1461,11 +1554,42 @@ export type Yevefi = {
 				},
 			];
 		},
+		{
+			name: "acceptRewardAuthority";
+			docs: [
+				"Accept the yevefi reward authority proposed by `set_reward_authority` at the provided `reward_index`.",
+				"",
+				"### Authority",
+				'- "new_reward_authority" - The pending reward authority for this particular reward.',
+				"",
+				"#### Special Errors",
+				"- `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "newRewardAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+			];
+			args: [
+				{
+					name: "rewardIndex";
+					type: "u8";
+				},
+			];
+		},
 		{
 			name: "setRewardAuthorityBySuperAuthority";
 			docs: [
 				"Set the yevefi reward authority at the provided `reward_index`.",
 				"Only the current reward super authority has permission to invoke this instruction.",
+				"Any pending reward authority transfer is cancelled.",
 				"",
 				"### Authority",
 				'- "reward_authority" - Set authority that can control reward emission for this particular reward.',
@@ -1485,7 +1609,6 @@ export type Yevefi = {
 					name: "yevefi";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefis_config"];
 				},
 				{
 					name: "rewardEmissionsSuperAuthority";
@@ -1508,8 +1631,10 @@ export type Yevefi = {
 		{
 			name: "setRewardEmissionsSuperAuthority";
 			docs: [
-				"Set the yevefi reward super authority for a YevefiConfig",
+				"Propose a new yevefi reward super authority for a YevefiConfig, which takes over once it signs",
+				"`accept_reward_emissions_super_authority`.",
 				"Only the current reward super authority has permission to invoke this instruction.",
+				"Proposing the default Pubkey cancels a pending transfer.",
 				"This instruction will not change the authority on any `YevefiRewardInfo` yevefi rewards.",
 				"",
 				"### Authority",
@@ -1535,16 +1660,39 @@ export type Yevefi = {
 			args: [];
 		},
 		{
-			name: "twoHopSwap";
+			name: "acceptRewardEmissionsSuperAuthority";
 			docs: [
-				"Perform a two-hop swap in this Yevefi",
+				"Accept the yevefi reward super authority proposed by `set_reward_emissions_super_authority`",
+				"for a YevefiConfig.",
 				"",
 				"### Authority",
-				'- "token_authority" - The authority to withdraw tokens from the input token account.',
-				"",
-				"### Parameters",
-				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
-				"- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
+				'- "new_reward_emissions_super_authority" - The pending reward super authority in the YevefiConfig',
+			];
+			accounts: [
+				{
+					name: "yevefisConfig";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "newRewardEmissionsSuperAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+			];
+			args: [];
+		},
+		{
+			name: "twoHopSwap";
+			docs: [
+				"Perform a two-hop swap in this Yevefi",
+				"",
+				"### Authority",
+				'- "token_authority" - The authority to withdraw tokens from the input token account.',
+				"",
+				"### Parameters",
+				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
+				"- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
 				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
 				"- `a_to_b_one` - The direction of the swap of hop one. True if swapping from A to B. False if swapping from B to A.",
 				"- `a_to_b_two` - The direction of the swap of hop two. True if swapping from A to B. False if swapping from B to A.",
@@ -1562,6 +1710,10 @@ export type Yevefi = {
 				"- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
 				"- `InvalidIntermediaryMint` - Error if the intermediary mint between hop one and two do not equal.",
 				"- `DuplicateTwoHopPool` - Error if yevefi one & two are the same pool.",
+				"- `YevefiPaused` - One of the Yevefis, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - A config extension account is missing or is not the config extension PDA of its Yevefi's YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of one of the Yevefis is initialized.",
+				"- `OracleNotWritable` - The oracle of one of the Yevefis is initialized but was passed as read-only.",
 			];
 			accounts: [
 				{
@@ -1658,41 +1810,11 @@ export type Yevefi = {
 					name: "oracleOne";
 					isMut: false;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "oracle";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "Yevefi";
-								path: "yevefi_one";
-							},
-						];
-					};
 				},
 				{
 					name: "oracleTwo";
 					isMut: false;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "oracle";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "Yevefi";
-								path: "yevefi_two";
-							},
-						];
-					};
 				},
 			];
 			args: [
@@ -1737,21 +1859,6 @@ export type Yevefi = {
 					name: "positionBundle";
 					isMut: true;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "position_bundle";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "Mint";
-								path: "position_bundle_mint";
-							},
-						];
-					};
 				},
 				{
 					name: "positionBundleMint";
@@ -1808,21 +1915,6 @@ export type Yevefi = {
 					name: "positionBundle";
 					isMut: true;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "position_bundle";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "Mint";
-								path: "position_bundle_mint";
-							},
-						];
-					};
 				},
 				{
 					name: "positionBundleMint";
@@ -1930,6 +2022,112 @@ export type Yevefi = {
 			];
 			args: [];
 		},
+		{
+			name: "initializePositionBundleWithTokenExtensions";
+			docs: [
+				"Initializes a PositionBundle account that bundles several positions.",
+				"A unique token will be minted to represent the position bundle in the users wallet.",
+				"Mint and TokenAccount are based on Token-2022, and the PositionBundle account is the",
+				"close authority of the mint, so that the rent of the mint can be reclaimed on delete.",
+				"",
+				"### Parameters",
+				"- `with_token_metadata_extension` - If true, the token metadata extension will be initialized.",
+			];
+			accounts: [
+				{
+					name: "positionBundle";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "positionBundleMint";
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: "positionBundleTokenAccount";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "positionBundleOwner";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "funder";
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: "token2022Program";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "systemProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "associatedTokenProgram";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "withTokenMetadataExtension";
+					type: "bool";
+				},
+			];
+		},
+		{
+			name: "deletePositionBundleWithTokenExtensions";
+			docs: [
+				"Delete a PositionBundle account initialized with Token-2022.",
+				"Burns the position bundle token in the owner's wallet and closes the mint.",
+				"",
+				"### Authority",
+				"- `position_bundle_owner` - The owner that owns the position bundle token.",
+				"",
+				"### Special Errors",
+				"- `PositionBundleNotDeletable` - The provided position bundle has open positions.",
+			];
+			accounts: [
+				{
+					name: "positionBundle";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "positionBundleMint";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "positionBundleTokenAccount";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "positionBundleOwner";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "receiver";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "token2022Program";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [];
+		},
 		{
 			name: "openBundledPosition";
 			docs: [
@@ -1955,26 +2153,6 @@ export type Yevefi = {
 					name: "bundledPosition";
 					isMut: true;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "bundled_position";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "PositionBundle";
-								path: "position_bundle.position_bundle_mint";
-							},
-							{
-								kind: "arg";
-								type: "u16";
-								path: "bundle_index";
-							},
-						];
-					};
 				},
 				{
 					name: "positionBundle";
@@ -2031,6 +2209,7 @@ export type Yevefi = {
 			name: "closeBundledPosition";
 			docs: [
 				"Close a bundled position in a Yevefi.",
+				"The expired PositionLock of the position, if any, is closed as well.",
 				"",
 				"### Authority",
 				"- `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.",
@@ -2041,32 +2220,14 @@ export type Yevefi = {
 				"#### Special Errors",
 				"- `InvalidBundleIndex` - If the provided bundle index is out of bounds.",
 				"- `ClosePositionNotEmpty` - The provided position account is not empty.",
+				"- `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.",
+				"- `InvalidPositionLock` - The PositionLock PDA of the position was not passed.",
 			];
 			accounts: [
 				{
 					name: "bundledPosition";
 					isMut: true;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "bundled_position";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "PositionBundle";
-								path: "position_bundle.position_bundle_mint";
-							},
-							{
-								kind: "arg";
-								type: "u16";
-								path: "bundle_index";
-							},
-						];
-					};
 				},
 				{
 					name: "positionBundle";
@@ -2097,188 +2258,257 @@ export type Yevefi = {
 			];
 		},
 		{
-			name: "collectFeesV2";
+			name: "openPositionWithTokenExtensions";
 			docs: [
-				"Collect fees accrued for this position.",
+				"Open a position in a Yevefi. A unique token will be minted to represent the position",
+				"in the users wallet. Additional TokenMetadata extension is initialized to identify the token.",
+				"The TokenMetadata includes the pool address, tick range, liquidity and in-range flag of the",
+				"position as additional fields, and the position account is its update authority.",
+				"Mint and TokenAccount are based on Token-2022.",
+				"The position will start off with 0 liquidity.",
 				"",
-				"### Authority",
-				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"### Parameters",
+				"- `tick_lower_index` - The tick specifying the lower end of the position range.",
+				"- `tick_upper_index` - The tick specifying the upper end of the position range.",
+				"- `with_token_metadata_extension` - If true, the token metadata extension will be initialized.",
+				"",
+				"#### Special Errors",
+				"- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
+				"the tick-spacing in this pool.",
 			];
 			accounts: [
 				{
-					name: "yevefi";
-					isMut: false;
-					isSigner: false;
+					name: "funder";
+					isMut: true;
+					isSigner: true;
 				},
 				{
-					name: "positionAuthority";
+					name: "owner";
 					isMut: false;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
 					name: "position";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefi"];
+				},
+				{
+					name: "positionMint";
+					isMut: true;
+					isSigner: true;
 				},
 				{
 					name: "positionTokenAccount";
-					isMut: false;
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: "tokenMintA";
+					name: "yevefi";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenMintB";
+					name: "token2022Program";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenOwnerAccountA";
-					isMut: true;
+					name: "systemProgram";
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenVaultA";
-					isMut: true;
+					name: "associatedTokenProgram";
+					isMut: false;
 					isSigner: false;
 				},
+			];
+			args: [
 				{
-					name: "tokenOwnerAccountB";
+					name: "tickLowerIndex";
+					type: "i32";
+				},
+				{
+					name: "tickUpperIndex";
+					type: "i32";
+				},
+				{
+					name: "withTokenMetadataExtension";
+					type: "bool";
+				},
+			];
+		},
+		{
+			name: "refreshPositionMetadata";
+			docs: [
+				"Refresh the additional TokenMetadata fields of a position opened with token extensions,",
+				"so that they reflect the current tick range, liquidity and in-range state of the position.",
+				"Anyone can refresh a position. The funder pays the rent if the fields grow.",
+				"",
+				"#### Special Errors",
+				"- `InvalidPositionMetadataUpdateAuthority` - The position account is not the update authority",
+				"of the TokenMetadata.",
+			];
+			accounts: [
+				{
+					name: "funder";
 					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: "position";
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenVaultB";
+					name: "positionMint";
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: "tokenProgramA";
+					name: "yevefi";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenProgramB";
+					name: "token2022Program";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "memoProgram";
+					name: "systemProgram";
 					isMut: false;
 					isSigner: false;
 				},
 			];
-			args: [
-				{
-					name: "remainingAccountsInfo";
-					type: {
-						option: {
-							defined: "RemainingAccountsInfo";
-						};
-					};
-				},
-			];
+			args: [];
 		},
 		{
-			name: "collectProtocolFeesV2";
+			name: "closePositionWithTokenExtensions";
 			docs: [
-				"Collect the protocol fees accrued in this Yevefi",
+				"Close a position in a Yevefi. Burns the position token in the owner's wallet.",
+				"Mint and TokenAccount are based on Token-2022. And Mint accout will be also closed.",
+				"The expired PositionLock of the position, if any, is closed as well.",
 				"",
 				"### Authority",
-				"- `collect_protocol_fees_authority` - assigned authority in the YevefiConfig that can collect protocol fees",
+				'- "position_authority" - The authority that owns the position token.',
+				"",
+				"#### Special Errors",
+				"- `ClosePositionNotEmpty` - The provided position account is not empty.",
+				"- `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.",
+				"- `InvalidPositionLock` - The PositionLock PDA of the position was not passed.",
 			];
 			accounts: [
 				{
-					name: "yevefisConfig";
+					name: "positionAuthority";
 					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: "yevefi";
+					name: "receiver";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefis_config"];
 				},
 				{
-					name: "collectProtocolFeesAuthority";
-					isMut: false;
-					isSigner: true;
+					name: "position";
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: "tokenMintA";
-					isMut: false;
+					name: "positionMint";
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: "tokenMintB";
-					isMut: false;
+					name: "positionTokenAccount";
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: "tokenVaultA";
-					isMut: true;
+					name: "token2022Program";
+					isMut: false;
 					isSigner: false;
 				},
+			];
+			args: [];
+		},
+		{
+			name: "lockPosition";
+			docs: [
+				"Lock the liquidity of a position until the unlock timestamp.",
+				"While locked, liquidity cannot be decreased and the position cannot be closed,",
+				"but fees and rewards can still be collected. A position can only be locked once,",
+				"the lock can be extended with `extend_position_lock`.",
+				"Bundled positions are locked by the holder of the position bundle token.",
+				"Instructions that check the lock require the PositionLock PDA of every position, locked",
+				"or not. v1 instructions take it as the first remaining account and v2 instructions as a",
+				"`PositionLock` remaining accounts slice.",
+				"",
+				"### Authority",
+				'- "position_authority" - The authority that owns the position token.',
+				"",
+				"### Parameters",
+				"- `unlock_timestamp` - The unix timestamp from which the position is unlocked.",
+				"",
+				"#### Special Errors",
+				"- `InvalidUnlockTimestamp` - The unlock timestamp is not in the future.",
+			];
+			accounts: [
 				{
-					name: "tokenVaultB";
+					name: "funder";
 					isMut: true;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: "tokenDestinationA";
-					isMut: true;
-					isSigner: false;
+					name: "positionAuthority";
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: "tokenDestinationB";
-					isMut: true;
+					name: "position";
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenProgramA";
+					name: "positionTokenAccount";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenProgramB";
-					isMut: false;
+					name: "positionLock";
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: "memoProgram";
+					name: "systemProgram";
 					isMut: false;
 					isSigner: false;
 				},
 			];
 			args: [
 				{
-					name: "remainingAccountsInfo";
-					type: {
-						option: {
-							defined: "RemainingAccountsInfo";
-						};
-					};
+					name: "unlockTimestamp";
+					type: "u64";
 				},
 			];
 		},
 		{
-			name: "collectRewardV2";
+			name: "extendPositionLock";
 			docs: [
-				"Collect rewards accrued for this position.",
+				"Move the unlock timestamp of a position lock later.",
+				"An expired lock can be extended to lock the position again.",
 				"",
 				"### Authority",
-				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				'- "position_authority" - The authority that owns the position token.',
+				"",
+				"### Parameters",
+				"- `unlock_timestamp` - The unix timestamp from which the position is unlocked.",
+				"",
+				"#### Special Errors",
+				"- `InvalidUnlockTimestamp` - The unlock timestamp is not in the future or not later than",
+				"the current unlock timestamp.",
 			];
 			accounts: [
-				{
-					name: "yevefi";
-					isMut: false;
-					isSigner: false;
-				},
 				{
 					name: "positionAuthority";
 					isMut: false;
@@ -2286,9 +2516,8 @@ export type Yevefi = {
 				},
 				{
 					name: "position";
-					isMut: true;
+					isMut: false;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 				{
 					name: "positionTokenAccount";
@@ -2296,84 +2525,83 @@ export type Yevefi = {
 					isSigner: false;
 				},
 				{
-					name: "rewardOwnerAccount";
+					name: "positionLock";
 					isMut: true;
 					isSigner: false;
 				},
+			];
+			args: [
 				{
-					name: "rewardMint";
+					name: "unlockTimestamp";
+					type: "u64";
+				},
+			];
+		},
+		{
+			name: "closePositionLock";
+			docs: [
+				"Close an expired position lock of a position that stays open.",
+				"Closing the position closes its lock as well.",
+				"The lock marker is removed from the position and its rent returned to the receiver.",
+				"",
+				"### Authority",
+				'- "position_authority" - The authority that owns the position token.',
+				"",
+				"#### Special Errors",
+				"- `PositionLocked` - The position is locked until its unlock timestamp.",
+			];
+			accounts: [
+				{
+					name: "positionAuthority";
 					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: "rewardVault";
+					name: "receiver";
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: "rewardTokenProgram";
+					name: "position";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "memoProgram";
+					name: "positionTokenAccount";
 					isMut: false;
 					isSigner: false;
 				},
-			];
-			args: [
-				{
-					name: "rewardIndex";
-					type: "u8";
-				},
 				{
-					name: "remainingAccountsInfo";
-					type: {
-						option: {
-							defined: "RemainingAccountsInfo";
-						};
-					};
+					name: "positionLock";
+					isMut: true;
+					isSigner: false;
 				},
 			];
+			args: [];
 		},
 		{
-			name: "decreaseLiquidityV2";
+			name: "initializePositionDelegate";
 			docs: [
-				"Withdraw liquidity from a position in the Yevefi. This call also updates the position's accrued fees and rewards.",
+				"Grant a delegate a set of permissions on a position, without transferring the position token.",
+				"The delegate can collect fees, collect rewards, increase liquidity or decrease liquidity",
+				"through the v2 instructions as permitted. Tokens withdrawn by the delegate can only be",
+				"transferred to the position owner. The permissions are void once the position token is",
+				"held by another owner.",
 				"",
 				"### Authority",
-				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				'- "position_authority" - The authority that owns the position token.',
 				"",
 				"### Parameters",
-				"- `liquidity_amount` - The total amount of Liquidity the user desires to withdraw.",
-				"- `token_min_a` - The minimum amount of tokenA the user is willing to withdraw.",
-				"- `token_min_b` - The minimum amount of tokenB the user is willing to withdraw.",
+				"- `permissions` - Bitmask of POSITION_DELEGATE_PERMISSION_* flags.",
 				"",
 				"#### Special Errors",
-				"- `LiquidityZero` - Provided liquidity amount is zero.",
-				"- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
-				"- `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.",
+				"- `InvalidPositionDelegatePermissions` - The permissions are empty or contain unknown flags.",
 			];
 			accounts: [
 				{
-					name: "yevefi";
+					name: "funder";
 					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: "tokenProgramA";
-					isMut: false;
-					isSigner: false;
-				},
-				{
-					name: "tokenProgramB";
-					isMut: false;
-					isSigner: false;
-				},
-				{
-					name: "memoProgram";
-					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
 					name: "positionAuthority";
@@ -2382,283 +2610,238 @@ export type Yevefi = {
 				},
 				{
 					name: "position";
-					isMut: true;
-					isSigner: false;
-					relations: ["yevefi"];
-				},
-				{
-					name: "positionTokenAccount";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenMintA";
+					name: "positionTokenAccount";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenMintB";
+					name: "delegate";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenOwnerAccountA";
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: "tokenOwnerAccountB";
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: "tokenVaultA";
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: "tokenVaultB";
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: "tickArrayLower";
+					name: "positionDelegate";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 				{
-					name: "tickArrayUpper";
-					isMut: true;
+					name: "systemProgram";
+					isMut: false;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 			];
 			args: [
 				{
-					name: "liquidityAmount";
-					type: "u128";
-				},
-				{
-					name: "tokenMinA";
-					type: "u64";
-				},
-				{
-					name: "tokenMinB";
-					type: "u64";
-				},
-				{
-					name: "remainingAccountsInfo";
-					type: {
-						option: {
-							defined: "RemainingAccountsInfo";
-						};
-					};
+					name: "permissions";
+					type: "u8";
 				},
 			];
 		},
 		{
-			name: "increaseLiquidityV2";
+			name: "setPositionDelegatePermissions";
 			docs: [
-				"Add liquidity to a position in the Yevefi. This call also updates the position's accrued fees and rewards.",
+				"Replace the permissions of a delegate on a position.",
 				"",
 				"### Authority",
-				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				'- "position_authority" - The authority that owns the position token.',
 				"",
 				"### Parameters",
-				"- `liquidity_amount` - The total amount of Liquidity the user is willing to deposit.",
-				"- `token_max_a` - The maximum amount of tokenA the user is willing to deposit.",
-				"- `token_max_b` - The maximum amount of tokenB the user is willing to deposit.",
+				"- `permissions` - Bitmask of POSITION_DELEGATE_PERMISSION_* flags.",
 				"",
 				"#### Special Errors",
-				"- `LiquidityZero` - Provided liquidity amount is zero.",
-				"- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
-				"- `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.",
+				"- `InvalidPositionDelegatePermissions` - The permissions are empty or contain unknown flags.",
 			];
 			accounts: [
 				{
-					name: "yevefi";
-					isMut: true;
-					isSigner: false;
+					name: "positionAuthority";
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: "tokenProgramA";
+					name: "position";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenProgramB";
+					name: "positionTokenAccount";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "memoProgram";
-					isMut: false;
+					name: "positionDelegate";
+					isMut: true;
 					isSigner: false;
 				},
+			];
+			args: [
+				{
+					name: "permissions";
+					type: "u8";
+				},
+			];
+		},
+		{
+			name: "closePositionDelegate";
+			docs: [
+				"Revoke a delegate of a position and close its PositionDelegate account.",
+				"",
+				"### Authority",
+				'- "position_authority" - The authority that owns the position token.',
+			];
+			accounts: [
 				{
 					name: "positionAuthority";
 					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: "position";
+					name: "receiver";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 				{
-					name: "positionTokenAccount";
+					name: "position";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenMintA";
+					name: "positionTokenAccount";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenMintB";
-					isMut: false;
+					name: "positionDelegate";
+					isMut: true;
 					isSigner: false;
 				},
+			];
+			args: [];
+		},
+		{
+			name: "migrateYevefis";
+			docs: [
+				"Migrates the Yevefis created before fields were appended to their layout, reallocating",
+				"them to the current size. The Yevefis are passed as remaining accounts, so that many",
+				"pools can be migrated in one transaction. Yevefis which already have the current layout",
+				"are skipped. The appended fields are zeroed, so the features they back start out disabled.",
+				"A Yevefi must be migrated before any other instruction can use it.",
+				"The funder pays the rent for the additional space.",
+				"",
+				"Each Yevefi is followed by the reward vaults of its initialized rewards. The rewards earned",
+				"by positions before the migration are unknown, so the reward vault amounts are kept owed",
+				"to the positions and cannot be withdrawn with `withdraw_reward_surplus`. For the same",
+				"reason they do not fund emissions, which continue once tokens are added to the vaults.",
+				"",
+				"#### Special Errors",
+				"- `InvalidRewardAccounts` - A reward vault is missing or does not match its reward.",
+			];
+			accounts: [
 				{
-					name: "tokenOwnerAccountA";
+					name: "funder";
 					isMut: true;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: "tokenOwnerAccountB";
-					isMut: true;
-					isSigner: false;
-				},
-				{
-					name: "tokenVaultA";
-					isMut: true;
+					name: "systemProgram";
+					isMut: false;
 					isSigner: false;
 				},
+			];
+			args: [];
+		},
+		{
+			name: "migrateYevefisConfig";
+			docs: [
+				"Migrates a YevefisConfig created before the pending authorities were appended to its",
+				"layout, reallocating it to the current size. The appended fields are zeroed, so no",
+				"authority transfer is pending. A YevefisConfig which already has the current layout is",
+				"left unchanged. It must be migrated before any other instruction can use it.",
+				"The funder pays the rent for the additional space.",
+				"",
+				"### Authority",
+				'- "fee_authority" - Set authority in the YevefisConfig',
+			];
+			accounts: [
 				{
-					name: "tokenVaultB";
+					name: "yevefisConfig";
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: "tickArrayLower";
-					isMut: true;
-					isSigner: false;
-					relations: ["yevefi"];
+					name: "feeAuthority";
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: "tickArrayUpper";
+					name: "funder";
 					isMut: true;
-					isSigner: false;
-					relations: ["yevefi"];
-				},
-			];
-			args: [
-				{
-					name: "liquidityAmount";
-					type: "u128";
-				},
-				{
-					name: "tokenMaxA";
-					type: "u64";
-				},
-				{
-					name: "tokenMaxB";
-					type: "u64";
+					isSigner: true;
 				},
 				{
-					name: "remainingAccountsInfo";
-					type: {
-						option: {
-							defined: "RemainingAccountsInfo";
-						};
-					};
+					name: "systemProgram";
+					isMut: false;
+					isSigner: false;
 				},
 			];
+			args: [];
 		},
 		{
-			name: "initializePoolV2";
+			name: "initializeOracle";
 			docs: [
-				"Initializes a Yevefi account.",
-				"Fee rate is set to the default values on the config and supplied fee_tier.",
-				"",
-				"### Parameters",
-				"- `bumps` - The bump value when deriving the PDA of the Yevefi address.",
-				"- `tick_spacing` - The desired tick spacing for this pool.",
-				"- `initial_sqrt_price` - The desired initial sqrt-price for this pool",
-				"",
-				"#### Special Errors",
-				"`InvalidTokenMintOrder` - The order of mints have to be ordered by",
-				"`SqrtPriceOutOfBounds` - provided initial_sqrt_price is not between 2^-64 to 2^64",
-				"",
+				"Initializes the oracle account of a Yevefi with a single observation slot.",
+				"Once initialized, every swap and liquidity change records the prior state of the pool into",
+				"the oracle, at most once per timestamp. The first observation is written on the next one.",
+				"The oracle must then be passed as writable: v1 `swap` and `two_hop_swap` clients which pass",
+				"it as read-only are rejected. Liquidity instructions without an oracle account require the",
+				"oracle PDA, initialized or not. v1 instructions take it as the second remaining account and",
+				"v2 instructions as an `Oracle` remaining accounts slice.",
 			];
 			accounts: [
 				{
-					name: "yevefisConfig";
+					name: "yevefi";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenMintA";
-					isMut: false;
-					isSigner: false;
+					name: "funder";
+					isMut: true;
+					isSigner: true;
 				},
 				{
-					name: "tokenMintB";
-					isMut: false;
+					name: "oracle";
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: "tokenBadgeA";
+					name: "systemProgram";
 					isMut: false;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "token_badge";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "YevefisConfig";
-								path: "yevefis_config";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								path: "token_mint_a";
-							},
-						];
-					};
 				},
+			];
+			args: [];
+		},
+		{
+			name: "increaseObservationCardinality";
+			docs: [
+				"Grows the observation ring buffer of an oracle. The funder pays the rent for the new slots.",
+				"The new slots are taken into use once the ring buffer wraps around.",
+				"",
+				"### Parameters",
+				"- `observation_cardinality_next` - The number of observation slots the oracle should hold.",
+				"",
+				"#### Special Errors",
+				"- `InvalidObservationCardinality` - If the cardinality does not increase, or increases by more",
+				"than MAX_OBSERVATION_CARDINALITY_INCREASE.",
+			];
+			accounts: [
 				{
-					name: "tokenBadgeB";
+					name: "yevefi";
 					isMut: false;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "token_badge";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "YevefisConfig";
-								path: "yevefis_config";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								path: "token_mint_b";
-							},
-						];
-					};
 				},
 				{
 					name: "funder";
@@ -2666,674 +2849,567 @@ export type Yevefi = {
 					isSigner: true;
 				},
 				{
-					name: "yevefi";
-					isMut: true;
-					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "yevefi";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "YevefisConfig";
-								path: "yevefis_config";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								path: "token_mint_a";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								path: "token_mint_b";
-							},
-							{
-								kind: "arg";
-								type: "u16";
-								path: "tick_spacing";
-							},
-						];
-					};
-				},
-				{
-					name: "tokenVaultA";
-					isMut: true;
-					isSigner: true;
-				},
-				{
-					name: "tokenVaultB";
+					name: "oracle";
 					isMut: true;
-					isSigner: true;
-				},
-				{
-					name: "feeTier";
-					isMut: false;
 					isSigner: false;
-					relations: ["yevefis_config"];
 				},
 				{
-					name: "tokenProgramA";
+					name: "systemProgram";
 					isMut: false;
 					isSigner: false;
 				},
+			];
+			args: [
 				{
-					name: "tokenProgramB";
-					isMut: false;
-					isSigner: false;
+					name: "observationCardinalityNext";
+					type: "u16";
 				},
+			];
+		},
+		{
+			name: "observe";
+			docs: [
+				"Returns the tick cumulative and seconds per liquidity cumulative of a Yevefi as of each",
+				"`seconds_ago` before the current timestamp. The result is an `ObserveResult` set as return data,",
+				"so the instruction can be used by other programs via CPI.",
+				"",
+				"### Parameters",
+				"- `seconds_agos` - The ages of the requested observations, in seconds.",
+				"",
+				"#### Special Errors",
+				"- `ObservationTooOld` - If a requested observation is older than the oldest observation.",
+				"- `TooManySecondsAgos` - If more than MAX_OBSERVE_SECONDS_AGOS_LEN observations are requested.",
+			];
+			accounts: [
 				{
-					name: "systemProgram";
+					name: "yevefi";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "rent";
+					name: "oracle";
 					isMut: false;
 					isSigner: false;
 				},
 			];
 			args: [
 				{
-					name: "tickSpacing";
-					type: "u16";
+					name: "secondsAgos";
+					type: {
+						vec: "u32";
+					};
 				},
+			];
+		},
+		{
+			name: "rewardRunway";
+			docs: [
+				"Returns the number of seconds until the funds of each reward of a Yevefi run out at its",
+				"scheduled emission rates, assuming the Yevefi has liquidity. The result is a",
+				"`RewardRunwayResult` set as return data, so the instruction can be used by other programs via CPI.",
+			];
+			accounts: [
 				{
-					name: "initialSqrtPrice";
-					type: "u128";
+					name: "yevefi";
+					isMut: false;
+					isSigner: false;
 				},
 			];
+			args: [];
 		},
 		{
-			name: "initializeRewardV2";
+			name: "initializeAdaptiveFeeTier";
 			docs: [
-				"Initialize reward for a Yevefi. A pool can only support up to a set number of rewards.",
+				"Initializes an adaptive_fee_tier account usable by Yevefis in a YevefiConfig space.",
+				"Yevefis initialized with an adaptive fee tier charge a base fee plus a variable fee that",
+				"grows with the volatility of the price and decays over time.",
 				"",
 				"### Authority",
-				'- "reward_authority" - assigned authority by the reward_super_authority for the specified',
-				"reward-index in this Yevefi",
+				'- "fee_authority" - Set authority in the YevefiConfig',
 				"",
 				"### Parameters",
-				"- `reward_index` - The reward index that we'd like to initialize. (0 <= index <= NUM_REWARDS)",
+				"- `fee_tier_index` - The index used as the tick_spacing seed of the Yevefis using this tier.",
+				"Must not be equal to tick_spacing, nor to the tick_spacing of a",
+				"FeeTier of the config.",
+				"- `tick_spacing` - The tick-spacing of the Yevefis using this tier.",
+				"- `default_base_fee_rate` - The base fee rate that a pool will use if the pool uses this",
+				"adaptive fee tier during initialization.",
+				"- `adaptive_fee_constants` - The parameters of the variable fee.",
 				"",
 				"#### Special Errors",
-				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
-				"index in this pool, or exceeds NUM_REWARDS, or",
-				"all reward slots for this pool has been initialized.",
+				"- `InvalidFeeTierIndex` - If fee_tier_index is equal to tick_spacing.",
+				"",
+				"The AdaptiveFeeTier shares its address with the FeeTier using fee_tier_index as",
+				"tick_spacing, so initializing one fails if the other exists.",
+				"- `InvalidTickSpacing` - If tick_spacing is 0.",
+				"- `FeeRateMaxExceeded` - If the provided default_base_fee_rate exceeds MAX_FEE_RATE.",
+				"- `InvalidAdaptiveFeeConstants` - If the provided adaptive_fee_constants are invalid.",
 			];
 			accounts: [
 				{
-					name: "rewardAuthority";
+					name: "config";
 					isMut: false;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: "funder";
+					name: "adaptiveFeeTier";
 					isMut: true;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: "yevefi";
+					name: "funder";
 					isMut: true;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: "rewardMint";
+					name: "feeAuthority";
 					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: "rewardTokenBadge";
+					name: "systemProgram";
 					isMut: false;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "token_badge";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "Yevefi";
-								path: "yevefi.yevefis_config";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								path: "reward_mint";
-							},
-						];
-					};
-				},
-				{
-					name: "rewardVault";
-					isMut: true;
-					isSigner: true;
 				},
+			];
+			args: [
 				{
-					name: "rewardTokenProgram";
-					isMut: false;
-					isSigner: false;
+					name: "feeTierIndex";
+					type: "u16";
 				},
 				{
-					name: "systemProgram";
-					isMut: false;
-					isSigner: false;
+					name: "tickSpacing";
+					type: "u16";
 				},
 				{
-					name: "rent";
-					isMut: false;
-					isSigner: false;
+					name: "defaultBaseFeeRate";
+					type: "u16";
 				},
-			];
-			args: [
 				{
-					name: "rewardIndex";
-					type: "u8";
+					name: "adaptiveFeeConstants";
+					type: {
+						defined: "AdaptiveFeeConstants";
+					};
 				},
 			];
 		},
 		{
-			name: "setRewardEmissionsV2";
+			name: "setDefaultBaseFeeRate";
 			docs: [
-				"Set the reward emissions for a reward in a Yevefi.",
+				"Set the default_base_fee_rate for an AdaptiveFeeTier",
+				"Only the current fee authority has permission to invoke this instruction.",
 				"",
 				"### Authority",
-				'- "reward_authority" - assigned authority by the reward_super_authority for the specified',
-				"reward-index in this Yevefi",
+				'- "fee_authority" - Set authority in the YevefiConfig',
 				"",
 				"### Parameters",
-				"- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.",
-				"- `emissions_per_second_x64` - The amount of rewards emitted in this pool.",
+				"- `default_base_fee_rate` - The base fee rate that a pool will use if the pool uses this",
+				"adaptive fee tier during initialization.",
 				"",
 				"#### Special Errors",
-				"- `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit",
-				"more than a day of desired emissions.",
-				"- `InvalidTimestamp` - Provided timestamp is not in order with the previous timestamp.",
-				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
-				"index in this pool, or exceeds NUM_REWARDS, or",
-				"all reward slots for this pool has been initialized.",
+				"- `FeeRateMaxExceeded` - If the provided default_base_fee_rate exceeds MAX_FEE_RATE.",
 			];
 			accounts: [
 				{
-					name: "yevefi";
-					isMut: true;
+					name: "yevefisConfig";
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "rewardAuthority";
-					isMut: false;
-					isSigner: true;
+					name: "adaptiveFeeTier";
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: "rewardVault";
+					name: "feeAuthority";
 					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				},
 			];
 			args: [
 				{
-					name: "rewardIndex";
-					type: "u8";
-				},
-				{
-					name: "emissionsPerSecondX64";
-					type: "u128";
+					name: "defaultBaseFeeRate";
+					type: "u16";
 				},
 			];
 		},
 		{
-			name: "swapV2";
+			name: "initializeRewardExtension";
 			docs: [
-				"Perform a swap in this Yevefi",
+				"Initializes the reward extension of a Yevefi, adding NUM_EXTENSION_REWARDS rewards on top",
+				"of the NUM_REWARDS rewards of the Yevefi. The authorities of the extension rewards are set",
+				"to the reward_emissions_super_authority.",
 				"",
-				"### Authority",
-				'- "token_authority" - The authority to withdraw tokens from the input token account.',
+				"Once initialized, the Yevefi can only be swapped with `swap_v2` and its liquidity can only be",
+				"modified with `increase_liquidity_v2` and `decrease_liquidity_v2`, which maintain the",
+				"extension rewards through the RewardExtension remaining accounts. The following",
+				"instructions do not maintain the extension rewards and fail with",
+				"`RewardExtensionNotSupported` on the Yevefi: `swap`, `two_hop_swap`, `two_hop_swap_v2`,",
+				"`n_hop_swap_v2`, `flash_swap_v2`, `increase_liquidity`, `decrease_liquidity`,",
+				"`compound_position_fees`, `zap_in`, `zap_out` and `reposition_liquidity`.",
+				"Extension rewards are emitted only while their vaults hold unemitted funds.",
 				"",
-				"### Parameters",
-				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
-				"- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
-				"- `sqrt_price_limit` - The maximum/minimum price the swap will swap to.",
-				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
-				"- `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.",
-				"",
-				"#### Special Errors",
-				"- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
-				"- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
-				"- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
-				"- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
-				"- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
-				"- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
-				"- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
-				"- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
+				"### Authority",
+				'- "reward_emissions_super_authority" - Set authority in the YevefiConfig',
 			];
 			accounts: [
 				{
-					name: "tokenProgramA";
-					isMut: false;
-					isSigner: false;
-				},
-				{
-					name: "tokenProgramB";
-					isMut: false;
-					isSigner: false;
-				},
-				{
-					name: "memoProgram";
+					name: "yevefisConfig";
 					isMut: false;
 					isSigner: false;
 				},
-				{
-					name: "tokenAuthority";
-					isMut: false;
-					isSigner: true;
-				},
 				{
 					name: "yevefi";
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: "tokenMintA";
-					isMut: false;
-					isSigner: false;
-				},
-				{
-					name: "tokenMintB";
+					name: "rewardEmissionsSuperAuthority";
 					isMut: false;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: "tokenOwnerAccountA";
+					name: "funder";
 					isMut: true;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: "tokenVaultA";
+					name: "yevefiRewardExtension";
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: "tokenOwnerAccountB";
-					isMut: true;
+					name: "systemProgram";
+					isMut: false;
 					isSigner: false;
 				},
+			];
+			args: [];
+		},
+		{
+			name: "initializeTickArrayRewardExtension";
+			docs: [
+				"Initializes the extension reward growths of the ticks of a TickArray.",
+				"Swaps crossing ticks of the TickArray must include the TickArrayRewardExtension once initialized.",
+				"",
+				"### Parameters",
+				"- `start_tick_index` - The starting tick index of the TickArray.",
+				"",
+				"#### Special Errors",
+				"- `InvalidStartTick` - if the provided start tick is out of bounds or is not a multiple of",
+				"TICK_ARRAY_SIZE * tick spacing.",
+			];
+			accounts: [
 				{
-					name: "tokenVaultB";
-					isMut: true;
+					name: "yevefi";
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tickArray0";
+					name: "yevefiRewardExtension";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 				{
-					name: "tickArray1";
+					name: "funder";
 					isMut: true;
-					isSigner: false;
-					relations: ["yevefi"];
+					isSigner: true;
 				},
 				{
-					name: "tickArray2";
+					name: "tickArrayRewardExtension";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefi"];
 				},
 				{
-					name: "oracle";
-					isMut: true;
+					name: "systemProgram";
+					isMut: false;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "oracle";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "Yevefi";
-								path: "yevefi";
-							},
-						];
-					};
 				},
 			];
 			args: [
 				{
-					name: "amount";
-					type: "u64";
-				},
-				{
-					name: "otherAmountThreshold";
-					type: "u64";
-				},
-				{
-					name: "sqrtPriceLimit";
-					type: "u128";
-				},
-				{
-					name: "amountSpecifiedIsInput";
-					type: "bool";
-				},
-				{
-					name: "aToB";
-					type: "bool";
-				},
-				{
-					name: "remainingAccountsInfo";
-					type: {
-						option: {
-							defined: "RemainingAccountsInfo";
-						};
-					};
+					name: "startTickIndex";
+					type: "i32";
 				},
 			];
 		},
 		{
-			name: "twoHopSwapV2";
+			name: "initializePositionRewardExtension";
 			docs: [
-				"Perform a two-hop swap in this Yevefi",
+				"Initializes the extension rewards of a position. The position earns extension rewards from",
+				"this point on. The TickArrayRewardExtensions of its lower and upper ticks must be initialized.",
 				"",
 				"### Authority",
-				'- "token_authority" - The authority to withdraw tokens from the input token account.',
-				"",
-				"### Parameters",
-				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
-				"- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
-				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
-				"- `a_to_b_one` - The direction of the swap of hop one. True if swapping from A to B. False if swapping from B to A.",
-				"- `a_to_b_two` - The direction of the swap of hop two. True if swapping from A to B. False if swapping from B to A.",
-				"- `sqrt_price_limit_one` - The maximum/minimum price the swap will swap to in the first hop.",
-				"- `sqrt_price_limit_two` - The maximum/minimum price the swap will swap to in the second hop.",
-				"",
-				"#### Special Errors",
-				"- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
-				"- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
-				"- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
-				"- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
-				"- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
-				"- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
-				"- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
-				"- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
-				"- `InvalidIntermediaryMint` - Error if the intermediary mint between hop one and two do not equal.",
-				"- `DuplicateTwoHopPool` - Error if yevefi one & two are the same pool.",
+				'- "position_authority" - The authority that owns the position token.',
 			];
 			accounts: [
 				{
-					name: "yevefiOne";
+					name: "funder";
 					isMut: true;
-					isSigner: false;
+					isSigner: true;
 				},
 				{
-					name: "yevefiTwo";
-					isMut: true;
-					isSigner: false;
+					name: "positionAuthority";
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: "tokenMintInput";
+					name: "position";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenMintIntermediate";
+					name: "positionTokenAccount";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenMintOutput";
+					name: "yevefi";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenProgramInput";
-					isMut: false;
+					name: "yevefiRewardExtension";
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: "tokenProgramIntermediate";
+					name: "tickArrayLowerRewardExtension";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenProgramOutput";
+					name: "tickArrayUpperRewardExtension";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenOwnerAccountInput";
+					name: "positionRewardExtension";
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: "tokenVaultOneInput";
-					isMut: true;
+					name: "systemProgram";
+					isMut: false;
 					isSigner: false;
 				},
+			];
+			args: [];
+		},
+		{
+			name: "updatePositionRewardExtension";
+			docs: ["Update the extension rewards owed to a position."];
+			accounts: [
 				{
-					name: "tokenVaultOneIntermediate";
-					isMut: true;
+					name: "yevefi";
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenVaultTwoIntermediate";
+					name: "yevefiRewardExtension";
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: "tokenVaultTwoOutput";
-					isMut: true;
+					name: "position";
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenOwnerAccountOutput";
+					name: "positionRewardExtension";
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: "tokenAuthority";
+					name: "tickArrayLowerRewardExtension";
 					isMut: false;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: "tickArrayOne0";
-					isMut: true;
+					name: "tickArrayUpperRewardExtension";
+					isMut: false;
 					isSigner: false;
 				},
+			];
+			args: [];
+		},
+		{
+			name: "closePositionRewardExtension";
+			docs: [
+				"Close the PositionRewardExtension of a position, and stop earning extension rewards.",
+				"",
+				"### Authority",
+				'- "position_authority" - The authority that owns the position token.',
+				"",
+				"#### Special Errors",
+				"- `ClosePositionNotEmpty` - The position has extension rewards owed.",
+			];
+			accounts: [
 				{
-					name: "tickArrayOne1";
-					isMut: true;
-					isSigner: false;
+					name: "positionAuthority";
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: "tickArrayOne2";
+					name: "receiver";
 					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: "tickArrayTwo0";
-					isMut: true;
+					name: "position";
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tickArrayTwo1";
-					isMut: true;
+					name: "positionTokenAccount";
+					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tickArrayTwo2";
+					name: "positionRewardExtension";
 					isMut: true;
 					isSigner: false;
 				},
+			];
+			args: [];
+		},
+		{
+			name: "setExtensionRewardAuthority";
+			docs: [
+				"Set the authority of an extension reward of a Yevefi.",
+				"",
+				"### Authority",
+				'- "reward_authority" - Set authority for the extension reward.',
+				"",
+				"### Parameters",
+				"- `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS) that we'd like to modify.",
+				"",
+				"#### Special Errors",
+				"- `InvalidRewardIndex` - If the provided reward index exceeds NUM_EXTENSION_REWARDS.",
+			];
+			accounts: [
 				{
-					name: "oracleOne";
+					name: "yevefiRewardExtension";
 					isMut: true;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "oracle";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "Yevefi";
-								path: "yevefi_one";
-							},
-						];
-					};
 				},
 				{
-					name: "oracleTwo";
-					isMut: true;
-					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "oracle";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "Yevefi";
-								path: "yevefi_two";
-							},
-						];
-					};
+					name: "rewardAuthority";
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: "memoProgram";
+					name: "newRewardAuthority";
 					isMut: false;
 					isSigner: false;
 				},
 			];
 			args: [
 				{
-					name: "amount";
-					type: "u64";
-				},
-				{
-					name: "otherAmountThreshold";
-					type: "u64";
-				},
-				{
-					name: "amountSpecifiedIsInput";
-					type: "bool";
+					name: "rewardIndex";
+					type: "u8";
 				},
-				{
-					name: "aToBOne";
-					type: "bool";
+			];
+		},
+		{
+			name: "collectFeesV2";
+			docs: [
+				"Collect fees accrued for this position.",
+				"",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"A PositionDelegate with the collect fees permission can also authorize this call,",
+				"passed as a `PositionDelegate` remaining accounts slice.",
+				"",
+				"#### Special Errors",
+				"- `PositionDelegateInvalidDestination` - A PositionDelegate transfers tokens to an account not owned by the position owner.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: "aToBTwo";
-					type: "bool";
+					name: "positionAuthority";
+					isMut: false;
+					isSigner: true;
 				},
 				{
-					name: "sqrtPriceLimitOne";
-					type: "u128";
+					name: "position";
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: "sqrtPriceLimitTwo";
-					type: "u128";
+					name: "positionTokenAccount";
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: "remainingAccountsInfo";
-					type: {
-						option: {
-							defined: "RemainingAccountsInfo";
-						};
-					};
+					name: "tokenMintA";
+					isMut: false;
+					isSigner: false;
 				},
-			];
-		},
-		{
-			name: "initializeConfigExtension";
-			accounts: [
 				{
-					name: "config";
+					name: "tokenMintB";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "configExtension";
+					name: "tokenOwnerAccountA";
 					isMut: true;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "config_extension";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "YevefisConfig";
-								path: "config";
-							},
-						];
-					};
 				},
 				{
-					name: "funder";
+					name: "tokenVaultA";
 					isMut: true;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: "feeAuthority";
-					isMut: false;
-					isSigner: true;
+					name: "tokenOwnerAccountB";
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: "systemProgram";
-					isMut: false;
+					name: "tokenVaultB";
+					isMut: true;
 					isSigner: false;
 				},
-			];
-			args: [];
-		},
-		{
-			name: "setConfigExtensionAuthority";
-			accounts: [
 				{
-					name: "yevefisConfig";
+					name: "tokenProgramA";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "yevefisConfigExtension";
-					isMut: true;
+					name: "tokenProgramB";
+					isMut: false;
 					isSigner: false;
-					relations: ["yevefis_config"];
 				},
 				{
-					name: "configExtensionAuthority";
+					name: "memoProgram";
 					isMut: false;
-					isSigner: true;
+					isSigner: false;
 				},
+			];
+			args: [
 				{
-					name: "newConfigExtensionAuthority";
-					isMut: false;
-					isSigner: false;
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
 				},
 			];
-			args: [];
 		},
 		{
-			name: "setTokenBadgeAuthority";
+			name: "collectProtocolFeesV2";
+			docs: [
+				"Collect the protocol fees accrued in this Yevefi",
+				"",
+				"### Authority",
+				"- `collect_protocol_fees_authority` - assigned authority in the YevefiConfig that can collect protocol fees",
+			];
 			accounts: [
 				{
 					name: "yevefisConfig";
@@ -3341,160 +3417,129 @@ export type Yevefi = {
 					isSigner: false;
 				},
 				{
-					name: "yevefisConfigExtension";
+					name: "yevefi";
 					isMut: true;
 					isSigner: false;
-					relations: ["yevefis_config"];
 				},
 				{
-					name: "configExtensionAuthority";
+					name: "collectProtocolFeesAuthority";
 					isMut: false;
 					isSigner: true;
 				},
 				{
-					name: "newTokenBadgeAuthority";
+					name: "tokenMintA";
 					isMut: false;
 					isSigner: false;
 				},
-			];
-			args: [];
-		},
-		{
-			name: "initializeTokenBadge";
-			accounts: [
 				{
-					name: "yevefisConfig";
+					name: "tokenMintB";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "yevefisConfigExtension";
-					isMut: false;
+					name: "tokenVaultA";
+					isMut: true;
 					isSigner: false;
-					relations: ["yevefis_config"];
 				},
 				{
-					name: "tokenBadgeAuthority";
-					isMut: false;
-					isSigner: true;
+					name: "tokenVaultB";
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: "tokenMint";
-					isMut: false;
+					name: "tokenDestinationA";
+					isMut: true;
 					isSigner: false;
 				},
 				{
-					name: "tokenBadge";
+					name: "tokenDestinationB";
 					isMut: true;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "token_badge";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "YevefisConfig";
-								path: "yevefis_config";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								path: "token_mint";
-							},
-						];
-					};
 				},
 				{
-					name: "funder";
-					isMut: true;
-					isSigner: true;
+					name: "tokenProgramA";
+					isMut: false;
+					isSigner: false;
 				},
 				{
-					name: "systemProgram";
+					name: "tokenProgramB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
 					isMut: false;
 					isSigner: false;
 				},
 			];
-			args: [];
+			args: [
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
 		},
 		{
-			name: "deleteTokenBadge";
+			name: "collectRewardV2";
+			docs: [
+				"Collect rewards accrued for this position.",
+				"",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"A PositionDelegate with the collect rewards permission can also authorize this call,",
+				"passed as a `PositionDelegate` remaining accounts slice.",
+				"",
+				"#### Special Errors",
+				"- `PositionDelegateInvalidDestination` - A PositionDelegate transfers tokens to an account not owned by the position owner.",
+			];
 			accounts: [
 				{
-					name: "yevefisConfig";
+					name: "yevefi";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "yevefisConfigExtension";
+					name: "positionAuthority";
 					isMut: false;
-					isSigner: false;
-					relations: ["yevefis_config"];
+					isSigner: true;
 				},
 				{
-					name: "tokenBadgeAuthority";
-					isMut: false;
-					isSigner: true;
+					name: "position";
+					isMut: true;
+					isSigner: false;
 				},
 				{
-					name: "tokenMint";
+					name: "positionTokenAccount";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "tokenBadge";
+					name: "rewardOwnerAccount";
 					isMut: true;
 					isSigner: false;
-					pda: {
-						seeds: [
-							{
-								kind: "const";
-								type: "string";
-								value: "token_badge";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								account: "YevefisConfig";
-								path: "yevefis_config";
-							},
-							{
-								kind: "account";
-								type: "publicKey";
-								path: "token_mint";
-							},
-						];
-					};
-					relations: ["yevefis_config"];
 				},
 				{
-					name: "receiver";
-					isMut: true;
+					name: "rewardMint";
+					isMut: false;
 					isSigner: false;
 				},
-			];
-			args: [];
-		},
-		{
-			name: "veRewardEmissions";
-			accounts: [
 				{
-					name: "funder";
+					name: "rewardVault";
 					isMut: true;
-					isSigner: true;
+					isSigner: false;
 				},
 				{
-					name: "config";
+					name: "rewardTokenProgram";
 					isMut: false;
 					isSigner: false;
 				},
 				{
-					name: "yevefi";
-					isMut: true;
+					name: "memoProgram";
+					isMut: false;
 					isSigner: false;
 				},
 			];
@@ -3504,827 +3549,8577 @@ export type Yevefi = {
 					type: "u8";
 				},
 				{
-					name: "emissionsPerSecondX64";
-					type: "u128";
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
 				},
 			];
 		},
-	];
-	accounts: [
-		{
-			name: "yevefisConfigExtension";
-			type: {
-				kind: "struct";
-				fields: [
-					{
-						name: "yevefisConfig";
-						type: "publicKey";
-					},
-					{
-						name: "configExtensionAuthority";
-						type: "publicKey";
-					},
-					{
-						name: "tokenBadgeAuthority";
-						type: "publicKey";
-					},
-				];
-			};
-		},
 		{
-			name: "yevefisConfig";
-			type: {
-				kind: "struct";
-				fields: [
-					{
-						name: "feeAuthority";
-						type: "publicKey";
-					},
-					{
-						name: "collectProtocolFeesAuthority";
-						type: "publicKey";
-					},
+			name: "harvestPositionV2";
+			docs: [
+				"Collect the fees and all rewards accrued for this position.",
+				"Fee and reward growths are updated once, then every initialized reward is paid out.",
+				"",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"A PositionDelegate with the collect fees and collect rewards permissions can also authorize this call,",
+				"passed as a `PositionDelegate` remaining accounts slice.",
+				"",
+				"#### Special Errors",
+				"- `InvalidRewardAccounts` - The reward accounts do not match the initialized rewards of the Yevefi.",
+				"- `PositionDelegateInvalidDestination` - A PositionDelegate transfers tokens to an account not owned by the position owner.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "positionAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "position";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "positionTokenAccount";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayLower";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayUpper";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "collectFeesBundledV2";
+			docs: [
+				"Collect fees accrued for bundled positions of a PositionBundle in one call.",
+				"",
+				"### Authority",
+				"- `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.",
+				"",
+				"### Parameters",
+				"- `bundle_indexes` - The bundle indexes of the positions, in strictly increasing order.",
+				"- `bundled_position_bumps` - The bumps of the bundled position addresses, in the order of `bundle_indexes`.",
+				"",
+				"#### Special Errors",
+				"- `BundledPositionAlreadyClosed` - A bundled position at the provided bundle index is not opened.",
+				"- `InvalidBundledPositionAccounts` - The bundled position accounts do not match `bundle_indexes`.",
+				"- `InvalidBundledPositionBump` - The bumps in `bundled_position_bumps` do not derive the bundled position addresses.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "positionBundleAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "positionBundle";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "positionBundleTokenAccount";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "bundleIndexes";
+					type: {
+						vec: "u16";
+					};
+				},
+				{
+					name: "bundledPositionBumps";
+					type: "bytes";
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "collectRewardBundledV2";
+			docs: [
+				"Collect rewards accrued for bundled positions of a PositionBundle in one call.",
+				"",
+				"### Authority",
+				"- `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.",
+				"",
+				"### Parameters",
+				"- `reward_index` - The reward to harvest. Acceptable values are 0, 1, and 2.",
+				"- `bundle_indexes` - The bundle indexes of the positions, in strictly increasing order.",
+				"- `bundled_position_bumps` - The bumps of the bundled position addresses, in the order of `bundle_indexes`.",
+				"",
+				"#### Special Errors",
+				"- `BundledPositionAlreadyClosed` - A bundled position at the provided bundle index is not opened.",
+				"- `InvalidBundledPositionAccounts` - The bundled position accounts do not match `bundle_indexes`.",
+				"- `InvalidBundledPositionBump` - The bumps in `bundled_position_bumps` do not derive the bundled position addresses.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "positionBundleAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "positionBundle";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "positionBundleTokenAccount";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "rewardOwnerAccount";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "rewardMint";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "rewardVault";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "rewardTokenProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "rewardIndex";
+					type: "u8";
+				},
+				{
+					name: "bundleIndexes";
+					type: {
+						vec: "u16";
+					};
+				},
+				{
+					name: "bundledPositionBumps";
+					type: "bytes";
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "updateFeesAndRewardsBundled";
+			docs: [
+				"Update the accrued fees and rewards for bundled positions of a PositionBundle in one call.",
+				"Bundled positions without liquidity are skipped.",
+				"",
+				"### Parameters",
+				"- `bundle_indexes` - The bundle indexes of the positions, in strictly increasing order.",
+				"- `bundled_position_bumps` - The bumps of the bundled position addresses, in the order of `bundle_indexes`.",
+				"",
+				"#### Special Errors",
+				"- `BundledPositionAlreadyClosed` - A bundled position at the provided bundle index is not opened.",
+				"- `InvalidBundledPositionAccounts` - The bundled position or tick array accounts do not match `bundle_indexes`.",
+				"- `InvalidBundledPositionBump` - The bumps in `bundled_position_bumps` do not derive the bundled position addresses.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "positionBundle";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "bundleIndexes";
+					type: {
+						vec: "u16";
+					};
+				},
+				{
+					name: "bundledPositionBumps";
+					type: "bytes";
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "decreaseLiquidityV2";
+			docs: [
+				"Withdraw liquidity from a position in the Yevefi. This call also updates the position's accrued fees and rewards.",
+				"",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"A PositionDelegate with the decrease liquidity permission can also authorize this call,",
+				"passed as a `PositionDelegate` remaining accounts slice.",
+				"",
+				"### Parameters",
+				"- `liquidity_amount` - The total amount of Liquidity the user desires to withdraw.",
+				"- `token_min_a` - The minimum amount of tokenA the user is willing to withdraw.",
+				"- `token_min_b` - The minimum amount of tokenB the user is willing to withdraw.",
+				"",
+				"#### Special Errors",
+				"- `LiquidityZero` - Provided liquidity amount is zero.",
+				"- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
+				"- `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.",
+				"- `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.",
+				"- `InvalidPositionLock` - The PositionLock PDA of the position was not passed.",
+				"- `PositionDelegateInvalidDestination` - A PositionDelegate transfers tokens to an account not owned by the position owner.",
+				"- `InvalidRewardExtensionAccounts` - The Yevefi has a reward extension, and the RewardExtension",
+				"accounts are missing or invalid.",
+				"- `InvalidOracle` - The oracle account is missing or is not the oracle PDA of the Yevefi.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "positionAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "position";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "positionTokenAccount";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayLower";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayUpper";
+					isMut: true;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "liquidityAmount";
+					type: "u128";
+				},
+				{
+					name: "tokenMinA";
+					type: "u64";
+				},
+				{
+					name: "tokenMinB";
+					type: "u64";
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "increaseLiquidityV2";
+			docs: [
+				"Add liquidity to a position in the Yevefi. This call also updates the position's accrued fees and rewards.",
+				"",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"A PositionDelegate with the increase liquidity permission can also authorize this call,",
+				"passed as a `PositionDelegate` remaining accounts slice.",
+				"",
+				"### Parameters",
+				"- `liquidity_amount` - The total amount of Liquidity the user is willing to deposit.",
+				"- `token_max_a` - The maximum amount of tokenA the user is willing to deposit.",
+				"- `token_max_b` - The maximum amount of tokenB the user is willing to deposit.",
+				"",
+				"#### Special Errors",
+				"- `LiquidityZero` - Provided liquidity amount is zero.",
+				"- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
+				"- `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `InvalidRewardExtensionAccounts` - The Yevefi has a reward extension, and the RewardExtension",
+				"accounts are missing or invalid.",
+				"- `InvalidOracle` - The oracle account is missing or is not the oracle PDA of the Yevefi.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "positionAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "position";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "positionTokenAccount";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayLower";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayUpper";
+					isMut: true;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "liquidityAmount";
+					type: "u128";
+				},
+				{
+					name: "tokenMaxA";
+					type: "u64";
+				},
+				{
+					name: "tokenMaxB";
+					type: "u64";
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "increaseLiquidityByTokenAmountsV2";
+			docs: [
+				"Add liquidity to a position in the Yevefi, computing the liquidity on-chain from token budgets.",
+				"The largest liquidity whose deposit fits both budgets at the current price is added.",
+				"This call also updates the position's accrued fees and rewards.",
+				"",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"",
+				"### Parameters",
+				"- `token_max_a` - The maximum amount of tokenA the user is willing to deposit.",
+				"- `token_max_b` - The maximum amount of tokenB the user is willing to deposit.",
+				"",
+				"#### Special Errors",
+				"- `LiquidityZero` - The budgets are too small to fund any liquidity.",
+				"- `LiquidityTooHigh` - The computed liquidity exceeds u128::max.",
+				"- `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `InvalidRewardExtensionAccounts` - The Yevefi has a reward extension, and the RewardExtension",
+				"accounts are missing or invalid.",
+				"- `InvalidOracle` - The oracle account is missing or is not the oracle PDA of the Yevefi.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "positionAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "position";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "positionTokenAccount";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayLower";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayUpper";
+					isMut: true;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "tokenMaxA";
+					type: "u64";
+				},
+				{
+					name: "tokenMaxB";
+					type: "u64";
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "zapIn";
+			docs: [
+				"Deposit a single token into a position in the Yevefi.",
+				"Part of the input token is swapped in the same Yevefi, and the remainder and the swap output",
+				"are added as liquidity at the post-swap price. The split is solved on-chain with a bounded",
+				"number of simulated swaps, and the part of the swap output that cannot be deposited is",
+				"returned to the owner.",
+				"This call also updates the position's accrued fees and rewards.",
+				"",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position",
+				"and the input token account.",
+				"",
+				"### Parameters",
+				"- `amount` - The maximum amount of the input token the user is willing to deposit.",
+				"- `a_to_b` - The input token is tokenA if true, tokenB otherwise.",
+				"- `min_liquidity` - The minimum amount of liquidity the user is willing to accept.",
+				"",
+				"#### Special Errors",
+				"- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
+				"- `LiquidityZero` - The input amount is too small to fund any liquidity.",
+				"- `LiquidityBelowMinimum` - The added liquidity is below the user defined minimum.",
+				"- `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "positionAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "position";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "positionTokenAccount";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayLower";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayUpper";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArray0";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArray1";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArray2";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "oracle";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "yevefisConfigExtension";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "amount";
+					type: "u64";
+				},
+				{
+					name: "aToB";
+					type: "bool";
+				},
+				{
+					name: "minLiquidity";
+					type: "u128";
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "zapOut";
+			docs: [
+				"Withdraw a position into a single token.",
+				"Liquidity is removed from the position and its fees owed are collected, then all of the",
+				"unwanted token is swapped in the same Yevefi. Only the output token is transferred to the owner.",
+				"",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"",
+				"### Parameters",
+				"- `liquidity_amount` - The amount of liquidity to remove, or all liquidity of the position if None.",
+				"- `a_to_b` - The output token is tokenB if true, tokenA otherwise.",
+				"- `min_amount_out` - The minimum amount of the output token the user is willing to receive.",
+				"",
+				"#### Special Errors",
+				"- `LiquidityZero` - The amount of liquidity to remove is 0.",
+				"- `PartialFillError` - The unwanted token cannot be swapped in full with the supplied tick arrays.",
+				"- `AmountOutBelowMinimum` - The amount of the output token is below the user defined minimum.",
+				"- `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "positionAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "position";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "positionTokenAccount";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayLower";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayUpper";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArray0";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArray1";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArray2";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "oracle";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "yevefisConfigExtension";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "positionLock";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "liquidityAmount";
+					type: {
+						option: "u128";
+					};
+				},
+				{
+					name: "aToB";
+					type: "bool";
+				},
+				{
+					name: "minAmountOut";
+					type: "u64";
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "repositionLiquidity";
+			docs: [
+				"Re-range a position with a new size in one step.",
+				"All liquidity is removed from the current range, the position is moved to the new range,",
+				"and `liquidity_amount` is added to it, which may be more or less than was removed.",
+				"Only the difference between the removed and added token amounts is transferred.",
+				"Fees and rewards accrued so far remain owed to the position.",
+				"The TokenMetadata of a position opened with token extensions is refreshed to the new range,",
+				"with the funder paying the rent if its fields grow.",
+				"",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"",
+				"### Parameters",
+				"- `new_tick_lower_index` - The tick specifying the lower end of the new position range.",
+				"- `new_tick_upper_index` - The tick specifying the upper end of the new position range.",
+				"- `liquidity_amount` - The amount of Liquidity to add to the new range.",
+				"- `token_max_a` - The maximum amount of tokenA the user is willing to deposit on top of the removed amount.",
+				"- `token_max_b` - The maximum amount of tokenB the user is willing to deposit on top of the removed amount.",
+				"- `token_min_a` - The minimum amount of tokenA the user is willing to withdraw, net of the added amount.",
+				"- `token_min_b` - The minimum amount of tokenB the user is willing to withdraw, net of the added amount.",
+				"",
+				"#### Special Errors",
+				"- `LiquidityZero` - Provided liquidity amount is zero.",
+				"- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
+				"the tick-spacing in this pool.",
+				"- `SameTickRangeNotAllowed` - The new tick range is the current tick range.",
+				"- `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.",
+				"- `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.",
+				"- `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
+			];
+			accounts: [
+				{
+					name: "funder";
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "positionAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "position";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "positionTokenAccount";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "positionMint";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayLower";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayUpper";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "newTickArrayLower";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "newTickArrayUpper";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "token2022Program";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "systemProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "oracle";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "yevefisConfigExtension";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "positionLock";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "newTickLowerIndex";
+					type: "i32";
+				},
+				{
+					name: "newTickUpperIndex";
+					type: "i32";
+				},
+				{
+					name: "liquidityAmount";
+					type: "u128";
+				},
+				{
+					name: "tokenMaxA";
+					type: "u64";
+				},
+				{
+					name: "tokenMaxB";
+					type: "u64";
+				},
+				{
+					name: "tokenMinA";
+					type: "u64";
+				},
+				{
+					name: "tokenMinB";
+					type: "u64";
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "initializePoolV2";
+			docs: [
+				"Initializes a Yevefi account.",
+				"Fee rate is set to the default values on the config and supplied fee_tier.",
+				"",
+				"### Parameters",
+				"- `bumps` - The bump value when deriving the PDA of the Yevefi address.",
+				"- `tick_spacing` - The desired tick spacing for this pool.",
+				"- `initial_sqrt_price` - The desired initial sqrt-price for this pool",
+				"",
+				"#### Special Errors",
+				"`InvalidTokenMintOrder` - The order of mints have to be ordered by",
+				"`SqrtPriceOutOfBounds` - provided initial_sqrt_price is not between 2^-64 to 2^64",
+				"",
+			];
+			accounts: [
+				{
+					name: "yevefisConfig";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenBadgeA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenBadgeB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "funder";
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultA";
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: "tokenVaultB";
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: "feeTier";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "systemProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "rent";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "tickSpacing";
+					type: "u16";
+				},
+				{
+					name: "initialSqrtPrice";
+					type: "u128";
+				},
+			];
+		},
+		{
+			name: "initializeRewardV2";
+			docs: [
+				"Initialize reward for a Yevefi. A pool can only support up to a set number of rewards.",
+				"",
+				"### Authority",
+				'- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+				"reward-index in this Yevefi",
+				"",
+				"### Parameters",
+				"- `reward_index` - The reward index that we'd like to initialize. (0 <= index <= NUM_REWARDS)",
+				"",
+				"#### Special Errors",
+				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+				"index in this pool, or exceeds NUM_REWARDS, or",
+				"all reward slots for this pool has been initialized.",
+			];
+			accounts: [
+				{
+					name: "rewardAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "funder";
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "rewardMint";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "rewardTokenBadge";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "rewardVault";
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: "rewardTokenProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "systemProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "rent";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "rewardIndex";
+					type: "u8";
+				},
+			];
+		},
+		{
+			name: "setRewardEmissionsV2";
+			docs: [
+				"Set the reward emissions for a reward in a Yevefi.",
+				"The emission schedule of the reward, if any, is replaced by the constant emission rate.",
+				"The reward vault funds the emissions from now on, which stop once the funds run out.",
+				"",
+				"### Authority",
+				'- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+				"reward-index in this Yevefi",
+				"",
+				"### Parameters",
+				"- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.",
+				"- `emissions_per_second_x64` - The amount of rewards emitted in this pool.",
+				"",
+				"#### Special Errors",
+				"- `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit",
+				"more than a day of desired emissions.",
+				"- `InvalidTimestamp` - Provided timestamp is not in order with the previous timestamp.",
+				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+				"index in this pool, or exceeds NUM_REWARDS, or",
+				"all reward slots for this pool has been initialized.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "rewardAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "rewardVault";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "rewardIndex";
+					type: "u8";
+				},
+				{
+					name: "emissionsPerSecondX64";
+					type: "u128";
+				},
+			];
+		},
+		{
+			name: "setRewardEmissionsSchedule";
+			docs: [
+				"Set an emission schedule for a reward in a Yevefi, so that emissions start, change and stop",
+				"automatically. Rewards are accrued under the previous schedule until the current timestamp.",
+				"`set_reward_emissions` replaces the schedule with a constant emission rate.",
+				"The reward vault funds the emissions from now on, which stop once the funds run out.",
+				"",
+				"### Authority",
+				'- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+				"reward-index in this Yevefi",
+				"",
+				"### Parameters",
+				"- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.",
+				"- `emissions_per_second_x64` - The amount of rewards emitted in this pool from `start_timestamp`.",
+				"- `start_timestamp` - No rewards are emitted before this timestamp.",
+				"- `end_timestamp` - No rewards are emitted from this timestamp on. 0 if the emissions have no end.",
+				"- `rate_changes` - Up to MAX_REWARD_EMISSIONS_RATE_CHANGES emission rate changes, in ascending",
+				"timestamp order, between `start_timestamp` and `end_timestamp`.",
+				"",
+				"#### Special Errors",
+				"- `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit",
+				"more than a day of the highest emissions of the schedule.",
+				"- `InvalidRewardEmissionsSchedule` - The timestamps of the schedule are not in order, or there",
+				"are too many rate changes.",
+				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+				"index in this pool, or exceeds NUM_REWARDS, or",
+				"all reward slots for this pool has been initialized.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "rewardAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "rewardVault";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "rewardIndex";
+					type: "u8";
+				},
+				{
+					name: "emissionsPerSecondX64";
+					type: "u128";
+				},
+				{
+					name: "startTimestamp";
+					type: "u64";
+				},
+				{
+					name: "endTimestamp";
+					type: "u64";
+				},
+				{
+					name: "rateChanges";
+					type: {
+						vec: {
+							defined: "RewardEmissionsRateChange";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "fundReward";
+			docs: [
+				"Deposit tokens into the vault of a reward. Anyone can fund a reward.",
+				"The amount received by the vault is recorded as funds, which are emitted from now on at the",
+				"emission rate set by the reward authority.",
+				"",
+				"### Parameters",
+				"- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) to fund.",
+				"- `amount` - The amount of reward tokens to deposit, transfer fee included.",
+				"- `extend_emissions` - If true, the end of the emission schedule is moved back by the time",
+				"the received amount lasts at the emission rate before the end.",
+				"",
+				"#### Special Errors",
+				"- `RewardEmissionsNotExtendable` - If `extend_emissions` is set, but the emissions have no end",
+				"or have already ended.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "funder";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "funderTokenAccount";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "rewardMint";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "rewardVault";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "rewardTokenProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "rewardIndex";
+					type: "u8";
+				},
+				{
+					name: "amount";
+					type: "u64";
+				},
+				{
+					name: "extendEmissions";
+					type: "bool";
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "withdrawRewardSurplus";
+			docs: [
+				"Withdraw tokens from the vault of a reward, up to the surplus over the rewards owed to positions.",
+				"The surplus is the funds of the reward not emitted yet, so that all positions can still",
+				"collect the rewards emitted to them, rounded up. The withdrawn amount no longer funds emissions.",
+				"",
+				"### Authority",
+				'- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+				"reward-index in this Yevefi",
+				"",
+				"### Parameters",
+				"- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) to withdraw from.",
+				"- `amount` - The amount of reward tokens to withdraw.",
+				"",
+				"#### Special Errors",
+				"- `RewardSurplusInsufficient` - The amount exceeds the surplus of the reward vault.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "rewardAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "rewardMint";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "rewardVault";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "destinationTokenAccount";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "rewardTokenProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "rewardIndex";
+					type: "u8";
+				},
+				{
+					name: "amount";
+					type: "u64";
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "initializeExtensionReward";
+			docs: [
+				"Initialize an extension reward for a Yevefi with a reward extension.",
+				"",
+				"### Authority",
+				'- "reward_authority" - assigned authority of the extension reward at the specified reward-index.',
+				"",
+				"### Parameters",
+				"- `reward_index` - The extension reward index that we'd like to initialize. (0 <= index < NUM_EXTENSION_REWARDS)",
+				"",
+				"#### Special Errors",
+				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+				"index of the reward extension, or exceeds NUM_EXTENSION_REWARDS.",
+			];
+			accounts: [
+				{
+					name: "rewardAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "funder";
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: "yevefi";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "yevefiRewardExtension";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "rewardMint";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "rewardTokenBadge";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "rewardVault";
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: "rewardTokenProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "systemProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "rent";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "rewardIndex";
+					type: "u8";
+				},
+			];
+		},
+		{
+			name: "setExtensionRewardEmissions";
+			docs: [
+				"Set the emissions of an extension reward of a Yevefi.",
+				"",
+				"### Authority",
+				'- "reward_authority" - assigned authority of the extension reward at the specified reward-index.',
+				"",
+				"### Parameters",
+				"- `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS) that we'd like to modify.",
+				"- `emissions_per_second_x64` - The amount of rewards emitted in this pool.",
+				"",
+				"#### Special Errors",
+				"- `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit",
+				"more than a day of desired emissions.",
+				"- `InvalidTimestamp` - Provided timestamp is not in order with the previous timestamp.",
+				"- `InvalidRewardIndex` - If the provided reward index exceeds NUM_EXTENSION_REWARDS.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "yevefiRewardExtension";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "rewardAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "rewardVault";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "rewardIndex";
+					type: "u8";
+				},
+				{
+					name: "emissionsPerSecondX64";
+					type: "u128";
+				},
+			];
+		},
+		{
+			name: "collectExtensionReward";
+			docs: [
+				"Collect the extension rewards owed to a position.",
+				"",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"A PositionDelegate with the collect rewards permission can also authorize this call,",
+				"passed as a `PositionDelegate` remaining accounts slice.",
+				"",
+				"### Parameters",
+				"- `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS) to collect.",
+				"",
+				"#### Special Errors",
+				"- `PositionDelegateInvalidDestination` - A PositionDelegate transfers tokens to an account not owned by the position owner.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "yevefiRewardExtension";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "positionAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "position";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "positionTokenAccount";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "positionRewardExtension";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "rewardOwnerAccount";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "rewardMint";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "rewardVault";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "rewardTokenProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "rewardIndex";
+					type: "u8";
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "swapV2";
+			docs: [
+				"Perform a swap in this Yevefi",
+				"",
+				"### Authority",
+				'- "token_authority" - The authority to withdraw tokens from the input token account.',
+				"",
+				"### Parameters",
+				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
+				"- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
+				"- `sqrt_price_limit` - The maximum/minimum price the swap will swap to.",
+				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
+				"- `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.",
+				"",
+				"#### Special Errors",
+				"- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
+				"- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
+				"- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
+				"- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
+				"- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
+				"- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
+				"- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
+				"- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
+				"- `InvalidReferralAccounts` - The referral accounts are not the YevefisConfig of the pool and",
+				"a Referrer of it, followed by a writable token account of the",
+				"input mint owned by the referrer.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `InvalidRewardExtensionAccounts` - The Yevefi has a reward extension, and the RewardExtension",
+				"accounts are missing or do not cover the crossed ticks.",
+			];
+			accounts: [
+				{
+					name: "tokenProgramA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArray0";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArray1";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArray2";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "oracle";
+					isMut: true;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "amount";
+					type: "u64";
+				},
+				{
+					name: "otherAmountThreshold";
+					type: "u64";
+				},
+				{
+					name: "sqrtPriceLimit";
+					type: "u128";
+				},
+				{
+					name: "amountSpecifiedIsInput";
+					type: "bool";
+				},
+				{
+					name: "aToB";
+					type: "bool";
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "twoHopSwapV2";
+			docs: [
+				"Perform a two-hop swap in this Yevefi",
+				"",
+				"### Authority",
+				'- "token_authority" - The authority to withdraw tokens from the input token account.',
+				"",
+				"### Parameters",
+				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
+				"- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
+				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
+				"- `a_to_b_one` - The direction of the swap of hop one. True if swapping from A to B. False if swapping from B to A.",
+				"- `a_to_b_two` - The direction of the swap of hop two. True if swapping from A to B. False if swapping from B to A.",
+				"- `sqrt_price_limit_one` - The maximum/minimum price the swap will swap to in the first hop.",
+				"- `sqrt_price_limit_two` - The maximum/minimum price the swap will swap to in the second hop.",
+				"",
+				"#### Special Errors",
+				"- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
+				"- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
+				"- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
+				"- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
+				"- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
+				"- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
+				"- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
+				"- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
+				"- `InvalidIntermediaryMint` - Error if the intermediary mint between hop one and two do not equal.",
+				"- `DuplicateTwoHopPool` - Error if yevefi one & two are the same pool.",
+				"- `InvalidReferralAccounts` - The referral accounts are not the YevefisConfig shared by both",
+				"pools and a Referrer of it, followed by writable token accounts",
+				"of the input and intermediate mints owned by the referrer.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of one of the Yevefis is initialized.",
+			];
+			accounts: [
+				{
+					name: "yevefiOne";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "yevefiTwo";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintInput";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintIntermediate";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintOutput";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramInput";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramIntermediate";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramOutput";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountInput";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultOneInput";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultOneIntermediate";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultTwoIntermediate";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultTwoOutput";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountOutput";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "tickArrayOne0";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayOne1";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayOne2";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayTwo0";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayTwo1";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArrayTwo2";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "oracleOne";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "oracleTwo";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "amount";
+					type: "u64";
+				},
+				{
+					name: "otherAmountThreshold";
+					type: "u64";
+				},
+				{
+					name: "amountSpecifiedIsInput";
+					type: "bool";
+				},
+				{
+					name: "aToBOne";
+					type: "bool";
+				},
+				{
+					name: "aToBTwo";
+					type: "bool";
+				},
+				{
+					name: "sqrtPriceLimitOne";
+					type: "u128";
+				},
+				{
+					name: "sqrtPriceLimitTwo";
+					type: "u128";
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "quoteSwap";
+			docs: [
+				"Simulate a swap in this Yevefi without writing to any account.",
+				"The result is a `SwapQuote` set as return data, so the instruction can be used by other",
+				"programs via CPI to learn the outcome of a swap before executing it.",
+				"If referral accounts are supplied, the quote includes the referral fee of the swap.",
+				"",
+				"### Parameters",
+				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
+				"- `sqrt_price_limit` - The maximum/minimum price the swap will swap to.",
+				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
+				"- `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.",
+				"",
+				"#### Special Errors",
+				"Same as `swap_v2`, except that tick-arrays don't need to be writable.",
+			];
+			accounts: [
+				{
+					name: "yevefi";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tickArray0";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tickArray1";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tickArray2";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "yevefisConfigExtension";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "amount";
+					type: "u64";
+				},
+				{
+					name: "sqrtPriceLimit";
+					type: "u128";
+				},
+				{
+					name: "amountSpecifiedIsInput";
+					type: "bool";
+				},
+				{
+					name: "aToB";
+					type: "bool";
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "nHopSwapV2";
+			docs: [
+				"Perform a swap along a route of up to four Yevefis.",
+				"Intermediate tokens are transferred directly from vault to vault.",
+				"",
+				"### Authority",
+				'- "token_authority" - The authority to withdraw tokens from the input token account.',
+				"",
+				"### Parameters",
+				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
+				"- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
+				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
+				"- `route` - The direction and the maximum/minimum price of each hop, in route order.",
+				"",
+				"#### Special Errors",
+				"- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
+				"- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
+				"- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
+				"- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
+				"- `InvalidSwapRouteLength` - User provided parameter `route` is empty or has more than four hops.",
+				"- `InvalidIntermediaryMint` - Error if the output mint of a hop does not equal the input mint of the next hop.",
+				"- `DuplicateTwoHopPool` - Error if the same yevefi appears more than once in the route.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of one of the Yevefis is initialized.",
+			];
+			accounts: [
+				{
+					name: "tokenAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "tokenMintInput";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramInput";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountInput";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountOutput";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "amount";
+					type: "u64";
+				},
+				{
+					name: "otherAmountThreshold";
+					type: "u64";
+				},
+				{
+					name: "amountSpecifiedIsInput";
+					type: "bool";
+				},
+				{
+					name: "route";
+					type: {
+						vec: {
+							defined: "SwapRouteHop";
+						};
+					};
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "flashLoanV2";
+			docs: [
+				"Borrow tokens from the vaults of this Yevefi and repay them with a fee within the same instruction.",
+				"The borrowed tokens are transferred to the token owner accounts, then `yevefi_flash_loan_callback`",
+				"of the callback program is invoked, then the borrowed amounts plus fees are transferred back",
+				"from the token owner accounts.",
+				"",
+				"### Authority",
+				'- "token_authority" - The authority to withdraw the repayment from the token owner accounts.',
+				"",
+				"### Parameters",
+				"- `amount_a` - The amount of token A to borrow.",
+				"- `amount_b` - The amount of token B to borrow.",
+				"- `callback_data` - Arbitrary data passed through to the callback instruction.",
+				"",
+				"#### Special Errors",
+				"- `ZeroTradableAmount` - User provided parameters `amount_a` and `amount_b` are both 0.",
+				"- `YevefiLocked` - A flash loan or flash swap of this Yevefi is already in progress.",
+				"- `FlashNotRepaid` - The vaults hold less than before the loan plus fees after repayment.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+			];
+			accounts: [
+				{
+					name: "tokenProgramA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "callbackProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "yevefisConfigExtension";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "amountA";
+					type: "u64";
+				},
+				{
+					name: "amountB";
+					type: "u64";
+				},
+				{
+					name: "callbackData";
+					type: "bytes";
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "flashSwapV2";
+			docs: [
+				"Perform a swap in this Yevefi, receiving the output before paying the input.",
+				"The output is transferred to the token owner account, then `yevefi_flash_swap_callback`",
+				"of the callback program is invoked, then the input is transferred from the token owner account.",
+				"",
+				"### Authority",
+				'- "token_authority" - The authority to withdraw tokens from the input token account.',
+				"",
+				"### Parameters",
+				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
+				"- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
+				"- `sqrt_price_limit` - The maximum/minimum price the swap will swap to.",
+				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
+				"- `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.",
+				"- `callback_data` - Arbitrary data passed through to the callback instruction.",
+				"",
+				"#### Special Errors",
+				"- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
+				"- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
+				"- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
+				"- `YevefiLocked` - A flash loan or flash swap of this Yevefi is already in progress.",
+				"- `FlashNotRepaid` - The input vault did not receive the input amount of the swap.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.",
+			];
+			accounts: [
+				{
+					name: "tokenProgramA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "memoProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultA";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenOwnerAccountB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultB";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArray0";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArray1";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tickArray2";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "oracle";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "callbackProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "yevefisConfigExtension";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "amount";
+					type: "u64";
+				},
+				{
+					name: "otherAmountThreshold";
+					type: "u64";
+				},
+				{
+					name: "sqrtPriceLimit";
+					type: "u128";
+				},
+				{
+					name: "amountSpecifiedIsInput";
+					type: "bool";
+				},
+				{
+					name: "aToB";
+					type: "bool";
+				},
+				{
+					name: "callbackData";
+					type: "bytes";
+				},
+				{
+					name: "remainingAccountsInfo";
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo";
+						};
+					};
+				},
+			];
+		},
+		{
+			name: "initializePoolWithAdaptiveFeeV2";
+			docs: [
+				"Initializes a Yevefi account with adaptive fee enabled.",
+				"Tick spacing, base fee rate and adaptive fee constants are set from the supplied",
+				"adaptive_fee_tier, and the fee_tier_index is used as the tick_spacing seed of the Yevefi.",
+				"",
+				"### Parameters",
+				"- `initial_sqrt_price` - The desired initial sqrt-price for this pool",
+				"",
+				"#### Special Errors",
+				"`InvalidTokenMintOrder` - The order of mints have to be ordered by",
+				"`SqrtPriceOutOfBounds` - provided initial_sqrt_price is not between 2^-64 to 2^64",
+				"",
+			];
+			accounts: [
+				{
+					name: "yevefisConfig";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenMintB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenBadgeA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenBadgeB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "funder";
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "tokenVaultA";
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: "tokenVaultB";
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: "adaptiveFeeTier";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramA";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenProgramB";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "systemProgram";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "rent";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [
+				{
+					name: "initialSqrtPrice";
+					type: "u128";
+				},
+			];
+		},
+		{
+			name: "initializeConfigExtension";
+			accounts: [
+				{
+					name: "config";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "configExtension";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "funder";
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: "feeAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "systemProgram";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [];
+		},
+		{
+			name: "setConfigExtensionAuthority";
+			docs: [
+				"Proposes a new config extension authority, which takes over once it signs",
+				"`accept_config_extension_authority`.",
+				"Proposing the default Pubkey cancels a pending transfer.",
+			];
+			accounts: [
+				{
+					name: "yevefisConfig";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "yevefisConfigExtension";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "configExtensionAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "newConfigExtensionAuthority";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [];
+		},
+		{
+			name: "acceptConfigExtensionAuthority";
+			accounts: [
+				{
+					name: "yevefisConfig";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "yevefisConfigExtension";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "newConfigExtensionAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+			];
+			args: [];
+		},
+		{
+			name: "setTokenBadgeAuthority";
+			docs: [
+				"Proposes a new token badge authority, which takes over once it signs",
+				"`accept_token_badge_authority`.",
+				"Proposing the default Pubkey cancels a pending transfer.",
+			];
+			accounts: [
+				{
+					name: "yevefisConfig";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "yevefisConfigExtension";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "configExtensionAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "newTokenBadgeAuthority";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [];
+		},
+		{
+			name: "acceptTokenBadgeAuthority";
+			accounts: [
+				{
+					name: "yevefisConfig";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "yevefisConfigExtension";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "newTokenBadgeAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+			];
+			args: [];
+		},
+		{
+			name: "setPauseAuthority";
+			accounts: [
+				{
+					name: "yevefisConfig";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "yevefisConfigExtension";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "configExtensionAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "newPauseAuthority";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [];
+		},
+		{
+			name: "setPoolPaused";
+			docs: [
+				"Pause or unpause a Yevefi.",
+				"While paused, swaps, flash loans and liquidity increases are rejected,",
+				"but liquidity can still be decreased and fees and rewards collected.",
+				"",
+				"### Authority",
+				'- "pause_authority" - Set authority in the YevefisConfigExtension',
+				"",
+				"### Parameters",
+				"- `paused` - Whether the Yevefi is paused",
+			];
+			accounts: [
+				{
+					name: "yevefisConfig";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "yevefisConfigExtension";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "yevefi";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "pauseAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+			];
+			args: [
+				{
+					name: "paused";
+					type: "bool";
+				},
+			];
+		},
+		{
+			name: "setGlobalPaused";
+			docs: [
+				"Pause or unpause all Yevefis of a YevefisConfig.",
+				"While paused, swaps, flash loans and liquidity increases are rejected,",
+				"but liquidity can still be decreased and fees and rewards collected.",
+				"Instructions that check the pause require the YevefisConfigExtension PDA of the",
+				"YevefisConfig, initialized or not. v1 instructions take it as the first remaining account",
+				"and v2 instructions as a `ConfigExtension` remaining accounts slice.",
+				"",
+				"### Authority",
+				'- "pause_authority" - Set authority in the YevefisConfigExtension',
+				"",
+				"### Parameters",
+				"- `paused` - Whether the Yevefis of the config are paused",
+			];
+			accounts: [
+				{
+					name: "yevefisConfig";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "yevefisConfigExtension";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "pauseAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+			];
+			args: [
+				{
+					name: "paused";
+					type: "bool";
+				},
+			];
+		},
+		{
+			name: "initializeTokenBadge";
+			accounts: [
+				{
+					name: "yevefisConfig";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "yevefisConfigExtension";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenBadgeAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "tokenMint";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenBadge";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "funder";
+					isMut: true;
+					isSigner: true;
+				},
+				{
+					name: "systemProgram";
+					isMut: false;
+					isSigner: false;
+				},
+			];
+			args: [];
+		},
+		{
+			name: "deleteTokenBadge";
+			accounts: [
+				{
+					name: "yevefisConfig";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "yevefisConfigExtension";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenBadgeAuthority";
+					isMut: false;
+					isSigner: true;
+				},
+				{
+					name: "tokenMint";
+					isMut: false;
+					isSigner: false;
+				},
+				{
+					name: "tokenBadge";
+					isMut: true;
+					isSigner: false;
+				},
+				{
+					name: "receiver";
+					isMut: true;
+					isSigner: false;
+				},
+			];
+			args: [];
+		},
+	];
+	accounts: [
+		{
+			name: "adaptiveFeeTier";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "yevefisConfig";
+						type: "publicKey";
+					},
+					{
+						name: "feeTierIndex";
+						type: "u16";
+					},
+					{
+						name: "tickSpacing";
+						type: "u16";
+					},
+					{
+						name: "defaultBaseFeeRate";
+						type: "u16";
+					},
+					{
+						name: "adaptiveFeeConstants";
+						type: {
+							defined: "AdaptiveFeeConstants";
+						};
+					},
+				];
+			};
+		},
+		{
+			name: "feeTier";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "yevefisConfig";
+						type: "publicKey";
+					},
+					{
+						name: "tickSpacing";
+						type: "u16";
+					},
+					{
+						name: "defaultFeeRate";
+						type: "u16";
+					},
+				];
+			};
+		},
+		{
+			name: "oracle";
+			docs: [
+				"Header of the oracle account of a yevefi.",
+				"",
+				"The header is followed by `observation_cardinality_next` observations which are used",
+				"as a ring buffer. Only the first `observation_cardinality` slots are in use; the rest",
+				"have been paid for and are taken into use once the buffer wraps around.",
+			];
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "yevefi";
+						type: "publicKey";
+					},
+					{
+						name: "observationIndex";
+						type: "u16";
+					},
+					{
+						name: "observationCardinality";
+						type: "u16";
+					},
+					{
+						name: "observationCardinalityNext";
+						type: "u16";
+					},
+					{
+						name: "reserved";
+						type: {
+							array: ["u8", 64];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: "position";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "yevefi";
+						type: "publicKey";
+					},
+					{
+						name: "positionMint";
+						type: "publicKey";
+					},
+					{
+						name: "liquidity";
+						type: "u128";
+					},
+					{
+						name: "tickLowerIndex";
+						type: "i32";
+					},
+					{
+						name: "tickUpperIndex";
+						type: "i32";
+					},
+					{
+						name: "feeGrowthCheckpointA";
+						type: "u128";
+					},
+					{
+						name: "feeOwedA";
+						type: "u64";
+					},
+					{
+						name: "feeGrowthCheckpointB";
+						type: "u128";
+					},
+					{
+						name: "feeOwedB";
+						type: "u64";
+					},
+					{
+						name: "rewardInfos";
+						type: {
+							array: [
+								{
+									defined: "PositionRewardInfo";
+								},
+								3,
+							];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: "positionBundle";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "positionBundleMint";
+						type: "publicKey";
+					},
+					{
+						name: "positionBitmap";
+						type: {
+							array: ["u8", 32];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: "positionDelegate";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "position";
+						type: "publicKey";
+					},
+					{
+						name: "positionOwner";
+						type: "publicKey";
+					},
+					{
+						name: "delegate";
+						type: "publicKey";
+					},
+					{
+						name: "permissions";
+						type: "u8";
+					},
+				];
+			};
+		},
+		{
+			name: "positionLock";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "position";
+						type: "publicKey";
+					},
+					{
+						name: "unlockTimestamp";
+						type: "u64";
+					},
+				];
+			};
+		},
+		{
+			name: "positionRewardExtension";
+			docs: [
+				"Checkpoints and amounts owed of the extension rewards of a Position.",
+				"A position only earns extension rewards once its PositionRewardExtension is initialized.",
+			];
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "position";
+						type: "publicKey";
+					},
+					{
+						name: "rewardInfos";
+						type: {
+							array: [
+								{
+									defined: "PositionRewardInfo";
+								},
+								2,
+							];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: "referrer";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "yevefisConfig";
+						type: "publicKey";
+					},
+					{
+						name: "referrerAuthority";
+						type: "publicKey";
+					},
+				];
+			};
+		},
+		{
+			name: "tickArray";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "startTickIndex";
+						type: "i32";
+					},
+					{
+						name: "ticks";
+						type: {
+							array: [
+								{
+									defined: "Tick";
+								},
+								88,
+							];
+						};
+					},
+					{
+						name: "yevefi";
+						type: "publicKey";
+					},
+				];
+			};
+		},
+		{
+			name: "tickArrayRewardExtension";
+			docs: [
+				"Growth of the extension rewards outside of each tick of a TickArray.",
+				"",
+				"Unlike `Tick.reward_growths_outside`, the values are maintained for every tick of the array,",
+				"whether it is initialized or not, and are flipped whenever the current tick index moves across",
+				"the tick. This way ticks don't need to be updated when their liquidity is modified.",
+			];
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "yevefi";
+						type: "publicKey";
+					},
+					{
+						name: "startTickIndex";
+						type: "i32";
+					},
+					{
+						name: "rewardGrowthsOutside";
+						type: {
+							array: [
+								{
+									array: ["u128", 2];
+								},
+								88,
+							];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: "tokenBadge";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "yevefisConfig";
+						type: "publicKey";
+					},
+					{
+						name: "tokenMint";
+						type: "publicKey";
+					},
+				];
+			};
+		},
+		{
+			name: "yevefi";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "yevefisConfig";
+						type: "publicKey";
+					},
+					{
+						name: "yevefiBump";
+						type: {
+							array: ["u8", 1];
+						};
+					},
+					{
+						name: "tickSpacing";
+						type: "u16";
+					},
+					{
+						name: "tickSpacingSeed";
+						type: {
+							array: ["u8", 2];
+						};
+					},
+					{
+						name: "feeRate";
+						type: "u16";
+					},
+					{
+						name: "protocolFeeRate";
+						type: "u16";
+					},
+					{
+						name: "liquidity";
+						type: "u128";
+					},
+					{
+						name: "sqrtPrice";
+						type: "u128";
+					},
+					{
+						name: "tickCurrentIndex";
+						type: "i32";
+					},
+					{
+						name: "protocolFeeOwedA";
+						type: "u64";
+					},
+					{
+						name: "protocolFeeOwedB";
+						type: "u64";
+					},
+					{
+						name: "tokenMintA";
+						type: "publicKey";
+					},
+					{
+						name: "tokenVaultA";
+						type: "publicKey";
+					},
+					{
+						name: "feeGrowthGlobalA";
+						type: "u128";
+					},
+					{
+						name: "tokenMintB";
+						type: "publicKey";
+					},
+					{
+						name: "tokenVaultB";
+						type: "publicKey";
+					},
+					{
+						name: "feeGrowthGlobalB";
+						type: "u128";
+					},
+					{
+						name: "rewardLastUpdatedTimestamp";
+						type: "u64";
+					},
+					{
+						name: "rewardInfos";
+						type: {
+							array: [
+								{
+									defined: "YevefiRewardInfo";
+								},
+								3,
+							];
+						};
+					},
+					{
+						name: "reentrancyLock";
+						type: "bool";
+					},
+					{
+						name: "adaptiveFeeInfo";
+						type: {
+							defined: "AdaptiveFeeInfo";
+						};
+					},
+					{
+						name: "paused";
+						type: "bool";
+					},
+					{
+						name: "rewardSchedules";
+						type: {
+							array: [
+								{
+									defined: "YevefiRewardSchedule";
+								},
+								3,
+							];
+						};
+					},
+					{
+						name: "rewardExtensionInitialized";
+						type: "bool";
+					},
+					{
+						name: "rewardTotals";
+						type: {
+							array: [
+								{
+									defined: "YevefiRewardTotals";
+								},
+								3,
+							];
+						};
+					},
+					{
+						name: "pendingRewardAuthorities";
+						type: {
+							array: ["publicKey", 3];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: "yevefiRewardExtension";
+			docs: [
+				"Additional rewards of a Yevefi.",
+				"",
+				"Extension rewards accrue with the same math as `Yevefi.reward_infos`, but their growth outside",
+				"of each tick is stored in a `TickArrayRewardExtension`, and the rewards owed to a position are",
+				"stored in a `PositionRewardExtension`.",
+			];
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "yevefi";
+						type: "publicKey";
+					},
+					{
+						name: "rewardLastUpdatedTimestamp";
+						type: "u64";
+					},
+					{
+						name: "rewardInfos";
+						type: {
+							array: [
+								{
+									defined: "YevefiRewardInfo";
+								},
+								2,
+							];
+						};
+					},
+					{
+						name: "rewardTotals";
+						type: {
+							array: [
+								{
+									defined: "YevefiRewardTotals";
+								},
+								2,
+							];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: "yevefisConfig";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "feeAuthority";
+						type: "publicKey";
+					},
+					{
+						name: "collectProtocolFeesAuthority";
+						type: "publicKey";
+					},
+					{
+						name: "rewardEmissionsSuperAuthority";
+						type: "publicKey";
+					},
+					{
+						name: "defaultProtocolFeeRate";
+						type: "u16";
+					},
+					{
+						name: "referralFeeRate";
+						type: "u16";
+					},
+					{
+						name: "pendingFeeAuthority";
+						type: "publicKey";
+					},
+					{
+						name: "pendingCollectProtocolFeesAuthority";
+						type: "publicKey";
+					},
+					{
+						name: "pendingRewardEmissionsSuperAuthority";
+						type: "publicKey";
+					},
+				];
+			};
+		},
+		{
+			name: "yevefisConfigExtension";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "yevefisConfig";
+						type: "publicKey";
+					},
+					{
+						name: "configExtensionAuthority";
+						type: "publicKey";
+					},
+					{
+						name: "tokenBadgeAuthority";
+						type: "publicKey";
+					},
+					{
+						name: "pauseAuthority";
+						type: "publicKey";
+					},
+					{
+						name: "paused";
+						type: "bool";
+					},
+					{
+						name: "pendingConfigExtensionAuthority";
+						type: "publicKey";
+					},
+					{
+						name: "pendingTokenBadgeAuthority";
+						type: "publicKey";
+					},
+				];
+			};
+		},
+	];
+	types: [
+		{
+			name: "AccountsType";
+			type: {
+				kind: "enum";
+				variants: [
+					{
+						name: "TransferHookA";
+					},
+					{
+						name: "TransferHookB";
+					},
+					{
+						name: "TransferHookReward";
+					},
+					{
+						name: "TransferHookInput";
+					},
+					{
+						name: "TransferHookIntermediate";
+					},
+					{
+						name: "TransferHookOutput";
+					},
+					{
+						name: "SupplementalTickArrays";
+					},
+					{
+						name: "SupplementalTickArraysOne";
+					},
+					{
+						name: "SupplementalTickArraysTwo";
+					},
+					{
+						name: "TransferHookIntermediateTwo";
+					},
+					{
+						name: "TransferHookIntermediateThree";
+					},
+					{
+						name: "SupplementalTickArraysThree";
+					},
+					{
+						name: "SupplementalTickArraysFour";
+					},
+					{
+						name: "FlashCallback";
+					},
+					{
+						name: "Referral";
+					},
+					{
+						name: "RewardAccounts";
+					},
+					{
+						name: "TransferHookRewardOne";
+					},
+					{
+						name: "TransferHookRewardTwo";
+					},
+					{
+						name: "TransferHookRewardThree";
+					},
+					{
+						name: "BundledPositions";
+					},
+					{
+						name: "BundledPositionTickArrays";
+					},
+					{
+						name: "RewardExtension";
+					},
+					{
+						name: "PositionLock";
+					},
+					{
+						name: "PositionDelegate";
+					},
+					{
+						name: "ConfigExtension";
+					},
+					{
+						name: "Oracle";
+					},
+				];
+			};
+		},
+		{
+			name: "AdaptiveFeeConstants";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "filterPeriod";
+						type: "u16";
+					},
+					{
+						name: "decayPeriod";
+						type: "u16";
+					},
+					{
+						name: "reductionFactor";
+						type: "u16";
+					},
+					{
+						name: "adaptiveFeeControlFactor";
+						type: "u32";
+					},
+					{
+						name: "maxVolatilityAccumulator";
+						type: "u32";
+					},
+				];
+			};
+		},
+		{
+			name: "AdaptiveFeeInfo";
+			docs: [
+				"Adaptive fee state of a Yevefi.",
+				"The fee of a swap step is `Yevefi.fee_rate` (the base fee) plus a variable fee derived from",
+				"the volatility accumulator, capped at MAX_FEE_RATE.",
+			];
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "constants";
+						type: {
+							defined: "AdaptiveFeeConstants";
+						};
+					},
+					{
+						name: "variables";
+						type: {
+							defined: "AdaptiveFeeVariables";
+						};
+					},
+				];
+			};
+		},
+		{
+			name: "AdaptiveFeeVariables";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "lastReferenceUpdateTimestamp";
+						type: "u64";
+					},
+					{
+						name: "volatilityReference";
+						type: "u32";
+					},
+					{
+						name: "tickGroupIndexReference";
+						type: "i32";
+					},
+					{
+						name: "volatilityAccumulator";
+						type: "u32";
+					},
+				];
+			};
+		},
+		{
+			name: "OpenPositionBumps";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "positionBump";
+						type: "u8";
+					},
+				];
+			};
+		},
+		{
+			name: "OpenPositionWithMetadataBumps";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "positionBump";
+						type: "u8";
+					},
+					{
+						name: "metadataBump";
+						type: "u8";
+					},
+				];
+			};
+		},
+		{
+			name: "PositionRewardInfo";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "growthInsideCheckpoint";
+						type: "u128";
+					},
+					{
+						name: "amountOwed";
+						type: "u64";
+					},
+				];
+			};
+		},
+		{
+			name: "RemainingAccountsInfo";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "slices";
+						type: {
+							vec: {
+								defined: "RemainingAccountsSlice";
+							};
+						};
+					},
+				];
+			};
+		},
+		{
+			name: "RemainingAccountsSlice";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "accountsType";
+						type: {
+							defined: "AccountsType";
+						};
+					},
+					{
+						name: "length";
+						type: "u8";
+					},
+				];
+			};
+		},
+		{
+			name: "RewardEmissionsRateChange";
+			docs: ["A scheduled change of the emission rate of a reward."];
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "timestamp";
+						docs: [
+							"Timestamp from which the emission rate applies. 0 marks an unused entry.",
+						];
+						type: "u64";
+					},
+					{
+						name: "emissionsPerSecondX64";
+						docs: [
+							"Q64.64 number that indicates how many tokens per second are earned per unit of liquidity.",
+						];
+						type: "u128";
+					},
+				];
+			};
+		},
+		{
+			name: "SwapRouteHop";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "aToB";
+						type: "bool";
+					},
+					{
+						name: "sqrtPriceLimit";
+						type: "u128";
+					},
+				];
+			};
+		},
+		{
+			name: "Tick";
+			type: {
+				kind: "struct";
+				fields: [
 					{
-						name: "rewardEmissionsSuperAuthority";
+						name: "initialized";
+						type: "bool";
+					},
+					{
+						name: "liquidityNet";
+						type: "i128";
+					},
+					{
+						name: "liquidityGross";
+						type: "u128";
+					},
+					{
+						name: "feeGrowthOutsideA";
+						type: "u128";
+					},
+					{
+						name: "feeGrowthOutsideB";
+						type: "u128";
+					},
+					{
+						name: "rewardGrowthsOutside";
+						type: {
+							array: ["u128", 3];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: "YevefiBumps";
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "yevefiBump";
+						type: "u8";
+					},
+				];
+			};
+		},
+		{
+			name: "YevefiRewardInfo";
+			docs: [
+				"Stores the state relevant for tracking liquidity mining rewards at the `Yevefi` level.",
+				"These values are used in conjunction with `PositionRewardInfo`, `Tick.reward_growths_outside`,",
+				"and `Yevefi.reward_last_updated_timestamp` to determine how many rewards are earned by open",
+				"positions.",
+			];
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "mint";
+						docs: ["Reward token mint."];
 						type: "publicKey";
 					},
 					{
-						name: "defaultProtocolFeeRate";
-						type: "u16";
+						name: "vault";
+						docs: ["Reward vault token account."];
+						type: "publicKey";
+					},
+					{
+						name: "authority";
+						docs: [
+							"Authority account that has permission to initialize the reward and set emissions.",
+						];
+						type: "publicKey";
+					},
+					{
+						name: "emissionsPerSecondX64";
+						docs: [
+							"Q64.64 number that indicates how many tokens per second are earned per unit of liquidity.",
+						];
+						type: "u128";
+					},
+					{
+						name: "growthGlobalX64";
+						docs: [
+							"Q64.64 number that tracks the total tokens earned per unit of liquidity since the reward",
+							"emissions were turned on.",
+						];
+						type: "u128";
+					},
+				];
+			};
+		},
+		{
+			name: "YevefiRewardSchedule";
+			docs: [
+				"Emission schedule of a reward, so that emissions start, change and stop without sending",
+				"`set_reward_emissions` at the exact second.",
+				"",
+				"The emission rate at a timestamp is 0 before `start_timestamp` and from `end_timestamp` on.",
+				"In between it is `YevefiRewardInfo.emissions_per_second_x64`, replaced by each rate change",
+				"whose timestamp has been reached.",
+				"The default (all zero) schedule emits at `YevefiRewardInfo.emissions_per_second_x64` forever.",
+			];
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "startTimestamp";
+						docs: ["No rewards are emitted before this timestamp."];
+						type: "u64";
+					},
+					{
+						name: "endTimestamp";
+						docs: [
+							"No rewards are emitted from this timestamp on. 0 if the emissions have no end.",
+						];
+						type: "u64";
+					},
+					{
+						name: "rateChanges";
+						docs: [
+							"Rate changes in ascending timestamp order. Unused entries are at the end.",
+						];
+						type: {
+							array: [
+								{
+									defined: "RewardEmissionsRateChange";
+								},
+								4,
+							];
+						};
+					},
+				];
+			};
+		},
+		{
+			name: "YevefiRewardTotals";
+			docs: [
+				"Amounts of a reward funded, emitted to and collected by the positions of a `Yevefi`.",
+			];
+			type: {
+				kind: "struct";
+				fields: [
+					{
+						name: "emitted";
+						docs: [
+							"Upper bound of the rewards earned by positions, rounded up.",
+						];
+						type: "u64";
+					},
+					{
+						name: "collected";
+						docs: [
+							"Rewards transferred from the reward vault to positions by the instructions which write the",
+							"yevefi. `collect_reward` leaves the yevefi read-only, so its transfers are not included.",
+						];
+						type: "u64";
+					},
+					{
+						name: "funded";
+						docs: [
+							"Tokens deposited into the reward vault for emissions, net of withdrawn surplus.",
+							"Emissions stop once `emitted` reaches this amount.",
+						];
+						type: "u64";
+					},
+					{
+						name: "legacyOwed";
+						docs: [
+							"Reward vault amount when a yevefi created before the totals were recorded was migrated.",
+							"The rewards its positions had earned by then are unknown, so all of it is kept owed and",
+							"none of it is funded.",
+						];
+						type: "u64";
 					},
 				];
 			};
 		},
+	];
+	events: [
+		{
+			fields: [
+				{
+					index: false;
+					name: "yevefi";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "position";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "feeAAmount";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "feeBAmount";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "feeATransferFee";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "feeBTransferFee";
+					type: "u64";
+				},
+			];
+			name: "FeesCollected";
+		},
+		{
+			fields: [
+				{
+					index: false;
+					name: "yevefi";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "position";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "liquidity";
+					type: "u128";
+				},
+				{
+					index: false;
+					name: "feeAAmount";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "feeBAmount";
+					type: "u64";
+				},
+			];
+			name: "FeesCompounded";
+		},
+		{
+			fields: [
+				{
+					index: false;
+					name: "yevefi";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "amountA";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "amountB";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "feeA";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "feeB";
+					type: "u64";
+				},
+			];
+			name: "FlashLoaned";
+		},
+		{
+			fields: [
+				{
+					index: false;
+					name: "yevefi";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "position";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "tickLowerIndex";
+					type: "i32";
+				},
+				{
+					index: false;
+					name: "tickUpperIndex";
+					type: "i32";
+				},
+				{
+					index: false;
+					name: "liquidity";
+					type: "u128";
+				},
+				{
+					index: false;
+					name: "tokenAAmount";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "tokenBAmount";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "tokenATransferFee";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "tokenBTransferFee";
+					type: "u64";
+				},
+			];
+			name: "LiquidityDecreased";
+		},
+		{
+			fields: [
+				{
+					index: false;
+					name: "yevefi";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "position";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "tickLowerIndex";
+					type: "i32";
+				},
+				{
+					index: false;
+					name: "tickUpperIndex";
+					type: "i32";
+				},
+				{
+					index: false;
+					name: "liquidity";
+					type: "u128";
+				},
+				{
+					index: false;
+					name: "tokenAAmount";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "tokenBAmount";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "tokenATransferFee";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "tokenBTransferFee";
+					type: "u64";
+				},
+			];
+			name: "LiquidityIncreased";
+		},
+		{
+			fields: [
+				{
+					index: false;
+					name: "yevefi";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "position";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "oldTickLowerIndex";
+					type: "i32";
+				},
+				{
+					index: false;
+					name: "oldTickUpperIndex";
+					type: "i32";
+				},
+				{
+					index: false;
+					name: "oldLiquidity";
+					type: "u128";
+				},
+				{
+					index: false;
+					name: "newTickLowerIndex";
+					type: "i32";
+				},
+				{
+					index: false;
+					name: "newTickUpperIndex";
+					type: "i32";
+				},
+				{
+					index: false;
+					name: "newLiquidity";
+					type: "u128";
+				},
+				{
+					index: false;
+					name: "tokenADeposited";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "tokenBDeposited";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "tokenAWithdrawn";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "tokenBWithdrawn";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "tokenATransferFee";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "tokenBTransferFee";
+					type: "u64";
+				},
+			];
+			name: "LiquidityRepositioned";
+		},
+		{
+			fields: [
+				{
+					index: false;
+					name: "yevefi";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "yevefisConfig";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "tokenMintA";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "tokenMintB";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "tickSpacing";
+					type: "u16";
+				},
+				{
+					index: false;
+					name: "tokenProgramA";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "tokenProgramB";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "decimalsA";
+					type: "u8";
+				},
+				{
+					index: false;
+					name: "decimalsB";
+					type: "u8";
+				},
+				{
+					index: false;
+					name: "initialSqrtPrice";
+					type: "u128";
+				},
+			];
+			name: "PoolInitialized";
+		},
+		{
+			fields: [
+				{
+					index: false;
+					name: "yevefi";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "referralTokenAccount";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "tokenMint";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "amount";
+					type: "u64";
+				},
+			];
+			name: "ReferralFeePaid";
+		},
+		{
+			fields: [
+				{
+					index: false;
+					name: "yevefi";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "position";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "rewardIndex";
+					type: "u8";
+				},
+				{
+					index: false;
+					name: "rewardMint";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "amount";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "transferFee";
+					type: "u64";
+				},
+			];
+			name: "RewardCollected";
+		},
+		{
+			fields: [
+				{
+					index: false;
+					name: "yevefi";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "rewardIndex";
+					type: "u8";
+				},
+				{
+					index: false;
+					name: "rewardMint";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "funder";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "amount";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "fundedAmount";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "emissionsEndTimestamp";
+					type: "u64";
+				},
+			];
+			name: "RewardFunded";
+		},
+		{
+			fields: [
+				{
+					index: false;
+					name: "yevefi";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "rewardIndex";
+					type: "u8";
+				},
+				{
+					index: false;
+					name: "rewardMint";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "amount";
+					type: "u64";
+				},
+			];
+			name: "RewardSurplusWithdrawn";
+		},
+		{
+			fields: [
+				{
+					index: false;
+					name: "yevefi";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "aToB";
+					type: "bool";
+				},
+				{
+					index: false;
+					name: "preSqrtPrice";
+					type: "u128";
+				},
+				{
+					index: false;
+					name: "postSqrtPrice";
+					type: "u128";
+				},
+				{
+					index: false;
+					name: "preTickCurrentIndex";
+					type: "i32";
+				},
+				{
+					index: false;
+					name: "postTickCurrentIndex";
+					type: "i32";
+				},
+				{
+					index: false;
+					name: "inputAmount";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "outputAmount";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "inputTransferFee";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "outputTransferFee";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "lpFee";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "protocolFee";
+					type: "u64";
+				},
+			];
+			name: "Traded";
+		},
+		{
+			fields: [
+				{
+					index: false;
+					name: "yevefi";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "position";
+					type: "publicKey";
+				},
+				{
+					index: false;
+					name: "tickLowerIndex";
+					type: "i32";
+				},
+				{
+					index: false;
+					name: "tickUpperIndex";
+					type: "i32";
+				},
+				{
+					index: false;
+					name: "liquidity";
+					type: "u128";
+				},
+				{
+					index: false;
+					name: "tokenAAmount";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "tokenBAmount";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "feeAAmount";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "feeBAmount";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "aToB";
+					type: "bool";
+				},
+				{
+					index: false;
+					name: "amountOut";
+					type: "u64";
+				},
+				{
+					index: false;
+					name: "transferFee";
+					type: "u64";
+				},
+			];
+			name: "ZappedOut";
+		},
+	];
+	errors: [
+		{
+			code: 6000;
+			name: "InvalidEnum";
+			msg: "Enum value could not be converted";
+		},
+		{
+			code: 6001;
+			name: "InvalidStartTick";
+			msg: "Invalid start tick index provided.";
+		},
+		{
+			code: 6002;
+			name: "TickArrayExistInPool";
+			msg: "Tick-array already exists in this yevefi";
+		},
+		{
+			code: 6003;
+			name: "TickArrayIndexOutofBounds";
+			msg: "Attempt to search for a tick-array failed";
+		},
+		{
+			code: 6004;
+			name: "InvalidTickSpacing";
+			msg: "Tick-spacing is not supported";
+		},
+		{
+			code: 6005;
+			name: "ClosePositionNotEmpty";
+			msg: "Position is not empty It cannot be closed";
+		},
+		{
+			code: 6006;
+			name: "DivideByZero";
+			msg: "Unable to divide by zero";
+		},
+		{
+			code: 6007;
+			name: "NumberCastError";
+			msg: "Unable to cast number into BigInt";
+		},
+		{
+			code: 6008;
+			name: "NumberDownCastError";
+			msg: "Unable to down cast number";
+		},
+		{
+			code: 6009;
+			name: "TickNotFound";
+			msg: "Tick not found within tick array";
+		},
+		{
+			code: 6010;
+			name: "InvalidTickIndex";
+			msg: "Provided tick index is either out of bounds or uninitializable";
+		},
+		{
+			code: 6011;
+			name: "SqrtPriceOutOfBounds";
+			msg: "Provided sqrt price out of bounds";
+		},
+		{
+			code: 6012;
+			name: "LiquidityZero";
+			msg: "Liquidity amount must be greater than zero";
+		},
+		{
+			code: 6013;
+			name: "LiquidityTooHigh";
+			msg: "Liquidity amount must be less than i64::MAX";
+		},
+		{
+			code: 6014;
+			name: "LiquidityOverflow";
+			msg: "Liquidity overflow";
+		},
+		{
+			code: 6015;
+			name: "LiquidityUnderflow";
+			msg: "Liquidity underflow";
+		},
+		{
+			code: 6016;
+			name: "LiquidityNetError";
+			msg: "Tick liquidity net underflowed or overflowed";
+		},
+		{
+			code: 6017;
+			name: "TokenMaxExceeded";
+			msg: "Exceeded token max";
+		},
+		{
+			code: 6018;
+			name: "TokenMinSubceeded";
+			msg: "Did not meet token min";
+		},
+		{
+			code: 6019;
+			name: "MissingOrInvalidDelegate";
+			msg: "Position token account has a missing or invalid delegate";
+		},
+		{
+			code: 6020;
+			name: "InvalidPositionTokenAmount";
+			msg: "Position token amount must be 1";
+		},
+		{
+			code: 6021;
+			name: "InvalidTimestampConversion";
+			msg: "Timestamp should be convertible from i64 to u64";
+		},
+		{
+			code: 6022;
+			name: "InvalidTimestamp";
+			msg: "Timestamp should be greater than the last updated timestamp";
+		},
+		{
+			code: 6023;
+			name: "InvalidTickArraySequence";
+			msg: "Invalid tick array sequence provided for instruction.";
+		},
+		{
+			code: 6024;
+			name: "InvalidTokenMintOrder";
+			msg: "Token Mint in wrong order";
+		},
+		{
+			code: 6025;
+			name: "RewardNotInitialized";
+			msg: "Reward not initialized";
+		},
+		{
+			code: 6026;
+			name: "InvalidRewardIndex";
+			msg: "Invalid reward index";
+		},
+		{
+			code: 6027;
+			name: "RewardVaultAmountInsufficient";
+			msg: "Reward vault requires amount to support emissions for at least one day";
+		},
+		{
+			code: 6028;
+			name: "FeeRateMaxExceeded";
+			msg: "Exceeded max fee rate";
+		},
+		{
+			code: 6029;
+			name: "ProtocolFeeRateMaxExceeded";
+			msg: "Exceeded max protocol fee rate";
+		},
+		{
+			code: 6030;
+			name: "MultiplicationShiftRightOverflow";
+			msg: "Multiplication with shift right overflow";
+		},
+		{
+			code: 6031;
+			name: "MulDivOverflow";
+			msg: "Muldiv overflow";
+		},
+		{
+			code: 6032;
+			name: "MulDivInvalidInput";
+			msg: "Invalid div_u256 input";
+		},
+		{
+			code: 6033;
+			name: "MultiplicationOverflow";
+			msg: "Multiplication overflow";
+		},
+		{
+			code: 6034;
+			name: "InvalidSqrtPriceLimitDirection";
+			msg: "Provided SqrtPriceLimit not in the same direction as the swap.";
+		},
+		{
+			code: 6035;
+			name: "ZeroTradableAmount";
+			msg: "There are no tradable amount to swap.";
+		},
+		{
+			code: 6036;
+			name: "AmountOutBelowMinimum";
+			msg: "Amount out below minimum threshold";
+		},
+		{
+			code: 6037;
+			name: "AmountInAboveMaximum";
+			msg: "Amount in above maximum threshold";
+		},
+		{
+			code: 6038;
+			name: "TickArraySequenceInvalidIndex";
+			msg: "Invalid index for tick array sequence";
+		},
+		{
+			code: 6039;
+			name: "AmountCalcOverflow";
+			msg: "Amount calculated overflows";
+		},
+		{
+			code: 6040;
+			name: "AmountRemainingOverflow";
+			msg: "Amount remaining overflows";
+		},
+		{
+			code: 6041;
+			name: "InvalidIntermediaryMint";
+			msg: "Invalid intermediary mint";
+		},
+		{
+			code: 6042;
+			name: "DuplicateTwoHopPool";
+			msg: "Duplicate two hop pool";
+		},
+		{
+			code: 6043;
+			name: "InvalidBundleIndex";
+			msg: "Bundle index is out of bounds";
+		},
+		{
+			code: 6044;
+			name: "BundledPositionAlreadyOpened";
+			msg: "Position has already been opened";
+		},
 		{
-			name: "feeTier";
-			type: {
-				kind: "struct";
-				fields: [
-					{
-						name: "yevefisConfig";
-						type: "publicKey";
-					},
-					{
-						name: "tickSpacing";
-						type: "u16";
-					},
-					{
-						name: "defaultFeeRate";
-						type: "u16";
-					},
-				];
-			};
+			code: 6045;
+			name: "BundledPositionAlreadyClosed";
+			msg: "Position has already been closed";
 		},
 		{
-			name: "positionBundle";
-			type: {
-				kind: "struct";
-				fields: [
-					{
-						name: "positionBundleMint";
-						type: "publicKey";
-					},
-					{
-						name: "positionBitmap";
-						type: {
-							array: ["u8", 32];
-						};
-					},
-				];
-			};
+			code: 6046;
+			name: "PositionBundleNotDeletable";
+			msg: "Unable to delete PositionBundle with open positions";
 		},
 		{
-			name: "position";
-			type: {
-				kind: "struct";
-				fields: [
-					{
-						name: "yevefi";
-						type: "publicKey";
-					},
-					{
-						name: "positionMint";
-						type: "publicKey";
-					},
-					{
-						name: "liquidity";
-						type: "u128";
-					},
-					{
-						name: "tickLowerIndex";
-						type: "i32";
-					},
-					{
-						name: "tickUpperIndex";
-						type: "i32";
-					},
-					{
-						name: "feeGrowthCheckpointA";
-						type: "u128";
-					},
-					{
-						name: "feeOwedA";
-						type: "u64";
-					},
-					{
-						name: "feeGrowthCheckpointB";
-						type: "u128";
-					},
-					{
-						name: "feeOwedB";
-						type: "u64";
-					},
-					{
-						name: "rewardInfos";
-						type: {
-							array: [
-								{
-									defined: "PositionRewardInfo";
-								},
-								3,
-							];
-						};
-					},
-				];
-			};
+			code: 6047;
+			name: "UnsupportedTokenMint";
+			msg: "Token mint has unsupported attributes";
 		},
 		{
-			name: "tickArray";
-			type: {
-				kind: "struct";
-				fields: [
-					{
-						name: "startTickIndex";
-						type: "i32";
-					},
-					{
-						name: "ticks";
-						type: {
-							array: [
-								{
-									defined: "Tick";
-								},
-								88,
-							];
-						};
-					},
-					{
-						name: "yevefi";
-						type: "publicKey";
-					},
-				];
-			};
+			code: 6048;
+			name: "RemainingAccountsInvalidSlice";
+			msg: "Invalid remaining accounts";
 		},
 		{
-			name: "tokenBadge";
-			type: {
-				kind: "struct";
-				fields: [
-					{
-						name: "yevefisConfig";
-						type: "publicKey";
-					},
-					{
-						name: "tokenMint";
-						type: "publicKey";
-					},
-				];
-			};
+			code: 6049;
+			name: "RemainingAccountsInsufficient";
+			msg: "Insufficient remaining accounts";
 		},
 		{
-			name: "yevefi";
-			type: {
-				kind: "struct";
-				fields: [
-					{
-						name: "yevefisConfig";
-						type: "publicKey";
-					},
-					{
-						name: "yevefiBump";
-						type: {
-							array: ["u8", 1];
-						};
-					},
-					{
-						name: "tickSpacing";
-						type: "u16";
-					},
-					{
-						name: "tickSpacingSeed";
-						type: {
-							array: ["u8", 2];
-						};
-					},
-					{
-						name: "feeRate";
-						type: "u16";
-					},
-					{
-						name: "protocolFeeRate";
-						type: "u16";
-					},
-					{
-						name: "liquidity";
-						type: "u128";
-					},
-					{
-						name: "sqrtPrice";
-						type: "u128";
-					},
-					{
-						name: "tickCurrentIndex";
-						type: "i32";
-					},
-					{
-						name: "protocolFeeOwedA";
-						type: "u64";
-					},
-					{
-						name: "protocolFeeOwedB";
-						type: "u64";
-					},
-					{
-						name: "tokenMintA";
-						type: "publicKey";
-					},
-					{
-						name: "tokenVaultA";
-						type: "publicKey";
-					},
-					{
-						name: "feeGrowthGlobalA";
-						type: "u128";
-					},
-					{
-						name: "tokenMintB";
-						type: "publicKey";
-					},
-					{
-						name: "tokenVaultB";
-						type: "publicKey";
-					},
-					{
-						name: "feeGrowthGlobalB";
-						type: "u128";
-					},
-					{
-						name: "rewardLastUpdatedTimestamp";
-						type: "u64";
-					},
-					{
-						name: "rewardInfos";
-						type: {
-							array: [
-								{
-									defined: "YevefiRewardInfo";
-								},
-								3,
-							];
-						};
-					},
-				];
-			};
+			code: 6050;
+			name: "NoExtraAccountsForTransferHook";
+			msg: "Unable to call transfer hook without extra accounts";
+		},
+		{
+			code: 6051;
+			name: "IntermediateTokenAmountMismatch";
+			msg: "Output and input amount mismatch";
+		},
+		{
+			code: 6052;
+			name: "TransferFeeCalculationError";
+			msg: "Transfer fee calculation failed";
+		},
+		{
+			code: 6053;
+			name: "RemainingAccountsDuplicatedAccountsType";
+			msg: "Same accounts type is provided more than once";
+		},
+		{
+			code: 6054;
+			name: "FullRangeOnlyPool";
+			msg: "This yevefi only supports full-range positions";
+		},
+		{
+			code: 6055;
+			name: "TooManySupplementalTickArrays";
+			msg: "Too many supplemental tick arrays provided";
+		},
+		{
+			code: 6056;
+			name: "DifferentYevefiTickArrayAccount";
+			msg: "TickArray account for different yevefi provided";
+		},
+		{
+			code: 6057;
+			name: "PartialFillError";
+			msg: "Trade resulted in partial fill";
+		},
+		{
+			code: 6058;
+			name: "InvalidObservationCardinality";
+			msg: "Invalid observation cardinality";
+		},
+		{
+			code: 6059;
+			name: "ObservationTooOld";
+			msg: "Requested observation is older than the oldest observation";
+		},
+		{
+			code: 6060;
+			name: "TooManySecondsAgos";
+			msg: "Too many seconds_agos provided";
+		},
+		{
+			code: 6061;
+			name: "OracleNotWritable";
+			msg: "Initialized oracle must be writable";
+		},
+		{
+			code: 6062;
+			name: "InvalidSwapRouteLength";
+			msg: "Swap route must have between 1 and 4 hops";
+		},
+		{
+			code: 6063;
+			name: "YevefiLocked";
+			msg: "Yevefi is locked by an in-progress flash loan or flash swap";
+		},
+		{
+			code: 6064;
+			name: "FlashNotRepaid";
+			msg: "Flash loan or flash swap was not repaid";
+		},
+		{
+			code: 6065;
+			name: "InvalidAdaptiveFeeConstants";
+			msg: "Invalid adaptive fee constants";
+		},
+		{
+			code: 6066;
+			name: "InvalidFeeTierIndex";
+			msg: "Fee tier index must not be a tick spacing used by the config";
+		},
+		{
+			code: 6067;
+			name: "ReferralFeeRateMaxExceeded";
+			msg: "Referral fee rate exceeds MAX_REFERRAL_FEE_RATE";
+		},
+		{
+			code: 6068;
+			name: "InvalidReferralAccounts";
+			msg: "Invalid referral accounts";
+		},
+		{
+			code: 6069;
+			name: "YevefiPaused";
+			msg: "Yevefi is paused";
+		},
+		{
+			code: 6070;
+			name: "PositionLocked";
+			msg: "Position is locked";
+		},
+		{
+			code: 6071;
+			name: "InvalidUnlockTimestamp";
+			msg: "Unlock timestamp must be in the future";
+		},
+		{
+			code: 6072;
+			name: "PositionLiquidityNonZero";
+			msg: "Position must not have liquidity";
+		},
+		{
+			code: 6073;
+			name: "SameTickRangeNotAllowed";
+			msg: "New tick range must differ from the current tick range";
+		},
+		{
+			code: 6074;
+			name: "LiquidityBelowMinimum";
+			msg: "Liquidity is below the minimum";
+		},
+		{
+			code: 6075;
+			name: "InvalidRewardAccounts";
+			msg: "Invalid reward accounts";
+		},
+		{
+			code: 6076;
+			name: "InvalidBundledPositionAccounts";
+			msg: "Invalid bundled position accounts";
+		},
+		{
+			code: 6077;
+			name: "InvalidPositionDelegatePermissions";
+			msg: "Invalid position delegate permissions";
+		},
+		{
+			code: 6078;
+			name: "PositionDelegatePermissionDenied";
+			msg: "Position delegate does not have the required permissions";
+		},
+		{
+			code: 6079;
+			name: "PositionDelegateInvalidDestination";
+			msg: "Position delegate can only transfer tokens to the position owner";
+		},
+		{
+			code: 6080;
+			name: "InvalidPositionMetadataUpdateAuthority";
+			msg: "Position token metadata is not updatable by the position";
+		},
+		{
+			code: 6081;
+			name: "InvalidRewardEmissionsSchedule";
+			msg: "Invalid reward emissions schedule";
+		},
+		{
+			code: 6082;
+			name: "InvalidRewardExtensionAccounts";
+			msg: "Invalid reward extension accounts";
+		},
+		{
+			code: 6083;
+			name: "RewardExtensionNotSupported";
+			msg: "Instruction does not support yevefis with a reward extension";
+		},
+		{
+			code: 6084;
+			name: "RewardSurplusInsufficient";
+			msg: "Withdrawal exceeds the surplus of the reward vault";
+		},
+		{
+			code: 6085;
+			name: "RewardEmissionsNotExtendable";
+			msg: "Reward emissions have no end or have already ended";
+		},
+		{
+			code: 6086;
+			name: "InvalidPositionLock";
+			msg: "Position lock account is missing or invalid";
+		},
+		{
+			code: 6087;
+			name: "InvalidBundledPositionBump";
+			msg: "Bundled position bump does not derive the bundled position address";
+		},
+		{
+			code: 6088;
+			name: "InvalidConfigExtension";
+			msg: "Config extension account is missing or invalid";
+		},
+		{
+			code: 6089;
+			name: "InvalidOracle";
+			msg: "Oracle account is missing or invalid";
 		},
 	];
-	types: [
+};
+
+export const IDL: Yevefi = {
+	version: "0.2.0",
+	name: "yevefi",
+	instructions: [
 		{
-			name: "OpenPositionBumps";
-			type: {
-				kind: "struct";
-				fields: [
-					{
-						name: "positionBump";
-						type: "u8";
-					},
-				];
-			};
+			name: "initializeConfig",
+			docs: [
+				"Initializes a YevefisConfig account that hosts info & authorities",
+				"required to govern a set of Yevefis.",
+				"",
+				"### Parameters",
+				"- `fee_authority` - Authority authorized to initialize fee-tiers and set customs fees.",
+				"- `collect_protocol_fees_authority` - Authority authorized to collect protocol fees.",
+				"- `reward_emissions_super_authority` - Authority authorized to set reward authorities in pools.",
+			],
+			accounts: [
+				{
+					name: "config",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "feeAuthority",
+					type: "publicKey",
+				},
+				{
+					name: "collectProtocolFeesAuthority",
+					type: "publicKey",
+				},
+				{
+					name: "rewardEmissionsSuperAuthority",
+					type: "publicKey",
+				},
+				{
+					name: "defaultProtocolFeeRate",
+					type: "u16",
+				},
+			],
 		},
 		{
-			name: "OpenPositionWithMetadataBumps";
-			type: {
-				kind: "struct";
-				fields: [
-					{
-						name: "positionBump";
-						type: "u8";
-					},
-					{
-						name: "metadataBump";
-						type: "u8";
+			name: "initializePool",
+			docs: [
+				"Initializes a Yevefi account.",
+				"Fee rate is set to the default values on the config and supplied fee_tier.",
+				"",
+				"### Parameters",
+				"- `bumps` - The bump value when deriving the PDA of the Yevefi address.",
+				"- `tick_spacing` - The desired tick spacing for this pool.",
+				"- `initial_sqrt_price` - The desired initial sqrt-price for this pool",
+				"",
+				"#### Special Errors",
+				"`InvalidTokenMintOrder` - The order of mints have to be ordered by",
+				"`SqrtPriceOutOfBounds` - provided initial_sqrt_price is not between 2^-64 to 2^64",
+				"",
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenMintA",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenMintB",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "yevefi",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenVaultA",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "tokenVaultB",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "feeTier",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "rent",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "bumps",
+					type: {
+						defined: "YevefiBumps",
 					},
-				];
-			};
+				},
+				{
+					name: "tickSpacing",
+					type: "u16",
+				},
+				{
+					name: "initialSqrtPrice",
+					type: "u128",
+				},
+			],
+		},
+		{
+			name: "initializeTickArray",
+			docs: [
+				"Initializes a tick_array account to represent a tick-range in a Yevefi.",
+				"",
+				"### Parameters",
+				"- `start_tick_index` - The starting tick index for this tick-array.",
+				"Has to be a multiple of TickArray size & the tick spacing of this pool.",
+				"",
+				"#### Special Errors",
+				"- `InvalidStartTick` - if the provided start tick is out of bounds or is not a multiple of",
+				"TICK_ARRAY_SIZE * tick spacing.",
+			],
+			accounts: [
+				{
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "tickArray",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "startTickIndex",
+					type: "i32",
+				},
+			],
 		},
 		{
-			name: "PositionRewardInfo";
-			type: {
-				kind: "struct";
-				fields: [
-					{
-						name: "growthInsideCheckpoint";
-						type: "u128";
-					},
-					{
-						name: "amountOwed";
-						type: "u64";
-					},
-				];
-			};
+			name: "initializeFeeTier",
+			docs: [
+				"Initializes a fee_tier account usable by Yevefis in a YevefiConfig space.",
+				"",
+				"### Authority",
+				'- "fee_authority" - Set authority in the YevefiConfig',
+				"",
+				"### Parameters",
+				"- `tick_spacing` - The tick-spacing that this fee-tier suggests the default_fee_rate for.",
+				"- `default_fee_rate` - The default fee rate that a pool will use if the pool uses this",
+				"fee tier during initialization.",
+				"",
+				"#### Special Errors",
+				"- `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE.",
+			],
+			accounts: [
+				{
+					name: "config",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "feeTier",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "feeAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "tickSpacing",
+					type: "u16",
+				},
+				{
+					name: "defaultFeeRate",
+					type: "u16",
+				},
+			],
 		},
 		{
-			name: "Tick";
-			type: {
-				kind: "struct";
-				fields: [
-					{
-						name: "initialized";
-						type: "bool";
-					},
-					{
-						name: "liquidityNet";
-						type: "i128";
-					},
-					{
-						name: "liquidityGross";
-						type: "u128";
-					},
-					{
-						name: "feeGrowthOutsideA";
-						type: "u128";
-					},
-					{
-						name: "feeGrowthOutsideB";
-						type: "u128";
-					},
-					{
-						name: "rewardGrowthsOutside";
-						type: {
-							array: ["u128", 3];
-						};
-					},
-				];
-			};
+			name: "initializeReward",
+			docs: [
+				"Initialize reward for a Yevefi. A pool can only support up to a set number of rewards.",
+				"",
+				"### Authority",
+				'- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+				"reward-index in this Yevefi",
+				"",
+				"### Parameters",
+				"- `reward_index` - The reward index that we'd like to initialize. (0 <= index <= NUM_REWARDS)",
+				"",
+				"#### Special Errors",
+				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+				"index in this pool, or exceeds NUM_REWARDS, or",
+				"all reward slots for this pool has been initialized.",
+			],
+			accounts: [
+				{
+					name: "rewardAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "yevefi",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "rewardMint",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "rewardVault",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "tokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "rent",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "rewardIndex",
+					type: "u8",
+				},
+			],
 		},
 		{
-			name: "YevefiRewardInfo";
+			name: "setRewardEmissions",
 			docs: [
-				"Stores the state relevant for tracking liquidity mining rewards at the `Yevefi` level.",
-				"These values are used in conjunction with `PositionRewardInfo`, `Tick.reward_growths_outside`,",
-				"and `Yevefi.reward_last_updated_timestamp` to determine how many rewards are earned by open",
-				"positions.",
-			];
-			type: {
-				kind: "struct";
-				fields: [
-					{
-						name: "mint";
-						docs: ["Reward token mint."];
-						type: "publicKey";
-					},
-					{
-						name: "vault";
-						docs: ["Reward vault token account."];
-						type: "publicKey";
-					},
-					{
-						name: "authority";
-						docs: [
-							"Authority account that has permission to initialize the reward and set emissions.",
-						];
-						type: "publicKey";
-					},
-					{
-						name: "emissionsPerSecondX64";
-						docs: [
-							"Q64.64 number that indicates how many tokens per second are earned per unit of liquidity.",
-						];
-						type: "u128";
-					},
-					{
-						name: "growthGlobalX64";
-						docs: [
-							"Q64.64 number that tracks the total tokens earned per unit of liquidity since the reward",
-							"emissions were turned on.",
-						];
-						type: "u128";
-					},
-				];
-			};
+				"Set the reward emissions for a reward in a Yevefi.",
+				"The emission schedule of the reward, if any, is replaced by the constant emission rate.",
+				"The reward vault funds the emissions from now on, which stop once the funds run out.",
+				"",
+				"### Authority",
+				'- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+				"reward-index in this Yevefi",
+				"",
+				"### Parameters",
+				"- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.",
+				"- `emissions_per_second_x64` - The amount of rewards emitted in this pool.",
+				"",
+				"#### Special Errors",
+				"- `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit",
+				"more than a day of desired emissions.",
+				"- `InvalidTimestamp` - Provided timestamp is not in order with the previous timestamp.",
+				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+				"index in this pool, or exceeds NUM_REWARDS, or",
+				"all reward slots for this pool has been initialized.",
+			],
+			accounts: [
+				{
+					name: "yevefi",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "rewardAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "rewardVault",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "rewardIndex",
+					type: "u8",
+				},
+				{
+					name: "emissionsPerSecondX64",
+					type: "u128",
+				},
+			],
 		},
 		{
-			name: "YevefiBumps";
-			type: {
-				kind: "struct";
-				fields: [
-					{
-						name: "yevefiBump";
-						type: "u8";
+			name: "openPosition",
+			docs: [
+				"Open a position in a Yevefi. A unique token will be minted to represent the position",
+				"in the users wallet. The position will start off with 0 liquidity.",
+				"",
+				"### Parameters",
+				"- `tick_lower_index` - The tick specifying the lower end of the position range.",
+				"- `tick_upper_index` - The tick specifying the upper end of the position range.",
+				"",
+				"#### Special Errors",
+				"- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
+				"the tick-spacing in this pool.",
+			],
+			accounts: [
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "owner",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "position",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionMint",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "rent",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "associatedTokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "bumps",
+					type: {
+						defined: "OpenPositionBumps",
 					},
-				];
-			};
+				},
+				{
+					name: "tickLowerIndex",
+					type: "i32",
+				},
+				{
+					name: "tickUpperIndex",
+					type: "i32",
+				},
+			],
 		},
 		{
-			name: "RemainingAccountsSlice";
-			type: {
-				kind: "struct";
-				fields: [
-					{
-						name: "accountsType";
-						type: {
-							defined: "AccountsType";
-						};
-					},
-					{
-						name: "length";
-						type: "u8";
+			name: "openPositionWithMetadata",
+			docs: [
+				"Open a position in a Yevefi. A unique token will be minted to represent the position",
+				"in the users wallet. Additional Metaplex metadata is appended to identify the token.",
+				"The position will start off with 0 liquidity.",
+				"",
+				"### Parameters",
+				"- `tick_lower_index` - The tick specifying the lower end of the position range.",
+				"- `tick_upper_index` - The tick specifying the upper end of the position range.",
+				"",
+				"#### Special Errors",
+				"- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
+				"the tick-spacing in this pool.",
+			],
+			accounts: [
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "owner",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "position",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionMint",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "positionMetadataAccount",
+					isMut: true,
+					isSigner: false,
+					docs: [
+						"https://github.com/metaplex-foundation/metaplex-program-library/blob/master/token-metadata/program/src/utils.rs#L873",
+					],
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "rent",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "associatedTokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "metadataProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "metadataUpdateAuth",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "bumps",
+					type: {
+						defined: "OpenPositionWithMetadataBumps",
 					},
-				];
-			};
+				},
+				{
+					name: "tickLowerIndex",
+					type: "i32",
+				},
+				{
+					name: "tickUpperIndex",
+					type: "i32",
+				},
+			],
 		},
 		{
-			name: "RemainingAccountsInfo";
-			type: {
-				kind: "struct";
-				fields: [
-					{
-						name: "slices";
-						type: {
-							vec: {
-								defined: "RemainingAccountsSlice";
-							};
-						};
-					},
-				];
-			};
+			name: "increaseLiquidity",
+			docs: [
+				"Add liquidity to a position in the Yevefi. This call also updates the position's accrued fees and rewards.",
+				"",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"",
+				"### Parameters",
+				"- `liquidity_amount` - The total amount of Liquidity the user is willing to deposit.",
+				"- `token_max_a` - The maximum amount of tokenA the user is willing to deposit.",
+				"- `token_max_b` - The maximum amount of tokenB the user is willing to deposit.",
+				"",
+				"#### Special Errors",
+				"- `LiquidityZero` - Provided liquidity amount is zero.",
+				"- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
+				"- `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.",
+				"- `InvalidOracle` - The oracle account is missing or is not the oracle PDA of the Yevefi.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
+			],
+			accounts: [
+				{
+					name: "yevefi",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "position",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenOwnerAccountA",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenOwnerAccountB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenVaultA",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenVaultB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayLower",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayUpper",
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "liquidityAmount",
+					type: "u128",
+				},
+				{
+					name: "tokenMaxA",
+					type: "u64",
+				},
+				{
+					name: "tokenMaxB",
+					type: "u64",
+				},
+			],
 		},
 		{
-			name: "AccountsType";
-			type: {
-				kind: "enum";
-				variants: [
-					{
-						name: "TransferHookA";
-					},
-					{
-						name: "TransferHookB";
-					},
-					{
-						name: "TransferHookReward";
-					},
-					{
-						name: "TransferHookInput";
-					},
-					{
-						name: "TransferHookIntermediate";
-					},
-					{
-						name: "TransferHookOutput";
-					},
-				];
-			};
+			name: "decreaseLiquidity",
+			docs: [
+				"Withdraw liquidity from a position in the Yevefi. This call also updates the position's accrued fees and rewards.",
+				"",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"",
+				"### Parameters",
+				"- `liquidity_amount` - The total amount of Liquidity the user desires to withdraw.",
+				"- `token_min_a` - The minimum amount of tokenA the user is willing to withdraw.",
+				"- `token_min_b` - The minimum amount of tokenB the user is willing to withdraw.",
+				"",
+				"#### Special Errors",
+				"- `LiquidityZero` - Provided liquidity amount is zero.",
+				"- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
+				"- `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.",
+				"- `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.",
+				"- `InvalidPositionLock` - The PositionLock PDA of the position was not passed.",
+				"- `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.",
+				"- `InvalidOracle` - The oracle account is missing or is not the oracle PDA of the Yevefi.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
+			],
+			accounts: [
+				{
+					name: "yevefi",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "position",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenOwnerAccountA",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenOwnerAccountB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenVaultA",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenVaultB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayLower",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayUpper",
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "liquidityAmount",
+					type: "u128",
+				},
+				{
+					name: "tokenMinA",
+					type: "u64",
+				},
+				{
+					name: "tokenMinB",
+					type: "u64",
+				},
+			],
 		},
-	];
-	errors: [
 		{
-			code: 6000;
-			name: "InvalidEnum";
-			msg: "Enum value could not be converted";
+			name: "updateFeesAndRewards",
+			docs: [
+				"Update the accrued fees and rewards for a position.",
+				"",
+				"#### Special Errors",
+				"- `TickNotFound` - Provided tick array account does not contain the tick for this position.",
+				"- `LiquidityZero` - Position has zero liquidity and therefore already has the most updated fees and reward values.",
+			],
+			accounts: [
+				{
+					name: "yevefi",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "position",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayLower",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayUpper",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6001;
-			name: "InvalidStartTick";
-			msg: "Invalid start tick index provided.";
+			name: "collectFees",
+			docs: [
+				"Collect fees accrued for this position.",
+				"",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+			],
+			accounts: [
+				{
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "position",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenOwnerAccountA",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenVaultA",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenOwnerAccountB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenVaultB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6002;
-			name: "TickArrayExistInPool";
-			msg: "Tick-array already exists in this yevefi";
+			name: "compoundPositionFees",
+			docs: [
+				"Reinvest the fees accrued for this position as liquidity of the same position.",
+				"The maximum liquidity that the fees owed can fund at the current price is added,",
+				"and the remainder stays owed to the position. No tokens are transferred.",
+				"",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"",
+				"#### Special Errors",
+				"- `TickNotFound` - Provided tick array account does not contain the tick for this position.",
+				"- `LiquidityZero` - Position has zero liquidity and therefore accrues no fees.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
+			],
+			accounts: [
+				{
+					name: "yevefi",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "position",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayLower",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayUpper",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "oracle",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "yevefisConfigExtension",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6003;
-			name: "TickArrayIndexOutofBounds";
-			msg: "Attempt to search for a tick-array failed";
+			name: "collectReward",
+			docs: [
+				"Collect rewards accrued for this position.",
+				"",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+			],
+			accounts: [
+				{
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "position",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "rewardOwnerAccount",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "rewardVault",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "rewardIndex",
+					type: "u8",
+				},
+			],
 		},
 		{
-			code: 6004;
-			name: "InvalidTickSpacing";
-			msg: "Tick-spacing is not supported";
+			name: "collectProtocolFees",
+			docs: [
+				"Collect the protocol fees accrued in this Yevefi",
+				"",
+				"### Authority",
+				"- `collect_protocol_fees_authority` - assigned authority in the YevefiConfig that can collect protocol fees",
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "yevefi",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "collectProtocolFeesAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "tokenVaultA",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenVaultB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenDestinationA",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenDestinationB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6005;
-			name: "ClosePositionNotEmpty";
-			msg: "Position is not empty It cannot be closed";
+			name: "swap",
+			docs: [
+				"Perform a swap in this Yevefi",
+				"",
+				"### Authority",
+				'- "token_authority" - The authority to withdraw tokens from the input token account.',
+				"",
+				"### Parameters",
+				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
+				"- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
+				"- `sqrt_price_limit` - The maximum/minimum price the swap will swap to.",
+				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
+				"- `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.",
+				"",
+				"#### Special Errors",
+				"- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
+				"- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
+				"- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
+				"- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
+				"- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
+				"- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
+				"- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
+				"- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
+			],
+			accounts: [
+				{
+					name: "tokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "yevefi",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenOwnerAccountA",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenVaultA",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenOwnerAccountB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenVaultB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArray0",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArray1",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArray2",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "oracle",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "amount",
+					type: "u64",
+				},
+				{
+					name: "otherAmountThreshold",
+					type: "u64",
+				},
+				{
+					name: "sqrtPriceLimit",
+					type: "u128",
+				},
+				{
+					name: "amountSpecifiedIsInput",
+					type: "bool",
+				},
+				{
+					name: "aToB",
+					type: "bool",
+				},
+			],
 		},
 		{
-			code: 6006;
-			name: "DivideByZero";
-			msg: "Unable to divide by zero";
+			name: "closePosition",
+			docs: [
+				"Close a position in a Yevefi. Burns the position token in the owner's wallet.",
+				"The expired PositionLock of the position, if any, is closed as well.",
+				"",
+				"### Authority",
+				'- "position_authority" - The authority that owns the position token.',
+				"",
+				"#### Special Errors",
+				"- `ClosePositionNotEmpty` - The provided position account is not empty.",
+				"- `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.",
+				"- `InvalidPositionLock` - The PositionLock PDA of the position was not passed.",
+			],
+			accounts: [
+				{
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "receiver",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "position",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionMint",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6007;
-			name: "NumberCastError";
-			msg: "Unable to cast number into BigInt";
+			name: "setDefaultFeeRate",
+			docs: [
+				"Set the default_fee_rate for a FeeTier",
+				"Only the current fee authority has permission to invoke this instruction.",
+				"",
+				"### Authority",
+				'- "fee_authority" - Set authority in the YevefiConfig',
+				"",
+				"### Parameters",
+				"- `default_fee_rate` - The default fee rate that a pool will use if the pool uses this",
+				"fee tier during initialization.",
+				"",
+				"#### Special Errors",
+				"- `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE.",
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "feeTier",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "feeAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: "defaultFeeRate",
+					type: "u16",
+				},
+			],
 		},
 		{
-			code: 6008;
-			name: "NumberDownCastError";
-			msg: "Unable to down cast number";
+			name: "setDefaultProtocolFeeRate",
+			docs: [
+				"Sets the default protocol fee rate for a YevefiConfig",
+				"Protocol fee rate is represented as a basis point.",
+				"Only the current fee authority has permission to invoke this instruction.",
+				"",
+				"### Authority",
+				'- "fee_authority" - Set authority that can modify pool fees in the YevefiConfig',
+				"",
+				"### Parameters",
+				"- `default_protocol_fee_rate` - Rate that is referenced during the initialization of a Yevefi using this config.",
+				"",
+				"#### Special Errors",
+				"- `ProtocolFeeRateMaxExceeded` - If the provided default_protocol_fee_rate exceeds MAX_PROTOCOL_FEE_RATE.",
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "feeAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: "defaultProtocolFeeRate",
+					type: "u16",
+				},
+			],
 		},
 		{
-			code: 6009;
-			name: "TickNotFound";
-			msg: "Tick not found within tick array";
+			name: "setFeeRate",
+			docs: [
+				"Sets the fee rate for a Yevefi.",
+				"Fee rate is represented as hundredths of a basis point.",
+				"Only the current fee authority has permission to invoke this instruction.",
+				"",
+				"### Authority",
+				'- "fee_authority" - Set authority that can modify pool fees in the YevefiConfig',
+				"",
+				"### Parameters",
+				"- `fee_rate` - The rate that the pool will use to calculate fees going onwards.",
+				"",
+				"#### Special Errors",
+				"- `FeeRateMaxExceeded` - If the provided fee_rate exceeds MAX_FEE_RATE.",
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "yevefi",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "feeAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: "feeRate",
+					type: "u16",
+				},
+			],
 		},
 		{
-			code: 6010;
-			name: "InvalidTickIndex";
-			msg: "Provided tick index is either out of bounds or uninitializable";
+			name: "setProtocolFeeRate",
+			docs: [
+				"Sets the protocol fee rate for a Yevefi.",
+				"Protocol fee rate is represented as a basis point.",
+				"Only the current fee authority has permission to invoke this instruction.",
+				"",
+				"### Authority",
+				'- "fee_authority" - Set authority that can modify pool fees in the YevefiConfig',
+				"",
+				"### Parameters",
+				"- `protocol_fee_rate` - The rate that the pool will use to calculate protocol fees going onwards.",
+				"",
+				"#### Special Errors",
+				"- `ProtocolFeeRateMaxExceeded` - If the provided default_protocol_fee_rate exceeds MAX_PROTOCOL_FEE_RATE.",
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "yevefi",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "feeAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: "protocolFeeRate",
+					type: "u16",
+				},
+			],
 		},
 		{
-			code: 6011;
-			name: "SqrtPriceOutOfBounds";
-			msg: "Provided sqrt price out of bounds";
+			name: "setReferralFeeRate",
+			docs: [
+				"Sets the referral fee rate for a YevefiConfig.",
+				"Referral fee rate is represented as a basis point of the LP fee, and is paid to the",
+				"Referrer registered with `initialize_referrer` of swaps that supply referral accounts.",
+				"Only the current fee authority has permission to invoke this instruction.",
+				"",
+				"### Authority",
+				'- "fee_authority" - Set authority that can modify pool fees in the YevefiConfig',
+				"",
+				"### Parameters",
+				"- `referral_fee_rate` - The rate that swaps on Yevefis using this config will pay to referrers.",
+				"",
+				"#### Special Errors",
+				"- `ReferralFeeRateMaxExceeded` - If the provided referral_fee_rate exceeds MAX_REFERRAL_FEE_RATE.",
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "feeAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: "referralFeeRate",
+					type: "u16",
+				},
+			],
 		},
 		{
-			code: 6012;
-			name: "LiquidityZero";
-			msg: "Liquidity amount must be greater than zero";
+			name: "initializeReferrer",
+			docs: [
+				"Registers a referrer of a YevefiConfig. Swaps on Yevefis using this config can only pay",
+				"referral fees to token accounts owned by a registered referrer_authority.",
+				"Only the current fee authority has permission to invoke this instruction.",
+				"",
+				"### Authority",
+				'- "fee_authority" - Set authority that can modify pool fees in the YevefiConfig',
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "feeAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "referrerAuthority",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "referrer",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6013;
-			name: "LiquidityTooHigh";
-			msg: "Liquidity amount must be less than i64::MAX";
+			name: "deleteReferrer",
+			docs: [
+				"Removes a referrer of a YevefiConfig, so that it no longer receives referral fees.",
+				"Only the current fee authority has permission to invoke this instruction.",
+				"",
+				"### Authority",
+				'- "fee_authority" - Set authority that can modify pool fees in the YevefiConfig',
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "feeAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "referrer",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "receiver",
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6014;
-			name: "LiquidityOverflow";
-			msg: "Liquidity overflow";
+			name: "setFeeAuthority",
+			docs: [
+				"Proposes a new fee authority for a YevefiConfig, which takes over once it signs",
+				"`accept_fee_authority`. The fee authority can set the fee & protocol fee rate for",
+				"individual pools or set the default fee rate for newly minted pools.",
+				"Only the current fee authority has permission to invoke this instruction.",
+				"Proposing the default Pubkey cancels a pending transfer.",
+				"",
+				"### Authority",
+				'- "fee_authority" - Set authority that can modify pool fees in the YevefiConfig',
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "feeAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "newFeeAuthority",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6015;
-			name: "LiquidityUnderflow";
-			msg: "Liquidity underflow";
+			name: "acceptFeeAuthority",
+			docs: [
+				"Accepts the fee authority proposed by `set_fee_authority` for a YevefiConfig.",
+				"",
+				"### Authority",
+				'- "new_fee_authority" - The pending fee authority in the YevefiConfig',
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "newFeeAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6016;
-			name: "LiquidityNetError";
-			msg: "Tick liquidity net underflowed or overflowed";
+			name: "setCollectProtocolFeesAuthority",
+			docs: [
+				"Proposes a new authority to collect protocol fees for a YevefiConfig, which takes over once",
+				"it signs `accept_collect_protocol_fees_authority`.",
+				"Only the current collect protocol fee authority has permission to invoke this instruction.",
+				"Proposing the default Pubkey cancels a pending transfer.",
+				"",
+				"### Authority",
+				'- "fee_authority" - Set authority that can collect protocol fees in the YevefiConfig',
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "collectProtocolFeesAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "newCollectProtocolFeesAuthority",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6017;
-			name: "TokenMaxExceeded";
-			msg: "Exceeded token max";
+			name: "acceptCollectProtocolFeesAuthority",
+			docs: [
+				"Accepts the collect protocol fees authority proposed by `set_collect_protocol_fees_authority`",
+				"for a YevefiConfig.",
+				"",
+				"### Authority",
+				'- "new_collect_protocol_fees_authority" - The pending collect protocol fees authority in the YevefiConfig',
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "newCollectProtocolFeesAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6018;
-			name: "TokenMinSubceeded";
-			msg: "Did not meet token min";
+			name: "setRewardAuthority",
+			docs: [
+				"Propose a new yevefi reward authority at the provided `reward_index`, which takes over once",
+				"it signs `accept_reward_authority`.",
+				"Only the current reward authority for this reward index has permission to invoke this instruction.",
+				"Proposing the default Pubkey cancels a pending transfer.",
+				"",
+				"### Authority",
+				'- "reward_authority" - Set authority that can control reward emission for this particular reward.',
+				"",
+				"#### Special Errors",
+				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+				"index in this pool, or exceeds NUM_REWARDS, or",
+				"all reward slots for this pool has been initialized.",
+			],
+			accounts: [
+				{
+					name: "yevefi",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "rewardAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "newRewardAuthority",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "rewardIndex",
+					type: "u8",
+				},
+			],
 		},
 		{
-			code: 6019;
-			name: "MissingOrInvalidDelegate";
-			msg: "Position token account has a missing or invalid delegate";
+			name: "acceptRewardAuthority",
+			docs: [
+				"Accept the yevefi reward authority proposed by `set_reward_authority` at the provided `reward_index`.",
+				"",
+				"### Authority",
+				'- "new_reward_authority" - The pending reward authority for this particular reward.',
+				"",
+				"#### Special Errors",
+				"- `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.",
+			],
+			accounts: [
+				{
+					name: "yevefi",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "newRewardAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: "rewardIndex",
+					type: "u8",
+				},
+			],
 		},
 		{
-			code: 6020;
-			name: "InvalidPositionTokenAmount";
-			msg: "Position token amount must be 1";
+			name: "setRewardAuthorityBySuperAuthority",
+			docs: [
+				"Set the yevefi reward authority at the provided `reward_index`.",
+				"Only the current reward super authority has permission to invoke this instruction.",
+				"Any pending reward authority transfer is cancelled.",
+				"",
+				"### Authority",
+				'- "reward_authority" - Set authority that can control reward emission for this particular reward.',
+				"",
+				"#### Special Errors",
+				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+				"index in this pool, or exceeds NUM_REWARDS, or",
+				"all reward slots for this pool has been initialized.",
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "yevefi",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "rewardEmissionsSuperAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "newRewardAuthority",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "rewardIndex",
+					type: "u8",
+				},
+			],
 		},
 		{
-			code: 6021;
-			name: "InvalidTimestampConversion";
-			msg: "Timestamp should be convertible from i64 to u64";
+			name: "setRewardEmissionsSuperAuthority",
+			docs: [
+				"Propose a new yevefi reward super authority for a YevefiConfig, which takes over once it signs",
+				"`accept_reward_emissions_super_authority`.",
+				"Only the current reward super authority has permission to invoke this instruction.",
+				"Proposing the default Pubkey cancels a pending transfer.",
+				"This instruction will not change the authority on any `YevefiRewardInfo` yevefi rewards.",
+				"",
+				"### Authority",
+				'- "reward_emissions_super_authority" - Set authority that can control reward authorities for all pools in this config space.',
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "rewardEmissionsSuperAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "newRewardEmissionsSuperAuthority",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6022;
-			name: "InvalidTimestamp";
-			msg: "Timestamp should be greater than the last updated timestamp";
+			name: "acceptRewardEmissionsSuperAuthority",
+			docs: [
+				"Accept the yevefi reward super authority proposed by `set_reward_emissions_super_authority`",
+				"for a YevefiConfig.",
+				"",
+				"### Authority",
+				'- "new_reward_emissions_super_authority" - The pending reward super authority in the YevefiConfig',
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "newRewardEmissionsSuperAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6023;
-			name: "InvalidTickArraySequence";
-			msg: "Invalid tick array sequence provided for instruction.";
+			name: "twoHopSwap",
+			docs: [
+				"Perform a two-hop swap in this Yevefi",
+				"",
+				"### Authority",
+				'- "token_authority" - The authority to withdraw tokens from the input token account.',
+				"",
+				"### Parameters",
+				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
+				"- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
+				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
+				"- `a_to_b_one` - The direction of the swap of hop one. True if swapping from A to B. False if swapping from B to A.",
+				"- `a_to_b_two` - The direction of the swap of hop two. True if swapping from A to B. False if swapping from B to A.",
+				"- `sqrt_price_limit_one` - The maximum/minimum price the swap will swap to in the first hop.",
+				"- `sqrt_price_limit_two` - The maximum/minimum price the swap will swap to in the second hop.",
+				"",
+				"#### Special Errors",
+				"- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
+				"- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
+				"- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
+				"- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
+				"- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
+				"- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
+				"- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
+				"- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
+				"- `InvalidIntermediaryMint` - Error if the intermediary mint between hop one and two do not equal.",
+				"- `DuplicateTwoHopPool` - Error if yevefi one & two are the same pool.",
+				"- `YevefiPaused` - One of the Yevefis, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - A config extension account is missing or is not the config extension PDA of its Yevefi's YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of one of the Yevefis is initialized.",
+				"- `OracleNotWritable` - The oracle of one of the Yevefis is initialized but was passed as read-only.",
+			],
+			accounts: [
+				{
+					name: "tokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "yevefiOne",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "yevefiTwo",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenOwnerAccountOneA",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenVaultOneA",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenOwnerAccountOneB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenVaultOneB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenOwnerAccountTwoA",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenVaultTwoA",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenOwnerAccountTwoB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenVaultTwoB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayOne0",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayOne1",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayOne2",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayTwo0",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayTwo1",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayTwo2",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "oracleOne",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "oracleTwo",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "amount",
+					type: "u64",
+				},
+				{
+					name: "otherAmountThreshold",
+					type: "u64",
+				},
+				{
+					name: "amountSpecifiedIsInput",
+					type: "bool",
+				},
+				{
+					name: "aToBOne",
+					type: "bool",
+				},
+				{
+					name: "aToBTwo",
+					type: "bool",
+				},
+				{
+					name: "sqrtPriceLimitOne",
+					type: "u128",
+				},
+				{
+					name: "sqrtPriceLimitTwo",
+					type: "u128",
+				},
+			],
 		},
 		{
-			code: 6024;
-			name: "InvalidTokenMintOrder";
-			msg: "Token Mint in wrong order";
+			name: "initializePositionBundle",
+			docs: [
+				"Initializes a PositionBundle account that bundles several positions.",
+				"A unique token will be minted to represent the position bundle in the users wallet.",
+			],
+			accounts: [
+				{
+					name: "positionBundle",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionBundleMint",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "positionBundleTokenAccount",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionBundleOwner",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "tokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "rent",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "associatedTokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6025;
-			name: "RewardNotInitialized";
-			msg: "Reward not initialized";
+			name: "initializePositionBundleWithMetadata",
+			docs: [
+				"Initializes a PositionBundle account that bundles several positions.",
+				"A unique token will be minted to represent the position bundle in the users wallet.",
+				"Additional Metaplex metadata is appended to identify the token.",
+			],
+			accounts: [
+				{
+					name: "positionBundle",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionBundleMint",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "positionBundleMetadata",
+					isMut: true,
+					isSigner: false,
+					docs: [
+						"https://github.com/metaplex-foundation/metaplex-program-library/blob/773a574c4b34e5b9f248a81306ec24db064e255f/token-metadata/program/src/utils/metadata.rs#L100",
+					],
+				},
+				{
+					name: "positionBundleTokenAccount",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionBundleOwner",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "metadataUpdateAuth",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "rent",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "associatedTokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "metadataProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6026;
-			name: "InvalidRewardIndex";
-			msg: "Invalid reward index";
+			name: "deletePositionBundle",
+			docs: [
+				"Delete a PositionBundle account. Burns the position bundle token in the owner's wallet.",
+				"",
+				"### Authority",
+				"- `position_bundle_owner` - The owner that owns the position bundle token.",
+				"",
+				"### Special Errors",
+				"- `PositionBundleNotDeletable` - The provided position bundle has open positions.",
+			],
+			accounts: [
+				{
+					name: "positionBundle",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionBundleMint",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionBundleTokenAccount",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionBundleOwner",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "receiver",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6027;
-			name: "RewardVaultAmountInsufficient";
-			msg: "Reward vault requires amount to support emissions for at least one day";
+			name: "initializePositionBundleWithTokenExtensions",
+			docs: [
+				"Initializes a PositionBundle account that bundles several positions.",
+				"A unique token will be minted to represent the position bundle in the users wallet.",
+				"Mint and TokenAccount are based on Token-2022, and the PositionBundle account is the",
+				"close authority of the mint, so that the rent of the mint can be reclaimed on delete.",
+				"",
+				"### Parameters",
+				"- `with_token_metadata_extension` - If true, the token metadata extension will be initialized.",
+			],
+			accounts: [
+				{
+					name: "positionBundle",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionBundleMint",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "positionBundleTokenAccount",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionBundleOwner",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "token2022Program",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "associatedTokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "withTokenMetadataExtension",
+					type: "bool",
+				},
+			],
 		},
 		{
-			code: 6028;
-			name: "FeeRateMaxExceeded";
-			msg: "Exceeded max fee rate";
+			name: "deletePositionBundleWithTokenExtensions",
+			docs: [
+				"Delete a PositionBundle account initialized with Token-2022.",
+				"Burns the position bundle token in the owner's wallet and closes the mint.",
+				"",
+				"### Authority",
+				"- `position_bundle_owner` - The owner that owns the position bundle token.",
+				"",
+				"### Special Errors",
+				"- `PositionBundleNotDeletable` - The provided position bundle has open positions.",
+			],
+			accounts: [
+				{
+					name: "positionBundle",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionBundleMint",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionBundleTokenAccount",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionBundleOwner",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "receiver",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "token2022Program",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6029;
-			name: "ProtocolFeeRateMaxExceeded";
-			msg: "Exceeded max protocol fee rate";
+			name: "openBundledPosition",
+			docs: [
+				"Open a bundled position in a Yevefi. No new tokens are issued",
+				"because the owner of the position bundle becomes the owner of the position.",
+				"The position will start off with 0 liquidity.",
+				"",
+				"### Authority",
+				"- `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.",
+				"",
+				"### Parameters",
+				"- `bundle_index` - The bundle index that we'd like to open.",
+				"- `tick_lower_index` - The tick specifying the lower end of the position range.",
+				"- `tick_upper_index` - The tick specifying the upper end of the position range.",
+				"",
+				"#### Special Errors",
+				"- `InvalidBundleIndex` - If the provided bundle index is out of bounds.",
+				"- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
+				"the tick-spacing in this pool.",
+			],
+			accounts: [
+				{
+					name: "bundledPosition",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionBundle",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionBundleTokenAccount",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionBundleAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "rent",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "bundleIndex",
+					type: "u16",
+				},
+				{
+					name: "tickLowerIndex",
+					type: "i32",
+				},
+				{
+					name: "tickUpperIndex",
+					type: "i32",
+				},
+			],
 		},
 		{
-			code: 6030;
-			name: "MultiplicationShiftRightOverflow";
-			msg: "Multiplication with shift right overflow";
+			name: "closeBundledPosition",
+			docs: [
+				"Close a bundled position in a Yevefi.",
+				"The expired PositionLock of the position, if any, is closed as well.",
+				"",
+				"### Authority",
+				"- `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.",
+				"",
+				"### Parameters",
+				"- `bundle_index` - The bundle index that we'd like to close.",
+				"",
+				"#### Special Errors",
+				"- `InvalidBundleIndex` - If the provided bundle index is out of bounds.",
+				"- `ClosePositionNotEmpty` - The provided position account is not empty.",
+				"- `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.",
+				"- `InvalidPositionLock` - The PositionLock PDA of the position was not passed.",
+			],
+			accounts: [
+				{
+					name: "bundledPosition",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionBundle",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionBundleTokenAccount",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionBundleAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "receiver",
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "bundleIndex",
+					type: "u16",
+				},
+			],
 		},
 		{
-			code: 6031;
-			name: "MulDivOverflow";
-			msg: "Muldiv overflow";
+			name: "openPositionWithTokenExtensions",
+			docs: [
+				"Open a position in a Yevefi. A unique token will be minted to represent the position",
+				"in the users wallet. Additional TokenMetadata extension is initialized to identify the token.",
+				"The TokenMetadata includes the pool address, tick range, liquidity and in-range flag of the",
+				"position as additional fields, and the position account is its update authority.",
+				"Mint and TokenAccount are based on Token-2022.",
+				"The position will start off with 0 liquidity.",
+				"",
+				"### Parameters",
+				"- `tick_lower_index` - The tick specifying the lower end of the position range.",
+				"- `tick_upper_index` - The tick specifying the upper end of the position range.",
+				"- `with_token_metadata_extension` - If true, the token metadata extension will be initialized.",
+				"",
+				"#### Special Errors",
+				"- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
+				"the tick-spacing in this pool.",
+			],
+			accounts: [
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "owner",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "position",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionMint",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "token2022Program",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "associatedTokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "tickLowerIndex",
+					type: "i32",
+				},
+				{
+					name: "tickUpperIndex",
+					type: "i32",
+				},
+				{
+					name: "withTokenMetadataExtension",
+					type: "bool",
+				},
+			],
 		},
 		{
-			code: 6032;
-			name: "MulDivInvalidInput";
-			msg: "Invalid div_u256 input";
+			name: "refreshPositionMetadata",
+			docs: [
+				"Refresh the additional TokenMetadata fields of a position opened with token extensions,",
+				"so that they reflect the current tick range, liquidity and in-range state of the position.",
+				"Anyone can refresh a position. The funder pays the rent if the fields grow.",
+				"",
+				"#### Special Errors",
+				"- `InvalidPositionMetadataUpdateAuthority` - The position account is not the update authority",
+				"of the TokenMetadata.",
+			],
+			accounts: [
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "position",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionMint",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "token2022Program",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6033;
-			name: "MultiplicationOverflow";
-			msg: "Multiplication overflow";
+			name: "closePositionWithTokenExtensions",
+			docs: [
+				"Close a position in a Yevefi. Burns the position token in the owner's wallet.",
+				"Mint and TokenAccount are based on Token-2022. And Mint accout will be also closed.",
+				"The expired PositionLock of the position, if any, is closed as well.",
+				"",
+				"### Authority",
+				'- "position_authority" - The authority that owns the position token.',
+				"",
+				"#### Special Errors",
+				"- `ClosePositionNotEmpty` - The provided position account is not empty.",
+				"- `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.",
+				"- `InvalidPositionLock` - The PositionLock PDA of the position was not passed.",
+			],
+			accounts: [
+				{
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "receiver",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "position",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionMint",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "token2022Program",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6034;
-			name: "InvalidSqrtPriceLimitDirection";
-			msg: "Provided SqrtPriceLimit not in the same direction as the swap.";
+			name: "lockPosition",
+			docs: [
+				"Lock the liquidity of a position until the unlock timestamp.",
+				"While locked, liquidity cannot be decreased and the position cannot be closed,",
+				"but fees and rewards can still be collected. A position can only be locked once,",
+				"the lock can be extended with `extend_position_lock`.",
+				"Bundled positions are locked by the holder of the position bundle token.",
+				"Instructions that check the lock require the PositionLock PDA of every position, locked",
+				"or not. v1 instructions take it as the first remaining account and v2 instructions as a",
+				"`PositionLock` remaining accounts slice.",
+				"",
+				"### Authority",
+				'- "position_authority" - The authority that owns the position token.',
+				"",
+				"### Parameters",
+				"- `unlock_timestamp` - The unix timestamp from which the position is unlocked.",
+				"",
+				"#### Special Errors",
+				"- `InvalidUnlockTimestamp` - The unlock timestamp is not in the future.",
+			],
+			accounts: [
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "position",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionLock",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "unlockTimestamp",
+					type: "u64",
+				},
+			],
 		},
 		{
-			code: 6035;
-			name: "ZeroTradableAmount";
-			msg: "There are no tradable amount to swap.";
+			name: "extendPositionLock",
+			docs: [
+				"Move the unlock timestamp of a position lock later.",
+				"An expired lock can be extended to lock the position again.",
+				"",
+				"### Authority",
+				'- "position_authority" - The authority that owns the position token.',
+				"",
+				"### Parameters",
+				"- `unlock_timestamp` - The unix timestamp from which the position is unlocked.",
+				"",
+				"#### Special Errors",
+				"- `InvalidUnlockTimestamp` - The unlock timestamp is not in the future or not later than",
+				"the current unlock timestamp.",
+			],
+			accounts: [
+				{
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "position",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionLock",
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "unlockTimestamp",
+					type: "u64",
+				},
+			],
 		},
 		{
-			code: 6036;
-			name: "AmountOutBelowMinimum";
-			msg: "Amount out below minimum threshold";
+			name: "closePositionLock",
+			docs: [
+				"Close an expired position lock of a position that stays open.",
+				"Closing the position closes its lock as well.",
+				"The lock marker is removed from the position and its rent returned to the receiver.",
+				"",
+				"### Authority",
+				'- "position_authority" - The authority that owns the position token.',
+				"",
+				"#### Special Errors",
+				"- `PositionLocked` - The position is locked until its unlock timestamp.",
+			],
+			accounts: [
+				{
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "receiver",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "position",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionLock",
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6037;
-			name: "AmountInAboveMaximum";
-			msg: "Amount in above maximum threshold";
+			name: "initializePositionDelegate",
+			docs: [
+				"Grant a delegate a set of permissions on a position, without transferring the position token.",
+				"The delegate can collect fees, collect rewards, increase liquidity or decrease liquidity",
+				"through the v2 instructions as permitted. Tokens withdrawn by the delegate can only be",
+				"transferred to the position owner. The permissions are void once the position token is",
+				"held by another owner.",
+				"",
+				"### Authority",
+				'- "position_authority" - The authority that owns the position token.',
+				"",
+				"### Parameters",
+				"- `permissions` - Bitmask of POSITION_DELEGATE_PERMISSION_* flags.",
+				"",
+				"#### Special Errors",
+				"- `InvalidPositionDelegatePermissions` - The permissions are empty or contain unknown flags.",
+			],
+			accounts: [
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "position",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "delegate",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionDelegate",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "permissions",
+					type: "u8",
+				},
+			],
 		},
 		{
-			code: 6038;
-			name: "TickArraySequenceInvalidIndex";
-			msg: "Invalid index for tick array sequence";
+			name: "setPositionDelegatePermissions",
+			docs: [
+				"Replace the permissions of a delegate on a position.",
+				"",
+				"### Authority",
+				'- "position_authority" - The authority that owns the position token.',
+				"",
+				"### Parameters",
+				"- `permissions` - Bitmask of POSITION_DELEGATE_PERMISSION_* flags.",
+				"",
+				"#### Special Errors",
+				"- `InvalidPositionDelegatePermissions` - The permissions are empty or contain unknown flags.",
+			],
+			accounts: [
+				{
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "position",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionDelegate",
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "permissions",
+					type: "u8",
+				},
+			],
 		},
 		{
-			code: 6039;
-			name: "AmountCalcOverflow";
-			msg: "Amount calculated overflows";
+			name: "closePositionDelegate",
+			docs: [
+				"Revoke a delegate of a position and close its PositionDelegate account.",
+				"",
+				"### Authority",
+				'- "position_authority" - The authority that owns the position token.',
+			],
+			accounts: [
+				{
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "receiver",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "position",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionDelegate",
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6040;
-			name: "AmountRemainingOverflow";
-			msg: "Amount remaining overflows";
+			name: "migrateYevefis",
+			docs: [
+				"Migrates the Yevefis created before fields were appended to their layout, reallocating",
+				"them to the current size. The Yevefis are passed as remaining accounts, so that many",
+				"pools can be migrated in one transaction. Yevefis which already have the current layout",
+				"are skipped. The appended fields are zeroed, so the features they back start out disabled.",
+				"A Yevefi must be migrated before any other instruction can use it.",
+				"The funder pays the rent for the additional space.",
+				"",
+				"Each Yevefi is followed by the reward vaults of its initialized rewards. The rewards earned",
+				"by positions before the migration are unknown, so the reward vault amounts are kept owed",
+				"to the positions and cannot be withdrawn with `withdraw_reward_surplus`. For the same",
+				"reason they do not fund emissions, which continue once tokens are added to the vaults.",
+				"",
+				"#### Special Errors",
+				"- `InvalidRewardAccounts` - A reward vault is missing or does not match its reward.",
+			],
+			accounts: [
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6041;
-			name: "InvalidIntermediaryMint";
-			msg: "Invalid intermediary mint";
+			name: "migrateYevefisConfig",
+			docs: [
+				"Migrates a YevefisConfig created before the pending authorities were appended to its",
+				"layout, reallocating it to the current size. The appended fields are zeroed, so no",
+				"authority transfer is pending. A YevefisConfig which already has the current layout is",
+				"left unchanged. It must be migrated before any other instruction can use it.",
+				"The funder pays the rent for the additional space.",
+				"",
+				"### Authority",
+				'- "fee_authority" - Set authority in the YevefisConfig',
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "feeAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6042;
-			name: "DuplicateTwoHopPool";
-			msg: "Duplicate two hop pool";
+			name: "initializeOracle",
+			docs: [
+				"Initializes the oracle account of a Yevefi with a single observation slot.",
+				"Once initialized, every swap and liquidity change records the prior state of the pool into",
+				"the oracle, at most once per timestamp. The first observation is written on the next one.",
+				"The oracle must then be passed as writable: v1 `swap` and `two_hop_swap` clients which pass",
+				"it as read-only are rejected. Liquidity instructions without an oracle account require the",
+				"oracle PDA, initialized or not. v1 instructions take it as the second remaining account and",
+				"v2 instructions as an `Oracle` remaining accounts slice.",
+			],
+			accounts: [
+				{
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "oracle",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6043;
-			name: "InvalidBundleIndex";
-			msg: "Bundle index is out of bounds";
+			name: "increaseObservationCardinality",
+			docs: [
+				"Grows the observation ring buffer of an oracle. The funder pays the rent for the new slots.",
+				"The new slots are taken into use once the ring buffer wraps around.",
+				"",
+				"### Parameters",
+				"- `observation_cardinality_next` - The number of observation slots the oracle should hold.",
+				"",
+				"#### Special Errors",
+				"- `InvalidObservationCardinality` - If the cardinality does not increase, or increases by more",
+				"than MAX_OBSERVATION_CARDINALITY_INCREASE.",
+			],
+			accounts: [
+				{
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "oracle",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "observationCardinalityNext",
+					type: "u16",
+				},
+			],
 		},
 		{
-			code: 6044;
-			name: "BundledPositionAlreadyOpened";
-			msg: "Position has already been opened";
+			name: "observe",
+			docs: [
+				"Returns the tick cumulative and seconds per liquidity cumulative of a Yevefi as of each",
+				"`seconds_ago` before the current timestamp. The result is an `ObserveResult` set as return data,",
+				"so the instruction can be used by other programs via CPI.",
+				"",
+				"### Parameters",
+				"- `seconds_agos` - The ages of the requested observations, in seconds.",
+				"",
+				"#### Special Errors",
+				"- `ObservationTooOld` - If a requested observation is older than the oldest observation.",
+				"- `TooManySecondsAgos` - If more than MAX_OBSERVE_SECONDS_AGOS_LEN observations are requested.",
+			],
+			accounts: [
+				{
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "oracle",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "secondsAgos",
+					type: {
+						vec: "u32",
+					},
+				},
+			],
 		},
 		{
-			code: 6045;
-			name: "BundledPositionAlreadyClosed";
-			msg: "Position has already been closed";
+			name: "rewardRunway",
+			docs: [
+				"Returns the number of seconds until the funds of each reward of a Yevefi run out at its",
+				"scheduled emission rates, assuming the Yevefi has liquidity. The result is a",
+				"`RewardRunwayResult` set as return data, so the instruction can be used by other programs via CPI.",
+			],
+			accounts: [
+				{
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6046;
-			name: "PositionBundleNotDeletable";
-			msg: "Unable to delete PositionBundle with open positions";
+			name: "initializeAdaptiveFeeTier",
+			docs: [
+				"Initializes an adaptive_fee_tier account usable by Yevefis in a YevefiConfig space.",
+				"Yevefis initialized with an adaptive fee tier charge a base fee plus a variable fee that",
+				"grows with the volatility of the price and decays over time.",
+				"",
+				"### Authority",
+				'- "fee_authority" - Set authority in the YevefiConfig',
+				"",
+				"### Parameters",
+				"- `fee_tier_index` - The index used as the tick_spacing seed of the Yevefis using this tier.",
+				"Must not be equal to tick_spacing, nor to the tick_spacing of a",
+				"FeeTier of the config.",
+				"- `tick_spacing` - The tick-spacing of the Yevefis using this tier.",
+				"- `default_base_fee_rate` - The base fee rate that a pool will use if the pool uses this",
+				"adaptive fee tier during initialization.",
+				"- `adaptive_fee_constants` - The parameters of the variable fee.",
+				"",
+				"#### Special Errors",
+				"- `InvalidFeeTierIndex` - If fee_tier_index is equal to tick_spacing.",
+				"",
+				"The AdaptiveFeeTier shares its address with the FeeTier using fee_tier_index as",
+				"tick_spacing, so initializing one fails if the other exists.",
+				"- `InvalidTickSpacing` - If tick_spacing is 0.",
+				"- `FeeRateMaxExceeded` - If the provided default_base_fee_rate exceeds MAX_FEE_RATE.",
+				"- `InvalidAdaptiveFeeConstants` - If the provided adaptive_fee_constants are invalid.",
+			],
+			accounts: [
+				{
+					name: "config",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "adaptiveFeeTier",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "feeAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "feeTierIndex",
+					type: "u16",
+				},
+				{
+					name: "tickSpacing",
+					type: "u16",
+				},
+				{
+					name: "defaultBaseFeeRate",
+					type: "u16",
+				},
+				{
+					name: "adaptiveFeeConstants",
+					type: {
+						defined: "AdaptiveFeeConstants",
+					},
+				},
+			],
 		},
 		{
-			code: 6047;
-			name: "UnsupportedTokenMint";
-			msg: "Token mint has unsupported attributes";
+			name: "setDefaultBaseFeeRate",
+			docs: [
+				"Set the default_base_fee_rate for an AdaptiveFeeTier",
+				"Only the current fee authority has permission to invoke this instruction.",
+				"",
+				"### Authority",
+				'- "fee_authority" - Set authority in the YevefiConfig',
+				"",
+				"### Parameters",
+				"- `default_base_fee_rate` - The base fee rate that a pool will use if the pool uses this",
+				"adaptive fee tier during initialization.",
+				"",
+				"#### Special Errors",
+				"- `FeeRateMaxExceeded` - If the provided default_base_fee_rate exceeds MAX_FEE_RATE.",
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "adaptiveFeeTier",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "feeAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [
+				{
+					name: "defaultBaseFeeRate",
+					type: "u16",
+				},
+			],
 		},
 		{
-			code: 6048;
-			name: "RemainingAccountsInvalidSlice";
-			msg: "Invalid remaining accounts";
+			name: "initializeRewardExtension",
+			docs: [
+				"Initializes the reward extension of a Yevefi, adding NUM_EXTENSION_REWARDS rewards on top",
+				"of the NUM_REWARDS rewards of the Yevefi. The authorities of the extension rewards are set",
+				"to the reward_emissions_super_authority.",
+				"",
+				"Once initialized, the Yevefi can only be swapped with `swap_v2` and its liquidity can only be",
+				"modified with `increase_liquidity_v2` and `decrease_liquidity_v2`, which maintain the",
+				"extension rewards through the RewardExtension remaining accounts. The following",
+				"instructions do not maintain the extension rewards and fail with",
+				"`RewardExtensionNotSupported` on the Yevefi: `swap`, `two_hop_swap`, `two_hop_swap_v2`,",
+				"`n_hop_swap_v2`, `flash_swap_v2`, `increase_liquidity`, `decrease_liquidity`,",
+				"`compound_position_fees`, `zap_in`, `zap_out` and `reposition_liquidity`.",
+				"Extension rewards are emitted only while their vaults hold unemitted funds.",
+				"",
+				"### Authority",
+				'- "reward_emissions_super_authority" - Set authority in the YevefiConfig',
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "yevefi",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "rewardEmissionsSuperAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "yevefiRewardExtension",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6049;
-			name: "RemainingAccountsInsufficient";
-			msg: "Insufficient remaining accounts";
+			name: "initializeTickArrayRewardExtension",
+			docs: [
+				"Initializes the extension reward growths of the ticks of a TickArray.",
+				"Swaps crossing ticks of the TickArray must include the TickArrayRewardExtension once initialized.",
+				"",
+				"### Parameters",
+				"- `start_tick_index` - The starting tick index of the TickArray.",
+				"",
+				"#### Special Errors",
+				"- `InvalidStartTick` - if the provided start tick is out of bounds or is not a multiple of",
+				"TICK_ARRAY_SIZE * tick spacing.",
+			],
+			accounts: [
+				{
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "yevefiRewardExtension",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "tickArrayRewardExtension",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "startTickIndex",
+					type: "i32",
+				},
+			],
 		},
 		{
-			code: 6050;
-			name: "NoExtraAccountsForTransferHook";
-			msg: "Unable to call transfer hook without extra accounts";
+			name: "initializePositionRewardExtension",
+			docs: [
+				"Initializes the extension rewards of a position. The position earns extension rewards from",
+				"this point on. The TickArrayRewardExtensions of its lower and upper ticks must be initialized.",
+				"",
+				"### Authority",
+				'- "position_authority" - The authority that owns the position token.',
+			],
+			accounts: [
+				{
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "position",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "yevefiRewardExtension",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayLowerRewardExtension",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayUpperRewardExtension",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionRewardExtension",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "systemProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6051;
-			name: "IntermediateTokenAmountMismatch";
-			msg: "Output and input amount mismatch";
+			name: "updatePositionRewardExtension",
+			docs: ["Update the extension rewards owed to a position."],
+			accounts: [
+				{
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "yevefiRewardExtension",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "position",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionRewardExtension",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayLowerRewardExtension",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tickArrayUpperRewardExtension",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6052;
-			name: "TransferFeeCalculationError";
-			msg: "Transfer fee calculation failed";
+			name: "closePositionRewardExtension",
+			docs: [
+				"Close the PositionRewardExtension of a position, and stop earning extension rewards.",
+				"",
+				"### Authority",
+				'- "position_authority" - The authority that owns the position token.',
+				"",
+				"#### Special Errors",
+				"- `ClosePositionNotEmpty` - The position has extension rewards owed.",
+			],
+			accounts: [
+				{
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "receiver",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "position",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionTokenAccount",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionRewardExtension",
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
 		},
 		{
-			code: 6053;
-			name: "RemainingAccountsDuplicatedAccountsType";
-			msg: "Same accounts type is provided more than once";
+			name: "setExtensionRewardAuthority",
+			docs: [
+				"Set the authority of an extension reward of a Yevefi.",
+				"",
+				"### Authority",
+				'- "reward_authority" - Set authority for the extension reward.',
+				"",
+				"### Parameters",
+				"- `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS) that we'd like to modify.",
+				"",
+				"#### Special Errors",
+				"- `InvalidRewardIndex` - If the provided reward index exceeds NUM_EXTENSION_REWARDS.",
+			],
+			accounts: [
+				{
+					name: "yevefiRewardExtension",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "rewardAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "newRewardAuthority",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "rewardIndex",
+					type: "u8",
+				},
+			],
 		},
-	];
-};
-
-export const IDL: Yevefi = {
-	version: "0.3.0",
-	name: "yevefi",
-	instructions: [
 		{
-			name: "initializeConfig",
+			name: "collectFeesV2",
 			docs: [
-				"Initializes a YevefisConfig account that hosts info & authorities",
-				"required to govern a set of Yevefis.",
+				"Collect fees accrued for this position.",
 				"",
-				"### Parameters",
-				"- `fee_authority` - Authority authorized to initialize fee-tiers and set customs fees.",
-				"- `collect_protocol_fees_authority` - Authority authorized to collect protocol fees.",
-				"- `reward_emissions_super_authority` - Authority authorized to set reward authorities in pools.",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"A PositionDelegate with the collect fees permission can also authorize this call,",
+				"passed as a `PositionDelegate` remaining accounts slice.",
+				"",
+				"#### Special Errors",
+				"- `PositionDelegateInvalidDestination` - A PositionDelegate transfers tokens to an account not owned by the position owner.",
 			],
 			accounts: [
 				{
-					name: "config",
-					isMut: true,
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionAuthority",
+					isMut: false,
 					isSigner: true,
 				},
 				{
-					name: "funder",
+					name: "position",
 					isMut: true,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "systemProgram",
+					name: "positionTokenAccount",
 					isMut: false,
 					isSigner: false,
 				},
-			],
-			args: [
 				{
-					name: "feeAuthority",
-					type: "publicKey",
+					name: "tokenMintA",
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: "collectProtocolFeesAuthority",
-					type: "publicKey",
+					name: "tokenMintB",
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: "rewardEmissionsSuperAuthority",
-					type: "publicKey",
+					name: "tokenOwnerAccountA",
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: "defaultProtocolFeeRate",
-					type: "u16",
+					name: "tokenVaultA",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenOwnerAccountB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenVaultB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenProgramA",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenProgramB",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "memoProgram",
+					isMut: false,
+					isSigner: false,
+				},
+			],
+			args: [
+				{
+					name: "remainingAccountsInfo",
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
+					},
 				},
 			],
 		},
 		{
-			name: "initializePool",
+			name: "collectProtocolFeesV2",
 			docs: [
-				"Initializes a Yevefi account.",
-				"Fee rate is set to the default values on the config and supplied fee_tier.",
-				"",
-				"### Parameters",
-				"- `bumps` - The bump value when deriving the PDA of the Yevefi address.",
-				"- `tick_spacing` - The desired tick spacing for this pool.",
-				"- `initial_sqrt_price` - The desired initial sqrt-price for this pool",
-				"",
-				"#### Special Errors",
-				"`InvalidTokenMintOrder` - The order of mints have to be ordered by",
-				"`SqrtPriceOutOfBounds` - provided initial_sqrt_price is not between 2^-64 to 2^64",
+				"Collect the protocol fees accrued in this Yevefi",
 				"",
+				"### Authority",
+				"- `collect_protocol_fees_authority` - assigned authority in the YevefiConfig that can collect protocol fees",
 			],
 			accounts: [
 				{
@@ -4332,6 +12127,16 @@ export const IDL: Yevefi = {
 					isMut: false,
 					isSigner: false,
 				},
+				{
+					name: "yevefi",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "collectProtocolFeesAuthority",
+					isMut: false,
+					isSigner: true,
+				},
 				{
 					name: "tokenMintA",
 					isMut: false,
@@ -4343,108 +12148,64 @@ export const IDL: Yevefi = {
 					isSigner: false,
 				},
 				{
-					name: "funder",
+					name: "tokenVaultA",
 					isMut: true,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "yevefi",
+					name: "tokenVaultB",
 					isMut: true,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "yevefi",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "YevefisConfig",
-								path: "yevefis_config",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "Mint",
-								path: "token_mint_a",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "Mint",
-								path: "token_mint_b",
-							},
-							{
-								kind: "arg",
-								type: "u16",
-								path: "tick_spacing",
-							},
-						],
-					},
 				},
 				{
-					name: "tokenVaultA",
+					name: "tokenDestinationA",
 					isMut: true,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "tokenVaultB",
+					name: "tokenDestinationB",
 					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: "feeTier",
-					isMut: false,
 					isSigner: false,
-					relations: ["yevefis_config"],
 				},
 				{
-					name: "tokenProgram",
+					name: "tokenProgramA",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "systemProgram",
+					name: "tokenProgramB",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "rent",
+					name: "memoProgram",
 					isMut: false,
 					isSigner: false,
 				},
 			],
 			args: [
 				{
-					name: "bumps",
+					name: "remainingAccountsInfo",
 					type: {
-						defined: "YevefiBumps",
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
 					},
 				},
-				{
-					name: "tickSpacing",
-					type: "u16",
-				},
-				{
-					name: "initialSqrtPrice",
-					type: "u128",
-				},
 			],
 		},
 		{
-			name: "initializeTickArray",
+			name: "collectRewardV2",
 			docs: [
-				"Initializes a tick_array account to represent a tick-range in a Yevefi.",
+				"Collect rewards accrued for this position.",
 				"",
-				"### Parameters",
-				"- `start_tick_index` - The starting tick index for this tick-array.",
-				"Has to be a multiple of TickArray size & the tick spacing of this pool.",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"A PositionDelegate with the collect rewards permission can also authorize this call,",
+				"passed as a `PositionDelegate` remaining accounts slice.",
 				"",
 				"#### Special Errors",
-				"- `InvalidStartTick` - if the provided start tick is out of bounds or is not a multiple of",
-				"TICK_ARRAY_SIZE * tick spacing.",
+				"- `PositionDelegateInvalidDestination` - A PositionDelegate transfers tokens to an account not owned by the position owner.",
 			],
 			accounts: [
 				{
@@ -4453,469 +12214,433 @@ export const IDL: Yevefi = {
 					isSigner: false,
 				},
 				{
-					name: "funder",
-					isMut: true,
+					name: "positionAuthority",
+					isMut: false,
 					isSigner: true,
 				},
 				{
-					name: "tickArray",
+					name: "position",
 					isMut: true,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "tick_array",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "Yevefi",
-								path: "yevefi",
-							},
-							{
-								kind: "arg",
-								type: "i32",
-								path: "start_tick_index",
-							},
-						],
-					},
 				},
 				{
-					name: "systemProgram",
+					name: "positionTokenAccount",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "rewardOwnerAccount",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "rewardMint",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "rewardVault",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "rewardTokenProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "memoProgram",
 					isMut: false,
 					isSigner: false,
 				},
 			],
 			args: [
 				{
-					name: "startTickIndex",
-					type: "i32",
+					name: "rewardIndex",
+					type: "u8",
+				},
+				{
+					name: "remainingAccountsInfo",
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
+					},
 				},
 			],
 		},
 		{
-			name: "initializeFeeTier",
+			name: "harvestPositionV2",
 			docs: [
-				"Initializes a fee_tier account usable by Yevefis in a YevefiConfig space.",
+				"Collect the fees and all rewards accrued for this position.",
+				"Fee and reward growths are updated once, then every initialized reward is paid out.",
 				"",
 				"### Authority",
-				'- "fee_authority" - Set authority in the YevefiConfig',
-				"",
-				"### Parameters",
-				"- `tick_spacing` - The tick-spacing that this fee-tier suggests the default_fee_rate for.",
-				"- `default_fee_rate` - The default fee rate that a pool will use if the pool uses this",
-				"fee tier during initialization.",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"A PositionDelegate with the collect fees and collect rewards permissions can also authorize this call,",
+				"passed as a `PositionDelegate` remaining accounts slice.",
 				"",
 				"#### Special Errors",
-				"- `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE.",
+				"- `InvalidRewardAccounts` - The reward accounts do not match the initialized rewards of the Yevefi.",
+				"- `PositionDelegateInvalidDestination` - A PositionDelegate transfers tokens to an account not owned by the position owner.",
 			],
 			accounts: [
 				{
-					name: "config",
-					isMut: false,
+					name: "yevefi",
+					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "feeTier",
-					isMut: true,
-					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "fee_tier",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "YevefisConfig",
-								path: "config",
-							},
-							{
-								kind: "arg",
-								type: "u16",
-								path: "tick_spacing",
-							},
-						],
-					},
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
 				},
 				{
-					name: "funder",
+					name: "position",
 					isMut: true,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "feeAuthority",
+					name: "positionTokenAccount",
 					isMut: false,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "systemProgram",
+					name: "tickArrayLower",
 					isMut: false,
 					isSigner: false,
 				},
-			],
-			args: [
 				{
-					name: "tickSpacing",
-					type: "u16",
+					name: "tickArrayUpper",
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: "defaultFeeRate",
-					type: "u16",
+					name: "tokenMintA",
+					isMut: false,
+					isSigner: false,
 				},
-			],
-		},
-		{
-			name: "initializeReward",
-			docs: [
-				"Initialize reward for a Yevefi. A pool can only support up to a set number of rewards.",
-				"",
-				"### Authority",
-				'- "reward_authority" - assigned authority by the reward_super_authority for the specified',
-				"reward-index in this Yevefi",
-				"",
-				"### Parameters",
-				"- `reward_index` - The reward index that we'd like to initialize. (0 <= index <= NUM_REWARDS)",
-				"",
-				"#### Special Errors",
-				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
-				"index in this pool, or exceeds NUM_REWARDS, or",
-				"all reward slots for this pool has been initialized.",
-			],
-			accounts: [
 				{
-					name: "rewardAuthority",
+					name: "tokenMintB",
 					isMut: false,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "funder",
+					name: "tokenOwnerAccountA",
 					isMut: true,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "yevefi",
+					name: "tokenVaultA",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "rewardMint",
-					isMut: false,
+					name: "tokenOwnerAccountB",
+					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "rewardVault",
+					name: "tokenVaultB",
 					isMut: true,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "tokenProgram",
+					name: "tokenProgramA",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "systemProgram",
+					name: "tokenProgramB",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "rent",
+					name: "memoProgram",
 					isMut: false,
 					isSigner: false,
 				},
 			],
 			args: [
 				{
-					name: "rewardIndex",
-					type: "u8",
+					name: "remainingAccountsInfo",
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
+					},
 				},
 			],
 		},
 		{
-			name: "setRewardEmissions",
+			name: "collectFeesBundledV2",
 			docs: [
-				"Set the reward emissions for a reward in a Yevefi.",
+				"Collect fees accrued for bundled positions of a PositionBundle in one call.",
 				"",
 				"### Authority",
-				'- "reward_authority" - assigned authority by the reward_super_authority for the specified',
-				"reward-index in this Yevefi",
+				"- `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.",
 				"",
 				"### Parameters",
-				"- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.",
-				"- `emissions_per_second_x64` - The amount of rewards emitted in this pool.",
+				"- `bundle_indexes` - The bundle indexes of the positions, in strictly increasing order.",
+				"- `bundled_position_bumps` - The bumps of the bundled position addresses, in the order of `bundle_indexes`.",
 				"",
 				"#### Special Errors",
-				"- `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit",
-				"more than a day of desired emissions.",
-				"- `InvalidTimestamp` - Provided timestamp is not in order with the previous timestamp.",
-				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
-				"index in this pool, or exceeds NUM_REWARDS, or",
-				"all reward slots for this pool has been initialized.",
+				"- `BundledPositionAlreadyClosed` - A bundled position at the provided bundle index is not opened.",
+				"- `InvalidBundledPositionAccounts` - The bundled position accounts do not match `bundle_indexes`.",
+				"- `InvalidBundledPositionBump` - The bumps in `bundled_position_bumps` do not derive the bundled position addresses.",
 			],
 			accounts: [
 				{
 					name: "yevefi",
-					isMut: true,
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "rewardAuthority",
+					name: "positionBundleAuthority",
 					isMut: false,
 					isSigner: true,
 				},
 				{
-					name: "rewardVault",
+					name: "positionBundle",
 					isMut: false,
 					isSigner: false,
 				},
-			],
-			args: [
-				{
-					name: "rewardIndex",
-					type: "u8",
-				},
-				{
-					name: "emissionsPerSecondX64",
-					type: "u128",
-				},
-			],
-		},
-		{
-			name: "openPosition",
-			docs: [
-				"Open a position in a Yevefi. A unique token will be minted to represent the position",
-				"in the users wallet. The position will start off with 0 liquidity.",
-				"",
-				"### Parameters",
-				"- `tick_lower_index` - The tick specifying the lower end of the position range.",
-				"- `tick_upper_index` - The tick specifying the upper end of the position range.",
-				"",
-				"#### Special Errors",
-				"- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
-				"the tick-spacing in this pool.",
-			],
-			accounts: [
 				{
-					name: "funder",
-					isMut: true,
-					isSigner: true,
+					name: "positionBundleTokenAccount",
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: "owner",
+					name: "tokenMintA",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "position",
-					isMut: true,
+					name: "tokenMintB",
+					isMut: false,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "position",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "Mint",
-								path: "position_mint",
-							},
-						],
-					},
 				},
 				{
-					name: "positionMint",
+					name: "tokenOwnerAccountA",
 					isMut: true,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "positionTokenAccount",
+					name: "tokenVaultA",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "yevefi",
-					isMut: false,
+					name: "tokenOwnerAccountB",
+					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenProgram",
-					isMut: false,
+					name: "tokenVaultB",
+					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "systemProgram",
+					name: "tokenProgramA",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "rent",
+					name: "tokenProgramB",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "associatedTokenProgram",
+					name: "memoProgram",
 					isMut: false,
 					isSigner: false,
 				},
 			],
 			args: [
 				{
-					name: "bumps",
+					name: "bundleIndexes",
 					type: {
-						defined: "OpenPositionBumps",
+						vec: "u16",
 					},
 				},
 				{
-					name: "tickLowerIndex",
-					type: "i32",
+					name: "bundledPositionBumps",
+					type: "bytes",
 				},
 				{
-					name: "tickUpperIndex",
-					type: "i32",
+					name: "remainingAccountsInfo",
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
+					},
 				},
 			],
 		},
 		{
-			name: "openPositionWithMetadata",
+			name: "collectRewardBundledV2",
 			docs: [
-				"Open a position in a Yevefi. A unique token will be minted to represent the position",
-				"in the users wallet. Additional Metaplex metadata is appended to identify the token.",
-				"The position will start off with 0 liquidity.",
+				"Collect rewards accrued for bundled positions of a PositionBundle in one call.",
+				"",
+				"### Authority",
+				"- `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.",
 				"",
 				"### Parameters",
-				"- `tick_lower_index` - The tick specifying the lower end of the position range.",
-				"- `tick_upper_index` - The tick specifying the upper end of the position range.",
+				"- `reward_index` - The reward to harvest. Acceptable values are 0, 1, and 2.",
+				"- `bundle_indexes` - The bundle indexes of the positions, in strictly increasing order.",
+				"- `bundled_position_bumps` - The bumps of the bundled position addresses, in the order of `bundle_indexes`.",
 				"",
 				"#### Special Errors",
-				"- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
-				"the tick-spacing in this pool.",
+				"- `BundledPositionAlreadyClosed` - A bundled position at the provided bundle index is not opened.",
+				"- `InvalidBundledPositionAccounts` - The bundled position accounts do not match `bundle_indexes`.",
+				"- `InvalidBundledPositionBump` - The bumps in `bundled_position_bumps` do not derive the bundled position addresses.",
 			],
 			accounts: [
 				{
-					name: "funder",
-					isMut: true,
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "positionBundleAuthority",
+					isMut: false,
 					isSigner: true,
 				},
 				{
-					name: "owner",
+					name: "positionBundle",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "position",
-					isMut: true,
+					name: "positionBundleTokenAccount",
+					isMut: false,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "position",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "Mint",
-								path: "position_mint",
-							},
-						],
-					},
 				},
 				{
-					name: "positionMint",
+					name: "rewardOwnerAccount",
 					isMut: true,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "positionMetadataAccount",
-					isMut: true,
+					name: "rewardMint",
+					isMut: false,
 					isSigner: false,
-					docs: [
-						"https://github.com/metaplex-foundation/metaplex-program-library/blob/master/token-metadata/program/src/utils.rs#L873",
-					],
 				},
 				{
-					name: "positionTokenAccount",
+					name: "rewardVault",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "yevefi",
+					name: "rewardTokenProgram",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenProgram",
+					name: "memoProgram",
 					isMut: false,
 					isSigner: false,
 				},
+			],
+			args: [
 				{
-					name: "systemProgram",
-					isMut: false,
-					isSigner: false,
+					name: "rewardIndex",
+					type: "u8",
 				},
 				{
-					name: "rent",
-					isMut: false,
-					isSigner: false,
+					name: "bundleIndexes",
+					type: {
+						vec: "u16",
+					},
 				},
 				{
-					name: "associatedTokenProgram",
-					isMut: false,
-					isSigner: false,
+					name: "bundledPositionBumps",
+					type: "bytes",
 				},
 				{
-					name: "metadataProgram",
-					isMut: false,
+					name: "remainingAccountsInfo",
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
+					},
+				},
+			],
+		},
+		{
+			name: "updateFeesAndRewardsBundled",
+			docs: [
+				"Update the accrued fees and rewards for bundled positions of a PositionBundle in one call.",
+				"Bundled positions without liquidity are skipped.",
+				"",
+				"### Parameters",
+				"- `bundle_indexes` - The bundle indexes of the positions, in strictly increasing order.",
+				"- `bundled_position_bumps` - The bumps of the bundled position addresses, in the order of `bundle_indexes`.",
+				"",
+				"#### Special Errors",
+				"- `BundledPositionAlreadyClosed` - A bundled position at the provided bundle index is not opened.",
+				"- `InvalidBundledPositionAccounts` - The bundled position or tick array accounts do not match `bundle_indexes`.",
+				"- `InvalidBundledPositionBump` - The bumps in `bundled_position_bumps` do not derive the bundled position addresses.",
+			],
+			accounts: [
+				{
+					name: "yevefi",
+					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "metadataUpdateAuth",
+					name: "positionBundle",
 					isMut: false,
 					isSigner: false,
 				},
 			],
 			args: [
 				{
-					name: "bumps",
+					name: "bundleIndexes",
 					type: {
-						defined: "OpenPositionWithMetadataBumps",
+						vec: "u16",
 					},
 				},
 				{
-					name: "tickLowerIndex",
-					type: "i32",
+					name: "bundledPositionBumps",
+					type: "bytes",
 				},
 				{
-					name: "tickUpperIndex",
-					type: "i32",
+					name: "remainingAccountsInfo",
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
+					},
 				},
 			],
 		},
 		{
-			name: "increaseLiquidity",
+			name: "decreaseLiquidityV2",
 			docs: [
-				"Add liquidity to a position in the Yevefi. This call also updates the position's accrued fees and rewards.",
+				"Withdraw liquidity from a position in the Yevefi. This call also updates the position's accrued fees and rewards.",
 				"",
 				"### Authority",
 				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"A PositionDelegate with the decrease liquidity permission can also authorize this call,",
+				"passed as a `PositionDelegate` remaining accounts slice.",
 				"",
 				"### Parameters",
-				"- `liquidity_amount` - The total amount of Liquidity the user is willing to deposit.",
-				"- `token_max_a` - The maximum amount of tokenA the user is willing to deposit.",
-				"- `token_max_b` - The maximum amount of tokenB the user is willing to deposit.",
+				"- `liquidity_amount` - The total amount of Liquidity the user desires to withdraw.",
+				"- `token_min_a` - The minimum amount of tokenA the user is willing to withdraw.",
+				"- `token_min_b` - The minimum amount of tokenB the user is willing to withdraw.",
 				"",
 				"#### Special Errors",
 				"- `LiquidityZero` - Provided liquidity amount is zero.",
 				"- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
-				"- `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.",
+				"- `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.",
+				"- `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.",
+				"- `InvalidPositionLock` - The PositionLock PDA of the position was not passed.",
+				"- `PositionDelegateInvalidDestination` - A PositionDelegate transfers tokens to an account not owned by the position owner.",
+				"- `InvalidRewardExtensionAccounts` - The Yevefi has a reward extension, and the RewardExtension",
+				"accounts are missing or invalid.",
+				"- `InvalidOracle` - The oracle account is missing or is not the oracle PDA of the Yevefi.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
 			],
 			accounts: [
 				{
@@ -4924,7 +12649,17 @@ export const IDL: Yevefi = {
 					isSigner: false,
 				},
 				{
-					name: "tokenProgram",
+					name: "tokenProgramA",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenProgramB",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "memoProgram",
 					isMut: false,
 					isSigner: false,
 				},
@@ -4937,13 +12672,22 @@ export const IDL: Yevefi = {
 					name: "position",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 				{
 					name: "positionTokenAccount",
 					isMut: false,
 					isSigner: false,
 				},
+				{
+					name: "tokenMintA",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenMintB",
+					isMut: false,
+					isSigner: false,
+				},
 				{
 					name: "tokenOwnerAccountA",
 					isMut: true,
@@ -4968,13 +12712,11 @@ export const IDL: Yevefi = {
 					name: "tickArrayLower",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 				{
 					name: "tickArrayUpper",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 			],
 			args: [
@@ -4983,32 +12725,48 @@ export const IDL: Yevefi = {
 					type: "u128",
 				},
 				{
-					name: "tokenMaxA",
+					name: "tokenMinA",
 					type: "u64",
 				},
 				{
-					name: "tokenMaxB",
+					name: "tokenMinB",
 					type: "u64",
 				},
+				{
+					name: "remainingAccountsInfo",
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
+					},
+				},
 			],
 		},
 		{
-			name: "decreaseLiquidity",
+			name: "increaseLiquidityV2",
 			docs: [
-				"Withdraw liquidity from a position in the Yevefi. This call also updates the position's accrued fees and rewards.",
+				"Add liquidity to a position in the Yevefi. This call also updates the position's accrued fees and rewards.",
 				"",
 				"### Authority",
 				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"A PositionDelegate with the increase liquidity permission can also authorize this call,",
+				"passed as a `PositionDelegate` remaining accounts slice.",
 				"",
 				"### Parameters",
-				"- `liquidity_amount` - The total amount of Liquidity the user desires to withdraw.",
-				"- `token_min_a` - The minimum amount of tokenA the user is willing to withdraw.",
-				"- `token_min_b` - The minimum amount of tokenB the user is willing to withdraw.",
+				"- `liquidity_amount` - The total amount of Liquidity the user is willing to deposit.",
+				"- `token_max_a` - The maximum amount of tokenA the user is willing to deposit.",
+				"- `token_max_b` - The maximum amount of tokenB the user is willing to deposit.",
 				"",
 				"#### Special Errors",
 				"- `LiquidityZero` - Provided liquidity amount is zero.",
 				"- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
-				"- `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.",
+				"- `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `InvalidRewardExtensionAccounts` - The Yevefi has a reward extension, and the RewardExtension",
+				"accounts are missing or invalid.",
+				"- `InvalidOracle` - The oracle account is missing or is not the oracle PDA of the Yevefi.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
 			],
 			accounts: [
 				{
@@ -5017,7 +12775,17 @@ export const IDL: Yevefi = {
 					isSigner: false,
 				},
 				{
-					name: "tokenProgram",
+					name: "tokenProgramA",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenProgramB",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "memoProgram",
 					isMut: false,
 					isSigner: false,
 				},
@@ -5030,13 +12798,22 @@ export const IDL: Yevefi = {
 					name: "position",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 				{
 					name: "positionTokenAccount",
 					isMut: false,
 					isSigner: false,
 				},
+				{
+					name: "tokenMintA",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenMintB",
+					isMut: false,
+					isSigner: false,
+				},
 				{
 					name: "tokenOwnerAccountA",
 					isMut: true,
@@ -5061,13 +12838,11 @@ export const IDL: Yevefi = {
 					name: "tickArrayLower",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 				{
 					name: "tickArrayUpper",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 			],
 			args: [
@@ -5076,23 +12851,47 @@ export const IDL: Yevefi = {
 					type: "u128",
 				},
 				{
-					name: "tokenMinA",
+					name: "tokenMaxA",
 					type: "u64",
 				},
 				{
-					name: "tokenMinB",
+					name: "tokenMaxB",
 					type: "u64",
 				},
+				{
+					name: "remainingAccountsInfo",
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
+					},
+				},
 			],
 		},
 		{
-			name: "updateFeesAndRewards",
+			name: "increaseLiquidityByTokenAmountsV2",
 			docs: [
-				"Update the accrued fees and rewards for a position.",
+				"Add liquidity to a position in the Yevefi, computing the liquidity on-chain from token budgets.",
+				"The largest liquidity whose deposit fits both budgets at the current price is added.",
+				"This call also updates the position's accrued fees and rewards.",
+				"",
+				"### Authority",
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"",
+				"### Parameters",
+				"- `token_max_a` - The maximum amount of tokenA the user is willing to deposit.",
+				"- `token_max_b` - The maximum amount of tokenB the user is willing to deposit.",
 				"",
 				"#### Special Errors",
-				"- `TickNotFound` - Provided tick array account does not contain the tick for this position.",
-				"- `LiquidityZero` - Position has zero liquidity and therefore already has the most updated fees and reward values.",
+				"- `LiquidityZero` - The budgets are too small to fund any liquidity.",
+				"- `LiquidityTooHigh` - The computed liquidity exceeds u128::max.",
+				"- `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `InvalidRewardExtensionAccounts` - The Yevefi has a reward extension, and the RewardExtension",
+				"accounts are missing or invalid.",
+				"- `InvalidOracle` - The oracle account is missing or is not the oracle PDA of the Yevefi.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
 			],
 			accounts: [
 				{
@@ -5101,37 +12900,17 @@ export const IDL: Yevefi = {
 					isSigner: false,
 				},
 				{
-					name: "position",
-					isMut: true,
-					isSigner: false,
-					relations: ["yevefi"],
-				},
-				{
-					name: "tickArrayLower",
+					name: "tokenProgramA",
 					isMut: false,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 				{
-					name: "tickArrayUpper",
+					name: "tokenProgramB",
 					isMut: false,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
-			],
-			args: [],
-		},
-		{
-			name: "collectFees",
-			docs: [
-				"Collect fees accrued for this position.",
-				"",
-				"### Authority",
-				"- `position_authority` - authority that owns the token corresponding to this desired position.",
-			],
-			accounts: [
 				{
-					name: "yevefi",
+					name: "memoProgram",
 					isMut: false,
 					isSigner: false,
 				},
@@ -5144,7 +12923,6 @@ export const IDL: Yevefi = {
 					name: "position",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 				{
 					name: "positionTokenAccount",
@@ -5152,890 +12930,647 @@ export const IDL: Yevefi = {
 					isSigner: false,
 				},
 				{
-					name: "tokenOwnerAccountA",
-					isMut: true,
+					name: "tokenMintA",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenVaultA",
-					isMut: true,
+					name: "tokenMintB",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenOwnerAccountB",
+					name: "tokenOwnerAccountA",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenVaultB",
+					name: "tokenOwnerAccountB",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenProgram",
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: "collectReward",
-			docs: [
-				"Collect rewards accrued for this position.",
-				"",
-				"### Authority",
-				"- `position_authority` - authority that owns the token corresponding to this desired position.",
-			],
-			accounts: [
-				{
-					name: "yevefi",
-					isMut: false,
+					name: "tokenVaultA",
+					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "positionAuthority",
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: "position",
+					name: "tokenVaultB",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 				{
-					name: "positionTokenAccount",
-					isMut: false,
+					name: "tickArrayLower",
+					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "rewardOwnerAccount",
+					name: "tickArrayUpper",
 					isMut: true,
 					isSigner: false,
 				},
+			],
+			args: [
 				{
-					name: "rewardVault",
-					isMut: true,
-					isSigner: false,
+					name: "tokenMaxA",
+					type: "u64",
 				},
 				{
-					name: "tokenProgram",
-					isMut: false,
-					isSigner: false,
+					name: "tokenMaxB",
+					type: "u64",
 				},
-			],
-			args: [
 				{
-					name: "rewardIndex",
-					type: "u8",
+					name: "remainingAccountsInfo",
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
+					},
 				},
 			],
 		},
 		{
-			name: "collectProtocolFees",
+			name: "zapIn",
 			docs: [
-				"Collect the protocol fees accrued in this Yevefi",
+				"Deposit a single token into a position in the Yevefi.",
+				"Part of the input token is swapped in the same Yevefi, and the remainder and the swap output",
+				"are added as liquidity at the post-swap price. The split is solved on-chain with a bounded",
+				"number of simulated swaps, and the part of the swap output that cannot be deposited is",
+				"returned to the owner.",
+				"This call also updates the position's accrued fees and rewards.",
 				"",
 				"### Authority",
-				"- `collect_protocol_fees_authority` - assigned authority in the YevefiConfig that can collect protocol fees",
+				"- `position_authority` - authority that owns the token corresponding to this desired position",
+				"and the input token account.",
+				"",
+				"### Parameters",
+				"- `amount` - The maximum amount of the input token the user is willing to deposit.",
+				"- `a_to_b` - The input token is tokenA if true, tokenB otherwise.",
+				"- `min_liquidity` - The minimum amount of liquidity the user is willing to accept.",
+				"",
+				"#### Special Errors",
+				"- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
+				"- `LiquidityZero` - The input amount is too small to fund any liquidity.",
+				"- `LiquidityBelowMinimum` - The added liquidity is below the user defined minimum.",
+				"- `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
 			],
 			accounts: [
-				{
-					name: "yevefisConfig",
-					isMut: false,
-					isSigner: false,
-				},
 				{
 					name: "yevefi",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefis_config"],
 				},
 				{
-					name: "collectProtocolFeesAuthority",
+					name: "tokenProgramA",
 					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: "tokenVaultA",
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: "tokenVaultB",
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: "tokenDestinationA",
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: "tokenDestinationB",
-					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenProgram",
-					isMut: false,
-					isSigner: false,
-				},
-			],
-			args: [],
-		},
-		{
-			name: "swap",
-			docs: [
-				"Perform a swap in this Yevefi",
-				"",
-				"### Authority",
-				'- "token_authority" - The authority to withdraw tokens from the input token account.',
-				"",
-				"### Parameters",
-				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
-				"- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
-				"- `sqrt_price_limit` - The maximum/minimum price the swap will swap to.",
-				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
-				"- `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.",
-				"",
-				"#### Special Errors",
-				"- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
-				"- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
-				"- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
-				"- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
-				"- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
-				"- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
-				"- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
-				"- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
-			],
-			accounts: [
+					name: "tokenProgramB",
+					isMut: false,
+					isSigner: false,
+				},
 				{
-					name: "tokenProgram",
+					name: "memoProgram",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenAuthority",
+					name: "positionAuthority",
 					isMut: false,
 					isSigner: true,
 				},
 				{
-					name: "yevefi",
+					name: "position",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenOwnerAccountA",
-					isMut: true,
+					name: "positionTokenAccount",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenVaultA",
-					isMut: true,
+					name: "tokenMintA",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenOwnerAccountB",
-					isMut: true,
+					name: "tokenMintB",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenVaultB",
+					name: "tokenOwnerAccountA",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tickArray0",
+					name: "tokenOwnerAccountB",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 				{
-					name: "tickArray1",
+					name: "tokenVaultA",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 				{
-					name: "tickArray2",
+					name: "tokenVaultB",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 				{
-					name: "oracle",
-					isMut: false,
+					name: "tickArrayLower",
+					isMut: true,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "oracle",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "Yevefi",
-								path: "yevefi",
-							},
-						],
-					},
-				},
-			],
-			args: [
-				{
-					name: "amount",
-					type: "u64",
-				},
-				{
-					name: "otherAmountThreshold",
-					type: "u64",
-				},
-				{
-					name: "sqrtPriceLimit",
-					type: "u128",
-				},
-				{
-					name: "amountSpecifiedIsInput",
-					type: "bool",
-				},
-				{
-					name: "aToB",
-					type: "bool",
 				},
-			],
-		},
-		{
-			name: "closePosition",
-			docs: [
-				"Close a position in a Yevefi. Burns the position token in the owner's wallet.",
-				"",
-				"### Authority",
-				'- "position_authority" - The authority that owns the position token.',
-				"",
-				"#### Special Errors",
-				"- `ClosePositionNotEmpty` - The provided position account is not empty.",
-			],
-			accounts: [
 				{
-					name: "positionAuthority",
-					isMut: false,
-					isSigner: true,
+					name: "tickArrayUpper",
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: "receiver",
+					name: "tickArray0",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "position",
+					name: "tickArray1",
 					isMut: true,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "position",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "Mint",
-								path: "position_mint",
-							},
-						],
-					},
 				},
 				{
-					name: "positionMint",
+					name: "tickArray2",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "positionTokenAccount",
+					name: "oracle",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenProgram",
+					name: "yevefisConfigExtension",
 					isMut: false,
 					isSigner: false,
 				},
 			],
-			args: [],
-		},
-		{
-			name: "setDefaultFeeRate",
-			docs: [
-				"Set the default_fee_rate for a FeeTier",
-				"Only the current fee authority has permission to invoke this instruction.",
-				"",
-				"### Authority",
-				'- "fee_authority" - Set authority in the YevefiConfig',
-				"",
-				"### Parameters",
-				"- `default_fee_rate` - The default fee rate that a pool will use if the pool uses this",
-				"fee tier during initialization.",
-				"",
-				"#### Special Errors",
-				"- `FeeRateMaxExceeded` - If the provided default_fee_rate exceeds MAX_FEE_RATE.",
-			],
-			accounts: [
+			args: [
 				{
-					name: "yevefisConfig",
-					isMut: false,
-					isSigner: false,
+					name: "amount",
+					type: "u64",
 				},
 				{
-					name: "feeTier",
-					isMut: true,
-					isSigner: false,
-					relations: ["yevefis_config"],
+					name: "aToB",
+					type: "bool",
 				},
 				{
-					name: "feeAuthority",
-					isMut: false,
-					isSigner: true,
+					name: "minLiquidity",
+					type: "u128",
 				},
-			],
-			args: [
 				{
-					name: "defaultFeeRate",
-					type: "u16",
+					name: "remainingAccountsInfo",
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
+					},
 				},
 			],
 		},
 		{
-			name: "setDefaultProtocolFeeRate",
+			name: "zapOut",
 			docs: [
-				"Sets the default protocol fee rate for a YevefiConfig",
-				"Protocol fee rate is represented as a basis point.",
-				"Only the current fee authority has permission to invoke this instruction.",
+				"Withdraw a position into a single token.",
+				"Liquidity is removed from the position and its fees owed are collected, then all of the",
+				"unwanted token is swapped in the same Yevefi. Only the output token is transferred to the owner.",
 				"",
 				"### Authority",
-				'- "fee_authority" - Set authority that can modify pool fees in the YevefiConfig',
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
 				"",
 				"### Parameters",
-				"- `default_protocol_fee_rate` - Rate that is referenced during the initialization of a Yevefi using this config.",
+				"- `liquidity_amount` - The amount of liquidity to remove, or all liquidity of the position if None.",
+				"- `a_to_b` - The output token is tokenB if true, tokenA otherwise.",
+				"- `min_amount_out` - The minimum amount of the output token the user is willing to receive.",
 				"",
 				"#### Special Errors",
-				"- `ProtocolFeeRateMaxExceeded` - If the provided default_protocol_fee_rate exceeds MAX_PROTOCOL_FEE_RATE.",
+				"- `LiquidityZero` - The amount of liquidity to remove is 0.",
+				"- `PartialFillError` - The unwanted token cannot be swapped in full with the supplied tick arrays.",
+				"- `AmountOutBelowMinimum` - The amount of the output token is below the user defined minimum.",
+				"- `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
 			],
 			accounts: [
 				{
-					name: "yevefisConfig",
+					name: "yevefi",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "feeAuthority",
+					name: "tokenProgramA",
 					isMut: false,
-					isSigner: true,
+					isSigner: false,
 				},
-			],
-			args: [
 				{
-					name: "defaultProtocolFeeRate",
-					type: "u16",
+					name: "tokenProgramB",
+					isMut: false,
+					isSigner: false,
 				},
-			],
-		},
-		{
-			name: "setFeeRate",
-			docs: [
-				"Sets the fee rate for a Yevefi.",
-				"Fee rate is represented as hundredths of a basis point.",
-				"Only the current fee authority has permission to invoke this instruction.",
-				"",
-				"### Authority",
-				'- "fee_authority" - Set authority that can modify pool fees in the YevefiConfig',
-				"",
-				"### Parameters",
-				"- `fee_rate` - The rate that the pool will use to calculate fees going onwards.",
-				"",
-				"#### Special Errors",
-				"- `FeeRateMaxExceeded` - If the provided fee_rate exceeds MAX_FEE_RATE.",
-			],
-			accounts: [
 				{
-					name: "yevefisConfig",
+					name: "memoProgram",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "yevefi",
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "position",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefis_config"],
 				},
 				{
-					name: "feeAuthority",
+					name: "positionTokenAccount",
 					isMut: false,
-					isSigner: true,
+					isSigner: false,
 				},
-			],
-			args: [
 				{
-					name: "feeRate",
-					type: "u16",
+					name: "tokenMintA",
+					isMut: false,
+					isSigner: false,
 				},
-			],
-		},
-		{
-			name: "setProtocolFeeRate",
-			docs: [
-				"Sets the protocol fee rate for a Yevefi.",
-				"Protocol fee rate is represented as a basis point.",
-				"Only the current fee authority has permission to invoke this instruction.",
-				"",
-				"### Authority",
-				'- "fee_authority" - Set authority that can modify pool fees in the YevefiConfig',
-				"",
-				"### Parameters",
-				"- `protocol_fee_rate` - The rate that the pool will use to calculate protocol fees going onwards.",
-				"",
-				"#### Special Errors",
-				"- `ProtocolFeeRateMaxExceeded` - If the provided default_protocol_fee_rate exceeds MAX_PROTOCOL_FEE_RATE.",
-			],
-			accounts: [
 				{
-					name: "yevefisConfig",
+					name: "tokenMintB",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "yevefi",
+					name: "tokenOwnerAccountA",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefis_config"],
 				},
 				{
-					name: "feeAuthority",
-					isMut: false,
-					isSigner: true,
+					name: "tokenOwnerAccountB",
+					isMut: true,
+					isSigner: false,
 				},
-			],
-			args: [
 				{
-					name: "protocolFeeRate",
-					type: "u16",
+					name: "tokenVaultA",
+					isMut: true,
+					isSigner: false,
 				},
-			],
-		},
-		{
-			name: "setFeeAuthority",
-			docs: [
-				"Sets the fee authority for a YevefiConfig.",
-				"The fee authority can set the fee & protocol fee rate for individual pools or",
-				"set the default fee rate for newly minted pools.",
-				"Only the current fee authority has permission to invoke this instruction.",
-				"",
-				"### Authority",
-				'- "fee_authority" - Set authority that can modify pool fees in the YevefiConfig',
-			],
-			accounts: [
 				{
-					name: "yevefisConfig",
+					name: "tokenVaultB",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "feeAuthority",
-					isMut: false,
-					isSigner: true,
+					name: "tickArrayLower",
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: "newFeeAuthority",
-					isMut: false,
+					name: "tickArrayUpper",
+					isMut: true,
 					isSigner: false,
 				},
-			],
-			args: [],
-		},
-		{
-			name: "setCollectProtocolFeesAuthority",
-			docs: [
-				"Sets the fee authority to collect protocol fees for a YevefiConfig.",
-				"Only the current collect protocol fee authority has permission to invoke this instruction.",
-				"",
-				"### Authority",
-				'- "fee_authority" - Set authority that can collect protocol fees in the YevefiConfig',
-			],
-			accounts: [
 				{
-					name: "yevefisConfig",
+					name: "tickArray0",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "collectProtocolFeesAuthority",
-					isMut: false,
-					isSigner: true,
+					name: "tickArray1",
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: "newCollectProtocolFeesAuthority",
-					isMut: false,
+					name: "tickArray2",
+					isMut: true,
 					isSigner: false,
 				},
-			],
-			args: [],
-		},
-		{
-			name: "setRewardAuthority",
-			docs: [
-				"Set the yevefi reward authority at the provided `reward_index`.",
-				"Only the current reward authority for this reward index has permission to invoke this instruction.",
-				"",
-				"### Authority",
-				'- "reward_authority" - Set authority that can control reward emission for this particular reward.',
-				"",
-				"#### Special Errors",
-				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
-				"index in this pool, or exceeds NUM_REWARDS, or",
-				"all reward slots for this pool has been initialized.",
-			],
-			accounts: [
 				{
-					name: "yevefi",
+					name: "oracle",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "rewardAuthority",
+					name: "yevefisConfigExtension",
 					isMut: false,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "newRewardAuthority",
+					name: "positionLock",
 					isMut: false,
 					isSigner: false,
 				},
 			],
 			args: [
 				{
-					name: "rewardIndex",
-					type: "u8",
+					name: "liquidityAmount",
+					type: {
+						option: "u128",
+					},
+				},
+				{
+					name: "aToB",
+					type: "bool",
+				},
+				{
+					name: "minAmountOut",
+					type: "u64",
+				},
+				{
+					name: "remainingAccountsInfo",
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
+					},
 				},
 			],
 		},
 		{
-			name: "setRewardAuthorityBySuperAuthority",
+			name: "repositionLiquidity",
 			docs: [
-				"Set the yevefi reward authority at the provided `reward_index`.",
-				"Only the current reward super authority has permission to invoke this instruction.",
+				"Re-range a position with a new size in one step.",
+				"All liquidity is removed from the current range, the position is moved to the new range,",
+				"and `liquidity_amount` is added to it, which may be more or less than was removed.",
+				"Only the difference between the removed and added token amounts is transferred.",
+				"Fees and rewards accrued so far remain owed to the position.",
+				"The TokenMetadata of a position opened with token extensions is refreshed to the new range,",
+				"with the funder paying the rent if its fields grow.",
 				"",
 				"### Authority",
-				'- "reward_authority" - Set authority that can control reward emission for this particular reward.',
+				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"",
+				"### Parameters",
+				"- `new_tick_lower_index` - The tick specifying the lower end of the new position range.",
+				"- `new_tick_upper_index` - The tick specifying the upper end of the new position range.",
+				"- `liquidity_amount` - The amount of Liquidity to add to the new range.",
+				"- `token_max_a` - The maximum amount of tokenA the user is willing to deposit on top of the removed amount.",
+				"- `token_max_b` - The maximum amount of tokenB the user is willing to deposit on top of the removed amount.",
+				"- `token_min_a` - The minimum amount of tokenA the user is willing to withdraw, net of the added amount.",
+				"- `token_min_b` - The minimum amount of tokenB the user is willing to withdraw, net of the added amount.",
 				"",
 				"#### Special Errors",
-				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
-				"index in this pool, or exceeds NUM_REWARDS, or",
-				"all reward slots for this pool has been initialized.",
+				"- `LiquidityZero` - Provided liquidity amount is zero.",
+				"- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
+				"the tick-spacing in this pool.",
+				"- `SameTickRangeNotAllowed` - The new tick range is the current tick range.",
+				"- `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.",
+				"- `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.",
+				"- `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.",
+				"- `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.",
 			],
 			accounts: [
 				{
-					name: "yevefisConfig",
-					isMut: false,
-					isSigner: false,
+					name: "funder",
+					isMut: true,
+					isSigner: true,
 				},
 				{
 					name: "yevefi",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefis_config"],
 				},
 				{
-					name: "rewardEmissionsSuperAuthority",
+					name: "tokenProgramA",
 					isMut: false,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "newRewardAuthority",
+					name: "tokenProgramB",
 					isMut: false,
 					isSigner: false,
 				},
-			],
-			args: [
 				{
-					name: "rewardIndex",
-					type: "u8",
+					name: "memoProgram",
+					isMut: false,
+					isSigner: false,
 				},
-			],
-		},
-		{
-			name: "setRewardEmissionsSuperAuthority",
-			docs: [
-				"Set the yevefi reward super authority for a YevefiConfig",
-				"Only the current reward super authority has permission to invoke this instruction.",
-				"This instruction will not change the authority on any `YevefiRewardInfo` yevefi rewards.",
-				"",
-				"### Authority",
-				'- "reward_emissions_super_authority" - Set authority that can control reward authorities for all pools in this config space.',
-			],
-			accounts: [
 				{
-					name: "yevefisConfig",
+					name: "positionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "position",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "rewardEmissionsSuperAuthority",
+					name: "positionTokenAccount",
 					isMut: false,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "newRewardEmissionsSuperAuthority",
-					isMut: false,
+					name: "positionMint",
+					isMut: true,
 					isSigner: false,
 				},
-			],
-			args: [],
-		},
-		{
-			name: "twoHopSwap",
-			docs: [
-				"Perform a two-hop swap in this Yevefi",
-				"",
-				"### Authority",
-				'- "token_authority" - The authority to withdraw tokens from the input token account.',
-				"",
-				"### Parameters",
-				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
-				"- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
-				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
-				"- `a_to_b_one` - The direction of the swap of hop one. True if swapping from A to B. False if swapping from B to A.",
-				"- `a_to_b_two` - The direction of the swap of hop two. True if swapping from A to B. False if swapping from B to A.",
-				"- `sqrt_price_limit_one` - The maximum/minimum price the swap will swap to in the first hop.",
-				"- `sqrt_price_limit_two` - The maximum/minimum price the swap will swap to in the second hop.",
-				"",
-				"#### Special Errors",
-				"- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
-				"- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
-				"- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
-				"- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
-				"- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
-				"- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
-				"- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
-				"- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
-				"- `InvalidIntermediaryMint` - Error if the intermediary mint between hop one and two do not equal.",
-				"- `DuplicateTwoHopPool` - Error if yevefi one & two are the same pool.",
-			],
-			accounts: [
 				{
-					name: "tokenProgram",
+					name: "tokenMintA",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenAuthority",
+					name: "tokenMintB",
 					isMut: false,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "yevefiOne",
+					name: "tokenOwnerAccountA",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "yevefiTwo",
+					name: "tokenOwnerAccountB",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenOwnerAccountOneA",
+					name: "tokenVaultA",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenVaultOneA",
+					name: "tokenVaultB",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenOwnerAccountOneB",
+					name: "tickArrayLower",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenVaultOneB",
+					name: "tickArrayUpper",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenOwnerAccountTwoA",
+					name: "newTickArrayLower",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenVaultTwoA",
+					name: "newTickArrayUpper",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenOwnerAccountTwoB",
-					isMut: true,
+					name: "token2022Program",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenVaultTwoB",
-					isMut: true,
+					name: "systemProgram",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tickArrayOne0",
+					name: "oracle",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tickArrayOne1",
-					isMut: true,
+					name: "yevefisConfigExtension",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tickArrayOne2",
-					isMut: true,
+					name: "positionLock",
+					isMut: false,
 					isSigner: false,
 				},
+			],
+			args: [
 				{
-					name: "tickArrayTwo0",
-					isMut: true,
-					isSigner: false,
+					name: "newTickLowerIndex",
+					type: "i32",
 				},
 				{
-					name: "tickArrayTwo1",
-					isMut: true,
-					isSigner: false,
+					name: "newTickUpperIndex",
+					type: "i32",
 				},
 				{
-					name: "tickArrayTwo2",
-					isMut: true,
-					isSigner: false,
+					name: "liquidityAmount",
+					type: "u128",
+				},
+				{
+					name: "tokenMaxA",
+					type: "u64",
+				},
+				{
+					name: "tokenMaxB",
+					type: "u64",
+				},
+				{
+					name: "tokenMinA",
+					type: "u64",
+				},
+				{
+					name: "tokenMinB",
+					type: "u64",
+				},
+				{
+					name: "remainingAccountsInfo",
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
+					},
 				},
+			],
+		},
+		{
+			name: "initializePoolV2",
+			docs: [
+				"Initializes a Yevefi account.",
+				"Fee rate is set to the default values on the config and supplied fee_tier.",
+				"",
+				"### Parameters",
+				"- `bumps` - The bump value when deriving the PDA of the Yevefi address.",
+				"- `tick_spacing` - The desired tick spacing for this pool.",
+				"- `initial_sqrt_price` - The desired initial sqrt-price for this pool",
+				"",
+				"#### Special Errors",
+				"`InvalidTokenMintOrder` - The order of mints have to be ordered by",
+				"`SqrtPriceOutOfBounds` - provided initial_sqrt_price is not between 2^-64 to 2^64",
+				"",
+			],
+			accounts: [
 				{
-					name: "oracleOne",
+					name: "yevefisConfig",
 					isMut: false,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "oracle",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "Yevefi",
-								path: "yevefi_one",
-							},
-						],
-					},
 				},
 				{
-					name: "oracleTwo",
+					name: "tokenMintA",
 					isMut: false,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "oracle",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "Yevefi",
-								path: "yevefi_two",
-							},
-						],
-					},
-				},
-			],
-			args: [
-				{
-					name: "amount",
-					type: "u64",
-				},
-				{
-					name: "otherAmountThreshold",
-					type: "u64",
-				},
-				{
-					name: "amountSpecifiedIsInput",
-					type: "bool",
 				},
 				{
-					name: "aToBOne",
-					type: "bool",
+					name: "tokenMintB",
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: "aToBTwo",
-					type: "bool",
+					name: "tokenBadgeA",
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: "sqrtPriceLimitOne",
-					type: "u128",
+					name: "tokenBadgeB",
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: "sqrtPriceLimitTwo",
-					type: "u128",
+					name: "funder",
+					isMut: true,
+					isSigner: true,
 				},
-			],
-		},
-		{
-			name: "initializePositionBundle",
-			docs: [
-				"Initializes a PositionBundle account that bundles several positions.",
-				"A unique token will be minted to represent the position bundle in the users wallet.",
-			],
-			accounts: [
 				{
-					name: "positionBundle",
+					name: "yevefi",
 					isMut: true,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "position_bundle",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "Mint",
-								path: "position_bundle_mint",
-							},
-						],
-					},
 				},
 				{
-					name: "positionBundleMint",
+					name: "tokenVaultA",
 					isMut: true,
 					isSigner: true,
 				},
 				{
-					name: "positionBundleTokenAccount",
+					name: "tokenVaultB",
 					isMut: true,
-					isSigner: false,
+					isSigner: true,
 				},
 				{
-					name: "positionBundleOwner",
+					name: "feeTier",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "funder",
-					isMut: true,
-					isSigner: true,
+					name: "tokenProgramA",
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: "tokenProgram",
+					name: "tokenProgramB",
 					isMut: false,
 					isSigner: false,
 				},
@@ -6049,77 +13584,68 @@ export const IDL: Yevefi = {
 					isMut: false,
 					isSigner: false,
 				},
+			],
+			args: [
+				{
+					name: "tickSpacing",
+					type: "u16",
+				},
 				{
-					name: "associatedTokenProgram",
-					isMut: false,
-					isSigner: false,
+					name: "initialSqrtPrice",
+					type: "u128",
 				},
 			],
-			args: [],
 		},
 		{
-			name: "initializePositionBundleWithMetadata",
+			name: "initializeRewardV2",
 			docs: [
-				"Initializes a PositionBundle account that bundles several positions.",
-				"A unique token will be minted to represent the position bundle in the users wallet.",
-				"Additional Metaplex metadata is appended to identify the token.",
+				"Initialize reward for a Yevefi. A pool can only support up to a set number of rewards.",
+				"",
+				"### Authority",
+				'- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+				"reward-index in this Yevefi",
+				"",
+				"### Parameters",
+				"- `reward_index` - The reward index that we'd like to initialize. (0 <= index <= NUM_REWARDS)",
+				"",
+				"#### Special Errors",
+				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+				"index in this pool, or exceeds NUM_REWARDS, or",
+				"all reward slots for this pool has been initialized.",
 			],
 			accounts: [
 				{
-					name: "positionBundle",
-					isMut: true,
-					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "position_bundle",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "Mint",
-								path: "position_bundle_mint",
-							},
-						],
-					},
+					name: "rewardAuthority",
+					isMut: false,
+					isSigner: true,
 				},
 				{
-					name: "positionBundleMint",
+					name: "funder",
 					isMut: true,
 					isSigner: true,
 				},
 				{
-					name: "positionBundleMetadata",
+					name: "yevefi",
 					isMut: true,
 					isSigner: false,
-					docs: [
-						"https://github.com/metaplex-foundation/metaplex-program-library/blob/773a574c4b34e5b9f248a81306ec24db064e255f/token-metadata/program/src/utils/metadata.rs#L100",
-					],
 				},
 				{
-					name: "positionBundleTokenAccount",
-					isMut: true,
+					name: "rewardMint",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "positionBundleOwner",
+					name: "rewardTokenBadge",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "funder",
+					name: "rewardVault",
 					isMut: true,
 					isSigner: true,
 				},
 				{
-					name: "metadataUpdateAuth",
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: "tokenProgram",
+					name: "rewardTokenProgram",
 					isMut: false,
 					isSigner: false,
 				},
@@ -6133,297 +13659,261 @@ export const IDL: Yevefi = {
 					isMut: false,
 					isSigner: false,
 				},
+			],
+			args: [
 				{
-					name: "associatedTokenProgram",
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: "metadataProgram",
-					isMut: false,
-					isSigner: false,
+					name: "rewardIndex",
+					type: "u8",
 				},
 			],
-			args: [],
 		},
 		{
-			name: "deletePositionBundle",
+			name: "setRewardEmissionsV2",
 			docs: [
-				"Delete a PositionBundle account. Burns the position bundle token in the owner's wallet.",
+				"Set the reward emissions for a reward in a Yevefi.",
+				"The emission schedule of the reward, if any, is replaced by the constant emission rate.",
+				"The reward vault funds the emissions from now on, which stop once the funds run out.",
 				"",
 				"### Authority",
-				"- `position_bundle_owner` - The owner that owns the position bundle token.",
+				'- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+				"reward-index in this Yevefi",
 				"",
-				"### Special Errors",
-				"- `PositionBundleNotDeletable` - The provided position bundle has open positions.",
+				"### Parameters",
+				"- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.",
+				"- `emissions_per_second_x64` - The amount of rewards emitted in this pool.",
+				"",
+				"#### Special Errors",
+				"- `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit",
+				"more than a day of desired emissions.",
+				"- `InvalidTimestamp` - Provided timestamp is not in order with the previous timestamp.",
+				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+				"index in this pool, or exceeds NUM_REWARDS, or",
+				"all reward slots for this pool has been initialized.",
 			],
 			accounts: [
 				{
-					name: "positionBundle",
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: "positionBundleMint",
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: "positionBundleTokenAccount",
+					name: "yevefi",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "positionBundleOwner",
+					name: "rewardAuthority",
 					isMut: false,
 					isSigner: true,
 				},
 				{
-					name: "receiver",
-					isMut: true,
+					name: "rewardVault",
+					isMut: false,
 					isSigner: false,
 				},
+			],
+			args: [
+				{
+					name: "rewardIndex",
+					type: "u8",
+				},
 				{
-					name: "tokenProgram",
-					isMut: false,
-					isSigner: false,
+					name: "emissionsPerSecondX64",
+					type: "u128",
 				},
 			],
-			args: [],
 		},
 		{
-			name: "openBundledPosition",
+			name: "setRewardEmissionsSchedule",
 			docs: [
-				"Open a bundled position in a Yevefi. No new tokens are issued",
-				"because the owner of the position bundle becomes the owner of the position.",
-				"The position will start off with 0 liquidity.",
+				"Set an emission schedule for a reward in a Yevefi, so that emissions start, change and stop",
+				"automatically. Rewards are accrued under the previous schedule until the current timestamp.",
+				"`set_reward_emissions` replaces the schedule with a constant emission rate.",
+				"The reward vault funds the emissions from now on, which stop once the funds run out.",
 				"",
 				"### Authority",
-				"- `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.",
+				'- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+				"reward-index in this Yevefi",
 				"",
 				"### Parameters",
-				"- `bundle_index` - The bundle index that we'd like to open.",
-				"- `tick_lower_index` - The tick specifying the lower end of the position range.",
-				"- `tick_upper_index` - The tick specifying the upper end of the position range.",
+				"- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.",
+				"- `emissions_per_second_x64` - The amount of rewards emitted in this pool from `start_timestamp`.",
+				"- `start_timestamp` - No rewards are emitted before this timestamp.",
+				"- `end_timestamp` - No rewards are emitted from this timestamp on. 0 if the emissions have no end.",
+				"- `rate_changes` - Up to MAX_REWARD_EMISSIONS_RATE_CHANGES emission rate changes, in ascending",
+				"timestamp order, between `start_timestamp` and `end_timestamp`.",
 				"",
 				"#### Special Errors",
-				"- `InvalidBundleIndex` - If the provided bundle index is out of bounds.",
-				"- `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of",
-				"the tick-spacing in this pool.",
+				"- `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit",
+				"more than a day of the highest emissions of the schedule.",
+				"- `InvalidRewardEmissionsSchedule` - The timestamps of the schedule are not in order, or there",
+				"are too many rate changes.",
+				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+				"index in this pool, or exceeds NUM_REWARDS, or",
+				"all reward slots for this pool has been initialized.",
 			],
 			accounts: [
 				{
-					name: "bundledPosition",
-					isMut: true,
-					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "bundled_position",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "PositionBundle",
-								path: "position_bundle.position_bundle_mint",
-							},
-							{
-								kind: "arg",
-								type: "u16",
-								path: "bundle_index",
-							},
-						],
-					},
-				},
-				{
-					name: "positionBundle",
+					name: "yevefi",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "positionBundleTokenAccount",
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: "positionBundleAuthority",
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: "yevefi",
+					name: "rewardAuthority",
 					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: "funder",
-					isMut: true,
 					isSigner: true,
 				},
 				{
-					name: "systemProgram",
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: "rent",
+					name: "rewardVault",
 					isMut: false,
 					isSigner: false,
 				},
 			],
 			args: [
 				{
-					name: "bundleIndex",
-					type: "u16",
+					name: "rewardIndex",
+					type: "u8",
 				},
 				{
-					name: "tickLowerIndex",
-					type: "i32",
+					name: "emissionsPerSecondX64",
+					type: "u128",
 				},
 				{
-					name: "tickUpperIndex",
-					type: "i32",
+					name: "startTimestamp",
+					type: "u64",
+				},
+				{
+					name: "endTimestamp",
+					type: "u64",
+				},
+				{
+					name: "rateChanges",
+					type: {
+						vec: {
+							defined: "RewardEmissionsRateChange",
+						},
+					},
 				},
 			],
 		},
 		{
-			name: "closeBundledPosition",
+			name: "fundReward",
 			docs: [
-				"Close a bundled position in a Yevefi.",
-				"",
-				"### Authority",
-				"- `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.",
+				"Deposit tokens into the vault of a reward. Anyone can fund a reward.",
+				"The amount received by the vault is recorded as funds, which are emitted from now on at the",
+				"emission rate set by the reward authority.",
 				"",
 				"### Parameters",
-				"- `bundle_index` - The bundle index that we'd like to close.",
+				"- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) to fund.",
+				"- `amount` - The amount of reward tokens to deposit, transfer fee included.",
+				"- `extend_emissions` - If true, the end of the emission schedule is moved back by the time",
+				"the received amount lasts at the emission rate before the end.",
 				"",
 				"#### Special Errors",
-				"- `InvalidBundleIndex` - If the provided bundle index is out of bounds.",
-				"- `ClosePositionNotEmpty` - The provided position account is not empty.",
+				"- `RewardEmissionsNotExtendable` - If `extend_emissions` is set, but the emissions have no end",
+				"or have already ended.",
 			],
 			accounts: [
 				{
-					name: "bundledPosition",
+					name: "yevefi",
 					isMut: true,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "bundled_position",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "PositionBundle",
-								path: "position_bundle.position_bundle_mint",
-							},
-							{
-								kind: "arg",
-								type: "u16",
-								path: "bundle_index",
-							},
-						],
-					},
 				},
 				{
-					name: "positionBundle",
+					name: "funder",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "funderTokenAccount",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "positionBundleTokenAccount",
+					name: "rewardMint",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "positionBundleAuthority",
+					name: "rewardVault",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "rewardTokenProgram",
 					isMut: false,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "receiver",
-					isMut: true,
+					name: "memoProgram",
+					isMut: false,
 					isSigner: false,
 				},
 			],
 			args: [
 				{
-					name: "bundleIndex",
-					type: "u16",
+					name: "rewardIndex",
+					type: "u8",
+				},
+				{
+					name: "amount",
+					type: "u64",
+				},
+				{
+					name: "extendEmissions",
+					type: "bool",
+				},
+				{
+					name: "remainingAccountsInfo",
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
+					},
 				},
 			],
 		},
 		{
-			name: "collectFeesV2",
+			name: "withdrawRewardSurplus",
 			docs: [
-				"Collect fees accrued for this position.",
+				"Withdraw tokens from the vault of a reward, up to the surplus over the rewards owed to positions.",
+				"The surplus is the funds of the reward not emitted yet, so that all positions can still",
+				"collect the rewards emitted to them, rounded up. The withdrawn amount no longer funds emissions.",
 				"",
 				"### Authority",
-				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				'- "reward_authority" - assigned authority by the reward_super_authority for the specified',
+				"reward-index in this Yevefi",
+				"",
+				"### Parameters",
+				"- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) to withdraw from.",
+				"- `amount` - The amount of reward tokens to withdraw.",
+				"",
+				"#### Special Errors",
+				"- `RewardSurplusInsufficient` - The amount exceeds the surplus of the reward vault.",
 			],
 			accounts: [
 				{
 					name: "yevefi",
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: "positionAuthority",
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: "position",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
-				},
-				{
-					name: "positionTokenAccount",
-					isMut: false,
-					isSigner: false,
 				},
 				{
-					name: "tokenMintA",
+					name: "rewardAuthority",
 					isMut: false,
-					isSigner: false,
+					isSigner: true,
 				},
 				{
-					name: "tokenMintB",
+					name: "rewardMint",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenOwnerAccountA",
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: "tokenVaultA",
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: "tokenOwnerAccountB",
+					name: "rewardVault",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenVaultB",
+					name: "destinationTokenAccount",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenProgramA",
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: "tokenProgramB",
+					name: "rewardTokenProgram",
 					isMut: false,
 					isSigner: false,
 				},
@@ -6434,6 +13924,14 @@ export const IDL: Yevefi = {
 				},
 			],
 			args: [
+				{
+					name: "rewardIndex",
+					type: "u8",
+				},
+				{
+					name: "amount",
+					type: "u64",
+				},
 				{
 					name: "remainingAccountsInfo",
 					type: {
@@ -6445,94 +13943,145 @@ export const IDL: Yevefi = {
 			],
 		},
 		{
-			name: "collectProtocolFeesV2",
+			name: "initializeExtensionReward",
 			docs: [
-				"Collect the protocol fees accrued in this Yevefi",
+				"Initialize an extension reward for a Yevefi with a reward extension.",
 				"",
 				"### Authority",
-				"- `collect_protocol_fees_authority` - assigned authority in the YevefiConfig that can collect protocol fees",
+				'- "reward_authority" - assigned authority of the extension reward at the specified reward-index.',
+				"",
+				"### Parameters",
+				"- `reward_index` - The extension reward index that we'd like to initialize. (0 <= index < NUM_EXTENSION_REWARDS)",
+				"",
+				"#### Special Errors",
+				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
+				"index of the reward extension, or exceeds NUM_EXTENSION_REWARDS.",
 			],
 			accounts: [
 				{
-					name: "yevefisConfig",
+					name: "rewardAuthority",
 					isMut: false,
-					isSigner: false,
+					isSigner: true,
 				},
 				{
-					name: "yevefi",
+					name: "funder",
 					isMut: true,
-					isSigner: false,
-					relations: ["yevefis_config"],
+					isSigner: true,
 				},
 				{
-					name: "collectProtocolFeesAuthority",
+					name: "yevefi",
 					isMut: false,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "tokenMintA",
-					isMut: false,
+					name: "yevefiRewardExtension",
+					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenMintB",
+					name: "rewardMint",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenVaultA",
-					isMut: true,
+					name: "rewardTokenBadge",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenVaultB",
+					name: "rewardVault",
 					isMut: true,
-					isSigner: false,
+					isSigner: true,
 				},
 				{
-					name: "tokenDestinationA",
-					isMut: true,
+					name: "rewardTokenProgram",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenDestinationB",
-					isMut: true,
+					name: "systemProgram",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenProgramA",
+					name: "rent",
 					isMut: false,
 					isSigner: false,
 				},
+			],
+			args: [
 				{
-					name: "tokenProgramB",
+					name: "rewardIndex",
+					type: "u8",
+				},
+			],
+		},
+		{
+			name: "setExtensionRewardEmissions",
+			docs: [
+				"Set the emissions of an extension reward of a Yevefi.",
+				"",
+				"### Authority",
+				'- "reward_authority" - assigned authority of the extension reward at the specified reward-index.',
+				"",
+				"### Parameters",
+				"- `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS) that we'd like to modify.",
+				"- `emissions_per_second_x64` - The amount of rewards emitted in this pool.",
+				"",
+				"#### Special Errors",
+				"- `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit",
+				"more than a day of desired emissions.",
+				"- `InvalidTimestamp` - Provided timestamp is not in order with the previous timestamp.",
+				"- `InvalidRewardIndex` - If the provided reward index exceeds NUM_EXTENSION_REWARDS.",
+			],
+			accounts: [
+				{
+					name: "yevefi",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "memoProgram",
+					name: "yevefiRewardExtension",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "rewardAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "rewardVault",
 					isMut: false,
 					isSigner: false,
 				},
 			],
 			args: [
 				{
-					name: "remainingAccountsInfo",
-					type: {
-						option: {
-							defined: "RemainingAccountsInfo",
-						},
-					},
+					name: "rewardIndex",
+					type: "u8",
+				},
+				{
+					name: "emissionsPerSecondX64",
+					type: "u128",
 				},
 			],
 		},
 		{
-			name: "collectRewardV2",
+			name: "collectExtensionReward",
 			docs: [
-				"Collect rewards accrued for this position.",
+				"Collect the extension rewards owed to a position.",
 				"",
 				"### Authority",
 				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				"A PositionDelegate with the collect rewards permission can also authorize this call,",
+				"passed as a `PositionDelegate` remaining accounts slice.",
+				"",
+				"### Parameters",
+				"- `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS) to collect.",
+				"",
+				"#### Special Errors",
+				"- `PositionDelegateInvalidDestination` - A PositionDelegate transfers tokens to an account not owned by the position owner.",
 			],
 			accounts: [
 				{
@@ -6540,6 +14089,11 @@ export const IDL: Yevefi = {
 					isMut: false,
 					isSigner: false,
 				},
+				{
+					name: "yevefiRewardExtension",
+					isMut: true,
+					isSigner: false,
+				},
 				{
 					name: "positionAuthority",
 					isMut: false,
@@ -6547,15 +14101,19 @@ export const IDL: Yevefi = {
 				},
 				{
 					name: "position",
-					isMut: true,
+					isMut: false,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 				{
 					name: "positionTokenAccount",
 					isMut: false,
 					isSigner: false,
 				},
+				{
+					name: "positionRewardExtension",
+					isMut: true,
+					isSigner: false,
+				},
 				{
 					name: "rewardOwnerAccount",
 					isMut: true,
@@ -6598,29 +14156,38 @@ export const IDL: Yevefi = {
 			],
 		},
 		{
-			name: "decreaseLiquidityV2",
+			name: "swapV2",
 			docs: [
-				"Withdraw liquidity from a position in the Yevefi. This call also updates the position's accrued fees and rewards.",
+				"Perform a swap in this Yevefi",
 				"",
 				"### Authority",
-				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				'- "token_authority" - The authority to withdraw tokens from the input token account.',
 				"",
 				"### Parameters",
-				"- `liquidity_amount` - The total amount of Liquidity the user desires to withdraw.",
-				"- `token_min_a` - The minimum amount of tokenA the user is willing to withdraw.",
-				"- `token_min_b` - The minimum amount of tokenB the user is willing to withdraw.",
+				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
+				"- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
+				"- `sqrt_price_limit` - The maximum/minimum price the swap will swap to.",
+				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
+				"- `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.",
 				"",
 				"#### Special Errors",
-				"- `LiquidityZero` - Provided liquidity amount is zero.",
-				"- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
-				"- `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.",
+				"- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
+				"- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
+				"- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
+				"- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
+				"- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
+				"- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
+				"- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
+				"- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
+				"- `InvalidReferralAccounts` - The referral accounts are not the YevefisConfig of the pool and",
+				"a Referrer of it, followed by a writable token account of the",
+				"input mint owned by the referrer.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `InvalidRewardExtensionAccounts` - The Yevefi has a reward extension, and the RewardExtension",
+				"accounts are missing or do not cover the crossed ticks.",
 			],
 			accounts: [
-				{
-					name: "yevefi",
-					isMut: true,
-					isSigner: false,
-				},
 				{
 					name: "tokenProgramA",
 					isMut: false,
@@ -6637,20 +14204,14 @@ export const IDL: Yevefi = {
 					isSigner: false,
 				},
 				{
-					name: "positionAuthority",
+					name: "tokenAuthority",
 					isMut: false,
 					isSigner: true,
 				},
 				{
-					name: "position",
+					name: "yevefi",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
-				},
-				{
-					name: "positionTokenAccount",
-					isMut: false,
-					isSigner: false,
 				},
 				{
 					name: "tokenMintA",
@@ -6668,12 +14229,12 @@ export const IDL: Yevefi = {
 					isSigner: false,
 				},
 				{
-					name: "tokenOwnerAccountB",
+					name: "tokenVaultA",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenVaultA",
+					name: "tokenOwnerAccountB",
 					isMut: true,
 					isSigner: false,
 				},
@@ -6683,30 +14244,46 @@ export const IDL: Yevefi = {
 					isSigner: false,
 				},
 				{
-					name: "tickArrayLower",
+					name: "tickArray0",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 				{
-					name: "tickArrayUpper",
+					name: "tickArray1",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tickArray2",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "oracle",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 			],
 			args: [
 				{
-					name: "liquidityAmount",
-					type: "u128",
+					name: "amount",
+					type: "u64",
 				},
 				{
-					name: "tokenMinA",
+					name: "otherAmountThreshold",
 					type: "u64",
 				},
 				{
-					name: "tokenMinB",
-					type: "u64",
+					name: "sqrtPriceLimit",
+					type: "u128",
+				},
+				{
+					name: "amountSpecifiedIsInput",
+					type: "bool",
+				},
+				{
+					name: "aToB",
+					type: "bool",
 				},
 				{
 					name: "remainingAccountsInfo",
@@ -6719,440 +14296,491 @@ export const IDL: Yevefi = {
 			],
 		},
 		{
-			name: "increaseLiquidityV2",
+			name: "twoHopSwapV2",
 			docs: [
-				"Add liquidity to a position in the Yevefi. This call also updates the position's accrued fees and rewards.",
+				"Perform a two-hop swap in this Yevefi",
 				"",
 				"### Authority",
-				"- `position_authority` - authority that owns the token corresponding to this desired position.",
+				'- "token_authority" - The authority to withdraw tokens from the input token account.',
 				"",
 				"### Parameters",
-				"- `liquidity_amount` - The total amount of Liquidity the user is willing to deposit.",
-				"- `token_max_a` - The maximum amount of tokenA the user is willing to deposit.",
-				"- `token_max_b` - The maximum amount of tokenB the user is willing to deposit.",
+				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
+				"- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
+				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
+				"- `a_to_b_one` - The direction of the swap of hop one. True if swapping from A to B. False if swapping from B to A.",
+				"- `a_to_b_two` - The direction of the swap of hop two. True if swapping from A to B. False if swapping from B to A.",
+				"- `sqrt_price_limit_one` - The maximum/minimum price the swap will swap to in the first hop.",
+				"- `sqrt_price_limit_two` - The maximum/minimum price the swap will swap to in the second hop.",
 				"",
 				"#### Special Errors",
-				"- `LiquidityZero` - Provided liquidity amount is zero.",
-				"- `LiquidityTooHigh` - Provided liquidity exceeds u128::max.",
-				"- `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.",
+				"- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
+				"- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
+				"- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
+				"- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
+				"- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
+				"- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
+				"- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
+				"- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
+				"- `InvalidIntermediaryMint` - Error if the intermediary mint between hop one and two do not equal.",
+				"- `DuplicateTwoHopPool` - Error if yevefi one & two are the same pool.",
+				"- `InvalidReferralAccounts` - The referral accounts are not the YevefisConfig shared by both",
+				"pools and a Referrer of it, followed by writable token accounts",
+				"of the input and intermediate mints owned by the referrer.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of one of the Yevefis is initialized.",
 			],
 			accounts: [
 				{
-					name: "yevefi",
+					name: "yevefiOne",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenProgramA",
-					isMut: false,
+					name: "yevefiTwo",
+					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenProgramB",
+					name: "tokenMintInput",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "memoProgram",
+					name: "tokenMintIntermediate",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "positionAuthority",
+					name: "tokenMintOutput",
 					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: "position",
-					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 				{
-					name: "positionTokenAccount",
+					name: "tokenProgramInput",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenMintA",
+					name: "tokenProgramIntermediate",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenMintB",
+					name: "tokenProgramOutput",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenOwnerAccountA",
+					name: "tokenOwnerAccountInput",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenOwnerAccountB",
+					name: "tokenVaultOneInput",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenVaultA",
+					name: "tokenVaultOneIntermediate",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenVaultB",
+					name: "tokenVaultTwoIntermediate",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tickArrayLower",
+					name: "tokenVaultTwoOutput",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 				{
-					name: "tickArrayUpper",
+					name: "tokenOwnerAccountOutput",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
-			],
-			args: [
 				{
-					name: "liquidityAmount",
-					type: "u128",
+					name: "tokenAuthority",
+					isMut: false,
+					isSigner: true,
 				},
 				{
-					name: "tokenMaxA",
-					type: "u64",
+					name: "tickArrayOne0",
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: "tokenMaxB",
-					type: "u64",
+					name: "tickArrayOne1",
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: "remainingAccountsInfo",
-					type: {
-						option: {
-							defined: "RemainingAccountsInfo",
-						},
-					},
+					name: "tickArrayOne2",
+					isMut: true,
+					isSigner: false,
 				},
-			],
-		},
-		{
-			name: "initializePoolV2",
-			docs: [
-				"Initializes a Yevefi account.",
-				"Fee rate is set to the default values on the config and supplied fee_tier.",
-				"",
-				"### Parameters",
-				"- `bumps` - The bump value when deriving the PDA of the Yevefi address.",
-				"- `tick_spacing` - The desired tick spacing for this pool.",
-				"- `initial_sqrt_price` - The desired initial sqrt-price for this pool",
-				"",
-				"#### Special Errors",
-				"`InvalidTokenMintOrder` - The order of mints have to be ordered by",
-				"`SqrtPriceOutOfBounds` - provided initial_sqrt_price is not between 2^-64 to 2^64",
-				"",
-			],
-			accounts: [
 				{
-					name: "yevefisConfig",
-					isMut: false,
+					name: "tickArrayTwo0",
+					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenMintA",
-					isMut: false,
+					name: "tickArrayTwo1",
+					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenMintB",
-					isMut: false,
+					name: "tickArrayTwo2",
+					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenBadgeA",
-					isMut: false,
+					name: "oracleOne",
+					isMut: true,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "token_badge",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "YevefisConfig",
-								path: "yevefis_config",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								path: "token_mint_a",
-							},
-						],
-					},
 				},
 				{
-					name: "tokenBadgeB",
+					name: "oracleTwo",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "memoProgram",
 					isMut: false,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "token_badge",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "YevefisConfig",
-								path: "yevefis_config",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								path: "token_mint_b",
-							},
-						],
-					},
 				},
+			],
+			args: [
 				{
-					name: "funder",
-					isMut: true,
-					isSigner: true,
+					name: "amount",
+					type: "u64",
 				},
 				{
-					name: "yevefi",
-					isMut: true,
-					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "yevefi",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "YevefisConfig",
-								path: "yevefis_config",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								path: "token_mint_a",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								path: "token_mint_b",
-							},
-							{
-								kind: "arg",
-								type: "u16",
-								path: "tick_spacing",
-							},
-						],
+					name: "otherAmountThreshold",
+					type: "u64",
+				},
+				{
+					name: "amountSpecifiedIsInput",
+					type: "bool",
+				},
+				{
+					name: "aToBOne",
+					type: "bool",
+				},
+				{
+					name: "aToBTwo",
+					type: "bool",
+				},
+				{
+					name: "sqrtPriceLimitOne",
+					type: "u128",
+				},
+				{
+					name: "sqrtPriceLimitTwo",
+					type: "u128",
+				},
+				{
+					name: "remainingAccountsInfo",
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
 					},
 				},
+			],
+		},
+		{
+			name: "quoteSwap",
+			docs: [
+				"Simulate a swap in this Yevefi without writing to any account.",
+				"The result is a `SwapQuote` set as return data, so the instruction can be used by other",
+				"programs via CPI to learn the outcome of a swap before executing it.",
+				"If referral accounts are supplied, the quote includes the referral fee of the swap.",
+				"",
+				"### Parameters",
+				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
+				"- `sqrt_price_limit` - The maximum/minimum price the swap will swap to.",
+				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
+				"- `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.",
+				"",
+				"#### Special Errors",
+				"Same as `swap_v2`, except that tick-arrays don't need to be writable.",
+			],
+			accounts: [
 				{
-					name: "tokenVaultA",
-					isMut: true,
-					isSigner: true,
+					name: "yevefi",
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: "tokenVaultB",
-					isMut: true,
-					isSigner: true,
+					name: "tokenMintA",
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: "feeTier",
+					name: "tokenMintB",
 					isMut: false,
 					isSigner: false,
-					relations: ["yevefis_config"],
 				},
 				{
-					name: "tokenProgramA",
+					name: "tickArray0",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenProgramB",
+					name: "tickArray1",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "systemProgram",
+					name: "tickArray2",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "rent",
+					name: "yevefisConfigExtension",
 					isMut: false,
 					isSigner: false,
 				},
 			],
 			args: [
 				{
-					name: "tickSpacing",
-					type: "u16",
+					name: "amount",
+					type: "u64",
 				},
 				{
-					name: "initialSqrtPrice",
+					name: "sqrtPriceLimit",
 					type: "u128",
 				},
+				{
+					name: "amountSpecifiedIsInput",
+					type: "bool",
+				},
+				{
+					name: "aToB",
+					type: "bool",
+				},
+				{
+					name: "remainingAccountsInfo",
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
+					},
+				},
 			],
 		},
 		{
-			name: "initializeRewardV2",
+			name: "nHopSwapV2",
 			docs: [
-				"Initialize reward for a Yevefi. A pool can only support up to a set number of rewards.",
+				"Perform a swap along a route of up to four Yevefis.",
+				"Intermediate tokens are transferred directly from vault to vault.",
 				"",
 				"### Authority",
-				'- "reward_authority" - assigned authority by the reward_super_authority for the specified',
-				"reward-index in this Yevefi",
+				'- "token_authority" - The authority to withdraw tokens from the input token account.',
 				"",
 				"### Parameters",
-				"- `reward_index` - The reward index that we'd like to initialize. (0 <= index <= NUM_REWARDS)",
+				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
+				"- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
+				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
+				"- `route` - The direction and the maximum/minimum price of each hop, in route order.",
 				"",
 				"#### Special Errors",
-				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
-				"index in this pool, or exceeds NUM_REWARDS, or",
-				"all reward slots for this pool has been initialized.",
+				"- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
+				"- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
+				"- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
+				"- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
+				"- `InvalidSwapRouteLength` - User provided parameter `route` is empty or has more than four hops.",
+				"- `InvalidIntermediaryMint` - Error if the output mint of a hop does not equal the input mint of the next hop.",
+				"- `DuplicateTwoHopPool` - Error if the same yevefi appears more than once in the route.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of one of the Yevefis is initialized.",
 			],
 			accounts: [
 				{
-					name: "rewardAuthority",
+					name: "tokenAuthority",
 					isMut: false,
 					isSigner: true,
 				},
 				{
-					name: "funder",
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: "yevefi",
-					isMut: true,
-					isSigner: false,
-				},
-				{
-					name: "rewardMint",
+					name: "tokenMintInput",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "rewardTokenBadge",
+					name: "tokenProgramInput",
 					isMut: false,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "token_badge",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "Yevefi",
-								path: "yevefi.yevefis_config",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								path: "reward_mint",
-							},
-						],
-					},
 				},
 				{
-					name: "rewardVault",
+					name: "tokenOwnerAccountInput",
 					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: "rewardTokenProgram",
-					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "systemProgram",
-					isMut: false,
+					name: "tokenOwnerAccountOutput",
+					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "rent",
+					name: "memoProgram",
 					isMut: false,
 					isSigner: false,
 				},
 			],
 			args: [
 				{
-					name: "rewardIndex",
-					type: "u8",
+					name: "amount",
+					type: "u64",
+				},
+				{
+					name: "otherAmountThreshold",
+					type: "u64",
+				},
+				{
+					name: "amountSpecifiedIsInput",
+					type: "bool",
+				},
+				{
+					name: "route",
+					type: {
+						vec: {
+							defined: "SwapRouteHop",
+						},
+					},
+				},
+				{
+					name: "remainingAccountsInfo",
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
+					},
 				},
 			],
 		},
 		{
-			name: "setRewardEmissionsV2",
+			name: "flashLoanV2",
 			docs: [
-				"Set the reward emissions for a reward in a Yevefi.",
+				"Borrow tokens from the vaults of this Yevefi and repay them with a fee within the same instruction.",
+				"The borrowed tokens are transferred to the token owner accounts, then `yevefi_flash_loan_callback`",
+				"of the callback program is invoked, then the borrowed amounts plus fees are transferred back",
+				"from the token owner accounts.",
 				"",
 				"### Authority",
-				'- "reward_authority" - assigned authority by the reward_super_authority for the specified',
-				"reward-index in this Yevefi",
+				'- "token_authority" - The authority to withdraw the repayment from the token owner accounts.',
 				"",
 				"### Parameters",
-				"- `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.",
-				"- `emissions_per_second_x64` - The amount of rewards emitted in this pool.",
+				"- `amount_a` - The amount of token A to borrow.",
+				"- `amount_b` - The amount of token B to borrow.",
+				"- `callback_data` - Arbitrary data passed through to the callback instruction.",
 				"",
 				"#### Special Errors",
-				"- `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit",
-				"more than a day of desired emissions.",
-				"- `InvalidTimestamp` - Provided timestamp is not in order with the previous timestamp.",
-				"- `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized",
-				"index in this pool, or exceeds NUM_REWARDS, or",
-				"all reward slots for this pool has been initialized.",
+				"- `ZeroTradableAmount` - User provided parameters `amount_a` and `amount_b` are both 0.",
+				"- `YevefiLocked` - A flash loan or flash swap of this Yevefi is already in progress.",
+				"- `FlashNotRepaid` - The vaults hold less than before the loan plus fees after repayment.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
 			],
 			accounts: [
+				{
+					name: "tokenProgramA",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenProgramB",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "memoProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenAuthority",
+					isMut: false,
+					isSigner: true,
+				},
 				{
 					name: "yevefi",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "rewardAuthority",
+					name: "tokenMintA",
 					isMut: false,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "rewardVault",
+					name: "tokenMintB",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenOwnerAccountA",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenVaultA",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenOwnerAccountB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "tokenVaultB",
+					isMut: true,
+					isSigner: false,
+				},
+				{
+					name: "callbackProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "yevefisConfigExtension",
 					isMut: false,
 					isSigner: false,
 				},
 			],
 			args: [
 				{
-					name: "rewardIndex",
-					type: "u8",
+					name: "amountA",
+					type: "u64",
 				},
 				{
-					name: "emissionsPerSecondX64",
-					type: "u128",
+					name: "amountB",
+					type: "u64",
+				},
+				{
+					name: "callbackData",
+					type: "bytes",
+				},
+				{
+					name: "remainingAccountsInfo",
+					type: {
+						option: {
+							defined: "RemainingAccountsInfo",
+						},
+					},
 				},
 			],
 		},
 		{
-			name: "swapV2",
+			name: "flashSwapV2",
 			docs: [
-				"Perform a swap in this Yevefi",
+				"Perform a swap in this Yevefi, receiving the output before paying the input.",
+				"The output is transferred to the token owner account, then `yevefi_flash_swap_callback`",
+				"of the callback program is invoked, then the input is transferred from the token owner account.",
 				"",
 				"### Authority",
 				'- "token_authority" - The authority to withdraw tokens from the input token account.',
@@ -7163,16 +14791,17 @@ export const IDL: Yevefi = {
 				"- `sqrt_price_limit` - The maximum/minimum price the swap will swap to.",
 				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
 				"- `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.",
+				"- `callback_data` - Arbitrary data passed through to the callback instruction.",
 				"",
 				"#### Special Errors",
 				"- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
 				"- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
 				"- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
-				"- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
-				"- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
-				"- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
-				"- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
-				"- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
+				"- `YevefiLocked` - A flash loan or flash swap of this Yevefi is already in progress.",
+				"- `FlashNotRepaid` - The input vault did not receive the input amount of the swap.",
+				"- `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.",
+				"- `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.",
+				"- `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.",
 			],
 			accounts: [
 				{
@@ -7234,39 +14863,31 @@ export const IDL: Yevefi = {
 					name: "tickArray0",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 				{
 					name: "tickArray1",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 				{
 					name: "tickArray2",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefi"],
 				},
 				{
 					name: "oracle",
 					isMut: true,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "oracle",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "Yevefi",
-								path: "yevefi",
-							},
-						],
-					},
+				},
+				{
+					name: "callbackProgram",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "yevefisConfigExtension",
+					isMut: false,
+					isSigner: false,
 				},
 			],
 			args: [
@@ -7290,6 +14911,10 @@ export const IDL: Yevefi = {
 					name: "aToB",
 					type: "bool",
 				},
+				{
+					name: "callbackData",
+					type: "bytes",
+				},
 				{
 					name: "remainingAccountsInfo",
 					type: {
@@ -7301,273 +14926,318 @@ export const IDL: Yevefi = {
 			],
 		},
 		{
-			name: "twoHopSwapV2",
+			name: "initializePoolWithAdaptiveFeeV2",
 			docs: [
-				"Perform a two-hop swap in this Yevefi",
-				"",
-				"### Authority",
-				'- "token_authority" - The authority to withdraw tokens from the input token account.',
+				"Initializes a Yevefi account with adaptive fee enabled.",
+				"Tick spacing, base fee rate and adaptive fee constants are set from the supplied",
+				"adaptive_fee_tier, and the fee_tier_index is used as the tick_spacing seed of the Yevefi.",
 				"",
 				"### Parameters",
-				"- `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).",
-				"- `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).",
-				"- `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.",
-				"- `a_to_b_one` - The direction of the swap of hop one. True if swapping from A to B. False if swapping from B to A.",
-				"- `a_to_b_two` - The direction of the swap of hop two. True if swapping from A to B. False if swapping from B to A.",
-				"- `sqrt_price_limit_one` - The maximum/minimum price the swap will swap to in the first hop.",
-				"- `sqrt_price_limit_two` - The maximum/minimum price the swap will swap to in the second hop.",
+				"- `initial_sqrt_price` - The desired initial sqrt-price for this pool",
 				"",
 				"#### Special Errors",
-				"- `ZeroTradableAmount` - User provided parameter `amount` is 0.",
-				"- `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.",
-				"- `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.",
-				"- `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.",
-				"- `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.",
-				"- `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.",
-				"- `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.",
-				"- `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.",
-				"- `InvalidIntermediaryMint` - Error if the intermediary mint between hop one and two do not equal.",
-				"- `DuplicateTwoHopPool` - Error if yevefi one & two are the same pool.",
+				"`InvalidTokenMintOrder` - The order of mints have to be ordered by",
+				"`SqrtPriceOutOfBounds` - provided initial_sqrt_price is not between 2^-64 to 2^64",
+				"",
 			],
 			accounts: [
-				{
-					name: "yevefiOne",
-					isMut: true,
+				{
+					name: "yevefisConfig",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "yevefiTwo",
-					isMut: true,
+					name: "tokenMintA",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenMintInput",
+					name: "tokenMintB",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenMintIntermediate",
+					name: "tokenBadgeA",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenMintOutput",
+					name: "tokenBadgeB",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenProgramInput",
-					isMut: false,
+					name: "funder",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "yevefi",
+					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenProgramIntermediate",
+					name: "tokenVaultA",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "tokenVaultB",
+					isMut: true,
+					isSigner: true,
+				},
+				{
+					name: "adaptiveFeeTier",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenProgramOutput",
+					name: "tokenProgramA",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenOwnerAccountInput",
-					isMut: true,
+					name: "tokenProgramB",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenVaultOneInput",
-					isMut: true,
+					name: "systemProgram",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenVaultOneIntermediate",
-					isMut: true,
+					name: "rent",
+					isMut: false,
 					isSigner: false,
 				},
+			],
+			args: [
+				{
+					name: "initialSqrtPrice",
+					type: "u128",
+				},
+			],
+		},
+		{
+			name: "initializeConfigExtension",
+			accounts: [
 				{
-					name: "tokenVaultTwoIntermediate",
-					isMut: true,
+					name: "config",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tokenVaultTwoOutput",
+					name: "configExtension",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tokenOwnerAccountOutput",
+					name: "funder",
 					isMut: true,
-					isSigner: false,
+					isSigner: true,
 				},
 				{
-					name: "tokenAuthority",
+					name: "feeAuthority",
 					isMut: false,
 					isSigner: true,
 				},
 				{
-					name: "tickArrayOne0",
-					isMut: true,
+					name: "systemProgram",
+					isMut: false,
 					isSigner: false,
 				},
+			],
+			args: [],
+		},
+		{
+			name: "setConfigExtensionAuthority",
+			docs: [
+				"Proposes a new config extension authority, which takes over once it signs",
+				"`accept_config_extension_authority`.",
+				"Proposing the default Pubkey cancels a pending transfer.",
+			],
+			accounts: [
 				{
-					name: "tickArrayOne1",
-					isMut: true,
+					name: "yevefisConfig",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tickArrayOne2",
+					name: "yevefisConfigExtension",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "tickArrayTwo0",
-					isMut: true,
+					name: "configExtensionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "newConfigExtensionAuthority",
+					isMut: false,
 					isSigner: false,
 				},
+			],
+			args: [],
+		},
+		{
+			name: "acceptConfigExtensionAuthority",
+			accounts: [
 				{
-					name: "tickArrayTwo1",
-					isMut: true,
+					name: "yevefisConfig",
+					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "tickArrayTwo2",
+					name: "yevefisConfigExtension",
 					isMut: true,
 					isSigner: false,
 				},
 				{
-					name: "oracleOne",
-					isMut: true,
+					name: "newConfigExtensionAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+			],
+			args: [],
+		},
+		{
+			name: "setTokenBadgeAuthority",
+			docs: [
+				"Proposes a new token badge authority, which takes over once it signs",
+				"`accept_token_badge_authority`.",
+				"Proposing the default Pubkey cancels a pending transfer.",
+			],
+			accounts: [
+				{
+					name: "yevefisConfig",
+					isMut: false,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "oracle",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "Yevefi",
-								path: "yevefi_one",
-							},
-						],
-					},
 				},
 				{
-					name: "oracleTwo",
+					name: "yevefisConfigExtension",
 					isMut: true,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "oracle",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "Yevefi",
-								path: "yevefi_two",
-							},
-						],
-					},
 				},
 				{
-					name: "memoProgram",
+					name: "configExtensionAuthority",
 					isMut: false,
-					isSigner: false,
+					isSigner: true,
 				},
-			],
-			args: [
 				{
-					name: "amount",
-					type: "u64",
+					name: "newTokenBadgeAuthority",
+					isMut: false,
+					isSigner: false,
 				},
+			],
+			args: [],
+		},
+		{
+			name: "acceptTokenBadgeAuthority",
+			accounts: [
 				{
-					name: "otherAmountThreshold",
-					type: "u64",
+					name: "yevefisConfig",
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: "amountSpecifiedIsInput",
-					type: "bool",
+					name: "yevefisConfigExtension",
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: "aToBOne",
-					type: "bool",
+					name: "newTokenBadgeAuthority",
+					isMut: false,
+					isSigner: true,
 				},
+			],
+			args: [],
+		},
+		{
+			name: "setPauseAuthority",
+			accounts: [
 				{
-					name: "aToBTwo",
-					type: "bool",
+					name: "yevefisConfig",
+					isMut: false,
+					isSigner: false,
 				},
 				{
-					name: "sqrtPriceLimitOne",
-					type: "u128",
+					name: "yevefisConfigExtension",
+					isMut: true,
+					isSigner: false,
 				},
 				{
-					name: "sqrtPriceLimitTwo",
-					type: "u128",
+					name: "configExtensionAuthority",
+					isMut: false,
+					isSigner: true,
 				},
 				{
-					name: "remainingAccountsInfo",
-					type: {
-						option: {
-							defined: "RemainingAccountsInfo",
-						},
-					},
+					name: "newPauseAuthority",
+					isMut: false,
+					isSigner: false,
 				},
 			],
+			args: [],
 		},
 		{
-			name: "initializeConfigExtension",
+			name: "setPoolPaused",
+			docs: [
+				"Pause or unpause a Yevefi.",
+				"While paused, swaps, flash loans and liquidity increases are rejected,",
+				"but liquidity can still be decreased and fees and rewards collected.",
+				"",
+				"### Authority",
+				'- "pause_authority" - Set authority in the YevefisConfigExtension',
+				"",
+				"### Parameters",
+				"- `paused` - Whether the Yevefi is paused",
+			],
 			accounts: [
 				{
-					name: "config",
+					name: "yevefisConfig",
 					isMut: false,
 					isSigner: false,
 				},
 				{
-					name: "configExtension",
-					isMut: true,
+					name: "yevefisConfigExtension",
+					isMut: false,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "config_extension",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "YevefisConfig",
-								path: "config",
-							},
-						],
-					},
 				},
 				{
-					name: "funder",
+					name: "yevefi",
 					isMut: true,
-					isSigner: true,
+					isSigner: false,
 				},
 				{
-					name: "feeAuthority",
+					name: "pauseAuthority",
 					isMut: false,
 					isSigner: true,
 				},
+			],
+			args: [
 				{
-					name: "systemProgram",
-					isMut: false,
-					isSigner: false,
+					name: "paused",
+					type: "bool",
 				},
 			],
-			args: [],
 		},
 		{
-			name: "setConfigExtensionAuthority",
+			name: "setGlobalPaused",
+			docs: [
+				"Pause or unpause all Yevefis of a YevefisConfig.",
+				"While paused, swaps, flash loans and liquidity increases are rejected,",
+				"but liquidity can still be decreased and fees and rewards collected.",
+				"Instructions that check the pause require the YevefisConfigExtension PDA of the",
+				"YevefisConfig, initialized or not. v1 instructions take it as the first remaining account",
+				"and v2 instructions as a `ConfigExtension` remaining accounts slice.",
+				"",
+				"### Authority",
+				'- "pause_authority" - Set authority in the YevefisConfigExtension',
+				"",
+				"### Parameters",
+				"- `paused` - Whether the Yevefis of the config are paused",
+			],
 			accounts: [
 				{
 					name: "yevefisConfig",
@@ -7578,23 +15248,22 @@ export const IDL: Yevefi = {
 					name: "yevefisConfigExtension",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefis_config"],
 				},
 				{
-					name: "configExtensionAuthority",
+					name: "pauseAuthority",
 					isMut: false,
 					isSigner: true,
 				},
+			],
+			args: [
 				{
-					name: "newConfigExtensionAuthority",
-					isMut: false,
-					isSigner: false,
+					name: "paused",
+					type: "bool",
 				},
 			],
-			args: [],
 		},
 		{
-			name: "setTokenBadgeAuthority",
+			name: "initializeTokenBadge",
 			accounts: [
 				{
 					name: "yevefisConfig",
@@ -7602,18 +15271,32 @@ export const IDL: Yevefi = {
 					isSigner: false,
 				},
 				{
-					name: "yevefisConfigExtension",
+					name: "yevefisConfigExtension",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenBadgeAuthority",
+					isMut: false,
+					isSigner: true,
+				},
+				{
+					name: "tokenMint",
+					isMut: false,
+					isSigner: false,
+				},
+				{
+					name: "tokenBadge",
 					isMut: true,
 					isSigner: false,
-					relations: ["yevefis_config"],
 				},
 				{
-					name: "configExtensionAuthority",
-					isMut: false,
+					name: "funder",
+					isMut: true,
 					isSigner: true,
 				},
 				{
-					name: "newTokenBadgeAuthority",
+					name: "systemProgram",
 					isMut: false,
 					isSigner: false,
 				},
@@ -7621,7 +15304,7 @@ export const IDL: Yevefi = {
 			args: [],
 		},
 		{
-			name: "initializeTokenBadge",
+			name: "deleteTokenBadge",
 			accounts: [
 				{
 					name: "yevefisConfig",
@@ -7632,7 +15315,6 @@ export const IDL: Yevefi = {
 					name: "yevefisConfigExtension",
 					isMut: false,
 					isSigner: false,
-					relations: ["yevefis_config"],
 				},
 				{
 					name: "tokenBadgeAuthority",
@@ -7648,146 +15330,519 @@ export const IDL: Yevefi = {
 					name: "tokenBadge",
 					isMut: true,
 					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "token_badge",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "YevefisConfig",
-								path: "yevefis_config",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								path: "token_mint",
-							},
-						],
+				},
+				{
+					name: "receiver",
+					isMut: true,
+					isSigner: false,
+				},
+			],
+			args: [],
+		},
+	],
+	accounts: [
+		{
+			name: "adaptiveFeeTier",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "yevefisConfig",
+						type: "publicKey",
+					},
+					{
+						name: "feeTierIndex",
+						type: "u16",
+					},
+					{
+						name: "tickSpacing",
+						type: "u16",
+					},
+					{
+						name: "defaultBaseFeeRate",
+						type: "u16",
+					},
+					{
+						name: "adaptiveFeeConstants",
+						type: {
+							defined: "AdaptiveFeeConstants",
+						},
+					},
+				],
+			},
+		},
+		{
+			name: "feeTier",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "yevefisConfig",
+						type: "publicKey",
+					},
+					{
+						name: "tickSpacing",
+						type: "u16",
+					},
+					{
+						name: "defaultFeeRate",
+						type: "u16",
+					},
+				],
+			},
+		},
+		{
+			name: "oracle",
+			docs: [
+				"Header of the oracle account of a yevefi.",
+				"",
+				"The header is followed by `observation_cardinality_next` observations which are used",
+				"as a ring buffer. Only the first `observation_cardinality` slots are in use; the rest",
+				"have been paid for and are taken into use once the buffer wraps around.",
+			],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "yevefi",
+						type: "publicKey",
+					},
+					{
+						name: "observationIndex",
+						type: "u16",
+					},
+					{
+						name: "observationCardinality",
+						type: "u16",
+					},
+					{
+						name: "observationCardinalityNext",
+						type: "u16",
+					},
+					{
+						name: "reserved",
+						type: {
+							array: ["u8", 64],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: "position",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "yevefi",
+						type: "publicKey",
+					},
+					{
+						name: "positionMint",
+						type: "publicKey",
+					},
+					{
+						name: "liquidity",
+						type: "u128",
+					},
+					{
+						name: "tickLowerIndex",
+						type: "i32",
+					},
+					{
+						name: "tickUpperIndex",
+						type: "i32",
+					},
+					{
+						name: "feeGrowthCheckpointA",
+						type: "u128",
+					},
+					{
+						name: "feeOwedA",
+						type: "u64",
+					},
+					{
+						name: "feeGrowthCheckpointB",
+						type: "u128",
+					},
+					{
+						name: "feeOwedB",
+						type: "u64",
+					},
+					{
+						name: "rewardInfos",
+						type: {
+							array: [
+								{
+									defined: "PositionRewardInfo",
+								},
+								3,
+							],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: "positionBundle",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "positionBundleMint",
+						type: "publicKey",
+					},
+					{
+						name: "positionBitmap",
+						type: {
+							array: ["u8", 32],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: "positionDelegate",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "position",
+						type: "publicKey",
+					},
+					{
+						name: "positionOwner",
+						type: "publicKey",
+					},
+					{
+						name: "delegate",
+						type: "publicKey",
+					},
+					{
+						name: "permissions",
+						type: "u8",
+					},
+				],
+			},
+		},
+		{
+			name: "positionLock",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "position",
+						type: "publicKey",
+					},
+					{
+						name: "unlockTimestamp",
+						type: "u64",
+					},
+				],
+			},
+		},
+		{
+			name: "positionRewardExtension",
+			docs: [
+				"Checkpoints and amounts owed of the extension rewards of a Position.",
+				"A position only earns extension rewards once its PositionRewardExtension is initialized.",
+			],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "position",
+						type: "publicKey",
+					},
+					{
+						name: "rewardInfos",
+						type: {
+							array: [
+								{
+									defined: "PositionRewardInfo",
+								},
+								2,
+							],
+						},
+					},
+				],
+			},
+		},
+		{
+			name: "referrer",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "yevefisConfig",
+						type: "publicKey",
+					},
+					{
+						name: "referrerAuthority",
+						type: "publicKey",
+					},
+				],
+			},
+		},
+		{
+			name: "tickArray",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "startTickIndex",
+						type: "i32",
+					},
+					{
+						name: "ticks",
+						type: {
+							array: [
+								{
+									defined: "Tick",
+								},
+								88,
+							],
+						},
 					},
-				},
-				{
-					name: "funder",
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: "systemProgram",
-					isMut: false,
-					isSigner: false,
-				},
+					{
+						name: "yevefi",
+						type: "publicKey",
+					},
+				],
+			},
+		},
+		{
+			name: "tickArrayRewardExtension",
+			docs: [
+				"Growth of the extension rewards outside of each tick of a TickArray.",
+				"",
+				"Unlike `Tick.reward_growths_outside`, the values are maintained for every tick of the array,",
+				"whether it is initialized or not, and are flipped whenever the current tick index moves across",
+				"the tick. This way ticks don't need to be updated when their liquidity is modified.",
 			],
-			args: [],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "yevefi",
+						type: "publicKey",
+					},
+					{
+						name: "startTickIndex",
+						type: "i32",
+					},
+					{
+						name: "rewardGrowthsOutside",
+						type: {
+							array: [
+								{
+									array: ["u128", 2],
+								},
+								88,
+							],
+						},
+					},
+				],
+			},
 		},
 		{
-			name: "deleteTokenBadge",
-			accounts: [
-				{
-					name: "yevefisConfig",
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: "yevefisConfigExtension",
-					isMut: false,
-					isSigner: false,
-					relations: ["yevefis_config"],
-				},
-				{
-					name: "tokenBadgeAuthority",
-					isMut: false,
-					isSigner: true,
-				},
-				{
-					name: "tokenMint",
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: "tokenBadge",
-					isMut: true,
-					isSigner: false,
-					pda: {
-						seeds: [
-							{
-								kind: "const",
-								type: "string",
-								value: "token_badge",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								account: "YevefisConfig",
-								path: "yevefis_config",
-							},
-							{
-								kind: "account",
-								type: "publicKey",
-								path: "token_mint",
-							},
-						],
+			name: "tokenBadge",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "yevefisConfig",
+						type: "publicKey",
 					},
-					relations: ["yevefis_config"],
-				},
-				{
-					name: "receiver",
-					isMut: true,
-					isSigner: false,
-				},
-			],
-			args: [],
+					{
+						name: "tokenMint",
+						type: "publicKey",
+					},
+				],
+			},
 		},
 		{
-			name: "veRewardEmissions",
-			accounts: [
-				{
-					name: "funder",
-					isMut: true,
-					isSigner: true,
-				},
-				{
-					name: "config",
-					isMut: false,
-					isSigner: false,
-				},
-				{
-					name: "yevefi",
-					isMut: true,
-					isSigner: false,
-				},
-			],
-			args: [
-				{
-					name: "rewardIndex",
-					type: "u8",
-				},
-				{
-					name: "emissionsPerSecondX64",
-					type: "u128",
-				},
-			],
+			name: "yevefi",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "yevefisConfig",
+						type: "publicKey",
+					},
+					{
+						name: "yevefiBump",
+						type: {
+							array: ["u8", 1],
+						},
+					},
+					{
+						name: "tickSpacing",
+						type: "u16",
+					},
+					{
+						name: "tickSpacingSeed",
+						type: {
+							array: ["u8", 2],
+						},
+					},
+					{
+						name: "feeRate",
+						type: "u16",
+					},
+					{
+						name: "protocolFeeRate",
+						type: "u16",
+					},
+					{
+						name: "liquidity",
+						type: "u128",
+					},
+					{
+						name: "sqrtPrice",
+						type: "u128",
+					},
+					{
+						name: "tickCurrentIndex",
+						type: "i32",
+					},
+					{
+						name: "protocolFeeOwedA",
+						type: "u64",
+					},
+					{
+						name: "protocolFeeOwedB",
+						type: "u64",
+					},
+					{
+						name: "tokenMintA",
+						type: "publicKey",
+					},
+					{
+						name: "tokenVaultA",
+						type: "publicKey",
+					},
+					{
+						name: "feeGrowthGlobalA",
+						type: "u128",
+					},
+					{
+						name: "tokenMintB",
+						type: "publicKey",
+					},
+					{
+						name: "tokenVaultB",
+						type: "publicKey",
+					},
+					{
+						name: "feeGrowthGlobalB",
+						type: "u128",
+					},
+					{
+						name: "rewardLastUpdatedTimestamp",
+						type: "u64",
+					},
+					{
+						name: "rewardInfos",
+						type: {
+							array: [
+								{
+									defined: "YevefiRewardInfo",
+								},
+								3,
+							],
+						},
+					},
+					{
+						name: "reentrancyLock",
+						type: "bool",
+					},
+					{
+						name: "adaptiveFeeInfo",
+						type: {
+							defined: "AdaptiveFeeInfo",
+						},
+					},
+					{
+						name: "paused",
+						type: "bool",
+					},
+					{
+						name: "rewardSchedules",
+						type: {
+							array: [
+								{
+									defined: "YevefiRewardSchedule",
+								},
+								3,
+							],
+						},
+					},
+					{
+						name: "rewardExtensionInitialized",
+						type: "bool",
+					},
+					{
+						name: "rewardTotals",
+						type: {
+							array: [
+								{
+									defined: "YevefiRewardTotals",
+								},
+								3,
+							],
+						},
+					},
+					{
+						name: "pendingRewardAuthorities",
+						type: {
+							array: ["publicKey", 3],
+						},
+					},
+				],
+			},
 		},
-	],
-	accounts: [
 		{
-			name: "yevefisConfigExtension",
+			name: "yevefiRewardExtension",
+			docs: [
+				"Additional rewards of a Yevefi.",
+				"",
+				"Extension rewards accrue with the same math as `Yevefi.reward_infos`, but their growth outside",
+				"of each tick is stored in a `TickArrayRewardExtension`, and the rewards owed to a position are",
+				"stored in a `PositionRewardExtension`.",
+			],
 			type: {
 				kind: "struct",
 				fields: [
 					{
-						name: "yevefisConfig",
+						name: "yevefi",
 						type: "publicKey",
 					},
 					{
-						name: "configExtensionAuthority",
-						type: "publicKey",
+						name: "rewardLastUpdatedTimestamp",
+						type: "u64",
 					},
 					{
-						name: "tokenBadgeAuthority",
-						type: "publicKey",
+						name: "rewardInfos",
+						type: {
+							array: [
+								{
+									defined: "YevefiRewardInfo",
+								},
+								2,
+							],
+						},
+					},
+					{
+						name: "rewardTotals",
+						type: {
+							array: [
+								{
+									defined: "YevefiRewardTotals",
+								},
+								2,
+							],
+						},
 					},
 				],
 			},
@@ -7813,11 +15868,27 @@ export const IDL: Yevefi = {
 						name: "defaultProtocolFeeRate",
 						type: "u16",
 					},
+					{
+						name: "referralFeeRate",
+						type: "u16",
+					},
+					{
+						name: "pendingFeeAuthority",
+						type: "publicKey",
+					},
+					{
+						name: "pendingCollectProtocolFeesAuthority",
+						type: "publicKey",
+					},
+					{
+						name: "pendingRewardEmissionsSuperAuthority",
+						type: "publicKey",
+					},
 				],
 			},
 		},
 		{
-			name: "feeTier",
+			name: "yevefisConfigExtension",
 			type: {
 				kind: "struct",
 				fields: [
@@ -7826,424 +15897,1073 @@ export const IDL: Yevefi = {
 						type: "publicKey",
 					},
 					{
-						name: "tickSpacing",
+						name: "configExtensionAuthority",
+						type: "publicKey",
+					},
+					{
+						name: "tokenBadgeAuthority",
+						type: "publicKey",
+					},
+					{
+						name: "pauseAuthority",
+						type: "publicKey",
+					},
+					{
+						name: "paused",
+						type: "bool",
+					},
+					{
+						name: "pendingConfigExtensionAuthority",
+						type: "publicKey",
+					},
+					{
+						name: "pendingTokenBadgeAuthority",
+						type: "publicKey",
+					},
+				],
+			},
+		},
+	],
+	types: [
+		{
+			name: "AccountsType",
+			type: {
+				kind: "enum",
+				variants: [
+					{
+						name: "TransferHookA",
+					},
+					{
+						name: "TransferHookB",
+					},
+					{
+						name: "TransferHookReward",
+					},
+					{
+						name: "TransferHookInput",
+					},
+					{
+						name: "TransferHookIntermediate",
+					},
+					{
+						name: "TransferHookOutput",
+					},
+					{
+						name: "SupplementalTickArrays",
+					},
+					{
+						name: "SupplementalTickArraysOne",
+					},
+					{
+						name: "SupplementalTickArraysTwo",
+					},
+					{
+						name: "TransferHookIntermediateTwo",
+					},
+					{
+						name: "TransferHookIntermediateThree",
+					},
+					{
+						name: "SupplementalTickArraysThree",
+					},
+					{
+						name: "SupplementalTickArraysFour",
+					},
+					{
+						name: "FlashCallback",
+					},
+					{
+						name: "Referral",
+					},
+					{
+						name: "RewardAccounts",
+					},
+					{
+						name: "TransferHookRewardOne",
+					},
+					{
+						name: "TransferHookRewardTwo",
+					},
+					{
+						name: "TransferHookRewardThree",
+					},
+					{
+						name: "BundledPositions",
+					},
+					{
+						name: "BundledPositionTickArrays",
+					},
+					{
+						name: "RewardExtension",
+					},
+					{
+						name: "PositionLock",
+					},
+					{
+						name: "PositionDelegate",
+					},
+					{
+						name: "ConfigExtension",
+					},
+					{
+						name: "Oracle",
+					},
+				],
+			},
+		},
+		{
+			name: "AdaptiveFeeConstants",
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "filterPeriod",
 						type: "u16",
 					},
 					{
-						name: "defaultFeeRate",
+						name: "decayPeriod",
+						type: "u16",
+					},
+					{
+						name: "reductionFactor",
 						type: "u16",
 					},
+					{
+						name: "adaptiveFeeControlFactor",
+						type: "u32",
+					},
+					{
+						name: "maxVolatilityAccumulator",
+						type: "u32",
+					},
 				],
 			},
 		},
 		{
-			name: "positionBundle",
+			name: "AdaptiveFeeInfo",
+			docs: [
+				"Adaptive fee state of a Yevefi.",
+				"The fee of a swap step is `Yevefi.fee_rate` (the base fee) plus a variable fee derived from",
+				"the volatility accumulator, capped at MAX_FEE_RATE.",
+			],
 			type: {
 				kind: "struct",
 				fields: [
 					{
-						name: "positionBundleMint",
-						type: "publicKey",
+						name: "constants",
+						type: {
+							defined: "AdaptiveFeeConstants",
+						},
 					},
 					{
-						name: "positionBitmap",
+						name: "variables",
 						type: {
-							array: ["u8", 32],
+							defined: "AdaptiveFeeVariables",
 						},
 					},
 				],
 			},
 		},
 		{
-			name: "position",
+			name: "AdaptiveFeeVariables",
 			type: {
 				kind: "struct",
 				fields: [
 					{
-						name: "yevefi",
-						type: "publicKey",
+						name: "lastReferenceUpdateTimestamp",
+						type: "u64",
 					},
 					{
-						name: "positionMint",
-						type: "publicKey",
+						name: "volatilityReference",
+						type: "u32",
 					},
 					{
-						name: "liquidity",
-						type: "u128",
+						name: "tickGroupIndexReference",
+						type: "i32",
 					},
 					{
-						name: "tickLowerIndex",
-						type: "i32",
+						name: "volatilityAccumulator",
+						type: "u32",
 					},
+				],
+			},
+		},
+		{
+			name: "OpenPositionBumps",
+			type: {
+				kind: "struct",
+				fields: [
 					{
-						name: "tickUpperIndex",
-						type: "i32",
+						name: "positionBump",
+						type: "u8",
 					},
+				],
+			},
+		},
+		{
+			name: "OpenPositionWithMetadataBumps",
+			type: {
+				kind: "struct",
+				fields: [
 					{
-						name: "feeGrowthCheckpointA",
-						type: "u128",
+						name: "positionBump",
+						type: "u8",
 					},
 					{
-						name: "feeOwedA",
-						type: "u64",
+						name: "metadataBump",
+						type: "u8",
 					},
+				],
+			},
+		},
+		{
+			name: "PositionRewardInfo",
+			type: {
+				kind: "struct",
+				fields: [
 					{
-						name: "feeGrowthCheckpointB",
+						name: "growthInsideCheckpoint",
 						type: "u128",
 					},
 					{
-						name: "feeOwedB",
+						name: "amountOwed",
 						type: "u64",
 					},
+				],
+			},
+		},
+		{
+			name: "RemainingAccountsInfo",
+			type: {
+				kind: "struct",
+				fields: [
 					{
-						name: "rewardInfos",
+						name: "slices",
 						type: {
-							array: [
-								{
-									defined: "PositionRewardInfo",
-								},
-								3,
-							],
+							vec: {
+								defined: "RemainingAccountsSlice",
+							},
 						},
 					},
 				],
 			},
 		},
 		{
-			name: "tickArray",
+			name: "RemainingAccountsSlice",
 			type: {
 				kind: "struct",
 				fields: [
 					{
-						name: "startTickIndex",
-						type: "i32",
-					},
-					{
-						name: "ticks",
+						name: "accountsType",
 						type: {
-							array: [
-								{
-									defined: "Tick",
-								},
-								88,
-							],
+							defined: "AccountsType",
 						},
 					},
 					{
-						name: "yevefi",
-						type: "publicKey",
+						name: "length",
+						type: "u8",
+					},
+				],
+			},
+		},
+		{
+			name: "RewardEmissionsRateChange",
+			docs: ["A scheduled change of the emission rate of a reward."],
+			type: {
+				kind: "struct",
+				fields: [
+					{
+						name: "timestamp",
+						docs: [
+							"Timestamp from which the emission rate applies. 0 marks an unused entry.",
+						],
+						type: "u64",
+					},
+					{
+						name: "emissionsPerSecondX64",
+						docs: [
+							"Q64.64 number that indicates how many tokens per second are earned per unit of liquidity.",
+						],
+						type: "u128",
 					},
 				],
 			},
 		},
 		{
-			name: "tokenBadge",
+			name: "SwapRouteHop",
 			type: {
 				kind: "struct",
 				fields: [
 					{
-						name: "yevefisConfig",
-						type: "publicKey",
+						name: "aToB",
+						type: "bool",
 					},
 					{
-						name: "tokenMint",
-						type: "publicKey",
+						name: "sqrtPriceLimit",
+						type: "u128",
 					},
 				],
 			},
 		},
 		{
-			name: "yevefi",
+			name: "Tick",
 			type: {
 				kind: "struct",
 				fields: [
 					{
-						name: "yevefisConfig",
-						type: "publicKey",
-					},
-					{
-						name: "yevefiBump",
-						type: {
-							array: ["u8", 1],
-						},
-					},
-					{
-						name: "tickSpacing",
-						type: "u16",
-					},
-					{
-						name: "tickSpacingSeed",
-						type: {
-							array: ["u8", 2],
-						},
+						name: "initialized",
+						type: "bool",
 					},
 					{
-						name: "feeRate",
-						type: "u16",
+						name: "liquidityNet",
+						type: "i128",
 					},
 					{
-						name: "protocolFeeRate",
-						type: "u16",
+						name: "liquidityGross",
+						type: "u128",
 					},
 					{
-						name: "liquidity",
+						name: "feeGrowthOutsideA",
 						type: "u128",
 					},
 					{
-						name: "sqrtPrice",
+						name: "feeGrowthOutsideB",
 						type: "u128",
 					},
 					{
-						name: "tickCurrentIndex",
-						type: "i32",
+						name: "rewardGrowthsOutside",
+						type: {
+							array: ["u128", 3],
+						},
 					},
+				],
+			},
+		},
+		{
+			name: "YevefiBumps",
+			type: {
+				kind: "struct",
+				fields: [
 					{
-						name: "protocolFeeOwedA",
-						type: "u64",
+						name: "yevefiBump",
+						type: "u8",
 					},
+				],
+			},
+		},
+		{
+			name: "YevefiRewardInfo",
+			docs: [
+				"Stores the state relevant for tracking liquidity mining rewards at the `Yevefi` level.",
+				"These values are used in conjunction with `PositionRewardInfo`, `Tick.reward_growths_outside`,",
+				"and `Yevefi.reward_last_updated_timestamp` to determine how many rewards are earned by open",
+				"positions.",
+			],
+			type: {
+				kind: "struct",
+				fields: [
 					{
-						name: "protocolFeeOwedB",
-						type: "u64",
+						name: "mint",
+						docs: ["Reward token mint."],
+						type: "publicKey",
 					},
 					{
-						name: "tokenMintA",
+						name: "vault",
+						docs: ["Reward vault token account."],
 						type: "publicKey",
 					},
 					{
-						name: "tokenVaultA",
+						name: "authority",
+						docs: [
+							"Authority account that has permission to initialize the reward and set emissions.",
+						],
 						type: "publicKey",
 					},
 					{
-						name: "feeGrowthGlobalA",
+						name: "emissionsPerSecondX64",
+						docs: [
+							"Q64.64 number that indicates how many tokens per second are earned per unit of liquidity.",
+						],
 						type: "u128",
 					},
 					{
-						name: "tokenMintB",
-						type: "publicKey",
-					},
-					{
-						name: "tokenVaultB",
-						type: "publicKey",
+						name: "growthGlobalX64",
+						docs: [
+							"Q64.64 number that tracks the total tokens earned per unit of liquidity since the reward",
+							"emissions were turned on.",
+						],
+						type: "u128",
 					},
+				],
+			},
+		},
+		{
+			name: "YevefiRewardSchedule",
+			docs: [
+				"Emission schedule of a reward, so that emissions start, change and stop without sending",
+				"`set_reward_emissions` at the exact second.",
+				"",
+				"The emission rate at a timestamp is 0 before `start_timestamp` and from `end_timestamp` on.",
+				"In between it is `YevefiRewardInfo.emissions_per_second_x64`, replaced by each rate change",
+				"whose timestamp has been reached.",
+				"The default (all zero) schedule emits at `YevefiRewardInfo.emissions_per_second_x64` forever.",
+			],
+			type: {
+				kind: "struct",
+				fields: [
 					{
-						name: "feeGrowthGlobalB",
-						type: "u128",
+						name: "startTimestamp",
+						docs: ["No rewards are emitted before this timestamp."],
+						type: "u64",
 					},
 					{
-						name: "rewardLastUpdatedTimestamp",
+						name: "endTimestamp",
+						docs: [
+							"No rewards are emitted from this timestamp on. 0 if the emissions have no end.",
+						],
 						type: "u64",
 					},
 					{
-						name: "rewardInfos",
+						name: "rateChanges",
+						docs: [
+							"Rate changes in ascending timestamp order. Unused entries are at the end.",
+						],
 						type: {
 							array: [
 								{
-									defined: "YevefiRewardInfo",
+									defined: "RewardEmissionsRateChange",
 								},
-								3,
+								4,
 							],
 						},
 					},
 				],
 			},
 		},
-	],
-	types: [
 		{
-			name: "OpenPositionBumps",
+			name: "YevefiRewardTotals",
+			docs: [
+				"Amounts of a reward funded, emitted to and collected by the positions of a `Yevefi`.",
+			],
 			type: {
 				kind: "struct",
 				fields: [
 					{
-						name: "positionBump",
-						type: "u8",
+						name: "emitted",
+						docs: [
+							"Upper bound of the rewards earned by positions, rounded up.",
+						],
+						type: "u64",
 					},
-				],
-			},
-		},
-		{
-			name: "OpenPositionWithMetadataBumps",
-			type: {
-				kind: "struct",
-				fields: [
 					{
-						name: "positionBump",
-						type: "u8",
+						name: "collected",
+						docs: [
+							"Rewards transferred from the reward vault to positions by the instructions which write the",
+							"yevefi. `collect_reward` leaves the yevefi read-only, so its transfers are not included.",
+						],
+						type: "u64",
 					},
 					{
-						name: "metadataBump",
-						type: "u8",
+						name: "funded",
+						docs: [
+							"Tokens deposited into the reward vault for emissions, net of withdrawn surplus.",
+							"Emissions stop once `emitted` reaches this amount.",
+						],
+						type: "u64",
+					},
+					{
+						name: "legacyOwed",
+						docs: [
+							"Reward vault amount when a yevefi created before the totals were recorded was migrated.",
+							"The rewards its positions had earned by then are unknown, so all of it is kept owed and",
+							"none of it is funded.",
+						],
+						type: "u64",
 					},
 				],
 			},
 		},
+	],
+	events: [
+		{
+			fields: [
+				{
+					index: false,
+					name: "yevefi",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "position",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "feeAAmount",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "feeBAmount",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "feeATransferFee",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "feeBTransferFee",
+					type: "u64",
+				},
+			],
+			name: "FeesCollected",
+		},
+		{
+			fields: [
+				{
+					index: false,
+					name: "yevefi",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "position",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "liquidity",
+					type: "u128",
+				},
+				{
+					index: false,
+					name: "feeAAmount",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "feeBAmount",
+					type: "u64",
+				},
+			],
+			name: "FeesCompounded",
+		},
+		{
+			fields: [
+				{
+					index: false,
+					name: "yevefi",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "amountA",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "amountB",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "feeA",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "feeB",
+					type: "u64",
+				},
+			],
+			name: "FlashLoaned",
+		},
+		{
+			fields: [
+				{
+					index: false,
+					name: "yevefi",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "position",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "tickLowerIndex",
+					type: "i32",
+				},
+				{
+					index: false,
+					name: "tickUpperIndex",
+					type: "i32",
+				},
+				{
+					index: false,
+					name: "liquidity",
+					type: "u128",
+				},
+				{
+					index: false,
+					name: "tokenAAmount",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "tokenBAmount",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "tokenATransferFee",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "tokenBTransferFee",
+					type: "u64",
+				},
+			],
+			name: "LiquidityDecreased",
+		},
 		{
-			name: "PositionRewardInfo",
-			type: {
-				kind: "struct",
-				fields: [
-					{
-						name: "growthInsideCheckpoint",
-						type: "u128",
-					},
-					{
-						name: "amountOwed",
-						type: "u64",
-					},
-				],
-			},
+			fields: [
+				{
+					index: false,
+					name: "yevefi",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "position",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "tickLowerIndex",
+					type: "i32",
+				},
+				{
+					index: false,
+					name: "tickUpperIndex",
+					type: "i32",
+				},
+				{
+					index: false,
+					name: "liquidity",
+					type: "u128",
+				},
+				{
+					index: false,
+					name: "tokenAAmount",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "tokenBAmount",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "tokenATransferFee",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "tokenBTransferFee",
+					type: "u64",
+				},
+			],
+			name: "LiquidityIncreased",
 		},
 		{
-			name: "Tick",
-			type: {
-				kind: "struct",
-				fields: [
-					{
-						name: "initialized",
-						type: "bool",
-					},
-					{
-						name: "liquidityNet",
-						type: "i128",
-					},
-					{
-						name: "liquidityGross",
-						type: "u128",
-					},
-					{
-						name: "feeGrowthOutsideA",
-						type: "u128",
-					},
-					{
-						name: "feeGrowthOutsideB",
-						type: "u128",
-					},
-					{
-						name: "rewardGrowthsOutside",
-						type: {
-							array: ["u128", 3],
-						},
-					},
-				],
-			},
+			fields: [
+				{
+					index: false,
+					name: "yevefi",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "position",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "oldTickLowerIndex",
+					type: "i32",
+				},
+				{
+					index: false,
+					name: "oldTickUpperIndex",
+					type: "i32",
+				},
+				{
+					index: false,
+					name: "oldLiquidity",
+					type: "u128",
+				},
+				{
+					index: false,
+					name: "newTickLowerIndex",
+					type: "i32",
+				},
+				{
+					index: false,
+					name: "newTickUpperIndex",
+					type: "i32",
+				},
+				{
+					index: false,
+					name: "newLiquidity",
+					type: "u128",
+				},
+				{
+					index: false,
+					name: "tokenADeposited",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "tokenBDeposited",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "tokenAWithdrawn",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "tokenBWithdrawn",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "tokenATransferFee",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "tokenBTransferFee",
+					type: "u64",
+				},
+			],
+			name: "LiquidityRepositioned",
 		},
 		{
-			name: "YevefiRewardInfo",
-			docs: [
-				"Stores the state relevant for tracking liquidity mining rewards at the `Yevefi` level.",
-				"These values are used in conjunction with `PositionRewardInfo`, `Tick.reward_growths_outside`,",
-				"and `Yevefi.reward_last_updated_timestamp` to determine how many rewards are earned by open",
-				"positions.",
+			fields: [
+				{
+					index: false,
+					name: "yevefi",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "yevefisConfig",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "tokenMintA",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "tokenMintB",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "tickSpacing",
+					type: "u16",
+				},
+				{
+					index: false,
+					name: "tokenProgramA",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "tokenProgramB",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "decimalsA",
+					type: "u8",
+				},
+				{
+					index: false,
+					name: "decimalsB",
+					type: "u8",
+				},
+				{
+					index: false,
+					name: "initialSqrtPrice",
+					type: "u128",
+				},
 			],
-			type: {
-				kind: "struct",
-				fields: [
-					{
-						name: "mint",
-						docs: ["Reward token mint."],
-						type: "publicKey",
-					},
-					{
-						name: "vault",
-						docs: ["Reward vault token account."],
-						type: "publicKey",
-					},
-					{
-						name: "authority",
-						docs: [
-							"Authority account that has permission to initialize the reward and set emissions.",
-						],
-						type: "publicKey",
-					},
-					{
-						name: "emissionsPerSecondX64",
-						docs: [
-							"Q64.64 number that indicates how many tokens per second are earned per unit of liquidity.",
-						],
-						type: "u128",
-					},
-					{
-						name: "growthGlobalX64",
-						docs: [
-							"Q64.64 number that tracks the total tokens earned per unit of liquidity since the reward",
-							"emissions were turned on.",
-						],
-						type: "u128",
-					},
-				],
-			},
+			name: "PoolInitialized",
 		},
 		{
-			name: "YevefiBumps",
-			type: {
-				kind: "struct",
-				fields: [
-					{
-						name: "yevefiBump",
-						type: "u8",
-					},
-				],
-			},
+			fields: [
+				{
+					index: false,
+					name: "yevefi",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "referralTokenAccount",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "tokenMint",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "amount",
+					type: "u64",
+				},
+			],
+			name: "ReferralFeePaid",
+		},
+		{
+			fields: [
+				{
+					index: false,
+					name: "yevefi",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "position",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "rewardIndex",
+					type: "u8",
+				},
+				{
+					index: false,
+					name: "rewardMint",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "amount",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "transferFee",
+					type: "u64",
+				},
+			],
+			name: "RewardCollected",
 		},
 		{
-			name: "RemainingAccountsSlice",
-			type: {
-				kind: "struct",
-				fields: [
-					{
-						name: "accountsType",
-						type: {
-							defined: "AccountsType",
-						},
-					},
-					{
-						name: "length",
-						type: "u8",
-					},
-				],
-			},
+			fields: [
+				{
+					index: false,
+					name: "yevefi",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "rewardIndex",
+					type: "u8",
+				},
+				{
+					index: false,
+					name: "rewardMint",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "funder",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "amount",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "fundedAmount",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "emissionsEndTimestamp",
+					type: "u64",
+				},
+			],
+			name: "RewardFunded",
 		},
 		{
-			name: "RemainingAccountsInfo",
-			type: {
-				kind: "struct",
-				fields: [
-					{
-						name: "slices",
-						type: {
-							vec: {
-								defined: "RemainingAccountsSlice",
-							},
-						},
-					},
-				],
-			},
+			fields: [
+				{
+					index: false,
+					name: "yevefi",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "rewardIndex",
+					type: "u8",
+				},
+				{
+					index: false,
+					name: "rewardMint",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "amount",
+					type: "u64",
+				},
+			],
+			name: "RewardSurplusWithdrawn",
 		},
 		{
-			name: "AccountsType",
-			type: {
-				kind: "enum",
-				variants: [
-					{
-						name: "TransferHookA",
-					},
-					{
-						name: "TransferHookB",
-					},
-					{
-						name: "TransferHookReward",
-					},
-					{
-						name: "TransferHookInput",
-					},
-					{
-						name: "TransferHookIntermediate",
-					},
-					{
-						name: "TransferHookOutput",
-					},
-				],
-			},
+			fields: [
+				{
+					index: false,
+					name: "yevefi",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "aToB",
+					type: "bool",
+				},
+				{
+					index: false,
+					name: "preSqrtPrice",
+					type: "u128",
+				},
+				{
+					index: false,
+					name: "postSqrtPrice",
+					type: "u128",
+				},
+				{
+					index: false,
+					name: "preTickCurrentIndex",
+					type: "i32",
+				},
+				{
+					index: false,
+					name: "postTickCurrentIndex",
+					type: "i32",
+				},
+				{
+					index: false,
+					name: "inputAmount",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "outputAmount",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "inputTransferFee",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "outputTransferFee",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "lpFee",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "protocolFee",
+					type: "u64",
+				},
+			],
+			name: "Traded",
+		},
+		{
+			fields: [
+				{
+					index: false,
+					name: "yevefi",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "position",
+					type: "publicKey",
+				},
+				{
+					index: false,
+					name: "tickLowerIndex",
+					type: "i32",
+				},
+				{
+					index: false,
+					name: "tickUpperIndex",
+					type: "i32",
+				},
+				{
+					index: false,
+					name: "liquidity",
+					type: "u128",
+				},
+				{
+					index: false,
+					name: "tokenAAmount",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "tokenBAmount",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "feeAAmount",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "feeBAmount",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "aToB",
+					type: "bool",
+				},
+				{
+					index: false,
+					name: "amountOut",
+					type: "u64",
+				},
+				{
+					index: false,
+					name: "transferFee",
+					type: "u64",
+				},
+			],
+			name: "ZappedOut",
 		},
 	],
 	errors: [
@@ -8517,5 +17237,185 @@ export const IDL: Yevefi = {
 			name: "RemainingAccountsDuplicatedAccountsType",
 			msg: "Same accounts type is provided more than once",
 		},
+		{
+			code: 6054,
+			name: "FullRangeOnlyPool",
+			msg: "This yevefi only supports full-range positions",
+		},
+		{
+			code: 6055,
+			name: "TooManySupplementalTickArrays",
+			msg: "Too many supplemental tick arrays provided",
+		},
+		{
+			code: 6056,
+			name: "DifferentYevefiTickArrayAccount",
+			msg: "TickArray account for different yevefi provided",
+		},
+		{
+			code: 6057,
+			name: "PartialFillError",
+			msg: "Trade resulted in partial fill",
+		},
+		{
+			code: 6058,
+			name: "InvalidObservationCardinality",
+			msg: "Invalid observation cardinality",
+		},
+		{
+			code: 6059,
+			name: "ObservationTooOld",
+			msg: "Requested observation is older than the oldest observation",
+		},
+		{
+			code: 6060,
+			name: "TooManySecondsAgos",
+			msg: "Too many seconds_agos provided",
+		},
+		{
+			code: 6061,
+			name: "OracleNotWritable",
+			msg: "Initialized oracle must be writable",
+		},
+		{
+			code: 6062,
+			name: "InvalidSwapRouteLength",
+			msg: "Swap route must have between 1 and 4 hops",
+		},
+		{
+			code: 6063,
+			name: "YevefiLocked",
+			msg: "Yevefi is locked by an in-progress flash loan or flash swap",
+		},
+		{
+			code: 6064,
+			name: "FlashNotRepaid",
+			msg: "Flash loan or flash swap was not repaid",
+		},
+		{
+			code: 6065,
+			name: "InvalidAdaptiveFeeConstants",
+			msg: "Invalid adaptive fee constants",
+		},
+		{
+			code: 6066,
+			name: "InvalidFeeTierIndex",
+			msg: "Fee tier index must not be a tick spacing used by the config",
+		},
+		{
+			code: 6067,
+			name: "ReferralFeeRateMaxExceeded",
+			msg: "Referral fee rate exceeds MAX_REFERRAL_FEE_RATE",
+		},
+		{
+			code: 6068,
+			name: "InvalidReferralAccounts",
+			msg: "Invalid referral accounts",
+		},
+		{
+			code: 6069,
+			name: "YevefiPaused",
+			msg: "Yevefi is paused",
+		},
+		{
+			code: 6070,
+			name: "PositionLocked",
+			msg: "Position is locked",
+		},
+		{
+			code: 6071,
+			name: "InvalidUnlockTimestamp",
+			msg: "Unlock timestamp must be in the future",
+		},
+		{
+			code: 6072,
+			name: "PositionLiquidityNonZero",
+			msg: "Position must not have liquidity",
+		},
+		{
+			code: 6073,
+			name: "SameTickRangeNotAllowed",
+			msg: "New tick range must differ from the current tick range",
+		},
+		{
+			code: 6074,
+			name: "LiquidityBelowMinimum",
+			msg: "Liquidity is below the minimum",
+		},
+		{
+			code: 6075,
+			name: "InvalidRewardAccounts",
+			msg: "Invalid reward accounts",
+		},
+		{
+			code: 6076,
+			name: "InvalidBundledPositionAccounts",
+			msg: "Invalid bundled position accounts",
+		},
+		{
+			code: 6077,
+			name: "InvalidPositionDelegatePermissions",
+			msg: "Invalid position delegate permissions",
+		},
+		{
+			code: 6078,
+			name: "PositionDelegatePermissionDenied",
+			msg: "Position delegate does not have the required permissions",
+		},
+		{
+			code: 6079,
+			name: "PositionDelegateInvalidDestination",
+			msg: "Position delegate can only transfer tokens to the position owner",
+		},
+		{
+			code: 6080,
+			name: "InvalidPositionMetadataUpdateAuthority",
+			msg: "Position token metadata is not updatable by the position",
+		},
+		{
+			code: 6081,
+			name: "InvalidRewardEmissionsSchedule",
+			msg: "Invalid reward emissions schedule",
+		},
+		{
+			code: 6082,
+			name: "InvalidRewardExtensionAccounts",
+			msg: "Invalid reward extension accounts",
+		},
+		{
+			code: 6083,
+			name: "RewardExtensionNotSupported",
+			msg: "Instruction does not support yevefis with a reward extension",
+		},
+		{
+			code: 6084,
+			name: "RewardSurplusInsufficient",
+			msg: "Withdrawal exceeds the surplus of the reward vault",
+		},
+		{
+			code: 6085,
+			name: "RewardEmissionsNotExtendable",
+			msg: "Reward emissions have no end or have already ended",
+		},
+		{
+			code: 6086,
+			name: "InvalidPositionLock",
+			msg: "Position lock account is missing or invalid",
+		},
+		{
+			code: 6087,
+			name: "InvalidBundledPositionBump",
+			msg: "Bundled position bump does not derive the bundled position address",
+		},
+		{
+			code: 6088,
+			name: "InvalidConfigExtension",
+			msg: "Config extension account is missing or invalid",
+		},
+		{
+			code: 6089,
+			name: "InvalidOracle",
+			msg: "Oracle account is missing or invalid",
+		},
 	],
 };
@@ -195,6 +195,14 @@ export class PositionImpl implements Position {
 					yevefi.tickSpacing,
 				),
 			).publicKey,
+			yevefisConfigExtension: PDAUtil.getConfigExtension(
+				this.ctx.program.programId,
+				yevefi.yevefisConfig,
+			).publicKey,
+			oracle: PDAUtil.getOracle(
+				this.ctx.program.programId,
+				this.data.yevefi,
+			).publicKey,
 			positionAuthority: positionWalletKey,
 		});
 		txBuilder.addInstruction(increaseIx);
@@ -293,6 +301,14 @@ export class PositionImpl implements Position {
 					yevefi.tickSpacing,
 				),
 			).publicKey,
+			positionLock: PDAUtil.getPositionLock(
+				this.ctx.program.programId,
+				this.address,
+			).publicKey,
+			oracle: PDAUtil.getOracle(
+				this.ctx.program.programId,
+				this.data.yevefi,
+			).publicKey,
 			positionAuthority: positionWalletKey,
 		});
 		txBuilder.addInstruction(decreaseIx);
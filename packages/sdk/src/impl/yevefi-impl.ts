@@ -399,6 +399,14 @@ export class YevefiImpl implements Yevefi {
 			tokenVaultB: yevefi.tokenVaultB,
 			tickArrayLower: tickArrayLowerPda.publicKey,
 			tickArrayUpper: tickArrayUpperPda.publicKey,
+			yevefisConfigExtension: PDAUtil.getConfigExtension(
+				this.ctx.program.programId,
+				yevefi.yevefisConfig,
+			).publicKey,
+			oracle: PDAUtil.getOracle(
+				this.ctx.program.programId,
+				this.address,
+			).publicKey,
 		});
 		txBuilder.addInstruction(liquidityIx);
 
@@ -590,6 +598,14 @@ export class YevefiImpl implements Yevefi {
 				tokenVaultB: yevefi.tokenVaultB,
 				tickArrayLower,
 				tickArrayUpper,
+				positionLock: PDAUtil.getPositionLock(
+					this.ctx.program.programId,
+					positionAddress,
+				).publicKey,
+				oracle: PDAUtil.getOracle(
+					this.ctx.program.programId,
+					positionData.yevefi,
+				).publicKey,
 			});
 
 			txBuilder.addInstruction(liquidityIx);
@@ -628,6 +644,10 @@ export class YevefiImpl implements Yevefi {
 			positionTokenAccount,
 			position: positionAddress,
 			positionMint: positionData.positionMint,
+			positionLock: PDAUtil.getPositionLock(
+				this.ctx.program.programId,
+				positionAddress,
+			).publicKey,
 		});
 
 		txBuilder.addInstruction(positionIx);
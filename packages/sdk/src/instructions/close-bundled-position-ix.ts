@@ -13,6 +13,7 @@ import type { Yevefi } from "../artifacts/yevefi";
  * @param positionBundleAuthority - authority that owns the token corresponding to this desired bundled position.
  * @param bundleIndex - The bundle index that holds the bundled position.
  * @param receiver - PublicKey for the wallet that will receive the rented lamports.
+ * @param positionLock - PublicKey for the position lock PDA of the bundled position, see PDAUtil.getPositionLock. Required whether or not the position is locked. Closed along with the position if initialized.
  */
 export type CloseBundledPositionParams = {
 	bundledPosition: PublicKey;
@@ -21,6 +22,7 @@ export type CloseBundledPositionParams = {
 	positionBundleAuthority: PublicKey;
 	bundleIndex: number;
 	receiver: PublicKey;
+	positionLock: PublicKey;
 };
 
 /**
@@ -29,6 +31,8 @@ export type CloseBundledPositionParams = {
  * #### Special Errors
  * `InvalidBundleIndex` - If the provided bundle index is out of bounds.
  * `ClosePositionNotEmpty` - The provided position account is not empty.
+ * `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.
+ * `InvalidPositionLock` - The provided position lock is not the PositionLock PDA of the position.
  *
  * @category Instructions
  * @param program - program object containing services required to generate the instruction
@@ -46,6 +50,7 @@ export function closeBundledPositionIx(
 		positionBundleAuthority,
 		bundleIndex,
 		receiver,
+		positionLock,
 	} = params;
 
 	const ix = program.instruction.closeBundledPosition(bundleIndex, {
@@ -56,6 +61,9 @@ export function closeBundledPositionIx(
 			positionBundleAuthority,
 			receiver,
 		},
+		remainingAccounts: [
+			{ pubkey: positionLock, isSigner: false, isWritable: true },
+		],
 	});
 
 	return {
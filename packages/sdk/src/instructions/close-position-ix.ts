@@ -13,6 +13,7 @@ import type { Yevefi } from "../artifacts/yevefi";
  * @param positionMint - PublicKey for the mint token for the Position token.
  * @param positionTokenAccount - The associated token address for the position token in the owners wallet.
  * @param positionAuthority - Authority that owns the position token.
+ * @param positionLock - PublicKey for the position lock PDA of this position, see PDAUtil.getPositionLock. Required whether or not the position is locked. Closed along with the position if initialized.
  */
 export type ClosePositionParams = {
 	receiver: PublicKey;
@@ -20,6 +21,7 @@ export type ClosePositionParams = {
 	positionMint: PublicKey;
 	positionTokenAccount: PublicKey;
 	positionAuthority: PublicKey;
+	positionLock: PublicKey;
 };
 
 /**
@@ -40,6 +42,7 @@ export function closePositionIx(
 		position,
 		positionMint,
 		positionTokenAccount,
+		positionLock,
 	} = params;
 
 	const ix = program.instruction.closePosition({
@@ -51,6 +54,9 @@ export function closePositionIx(
 			positionTokenAccount,
 			tokenProgram: TOKEN_PROGRAM_ID,
 		},
+		remainingAccounts: [
+			{ pubkey: positionLock, isSigner: false, isWritable: true },
+		],
 	});
 
 	return {
@@ -195,6 +195,11 @@ export async function getSwapFromRoute(
 			const accA = ataInstructionMap[mintA.toString()].address;
 			const accB = ataInstructionMap[mintB.toString()].address;
 			const oraclePda = PDAUtil.getOracle(ctx.program.programId, wp);
+			const yevefisConfigExtension = await getConfigExtensionForPool(
+				ctx,
+				wp,
+				opts,
+			);
 			txBuilder.addInstruction(
 				swapIx(ctx.program, {
 					yevefi: wp,
@@ -203,6 +208,7 @@ export async function getSwapFromRoute(
 					tokenVaultA,
 					tokenVaultB,
 					oracle: oraclePda.publicKey,
+					yevefisConfigExtension,
 					tokenAuthority: wallet,
 					...quote,
 				}),
@@ -250,6 +256,16 @@ export async function getSwapFromRoute(
 				ctx.program.programId,
 				wpTwo,
 			).publicKey;
+			const yevefisConfigExtensionOne = await getConfigExtensionForPool(
+				ctx,
+				wpOne,
+				opts,
+			);
+			const yevefisConfigExtensionTwo = await getConfigExtensionForPool(
+				ctx,
+				wpTwo,
+				opts,
+			);
 
 			const tokenOwnerAccountOneA =
 				ataInstructionMap[mintOneA.toString()].address;
@@ -274,6 +290,8 @@ export async function getSwapFromRoute(
 					tokenVaultTwoB,
 					oracleOne,
 					oracleTwo,
+					yevefisConfigExtensionOne,
+					yevefisConfigExtensionTwo,
 					tokenAuthority: wallet,
 				}),
 			);
@@ -282,6 +300,21 @@ export async function getSwapFromRoute(
 	return txBuilder;
 }
 
+async function getConfigExtensionForPool(
+	ctx: YevefiContext,
+	yevefi: PublicKey,
+	opts: YevefiAccountFetchOptions,
+): Promise<PublicKey> {
+	const yevefiData = await ctx.fetcher.getPool(yevefi, opts);
+	if (!yevefiData) {
+		throw new Error(`Yevefi not found: ${yevefi.toBase58()}`);
+	}
+	return PDAUtil.getConfigExtension(
+		ctx.program.programId,
+		yevefiData.yevefisConfig,
+	).publicKey;
+}
+
 function adjustQuoteForSlippage(
 	quote: SubTradeRoute,
 	slippage: Percentage,
@@ -20,6 +20,8 @@ import type { Yevefi } from "../artifacts/yevefi";
  * @param tokenVaultB - PublicKey for the tokenB vault for this yevefi.
  * @param tickArrayLower - PublicKey for the tick-array account that hosts the tick at the lower tick index.
  * @param tickArrayUpper - PublicKey for the tick-array account that hosts the tick at the upper tick index.
+ * @param positionLock - PublicKey for the position lock PDA of this position, see PDAUtil.getPositionLock. Required whether or not the position is locked.
+ * @param oracle - PublicKey for the oracle PDA of this yevefi, see PDAUtil.getOracle. Required whether or not it is initialized, and written to once it is.
  * @param positionAuthority - authority that owns the token corresponding to this desired position.
  */
 export type DecreaseLiquidityParams = {
@@ -32,6 +34,8 @@ export type DecreaseLiquidityParams = {
 	tokenVaultB: PublicKey;
 	tickArrayLower: PublicKey;
 	tickArrayUpper: PublicKey;
+	positionLock: PublicKey;
+	oracle: PublicKey;
 	positionAuthority: PublicKey;
 } & DecreaseLiquidityInput;
 
@@ -51,6 +55,9 @@ export type DecreaseLiquidityInput = {
  * - `LiquidityZero` - Provided liquidity amount is zero.
  * - `LiquidityTooHigh` - Provided liquidity exceeds u128::max.
  * - `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.
+ * - `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.
+ * - `InvalidPositionLock` - The provided position lock is not the PositionLock PDA of the position.
+ * - `InvalidOracle` - The provided oracle is not the oracle PDA of the yevefi.
  *
  * @category Instructions
  * @param context - Context object containing services required to generate the instruction
@@ -75,6 +82,8 @@ export function decreaseLiquidityIx(
 		tokenVaultB,
 		tickArrayLower,
 		tickArrayUpper,
+		positionLock,
+		oracle,
 	} = params;
 
 	const ix = program.instruction.decreaseLiquidity(
@@ -95,6 +104,10 @@ export function decreaseLiquidityIx(
 				tickArrayLower,
 				tickArrayUpper,
 			},
+			remainingAccounts: [
+				{ pubkey: positionLock, isSigner: false, isWritable: false },
+				{ pubkey: oracle, isSigner: false, isWritable: true },
+			],
 		},
 	);
 
@@ -22,6 +22,8 @@ import type { Instruction } from "@orca-so/common-sdk";
  * @param tokenVaultB - PublicKey for the tokenB vault for this yevefi.
  * @param tickArrayLower - PublicKey for the tick-array account that hosts the tick at the lower tick index.
  * @param tickArrayUpper - PublicKey for the tick-array account that hosts the tick at the upper tick index.
+ * @param yevefisConfigExtension - PublicKey for the config extension PDA of the YevefisConfig this yevefi belongs to, see PDAUtil.getConfigExtension. Required whether or not it is initialized.
+ * @param oracle - PublicKey for the oracle PDA of this yevefi, see PDAUtil.getOracle. Required whether or not it is initialized, and written to once it is.
  * @param positionAuthority - authority that owns the token corresponding to this desired position.
  */
 export type IncreaseLiquidityParams = {
@@ -34,6 +36,8 @@ export type IncreaseLiquidityParams = {
 	tokenVaultB: PublicKey;
 	tickArrayLower: PublicKey;
 	tickArrayUpper: PublicKey;
+	yevefisConfigExtension: PublicKey;
+	oracle: PublicKey;
 	positionAuthority: PublicKey;
 } & IncreaseLiquidityInput;
 
@@ -61,6 +65,9 @@ export type IncreaseLiquidityInput = {
  * `LiquidityZero` - Provided liquidity amount is zero.
  * `LiquidityTooHigh` - Provided liquidity exceeds u128::max.
  * `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.
+ * `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.
+ * `InvalidConfigExtension` - The provided config extension is not the config extension PDA of the YevefisConfig.
+ * `InvalidOracle` - The provided oracle is not the oracle PDA of the yevefi.
  *
  * @category Instructions
  * @param context - Context object containing services required to generate the instruction
@@ -85,6 +92,8 @@ export function increaseLiquidityIx(
 		tokenVaultB,
 		tickArrayLower,
 		tickArrayUpper,
+		yevefisConfigExtension,
+		oracle,
 	} = params;
 
 	const ix = program.instruction.increaseLiquidity(
@@ -105,6 +114,10 @@ export function increaseLiquidityIx(
 				tickArrayLower,
 				tickArrayUpper,
 			},
+			remainingAccounts: [
+				{ pubkey: yevefisConfigExtension, isSigner: false, isWritable: false },
+				{ pubkey: oracle, isSigner: false, isWritable: true },
+			],
 		},
 	);
 
@@ -15,7 +15,8 @@ import type { Yevefi } from "../artifacts/yevefi";
  * @param tokenOwnerAccountB - PublicKey for the associated token account for tokenB in the collection wallet
  * @param tokenVaultA - PublicKey for the tokenA vault for this yevefi.
  * @param tokenVaultB - PublicKey for the tokenB vault for this yevefi.
- * @param oracle - PublicKey for the oracle account for this Yevefi.
+ * @param oracle - PublicKey for the oracle account for this Yevefi. Passed as writable, so that the swap is recorded once the oracle is initialized.
+ * @param yevefisConfigExtension - PublicKey for the config extension PDA of the YevefisConfig this Yevefi belongs to, see PDAUtil.getConfigExtension. Required whether or not it is initialized.
  * @param tokenAuthority - authority to withdraw tokens from the input token account
  */
 export type SwapParams = SwapInput & {
@@ -25,6 +26,7 @@ export type SwapParams = SwapInput & {
 	tokenVaultA: PublicKey;
 	tokenVaultB: PublicKey;
 	oracle: PublicKey;
+	yevefisConfigExtension: PublicKey;
 	tokenAuthority: PublicKey;
 };
 
@@ -76,6 +78,9 @@ export type DevFeeSwapInput = SwapInput & {
  * - `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.
  * - `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.
  * - `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.
+ * - `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.
+ * - `InvalidConfigExtension` - The provided config extension is not the config extension PDA of the YevefisConfig.
+ * - `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.
  *
  * ### Parameters
  * @category Instructions
@@ -103,6 +108,7 @@ export function swapIx(
 		tickArray1,
 		tickArray2,
 		oracle,
+		yevefisConfigExtension,
 	} = params;
 
 	const ix = program.instruction.swap(
@@ -125,8 +131,15 @@ export function swapIx(
 				tickArray2,
 				oracle,
 			},
+			remainingAccounts: [
+				{ pubkey: yevefisConfigExtension, isSigner: false, isWritable: false },
+			],
 		},
 	);
+	// The oracle is read-only in the v1 interface, but an initialized oracle must be writable
+	ix.keys = ix.keys.map((key) =>
+		key.pubkey.equals(oracle) ? { ...key, isWritable: true } : key,
+	);
 
 	return {
 		instructions: [ix],
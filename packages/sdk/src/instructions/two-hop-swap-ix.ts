@@ -19,8 +19,10 @@ import type { Yevefi } from "../artifacts/yevefi";
  * @param tokenVaultOneB - PublicKey for the tokenB vault for yevefiOne.
  * @param tokenVaultTwoA - PublicKey for the tokenA vault for yevefiTwo.
  * @param tokenVaultTwoB - PublicKey for the tokenB vault for yevefiTwo.
- * @param oracleOne - PublicKey for the oracle account for this yevefiOne.
- * @param oracleTwo - PublicKey for the oracle account for this yevefiTwo.
+ * @param oracleOne - PublicKey for the oracle account for this yevefiOne. Passed as writable, so that the swap is recorded once the oracle is initialized.
+ * @param oracleTwo - PublicKey for the oracle account for this yevefiTwo. Passed as writable, so that the swap is recorded once the oracle is initialized.
+ * @param yevefisConfigExtensionOne - PublicKey for the config extension PDA of the YevefisConfig yevefiOne belongs to, see PDAUtil.getConfigExtension.
+ * @param yevefisConfigExtensionTwo - PublicKey for the config extension PDA of the YevefisConfig yevefiTwo belongs to, see PDAUtil.getConfigExtension.
  * @param tokenAuthority - authority to withdraw tokens from the input token account
  * @param swapInput - Parameters in {@link TwoHopSwapInput}
  */
@@ -37,6 +39,8 @@ export type TwoHopSwapParams = TwoHopSwapInput & {
 	tokenVaultTwoB: PublicKey;
 	oracleOne: PublicKey;
 	oracleTwo: PublicKey;
+	yevefisConfigExtensionOne: PublicKey;
+	yevefisConfigExtensionTwo: PublicKey;
 	tokenAuthority: PublicKey;
 };
 
@@ -89,6 +93,9 @@ export type TwoHopSwapInput = {
  * - `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.
  * - `InvalidIntermediaryMint` - Error if the intermediary mint between hop one and two do not equal.
  * - `DuplicateTwoHopPool` - Error if yevefi one & two are the same pool.
+ * - `YevefiPaused` - One of the Yevefis, or all Yevefis of its YevefisConfig, are paused.
+ * - `InvalidConfigExtension` - A provided config extension is not the config extension PDA of its Yevefi's YevefisConfig.
+ * - `OracleNotWritable` - The oracle of one of the Yevefis is initialized but was passed as read-only.
  *
  * ### Parameters
  * @category Instructions
@@ -127,6 +134,8 @@ export function twoHopSwapIx(
 		tickArrayTwo2,
 		oracleOne,
 		oracleTwo,
+		yevefisConfigExtensionOne,
+		yevefisConfigExtensionTwo,
 	} = params;
 
 	const ix = program.instruction.twoHopSwap(
@@ -160,8 +169,26 @@ export function twoHopSwapIx(
 				oracleOne,
 				oracleTwo,
 			},
+			remainingAccounts: [
+				{
+					pubkey: yevefisConfigExtensionOne,
+					isSigner: false,
+					isWritable: false,
+				},
+				{
+					pubkey: yevefisConfigExtensionTwo,
+					isSigner: false,
+					isWritable: false,
+				},
+			],
 		},
 	);
+	// The oracles are read-only in the v1 interface, but an initialized oracle must be writable
+	ix.keys = ix.keys.map((key) =>
+		key.pubkey.equals(oracleOne) || key.pubkey.equals(oracleTwo)
+			? { ...key, isWritable: true }
+			: key,
+	);
 
 	return {
 		instructions: [ix],
@@ -11,6 +11,8 @@ const PDA_METADATA_SEED = "metadata";
 const PDA_TICK_ARRAY_SEED = "tick_array";
 const PDA_FEE_TIER_SEED = "fee_tier";
 const PDA_ORACLE_SEED = "oracle";
+const PDA_CONFIG_EXTENSION_SEED = "config_extension";
+const PDA_POSITION_LOCK_SEED = "position_lock";
 const PDA_POSITION_BUNDLE_SEED = "position_bundle";
 const PDA_BUNDLED_POSITION_SEED = "bundled_position";
 
@@ -179,6 +181,47 @@ export class PDAUtil {
 		);
 	}
 
+	/**
+	 * The YevefisConfigExtension PDA of a YevefisConfig. Swapping and increasing liquidity require
+	 * it whether or not it is initialized, as the global pause is only recorded in this account.
+	 *
+	 * @category Program Derived Addresses
+	 * @param programId
+	 * @param yevefisConfigAddress
+	 * @returns
+	 */
+	public static getConfigExtension(
+		programId: PublicKey,
+		yevefisConfigAddress: PublicKey,
+	) {
+		return AddressUtil.findProgramAddress(
+			[
+				Buffer.from(PDA_CONFIG_EXTENSION_SEED),
+				yevefisConfigAddress.toBuffer(),
+			],
+			programId,
+		);
+	}
+
+	/**
+	 * The PositionLock PDA of a position. Decreasing liquidity and closing a position require it
+	 * whether or not the position is locked, as the lock is only recorded in this account.
+	 *
+	 * @category Program Derived Addresses
+	 * @param programId
+	 * @param positionAddress
+	 * @returns
+	 */
+	public static getPositionLock(
+		programId: PublicKey,
+		positionAddress: PublicKey,
+	) {
+		return AddressUtil.findProgramAddress(
+			[Buffer.from(PDA_POSITION_LOCK_SEED), positionAddress.toBuffer()],
+			programId,
+		);
+	}
+
 	/**
 	 * @category Program Derived Addresses
 	 * @param programId
@@ -270,6 +270,7 @@ export class SwapUtils {
 			quote,
 			ctx,
 			yevefi.getAddress(),
+			data.yevefisConfig,
 			data.tokenVaultA,
 			data.tokenVaultB,
 			inputTokenAssociatedAddress,
@@ -282,6 +283,7 @@ export class SwapUtils {
 		quote: SwapInput,
 		ctx: YevefiContext,
 		yevefi: PublicKey,
+		yevefisConfig: PublicKey,
 		tokenVaultA: PublicKey,
 		tokenVaultB: PublicKey,
 		inputTokenAssociatedAddress: Address,
@@ -294,6 +296,10 @@ export class SwapUtils {
 			outputTokenAssociatedAddress,
 		]);
 		const oraclePda = PDAUtil.getOracle(ctx.program.programId, yevefi);
+		const configExtensionPda = PDAUtil.getConfigExtension(
+			ctx.program.programId,
+			yevefisConfig,
+		);
 		const params: SwapParams = {
 			yevefi,
 			tokenOwnerAccountA: aToB ? inputTokenATA : outputTokenATA,
@@ -301,6 +307,7 @@ export class SwapUtils {
 			tokenVaultA,
 			tokenVaultB,
 			oracle: oraclePda.publicKey,
+			yevefisConfigExtension: configExtensionPda.publicKey,
 			tokenAuthority: wallet,
 			...quote,
 		};
@@ -12,11 +12,13 @@ pub mod yevefi_nft_update_auth {
 // METADATA_URI    : max 200 bytes
 pub const TK_METADATA_NAME: &str = "SOLV3 Position";
 pub const TK_METADATA_SYMBOL: &str = "SOLV3P";
-pub const TK_METADATA_URI: &str = "https://ipfs.io/ipfs/QmWDxYzCEe3zoabwqNXCxv6NtLwzZLtPy6e94NAUg3q95z";
+pub const TK_METADATA_URI: &str =
+    "https://ipfs.io/ipfs/QmWDxYzCEe3zoabwqNXCxv6NtLwzZLtPy6e94NAUg3q95z";
 
 pub const WPB_METADATA_NAME_PREFIX: &str = "Yeve Position Bundle";
 pub const WPB_METADATA_SYMBOL: &str = "SOLV3B";
-pub const WPB_METADATA_URI: &str = "https://ipfs.io/ipfs/QmW5Q1RCsGTyWXdYaiJydzh6Yyj5AmDEGPqwRUi5StbMWk";
+pub const WPB_METADATA_URI: &str =
+    "https://ipfs.io/ipfs/QmW5Q1RCsGTyWXdYaiJydzh6Yyj5AmDEGPqwRUi5StbMWk";
 
 // Based on Token-2022 TokenMetadata extension
 //
@@ -28,3 +30,10 @@ pub const WPB_METADATA_URI: &str = "https://ipfs.io/ipfs/QmW5Q1RCsGTyWXdYaiJydzh
 pub const TK_2022_METADATA_NAME_PREFIX: &str = "SOLV3P";
 pub const TK_2022_METADATA_SYMBOL: &str = "SOLV3P";
 pub const TK_2022_METADATA_URI_BASE: &str = "https://solve3.fi/meta";
+
+// Additional fields of the TokenMetadata extension, refreshed by refresh_position_metadata
+pub const TK_2022_METADATA_FIELD_POOL: &str = "pool";
+pub const TK_2022_METADATA_FIELD_TICK_LOWER_INDEX: &str = "tick_lower_index";
+pub const TK_2022_METADATA_FIELD_TICK_UPPER_INDEX: &str = "tick_upper_index";
+pub const TK_2022_METADATA_FIELD_LIQUIDITY: &str = "liquidity";
+pub const TK_2022_METADATA_FIELD_IN_RANGE: &str = "in_range";
@@ -1,5 +1,8 @@
 pub const TRANSFER_MEMO_COLLECT_PROTOCOL_FEES: &str = "Yeve CollectProtocolFees";
 pub const TRANSFER_MEMO_COLLECT_FEES: &str = "Yeve CollectFees";
 pub const TRANSFER_MEMO_COLLECT_REWARD: &str = "Yeve CollectReward";
+pub const TRANSFER_MEMO_WITHDRAW_REWARD_SURPLUS: &str = "Yeve WithdrawRewardSurplus";
 pub const TRANSFER_MEMO_DECREASE_LIQUIDITY: &str = "Yeve Withdraw";
 pub const TRANSFER_MEMO_SWAP: &str = "Yeve Trade";
+pub const TRANSFER_MEMO_FLASH_LOAN: &str = "Yeve FlashLoan";
+pub const TRANSFER_MEMO_REFERRAL_FEE: &str = "Yeve ReferralFee";
@@ -146,6 +146,89 @@ pub enum ErrorCode {
 
     #[msg("Trade resulted in partial fill")]
     PartialFillError, // 0x17a9 (6057)
+
+    #[msg("Invalid observation cardinality")]
+    InvalidObservationCardinality, // 0x17aa (6058)
+    #[msg("Requested observation is older than the oldest observation")]
+    ObservationTooOld, // 0x17ab (6059)
+    #[msg("Too many seconds_agos provided")]
+    TooManySecondsAgos, // 0x17ac (6060)
+    #[msg("Initialized oracle must be writable")]
+    OracleNotWritable, // 0x17ad (6061)
+
+    #[msg("Swap route must have between 1 and 4 hops")]
+    InvalidSwapRouteLength, // 0x17ae (6062)
+
+    #[msg("Yevefi is locked by an in-progress flash loan or flash swap")]
+    YevefiLocked, // 0x17af (6063)
+    #[msg("Flash loan or flash swap was not repaid")]
+    FlashNotRepaid, // 0x17b0 (6064)
+
+    #[msg("Invalid adaptive fee constants")]
+    InvalidAdaptiveFeeConstants, // 0x17b1 (6065)
+    #[msg("Fee tier index must not be a tick spacing used by the config")]
+    InvalidFeeTierIndex, // 0x17b2 (6066)
+
+    #[msg("Referral fee rate exceeds MAX_REFERRAL_FEE_RATE")]
+    ReferralFeeRateMaxExceeded, // 0x17b3 (6067)
+    #[msg("Invalid referral accounts")]
+    InvalidReferralAccounts, // 0x17b4 (6068)
+
+    #[msg("Yevefi is paused")]
+    YevefiPaused, // 0x17b5 (6069)
+
+    #[msg("Position is locked")]
+    PositionLocked, // 0x17b6 (6070)
+    #[msg("Unlock timestamp must be in the future")]
+    InvalidUnlockTimestamp, // 0x17b7 (6071)
+
+    #[msg("Position must not have liquidity")]
+    PositionLiquidityNonZero, // 0x17b8 (6072)
+    #[msg("New tick range must differ from the current tick range")]
+    SameTickRangeNotAllowed, // 0x17b9 (6073)
+
+    #[msg("Liquidity is below the minimum")]
+    LiquidityBelowMinimum, // 0x17ba (6074)
+
+    #[msg("Invalid reward accounts")]
+    InvalidRewardAccounts, // 0x17bb (6075)
+    #[msg("Invalid bundled position accounts")]
+    InvalidBundledPositionAccounts, // 0x17bc (6076)
+
+    #[msg("Invalid position delegate permissions")]
+    InvalidPositionDelegatePermissions, // 0x17bd (6077)
+    #[msg("Position delegate does not have the required permissions")]
+    PositionDelegatePermissionDenied, // 0x17be (6078)
+    #[msg("Position delegate can only transfer tokens to the position owner")]
+    PositionDelegateInvalidDestination, // 0x17bf (6079)
+
+    #[msg("Position token metadata is not updatable by the position")]
+    InvalidPositionMetadataUpdateAuthority, // 0x17c0 (6080)
+
+    #[msg("Invalid reward emissions schedule")]
+    InvalidRewardEmissionsSchedule, // 0x17c1 (6081)
+
+    #[msg("Invalid reward extension accounts")]
+    InvalidRewardExtensionAccounts, // 0x17c2 (6082)
+    #[msg("Instruction does not support yevefis with a reward extension")]
+    RewardExtensionNotSupported, // 0x17c3 (6083)
+
+    #[msg("Withdrawal exceeds the surplus of the reward vault")]
+    RewardSurplusInsufficient, // 0x17c4 (6084)
+
+    #[msg("Reward emissions have no end or have already ended")]
+    RewardEmissionsNotExtendable, // 0x17c5 (6085)
+
+    #[msg("Position lock account is missing or invalid")]
+    InvalidPositionLock, // 0x17c6 (6086)
+
+    #[msg("Bundled position bump does not derive the bundled position address")]
+    InvalidBundledPositionBump, // 0x17c7 (6087)
+
+    #[msg("Config extension account is missing or invalid")]
+    InvalidConfigExtension, // 0x17c8 (6088)
+    #[msg("Oracle account is missing or invalid")]
+    InvalidOracle, // 0x17c9 (6089)
 }
 
 impl From<TryFromIntError> for ErrorCode {
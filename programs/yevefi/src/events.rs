@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct PoolInitialized {
+    pub yevefi: Pubkey,
+    pub yevefis_config: Pubkey,
+    pub token_mint_a: Pubkey,
+    pub token_mint_b: Pubkey,
+    pub tick_spacing: u16,
+    pub token_program_a: Pubkey,
+    pub token_program_b: Pubkey,
+    pub decimals_a: u8,
+    pub decimals_b: u8,
+    pub initial_sqrt_price: u128,
+}
+
+#[event]
+pub struct Traded {
+    pub yevefi: Pubkey,
+    pub a_to_b: bool,
+    pub pre_sqrt_price: u128,
+    pub post_sqrt_price: u128,
+    pub pre_tick_current_index: i32,
+    pub post_tick_current_index: i32,
+    // transfer fee included amounts
+    pub input_amount: u64,
+    pub output_amount: u64,
+    pub input_transfer_fee: u64,
+    pub output_transfer_fee: u64,
+    pub lp_fee: u64,
+    pub protocol_fee: u64,
+}
+
+#[event]
+pub struct ReferralFeePaid {
+    pub yevefi: Pubkey,
+    pub referral_token_account: Pubkey,
+    pub token_mint: Pubkey,
+    // transfer fee included amount
+    pub amount: u64,
+}
+
+#[event]
+pub struct FlashLoaned {
+    pub yevefi: Pubkey,
+    // transfer fee included amounts
+    pub amount_a: u64,
+    pub amount_b: u64,
+    pub fee_a: u64,
+    pub fee_b: u64,
+}
+
+#[event]
+pub struct LiquidityIncreased {
+    pub yevefi: Pubkey,
+    pub position: Pubkey,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    // transfer fee included amounts
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub token_a_transfer_fee: u64,
+    pub token_b_transfer_fee: u64,
+}
+
+#[event]
+pub struct LiquidityDecreased {
+    pub yevefi: Pubkey,
+    pub position: Pubkey,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    // transfer fee included amounts
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub token_a_transfer_fee: u64,
+    pub token_b_transfer_fee: u64,
+}
+
+#[event]
+pub struct ZappedOut {
+    pub yevefi: Pubkey,
+    pub position: Pubkey,
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    // withdrawn liquidity and collected fees, before the swap
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub fee_a_amount: u64,
+    pub fee_b_amount: u64,
+    // the output token is tokenB if true, tokenA otherwise
+    pub a_to_b: bool,
+    // transfer fee included amount
+    pub amount_out: u64,
+    pub transfer_fee: u64,
+}
+
+#[event]
+pub struct LiquidityRepositioned {
+    pub yevefi: Pubkey,
+    pub position: Pubkey,
+    pub old_tick_lower_index: i32,
+    pub old_tick_upper_index: i32,
+    pub old_liquidity: u128,
+    pub new_tick_lower_index: i32,
+    pub new_tick_upper_index: i32,
+    pub new_liquidity: u128,
+    // only the difference between the removed and added amounts is transferred
+    // transfer fee included amounts
+    pub token_a_deposited: u64,
+    pub token_b_deposited: u64,
+    pub token_a_withdrawn: u64,
+    pub token_b_withdrawn: u64,
+    pub token_a_transfer_fee: u64,
+    pub token_b_transfer_fee: u64,
+}
+
+#[event]
+pub struct FeesCollected {
+    pub yevefi: Pubkey,
+    pub position: Pubkey,
+    // transfer fee included amounts
+    pub fee_a_amount: u64,
+    pub fee_b_amount: u64,
+    pub fee_a_transfer_fee: u64,
+    pub fee_b_transfer_fee: u64,
+}
+
+#[event]
+pub struct FeesCompounded {
+    pub yevefi: Pubkey,
+    pub position: Pubkey,
+    pub liquidity: u128,
+    // fees reinvested as liquidity, the remainder stays owed to the position
+    pub fee_a_amount: u64,
+    pub fee_b_amount: u64,
+}
+
+#[event]
+pub struct RewardCollected {
+    pub yevefi: Pubkey,
+    pub position: Pubkey,
+    pub reward_index: u8,
+    pub reward_mint: Pubkey,
+    // transfer fee included amount
+    pub amount: u64,
+    pub transfer_fee: u64,
+}
+
+#[event]
+pub struct RewardFunded {
+    pub yevefi: Pubkey,
+    pub reward_index: u8,
+    pub reward_mint: Pubkey,
+    pub funder: Pubkey,
+    // transfer fee included amount
+    pub amount: u64,
+    // transfer fee excluded amount, recorded as funds for emissions
+    pub funded_amount: u64,
+    // 0 if the emissions have no end
+    pub emissions_end_timestamp: u64,
+}
+
+#[event]
+pub struct RewardSurplusWithdrawn {
+    pub yevefi: Pubkey,
+    pub reward_index: u8,
+    pub reward_mint: Pubkey,
+    // transfer fee included amount
+    pub amount: u64,
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::state::YevefisConfig;
+
+#[derive(Accounts)]
+pub struct AcceptCollectProtocolFeesAuthority<'info> {
+    #[account(mut)]
+    pub yevefis_config: Account<'info, YevefisConfig>,
+
+    #[account(address = yevefis_config.pending_collect_protocol_fees_authority)]
+    pub new_collect_protocol_fees_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptCollectProtocolFeesAuthority>) -> Result<()> {
+    ctx.accounts
+        .yevefis_config
+        .accept_collect_protocol_fees_authority();
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::state::YevefisConfig;
+
+#[derive(Accounts)]
+pub struct AcceptFeeAuthority<'info> {
+    #[account(mut)]
+    pub yevefis_config: Account<'info, YevefisConfig>,
+
+    #[account(address = yevefis_config.pending_fee_authority)]
+    pub new_fee_authority: Signer<'info>,
+}
+
+/// Accept the fee authority proposed by `set_fee_authority`. Only the pending fee authority has permission to invoke this instruction.
+pub fn handler(ctx: Context<AcceptFeeAuthority>) -> Result<()> {
+    ctx.accounts.yevefis_config.accept_fee_authority();
+    Ok(())
+}
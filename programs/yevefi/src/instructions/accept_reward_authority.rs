@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Yevefi;
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct AcceptRewardAuthority<'info> {
+    #[account(mut)]
+    pub yevefi: Account<'info, Yevefi>,
+
+    #[account(address = yevefi.pending_reward_authorities[reward_index as usize])]
+    pub new_reward_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptRewardAuthority>, reward_index: u8) -> Result<()> {
+    ctx.accounts
+        .yevefi
+        .accept_reward_authority(reward_index as usize)
+}
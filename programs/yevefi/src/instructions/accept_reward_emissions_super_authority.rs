@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::state::YevefisConfig;
+
+#[derive(Accounts)]
+pub struct AcceptRewardEmissionsSuperAuthority<'info> {
+    #[account(mut)]
+    pub yevefis_config: Account<'info, YevefisConfig>,
+
+    #[account(address = yevefis_config.pending_reward_emissions_super_authority)]
+    pub new_reward_emissions_super_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptRewardEmissionsSuperAuthority>) -> Result<()> {
+    ctx.accounts
+        .yevefis_config
+        .accept_reward_emissions_super_authority();
+    Ok(())
+}
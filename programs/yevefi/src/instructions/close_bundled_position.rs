@@ -1,8 +1,14 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::TokenAccount;
 
 use crate::errors::ErrorCode;
-use crate::{state::*, util::verify_position_bundle_authority};
+use crate::{
+    state::*,
+    util::{
+        close_position_lock_if_initialized, require_position_unlocked, to_timestamp_u64,
+        verify_position_authority_interface,
+    },
+};
 
 #[derive(Accounts)]
 #[instruction(bundle_index: u16)]
@@ -26,7 +32,7 @@ pub struct CloseBundledPosition<'info> {
         constraint = position_bundle_token_account.mint == position_bundle.position_bundle_mint,
         constraint = position_bundle_token_account.amount == 1
     )]
-    pub position_bundle_token_account: Box<Account<'info, TokenAccount>>,
+    pub position_bundle_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     pub position_bundle_authority: Signer<'info>,
 
@@ -35,21 +41,34 @@ pub struct CloseBundledPosition<'info> {
     pub receiver: UncheckedAccount<'info>,
 }
 
-pub fn handler(ctx: Context<CloseBundledPosition>, bundle_index: u16) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseBundledPosition<'info>>,
+    bundle_index: u16,
+) -> Result<()> {
     let position_bundle = &mut ctx.accounts.position_bundle;
 
     // Allow delegation
-    verify_position_bundle_authority(
+    verify_position_authority_interface(
         &ctx.accounts.position_bundle_token_account,
         &ctx.accounts.position_bundle_authority,
+        None,
     )?;
 
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    // The position lock PDA is the first remaining account
+    let position = ctx.accounts.bundled_position.to_account_info();
+    let position_lock = ctx.remaining_accounts.first();
+    require_position_unlocked(&position, position_lock, timestamp)?;
+
     if !Position::is_position_empty(&ctx.accounts.bundled_position) {
         return Err(ErrorCode::ClosePositionNotEmpty.into());
     }
 
     position_bundle.close_bundled_position(bundle_index)?;
 
+    close_position_lock_if_initialized(&position, position_lock, &ctx.accounts.receiver)?;
+
     // Anchor will close the Position account
 
     Ok(())
@@ -3,7 +3,10 @@ use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
 use crate::errors::ErrorCode;
 use crate::state::*;
-use crate::util::{burn_and_close_user_position_token, verify_position_authority};
+use crate::util::{
+    burn_and_close_user_position_token, close_position_lock_if_initialized,
+    require_position_unlocked, to_timestamp_u64, verify_position_authority,
+};
 
 #[derive(Accounts)]
 pub struct ClosePosition<'info> {
@@ -32,16 +35,25 @@ pub struct ClosePosition<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(ctx: Context<ClosePosition>) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClosePosition<'info>>) -> Result<()> {
     verify_position_authority(
         &ctx.accounts.position_token_account,
         &ctx.accounts.position_authority,
     )?;
 
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    // The position lock PDA is the first remaining account
+    let position = ctx.accounts.position.to_account_info();
+    let position_lock = ctx.remaining_accounts.first();
+    require_position_unlocked(&position, position_lock, timestamp)?;
+
     if !Position::is_position_empty(&ctx.accounts.position) {
         return Err(ErrorCode::ClosePositionNotEmpty.into());
     }
 
+    close_position_lock_if_initialized(&position, position_lock, &ctx.accounts.receiver)?;
+
     burn_and_close_user_position_token(
         &ctx.accounts.position_authority,
         &ctx.accounts.receiver,
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::state::*;
+use crate::util::verify_position_authority_interface;
+
+#[derive(Accounts)]
+pub struct ClosePositionDelegate<'info> {
+    pub position_authority: Signer<'info>,
+
+    /// CHECK: safe, for receiving rent only
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+
+    pub position: Account<'info, Position>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, has_one = position, close = receiver)]
+    pub position_delegate: Account<'info, PositionDelegate>,
+}
+
+pub fn handler(ctx: Context<ClosePositionDelegate>) -> Result<()> {
+    verify_position_authority_interface(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        None,
+    )?;
+
+    Ok(())
+}
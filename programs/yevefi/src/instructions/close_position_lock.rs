@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::state::*;
+use crate::util::{
+    require_position_unlocked, to_timestamp_u64, verify_position_authority_interface,
+};
+
+#[derive(Accounts)]
+pub struct ClosePositionLock<'info> {
+    pub position_authority: Signer<'info>,
+
+    /// CHECK: safe, for receiving rent only
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+
+    pub position: Account<'info, Position>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut,
+      close = receiver,
+      seeds = [b"position_lock", position.key().as_ref()],
+      bump)]
+    pub position_lock: Account<'info, PositionLock>,
+}
+
+/*
+  Closes an expired position lock of a position that stays open.
+  Closing the position closes its lock as well.
+*/
+pub fn handler(ctx: Context<ClosePositionLock>) -> Result<()> {
+    verify_position_authority_interface(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        None,
+    )?;
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    require_position_unlocked(
+        &ctx.accounts.position.to_account_info(),
+        Some(&ctx.accounts.position_lock.to_account_info()),
+        timestamp,
+    )
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::util::verify_position_authority_interface;
+
+#[derive(Accounts)]
+pub struct ClosePositionRewardExtension<'info> {
+    pub position_authority: Signer<'info>,
+
+    /// CHECK: safe, for receiving rent only
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+
+    pub position: Box<Account<'info, Position>>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut,
+      has_one = position,
+      close = receiver,
+      seeds = [b"position_reward_extension", position.key().as_ref()],
+      bump)]
+    pub position_reward_extension: Account<'info, PositionRewardExtension>,
+}
+
+/*
+  Stops tracking the extension rewards of a position.
+  All extension rewards owed must have been collected.
+*/
+pub fn handler(ctx: Context<ClosePositionRewardExtension>) -> Result<()> {
+    verify_position_authority_interface(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        None,
+    )?;
+
+    if !ctx.accounts.position_reward_extension.is_empty() {
+        return Err(ErrorCode::ClosePositionNotEmpty.into());
+    }
+
+    Ok(())
+}
@@ -4,7 +4,10 @@ use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use crate::errors::ErrorCode;
 use crate::state::*;
-use crate::util::{burn_and_close_user_position_token_2022, verify_position_authority_interface};
+use crate::util::{
+    burn_and_close_user_position_token_2022, close_position_lock_if_initialized,
+    require_position_unlocked, to_timestamp_u64, verify_position_authority_interface,
+};
 
 #[derive(Accounts)]
 pub struct ClosePositionWithTokenExtensions<'info> {
@@ -34,16 +37,28 @@ pub struct ClosePositionWithTokenExtensions<'info> {
     pub token_2022_program: Program<'info, Token2022>,
 }
 
-pub fn handler(ctx: Context<ClosePositionWithTokenExtensions>) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClosePositionWithTokenExtensions<'info>>,
+) -> Result<()> {
     verify_position_authority_interface(
         &ctx.accounts.position_token_account,
         &ctx.accounts.position_authority,
+        None,
     )?;
 
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    // The position lock PDA is the first remaining account
+    let position = ctx.accounts.position.to_account_info();
+    let position_lock = ctx.remaining_accounts.first();
+    require_position_unlocked(&position, position_lock, timestamp)?;
+
     if !Position::is_position_empty(&ctx.accounts.position) {
         return Err(ErrorCode::ClosePositionNotEmpty.into());
     }
 
+    close_position_lock_if_initialized(&position, position_lock, &ctx.accounts.receiver)?;
+
     burn_and_close_user_position_token_2022(
         &ctx.accounts.position_authority,
         &ctx.accounts.receiver,
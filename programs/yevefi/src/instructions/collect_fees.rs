@@ -3,6 +3,7 @@ use anchor_spl::token::{self, Token, TokenAccount};
 use anchor_spl::token_interface::TokenAccount as TokenAccountInterface;
 
 use crate::{
+    events::FeesCollected,
     state::*,
     util::{transfer_from_vault_to_owner, verify_position_authority_interface},
 };
@@ -39,6 +40,7 @@ pub fn handler(ctx: Context<CollectFees>) -> Result<()> {
     verify_position_authority_interface(
         &ctx.accounts.position_token_account,
         &ctx.accounts.position_authority,
+        None,
     )?;
 
     let position = &mut ctx.accounts.position;
@@ -65,5 +67,14 @@ pub fn handler(ctx: Context<CollectFees>) -> Result<()> {
         fee_owed_b,
     )?;
 
+    emit!(FeesCollected {
+        yevefi: ctx.accounts.yevefi.key(),
+        position: ctx.accounts.position.key(),
+        fee_a_amount: fee_owed_a,
+        fee_b_amount: fee_owed_b,
+        fee_a_transfer_fee: 0,
+        fee_b_transfer_fee: 0,
+    });
+
     Ok(())
 }
@@ -3,6 +3,7 @@ use anchor_spl::token::{self, Token, TokenAccount};
 use anchor_spl::token_interface::TokenAccount as TokenAccountInterface;
 
 use crate::{
+    events::RewardCollected,
     state::*,
     util::{transfer_from_vault_to_owner, verify_position_authority_interface},
 };
@@ -51,6 +52,7 @@ pub fn handler(ctx: Context<CollectReward>, reward_index: u8) -> Result<()> {
     verify_position_authority_interface(
         &ctx.accounts.position_token_account,
         &ctx.accounts.position_authority,
+        None,
     )?;
 
     let index = reward_index as usize;
@@ -69,7 +71,18 @@ pub fn handler(ctx: Context<CollectReward>, reward_index: u8) -> Result<()> {
         &ctx.accounts.reward_owner_account,
         &ctx.accounts.token_program,
         transfer_amount,
-    )
+    )?;
+
+    emit!(RewardCollected {
+        yevefi: ctx.accounts.yevefi.key(),
+        position: ctx.accounts.position.key(),
+        reward_index,
+        reward_mint: ctx.accounts.yevefi.reward_infos[index].mint,
+        amount: transfer_amount,
+        transfer_fee: 0,
+    });
+
+    Ok(())
 }
 
 fn calculate_collect_reward(position_reward: PositionRewardInfo, vault_amount: u64) -> (u64, u64) {
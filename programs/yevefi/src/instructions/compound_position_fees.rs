@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::events::FeesCompounded;
+use crate::manager::liquidity_manager::{
+    calculate_fee_and_reward_growths, calculate_liquidity_token_deltas, calculate_modify_liquidity,
+    sync_modify_liquidity_values,
+};
+use crate::math::{convert_to_liquidity_delta, get_liquidity_from_amounts};
+use crate::state::*;
+use crate::util::{
+    require_no_reward_extension, require_not_paused, to_timestamp_u64, update_oracle,
+    verify_position_authority_interface,
+};
+
+#[derive(Accounts)]
+pub struct CompoundPositionFees<'info> {
+    #[account(mut)]
+    pub yevefi: Account<'info, Yevefi>,
+
+    pub position_authority: Signer<'info>,
+
+    #[account(mut, has_one = yevefi)]
+    pub position: Account<'info, Position>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, has_one = yevefi)]
+    pub tick_array_lower: AccountLoader<'info, TickArray>,
+    #[account(mut, has_one = yevefi)]
+    pub tick_array_upper: AccountLoader<'info, TickArray>,
+
+    #[account(mut, seeds = [b"oracle", yevefi.key().as_ref()], bump)]
+    /// CHECK: Oracle is optional, checked in the handler if initialized
+    pub oracle: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"config_extension", yevefi.yevefis_config.as_ref()], bump)]
+    /// CHECK: Config extension may be uninitialized, checked in the handler
+    pub yevefis_config_extension: UncheckedAccount<'info>,
+}
+
+/*
+  Reinvests the fees owed to a position as liquidity of the same position.
+  The fees are already held by the vaults, so no tokens are transferred.
+*/
+pub fn handler(ctx: Context<CompoundPositionFees>) -> Result<()> {
+    verify_position_authority_interface(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        None,
+    )?;
+
+    require_not_paused(
+        &ctx.accounts.yevefi,
+        Some(&ctx.accounts.yevefis_config_extension.to_account_info()),
+    )?;
+    require_no_reward_extension(&ctx.accounts.yevefi)?;
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    update_oracle(&ctx.accounts.oracle, &ctx.accounts.yevefi, timestamp)?;
+
+    let (position_update, reward_infos) = calculate_fee_and_reward_growths(
+        &ctx.accounts.yevefi,
+        &ctx.accounts.position,
+        &ctx.accounts.tick_array_lower,
+        &ctx.accounts.tick_array_upper,
+        timestamp,
+    )?;
+
+    ctx.accounts.yevefi.update_rewards(reward_infos, timestamp);
+    ctx.accounts.position.update(&position_update);
+
+    let liquidity_amount = get_liquidity_from_amounts(
+        ctx.accounts.yevefi.tick_current_index,
+        ctx.accounts.yevefi.sqrt_price,
+        ctx.accounts.position.tick_lower_index,
+        ctx.accounts.position.tick_upper_index,
+        ctx.accounts.position.fee_owed_a,
+        ctx.accounts.position.fee_owed_b,
+    )?;
+
+    // Not enough fees for a unit of liquidity, keep them owed
+    if liquidity_amount == 0 {
+        return Ok(());
+    }
+
+    let liquidity_delta = convert_to_liquidity_delta(liquidity_amount, true)?;
+
+    let update = calculate_modify_liquidity(
+        &ctx.accounts.yevefi,
+        &ctx.accounts.position,
+        &ctx.accounts.tick_array_lower,
+        &ctx.accounts.tick_array_upper,
+        liquidity_delta,
+        timestamp,
+    )?;
+
+    sync_modify_liquidity_values(
+        &mut ctx.accounts.yevefi,
+        &mut ctx.accounts.position,
+        &ctx.accounts.tick_array_lower,
+        &ctx.accounts.tick_array_upper,
+        update,
+        timestamp,
+    )?;
+
+    let (delta_a, delta_b) = calculate_liquidity_token_deltas(
+        ctx.accounts.yevefi.tick_current_index,
+        ctx.accounts.yevefi.sqrt_price,
+        &ctx.accounts.position,
+        liquidity_delta,
+    )?;
+
+    // The deltas are paid from the fees owed, the remainder stays owed
+    let position = &mut ctx.accounts.position;
+    let fee_owed_a = position
+        .fee_owed_a
+        .checked_sub(delta_a)
+        .ok_or(ErrorCode::TokenMaxExceeded)?;
+    let fee_owed_b = position
+        .fee_owed_b
+        .checked_sub(delta_b)
+        .ok_or(ErrorCode::TokenMaxExceeded)?;
+    position.update_fees_owed(fee_owed_a, fee_owed_b);
+
+    emit!(FeesCompounded {
+        yevefi: ctx.accounts.yevefi.key(),
+        position: ctx.accounts.position.key(),
+        liquidity: liquidity_amount,
+        fee_a_amount: delta_a,
+        fee_b_amount: delta_b,
+    });
+
+    Ok(())
+}
@@ -1,12 +1,15 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::ErrorCode;
+use crate::events::LiquidityDecreased;
 use crate::manager::liquidity_manager::{
     calculate_liquidity_token_deltas, calculate_modify_liquidity, sync_modify_liquidity_values,
 };
 use crate::math::convert_to_liquidity_delta;
 use crate::util::{
-    to_timestamp_u64, transfer_from_vault_to_owner, verify_position_authority_interface,
+    require_no_reward_extension, require_position_unlocked, to_timestamp_u64,
+    transfer_from_vault_to_owner, update_oracle_before_liquidity_change,
+    verify_position_authority_interface,
 };
 
 use super::increase_liquidity::ModifyLiquidity;
@@ -23,8 +26,11 @@ pub fn handler(
     verify_position_authority_interface(
         &ctx.accounts.position_token_account,
         &ctx.accounts.position_authority,
+        None,
     )?;
 
+    require_no_reward_extension(&ctx.accounts.yevefi)?;
+
     let clock = Clock::get()?;
 
     if liquidity_amount == 0 {
@@ -33,6 +39,21 @@ pub fn handler(
     let liquidity_delta = convert_to_liquidity_delta(liquidity_amount, false)?;
     let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
 
+    // The position lock PDA is the first remaining account
+    require_position_unlocked(
+        &ctx.accounts.position.to_account_info(),
+        ctx.remaining_accounts.first(),
+        timestamp,
+    )?;
+
+    // The oracle PDA is the second remaining account
+    update_oracle_before_liquidity_change(
+        ctx.remaining_accounts.get(1),
+        &ctx.accounts.yevefi.key(),
+        &ctx.accounts.yevefi,
+        timestamp,
+    )?;
+
     let update = calculate_modify_liquidity(
         &ctx.accounts.yevefi,
         &ctx.accounts.position,
@@ -78,5 +99,17 @@ pub fn handler(
         delta_b,
     )?;
 
+    emit!(LiquidityDecreased {
+        yevefi: ctx.accounts.yevefi.key(),
+        position: ctx.accounts.position.key(),
+        tick_lower_index: ctx.accounts.position.tick_lower_index,
+        tick_upper_index: ctx.accounts.position.tick_upper_index,
+        liquidity: liquidity_amount,
+        token_a_amount: delta_a,
+        token_b_amount: delta_b,
+        token_a_transfer_fee: 0,
+        token_b_transfer_fee: 0,
+    });
+
     Ok(())
 }
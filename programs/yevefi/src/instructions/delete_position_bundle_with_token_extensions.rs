@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::util::burn_and_close_position_bundle_token_2022;
+
+#[derive(Accounts)]
+pub struct DeletePositionBundleWithTokenExtensions<'info> {
+    #[account(mut,
+        close = receiver,
+        seeds = [b"position_bundle".as_ref(), position_bundle_mint.key().as_ref()],
+        bump,
+    )]
+    pub position_bundle: Account<'info, PositionBundle>,
+
+    #[account(mut, address = position_bundle.position_bundle_mint, owner = token_2022_program.key())]
+    pub position_bundle_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut,
+        constraint = position_bundle_token_account.mint == position_bundle.position_bundle_mint,
+        constraint = position_bundle_token_account.owner == position_bundle_owner.key(),
+        constraint = position_bundle_token_account.amount == 1,
+    )]
+    pub position_bundle_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub position_bundle_owner: Signer<'info>,
+
+    /// CHECK: safe, for receiving rent only
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+
+    #[account(address = spl_token_2022::ID)]
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+/*
+  Deletes a PositionBundle initialized with Token-2022, and closes the mint to reclaim its rent.
+*/
+pub fn handler(ctx: Context<DeletePositionBundleWithTokenExtensions>) -> Result<()> {
+    let position_bundle = &ctx.accounts.position_bundle;
+
+    if !position_bundle.is_deletable() {
+        return Err(ErrorCode::PositionBundleNotDeletable.into());
+    }
+
+    let position_bundle_seeds = [
+        b"position_bundle".as_ref(),
+        position_bundle.position_bundle_mint.as_ref(),
+        &[ctx.bumps.position_bundle],
+    ];
+
+    burn_and_close_position_bundle_token_2022(
+        &ctx.accounts.position_bundle_owner,
+        &ctx.accounts.receiver,
+        &ctx.accounts.position_bundle_mint,
+        &ctx.accounts.position_bundle_token_account,
+        &ctx.accounts.token_2022_program,
+        position_bundle,
+        &position_bundle_seeds,
+    )
+}
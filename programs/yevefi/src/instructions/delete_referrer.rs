@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct DeleteReferrer<'info> {
+    pub yevefis_config: Box<Account<'info, YevefisConfig>>,
+
+    #[account(address = yevefis_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    #[account(
+      mut,
+      seeds = [
+        b"referrer",
+        yevefis_config.key().as_ref(),
+        referrer.referrer_authority.as_ref(),
+      ],
+      bump,
+      has_one = yevefis_config,
+      close = receiver
+    )]
+    pub referrer: Account<'info, Referrer>,
+
+    /// CHECK: safe, for receiving rent only
+    #[account(mut)]
+    pub receiver: UncheckedAccount<'info>,
+}
+
+pub fn handler(_ctx: Context<DeleteReferrer>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::state::*;
+use crate::util::{to_timestamp_u64, verify_position_authority_interface};
+
+#[derive(Accounts)]
+pub struct ExtendPositionLock<'info> {
+    pub position_authority: Signer<'info>,
+
+    pub position: Account<'info, Position>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut,
+      seeds = [b"position_lock", position.key().as_ref()],
+      bump)]
+    pub position_lock: Account<'info, PositionLock>,
+}
+
+/*
+  Moves the unlock timestamp of a position lock later.
+  An expired lock can be extended to lock the position again.
+*/
+pub fn handler(ctx: Context<ExtendPositionLock>, unlock_timestamp: u64) -> Result<()> {
+    verify_position_authority_interface(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        None,
+    )?;
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    ctx.accounts
+        .position_lock
+        .extend(unlock_timestamp, timestamp)
+}
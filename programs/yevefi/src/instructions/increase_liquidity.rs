@@ -3,13 +3,16 @@ use anchor_spl::token::{self, Token, TokenAccount};
 use anchor_spl::token_interface::TokenAccount as TokenAccountInterface;
 
 use crate::errors::ErrorCode;
+use crate::events::LiquidityIncreased;
 use crate::manager::liquidity_manager::{
     calculate_liquidity_token_deltas, calculate_modify_liquidity, sync_modify_liquidity_values,
 };
 use crate::math::convert_to_liquidity_delta;
 use crate::state::*;
 use crate::util::{
-    to_timestamp_u64, transfer_from_owner_to_vault, verify_position_authority_interface,
+    require_no_reward_extension, require_not_paused, to_timestamp_u64,
+    transfer_from_owner_to_vault, update_oracle_before_liquidity_change,
+    verify_position_authority_interface,
 };
 
 #[derive(Accounts)]
@@ -55,8 +58,13 @@ pub fn handler(
     verify_position_authority_interface(
         &ctx.accounts.position_token_account,
         &ctx.accounts.position_authority,
+        None,
     )?;
 
+    // The config extension PDA is the first remaining account
+    require_not_paused(&ctx.accounts.yevefi, ctx.remaining_accounts.first())?;
+    require_no_reward_extension(&ctx.accounts.yevefi)?;
+
     let clock = Clock::get()?;
 
     if liquidity_amount == 0 {
@@ -65,6 +73,14 @@ pub fn handler(
     let liquidity_delta = convert_to_liquidity_delta(liquidity_amount, true)?;
     let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
 
+    // The oracle PDA is the second remaining account
+    update_oracle_before_liquidity_change(
+        ctx.remaining_accounts.get(1),
+        &ctx.accounts.yevefi.key(),
+        &ctx.accounts.yevefi,
+        timestamp,
+    )?;
+
     let update = calculate_modify_liquidity(
         &ctx.accounts.yevefi,
         &ctx.accounts.position,
@@ -110,5 +126,17 @@ pub fn handler(
         delta_b,
     )?;
 
+    emit!(LiquidityIncreased {
+        yevefi: ctx.accounts.yevefi.key(),
+        position: ctx.accounts.position.key(),
+        tick_lower_index: ctx.accounts.position.tick_lower_index,
+        tick_upper_index: ctx.accounts.position.tick_upper_index,
+        liquidity: liquidity_amount,
+        token_a_amount: delta_a,
+        token_b_amount: delta_b,
+        token_a_transfer_fee: 0,
+        token_b_transfer_fee: 0,
+    });
+
     Ok(())
 }
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use solana_program::program::invoke;
+use solana_program::system_instruction::transfer;
+
+use crate::{errors::ErrorCode, state::*};
+
+#[derive(Accounts)]
+pub struct IncreaseObservationCardinality<'info> {
+    pub yevefi: Account<'info, Yevefi>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(mut, seeds = [b"oracle", yevefi.key().as_ref()], bump)]
+    pub oracle: AccountLoader<'info, Oracle>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<IncreaseObservationCardinality>,
+    observation_cardinality_next: u16,
+) -> Result<()> {
+    let current_cardinality_next = ctx.accounts.oracle.load()?.observation_cardinality_next;
+    if observation_cardinality_next <= current_cardinality_next
+        || observation_cardinality_next - current_cardinality_next
+            > MAX_OBSERVATION_CARDINALITY_INCREASE
+    {
+        return Err(ErrorCode::InvalidObservationCardinality.into());
+    }
+
+    let oracle = ctx.accounts.oracle.to_account_info();
+    let new_account_len = Oracle::space(observation_cardinality_next);
+
+    // transfer additional rent
+    let new_rent_exempt_minimum = Rent::get()?.minimum_balance(new_account_len);
+    let additional_rent = new_rent_exempt_minimum.saturating_sub(oracle.lamports());
+    if additional_rent > 0 {
+        invoke(
+            &transfer(ctx.accounts.funder.key, oracle.key, additional_rent),
+            &[
+                ctx.accounts.funder.to_account_info(),
+                oracle.clone(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    // new observation slots are zeroed, so they are treated as unwritten
+    oracle.realloc(new_account_len, true)?;
+
+    ctx.accounts
+        .oracle
+        .load_mut()?
+        .update_observation_cardinality_next(observation_cardinality_next);
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(fee_tier_index: u16)]
+pub struct InitializeAdaptiveFeeTier<'info> {
+    pub config: Box<Account<'info, YevefisConfig>>,
+
+    // The fee_tier_index is used as the tick_spacing seed of the yevefis, so an AdaptiveFeeTier
+    // shares its address with the FeeTier of the same tick_spacing. Either one can be initialized
+    // for an index, so the yevefis of the two tiers never collide.
+    #[account(init,
+      payer = funder,
+      seeds = [b"fee_tier", config.key().as_ref(),
+               fee_tier_index.to_le_bytes().as_ref()],
+      bump,
+      space = AdaptiveFeeTier::LEN)]
+    pub adaptive_fee_tier: Account<'info, AdaptiveFeeTier>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(address = config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeAdaptiveFeeTier>,
+    fee_tier_index: u16,
+    tick_spacing: u16,
+    default_base_fee_rate: u16,
+    adaptive_fee_constants: AdaptiveFeeConstants,
+) -> Result<()> {
+    ctx.accounts.adaptive_fee_tier.initialize(
+        &ctx.accounts.config,
+        fee_tier_index,
+        tick_spacing,
+        default_base_fee_rate,
+        adaptive_fee_constants,
+    )
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use anchor_lang::prelude::Pubkey;
+
+    // seeds of both InitializeFeeTier.fee_tier and InitializeAdaptiveFeeTier.adaptive_fee_tier
+    fn fee_tier_address(config: &Pubkey, tick_spacing_seed: u16) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                b"fee_tier",
+                config.as_ref(),
+                tick_spacing_seed.to_le_bytes().as_ref(),
+            ],
+            &crate::ID,
+        )
+        .0
+    }
+
+    #[test]
+    fn test_adaptive_fee_tier_index_collides_with_fee_tier() {
+        let config = Pubkey::new_unique();
+        let fee_tier = fee_tier_address(&config, 64);
+
+        // an AdaptiveFeeTier with fee_tier_index 64 cannot be initialized once the FeeTier with
+        // tick_spacing 64 exists, and vice versa
+        assert_eq!(fee_tier_address(&config, 64), fee_tier);
+        // other indexes and configs are not affected
+        assert_ne!(fee_tier_address(&config, 1024), fee_tier);
+        assert_ne!(fee_tier_address(&Pubkey::new_unique(), 64), fee_tier);
+    }
+}
@@ -6,6 +6,7 @@ use anchor_lang::prelude::*;
 pub struct InitializeFeeTier<'info> {
     pub config: Box<Account<'info, YevefisConfig>>,
 
+    // Shares its address with the AdaptiveFeeTier using tick_spacing as fee_tier_index
     #[account(init,
       payer = funder,
       seeds = [b"fee_tier", config.key().as_ref(),
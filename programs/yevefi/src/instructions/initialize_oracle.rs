@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeOracle<'info> {
+    pub yevefi: Account<'info, Yevefi>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"oracle", yevefi.key().as_ref()],
+      bump,
+      space = Oracle::space(1))]
+    pub oracle: AccountLoader<'info, Oracle>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeOracle>) -> Result<()> {
+    let mut oracle = ctx.accounts.oracle.load_init()?;
+    oracle.initialize(ctx.accounts.yevefi.key());
+    Ok(())
+}
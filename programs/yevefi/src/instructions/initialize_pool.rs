@@ -1,4 +1,4 @@
-use crate::state::*;
+use crate::{events::PoolInitialized, state::*};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
@@ -69,11 +69,27 @@ pub fn handler(
         yevefis_config,
         bump,
         tick_spacing,
+        tick_spacing,
         initial_sqrt_price,
         default_fee_rate,
         token_mint_a,
         ctx.accounts.token_vault_a.key(),
         token_mint_b,
         ctx.accounts.token_vault_b.key(),
-    )
+    )?;
+
+    emit!(PoolInitialized {
+        yevefi: yevefi.key(),
+        yevefis_config: yevefis_config.key(),
+        token_mint_a,
+        token_mint_b,
+        tick_spacing,
+        token_program_a: ctx.accounts.token_program.key(),
+        token_program_b: ctx.accounts.token_program.key(),
+        decimals_a: ctx.accounts.token_mint_a.decimals,
+        decimals_b: ctx.accounts.token_mint_b.decimals,
+        initial_sqrt_price,
+    });
+
+    Ok(())
 }
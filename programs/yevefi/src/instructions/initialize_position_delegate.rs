@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::state::*;
+use crate::util::verify_position_authority_interface;
+
+#[derive(Accounts)]
+pub struct InitializePositionDelegate<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub position_authority: Signer<'info>,
+
+    pub position: Account<'info, Position>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: safe, the delegate can be any account
+    pub delegate: UncheckedAccount<'info>,
+
+    #[account(init,
+      payer = funder,
+      seeds = [b"position_delegate", position.key().as_ref(), delegate.key().as_ref()],
+      bump,
+      space = PositionDelegate::LEN)]
+    pub position_delegate: Account<'info, PositionDelegate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+  Grants the delegate the permissions on a position.
+  The permissions are void once the position token is held by another owner.
+*/
+pub fn handler(ctx: Context<InitializePositionDelegate>, permissions: u8) -> Result<()> {
+    verify_position_authority_interface(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        None,
+    )?;
+
+    ctx.accounts.position_delegate.initialize(
+        ctx.accounts.position.key(),
+        ctx.accounts.position_token_account.owner,
+        ctx.accounts.delegate.key(),
+        permissions,
+    )
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    manager::{
+        liquidity_manager::calculate_extension_reward_growths_inside,
+        yevefi_manager::next_yevefi_reward_extension_infos,
+    },
+    state::*,
+    util::{to_timestamp_u64, verify_position_authority_interface},
+};
+
+#[derive(Accounts)]
+pub struct InitializePositionRewardExtension<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub position_authority: Signer<'info>,
+
+    #[account(has_one = yevefi)]
+    pub position: Box<Account<'info, Position>>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub yevefi: Box<Account<'info, Yevefi>>,
+
+    #[account(mut, has_one = yevefi)]
+    pub yevefi_reward_extension: Box<Account<'info, YevefiRewardExtension>>,
+
+    #[account(has_one = yevefi)]
+    pub tick_array_lower_reward_extension: AccountLoader<'info, TickArrayRewardExtension>,
+    #[account(has_one = yevefi)]
+    pub tick_array_upper_reward_extension: AccountLoader<'info, TickArrayRewardExtension>,
+
+    #[account(init,
+      payer = funder,
+      seeds = [b"position_reward_extension", position.key().as_ref()],
+      bump,
+      space = PositionRewardExtension::LEN)]
+    pub position_reward_extension: Box<Account<'info, PositionRewardExtension>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+  Starts tracking the extension rewards of a position.
+  The position only earns extension rewards from this point on.
+*/
+pub fn handler(ctx: Context<InitializePositionRewardExtension>) -> Result<()> {
+    verify_position_authority_interface(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        None,
+    )?;
+
+    let yevefi = &ctx.accounts.yevefi;
+    let yevefi_reward_extension = &mut ctx.accounts.yevefi_reward_extension;
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    let next_reward_infos =
+        next_yevefi_reward_extension_infos(yevefi, yevefi_reward_extension, timestamp)?;
+    yevefi_reward_extension.update_rewards(yevefi.liquidity, next_reward_infos, timestamp);
+
+    let reward_growths_inside = calculate_extension_reward_growths_inside(
+        yevefi,
+        &ctx.accounts.position,
+        &*ctx.accounts.tick_array_lower_reward_extension.load()?,
+        &*ctx.accounts.tick_array_upper_reward_extension.load()?,
+        &next_reward_infos,
+    )?;
+
+    ctx.accounts
+        .position_reward_extension
+        .initialize(ctx.accounts.position.key(), reward_growths_inside);
+
+    Ok(())
+}
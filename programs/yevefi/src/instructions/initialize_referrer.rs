@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeReferrer<'info> {
+    pub yevefis_config: Box<Account<'info, YevefisConfig>>,
+
+    #[account(address = yevefis_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+
+    /// CHECK: safe, the referrer only receives referral fees in its token accounts
+    pub referrer_authority: UncheckedAccount<'info>,
+
+    #[account(init,
+      payer = funder,
+      seeds = [
+        b"referrer",
+        yevefis_config.key().as_ref(),
+        referrer_authority.key().as_ref(),
+      ],
+      bump,
+      space = Referrer::LEN)]
+    pub referrer: Account<'info, Referrer>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeReferrer>) -> Result<()> {
+    ctx.accounts.referrer.initialize(
+        ctx.accounts.yevefis_config.key(),
+        ctx.accounts.referrer_authority.key(),
+    )
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Yevefi, YevefiRewardExtension, YevefisConfig},
+    util::to_timestamp_u64,
+};
+
+#[derive(Accounts)]
+pub struct InitializeRewardExtension<'info> {
+    pub yevefis_config: Box<Account<'info, YevefisConfig>>,
+
+    #[account(mut, has_one = yevefis_config)]
+    pub yevefi: Box<Account<'info, Yevefi>>,
+
+    #[account(address = yevefis_config.reward_emissions_super_authority)]
+    pub reward_emissions_super_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"reward_extension", yevefi.key().as_ref()],
+      bump,
+      space = YevefiRewardExtension::LEN)]
+    pub yevefi_reward_extension: Account<'info, YevefiRewardExtension>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+  Enables the extension rewards of a yevefi.
+  Once enabled, swaps and liquidity changes are only supported by the instructions maintaining
+  the extension rewards.
+*/
+pub fn handler(ctx: Context<InitializeRewardExtension>) -> Result<()> {
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    ctx.accounts.yevefi.initialize_reward_extension();
+    ctx.accounts.yevefi_reward_extension.initialize(
+        ctx.accounts.yevefi.key(),
+        ctx.accounts.reward_emissions_super_authority.key(),
+        timestamp,
+    );
+
+    Ok(())
+}
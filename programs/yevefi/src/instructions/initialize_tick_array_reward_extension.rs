@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    manager::yevefi_manager::next_yevefi_reward_extension_infos, state::*, util::to_timestamp_u64,
+};
+
+#[derive(Accounts)]
+#[instruction(start_tick_index: i32)]
+pub struct InitializeTickArrayRewardExtension<'info> {
+    pub yevefi: Box<Account<'info, Yevefi>>,
+
+    #[account(mut, has_one = yevefi)]
+    pub yevefi_reward_extension: Account<'info, YevefiRewardExtension>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+      init,
+      payer = funder,
+      seeds = [b"tick_array_reward_extension", yevefi.key().as_ref(), start_tick_index.to_string().as_bytes()],
+      bump,
+      space = TickArrayRewardExtension::LEN)]
+    pub tick_array_reward_extension: AccountLoader<'info, TickArrayRewardExtension>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeTickArrayRewardExtension>,
+    start_tick_index: i32,
+) -> Result<()> {
+    let yevefi = &ctx.accounts.yevefi;
+    let yevefi_reward_extension = &mut ctx.accounts.yevefi_reward_extension;
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    let next_reward_infos =
+        next_yevefi_reward_extension_infos(yevefi, yevefi_reward_extension, timestamp)?;
+    yevefi_reward_extension.update_rewards(yevefi.liquidity, next_reward_infos, timestamp);
+
+    let mut tick_array_reward_extension = ctx.accounts.tick_array_reward_extension.load_init()?;
+    tick_array_reward_extension.initialize(
+        yevefi,
+        yevefi.key(),
+        start_tick_index,
+        next_reward_infos.map(|reward_info| reward_info.growth_global_x64),
+    )
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::state::*;
+use crate::util::{to_timestamp_u64, verify_position_authority_interface};
+
+#[derive(Accounts)]
+pub struct LockPosition<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub position_authority: Signer<'info>,
+
+    pub position: Account<'info, Position>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(init,
+      payer = funder,
+      seeds = [b"position_lock", position.key().as_ref()],
+      bump,
+      space = PositionLock::LEN)]
+    pub position_lock: Account<'info, PositionLock>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+  Locks the liquidity of a position until unlock_timestamp.
+  Bundled positions are locked by the holder of the position bundle token.
+  Instructions checking the lock always take the position lock PDA, initialized or not.
+*/
+pub fn handler(ctx: Context<LockPosition>, unlock_timestamp: u64) -> Result<()> {
+    verify_position_authority_interface(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        None,
+    )?;
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    ctx.accounts
+        .position_lock
+        .initialize(ctx.accounts.position.key(), unlock_timestamp, timestamp)
+}
@@ -0,0 +1,263 @@
+use anchor_lang::{error::ErrorCode as AnchorErrorCode, prelude::*, Discriminator};
+use anchor_spl::token_interface::TokenAccount;
+use solana_program::program::invoke;
+use solana_program::system_instruction::transfer;
+
+use crate::errors::ErrorCode;
+use crate::state::{Yevefi, NUM_REWARDS};
+
+#[derive(Accounts)]
+pub struct MigrateYevefis<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    // remaining accounts
+    // - for each yevefi to migrate:
+    //   - yevefi (writable)
+    //   - reward vaults of its initialized rewards, in reward index order
+}
+
+/*
+  Migrates every yevefi passed as a remaining account, so that all pools of a deployment can be
+  brought to the current layout with a few transactions. Yevefis which already have the current
+  layout are skipped, so that batches sent concurrently do not fail each other.
+
+  The reward totals did not exist in the previous layout, so the rewards already earned by the
+  positions are unknown. The whole reward vault amounts are kept owed to the positions, so that
+  they cannot be withdrawn as surplus, and fund the emissions, so that they do not stop.
+*/
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, MigrateYevefis<'info>>) -> Result<()> {
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+    while let Some(yevefi) = remaining_accounts.next() {
+        // A yevefi with the previous layout cannot be deserialized, so it is checked manually
+        if yevefi.owner != &crate::ID {
+            return Err(AnchorErrorCode::ConstraintOwner.into());
+        }
+        if !yevefi.is_writable {
+            return Err(AnchorErrorCode::ConstraintMut.into());
+        }
+        let (migratable, reward_infos) = {
+            let data = yevefi.try_borrow_data()?;
+            (
+                is_migratable(&data)?,
+                deserialize_migrated_yevefi(&data)?.reward_infos,
+            )
+        };
+
+        // The reward vaults follow their yevefi even if it is skipped
+        let mut reward_vault_amounts = [0u64; NUM_REWARDS];
+        for (reward_info, reward_vault_amount) in
+            reward_infos.iter().zip(reward_vault_amounts.iter_mut())
+        {
+            if !reward_info.initialized() {
+                continue;
+            }
+            let reward_vault = remaining_accounts
+                .next()
+                .ok_or(ErrorCode::InvalidRewardAccounts)?;
+            if reward_vault.key() != reward_info.vault {
+                return Err(ErrorCode::InvalidRewardAccounts.into());
+            }
+            *reward_vault_amount = InterfaceAccount::<TokenAccount>::try_from(reward_vault)?.amount;
+        }
+        if !migratable {
+            continue;
+        }
+
+        migrate_yevefi(
+            yevefi,
+            &reward_vault_amounts,
+            &ctx.accounts.funder,
+            &ctx.accounts.system_program,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn migrate_yevefi<'info>(
+    yevefi: &AccountInfo<'info>,
+    reward_vault_amounts: &[u64; NUM_REWARDS],
+    funder: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    // transfer additional rent
+    let new_rent_exempt_minimum = Rent::get()?.minimum_balance(Yevefi::LEN);
+    let additional_rent = new_rent_exempt_minimum.saturating_sub(yevefi.lamports());
+    if additional_rent > 0 {
+        invoke(
+            &transfer(funder.key, yevefi.key, additional_rent),
+            &[
+                funder.to_account_info(),
+                yevefi.clone(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    // the fields appended to the layout are zeroed, which leaves the features they back disabled
+    yevefi.realloc(Yevefi::LEN, true)?;
+
+    let mut migrated_yevefi = deserialize_migrated_yevefi(&yevefi.try_borrow_data()?)?;
+    seed_reward_totals(&mut migrated_yevefi, reward_vault_amounts);
+    migrated_yevefi.try_serialize(&mut yevefi.try_borrow_mut_data()?.as_mut())?;
+
+    Ok(())
+}
+
+// Returns the yevefi as it is after its migration, with the appended fields zeroed.
+fn deserialize_migrated_yevefi(data: &[u8]) -> Result<Yevefi> {
+    let mut data = data.to_vec();
+    data.resize(Yevefi::LEN, 0);
+    Yevefi::try_deserialize(&mut data.as_slice())
+}
+
+fn seed_reward_totals(yevefi: &mut Yevefi, reward_vault_amounts: &[u64; NUM_REWARDS]) {
+    for (reward_totals, reward_vault_amount) in
+        yevefi.reward_totals.iter_mut().zip(reward_vault_amounts)
+    {
+        // the vault is owed to the positions, so nothing is left to fund emissions
+        reward_totals.funded = 0;
+        reward_totals.legacy_owed = *reward_vault_amount;
+    }
+}
+
+// Fields are only ever appended to Yevefi, so any shorter yevefi account is a prefix of the
+// current layout and can be migrated by extending it with zeroes.
+// Returns false if the yevefi already has the current layout.
+fn is_migratable(data: &[u8]) -> Result<bool> {
+    if data.len() < 8 || data[..8] != Yevefi::discriminator() {
+        return Err(AnchorErrorCode::AccountDiscriminatorMismatch.into());
+    }
+    Ok(data.len() < Yevefi::LEN)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::manager::yevefi_manager::next_yevefi_reward_infos;
+    use crate::state::{
+        yevefi_builder::YevefiBuilder, AdaptiveFeeInfo, YevefiRewardInfo, YevefiRewardTotals,
+        NUM_REWARDS,
+    };
+
+    fn serialize(yevefi: &Yevefi) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Yevefi::LEN);
+        yevefi.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), Yevefi::LEN);
+        data
+    }
+
+    fn legacy_yevefi_data() -> Vec<u8> {
+        let yevefi = YevefiBuilder::new()
+            .liquidity(1_000_000)
+            .sqrt_price(1 << 64)
+            .tick_current_index(0)
+            .fee_growth_global_a(1234)
+            .fee_growth_global_b(5678)
+            .reward_info(
+                0,
+                YevefiRewardInfo {
+                    mint: Pubkey::new_unique(),
+                    vault: Pubkey::new_unique(),
+                    emissions_per_second_x64: 1 << 64,
+                    ..Default::default()
+                },
+            )
+            .build();
+        let mut data = serialize(&yevefi);
+        data.truncate(Yevefi::LEGACY_LEN);
+        data
+    }
+
+    #[test]
+    fn test_legacy_yevefi_cannot_be_deserialized() {
+        let data = legacy_yevefi_data();
+        assert!(Yevefi::try_deserialize(&mut data.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_migrated_legacy_yevefi_keeps_fields() {
+        let mut data = legacy_yevefi_data();
+        assert!(is_migratable(&data).unwrap());
+
+        data.resize(Yevefi::LEN, 0);
+        let yevefi = Yevefi::try_deserialize(&mut data.as_slice()).unwrap();
+
+        assert_eq!(yevefi.liquidity, 1_000_000);
+        assert_eq!(yevefi.sqrt_price, 1 << 64);
+        assert_eq!(yevefi.fee_growth_global_a, 1234);
+        assert_eq!(yevefi.fee_growth_global_b, 5678);
+
+        // appended fields are disabled
+        assert!(!yevefi.reentrancy_lock);
+        assert_eq!(yevefi.adaptive_fee_info, AdaptiveFeeInfo::default());
+        assert!(!yevefi.paused);
+        assert!(!yevefi.reward_extension_initialized);
+        for i in 0..NUM_REWARDS {
+            assert_eq!(yevefi.reward_schedules[i], Default::default());
+            assert_eq!(yevefi.reward_totals[i], YevefiRewardTotals::default());
+        }
+    }
+
+    #[test]
+    fn test_migrated_reward_vault_amounts_are_not_surplus() {
+        let mut yevefi = deserialize_migrated_yevefi(&legacy_yevefi_data()).unwrap();
+
+        // the reward vault held 1000 before the migration, part of which positions had earned
+        seed_reward_totals(&mut yevefi, &[1000, 0, 0]);
+        assert_eq!(yevefi.reward_surplus(0, 1000), 0);
+        assert_eq!(yevefi.reward_totals[0].unemitted(), 0);
+
+        // positions collect the rewards they had earned
+        yevefi.record_reward_collected(0, 400);
+        yevefi.sync_reward_funded(0, 600);
+        assert_eq!(yevefi.reward_surplus(0, 600), 0);
+
+        // funds added after the migration are surplus until they are emitted
+        yevefi.record_reward_funded(0, 500);
+        assert_eq!(yevefi.reward_surplus(0, 1100), 500);
+    }
+
+    #[test]
+    fn test_migrated_reward_emissions_stop_at_surplus() {
+        let mut yevefi = deserialize_migrated_yevefi(&legacy_yevefi_data()).unwrap();
+        seed_reward_totals(&mut yevefi, &[1000, 0, 0]);
+
+        // syncing the funds with the vault does not make the owed rewards emittable
+        yevefi.sync_reward_funded(0, 1000);
+        assert_eq!(yevefi.reward_totals[0].unemitted(), 0);
+        let reward_infos = next_yevefi_reward_infos(&yevefi, 1000).unwrap();
+        yevefi.update_rewards(reward_infos, 1000);
+        assert_eq!(yevefi.reward_totals[0].emitted, 0);
+
+        // only the 300 deposited after the migration are emitted
+        yevefi.sync_reward_funded(0, 1300);
+        assert_eq!(yevefi.reward_totals[0].unemitted(), 300);
+        let reward_infos = next_yevefi_reward_infos(&yevefi, 10_000).unwrap();
+        yevefi.update_rewards(reward_infos, 10_000);
+        assert_eq!(yevefi.reward_totals[0].emitted, 300);
+        assert_eq!(yevefi.reward_surplus(0, 1300), 0);
+    }
+
+    #[test]
+    fn test_already_migrated_yevefi() {
+        let data = serialize(&YevefiBuilder::new().build());
+        assert!(!is_migratable(&data).unwrap());
+    }
+
+    #[test]
+    fn test_migrate_rejects_other_accounts() {
+        let mut data = legacy_yevefi_data();
+        data[0] ^= 0xff;
+        assert_eq!(
+            is_migratable(&data).unwrap_err(),
+            AnchorErrorCode::AccountDiscriminatorMismatch.into()
+        );
+        assert_eq!(
+            is_migratable(&[]).unwrap_err(),
+            AnchorErrorCode::AccountDiscriminatorMismatch.into()
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use anchor_lang::{error::ErrorCode as AnchorErrorCode, prelude::*, Discriminator};
+use solana_program::program::invoke;
+use solana_program::system_instruction::transfer;
+
+use crate::state::YevefisConfig;
+
+#[derive(Accounts)]
+pub struct MigrateYevefisConfig<'info> {
+    /// CHECK: a config with the previous layout cannot be deserialized, so it is checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub yevefis_config: UncheckedAccount<'info>,
+
+    pub fee_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/*
+  Migrates a YevefisConfig created before the pending authorities were appended to its layout.
+  The appended fields are zeroed, so no authority transfer is pending. A config which already has
+  the current layout is left unchanged.
+*/
+pub fn handler(ctx: Context<MigrateYevefisConfig>) -> Result<()> {
+    let yevefis_config = ctx.accounts.yevefis_config.to_account_info();
+    let (migratable, config) = {
+        let data = yevefis_config.try_borrow_data()?;
+        (is_migratable(&data)?, deserialize_migrated_config(&data)?)
+    };
+    if config.fee_authority != ctx.accounts.fee_authority.key() {
+        return Err(AnchorErrorCode::ConstraintAddress.into());
+    }
+    if !migratable {
+        return Ok(());
+    }
+
+    // transfer additional rent
+    let new_rent_exempt_minimum = Rent::get()?.minimum_balance(YevefisConfig::LEN);
+    let additional_rent = new_rent_exempt_minimum.saturating_sub(yevefis_config.lamports());
+    if additional_rent > 0 {
+        invoke(
+            &transfer(ctx.accounts.funder.key, yevefis_config.key, additional_rent),
+            &[
+                ctx.accounts.funder.to_account_info(),
+                yevefis_config.clone(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    yevefis_config.realloc(YevefisConfig::LEN, true)?;
+
+    Ok(())
+}
+
+// Returns the config as it is after its migration, with the appended fields zeroed.
+fn deserialize_migrated_config(data: &[u8]) -> Result<YevefisConfig> {
+    let mut data = data.to_vec();
+    data.resize(YevefisConfig::LEN, 0);
+    YevefisConfig::try_deserialize(&mut data.as_slice())
+}
+
+// Returns false if the config already has the current layout.
+fn is_migratable(data: &[u8]) -> Result<bool> {
+    if data.len() < YevefisConfig::LEGACY_LEN || data[..8] != YevefisConfig::discriminator() {
+        return Err(AnchorErrorCode::AccountDiscriminatorMismatch.into());
+    }
+    Ok(data.len() < YevefisConfig::LEN)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn legacy_config_data() -> (YevefisConfig, Vec<u8>) {
+        let config = YevefisConfig {
+            fee_authority: Pubkey::new_unique(),
+            collect_protocol_fees_authority: Pubkey::new_unique(),
+            reward_emissions_super_authority: Pubkey::new_unique(),
+            default_protocol_fee_rate: 300,
+            referral_fee_rate: 1000,
+            pending_fee_authority: Pubkey::default(),
+            pending_collect_protocol_fees_authority: Pubkey::default(),
+            pending_reward_emissions_super_authority: Pubkey::default(),
+        };
+        let mut data = Vec::with_capacity(YevefisConfig::LEN);
+        config.try_serialize(&mut data).unwrap();
+        data.truncate(YevefisConfig::LEGACY_LEN);
+        (config, data)
+    }
+
+    #[test]
+    fn test_migrated_legacy_config_keeps_fields() {
+        let (config, data) = legacy_config_data();
+        assert!(is_migratable(&data).unwrap());
+
+        let migrated = deserialize_migrated_config(&data).unwrap();
+        assert_eq!(migrated.fee_authority, config.fee_authority);
+        assert_eq!(
+            migrated.collect_protocol_fees_authority,
+            config.collect_protocol_fees_authority
+        );
+        assert_eq!(
+            migrated.reward_emissions_super_authority,
+            config.reward_emissions_super_authority
+        );
+        assert_eq!(migrated.default_protocol_fee_rate, 300);
+        assert_eq!(migrated.referral_fee_rate, 1000);
+        assert_eq!(migrated.pending_fee_authority, Pubkey::default());
+    }
+
+    #[test]
+    fn test_already_migrated_config() {
+        let (_, mut data) = legacy_config_data();
+        data.resize(YevefisConfig::LEN, 0);
+        assert!(!is_migratable(&data).unwrap());
+    }
+
+    #[test]
+    fn test_migrate_rejects_other_accounts() {
+        let (_, mut data) = legacy_config_data();
+        data[0] ^= 0xff;
+        assert_eq!(
+            is_migratable(&data).unwrap_err(),
+            AnchorErrorCode::AccountDiscriminatorMismatch.into()
+        );
+        assert_eq!(
+            is_migratable(&[]).unwrap_err(),
+            AnchorErrorCode::AccountDiscriminatorMismatch.into()
+        );
+    }
+}
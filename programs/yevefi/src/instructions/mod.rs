@@ -1,32 +1,62 @@
 #![allow(unknown_lints)]
 #![allow(ambiguous_glob_reexports)]
 
+pub mod accept_collect_protocol_fees_authority;
+pub mod accept_fee_authority;
+pub mod accept_reward_authority;
+pub mod accept_reward_emissions_super_authority;
 pub mod close_bundled_position;
 pub mod close_position;
+pub mod close_position_delegate;
+pub mod close_position_lock;
+pub mod close_position_reward_extension;
 pub mod close_position_with_token_extensions;
 pub mod collect_fees;
 pub mod collect_protocol_fees;
 pub mod collect_reward;
+pub mod compound_position_fees;
 pub mod decrease_liquidity;
 pub mod delete_position_bundle;
+pub mod delete_position_bundle_with_token_extensions;
+pub mod delete_referrer;
+pub mod extend_position_lock;
 pub mod increase_liquidity;
+pub mod increase_observation_cardinality;
+pub mod initialize_adaptive_fee_tier;
 pub mod initialize_config;
 pub mod initialize_fee_tier;
+pub mod initialize_oracle;
 pub mod initialize_pool;
 pub mod initialize_position_bundle;
 pub mod initialize_position_bundle_with_metadata;
+pub mod initialize_position_bundle_with_token_extensions;
+pub mod initialize_position_delegate;
+pub mod initialize_position_reward_extension;
+pub mod initialize_referrer;
 pub mod initialize_reward;
+pub mod initialize_reward_extension;
 pub mod initialize_tick_array;
+pub mod initialize_tick_array_reward_extension;
+pub mod lock_position;
+pub mod migrate_yevefis;
+pub mod migrate_yevefis_config;
+pub mod observe;
 pub mod open_bundled_position;
 pub mod open_position;
 pub mod open_position_with_metadata;
 pub mod open_position_with_token_extensions;
+pub mod refresh_position_metadata;
+pub mod reward_runway;
 pub mod set_collect_protocol_fees_authority;
+pub mod set_default_base_fee_rate;
 pub mod set_default_fee_rate;
 pub mod set_default_protocol_fee_rate;
+pub mod set_extension_reward_authority;
 pub mod set_fee_authority;
 pub mod set_fee_rate;
+pub mod set_position_delegate_permissions;
 pub mod set_protocol_fee_rate;
+pub mod set_referral_fee_rate;
 pub mod set_reward_authority;
 pub mod set_reward_authority_by_super_authority;
 pub mod set_reward_emissions;
@@ -34,33 +64,65 @@ pub mod set_reward_emissions_super_authority;
 pub mod swap;
 pub mod two_hop_swap;
 pub mod update_fees_and_rewards;
+pub mod update_position_reward_extension;
 
+pub use accept_collect_protocol_fees_authority::*;
+pub use accept_fee_authority::*;
+pub use accept_reward_authority::*;
+pub use accept_reward_emissions_super_authority::*;
 pub use close_bundled_position::*;
 pub use close_position::*;
+pub use close_position_delegate::*;
+pub use close_position_lock::*;
+pub use close_position_reward_extension::*;
 pub use close_position_with_token_extensions::*;
 pub use collect_fees::*;
 pub use collect_protocol_fees::*;
 pub use collect_reward::*;
+pub use compound_position_fees::*;
+pub use initialize_position_reward_extension::*;
+pub use initialize_reward_extension::*;
+pub use initialize_tick_array_reward_extension::*;
+pub use set_extension_reward_authority::*;
+pub use update_position_reward_extension::*;
 
 pub use delete_position_bundle::*;
+pub use delete_position_bundle_with_token_extensions::*;
+pub use delete_referrer::*;
+pub use extend_position_lock::*;
 pub use increase_liquidity::*;
+pub use increase_observation_cardinality::*;
+pub use initialize_adaptive_fee_tier::*;
 pub use initialize_config::*;
 pub use initialize_fee_tier::*;
+pub use initialize_oracle::*;
 pub use initialize_pool::*;
 pub use initialize_position_bundle::*;
 pub use initialize_position_bundle_with_metadata::*;
+pub use initialize_position_bundle_with_token_extensions::*;
+pub use initialize_position_delegate::*;
+pub use initialize_referrer::*;
 pub use initialize_reward::*;
 pub use initialize_tick_array::*;
+pub use lock_position::*;
+pub use migrate_yevefis::*;
+pub use migrate_yevefis_config::*;
+pub use observe::*;
 pub use open_bundled_position::*;
 pub use open_position::*;
 pub use open_position_with_metadata::*;
 pub use open_position_with_token_extensions::*;
+pub use refresh_position_metadata::*;
+pub use reward_runway::*;
 pub use set_collect_protocol_fees_authority::*;
+pub use set_default_base_fee_rate::*;
 pub use set_default_fee_rate::*;
 pub use set_default_protocol_fee_rate::*;
 pub use set_fee_authority::*;
 pub use set_fee_rate::*;
+pub use set_position_delegate_permissions::*;
 pub use set_protocol_fee_rate::*;
+pub use set_referral_fee_rate::*;
 pub use set_reward_authority::*;
 pub use set_reward_authority_by_super_authority::*;
 pub use set_reward_emissions::*;
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use solana_program::program::set_return_data;
+
+use crate::{manager::oracle_manager::observe, state::*, util::to_timestamp_u64};
+
+#[derive(Accounts)]
+pub struct Observe<'info> {
+    pub yevefi: Account<'info, Yevefi>,
+
+    #[account(seeds = [b"oracle", yevefi.key().as_ref()], bump)]
+    pub oracle: AccountLoader<'info, Oracle>,
+}
+
+pub fn handler(ctx: Context<Observe>, seconds_agos: Vec<u32>) -> Result<()> {
+    let yevefi = &ctx.accounts.yevefi;
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    let data = ctx.accounts.oracle.as_ref().try_borrow_data()?;
+    let (oracle, observations) = Oracle::unpack(&data)?;
+
+    let result = observe(
+        oracle,
+        observations,
+        yevefi.tick_current_index,
+        yevefi.liquidity,
+        timestamp,
+        &seconds_agos,
+    )?;
+
+    set_return_data(&result.try_to_vec()?);
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::TokenAccount;
 
-use crate::{state::*, util::verify_position_bundle_authority};
+use crate::{state::*, util::verify_position_authority_interface};
 
 #[derive(Accounts)]
 #[instruction(bundle_index: u16)]
@@ -25,7 +25,7 @@ pub struct OpenBundledPosition<'info> {
         constraint = position_bundle_token_account.mint == position_bundle.position_bundle_mint,
         constraint = position_bundle_token_account.amount == 1
     )]
-    pub position_bundle_token_account: Box<Account<'info, TokenAccount>>,
+    pub position_bundle_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     pub position_bundle_authority: Signer<'info>,
 
@@ -49,9 +49,10 @@ pub fn handler(
     let position = &mut ctx.accounts.bundled_position;
 
     // Allow delegation
-    verify_position_bundle_authority(
+    verify_position_authority_interface(
         &ctx.accounts.position_bundle_token_account,
         &ctx.accounts.position_bundle_authority,
+        None,
     )?;
 
     position_bundle.open_bundled_position(bundle_index)?;
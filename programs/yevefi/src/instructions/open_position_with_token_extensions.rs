@@ -1,11 +1,10 @@
 use crate::state::*;
-use crate::util::build_position_token_metadata;
+use crate::util::{build_position_token_metadata, build_position_token_metadata_fields};
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_2022::spl_token_2022;
 use anchor_spl::token_2022::Token2022;
 
-use crate::constants::nft::yevefi_nft_update_auth::ID as TK_NFT_UPDATE_AUTH;
 use crate::util::{
     initialize_position_mint_2022, initialize_position_token_account_2022,
     initialize_token_metadata_extension, mint_position_token_2022_and_remove_authority,
@@ -41,10 +40,6 @@ pub struct OpenPositionWithTokenExtensions<'info> {
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
-
-    /// CHECK: checked via account constraints
-    #[account(address = TK_NFT_UPDATE_AUTH)]
-    pub metadata_update_auth: UncheckedAccount<'info>,
 }
 
 /*
@@ -84,14 +79,15 @@ pub fn handler(
 
     if with_token_metadata {
         let (name, symbol, uri) = build_position_token_metadata(position_mint, position, yevefi);
+        let additional_metadata = build_position_token_metadata_fields(position, yevefi);
 
         initialize_token_metadata_extension(
             name,
             symbol,
             uri,
+            additional_metadata,
             position_mint,
             position,
-            &ctx.accounts.metadata_update_auth,
             &ctx.accounts.funder,
             &ctx.accounts.system_program,
             &ctx.accounts.token_2022_program,
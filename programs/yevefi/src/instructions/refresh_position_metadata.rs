@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::*;
+use crate::util::{build_position_token_metadata_fields, update_token_metadata_fields};
+
+#[derive(Accounts)]
+pub struct RefreshPositionMetadata<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        seeds = [b"position".as_ref(), position_mint.key().as_ref()],
+        bump,
+        has_one = yevefi,
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(mut,
+        address = position.position_mint,
+        owner = token_2022_program.key(),
+    )]
+    pub position_mint: InterfaceAccount<'info, Mint>,
+
+    pub yevefi: Box<Account<'info, Yevefi>>,
+
+    #[account(address = spl_token_2022::ID)]
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+/*
+  Refreshes the additional TokenMetadata fields of a position opened with token extensions.
+  Anyone can refresh, the funder pays the rent if the fields grow.
+*/
+pub fn handler(ctx: Context<RefreshPositionMetadata>) -> Result<()> {
+    let position_mint = &ctx.accounts.position_mint;
+
+    let position_seeds = [
+        b"position".as_ref(),
+        position_mint.to_account_info().key.as_ref(),
+        &[ctx.bumps.position],
+    ];
+
+    let fields = build_position_token_metadata_fields(&ctx.accounts.position, &ctx.accounts.yevefi);
+
+    update_token_metadata_fields(
+        fields,
+        position_mint,
+        &ctx.accounts.position,
+        &ctx.accounts.funder,
+        &ctx.accounts.system_program,
+        &ctx.accounts.token_2022_program,
+        &position_seeds,
+    )
+}
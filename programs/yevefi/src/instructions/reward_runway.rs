@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use solana_program::program::set_return_data;
+
+use crate::{
+    manager::yevefi_manager::{next_yevefi_reward_infos, reward_runway_seconds},
+    state::*,
+    util::to_timestamp_u64,
+};
+
+#[derive(Accounts)]
+pub struct RewardRunway<'info> {
+    pub yevefi: Account<'info, Yevefi>,
+}
+
+pub fn handler(ctx: Context<RewardRunway>) -> Result<()> {
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    // Rewards are accrued on a copy, so that the emissions up to now are recorded
+    let mut yevefi = (*ctx.accounts.yevefi).clone();
+    let next_reward_infos = next_yevefi_reward_infos(&yevefi, timestamp)?;
+    yevefi.update_rewards(next_reward_infos, timestamp);
+
+    let mut result = RewardRunwayResult::default();
+    for (index, runway_seconds) in result.runway_seconds.iter_mut().enumerate() {
+        *runway_seconds = reward_runway_seconds(&yevefi, index, timestamp);
+    }
+
+    set_return_data(&result.try_to_vec()?);
+    Ok(())
+}
@@ -17,7 +17,7 @@ pub struct SetCollectProtocolFeesAuthority<'info> {
 pub fn handler(ctx: Context<SetCollectProtocolFeesAuthority>) -> Result<()> {
     ctx.accounts
         .yevefis_config
-        .update_collect_protocol_fees_authority(
+        .update_pending_collect_protocol_fees_authority(
             ctx.accounts.new_collect_protocol_fees_authority.key(),
         );
     Ok(())
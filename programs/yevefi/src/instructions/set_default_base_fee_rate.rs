@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{AdaptiveFeeTier, YevefisConfig};
+
+#[derive(Accounts)]
+pub struct SetDefaultBaseFeeRate<'info> {
+    pub yevefis_config: Account<'info, YevefisConfig>,
+
+    #[account(mut, has_one = yevefis_config)]
+    pub adaptive_fee_tier: Account<'info, AdaptiveFeeTier>,
+
+    #[account(address = yevefis_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+}
+
+/*
+   Updates the default base fee rate on an AdaptiveFeeTier object.
+*/
+pub fn handler(ctx: Context<SetDefaultBaseFeeRate>, default_base_fee_rate: u16) -> Result<()> {
+    ctx.accounts
+        .adaptive_fee_tier
+        .update_default_base_fee_rate(default_base_fee_rate)
+}
@@ -14,10 +14,11 @@ pub struct SetFeeAuthority<'info> {
     pub new_fee_authority: UncheckedAccount<'info>,
 }
 
-/// Set the fee authority. Only the current fee authority has permission to invoke this instruction.
+/// Propose a new fee authority, which takes over once it accepts with `accept_fee_authority`.
+/// Only the current fee authority has permission to invoke this instruction.
 pub fn handler(ctx: Context<SetFeeAuthority>) -> Result<()> {
     ctx.accounts
         .yevefis_config
-        .update_fee_authority(ctx.accounts.new_fee_authority.key());
+        .update_pending_fee_authority(ctx.accounts.new_fee_authority.key());
     Ok(())
 }
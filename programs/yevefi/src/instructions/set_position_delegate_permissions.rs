@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::state::*;
+use crate::util::verify_position_authority_interface;
+
+#[derive(Accounts)]
+pub struct SetPositionDelegatePermissions<'info> {
+    pub position_authority: Signer<'info>,
+
+    pub position: Account<'info, Position>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, has_one = position)]
+    pub position_delegate: Account<'info, PositionDelegate>,
+}
+
+/*
+  Replaces the permissions of the delegate on a position.
+  The permissions are granted by the current owner of the position token.
+*/
+pub fn handler(ctx: Context<SetPositionDelegatePermissions>, permissions: u8) -> Result<()> {
+    verify_position_authority_interface(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        None,
+    )?;
+
+    ctx.accounts
+        .position_delegate
+        .set_permissions(ctx.accounts.position_token_account.owner, permissions)
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::state::YevefisConfig;
+
+#[derive(Accounts)]
+pub struct SetReferralFeeRate<'info> {
+    #[account(mut)]
+    pub yevefis_config: Account<'info, YevefisConfig>,
+
+    #[account(address = yevefis_config.fee_authority)]
+    pub fee_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetReferralFeeRate>, referral_fee_rate: u16) -> Result<()> {
+    ctx.accounts
+        .yevefis_config
+        .update_referral_fee_rate(referral_fee_rate)
+}
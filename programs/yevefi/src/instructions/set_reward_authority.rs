@@ -16,7 +16,7 @@ pub struct SetRewardAuthority<'info> {
 }
 
 pub fn handler(ctx: Context<SetRewardAuthority>, reward_index: u8) -> Result<()> {
-    ctx.accounts.yevefi.update_reward_authority(
+    ctx.accounts.yevefi.update_pending_reward_authority(
         reward_index as usize,
         ctx.accounts.new_reward_authority.key(),
     )
@@ -17,7 +17,7 @@ pub struct SetRewardEmissionsSuperAuthority<'info> {
 pub fn handler(ctx: Context<SetRewardEmissionsSuperAuthority>) -> Result<()> {
     ctx.accounts
         .yevefis_config
-        .update_reward_emissions_super_authority(
+        .update_pending_reward_emissions_super_authority(
             ctx.accounts.new_reward_emissions_super_authority.key(),
         );
     Ok(())
@@ -5,7 +5,10 @@ use crate::{
     errors::ErrorCode,
     manager::swap_manager::*,
     state::Yevefi,
-    util::{to_timestamp_u64, update_and_swap_yevefi, SparseSwapTickSequenceBuilder},
+    util::{
+        require_no_reward_extension, require_not_paused, to_timestamp_u64, update_and_swap_yevefi,
+        update_oracle, SparseSwapTickSequenceBuilder,
+    },
 };
 
 #[derive(Accounts)]
@@ -41,7 +44,7 @@ pub struct Swap<'info> {
     pub tick_array_2: UncheckedAccount<'info>,
 
     #[account(seeds = [b"oracle", yevefi.key().as_ref()],bump)]
-    /// CHECK: Oracle is currently unused and will be enabled on subsequent updates
+    /// CHECK: Oracle is optional, checked in the handler if initialized
     pub oracle: UncheckedAccount<'info>,
 }
 
@@ -54,10 +57,17 @@ pub fn handler(
     a_to_b: bool, // Zero for one
 ) -> Result<()> {
     let yevefi = &mut ctx.accounts.yevefi;
+    // The config extension PDA is the first remaining account
+    require_not_paused(yevefi, ctx.remaining_accounts.first())?;
+    require_no_reward_extension(yevefi)?;
+
     let clock = Clock::get()?;
     // Update the global reward growth which increases as a function of time.
     let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
 
+    // v1 clients pass the oracle as read-only, which is rejected once it is initialized
+    update_oracle(&ctx.accounts.oracle, yevefi, timestamp)?;
+
     let builder = SparseSwapTickSequenceBuilder::try_from(
         yevefi,
         a_to_b,
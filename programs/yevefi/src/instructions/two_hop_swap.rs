@@ -5,7 +5,10 @@ use crate::{
     errors::ErrorCode,
     manager::swap_manager::*,
     state::Yevefi,
-    util::{to_timestamp_u64, update_and_swap_yevefi, SparseSwapTickSequenceBuilder},
+    util::{
+        require_no_reward_extension, require_not_paused, to_timestamp_u64, update_and_swap_yevefi,
+        update_oracle, SparseSwapTickSequenceBuilder,
+    },
 };
 
 #[derive(Accounts)]
@@ -66,11 +69,11 @@ pub struct TwoHopSwap<'info> {
     pub tick_array_two_2: UncheckedAccount<'info>,
 
     #[account(seeds = [b"oracle", yevefi_one.key().as_ref()],bump)]
-    /// CHECK: Oracle is currently unused and will be enabled on subsequent updates
+    /// CHECK: Oracle is optional, checked in the handler if initialized
     pub oracle_one: UncheckedAccount<'info>,
 
     #[account(seeds = [b"oracle", yevefi_two.key().as_ref()],bump)]
-    /// CHECK: Oracle is currently unused and will be enabled on subsequent updates
+    /// CHECK: Oracle is optional, checked in the handler if initialized
     pub oracle_two: UncheckedAccount<'info>,
 }
 
@@ -97,6 +100,12 @@ pub fn handler(
         return Err(ErrorCode::DuplicateTwoHopPool.into());
     }
 
+    // The config extension PDAs of yevefi_one and yevefi_two are the first remaining accounts
+    require_not_paused(yevefi_one, ctx.remaining_accounts.first())?;
+    require_no_reward_extension(yevefi_one)?;
+    require_not_paused(yevefi_two, ctx.remaining_accounts.get(1))?;
+    require_no_reward_extension(yevefi_two)?;
+
     let swap_one_output_mint = if a_to_b_one {
         yevefi_one.token_mint_b
     } else {
@@ -112,6 +121,10 @@ pub fn handler(
         return Err(ErrorCode::InvalidIntermediaryMint.into());
     }
 
+    // v1 clients pass the oracles as read-only, which is rejected once they are initialized
+    update_oracle(&ctx.accounts.oracle_one, yevefi_one, timestamp)?;
+    update_oracle(&ctx.accounts.oracle_two, yevefi_two, timestamp)?;
+
     let builder_one = SparseSwapTickSequenceBuilder::try_from(
         yevefi_one,
         a_to_b_one,
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    manager::{
+        liquidity_manager::calculate_extension_reward_growths,
+        yevefi_manager::next_yevefi_reward_extension_infos,
+    },
+    state::*,
+    util::to_timestamp_u64,
+};
+
+#[derive(Accounts)]
+pub struct UpdatePositionRewardExtension<'info> {
+    pub yevefi: Box<Account<'info, Yevefi>>,
+
+    #[account(mut, has_one = yevefi)]
+    pub yevefi_reward_extension: Box<Account<'info, YevefiRewardExtension>>,
+
+    #[account(has_one = yevefi)]
+    pub position: Box<Account<'info, Position>>,
+
+    #[account(mut, has_one = position)]
+    pub position_reward_extension: Box<Account<'info, PositionRewardExtension>>,
+
+    #[account(has_one = yevefi)]
+    pub tick_array_lower_reward_extension: AccountLoader<'info, TickArrayRewardExtension>,
+    #[account(has_one = yevefi)]
+    pub tick_array_upper_reward_extension: AccountLoader<'info, TickArrayRewardExtension>,
+}
+
+pub fn handler(ctx: Context<UpdatePositionRewardExtension>) -> Result<()> {
+    let yevefi = &ctx.accounts.yevefi;
+    let yevefi_reward_extension = &mut ctx.accounts.yevefi_reward_extension;
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    let next_reward_infos =
+        next_yevefi_reward_extension_infos(yevefi, yevefi_reward_extension, timestamp)?;
+    yevefi_reward_extension.update_rewards(yevefi.liquidity, next_reward_infos, timestamp);
+
+    let next_position_reward_infos = calculate_extension_reward_growths(
+        yevefi,
+        &ctx.accounts.position,
+        &ctx.accounts.position_reward_extension,
+        &*ctx.accounts.tick_array_lower_reward_extension.load()?,
+        &*ctx.accounts.tick_array_upper_reward_extension.load()?,
+        &next_reward_infos,
+    )?;
+
+    ctx.accounts
+        .position_reward_extension
+        .update_reward_infos(next_position_reward_infos);
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{YevefisConfig, YevefisConfigExtension};
+
+#[derive(Accounts)]
+pub struct AcceptConfigExtensionAuthority<'info> {
+    pub yevefis_config: Box<Account<'info, YevefisConfig>>,
+
+    #[account(mut, has_one = yevefis_config)]
+    pub yevefis_config_extension: Account<'info, YevefisConfigExtension>,
+
+    #[account(address = yevefis_config_extension.pending_config_extension_authority)]
+    pub new_config_extension_authority: Signer<'info>,
+}
+
+/// Accept the config extension authority proposed by `set_config_extension_authority`. Only the pending config extension authority has permission to invoke this instruction.
+pub fn handler(ctx: Context<AcceptConfigExtensionAuthority>) -> Result<()> {
+    ctx.accounts
+        .yevefis_config_extension
+        .accept_config_extension_authority();
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{YevefisConfig, YevefisConfigExtension};
+
+#[derive(Accounts)]
+pub struct AcceptTokenBadgeAuthority<'info> {
+    pub yevefis_config: Box<Account<'info, YevefisConfig>>,
+
+    #[account(mut, has_one = yevefis_config)]
+    pub yevefis_config_extension: Account<'info, YevefisConfigExtension>,
+
+    #[account(address = yevefis_config_extension.pending_token_badge_authority)]
+    pub new_token_badge_authority: Signer<'info>,
+}
+
+/// Accept the token badge authority proposed by `set_token_badge_authority`. Only the pending token badge authority has permission to invoke this instruction.
+pub fn handler(ctx: Context<AcceptTokenBadgeAuthority>) -> Result<()> {
+    ctx.accounts
+        .yevefis_config_extension
+        .accept_token_badge_authority();
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::util::{
+    calculate_transfer_fee_excluded_amount, parse_remaining_accounts, AccountsType,
+    RemainingAccountsInfo,
+};
+use crate::{
+    constants::transfer_memo,
+    events::RewardCollected,
+    state::*,
+    util::{
+        require_position_owner_destination, v2::transfer_from_vault_to_owner_v2,
+        verify_position_authority_interface,
+    },
+};
+
+use super::collect_reward::calculate_collect_reward;
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct CollectExtensionReward<'info> {
+    pub yevefi: Box<Account<'info, Yevefi>>,
+
+    #[account(mut, has_one = yevefi)]
+    pub yevefi_reward_extension: Box<Account<'info, YevefiRewardExtension>>,
+
+    pub position_authority: Signer<'info>,
+
+    #[account(has_one = yevefi)]
+    pub position: Box<Account<'info, Position>>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, has_one = position)]
+    pub position_reward_extension: Box<Account<'info, PositionRewardExtension>>,
+
+    #[account(mut,
+        constraint = reward_owner_account.mint == yevefi_reward_extension.reward_infos[reward_index as usize].mint
+    )]
+    pub reward_owner_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = yevefi_reward_extension.reward_infos[reward_index as usize].mint)]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = yevefi_reward_extension.reward_infos[reward_index as usize].vault)]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = *reward_mint.to_account_info().owner)]
+    pub reward_token_program: Interface<'info, TokenInterface>,
+    pub memo_program: Program<'info, Memo>,
+    // remaining accounts
+    // - accounts for transfer hook program of reward_mint
+}
+
+/// Collects all harvestable tokens for a specified extension reward.
+///
+/// If the reward vault does not have enough tokens, the maximum number of available
+/// tokens will be debited to the user. The unharvested amount remains tracked, and it can be
+/// harvested in the future.
+///
+/// # Parameters
+/// - `reward_index` - The extension reward to harvest. Acceptable values are 0 and 1.
+///
+/// # Returns
+/// - `Ok`: Reward tokens at the specified extension reward index have been successfully harvested
+/// - `Err`: `InvalidRewardIndex` if the reward index is not 0 or 1
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CollectExtensionReward<'info>>,
+    reward_index: u8,
+    remaining_accounts_info: Option<RemainingAccountsInfo>,
+) -> Result<()> {
+    // Process remaining accounts
+    let remaining_accounts = parse_remaining_accounts(
+        ctx.remaining_accounts,
+        &remaining_accounts_info,
+        &[
+            AccountsType::TransferHookReward,
+            AccountsType::PositionDelegate,
+        ],
+    )?;
+
+    // The PositionDelegate is only passed if position_authority is not the position owner
+    let position_delegate = remaining_accounts
+        .position_delegate
+        .as_ref()
+        .and_then(|accounts| accounts.first());
+    let is_position_delegate = verify_position_authority_interface(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        position_delegate.map(|position_delegate| {
+            (
+                position_delegate,
+                ctx.accounts.position.key(),
+                POSITION_DELEGATE_PERMISSION_COLLECT_REWARDS,
+            )
+        }),
+    )?;
+
+    // A PositionDelegate can only transfer tokens to the position owner
+    if is_position_delegate {
+        require_position_owner_destination(
+            &ctx.accounts.position_token_account,
+            &ctx.accounts.reward_owner_account,
+        )?;
+    }
+
+    let index = reward_index as usize;
+
+    let position_reward_extension = &mut ctx.accounts.position_reward_extension;
+    let (transfer_amount, updated_amount_owed) = calculate_collect_reward(
+        position_reward_extension.reward_infos[index],
+        ctx.accounts.reward_vault.amount,
+    );
+
+    position_reward_extension.update_reward_owed(index, updated_amount_owed);
+    ctx.accounts
+        .yevefi_reward_extension
+        .record_reward_collected(index, transfer_amount);
+
+    transfer_from_vault_to_owner_v2(
+        &ctx.accounts.yevefi,
+        &ctx.accounts.reward_mint,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.reward_owner_account,
+        &ctx.accounts.reward_token_program,
+        &ctx.accounts.memo_program,
+        &remaining_accounts.transfer_hook_reward,
+        transfer_amount,
+        transfer_memo::TRANSFER_MEMO_COLLECT_REWARD.as_bytes(),
+    )?;
+
+    // Extension rewards are indexed after the rewards of the yevefi
+    emit!(RewardCollected {
+        yevefi: ctx.accounts.yevefi.key(),
+        position: ctx.accounts.position.key(),
+        reward_index: (NUM_REWARDS + index) as u8,
+        reward_mint: ctx.accounts.reward_mint.key(),
+        amount: transfer_amount,
+        transfer_fee: calculate_transfer_fee_excluded_amount(
+            &ctx.accounts.reward_mint,
+            transfer_amount
+        )?
+        .transfer_fee,
+    });
+
+    Ok(())
+}
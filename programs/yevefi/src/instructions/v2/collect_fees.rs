@@ -2,11 +2,18 @@ use anchor_lang::prelude::*;
 use anchor_spl::memo::Memo;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::util::{parse_remaining_accounts, AccountsType, RemainingAccountsInfo};
+use crate::util::{
+    calculate_transfer_fee_excluded_amount, parse_remaining_accounts, AccountsType,
+    RemainingAccountsInfo,
+};
 use crate::{
     constants::transfer_memo,
+    events::FeesCollected,
     state::*,
-    util::{v2::transfer_from_vault_to_owner_v2, verify_position_authority_interface},
+    util::{
+        require_position_owner_destination, v2::transfer_from_vault_to_owner_v2,
+        verify_position_authority_interface,
+    },
 };
 
 #[derive(Accounts)]
@@ -52,18 +59,46 @@ pub fn handler<'info>(
     ctx: Context<'_, '_, '_, 'info, CollectFeesV2<'info>>,
     remaining_accounts_info: Option<RemainingAccountsInfo>,
 ) -> Result<()> {
-    verify_position_authority_interface(
-        &ctx.accounts.position_token_account,
-        &ctx.accounts.position_authority,
-    )?;
-
     // Process remaining accounts
     let remaining_accounts = parse_remaining_accounts(
         ctx.remaining_accounts,
         &remaining_accounts_info,
-        &[AccountsType::TransferHookA, AccountsType::TransferHookB],
+        &[
+            AccountsType::TransferHookA,
+            AccountsType::TransferHookB,
+            AccountsType::PositionDelegate,
+        ],
     )?;
 
+    // The PositionDelegate is only passed if position_authority is not the position owner
+    let position_delegate = remaining_accounts
+        .position_delegate
+        .as_ref()
+        .and_then(|accounts| accounts.first());
+    let is_position_delegate = verify_position_authority_interface(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        position_delegate.map(|position_delegate| {
+            (
+                position_delegate,
+                ctx.accounts.position.key(),
+                POSITION_DELEGATE_PERMISSION_COLLECT_FEES,
+            )
+        }),
+    )?;
+
+    // A PositionDelegate can only transfer tokens to the position owner
+    if is_position_delegate {
+        require_position_owner_destination(
+            &ctx.accounts.position_token_account,
+            &ctx.accounts.token_owner_account_a,
+        )?;
+        require_position_owner_destination(
+            &ctx.accounts.position_token_account,
+            &ctx.accounts.token_owner_account_b,
+        )?;
+    }
+
     let position = &mut ctx.accounts.position;
 
     // Store the fees owed to use as transfer amounts.
@@ -96,5 +131,22 @@ pub fn handler<'info>(
         transfer_memo::TRANSFER_MEMO_COLLECT_FEES.as_bytes(),
     )?;
 
+    emit!(FeesCollected {
+        yevefi: ctx.accounts.yevefi.key(),
+        position: ctx.accounts.position.key(),
+        fee_a_amount: fee_owed_a,
+        fee_b_amount: fee_owed_b,
+        fee_a_transfer_fee: calculate_transfer_fee_excluded_amount(
+            &ctx.accounts.token_mint_a,
+            fee_owed_a
+        )?
+        .transfer_fee,
+        fee_b_transfer_fee: calculate_transfer_fee_excluded_amount(
+            &ctx.accounts.token_mint_b,
+            fee_owed_b
+        )?
+        .transfer_fee,
+    });
+
     Ok(())
 }
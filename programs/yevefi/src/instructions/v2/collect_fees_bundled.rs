@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::util::{
+    calculate_transfer_fee_excluded_amount, load_bundled_positions, parse_remaining_accounts,
+    AccountsType, RemainingAccountsInfo,
+};
+use crate::{
+    constants::transfer_memo,
+    events::FeesCollected,
+    state::*,
+    util::{v2::transfer_from_vault_to_owner_v2, verify_position_authority_interface},
+};
+
+#[derive(Accounts)]
+pub struct CollectFeesBundledV2<'info> {
+    pub yevefi: Box<Account<'info, Yevefi>>,
+
+    pub position_bundle_authority: Signer<'info>,
+
+    pub position_bundle: Box<Account<'info, PositionBundle>>,
+    #[account(
+        constraint = position_bundle_token_account.mint == position_bundle.position_bundle_mint,
+        constraint = position_bundle_token_account.amount == 1
+    )]
+    pub position_bundle_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = yevefi.token_mint_a)]
+    pub token_mint_a: InterfaceAccount<'info, Mint>,
+    #[account(address = yevefi.token_mint_b)]
+    pub token_mint_b: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, constraint = token_owner_account_a.mint == yevefi.token_mint_a)]
+    pub token_owner_account_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, address = yevefi.token_vault_a)]
+    pub token_vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, constraint = token_owner_account_b.mint == yevefi.token_mint_b)]
+    pub token_owner_account_b: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, address = yevefi.token_vault_b)]
+    pub token_vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = *token_mint_a.to_account_info().owner)]
+    pub token_program_a: Interface<'info, TokenInterface>,
+    #[account(address = *token_mint_b.to_account_info().owner)]
+    pub token_program_b: Interface<'info, TokenInterface>,
+    pub memo_program: Program<'info, Memo>,
+    // remaining accounts
+    // - accounts for transfer hook program of token_mint_a
+    // - accounts for transfer hook program of token_mint_b
+    // - bundled positions at bundle_indexes, in order
+}
+
+/*
+  Collects the fees owed to the bundled positions at bundle_indexes.
+  The fees of all positions are summed and transferred at once.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CollectFeesBundledV2<'info>>,
+    bundle_indexes: Vec<u16>,
+    bundled_position_bumps: Vec<u8>,
+    remaining_accounts_info: Option<RemainingAccountsInfo>,
+) -> Result<()> {
+    verify_position_authority_interface(
+        &ctx.accounts.position_bundle_token_account,
+        &ctx.accounts.position_bundle_authority,
+        None,
+    )?;
+
+    // Process remaining accounts
+    let remaining_accounts = parse_remaining_accounts(
+        ctx.remaining_accounts,
+        &remaining_accounts_info,
+        &[
+            AccountsType::TransferHookA,
+            AccountsType::TransferHookB,
+            AccountsType::BundledPositions,
+        ],
+    )?;
+
+    let mut bundled_positions = load_bundled_positions(
+        ctx.remaining_accounts,
+        &remaining_accounts.bundled_positions,
+        &ctx.accounts.position_bundle,
+        ctx.accounts.yevefi.key(),
+        &bundle_indexes,
+        &bundled_position_bumps,
+    )?;
+
+    // Store the fees owed to use as transfer amounts.
+    let mut fees_owed = Vec::with_capacity(bundled_positions.len());
+    let mut total_fee_owed_a: u64 = 0;
+    let mut total_fee_owed_b: u64 = 0;
+    for bundled_position in bundled_positions.iter_mut() {
+        let fee_owed_a = bundled_position.fee_owed_a;
+        let fee_owed_b = bundled_position.fee_owed_b;
+        fees_owed.push((fee_owed_a, fee_owed_b));
+
+        bundled_position.reset_fees_owed();
+
+        total_fee_owed_a = total_fee_owed_a
+            .checked_add(fee_owed_a)
+            .ok_or(ErrorCode::AmountCalcOverflow)?;
+        total_fee_owed_b = total_fee_owed_b
+            .checked_add(fee_owed_b)
+            .ok_or(ErrorCode::AmountCalcOverflow)?;
+    }
+
+    transfer_from_vault_to_owner_v2(
+        &ctx.accounts.yevefi,
+        &ctx.accounts.token_mint_a,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_owner_account_a,
+        &ctx.accounts.token_program_a,
+        &ctx.accounts.memo_program,
+        &remaining_accounts.transfer_hook_a,
+        total_fee_owed_a,
+        transfer_memo::TRANSFER_MEMO_COLLECT_FEES.as_bytes(),
+    )?;
+
+    transfer_from_vault_to_owner_v2(
+        &ctx.accounts.yevefi,
+        &ctx.accounts.token_mint_b,
+        &ctx.accounts.token_vault_b,
+        &ctx.accounts.token_owner_account_b,
+        &ctx.accounts.token_program_b,
+        &ctx.accounts.memo_program,
+        &remaining_accounts.transfer_hook_b,
+        total_fee_owed_b,
+        transfer_memo::TRANSFER_MEMO_COLLECT_FEES.as_bytes(),
+    )?;
+
+    // The bundled positions are not in the Accounts struct, so Anchor does not write them back
+    for (bundled_position, (fee_owed_a, fee_owed_b)) in bundled_positions.iter().zip(fees_owed) {
+        bundled_position.exit(&crate::ID)?;
+
+        emit!(FeesCollected {
+            yevefi: ctx.accounts.yevefi.key(),
+            position: bundled_position.key(),
+            fee_a_amount: fee_owed_a,
+            fee_b_amount: fee_owed_b,
+            fee_a_transfer_fee: calculate_transfer_fee_excluded_amount(
+                &ctx.accounts.token_mint_a,
+                fee_owed_a
+            )?
+            .transfer_fee,
+            fee_b_transfer_fee: calculate_transfer_fee_excluded_amount(
+                &ctx.accounts.token_mint_b,
+                fee_owed_b
+            )?
+            .transfer_fee,
+        });
+    }
+
+    Ok(())
+}
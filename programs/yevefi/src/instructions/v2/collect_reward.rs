@@ -2,11 +2,18 @@ use anchor_lang::prelude::*;
 use anchor_spl::memo::Memo;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::util::{parse_remaining_accounts, AccountsType, RemainingAccountsInfo};
+use crate::util::{
+    calculate_transfer_fee_excluded_amount, parse_remaining_accounts, AccountsType,
+    RemainingAccountsInfo,
+};
 use crate::{
     constants::transfer_memo,
+    events::RewardCollected,
     state::*,
-    util::{v2::transfer_from_vault_to_owner_v2, verify_position_authority_interface},
+    util::{
+        require_position_owner_destination, v2::transfer_from_vault_to_owner_v2,
+        verify_position_authority_interface,
+    },
 };
 
 #[derive(Accounts)]
@@ -60,18 +67,41 @@ pub fn handler<'info>(
     reward_index: u8,
     remaining_accounts_info: Option<RemainingAccountsInfo>,
 ) -> Result<()> {
-    verify_position_authority_interface(
-        &ctx.accounts.position_token_account,
-        &ctx.accounts.position_authority,
-    )?;
-
     // Process remaining accounts
     let remaining_accounts = parse_remaining_accounts(
         ctx.remaining_accounts,
         &remaining_accounts_info,
-        &[AccountsType::TransferHookReward],
+        &[
+            AccountsType::TransferHookReward,
+            AccountsType::PositionDelegate,
+        ],
     )?;
 
+    // The PositionDelegate is only passed if position_authority is not the position owner
+    let position_delegate = remaining_accounts
+        .position_delegate
+        .as_ref()
+        .and_then(|accounts| accounts.first());
+    let is_position_delegate = verify_position_authority_interface(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        position_delegate.map(|position_delegate| {
+            (
+                position_delegate,
+                ctx.accounts.position.key(),
+                POSITION_DELEGATE_PERMISSION_COLLECT_REWARDS,
+            )
+        }),
+    )?;
+
+    // A PositionDelegate can only transfer tokens to the position owner
+    if is_position_delegate {
+        require_position_owner_destination(
+            &ctx.accounts.position_token_account,
+            &ctx.accounts.reward_owner_account,
+        )?;
+    }
+
     let index = reward_index as usize;
 
     let position = &mut ctx.accounts.position;
@@ -92,11 +122,29 @@ pub fn handler<'info>(
         &remaining_accounts.transfer_hook_reward,
         transfer_amount,
         transfer_memo::TRANSFER_MEMO_COLLECT_REWARD.as_bytes(),
-    )
+    )?;
+
+    emit!(RewardCollected {
+        yevefi: ctx.accounts.yevefi.key(),
+        position: ctx.accounts.position.key(),
+        reward_index,
+        reward_mint: ctx.accounts.reward_mint.key(),
+        amount: transfer_amount,
+        transfer_fee: calculate_transfer_fee_excluded_amount(
+            &ctx.accounts.reward_mint,
+            transfer_amount
+        )?
+        .transfer_fee,
+    });
+
+    Ok(())
 }
 
 // TODO: refactor (remove (dup))
-fn calculate_collect_reward(position_reward: PositionRewardInfo, vault_amount: u64) -> (u64, u64) {
+pub(crate) fn calculate_collect_reward(
+    position_reward: PositionRewardInfo,
+    vault_amount: u64,
+) -> (u64, u64) {
     let amount_owed = position_reward.amount_owed;
     let (transfer_amount, updated_amount_owed) = if amount_owed > vault_amount {
         (vault_amount, amount_owed - vault_amount)
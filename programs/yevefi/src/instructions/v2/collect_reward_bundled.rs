@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::util::{
+    calculate_transfer_fee_excluded_amount, load_bundled_positions, parse_remaining_accounts,
+    AccountsType, RemainingAccountsInfo,
+};
+use crate::{
+    constants::transfer_memo,
+    events::RewardCollected,
+    state::*,
+    util::{v2::transfer_from_vault_to_owner_v2, verify_position_authority_interface},
+};
+
+use super::collect_reward::calculate_collect_reward;
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct CollectRewardBundledV2<'info> {
+    pub yevefi: Box<Account<'info, Yevefi>>,
+
+    pub position_bundle_authority: Signer<'info>,
+
+    pub position_bundle: Box<Account<'info, PositionBundle>>,
+    #[account(
+        constraint = position_bundle_token_account.mint == position_bundle.position_bundle_mint,
+        constraint = position_bundle_token_account.amount == 1
+    )]
+    pub position_bundle_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut,
+        constraint = reward_owner_account.mint == yevefi.reward_infos[reward_index as usize].mint
+    )]
+    pub reward_owner_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = yevefi.reward_infos[reward_index as usize].mint)]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = yevefi.reward_infos[reward_index as usize].vault)]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = *reward_mint.to_account_info().owner)]
+    pub reward_token_program: Interface<'info, TokenInterface>,
+    pub memo_program: Program<'info, Memo>,
+    // remaining accounts
+    // - accounts for transfer hook program of reward_mint
+    // - bundled positions at bundle_indexes, in order
+}
+
+/*
+  Collects the specified reward owed to the bundled positions at bundle_indexes.
+  The rewards of all positions are summed and transferred at once.
+
+  If the reward vault does not have enough tokens, positions are paid in order until the vault
+  is exhausted. The unharvested amount remains tracked, and it can be harvested in the future.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CollectRewardBundledV2<'info>>,
+    reward_index: u8,
+    bundle_indexes: Vec<u16>,
+    bundled_position_bumps: Vec<u8>,
+    remaining_accounts_info: Option<RemainingAccountsInfo>,
+) -> Result<()> {
+    verify_position_authority_interface(
+        &ctx.accounts.position_bundle_token_account,
+        &ctx.accounts.position_bundle_authority,
+        None,
+    )?;
+
+    // Process remaining accounts
+    let remaining_accounts = parse_remaining_accounts(
+        ctx.remaining_accounts,
+        &remaining_accounts_info,
+        &[
+            AccountsType::TransferHookReward,
+            AccountsType::BundledPositions,
+        ],
+    )?;
+
+    let mut bundled_positions = load_bundled_positions(
+        ctx.remaining_accounts,
+        &remaining_accounts.bundled_positions,
+        &ctx.accounts.position_bundle,
+        ctx.accounts.yevefi.key(),
+        &bundle_indexes,
+        &bundled_position_bumps,
+    )?;
+
+    let index = reward_index as usize;
+
+    let mut vault_amount = ctx.accounts.reward_vault.amount;
+    let mut transfer_amounts = Vec::with_capacity(bundled_positions.len());
+    let mut total_transfer_amount: u64 = 0;
+    for bundled_position in bundled_positions.iter_mut() {
+        let (transfer_amount, updated_amount_owed) =
+            calculate_collect_reward(bundled_position.reward_infos[index], vault_amount);
+        transfer_amounts.push(transfer_amount);
+
+        bundled_position.update_reward_owed(index, updated_amount_owed);
+
+        vault_amount -= transfer_amount;
+        total_transfer_amount = total_transfer_amount
+            .checked_add(transfer_amount)
+            .ok_or(ErrorCode::AmountCalcOverflow)?;
+    }
+
+    transfer_from_vault_to_owner_v2(
+        &ctx.accounts.yevefi,
+        &ctx.accounts.reward_mint,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.reward_owner_account,
+        &ctx.accounts.reward_token_program,
+        &ctx.accounts.memo_program,
+        &remaining_accounts.transfer_hook_reward,
+        total_transfer_amount,
+        transfer_memo::TRANSFER_MEMO_COLLECT_REWARD.as_bytes(),
+    )?;
+
+    // The bundled positions are not in the Accounts struct, so Anchor does not write them back
+    for (bundled_position, transfer_amount) in bundled_positions.iter().zip(transfer_amounts) {
+        bundled_position.exit(&crate::ID)?;
+
+        emit!(RewardCollected {
+            yevefi: ctx.accounts.yevefi.key(),
+            position: bundled_position.key(),
+            reward_index,
+            reward_mint: ctx.accounts.reward_mint.key(),
+            amount: transfer_amount,
+            transfer_fee: calculate_transfer_fee_excluded_amount(
+                &ctx.accounts.reward_mint,
+                transfer_amount
+            )?
+            .transfer_fee,
+        });
+    }
+
+    Ok(())
+}
@@ -2,16 +2,20 @@ use anchor_lang::prelude::*;
 
 use crate::constants::transfer_memo;
 use crate::errors::ErrorCode;
+use crate::events::LiquidityDecreased;
 use crate::manager::liquidity_manager::{
     calculate_liquidity_token_deltas, calculate_modify_liquidity, sync_modify_liquidity_values,
 };
 use crate::math::convert_to_liquidity_delta;
+use crate::state::POSITION_DELEGATE_PERMISSION_DECREASE_LIQUIDITY;
 use crate::util::{
-    calculate_transfer_fee_excluded_amount, parse_remaining_accounts, AccountsType,
-    RemainingAccountsInfo,
+    calculate_transfer_fee_excluded_amount, load_reward_extension, parse_remaining_accounts,
+    AccountsType, RemainingAccountsInfo,
 };
 use crate::util::{
-    to_timestamp_u64, v2::transfer_from_vault_to_owner_v2, verify_position_authority_interface,
+    require_position_owner_destination, require_position_unlocked, to_timestamp_u64,
+    update_oracle_before_liquidity_change, v2::transfer_from_vault_to_owner_v2,
+    verify_position_authority_interface,
 };
 
 use super::increase_liquidity::ModifyLiquidityV2;
@@ -26,27 +30,97 @@ pub fn handler<'info>(
     token_min_b: u64,
     remaining_accounts_info: Option<RemainingAccountsInfo>,
 ) -> Result<()> {
-    verify_position_authority_interface(
+    // Process remaining accounts
+    let remaining_accounts = parse_remaining_accounts(
+        ctx.remaining_accounts,
+        &remaining_accounts_info,
+        &[
+            AccountsType::TransferHookA,
+            AccountsType::TransferHookB,
+            AccountsType::RewardExtension,
+            AccountsType::PositionLock,
+            AccountsType::PositionDelegate,
+            AccountsType::Oracle,
+        ],
+    )?;
+
+    // The PositionDelegate is only passed if position_authority is not the position owner
+    let position_delegate = remaining_accounts
+        .position_delegate
+        .as_ref()
+        .and_then(|accounts| accounts.first());
+    let is_position_delegate = verify_position_authority_interface(
         &ctx.accounts.position_token_account,
         &ctx.accounts.position_authority,
+        position_delegate.map(|position_delegate| {
+            (
+                position_delegate,
+                ctx.accounts.position.key(),
+                POSITION_DELEGATE_PERMISSION_DECREASE_LIQUIDITY,
+            )
+        }),
     )?;
 
+    // A PositionDelegate can only transfer tokens to the position owner
+    if is_position_delegate {
+        require_position_owner_destination(
+            &ctx.accounts.position_token_account,
+            &ctx.accounts.token_owner_account_a,
+        )?;
+        require_position_owner_destination(
+            &ctx.accounts.position_token_account,
+            &ctx.accounts.token_owner_account_b,
+        )?;
+    }
+
     let clock = Clock::get()?;
 
     if liquidity_amount == 0 {
         return Err(ErrorCode::LiquidityZero.into());
     }
 
-    // Process remaining accounts
-    let remaining_accounts = parse_remaining_accounts(
-        ctx.remaining_accounts,
-        &remaining_accounts_info,
-        &[AccountsType::TransferHookA, AccountsType::TransferHookB],
-    )?;
-
     let liquidity_delta = convert_to_liquidity_delta(liquidity_amount, false)?;
     let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
 
+    // The position lock PDA is passed as the PositionLock slice
+    let position_lock = remaining_accounts
+        .position_lock
+        .as_ref()
+        .and_then(|accounts| accounts.first());
+    require_position_unlocked(
+        &ctx.accounts.position.to_account_info(),
+        position_lock,
+        timestamp,
+    )?;
+
+    // The extension rewards owed to the position must be accrued with its current liquidity
+    let reward_extension = load_reward_extension(
+        &ctx.accounts.yevefi,
+        ctx.accounts.yevefi.key(),
+        &remaining_accounts.reward_extension,
+        timestamp,
+    )?;
+    if let Some(reward_extension) = reward_extension {
+        reward_extension.update_position(
+            &ctx.accounts.yevefi,
+            &ctx.accounts.position,
+            ctx.accounts.position.key(),
+        )?;
+        reward_extension.exit()?;
+    }
+
+    // The oracle PDA is passed as the Oracle slice
+    let oracle = remaining_accounts
+        .oracle
+        .as_ref()
+        .and_then(|accounts| accounts.first());
+    update_oracle_before_liquidity_change(
+        oracle,
+        &ctx.accounts.yevefi.key(),
+        &ctx.accounts.yevefi,
+        timestamp,
+    )?;
+
     let update = calculate_modify_liquidity(
         &ctx.accounts.yevefi,
         &ctx.accounts.position,
@@ -109,5 +183,17 @@ pub fn handler<'info>(
         transfer_memo::TRANSFER_MEMO_DECREASE_LIQUIDITY.as_bytes(),
     )?;
 
+    emit!(LiquidityDecreased {
+        yevefi: ctx.accounts.yevefi.key(),
+        position: ctx.accounts.position.key(),
+        tick_lower_index: ctx.accounts.position.tick_lower_index,
+        tick_upper_index: ctx.accounts.position.tick_upper_index,
+        liquidity: liquidity_amount,
+        token_a_amount: delta_a,
+        token_b_amount: delta_b,
+        token_a_transfer_fee: transfer_fee_excluded_delta_a.transfer_fee,
+        token_b_transfer_fee: transfer_fee_excluded_delta_b.transfer_fee,
+    });
+
     Ok(())
 }
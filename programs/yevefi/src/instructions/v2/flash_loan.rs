@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    constants::transfer_memo,
+    errors::ErrorCode,
+    events::FlashLoaned,
+    manager::flash_manager::flash_loan,
+    state::Yevefi,
+    util::{
+        calculate_transfer_fee_included_amount, invoke_flash_callback, parse_remaining_accounts,
+        require_not_paused, transfer_from_owner_to_vault_v2, transfer_from_vault_to_owner_v2,
+        AccountsType, FlashLoanCallbackArgs, RemainingAccountsInfo, FLASH_LOAN_CALLBACK_NAME,
+    },
+};
+
+#[derive(Accounts)]
+pub struct FlashLoanV2<'info> {
+    #[account(address = *token_mint_a.to_account_info().owner)]
+    pub token_program_a: Interface<'info, TokenInterface>,
+    #[account(address = *token_mint_b.to_account_info().owner)]
+    pub token_program_b: Interface<'info, TokenInterface>,
+
+    pub memo_program: Program<'info, Memo>,
+
+    pub token_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub yevefi: Box<Account<'info, Yevefi>>,
+
+    #[account(address = yevefi.token_mint_a)]
+    pub token_mint_a: InterfaceAccount<'info, Mint>,
+    #[account(address = yevefi.token_mint_b)]
+    pub token_mint_b: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, constraint = token_owner_account_a.mint == yevefi.token_mint_a)]
+    pub token_owner_account_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, address = yevefi.token_vault_a)]
+    pub token_vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, constraint = token_owner_account_b.mint == yevefi.token_mint_b)]
+    pub token_owner_account_b: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, address = yevefi.token_vault_b)]
+    pub token_vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: invoked with the FlashCallback accounts, repayment is checked in the handler
+    #[account(executable)]
+    pub callback_program: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"config_extension", yevefi.yevefis_config.as_ref()], bump)]
+    /// CHECK: Config extension may be uninitialized, checked in the handler
+    pub yevefis_config_extension: UncheckedAccount<'info>,
+    // remaining accounts
+    // - accounts for transfer hook program of token_mint_a
+    // - accounts for transfer hook program of token_mint_b
+    // - accounts for the callback instruction
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, FlashLoanV2<'info>>,
+    amount_a: u64,
+    amount_b: u64,
+    callback_data: Vec<u8>,
+    remaining_accounts_info: Option<RemainingAccountsInfo>,
+) -> Result<()> {
+    require_not_paused(
+        &ctx.accounts.yevefi,
+        Some(&ctx.accounts.yevefis_config_extension.to_account_info()),
+    )?;
+
+    let flash_loan_update = flash_loan(&ctx.accounts.yevefi, amount_a, amount_b)?;
+
+    // Process remaining accounts
+    let remaining_accounts = parse_remaining_accounts(
+        ctx.remaining_accounts,
+        &remaining_accounts_info,
+        &[
+            AccountsType::TransferHookA,
+            AccountsType::TransferHookB,
+            AccountsType::FlashCallback,
+        ],
+    )?;
+
+    let vault_a_amount_before = ctx.accounts.token_vault_a.amount;
+    let vault_b_amount_before = ctx.accounts.token_vault_b.amount;
+
+    transfer_from_vault_to_owner_v2(
+        &ctx.accounts.yevefi,
+        &ctx.accounts.token_mint_a,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_owner_account_a,
+        &ctx.accounts.token_program_a,
+        &ctx.accounts.memo_program,
+        &remaining_accounts.transfer_hook_a,
+        amount_a,
+        transfer_memo::TRANSFER_MEMO_FLASH_LOAN.as_bytes(),
+    )?;
+
+    transfer_from_vault_to_owner_v2(
+        &ctx.accounts.yevefi,
+        &ctx.accounts.token_mint_b,
+        &ctx.accounts.token_vault_b,
+        &ctx.accounts.token_owner_account_b,
+        &ctx.accounts.token_program_b,
+        &ctx.accounts.memo_program,
+        &remaining_accounts.transfer_hook_b,
+        amount_b,
+        transfer_memo::TRANSFER_MEMO_FLASH_LOAN.as_bytes(),
+    )?;
+
+    invoke_flash_callback(
+        &mut ctx.accounts.yevefi,
+        &ctx.accounts.callback_program,
+        &remaining_accounts.flash_callback,
+        FLASH_LOAN_CALLBACK_NAME,
+        &FlashLoanCallbackArgs {
+            amount_a,
+            amount_b,
+            fee_a: flash_loan_update.fee_a,
+            fee_b: flash_loan_update.fee_b,
+            data: callback_data,
+        },
+    )?;
+
+    // The vaults must receive the borrowed amounts plus fees, so the transfer fee is paid by the borrower
+    let repayment_a = calculate_transfer_fee_included_amount(
+        &ctx.accounts.token_mint_a,
+        flash_loan_update.repayment_a,
+    )?
+    .amount;
+    let repayment_b = calculate_transfer_fee_included_amount(
+        &ctx.accounts.token_mint_b,
+        flash_loan_update.repayment_b,
+    )?
+    .amount;
+
+    transfer_from_owner_to_vault_v2(
+        &ctx.accounts.token_authority,
+        &ctx.accounts.token_mint_a,
+        &ctx.accounts.token_owner_account_a,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_program_a,
+        &ctx.accounts.memo_program,
+        &remaining_accounts.transfer_hook_a,
+        repayment_a,
+    )?;
+
+    transfer_from_owner_to_vault_v2(
+        &ctx.accounts.token_authority,
+        &ctx.accounts.token_mint_b,
+        &ctx.accounts.token_owner_account_b,
+        &ctx.accounts.token_vault_b,
+        &ctx.accounts.token_program_b,
+        &ctx.accounts.memo_program,
+        &remaining_accounts.transfer_hook_b,
+        repayment_b,
+    )?;
+
+    ctx.accounts.token_vault_a.reload()?;
+    ctx.accounts.token_vault_b.reload()?;
+    let min_vault_a_amount = vault_a_amount_before
+        .checked_add(flash_loan_update.fee_a)
+        .ok_or(ErrorCode::AmountCalcOverflow)?;
+    let min_vault_b_amount = vault_b_amount_before
+        .checked_add(flash_loan_update.fee_b)
+        .ok_or(ErrorCode::AmountCalcOverflow)?;
+    if ctx.accounts.token_vault_a.amount < min_vault_a_amount
+        || ctx.accounts.token_vault_b.amount < min_vault_b_amount
+    {
+        return Err(ErrorCode::FlashNotRepaid.into());
+    }
+
+    ctx.accounts.yevefi.update_after_flash_loan(
+        flash_loan_update.next_fee_growth_global_a,
+        flash_loan_update.next_fee_growth_global_b,
+        flash_loan_update.next_protocol_fee_owed_a,
+        flash_loan_update.next_protocol_fee_owed_b,
+    );
+
+    emit!(FlashLoaned {
+        yevefi: ctx.accounts.yevefi.key(),
+        amount_a,
+        amount_b,
+        fee_a: flash_loan_update.fee_a,
+        fee_b: flash_loan_update.fee_b,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::swap_with_transfer_fee_extension;
+use crate::util::{
+    calculate_transfer_fee_excluded_amount, parse_remaining_accounts, AccountsType,
+    RemainingAccountsInfo,
+};
+use crate::{
+    constants::transfer_memo,
+    errors::ErrorCode,
+    state::Yevefi,
+    util::{
+        require_no_reward_extension, require_not_paused, to_timestamp_u64, update_oracle,
+        v2::update_and_flash_swap_yevefi_v2, SparseSwapTickSequenceBuilder,
+    },
+};
+
+#[derive(Accounts)]
+pub struct FlashSwapV2<'info> {
+    #[account(address = *token_mint_a.to_account_info().owner)]
+    pub token_program_a: Interface<'info, TokenInterface>,
+    #[account(address = *token_mint_b.to_account_info().owner)]
+    pub token_program_b: Interface<'info, TokenInterface>,
+
+    pub memo_program: Program<'info, Memo>,
+
+    pub token_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub yevefi: Box<Account<'info, Yevefi>>,
+
+    #[account(address = yevefi.token_mint_a)]
+    pub token_mint_a: InterfaceAccount<'info, Mint>,
+    #[account(address = yevefi.token_mint_b)]
+    pub token_mint_b: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, constraint = token_owner_account_a.mint == yevefi.token_mint_a)]
+    pub token_owner_account_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, address = yevefi.token_vault_a)]
+    pub token_vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, constraint = token_owner_account_b.mint == yevefi.token_mint_b)]
+    pub token_owner_account_b: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, address = yevefi.token_vault_b)]
+    pub token_vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    /// CHECK: checked in the handler
+    pub tick_array_0: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: checked in the handler
+    pub tick_array_1: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: checked in the handler
+    pub tick_array_2: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [b"oracle", yevefi.key().as_ref()], bump)]
+    /// CHECK: Oracle is optional, checked in the handler if initialized
+    pub oracle: UncheckedAccount<'info>,
+
+    /// CHECK: invoked with the FlashCallback accounts, repayment is checked in the handler
+    #[account(executable)]
+    pub callback_program: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"config_extension", yevefi.yevefis_config.as_ref()], bump)]
+    /// CHECK: Config extension may be uninitialized, checked in the handler
+    pub yevefis_config_extension: UncheckedAccount<'info>,
+    // remaining accounts
+    // - accounts for transfer hook program of token_mint_a
+    // - accounts for transfer hook program of token_mint_b
+    // - supplemental TickArray accounts
+    // - accounts for the callback instruction
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, FlashSwapV2<'info>>,
+    amount: u64,
+    other_amount_threshold: u64,
+    sqrt_price_limit: u128,
+    amount_specified_is_input: bool,
+    a_to_b: bool, // Zero for one
+    callback_data: Vec<u8>,
+    remaining_accounts_info: Option<RemainingAccountsInfo>,
+) -> Result<()> {
+    let yevefi = &mut ctx.accounts.yevefi;
+    require_not_paused(
+        yevefi,
+        Some(&ctx.accounts.yevefis_config_extension.to_account_info()),
+    )?;
+    require_no_reward_extension(yevefi)?;
+
+    let clock = Clock::get()?;
+    // Update the global reward growth which increases as a function of time.
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    update_oracle(&ctx.accounts.oracle, yevefi, timestamp)?;
+
+    // Process remaining accounts
+    let remaining_accounts = parse_remaining_accounts(
+        ctx.remaining_accounts,
+        &remaining_accounts_info,
+        &[
+            AccountsType::TransferHookA,
+            AccountsType::TransferHookB,
+            AccountsType::SupplementalTickArrays,
+            AccountsType::FlashCallback,
+        ],
+    )?;
+
+    let builder = SparseSwapTickSequenceBuilder::try_from(
+        yevefi,
+        a_to_b,
+        vec![
+            ctx.accounts.tick_array_0.to_account_info(),
+            ctx.accounts.tick_array_1.to_account_info(),
+            ctx.accounts.tick_array_2.to_account_info(),
+        ],
+        remaining_accounts.supplemental_tick_arrays,
+    )?;
+    let mut swap_tick_sequence = builder.build()?;
+
+    let swap_update = swap_with_transfer_fee_extension(
+        yevefi,
+        &ctx.accounts.token_mint_a,
+        &ctx.accounts.token_mint_b,
+        &mut swap_tick_sequence,
+        amount,
+        sqrt_price_limit,
+        amount_specified_is_input,
+        a_to_b,
+        timestamp,
+        0,
+    )?;
+
+    if amount_specified_is_input {
+        let transfer_fee_excluded_output_amount = if a_to_b {
+            calculate_transfer_fee_excluded_amount(
+                &ctx.accounts.token_mint_b,
+                swap_update.amount_b,
+            )?
+            .amount
+        } else {
+            calculate_transfer_fee_excluded_amount(
+                &ctx.accounts.token_mint_a,
+                swap_update.amount_a,
+            )?
+            .amount
+        };
+        if transfer_fee_excluded_output_amount < other_amount_threshold {
+            return Err(ErrorCode::AmountOutBelowMinimum.into());
+        }
+    } else {
+        let transfer_fee_included_input_amount = if a_to_b {
+            swap_update.amount_a
+        } else {
+            swap_update.amount_b
+        };
+        if transfer_fee_included_input_amount > other_amount_threshold {
+            return Err(ErrorCode::AmountInAboveMaximum.into());
+        }
+    }
+
+    // Release the borrowed tick arrays so that they can be passed to the callback
+    drop(swap_tick_sequence);
+
+    let (token_mint_input, vault_input_amount_before) = if a_to_b {
+        (
+            &ctx.accounts.token_mint_a,
+            ctx.accounts.token_vault_a.amount,
+        )
+    } else {
+        (
+            &ctx.accounts.token_mint_b,
+            ctx.accounts.token_vault_b.amount,
+        )
+    };
+    // The vault must receive the input amount the swap was calculated with
+    let transfer_fee_excluded_input_amount = calculate_transfer_fee_excluded_amount(
+        token_mint_input,
+        if a_to_b {
+            swap_update.amount_a
+        } else {
+            swap_update.amount_b
+        },
+    )?
+    .amount;
+
+    update_and_flash_swap_yevefi_v2(
+        yevefi,
+        &ctx.accounts.token_authority,
+        &ctx.accounts.token_mint_a,
+        &ctx.accounts.token_mint_b,
+        &ctx.accounts.token_owner_account_a,
+        &ctx.accounts.token_owner_account_b,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_vault_b,
+        &remaining_accounts.transfer_hook_a,
+        &remaining_accounts.transfer_hook_b,
+        &ctx.accounts.token_program_a,
+        &ctx.accounts.token_program_b,
+        &ctx.accounts.memo_program,
+        &ctx.accounts.callback_program,
+        &remaining_accounts.flash_callback,
+        callback_data,
+        swap_update,
+        a_to_b,
+        timestamp,
+        transfer_memo::TRANSFER_MEMO_SWAP.as_bytes(),
+    )?;
+
+    let token_vault_input = if a_to_b {
+        &mut ctx.accounts.token_vault_a
+    } else {
+        &mut ctx.accounts.token_vault_b
+    };
+    token_vault_input.reload()?;
+    let min_vault_input_amount = vault_input_amount_before
+        .checked_add(transfer_fee_excluded_input_amount)
+        .ok_or(ErrorCode::AmountCalcOverflow)?;
+    if token_vault_input.amount < min_vault_input_amount {
+        return Err(ErrorCode::FlashNotRepaid.into());
+    }
+
+    Ok(())
+}
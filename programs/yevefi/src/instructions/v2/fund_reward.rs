@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::util::{
+    calculate_transfer_fee_excluded_amount, parse_remaining_accounts, AccountsType,
+    RemainingAccountsInfo,
+};
+use crate::{
+    events::RewardFunded,
+    manager::yevefi_manager::next_yevefi_reward_infos,
+    state::Yevefi,
+    util::{to_timestamp_u64, v2::transfer_from_owner_to_vault_v2},
+};
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct FundReward<'info> {
+    #[account(mut)]
+    pub yevefi: Box<Account<'info, Yevefi>>,
+
+    pub funder: Signer<'info>,
+
+    #[account(mut, constraint = funder_token_account.mint == reward_mint.key())]
+    pub funder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = yevefi.reward_infos[reward_index as usize].mint)]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = yevefi.reward_infos[reward_index as usize].vault)]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = *reward_mint.to_account_info().owner)]
+    pub reward_token_program: Interface<'info, TokenInterface>,
+    pub memo_program: Program<'info, Memo>,
+    // remaining accounts
+    // - accounts for transfer hook program of reward_mint
+}
+
+/*
+  Deposits tokens into a reward vault and records them as funds for emissions.
+  Rewards are accrued up to the current timestamp first, so the new funds are only emitted
+  from now on.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, FundReward<'info>>,
+    reward_index: u8,
+    amount: u64,
+    extend_emissions: bool,
+    remaining_accounts_info: Option<RemainingAccountsInfo>,
+) -> Result<()> {
+    // Process remaining accounts
+    let remaining_accounts = parse_remaining_accounts(
+        ctx.remaining_accounts,
+        &remaining_accounts_info,
+        &[AccountsType::TransferHookReward],
+    )?;
+
+    let index = reward_index as usize;
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    let next_reward_infos = next_yevefi_reward_infos(&ctx.accounts.yevefi, timestamp)?;
+    ctx.accounts
+        .yevefi
+        .update_rewards(next_reward_infos, timestamp);
+
+    // The vault only receives the transfer fee excluded amount
+    let funded_amount =
+        calculate_transfer_fee_excluded_amount(&ctx.accounts.reward_mint, amount)?.amount;
+    ctx.accounts
+        .yevefi
+        .record_reward_funded(index, funded_amount);
+    if extend_emissions {
+        ctx.accounts
+            .yevefi
+            .extend_emissions_end(index, timestamp, funded_amount)?;
+    }
+
+    transfer_from_owner_to_vault_v2(
+        &ctx.accounts.funder,
+        &ctx.accounts.reward_mint,
+        &ctx.accounts.funder_token_account,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.reward_token_program,
+        &ctx.accounts.memo_program,
+        &remaining_accounts.transfer_hook_reward,
+        amount,
+    )?;
+
+    emit!(RewardFunded {
+        yevefi: ctx.accounts.yevefi.key(),
+        reward_index,
+        reward_mint: ctx.accounts.reward_mint.key(),
+        funder: ctx.accounts.funder.key(),
+        amount,
+        funded_amount,
+        emissions_end_timestamp: ctx.accounts.yevefi.reward_schedules[index].end_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,332 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::manager::liquidity_manager::calculate_fee_and_reward_growths;
+use crate::util::{
+    calculate_transfer_fee_excluded_amount, parse_remaining_accounts, AccountsType,
+    RemainingAccountsInfo,
+};
+use crate::{
+    constants::transfer_memo,
+    events::{FeesCollected, RewardCollected},
+    state::*,
+    util::{
+        require_position_owner_destination, to_timestamp_u64, v2::transfer_from_vault_to_owner_v2,
+        verify_position_authority_interface,
+    },
+};
+
+use super::collect_reward::calculate_collect_reward;
+
+// reward_owner_account, reward_mint, reward_vault, reward_token_program
+pub const HARVEST_REWARD_ACCOUNTS_LEN: usize = 4;
+
+#[derive(Accounts)]
+pub struct HarvestPositionV2<'info> {
+    #[account(mut)]
+    pub yevefi: Box<Account<'info, Yevefi>>,
+
+    pub position_authority: Signer<'info>,
+
+    #[account(mut, has_one = yevefi)]
+    pub position: Box<Account<'info, Position>>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(has_one = yevefi)]
+    pub tick_array_lower: AccountLoader<'info, TickArray>,
+    #[account(has_one = yevefi)]
+    pub tick_array_upper: AccountLoader<'info, TickArray>,
+
+    #[account(address = yevefi.token_mint_a)]
+    pub token_mint_a: InterfaceAccount<'info, Mint>,
+    #[account(address = yevefi.token_mint_b)]
+    pub token_mint_b: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, constraint = token_owner_account_a.mint == yevefi.token_mint_a)]
+    pub token_owner_account_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, address = yevefi.token_vault_a)]
+    pub token_vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, constraint = token_owner_account_b.mint == yevefi.token_mint_b)]
+    pub token_owner_account_b: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, address = yevefi.token_vault_b)]
+    pub token_vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = *token_mint_a.to_account_info().owner)]
+    pub token_program_a: Interface<'info, TokenInterface>,
+    #[account(address = *token_mint_b.to_account_info().owner)]
+    pub token_program_b: Interface<'info, TokenInterface>,
+    pub memo_program: Program<'info, Memo>,
+    // remaining accounts
+    // - accounts for transfer hook program of token_mint_a
+    // - accounts for transfer hook program of token_mint_b
+    // - reward accounts of each initialized reward, in reward index order
+    // - accounts for transfer hook program of each reward mint
+}
+
+struct HarvestRewardAccounts<'info> {
+    reward_owner_account: InterfaceAccount<'info, TokenAccount>,
+    reward_mint: InterfaceAccount<'info, Mint>,
+    reward_vault: InterfaceAccount<'info, TokenAccount>,
+    reward_token_program: Interface<'info, TokenInterface>,
+}
+
+/*
+  Collects the fees and all rewards owed to a position.
+  Fee and reward growths are updated once, then every initialized reward is paid out.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, HarvestPositionV2<'info>>,
+    remaining_accounts_info: Option<RemainingAccountsInfo>,
+) -> Result<()> {
+    // Process remaining accounts
+    let remaining_accounts = parse_remaining_accounts(
+        ctx.remaining_accounts,
+        &remaining_accounts_info,
+        &[
+            AccountsType::TransferHookA,
+            AccountsType::TransferHookB,
+            AccountsType::RewardAccounts,
+            AccountsType::TransferHookRewardOne,
+            AccountsType::TransferHookRewardTwo,
+            AccountsType::TransferHookRewardThree,
+            AccountsType::PositionDelegate,
+        ],
+    )?;
+
+    // The PositionDelegate is only passed if position_authority is not the position owner
+    let position_delegate = remaining_accounts
+        .position_delegate
+        .as_ref()
+        .and_then(|accounts| accounts.first());
+    let is_position_delegate = verify_position_authority_interface(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        position_delegate.map(|position_delegate| {
+            (
+                position_delegate,
+                ctx.accounts.position.key(),
+                POSITION_DELEGATE_PERMISSION_COLLECT_FEES
+                    | POSITION_DELEGATE_PERMISSION_COLLECT_REWARDS,
+            )
+        }),
+    )?;
+
+    // A PositionDelegate can only transfer tokens to the position owner
+    if is_position_delegate {
+        require_position_owner_destination(
+            &ctx.accounts.position_token_account,
+            &ctx.accounts.token_owner_account_a,
+        )?;
+        require_position_owner_destination(
+            &ctx.accounts.position_token_account,
+            &ctx.accounts.token_owner_account_b,
+        )?;
+    }
+
+    let reward_accounts = load_reward_accounts(
+        ctx.remaining_accounts,
+        &remaining_accounts.reward_accounts,
+        &ctx.accounts.yevefi.reward_infos,
+    )?;
+
+    if is_position_delegate {
+        for reward_accounts in reward_accounts.iter().flatten() {
+            require_position_owner_destination(
+                &ctx.accounts.position_token_account,
+                &reward_accounts.reward_owner_account,
+            )?;
+        }
+    }
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    // Growths of an empty position were settled when its liquidity was removed
+    if ctx.accounts.position.liquidity > 0 {
+        let (position_update, reward_infos) = calculate_fee_and_reward_growths(
+            &ctx.accounts.yevefi,
+            &ctx.accounts.position,
+            &ctx.accounts.tick_array_lower,
+            &ctx.accounts.tick_array_upper,
+            timestamp,
+        )?;
+
+        ctx.accounts.yevefi.update_rewards(reward_infos, timestamp);
+        ctx.accounts.position.update(&position_update);
+    }
+
+    let position = &mut ctx.accounts.position;
+
+    // Store the fees owed to use as transfer amounts.
+    let fee_owed_a = position.fee_owed_a;
+    let fee_owed_b = position.fee_owed_b;
+
+    position.reset_fees_owed();
+
+    transfer_from_vault_to_owner_v2(
+        &ctx.accounts.yevefi,
+        &ctx.accounts.token_mint_a,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_owner_account_a,
+        &ctx.accounts.token_program_a,
+        &ctx.accounts.memo_program,
+        &remaining_accounts.transfer_hook_a,
+        fee_owed_a,
+        transfer_memo::TRANSFER_MEMO_COLLECT_FEES.as_bytes(),
+    )?;
+
+    transfer_from_vault_to_owner_v2(
+        &ctx.accounts.yevefi,
+        &ctx.accounts.token_mint_b,
+        &ctx.accounts.token_vault_b,
+        &ctx.accounts.token_owner_account_b,
+        &ctx.accounts.token_program_b,
+        &ctx.accounts.memo_program,
+        &remaining_accounts.transfer_hook_b,
+        fee_owed_b,
+        transfer_memo::TRANSFER_MEMO_COLLECT_FEES.as_bytes(),
+    )?;
+
+    emit!(FeesCollected {
+        yevefi: ctx.accounts.yevefi.key(),
+        position: ctx.accounts.position.key(),
+        fee_a_amount: fee_owed_a,
+        fee_b_amount: fee_owed_b,
+        fee_a_transfer_fee: calculate_transfer_fee_excluded_amount(
+            &ctx.accounts.token_mint_a,
+            fee_owed_a
+        )?
+        .transfer_fee,
+        fee_b_transfer_fee: calculate_transfer_fee_excluded_amount(
+            &ctx.accounts.token_mint_b,
+            fee_owed_b
+        )?
+        .transfer_fee,
+    });
+
+    let transfer_hook_rewards = [
+        &remaining_accounts.transfer_hook_reward_one,
+        &remaining_accounts.transfer_hook_reward_two,
+        &remaining_accounts.transfer_hook_reward_three,
+    ];
+
+    for (index, reward_accounts) in reward_accounts.iter().enumerate() {
+        let reward_accounts = match reward_accounts {
+            Some(reward_accounts) => reward_accounts,
+            None => continue,
+        };
+
+        let position = &mut ctx.accounts.position;
+        let (transfer_amount, updated_amount_owed) = calculate_collect_reward(
+            position.reward_infos[index],
+            reward_accounts.reward_vault.amount,
+        );
+
+        position.update_reward_owed(index, updated_amount_owed);
+        ctx.accounts
+            .yevefi
+            .record_reward_collected(index, transfer_amount);
+
+        transfer_from_vault_to_owner_v2(
+            &ctx.accounts.yevefi,
+            &reward_accounts.reward_mint,
+            &reward_accounts.reward_vault,
+            &reward_accounts.reward_owner_account,
+            &reward_accounts.reward_token_program,
+            &ctx.accounts.memo_program,
+            transfer_hook_rewards[index],
+            transfer_amount,
+            transfer_memo::TRANSFER_MEMO_COLLECT_REWARD.as_bytes(),
+        )?;
+
+        emit!(RewardCollected {
+            yevefi: ctx.accounts.yevefi.key(),
+            position: ctx.accounts.position.key(),
+            reward_index: index as u8,
+            reward_mint: reward_accounts.reward_mint.key(),
+            amount: transfer_amount,
+            transfer_fee: calculate_transfer_fee_excluded_amount(
+                &reward_accounts.reward_mint,
+                transfer_amount
+            )?
+            .transfer_fee,
+        });
+    }
+
+    Ok(())
+}
+
+// The reward accounts are HARVEST_REWARD_ACCOUNTS_LEN accounts for each initialized reward,
+// in reward index order.
+//
+// The accounts are looked up in remaining_accounts, because InterfaceAccount must borrow
+// the AccountInfo for 'info.
+fn load_reward_accounts<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    reward_accounts: &Option<Vec<AccountInfo<'info>>>,
+    reward_infos: &[YevefiRewardInfo; NUM_REWARDS],
+) -> Result<[Option<HarvestRewardAccounts<'info>>; NUM_REWARDS]> {
+    let reward_accounts = reward_accounts.as_deref().unwrap_or_default();
+
+    let initialized_count = reward_infos
+        .iter()
+        .filter(|reward_info| reward_info.initialized())
+        .count();
+    if reward_accounts.len() != initialized_count * HARVEST_REWARD_ACCOUNTS_LEN {
+        return Err(ErrorCode::InvalidRewardAccounts.into());
+    }
+
+    let find_account_info = |account: &AccountInfo<'info>| -> Result<&'info AccountInfo<'info>> {
+        remaining_accounts
+            .iter()
+            .find(|remaining_account| remaining_account.key == account.key)
+            .ok_or(ErrorCode::InvalidRewardAccounts.into())
+    };
+
+    let mut loaded: [Option<HarvestRewardAccounts<'info>>; NUM_REWARDS] = Default::default();
+    let mut accounts_iter = reward_accounts.chunks(HARVEST_REWARD_ACCOUNTS_LEN);
+    for (index, reward_info) in reward_infos.iter().enumerate() {
+        if !reward_info.initialized() {
+            continue;
+        }
+
+        let accounts = accounts_iter
+            .next()
+            .ok_or(ErrorCode::InvalidRewardAccounts)?;
+        let owner_account_info = find_account_info(&accounts[0])?;
+        let vault_account_info = find_account_info(&accounts[2])?;
+        if !owner_account_info.is_writable || !vault_account_info.is_writable {
+            return Err(ErrorCode::InvalidRewardAccounts.into());
+        }
+
+        let reward_owner_account = InterfaceAccount::<TokenAccount>::try_from(owner_account_info)?;
+        let reward_mint = InterfaceAccount::<Mint>::try_from(find_account_info(&accounts[1])?)?;
+        let reward_vault = InterfaceAccount::<TokenAccount>::try_from(vault_account_info)?;
+        let reward_token_program =
+            Interface::<TokenInterface>::try_from(find_account_info(&accounts[3])?)?;
+
+        if reward_mint.key() != reward_info.mint
+            || reward_vault.key() != reward_info.vault
+            || reward_owner_account.mint != reward_info.mint
+            || reward_token_program.key() != *reward_mint.to_account_info().owner
+        {
+            return Err(ErrorCode::InvalidRewardAccounts.into());
+        }
+
+        loaded[index] = Some(HarvestRewardAccounts {
+            reward_owner_account,
+            reward_mint,
+            reward_vault,
+            reward_token_program,
+        });
+    }
+
+    Ok(loaded)
+}
@@ -3,17 +3,19 @@ use anchor_spl::memo::Memo;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::errors::ErrorCode;
+use crate::events::LiquidityIncreased;
 use crate::manager::liquidity_manager::{
     calculate_liquidity_token_deltas, calculate_modify_liquidity, sync_modify_liquidity_values,
 };
 use crate::math::convert_to_liquidity_delta;
 use crate::state::*;
 use crate::util::{
-    calculate_transfer_fee_included_amount, parse_remaining_accounts, AccountsType,
-    RemainingAccountsInfo,
+    calculate_transfer_fee_included_amount, load_reward_extension, parse_remaining_accounts,
+    AccountsType, RemainingAccountsInfo,
 };
 use crate::util::{
-    to_timestamp_u64, v2::transfer_from_owner_to_vault_v2, verify_position_authority_interface,
+    require_not_paused, to_timestamp_u64, update_oracle_before_liquidity_change,
+    v2::transfer_from_owner_to_vault_v2, verify_position_authority_interface,
 };
 
 #[derive(Accounts)]
@@ -60,6 +62,9 @@ pub struct ModifyLiquidityV2<'info> {
     // remaining accounts
     // - accounts for transfer hook program of token_mint_a
     // - accounts for transfer hook program of token_mint_b
+    // - reward extension accounts (required if the reward extension is initialized)
+    // - config extension of yevefis_config (required for increase, may be uninitialized)
+    // - oracle of yevefi (required, may be uninitialized)
 }
 
 pub fn handler<'info>(
@@ -69,27 +74,80 @@ pub fn handler<'info>(
     token_max_b: u64,
     remaining_accounts_info: Option<RemainingAccountsInfo>,
 ) -> Result<()> {
+    // Process remaining accounts
+    let remaining_accounts = parse_remaining_accounts(
+        ctx.remaining_accounts,
+        &remaining_accounts_info,
+        &[
+            AccountsType::TransferHookA,
+            AccountsType::TransferHookB,
+            AccountsType::RewardExtension,
+            AccountsType::PositionDelegate,
+            AccountsType::ConfigExtension,
+            AccountsType::Oracle,
+        ],
+    )?;
+
+    // The PositionDelegate is only passed if position_authority is not the position owner
+    let position_delegate = remaining_accounts
+        .position_delegate
+        .as_ref()
+        .and_then(|accounts| accounts.first());
     verify_position_authority_interface(
         &ctx.accounts.position_token_account,
         &ctx.accounts.position_authority,
+        position_delegate.map(|position_delegate| {
+            (
+                position_delegate,
+                ctx.accounts.position.key(),
+                POSITION_DELEGATE_PERMISSION_INCREASE_LIQUIDITY,
+            )
+        }),
     )?;
 
+    let config_extension = remaining_accounts
+        .config_extension
+        .as_ref()
+        .and_then(|accounts| accounts.first());
+    require_not_paused(&ctx.accounts.yevefi, config_extension)?;
+
     let clock = Clock::get()?;
 
     if liquidity_amount == 0 {
         return Err(ErrorCode::LiquidityZero.into());
     }
 
-    // Process remaining accounts
-    let remaining_accounts = parse_remaining_accounts(
-        ctx.remaining_accounts,
-        &remaining_accounts_info,
-        &[AccountsType::TransferHookA, AccountsType::TransferHookB],
-    )?;
-
     let liquidity_delta = convert_to_liquidity_delta(liquidity_amount, true)?;
     let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
 
+    // The extension rewards owed to the position must be accrued with its current liquidity
+    let reward_extension = load_reward_extension(
+        &ctx.accounts.yevefi,
+        ctx.accounts.yevefi.key(),
+        &remaining_accounts.reward_extension,
+        timestamp,
+    )?;
+    if let Some(reward_extension) = reward_extension {
+        reward_extension.update_position(
+            &ctx.accounts.yevefi,
+            &ctx.accounts.position,
+            ctx.accounts.position.key(),
+        )?;
+        reward_extension.exit()?;
+    }
+
+    // The oracle PDA is passed as the Oracle slice
+    let oracle = remaining_accounts
+        .oracle
+        .as_ref()
+        .and_then(|accounts| accounts.first());
+    update_oracle_before_liquidity_change(
+        oracle,
+        &ctx.accounts.yevefi.key(),
+        &ctx.accounts.yevefi,
+        timestamp,
+    )?;
+
     let update = calculate_modify_liquidity(
         &ctx.accounts.yevefi,
         &ctx.accounts.position,
@@ -150,5 +208,17 @@ pub fn handler<'info>(
         transfer_fee_included_delta_b.amount,
     )?;
 
+    emit!(LiquidityIncreased {
+        yevefi: ctx.accounts.yevefi.key(),
+        position: ctx.accounts.position.key(),
+        tick_lower_index: ctx.accounts.position.tick_lower_index,
+        tick_upper_index: ctx.accounts.position.tick_upper_index,
+        liquidity: liquidity_amount,
+        token_a_amount: transfer_fee_included_delta_a.amount,
+        token_b_amount: transfer_fee_included_delta_b.amount,
+        token_a_transfer_fee: transfer_fee_included_delta_a.transfer_fee,
+        token_b_transfer_fee: transfer_fee_included_delta_b.transfer_fee,
+    });
+
     Ok(())
 }
@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+
+use crate::math::get_liquidity_from_amounts;
+use crate::util::{calculate_transfer_fee_excluded_amount, RemainingAccountsInfo};
+
+use super::increase_liquidity::{self, ModifyLiquidityV2};
+
+/*
+  Adds the largest liquidity that token_max_a and token_max_b can fund at the current price.
+  The budgets are reduced by the transfer fees, as the vaults only receive the transfer fee
+  excluded amounts.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ModifyLiquidityV2<'info>>,
+    token_max_a: u64,
+    token_max_b: u64,
+    remaining_accounts_info: Option<RemainingAccountsInfo>,
+) -> Result<()> {
+    let budget_a = calculate_transfer_fee_excluded_amount(&ctx.accounts.token_mint_a, token_max_a)?;
+    let budget_b = calculate_transfer_fee_excluded_amount(&ctx.accounts.token_mint_b, token_max_b)?;
+
+    let liquidity_amount = get_liquidity_from_amounts(
+        ctx.accounts.yevefi.tick_current_index,
+        ctx.accounts.yevefi.sqrt_price,
+        ctx.accounts.position.tick_lower_index,
+        ctx.accounts.position.tick_upper_index,
+        budget_a.amount,
+        budget_b.amount,
+    )?;
+
+    // increase_liquidity_v2 rejects zero liquidity, and applies token_max_a and token_max_b
+    // to the transfer fee included amounts of the deposit
+    increase_liquidity::handler(
+        ctx,
+        liquidity_amount,
+        token_max_a,
+        token_max_b,
+        remaining_accounts_info,
+    )
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use crate::math::{
+        get_amount_delta_a, get_amount_delta_b, get_liquidity_from_amounts,
+        sqrt_price_from_tick_index,
+    };
+    use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFee;
+
+    fn transfer_fee(transfer_fee_basis_points: u16, maximum_fee: u64) -> TransferFee {
+        TransferFee {
+            epoch: 0.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: transfer_fee_basis_points.into(),
+        }
+    }
+
+    fn transfer_fee_excluded_amount(fee: &TransferFee, amount: u64) -> u64 {
+        amount - fee.calculate_fee(amount).unwrap()
+    }
+
+    fn transfer_fee_included_amount(fee: &TransferFee, amount: u64) -> u64 {
+        if amount == 0 {
+            return 0;
+        }
+        amount + fee.calculate_inverse_fee(amount).unwrap()
+    }
+
+    struct Deposit {
+        liquidity: u128,
+        budget_a: u64,
+        budget_b: u64,
+        delta_a: u64,
+        delta_b: u64,
+        transfer_fee_included_a: u64,
+        transfer_fee_included_b: u64,
+    }
+
+    // Follows the handler: liquidity is funded by the transfer fee excluded token_max amounts,
+    // and increase_liquidity_v2 transfers the transfer fee included token deltas.
+    fn deposit(
+        fee_a: &TransferFee,
+        fee_b: &TransferFee,
+        current_tick_index: i32,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+        token_max_a: u64,
+        token_max_b: u64,
+    ) -> Deposit {
+        let sqrt_price = sqrt_price_from_tick_index(current_tick_index);
+        let lower_price = sqrt_price_from_tick_index(tick_lower_index);
+        let upper_price = sqrt_price_from_tick_index(tick_upper_index);
+
+        let budget_a = transfer_fee_excluded_amount(fee_a, token_max_a);
+        let budget_b = transfer_fee_excluded_amount(fee_b, token_max_b);
+
+        let liquidity = get_liquidity_from_amounts(
+            current_tick_index,
+            sqrt_price,
+            tick_lower_index,
+            tick_upper_index,
+            budget_a,
+            budget_b,
+        )
+        .unwrap();
+
+        let price = sqrt_price.clamp(lower_price, upper_price);
+        let delta_a = get_amount_delta_a(price, upper_price, liquidity, true).unwrap();
+        let delta_b = get_amount_delta_b(lower_price, price, liquidity, true).unwrap();
+
+        Deposit {
+            liquidity,
+            budget_a,
+            budget_b,
+            delta_a,
+            delta_b,
+            transfer_fee_included_a: transfer_fee_included_amount(fee_a, delta_a),
+            transfer_fee_included_b: transfer_fee_included_amount(fee_b, delta_b),
+        }
+    }
+
+    #[test]
+    fn test_deposit_does_not_exceed_token_max() {
+        let fees = [
+            transfer_fee(0, 0),
+            transfer_fee(1, u64::MAX),
+            transfer_fee(100, u64::MAX),
+            transfer_fee(300, 1_000),
+            transfer_fee(9_999, u64::MAX),
+        ];
+        for fee_a in fees.iter() {
+            for fee_b in fees.iter() {
+                for (current_tick_index, token_max_a, token_max_b) in [
+                    (-200, 1_000_000, 0),
+                    (0, 1_000_000, 1_000_000),
+                    (0, 12_345_678, 987_654),
+                    (200, 0, 1_000_000),
+                ] {
+                    let deposit = deposit(
+                        fee_a,
+                        fee_b,
+                        current_tick_index,
+                        -128,
+                        128,
+                        token_max_a,
+                        token_max_b,
+                    );
+                    assert!(deposit.liquidity > 0);
+                    assert!(deposit.delta_a <= deposit.budget_a);
+                    assert!(deposit.delta_b <= deposit.budget_b);
+                    assert!(deposit.transfer_fee_included_a <= token_max_a);
+                    assert!(deposit.transfer_fee_included_b <= token_max_b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deposit_of_whole_budget_hits_token_max() {
+        // 1% transfer fee, rounded up: 10_000 -> fee 100 -> budget 9_900 -> fee included 10_000
+        let fee = transfer_fee(100, u64::MAX);
+        let no_fee = transfer_fee(0, 0);
+
+        // the transfer fee of a multiple of 100 is not rounded
+        let mut hits = 0;
+        for token_max_a in (1_000_000..1_010_000).step_by(100) {
+            // only token A is used below the range
+            let deposit = deposit(&fee, &no_fee, -200, -128, 128, token_max_a, 0);
+            assert!(deposit.transfer_fee_included_a <= token_max_a);
+
+            if deposit.delta_a == deposit.budget_a {
+                // the deposit uses the whole budget, and the fee included amount is token_max
+                assert_eq!(deposit.transfer_fee_included_a, token_max_a);
+                hits += 1;
+            }
+        }
+        assert!(hits > 0);
+    }
+
+    #[test]
+    fn test_budget_below_transfer_fee() {
+        // the whole token_max is taken by the transfer fee, so no liquidity can be funded
+        let fee = transfer_fee(100, u64::MAX);
+        let deposit = deposit(&fee, &fee, 0, -128, 128, 1, 1);
+        assert_eq!(deposit.budget_a, 0);
+        assert_eq!(deposit.budget_b, 0);
+        assert_eq!(deposit.liquidity, 0);
+        assert_eq!(deposit.transfer_fee_included_a, 0);
+        assert_eq!(deposit.transfer_fee_included_b, 0);
+    }
+}
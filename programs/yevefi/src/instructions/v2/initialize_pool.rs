@@ -3,6 +3,7 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
     errors::ErrorCode,
+    events::PoolInitialized,
     state::*,
     util::{is_token_badge_initialized, v2::is_supported_token_mint},
 };
@@ -104,11 +105,27 @@ pub fn handler(
         yevefis_config,
         bump,
         tick_spacing,
+        tick_spacing,
         initial_sqrt_price,
         default_fee_rate,
         token_mint_a,
         ctx.accounts.token_vault_a.key(),
         token_mint_b,
         ctx.accounts.token_vault_b.key(),
-    )
+    )?;
+
+    emit!(PoolInitialized {
+        yevefi: yevefi.key(),
+        yevefis_config: yevefis_config.key(),
+        token_mint_a,
+        token_mint_b,
+        tick_spacing,
+        token_program_a: ctx.accounts.token_program_a.key(),
+        token_program_b: ctx.accounts.token_program_b.key(),
+        decimals_a: ctx.accounts.token_mint_a.decimals,
+        decimals_b: ctx.accounts.token_mint_b.decimals,
+        initial_sqrt_price,
+    });
+
+    Ok(())
 }
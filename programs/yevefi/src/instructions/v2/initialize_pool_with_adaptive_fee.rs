@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    errors::ErrorCode,
+    events::PoolInitialized,
+    state::*,
+    util::{is_token_badge_initialized, v2::is_supported_token_mint},
+};
+
+#[derive(Accounts)]
+pub struct InitializePoolWithAdaptiveFeeV2<'info> {
+    pub yevefis_config: Box<Account<'info, YevefisConfig>>,
+
+    pub token_mint_a: InterfaceAccount<'info, Mint>,
+    pub token_mint_b: InterfaceAccount<'info, Mint>,
+
+    #[account(seeds = [b"token_badge", yevefis_config.key().as_ref(), token_mint_a.key().as_ref()], bump)]
+    /// CHECK: checked in the handler
+    pub token_badge_a: UncheckedAccount<'info>,
+    #[account(seeds = [b"token_badge", yevefis_config.key().as_ref(), token_mint_b.key().as_ref()], bump)]
+    /// CHECK: checked in the handler
+    pub token_badge_b: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(init,
+      seeds = [
+        b"yevefi".as_ref(),
+        yevefis_config.key().as_ref(),
+        token_mint_a.key().as_ref(),
+        token_mint_b.key().as_ref(),
+        adaptive_fee_tier.fee_tier_index.to_le_bytes().as_ref()
+      ],
+      bump,
+      payer = funder,
+      space = Yevefi::LEN)]
+    pub yevefi: Box<Account<'info, Yevefi>>,
+
+    #[account(init,
+      payer = funder,
+      token::token_program = token_program_a,
+      token::mint = token_mint_a,
+      token::authority = yevefi)]
+    pub token_vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(init,
+      payer = funder,
+      token::token_program = token_program_b,
+      token::mint = token_mint_b,
+      token::authority = yevefi)]
+    pub token_vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(has_one = yevefis_config)]
+    pub adaptive_fee_tier: Box<Account<'info, AdaptiveFeeTier>>,
+
+    #[account(address = *token_mint_a.to_account_info().owner)]
+    pub token_program_a: Interface<'info, TokenInterface>,
+    #[account(address = *token_mint_b.to_account_info().owner)]
+    pub token_program_b: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<InitializePoolWithAdaptiveFeeV2>,
+    initial_sqrt_price: u128,
+) -> Result<()> {
+    let token_mint_a = ctx.accounts.token_mint_a.key();
+    let token_mint_b = ctx.accounts.token_mint_b.key();
+
+    let yevefi = &mut ctx.accounts.yevefi;
+    let yevefis_config = &ctx.accounts.yevefis_config;
+
+    let adaptive_fee_tier = &ctx.accounts.adaptive_fee_tier;
+    let fee_tier_index = adaptive_fee_tier.fee_tier_index;
+    let tick_spacing = adaptive_fee_tier.tick_spacing;
+    let default_base_fee_rate = adaptive_fee_tier.default_base_fee_rate;
+
+    // ignore the bump passed and use one Anchor derived
+    let bump = ctx.bumps.yevefi;
+
+    // Don't allow creating a pool with unsupported token mints
+    let is_token_badge_initialized_a = is_token_badge_initialized(
+        yevefis_config.key(),
+        token_mint_a,
+        &ctx.accounts.token_badge_a,
+    )?;
+
+    if !is_supported_token_mint(&ctx.accounts.token_mint_a, is_token_badge_initialized_a).unwrap() {
+        return Err(ErrorCode::UnsupportedTokenMint.into());
+    }
+
+    let is_token_badge_initialized_b = is_token_badge_initialized(
+        yevefis_config.key(),
+        token_mint_b,
+        &ctx.accounts.token_badge_b,
+    )?;
+
+    if !is_supported_token_mint(&ctx.accounts.token_mint_b, is_token_badge_initialized_b).unwrap() {
+        return Err(ErrorCode::UnsupportedTokenMint.into());
+    }
+
+    yevefi.initialize(
+        yevefis_config,
+        bump,
+        tick_spacing,
+        fee_tier_index,
+        initial_sqrt_price,
+        default_base_fee_rate,
+        token_mint_a,
+        ctx.accounts.token_vault_a.key(),
+        token_mint_b,
+        ctx.accounts.token_vault_b.key(),
+    )?;
+    yevefi.initialize_adaptive_fee(adaptive_fee_tier.adaptive_fee_constants)?;
+
+    emit!(PoolInitialized {
+        yevefi: yevefi.key(),
+        yevefis_config: yevefis_config.key(),
+        token_mint_a,
+        token_mint_b,
+        tick_spacing,
+        token_program_a: ctx.accounts.token_program_a.key(),
+        token_program_b: ctx.accounts.token_program_b.key(),
+        decimals_a: ctx.accounts.token_mint_a.decimals,
+        decimals_b: ctx.accounts.token_mint_b.decimals,
+        initial_sqrt_price,
+    });
+
+    Ok(())
+}
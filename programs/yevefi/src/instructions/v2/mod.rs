@@ -1,35 +1,82 @@
 #![allow(unknown_lints)]
 #![allow(ambiguous_glob_reexports)]
 
+pub mod collect_extension_reward;
 pub mod collect_fees;
+pub mod collect_fees_bundled;
 pub mod collect_protocol_fees;
 pub mod collect_reward;
+pub mod collect_reward_bundled;
 pub mod decrease_liquidity;
+pub mod flash_loan;
+pub mod flash_swap;
+pub mod fund_reward;
+pub mod harvest_position;
 pub mod increase_liquidity;
+pub mod increase_liquidity_by_token_amounts;
+pub mod initialize_extension_reward;
 pub mod initialize_pool;
+pub mod initialize_pool_with_adaptive_fee;
 pub mod initialize_reward;
+pub mod n_hop_swap;
+pub mod quote_swap;
+pub mod reposition_liquidity;
+pub mod set_extension_reward_emissions;
 pub mod set_reward_emissions;
+pub mod set_reward_emissions_schedule;
 pub mod swap;
 pub mod two_hop_swap;
+pub mod update_fees_and_rewards_bundled;
+pub mod withdraw_reward_surplus;
+pub mod zap_in;
+pub mod zap_out;
 
+pub mod accept_config_extension_authority;
+pub mod accept_token_badge_authority;
 pub mod delete_token_badge;
 pub mod initialize_config_extension;
 pub mod initialize_token_badge;
 pub mod set_config_extension_authority;
+pub mod set_global_paused;
+pub mod set_pause_authority;
+pub mod set_pool_paused;
 pub mod set_token_badge_authority;
 
+pub use collect_extension_reward::*;
 pub use collect_fees::*;
+pub use collect_fees_bundled::*;
 pub use collect_protocol_fees::*;
 pub use collect_reward::*;
+pub use collect_reward_bundled::*;
+pub use flash_loan::*;
+pub use flash_swap::*;
+pub use fund_reward::*;
+pub use harvest_position::*;
 pub use increase_liquidity::*;
+pub use initialize_extension_reward::*;
 pub use initialize_pool::*;
+pub use initialize_pool_with_adaptive_fee::*;
 pub use initialize_reward::*;
+pub use n_hop_swap::*;
+pub use quote_swap::*;
+pub use reposition_liquidity::*;
+pub use set_extension_reward_emissions::*;
 pub use set_reward_emissions::*;
+pub use set_reward_emissions_schedule::*;
 pub use swap::*;
 pub use two_hop_swap::*;
+pub use update_fees_and_rewards_bundled::*;
+pub use withdraw_reward_surplus::*;
+pub use zap_in::*;
+pub use zap_out::*;
 
+pub use accept_config_extension_authority::*;
+pub use accept_token_badge_authority::*;
 pub use delete_token_badge::*;
 pub use initialize_config_extension::*;
 pub use initialize_token_badge::*;
 pub use set_config_extension_authority::*;
+pub use set_global_paused::*;
+pub use set_pause_authority::*;
+pub use set_pool_paused::*;
 pub use set_token_badge_authority::*;
@@ -0,0 +1,578 @@
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::manager::swap_manager::PostSwapUpdate;
+use crate::swap_with_transfer_fee_extension;
+use crate::util::{
+    calculate_transfer_fee_excluded_amount, parse_remaining_accounts,
+    update_and_n_hop_swap_yevefi_v2, AccountsType, RemainingAccountsInfo, SwapHopAccounts,
+};
+use crate::{
+    constants::transfer_memo,
+    errors::ErrorCode,
+    state::Yevefi,
+    util::{
+        require_no_reward_extension, require_not_paused, to_timestamp_u64, update_oracle,
+        SparseSwapTickSequenceBuilder,
+    },
+};
+
+pub const MAX_SWAP_ROUTE_LEN: usize = 4;
+
+// yevefi, token_mint_output, token_program_output, token_vault_input, token_vault_output,
+// tick_array_0, tick_array_1, tick_array_2, oracle, yevefis_config_extension
+const SWAP_HOP_ACCOUNTS_LEN: usize = 10;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct SwapRouteHop {
+    pub a_to_b: bool,
+    pub sqrt_price_limit: u128,
+}
+
+#[derive(Accounts)]
+pub struct NHopSwapV2<'info> {
+    pub token_authority: Signer<'info>,
+
+    pub token_mint_input: InterfaceAccount<'info, Mint>,
+    #[account(address = *token_mint_input.to_account_info().owner)]
+    pub token_program_input: Interface<'info, TokenInterface>,
+
+    #[account(mut, constraint = token_owner_account_input.mint == token_mint_input.key())]
+    pub token_owner_account_input: Box<InterfaceAccount<'info, TokenAccount>>,
+    // mint is checked in the handler against the output mint of the last hop
+    #[account(mut)]
+    pub token_owner_account_output: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub memo_program: Program<'info, Memo>,
+    // remaining accounts
+    // - for each hop in route order:
+    //   yevefi, token_mint_output, token_program_output, token_vault_input, token_vault_output,
+    //   tick_array_0, tick_array_1, tick_array_2, oracle, yevefis_config_extension
+    // - accounts for transfer hook program of token_mint_input
+    // - accounts for transfer hook program of each intermediate mint
+    // - accounts for transfer hook program of the output mint
+    // - supplemental TickArray accounts for each hop
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, NHopSwapV2<'info>>,
+    amount: u64,
+    other_amount_threshold: u64,
+    amount_specified_is_input: bool,
+    route: Vec<SwapRouteHop>,
+    remaining_accounts_info: Option<RemainingAccountsInfo>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    // Update the global reward growth which increases as a function of time.
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    let route_len = route.len();
+    let (hop_account_infos, remaining_accounts) =
+        split_hop_account_infos(ctx.remaining_accounts, route_len)?;
+
+    let mut hops: Vec<SwapHopAccounts<'info>> = Vec::with_capacity(route_len);
+    for (route_hop, account_infos) in route
+        .iter()
+        .zip(hop_account_infos.chunks(SWAP_HOP_ACCOUNTS_LEN))
+    {
+        hops.push(load_swap_hop_accounts(route_hop.a_to_b, account_infos)?);
+    }
+
+    // Don't allow swaps on the same yevefi
+    for i in 0..route_len {
+        for j in (i + 1)..route_len {
+            if hops[i].yevefi.key() == hops[j].yevefi.key() {
+                return Err(ErrorCode::DuplicateTwoHopPool.into());
+            }
+        }
+    }
+
+    if hops[0].yevefi.input_token_mint(route[0].a_to_b) != ctx.accounts.token_mint_input.key() {
+        return Err(AnchorErrorCode::ConstraintAddress.into());
+    }
+    for i in 1..route_len {
+        if hops[i].yevefi.input_token_mint(route[i].a_to_b) != hops[i - 1].token_mint_output.key() {
+            return Err(ErrorCode::InvalidIntermediaryMint.into());
+        }
+    }
+    if ctx.accounts.token_owner_account_output.mint != hops[route_len - 1].token_mint_output.key() {
+        return Err(AnchorErrorCode::ConstraintRaw.into());
+    }
+
+    for (hop, account_infos) in hops
+        .iter()
+        .zip(hop_account_infos.chunks(SWAP_HOP_ACCOUNTS_LEN))
+    {
+        require_not_paused(&hop.yevefi, Some(&account_infos[9]))?;
+        require_no_reward_extension(&hop.yevefi)?;
+        update_oracle(&account_infos[8], &hop.yevefi, timestamp)?;
+    }
+
+    // Process remaining accounts
+    // Only the slices for the hops of this route are accepted
+    let valid_accounts_types: Vec<AccountsType> = [
+        AccountsType::TransferHookInput,
+        AccountsType::TransferHookOutput,
+    ]
+    .into_iter()
+    .chain(
+        [
+            AccountsType::TransferHookIntermediate,
+            AccountsType::TransferHookIntermediateTwo,
+            AccountsType::TransferHookIntermediateThree,
+        ]
+        .into_iter()
+        .take(route_len - 1),
+    )
+    .chain(
+        [
+            AccountsType::SupplementalTickArraysOne,
+            AccountsType::SupplementalTickArraysTwo,
+            AccountsType::SupplementalTickArraysThree,
+            AccountsType::SupplementalTickArraysFour,
+        ]
+        .into_iter()
+        .take(route_len),
+    )
+    .collect();
+    let remaining_accounts = parse_remaining_accounts(
+        remaining_accounts,
+        &remaining_accounts_info,
+        &valid_accounts_types,
+    )?;
+
+    let supplemental_tick_arrays = [
+        remaining_accounts.supplemental_tick_arrays_one,
+        remaining_accounts.supplemental_tick_arrays_two,
+        remaining_accounts.supplemental_tick_arrays_three,
+        remaining_accounts.supplemental_tick_arrays_four,
+    ];
+    let mut builders = Vec::with_capacity(route_len);
+    for ((hop, account_infos), supplemental_tick_array_account_infos) in hops
+        .iter()
+        .zip(hop_account_infos.chunks(SWAP_HOP_ACCOUNTS_LEN))
+        .zip(supplemental_tick_arrays)
+    {
+        builders.push(SparseSwapTickSequenceBuilder::try_from(
+            &hop.yevefi,
+            hop.a_to_b,
+            account_infos[5..8].to_vec(),
+            supplemental_tick_array_account_infos,
+        )?);
+    }
+    let mut swap_tick_sequences = Vec::with_capacity(route_len);
+    for builder in builders.iter() {
+        swap_tick_sequences.push(builder.build()?);
+    }
+
+    // hop i swaps token_mints[i] into token_mints[i + 1]
+    let mut token_mints: Vec<&InterfaceAccount<'info, Mint>> = Vec::with_capacity(route_len + 1);
+    token_mints.push(&ctx.accounts.token_mint_input);
+    token_mints.extend(hops.iter().map(|hop| hop.token_mint_output.as_ref()));
+
+    let swap_hop = |i: usize, amount: u64| -> Result<PostSwapUpdate> {
+        let a_to_b = route[i].a_to_b;
+        swap_with_transfer_fee_extension(
+            &hops[i].yevefi,
+            if a_to_b {
+                token_mints[i]
+            } else {
+                token_mints[i + 1]
+            },
+            if a_to_b {
+                token_mints[i + 1]
+            } else {
+                token_mints[i]
+            },
+            &mut swap_tick_sequences[i],
+            amount,
+            route[i].sqrt_price_limit,
+            amount_specified_is_input,
+            a_to_b,
+            timestamp,
+            0,
+        )
+    };
+
+    let swap_updates = chain_swap_hops(
+        &route,
+        amount,
+        amount_specified_is_input,
+        swap_hop,
+        |i, input_amount| {
+            Ok(calculate_transfer_fee_excluded_amount(token_mints[i], input_amount)?.amount)
+        },
+    )?;
+
+    // All output token of a hop should be consumed by the next hop
+    verify_intermediate_amounts(&route, &swap_updates)?;
+
+    if amount_specified_is_input {
+        // If amount_specified_is_input == true, then we have a variable amount of output
+        // The slippage we care about is the output of the last hop.
+        let last = route_len - 1;
+        let output_amount = calculate_transfer_fee_excluded_amount(
+            token_mints[route_len],
+            hop_output_amount(route[last].a_to_b, &swap_updates[last]),
+        )?
+        .amount;
+
+        // If we have received less than the minimum out, throw an error
+        if output_amount < other_amount_threshold {
+            return Err(ErrorCode::AmountOutBelowMinimum.into());
+        }
+    } else {
+        // amount_specified_is_output == false, then we have a variable amount of input
+        // The slippage we care about is the input of the first hop
+        let input_amount = hop_input_amount(route[0].a_to_b, &swap_updates[0]);
+        if input_amount > other_amount_threshold {
+            return Err(ErrorCode::AmountInAboveMaximum.into());
+        }
+    }
+
+    update_and_n_hop_swap_yevefi_v2(
+        &swap_updates,
+        &mut hops,
+        &ctx.accounts.token_mint_input,
+        &ctx.accounts.token_program_input,
+        &ctx.accounts.token_owner_account_input,
+        &ctx.accounts.token_owner_account_output,
+        &remaining_accounts.transfer_hook_input,
+        &[
+            remaining_accounts.transfer_hook_intermediate,
+            remaining_accounts.transfer_hook_intermediate_two,
+            remaining_accounts.transfer_hook_intermediate_three,
+        ],
+        &remaining_accounts.transfer_hook_output,
+        &ctx.accounts.token_authority,
+        &ctx.accounts.memo_program,
+        timestamp,
+        transfer_memo::TRANSFER_MEMO_SWAP.as_bytes(),
+    )?;
+
+    // Yevefis are not part of the Accounts struct, so they must be persisted explicitly
+    for hop in hops.iter() {
+        hop.yevefi.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+// Splits the remaining accounts into the accounts of each hop and the accounts that follow them.
+fn split_hop_account_infos<T>(remaining_accounts: &[T], route_len: usize) -> Result<(&[T], &[T])> {
+    if route_len == 0 || route_len > MAX_SWAP_ROUTE_LEN {
+        return Err(ErrorCode::InvalidSwapRouteLength.into());
+    }
+
+    let hop_accounts_len = route_len * SWAP_HOP_ACCOUNTS_LEN;
+    if remaining_accounts.len() < hop_accounts_len {
+        return Err(ErrorCode::RemainingAccountsInsufficient.into());
+    }
+    Ok(remaining_accounts.split_at(hop_accounts_len))
+}
+
+fn hop_input_amount(a_to_b: bool, swap_update: &PostSwapUpdate) -> u64 {
+    if a_to_b {
+        swap_update.amount_a
+    } else {
+        swap_update.amount_b
+    }
+}
+
+fn hop_output_amount(a_to_b: bool, swap_update: &PostSwapUpdate) -> u64 {
+    if a_to_b {
+        swap_update.amount_b
+    } else {
+        swap_update.amount_a
+    }
+}
+
+// Calculates the swap of each hop, feeding the amount of a hop into its neighbour.
+// Returns the swap updates in route order.
+fn chain_swap_hops(
+    route: &[SwapRouteHop],
+    amount: u64,
+    amount_specified_is_input: bool,
+    mut swap_hop: impl FnMut(usize, u64) -> Result<PostSwapUpdate>,
+    transfer_fee_excluded_input: impl Fn(usize, u64) -> Result<u64>,
+) -> Result<Vec<PostSwapUpdate>> {
+    let mut swap_updates = Vec::with_capacity(route.len());
+    let mut swap_amount = amount;
+    if amount_specified_is_input {
+        // If the amount specified is input, this means we are doing exact-in
+        // and the swap calculations occur from the first hop to the last hop
+        for (i, route_hop) in route.iter().enumerate() {
+            let swap_update = swap_hop(i, swap_amount)?;
+
+            // The input of the next hop is the output of this hop
+            // We use vault to vault transfer, so transfer fee will be collected once.
+            swap_amount = hop_output_amount(route_hop.a_to_b, &swap_update);
+            swap_updates.push(swap_update);
+        }
+    } else {
+        // If the amount specified is output, this means we need to invert the ordering of the calculations
+        // and the swap calculations occur from the last hop to the first hop
+        // but the actual swaps occur from the first hop to the last hop (to ensure that the intermediate tokens exist in the accounts)
+        for (i, route_hop) in route.iter().enumerate().rev() {
+            let swap_update = swap_hop(i, swap_amount)?;
+
+            // The output of the previous hop is the input of this hop
+            let input_amount = hop_input_amount(route_hop.a_to_b, &swap_update);
+            swap_amount = transfer_fee_excluded_input(i, input_amount)?;
+            swap_updates.push(swap_update);
+        }
+        swap_updates.reverse();
+    }
+    Ok(swap_updates)
+}
+
+fn verify_intermediate_amounts(
+    route: &[SwapRouteHop],
+    swap_updates: &[PostSwapUpdate],
+) -> Result<()> {
+    for (route_hops, swap_updates) in route.windows(2).zip(swap_updates.windows(2)) {
+        let output_amount = hop_output_amount(route_hops[0].a_to_b, &swap_updates[0]);
+        let next_input_amount = hop_input_amount(route_hops[1].a_to_b, &swap_updates[1]);
+        if output_amount != next_input_amount {
+            return Err(ErrorCode::IntermediateTokenAmountMismatch.into());
+        }
+    }
+    Ok(())
+}
+
+// Loads and verifies the accounts of a hop, as anchor constraints would do for a fixed route.
+fn load_swap_hop_accounts<'info>(
+    a_to_b: bool,
+    account_infos: &'info [AccountInfo<'info>],
+) -> Result<SwapHopAccounts<'info>> {
+    for writable_account_info in [&account_infos[0], &account_infos[3], &account_infos[4]] {
+        if !writable_account_info.is_writable {
+            return Err(AnchorErrorCode::ConstraintMut.into());
+        }
+    }
+
+    let yevefi = Box::new(Account::<Yevefi>::try_from(&account_infos[0])?);
+
+    let token_mint_output = Box::new(InterfaceAccount::<Mint>::try_from(&account_infos[1])?);
+    if token_mint_output.key() != yevefi.output_token_mint(a_to_b) {
+        return Err(AnchorErrorCode::ConstraintAddress.into());
+    }
+
+    let token_program_output = Interface::<TokenInterface>::try_from(&account_infos[2])?;
+    if token_program_output.key() != *token_mint_output.to_account_info().owner {
+        return Err(AnchorErrorCode::ConstraintAddress.into());
+    }
+
+    let token_vault_input = Box::new(InterfaceAccount::<TokenAccount>::try_from(
+        &account_infos[3],
+    )?);
+    if token_vault_input.key() != yevefi.input_token_vault(a_to_b) {
+        return Err(AnchorErrorCode::ConstraintAddress.into());
+    }
+
+    let token_vault_output = Box::new(InterfaceAccount::<TokenAccount>::try_from(
+        &account_infos[4],
+    )?);
+    if token_vault_output.key() != yevefi.output_token_vault(a_to_b) {
+        return Err(AnchorErrorCode::ConstraintAddress.into());
+    }
+
+    let (oracle, _) = Pubkey::find_program_address(&[b"oracle", yevefi.key().as_ref()], &crate::ID);
+    if account_infos[8].key() != oracle {
+        return Err(AnchorErrorCode::ConstraintSeeds.into());
+    }
+
+    let (config_extension, _) = Pubkey::find_program_address(
+        &[b"config_extension", yevefi.yevefis_config.as_ref()],
+        &crate::ID,
+    );
+    if account_infos[9].key() != config_extension {
+        return Err(AnchorErrorCode::ConstraintSeeds.into());
+    }
+
+    Ok(SwapHopAccounts {
+        yevefi,
+        a_to_b,
+        token_mint_output,
+        token_program_output,
+        token_vault_input,
+        token_vault_output,
+    })
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::state::{AdaptiveFeeInfo, YevefiRewardInfo, NUM_REWARDS};
+
+    fn route(a_to_bs: &[bool]) -> Vec<SwapRouteHop> {
+        a_to_bs
+            .iter()
+            .map(|&a_to_b| SwapRouteHop {
+                a_to_b,
+                sqrt_price_limit: 0,
+            })
+            .collect()
+    }
+
+    fn swap_update(a_to_b: bool, amount_in: u64, amount_out: u64) -> PostSwapUpdate {
+        let (amount_a, amount_b) = if a_to_b {
+            (amount_in, amount_out)
+        } else {
+            (amount_out, amount_in)
+        };
+        PostSwapUpdate {
+            amount_a,
+            amount_b,
+            next_liquidity: 0,
+            next_tick_index: 0,
+            next_sqrt_price: 0,
+            next_fee_growth_global: 0,
+            next_reward_infos: [YevefiRewardInfo::default(); NUM_REWARDS],
+            next_protocol_fee: 0,
+            next_referral_fee: 0,
+            next_lp_fee: 0,
+            next_adaptive_fee_info: AdaptiveFeeInfo::default(),
+            ticks_crossed: 0,
+            tick_arrays_touched: 0,
+        }
+    }
+
+    #[test]
+    fn test_split_hop_account_infos() {
+        let accounts: Vec<usize> = (0..SWAP_HOP_ACCOUNTS_LEN * 2 + 3).collect();
+
+        let (hop_accounts, rest) = split_hop_account_infos(&accounts, 2).unwrap();
+        assert_eq!(hop_accounts.len(), SWAP_HOP_ACCOUNTS_LEN * 2);
+        assert_eq!(rest, &[20, 21, 22]);
+
+        // each hop gets its own fixed-size slice in route order
+        let hops: Vec<&[usize]> = hop_accounts.chunks(SWAP_HOP_ACCOUNTS_LEN).collect();
+        assert_eq!(hops[0][0], 0);
+        assert_eq!(hops[1][0], SWAP_HOP_ACCOUNTS_LEN);
+        assert_eq!(
+            hops[1][SWAP_HOP_ACCOUNTS_LEN - 1],
+            SWAP_HOP_ACCOUNTS_LEN * 2 - 1
+        );
+    }
+
+    #[test]
+    fn test_split_hop_account_infos_exact_len() {
+        let accounts: Vec<usize> = (0..SWAP_HOP_ACCOUNTS_LEN * 3).collect();
+        let (hop_accounts, rest) = split_hop_account_infos(&accounts, 3).unwrap();
+        assert_eq!(hop_accounts.len(), SWAP_HOP_ACCOUNTS_LEN * 3);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn test_split_hop_account_infos_insufficient() {
+        let accounts: Vec<usize> = (0..SWAP_HOP_ACCOUNTS_LEN * 2 - 1).collect();
+        let result = split_hop_account_infos(&accounts, 2);
+        assert_eq!(
+            result.unwrap_err(),
+            ErrorCode::RemainingAccountsInsufficient.into()
+        );
+    }
+
+    #[test]
+    fn test_split_hop_account_infos_route_len_bounds() {
+        let accounts: Vec<usize> = (0..SWAP_HOP_ACCOUNTS_LEN * (MAX_SWAP_ROUTE_LEN + 1)).collect();
+
+        assert_eq!(
+            split_hop_account_infos(&accounts, 0).unwrap_err(),
+            ErrorCode::InvalidSwapRouteLength.into()
+        );
+        assert_eq!(
+            split_hop_account_infos(&accounts, MAX_SWAP_ROUTE_LEN + 1).unwrap_err(),
+            ErrorCode::InvalidSwapRouteLength.into()
+        );
+        assert!(split_hop_account_infos(&accounts, 1).is_ok());
+        assert!(split_hop_account_infos(&accounts, MAX_SWAP_ROUTE_LEN).is_ok());
+    }
+
+    #[test]
+    fn test_chain_swap_hops_exact_in() {
+        let route = route(&[true, false, true]);
+        let mut inputs = vec![];
+        // each hop outputs half of its input
+        let swap_updates = chain_swap_hops(
+            &route,
+            1000,
+            true,
+            |i, amount| {
+                inputs.push((i, amount));
+                Ok(swap_update(route[i].a_to_b, amount, amount / 2))
+            },
+            |_, _| panic!("transfer fee is only applied for exact out"),
+        )
+        .unwrap();
+
+        assert_eq!(inputs, vec![(0, 1000), (1, 500), (2, 250)]);
+        assert_eq!(swap_updates.len(), 3);
+        assert_eq!(hop_output_amount(true, &swap_updates[2]), 125);
+        assert!(verify_intermediate_amounts(&route, &swap_updates).is_ok());
+    }
+
+    #[test]
+    fn test_chain_swap_hops_exact_out() {
+        let route = route(&[false, true, false, true]);
+        let mut outputs = vec![];
+        // each hop needs twice its output as input, and every transfer pays a fee of 1
+        let swap_updates = chain_swap_hops(
+            &route,
+            100,
+            false,
+            |i, amount| {
+                outputs.push((i, amount));
+                Ok(swap_update(route[i].a_to_b, amount * 2 + 1, amount + 1))
+            },
+            |_, amount| Ok(amount - 1),
+        )
+        .unwrap();
+
+        // calculated from the last hop to the first hop
+        assert_eq!(outputs, vec![(3, 100), (2, 200), (1, 400), (0, 800)]);
+        // but returned in route order
+        assert_eq!(hop_input_amount(false, &swap_updates[0]), 1601);
+        assert_eq!(hop_output_amount(true, &swap_updates[3]), 101);
+        assert!(verify_intermediate_amounts(&route, &swap_updates).is_ok());
+    }
+
+    #[test]
+    fn test_chain_swap_hops_propagates_error() {
+        let route = route(&[true, true]);
+        let result = chain_swap_hops(
+            &route,
+            1000,
+            true,
+            |i, amount| {
+                if i == 1 {
+                    return Err(ErrorCode::ZeroTradableAmount.into());
+                }
+                Ok(swap_update(true, amount, amount))
+            },
+            |_, amount| Ok(amount),
+        );
+        assert_eq!(result.unwrap_err(), ErrorCode::ZeroTradableAmount.into());
+    }
+
+    #[test]
+    fn test_verify_intermediate_amounts_mismatch() {
+        let route = route(&[true, false]);
+        let swap_updates = vec![swap_update(true, 1000, 500), swap_update(false, 499, 250)];
+        assert_eq!(
+            verify_intermediate_amounts(&route, &swap_updates).unwrap_err(),
+            ErrorCode::IntermediateTokenAmountMismatch.into()
+        );
+    }
+
+    #[test]
+    fn test_verify_intermediate_amounts_single_hop() {
+        let route = route(&[true]);
+        let swap_updates = vec![swap_update(true, 1000, 500)];
+        assert!(verify_intermediate_amounts(&route, &swap_updates).is_ok());
+    }
+}
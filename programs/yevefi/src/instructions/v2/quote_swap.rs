@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use solana_program::program::set_return_data;
+
+use crate::util::{
+    calculate_transfer_fee_excluded_amount, load_referral, parse_remaining_accounts, AccountsType,
+    RemainingAccountsInfo,
+};
+use crate::{
+    state::Yevefi,
+    swap_with_transfer_fee_extension,
+    util::{require_not_paused, to_timestamp_u64, SparseSwapTickSequenceBuilder},
+};
+
+/// Result of the `quote_swap` instruction, set as return data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug, PartialEq)]
+pub struct SwapQuote {
+    // transfer fee included amount paid by the swapper
+    pub amount_in: u64,
+    // transfer fee excluded amount received by the swapper
+    pub amount_out: u64,
+    // lp fee + protocol fee + referral fee, in input token
+    pub fee_amount: u64,
+    // portion of fee_amount paid to the referrer, 0 if no referral accounts are supplied
+    pub referral_fee: u64,
+    pub input_transfer_fee: u64,
+    pub output_transfer_fee: u64,
+    pub end_sqrt_price: u128,
+    pub end_tick_index: i32,
+    pub ticks_crossed: u16,
+    pub tick_arrays_touched: u8,
+}
+
+#[derive(Accounts)]
+pub struct QuoteSwap<'info> {
+    pub yevefi: Box<Account<'info, Yevefi>>,
+
+    #[account(address = yevefi.token_mint_a)]
+    pub token_mint_a: InterfaceAccount<'info, Mint>,
+    #[account(address = yevefi.token_mint_b)]
+    pub token_mint_b: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: checked in the handler
+    pub tick_array_0: UncheckedAccount<'info>,
+
+    /// CHECK: checked in the handler
+    pub tick_array_1: UncheckedAccount<'info>,
+
+    /// CHECK: checked in the handler
+    pub tick_array_2: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"config_extension", yevefi.yevefis_config.as_ref()], bump)]
+    /// CHECK: Config extension may be uninitialized, checked in the handler
+    pub yevefis_config_extension: UncheckedAccount<'info>,
+    // remaining accounts
+    // - supplemental TickArray accounts
+    // - referral accounts (optional)
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, QuoteSwap<'info>>,
+    amount: u64,
+    sqrt_price_limit: u128,
+    amount_specified_is_input: bool,
+    a_to_b: bool, // Zero for one
+    remaining_accounts_info: Option<RemainingAccountsInfo>,
+) -> Result<()> {
+    let yevefi = &ctx.accounts.yevefi;
+    // A paused yevefi cannot be swapped by swap_v2, so it is not quoted either.
+    // The reward extension does not change the swapped amounts, so its accounts are not required.
+    require_not_paused(
+        yevefi,
+        Some(&ctx.accounts.yevefis_config_extension.to_account_info()),
+    )?;
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    // Process remaining accounts
+    let remaining_accounts = parse_remaining_accounts(
+        ctx.remaining_accounts,
+        &remaining_accounts_info,
+        &[AccountsType::SupplementalTickArrays, AccountsType::Referral],
+    )?;
+
+    let referral_fee_rate = load_referral(
+        ctx.remaining_accounts,
+        &remaining_accounts.referral,
+        yevefi.yevefis_config,
+        &[yevefi.input_token_mint(a_to_b)],
+    )?
+    .map_or(0, |referral| referral.referral_fee_rate);
+
+    // Tick updates on crossing are discarded, so no account is written
+    let builder = SparseSwapTickSequenceBuilder::try_from_read_only(
+        yevefi,
+        a_to_b,
+        vec![
+            ctx.accounts.tick_array_0.to_account_info(),
+            ctx.accounts.tick_array_1.to_account_info(),
+            ctx.accounts.tick_array_2.to_account_info(),
+        ],
+        remaining_accounts.supplemental_tick_arrays,
+    )?;
+    let mut swap_tick_sequence = builder.build()?;
+
+    let swap_update = swap_with_transfer_fee_extension(
+        yevefi,
+        &ctx.accounts.token_mint_a,
+        &ctx.accounts.token_mint_b,
+        &mut swap_tick_sequence,
+        amount,
+        sqrt_price_limit,
+        amount_specified_is_input,
+        a_to_b,
+        timestamp,
+        referral_fee_rate,
+    )?;
+
+    let (input_token_mint, output_token_mint, amount_in, transfer_fee_included_output) = if a_to_b {
+        (
+            &ctx.accounts.token_mint_a,
+            &ctx.accounts.token_mint_b,
+            swap_update.amount_a,
+            swap_update.amount_b,
+        )
+    } else {
+        (
+            &ctx.accounts.token_mint_b,
+            &ctx.accounts.token_mint_a,
+            swap_update.amount_b,
+            swap_update.amount_a,
+        )
+    };
+
+    let input_transfer_fee =
+        calculate_transfer_fee_excluded_amount(input_token_mint, amount_in)?.transfer_fee;
+    let transfer_fee_excluded_output =
+        calculate_transfer_fee_excluded_amount(output_token_mint, transfer_fee_included_output)?;
+
+    let quote = SwapQuote {
+        amount_in,
+        amount_out: transfer_fee_excluded_output.amount,
+        fee_amount: swap_update.next_lp_fee
+            + swap_update.next_protocol_fee
+            + swap_update.next_referral_fee,
+        referral_fee: swap_update.next_referral_fee,
+        input_transfer_fee,
+        output_transfer_fee: transfer_fee_excluded_output.transfer_fee,
+        end_sqrt_price: swap_update.next_sqrt_price,
+        end_tick_index: swap_update.next_tick_index,
+        ticks_crossed: swap_update.ticks_crossed,
+        tick_arrays_touched: swap_update.tick_arrays_touched,
+    };
+
+    set_return_data(&quote.try_to_vec()?);
+    Ok(())
+}
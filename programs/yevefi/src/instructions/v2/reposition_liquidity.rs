@@ -0,0 +1,374 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::transfer_memo;
+use crate::errors::ErrorCode;
+use crate::events::LiquidityRepositioned;
+use crate::manager::liquidity_manager::{
+    calculate_liquidity_token_deltas, calculate_modify_liquidity, sync_modify_liquidity_values,
+};
+use crate::math::convert_to_liquidity_delta;
+use crate::state::*;
+use crate::util::{
+    build_position_token_metadata_fields, calculate_transfer_fee_excluded_amount,
+    calculate_transfer_fee_included_amount, has_position_token_metadata, parse_remaining_accounts,
+    update_token_metadata_fields, AccountsType, RemainingAccountsInfo,
+};
+use crate::util::{
+    require_no_reward_extension, require_not_paused, require_position_unlocked, to_timestamp_u64,
+    update_oracle,
+    v2::{transfer_from_owner_to_vault_v2, transfer_from_vault_to_owner_v2},
+    verify_position_authority_interface,
+};
+
+#[derive(Accounts)]
+pub struct RepositionLiquidity<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(mut)]
+    pub yevefi: Account<'info, Yevefi>,
+
+    #[account(address = *token_mint_a.to_account_info().owner)]
+    pub token_program_a: Interface<'info, TokenInterface>,
+    #[account(address = *token_mint_b.to_account_info().owner)]
+    pub token_program_b: Interface<'info, TokenInterface>,
+
+    pub memo_program: Program<'info, Memo>,
+
+    pub position_authority: Signer<'info>,
+
+    #[account(mut, has_one = yevefi)]
+    pub position: Account<'info, Position>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, address = position.position_mint)]
+    pub position_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(address = yevefi.token_mint_a)]
+    pub token_mint_a: InterfaceAccount<'info, Mint>,
+    #[account(address = yevefi.token_mint_b)]
+    pub token_mint_b: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, constraint = token_owner_account_a.mint == yevefi.token_mint_a)]
+    pub token_owner_account_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, constraint = token_owner_account_b.mint == yevefi.token_mint_b)]
+    pub token_owner_account_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, constraint = token_vault_a.key() == yevefi.token_vault_a)]
+    pub token_vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, constraint = token_vault_b.key() == yevefi.token_vault_b)]
+    pub token_vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // tick arrays of the current range
+    #[account(mut, has_one = yevefi)]
+    pub tick_array_lower: AccountLoader<'info, TickArray>,
+    #[account(mut, has_one = yevefi)]
+    pub tick_array_upper: AccountLoader<'info, TickArray>,
+
+    // tick arrays of the new range
+    #[account(mut, has_one = yevefi)]
+    pub new_tick_array_lower: AccountLoader<'info, TickArray>,
+    #[account(mut, has_one = yevefi)]
+    pub new_tick_array_upper: AccountLoader<'info, TickArray>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+
+    #[account(mut, seeds = [b"oracle", yevefi.key().as_ref()], bump)]
+    /// CHECK: Oracle is optional, checked in the handler if initialized
+    pub oracle: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"config_extension", yevefi.yevefis_config.as_ref()], bump)]
+    /// CHECK: Config extension may be uninitialized, checked in the handler
+    pub yevefis_config_extension: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"position_lock", position.key().as_ref()], bump)]
+    /// CHECK: Position lock is checked in the handler if the position is locked
+    pub position_lock: UncheckedAccount<'info>,
+    // remaining accounts
+    // - accounts for transfer hook program of token_mint_a
+    // - accounts for transfer hook program of token_mint_b
+}
+
+/*
+  Re-ranges a position with a new size: removes all of its liquidity, moves it to a new
+  tick range and adds liquidity_amount there, which may be more or less than was removed.
+  Only the difference between the removed and added token amounts is transferred.
+  The TokenMetadata of a position opened with token extensions is refreshed to the new range.
+*/
+#[allow(clippy::too_many_arguments)]
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RepositionLiquidity<'info>>,
+    new_tick_lower_index: i32,
+    new_tick_upper_index: i32,
+    liquidity_amount: u128,
+    token_max_a: u64,
+    token_max_b: u64,
+    token_min_a: u64,
+    token_min_b: u64,
+    remaining_accounts_info: Option<RemainingAccountsInfo>,
+) -> Result<()> {
+    verify_position_authority_interface(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        None,
+    )?;
+
+    require_not_paused(
+        &ctx.accounts.yevefi,
+        Some(&ctx.accounts.yevefis_config_extension.to_account_info()),
+    )?;
+    require_no_reward_extension(&ctx.accounts.yevefi)?;
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    require_position_unlocked(
+        &ctx.accounts.position.to_account_info(),
+        Some(&ctx.accounts.position_lock),
+        timestamp,
+    )?;
+
+    if liquidity_amount == 0 {
+        return Err(ErrorCode::LiquidityZero.into());
+    }
+
+    // Process remaining accounts
+    let remaining_accounts = parse_remaining_accounts(
+        ctx.remaining_accounts,
+        &remaining_accounts_info,
+        &[AccountsType::TransferHookA, AccountsType::TransferHookB],
+    )?;
+
+    update_oracle(&ctx.accounts.oracle, &ctx.accounts.yevefi, timestamp)?;
+
+    let old_tick_lower_index = ctx.accounts.position.tick_lower_index;
+    let old_tick_upper_index = ctx.accounts.position.tick_upper_index;
+    let old_liquidity = ctx.accounts.position.liquidity;
+
+    // Remove all liquidity from the current range
+    let (removed_a, removed_b) = if old_liquidity > 0 {
+        let liquidity_delta = convert_to_liquidity_delta(old_liquidity, false)?;
+
+        let update = calculate_modify_liquidity(
+            &ctx.accounts.yevefi,
+            &ctx.accounts.position,
+            &ctx.accounts.tick_array_lower,
+            &ctx.accounts.tick_array_upper,
+            liquidity_delta,
+            timestamp,
+        )?;
+
+        sync_modify_liquidity_values(
+            &mut ctx.accounts.yevefi,
+            &mut ctx.accounts.position,
+            &ctx.accounts.tick_array_lower,
+            &ctx.accounts.tick_array_upper,
+            update,
+            timestamp,
+        )?;
+
+        calculate_liquidity_token_deltas(
+            ctx.accounts.yevefi.tick_current_index,
+            ctx.accounts.yevefi.sqrt_price,
+            &ctx.accounts.position,
+            liquidity_delta,
+        )?
+    } else {
+        (0, 0)
+    };
+
+    ctx.accounts.position.reset_position_range(
+        &ctx.accounts.yevefi,
+        new_tick_lower_index,
+        new_tick_upper_index,
+    )?;
+
+    // Add liquidity to the new range
+    let liquidity_delta = convert_to_liquidity_delta(liquidity_amount, true)?;
+
+    let update = calculate_modify_liquidity(
+        &ctx.accounts.yevefi,
+        &ctx.accounts.position,
+        &ctx.accounts.new_tick_array_lower,
+        &ctx.accounts.new_tick_array_upper,
+        liquidity_delta,
+        timestamp,
+    )?;
+
+    sync_modify_liquidity_values(
+        &mut ctx.accounts.yevefi,
+        &mut ctx.accounts.position,
+        &ctx.accounts.new_tick_array_lower,
+        &ctx.accounts.new_tick_array_upper,
+        update,
+        timestamp,
+    )?;
+
+    let (added_a, added_b) = calculate_liquidity_token_deltas(
+        ctx.accounts.yevefi.tick_current_index,
+        ctx.accounts.yevefi.sqrt_price,
+        &ctx.accounts.position,
+        liquidity_delta,
+    )?;
+
+    if has_position_token_metadata(&ctx.accounts.position_mint, &ctx.accounts.position)? {
+        let position_mint_key = ctx.accounts.position_mint.key();
+        let (_, position_bump) = Pubkey::find_program_address(
+            &[b"position".as_ref(), position_mint_key.as_ref()],
+            &crate::ID,
+        );
+        let position_seeds = [
+            b"position".as_ref(),
+            position_mint_key.as_ref(),
+            &[position_bump],
+        ];
+
+        let fields =
+            build_position_token_metadata_fields(&ctx.accounts.position, &ctx.accounts.yevefi);
+
+        update_token_metadata_fields(
+            fields,
+            &ctx.accounts.position_mint,
+            &ctx.accounts.position,
+            &ctx.accounts.funder,
+            &ctx.accounts.system_program,
+            &ctx.accounts.token_2022_program,
+            &position_seeds,
+        )?;
+    }
+
+    let net_transfer_a = transfer_net_amount(
+        &ctx.accounts.yevefi,
+        &ctx.accounts.position_authority,
+        &ctx.accounts.token_mint_a,
+        &ctx.accounts.token_owner_account_a,
+        &ctx.accounts.token_vault_a,
+        &ctx.accounts.token_program_a,
+        &ctx.accounts.memo_program,
+        &remaining_accounts.transfer_hook_a,
+        added_a,
+        removed_a,
+        token_max_a,
+        token_min_a,
+    )?;
+
+    let net_transfer_b = transfer_net_amount(
+        &ctx.accounts.yevefi,
+        &ctx.accounts.position_authority,
+        &ctx.accounts.token_mint_b,
+        &ctx.accounts.token_owner_account_b,
+        &ctx.accounts.token_vault_b,
+        &ctx.accounts.token_program_b,
+        &ctx.accounts.memo_program,
+        &remaining_accounts.transfer_hook_b,
+        added_b,
+        removed_b,
+        token_max_b,
+        token_min_b,
+    )?;
+
+    emit!(LiquidityRepositioned {
+        yevefi: ctx.accounts.yevefi.key(),
+        position: ctx.accounts.position.key(),
+        old_tick_lower_index,
+        old_tick_upper_index,
+        old_liquidity,
+        new_tick_lower_index,
+        new_tick_upper_index,
+        new_liquidity: liquidity_amount,
+        token_a_deposited: net_transfer_a.deposited,
+        token_b_deposited: net_transfer_b.deposited,
+        token_a_withdrawn: net_transfer_a.withdrawn,
+        token_b_withdrawn: net_transfer_b.withdrawn,
+        token_a_transfer_fee: net_transfer_a.transfer_fee,
+        token_b_transfer_fee: net_transfer_b.transfer_fee,
+    });
+
+    Ok(())
+}
+
+struct NetTransfer {
+    deposited: u64,
+    withdrawn: u64,
+    transfer_fee: u64,
+}
+
+// Transfers the difference between the added and removed amounts of a token.
+// token_max is applied to the transfer fee included amount of a deposit,
+// token_min is applied to the transfer fee excluded amount of a withdrawal.
+#[allow(clippy::too_many_arguments)]
+fn transfer_net_amount<'info>(
+    yevefi: &Account<'info, Yevefi>,
+    position_authority: &Signer<'info>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    token_owner_account: &InterfaceAccount<'info, TokenAccount>,
+    token_vault: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    memo_program: &Program<'info, Memo>,
+    transfer_hook_accounts: &Option<Vec<AccountInfo<'info>>>,
+    added: u64,
+    removed: u64,
+    token_max: u64,
+    token_min: u64,
+) -> Result<NetTransfer> {
+    if added >= removed {
+        // nothing is withdrawn
+        if token_min > 0 {
+            return Err(ErrorCode::TokenMinSubceeded.into());
+        }
+
+        let transfer_fee_included_amount =
+            calculate_transfer_fee_included_amount(token_mint, added - removed)?;
+        if transfer_fee_included_amount.amount > token_max {
+            return Err(ErrorCode::TokenMaxExceeded.into());
+        }
+
+        transfer_from_owner_to_vault_v2(
+            position_authority,
+            token_mint,
+            token_owner_account,
+            token_vault,
+            token_program,
+            memo_program,
+            transfer_hook_accounts,
+            transfer_fee_included_amount.amount,
+        )?;
+
+        Ok(NetTransfer {
+            deposited: transfer_fee_included_amount.amount,
+            withdrawn: 0,
+            transfer_fee: transfer_fee_included_amount.transfer_fee,
+        })
+    } else {
+        let transfer_fee_excluded_amount =
+            calculate_transfer_fee_excluded_amount(token_mint, removed - added)?;
+        if transfer_fee_excluded_amount.amount < token_min {
+            return Err(ErrorCode::TokenMinSubceeded.into());
+        }
+
+        transfer_from_vault_to_owner_v2(
+            yevefi,
+            token_mint,
+            token_vault,
+            token_owner_account,
+            token_program,
+            memo_program,
+            transfer_hook_accounts,
+            removed - added,
+            transfer_memo::TRANSFER_MEMO_DECREASE_LIQUIDITY.as_bytes(),
+        )?;
+
+        Ok(NetTransfer {
+            deposited: 0,
+            withdrawn: removed - added,
+            transfer_fee: transfer_fee_excluded_amount.transfer_fee,
+        })
+    }
+}
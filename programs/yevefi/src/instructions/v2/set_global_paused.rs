@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{YevefisConfig, YevefisConfigExtension};
+
+#[derive(Accounts)]
+pub struct SetGlobalPaused<'info> {
+    pub yevefis_config: Box<Account<'info, YevefisConfig>>,
+
+    #[account(mut, has_one = yevefis_config)]
+    pub yevefis_config_extension: Account<'info, YevefisConfigExtension>,
+
+    #[account(address = yevefis_config_extension.pause_authority)]
+    pub pause_authority: Signer<'info>,
+}
+
+/// Pause or unpause all yevefis of the config. Only the pause authority has permission to invoke this instruction.
+pub fn handler(ctx: Context<SetGlobalPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.yevefis_config_extension.update_paused(paused);
+    Ok(())
+}
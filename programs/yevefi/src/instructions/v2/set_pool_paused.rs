@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Yevefi, YevefisConfig, YevefisConfigExtension};
+
+#[derive(Accounts)]
+pub struct SetPoolPaused<'info> {
+    pub yevefis_config: Box<Account<'info, YevefisConfig>>,
+
+    #[account(has_one = yevefis_config)]
+    pub yevefis_config_extension: Account<'info, YevefisConfigExtension>,
+
+    #[account(mut, has_one = yevefis_config)]
+    pub yevefi: Account<'info, Yevefi>,
+
+    #[account(address = yevefis_config_extension.pause_authority)]
+    pub pause_authority: Signer<'info>,
+}
+
+/// Pause or unpause a yevefi. Only the pause authority has permission to invoke this instruction.
+pub fn handler(ctx: Context<SetPoolPaused>, paused: bool) -> Result<()> {
+    ctx.accounts.yevefi.update_paused(paused);
+    Ok(())
+}
@@ -39,10 +39,15 @@ pub fn handler(
     let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
     let next_reward_infos = next_yevefi_reward_infos(yevefi, timestamp)?;
 
-    ctx.accounts.yevefi.update_emissions(
+    let yevefi = &mut ctx.accounts.yevefi;
+    yevefi.update_emissions(
         reward_index as usize,
         next_reward_infos,
         timestamp,
         emissions_per_second_x64,
-    )
+    )?;
+    // Rewards are accrued up to now, so the vault funds the emissions from now on
+    yevefi.sync_reward_funded(reward_index as usize, reward_vault.amount);
+
+    Ok(())
 }
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::errors::ErrorCode;
+use crate::manager::yevefi_manager::next_yevefi_reward_infos;
+use crate::math::checked_mul_shift_right;
+use crate::state::{RewardEmissionsRateChange, Yevefi, YevefiRewardSchedule};
+use crate::util::to_timestamp_u64;
+
+const DAY_IN_SECONDS: u128 = 60 * 60 * 24;
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct SetRewardEmissionsSchedule<'info> {
+    #[account(mut)]
+    pub yevefi: Account<'info, Yevefi>,
+
+    #[account(address = yevefi.reward_infos[reward_index as usize].authority)]
+    pub reward_authority: Signer<'info>,
+
+    #[account(address = yevefi.reward_infos[reward_index as usize].vault)]
+    pub reward_vault: InterfaceAccount<'info, TokenAccount>,
+}
+
+pub fn handler(
+    ctx: Context<SetRewardEmissionsSchedule>,
+    reward_index: u8,
+    emissions_per_second_x64: u128,
+    start_timestamp: u64,
+    end_timestamp: u64,
+    rate_changes: Vec<RewardEmissionsRateChange>,
+) -> Result<()> {
+    let yevefi = &ctx.accounts.yevefi;
+    let reward_vault = &ctx.accounts.reward_vault;
+
+    let reward_schedule = YevefiRewardSchedule::new(start_timestamp, end_timestamp, &rate_changes)?;
+
+    // Same requirement as set_reward_emissions, for the highest rate of the schedule
+    let max_emissions_per_second_x64 =
+        reward_schedule.max_emissions_per_second_x64(emissions_per_second_x64);
+    let emissions_per_day = checked_mul_shift_right(DAY_IN_SECONDS, max_emissions_per_second_x64)?;
+    if reward_vault.amount < emissions_per_day {
+        return Err(ErrorCode::RewardVaultAmountInsufficient.into());
+    }
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    // Rewards are accrued under the current schedule until now
+    let next_reward_infos = next_yevefi_reward_infos(yevefi, timestamp)?;
+
+    let yevefi = &mut ctx.accounts.yevefi;
+    yevefi.update_emissions_schedule(
+        reward_index as usize,
+        next_reward_infos,
+        timestamp,
+        emissions_per_second_x64,
+        reward_schedule,
+    )?;
+    // Rewards are accrued up to now, so the vault funds the emissions from now on
+    yevefi.sync_reward_funded(reward_index as usize, reward_vault.amount);
+
+    Ok(())
+}
@@ -16,10 +16,11 @@ pub struct SetTokenBadgeAuthority<'info> {
     pub new_token_badge_authority: UncheckedAccount<'info>,
 }
 
-/// Set the token badge authority. Only the config extension authority has permission to invoke this instruction.
+/// Propose a new token badge authority, which takes over once it accepts with `accept_token_badge_authority`.
+/// Only the config extension authority has permission to invoke this instruction.
 pub fn handler(ctx: Context<SetTokenBadgeAuthority>) -> Result<()> {
     ctx.accounts
         .yevefis_config_extension
-        .update_token_badge_authority(ctx.accounts.new_token_badge_authority.key());
+        .update_pending_token_badge_authority(ctx.accounts.new_token_badge_authority.key());
     Ok(())
 }
@@ -3,8 +3,9 @@ use anchor_spl::memo::Memo;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::util::{
-    calculate_transfer_fee_excluded_amount, calculate_transfer_fee_included_amount,
-    parse_remaining_accounts, AccountsType, RemainingAccountsInfo,
+    calculate_transfer_fee_excluded_amount, calculate_transfer_fee_included_amount, load_referral,
+    load_reward_extension, parse_remaining_accounts, transfer_referral_fee_v2, AccountsType,
+    RemainingAccountsInfo,
 };
 use crate::{
     constants::transfer_memo,
@@ -12,8 +13,8 @@ use crate::{
     manager::swap_manager::*,
     state::Yevefi,
     util::{
-        to_timestamp_u64, v2::update_and_swap_yevefi_v2, SparseSwapTickSequenceBuilder,
-        SwapTickSequence,
+        require_not_paused, to_timestamp_u64, update_oracle, v2::update_and_swap_yevefi_v2,
+        SparseSwapTickSequenceBuilder, SwapTickSequence,
     },
 };
 
@@ -59,16 +60,19 @@ pub struct SwapV2<'info> {
     pub tick_array_2: UncheckedAccount<'info>,
 
     #[account(mut, seeds = [b"oracle", yevefi.key().as_ref()], bump)]
-    /// CHECK: Oracle is currently unused and will be enabled on subsequent updates
+    /// CHECK: Oracle is optional, checked in the handler if initialized
     pub oracle: UncheckedAccount<'info>,
     // remaining accounts
     // - accounts for transfer hook program of token_mint_a
     // - accounts for transfer hook program of token_mint_b
     // - supplemental TickArray accounts
+    // - referral accounts (optional)
+    // - reward extension accounts (required if the reward extension is initialized)
+    // - config extension of yevefis_config (required, may be uninitialized)
 }
 
 pub fn handler<'info>(
-    ctx: Context<'_, '_, '_, 'info, SwapV2<'info>>,
+    ctx: Context<'_, '_, 'info, 'info, SwapV2<'info>>,
     amount: u64,
     other_amount_threshold: u64,
     sqrt_price_limit: u128,
@@ -77,9 +81,6 @@ pub fn handler<'info>(
     remaining_accounts_info: Option<RemainingAccountsInfo>,
 ) -> Result<()> {
     let yevefi = &mut ctx.accounts.yevefi;
-    let clock = Clock::get()?;
-    // Update the global reward growth which increases as a function of time.
-    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
 
     // Process remaining accounts
     let remaining_accounts = parse_remaining_accounts(
@@ -89,9 +90,41 @@ pub fn handler<'info>(
             AccountsType::TransferHookA,
             AccountsType::TransferHookB,
             AccountsType::SupplementalTickArrays,
+            AccountsType::Referral,
+            AccountsType::RewardExtension,
+            AccountsType::ConfigExtension,
         ],
     )?;
 
+    let config_extension = remaining_accounts
+        .config_extension
+        .as_ref()
+        .and_then(|accounts| accounts.first());
+    require_not_paused(yevefi, config_extension)?;
+
+    let clock = Clock::get()?;
+    // Update the global reward growth which increases as a function of time.
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    update_oracle(&ctx.accounts.oracle, yevefi, timestamp)?;
+
+    let reward_extension = load_reward_extension(
+        yevefi,
+        yevefi.key(),
+        &remaining_accounts.reward_extension,
+        timestamp,
+    )?;
+
+    let referral = load_referral(
+        ctx.remaining_accounts,
+        &remaining_accounts.referral,
+        yevefi.yevefis_config,
+        &[yevefi.input_token_mint(a_to_b)],
+    )?;
+    let referral_fee_rate = referral
+        .as_ref()
+        .map_or(0, |referral| referral.referral_fee_rate);
+
     let builder = SparseSwapTickSequenceBuilder::try_from(
         yevefi,
         a_to_b,
@@ -114,6 +147,7 @@ pub fn handler<'info>(
         amount_specified_is_input,
         a_to_b,
         timestamp,
+        referral_fee_rate,
     )?;
 
     if amount_specified_is_input {
@@ -144,6 +178,9 @@ pub fn handler<'info>(
         }
     }
 
+    let referral_fee = swap_update.next_referral_fee;
+    let tick_current_index_before = yevefi.tick_current_index;
+
     update_and_swap_yevefi_v2(
         yevefi,
         &ctx.accounts.token_authority,
@@ -162,7 +199,46 @@ pub fn handler<'info>(
         a_to_b,
         timestamp,
         transfer_memo::TRANSFER_MEMO_SWAP.as_bytes(),
-    )
+    )?;
+
+    if let Some(reward_extension) = reward_extension {
+        reward_extension.cross_ticks(
+            yevefi,
+            tick_current_index_before,
+            yevefi.tick_current_index,
+        )?;
+        reward_extension.exit()?;
+    }
+
+    if let Some(referral) = referral {
+        let (token_mint, token_vault, token_program, transfer_hook_accounts) = if a_to_b {
+            (
+                &ctx.accounts.token_mint_a,
+                &ctx.accounts.token_vault_a,
+                &ctx.accounts.token_program_a,
+                &remaining_accounts.transfer_hook_a,
+            )
+        } else {
+            (
+                &ctx.accounts.token_mint_b,
+                &ctx.accounts.token_vault_b,
+                &ctx.accounts.token_program_b,
+                &remaining_accounts.transfer_hook_b,
+            )
+        };
+        transfer_referral_fee_v2(
+            &ctx.accounts.yevefi,
+            token_mint,
+            token_vault,
+            &referral.referral_token_accounts[0],
+            token_program,
+            &ctx.accounts.memo_program,
+            transfer_hook_accounts,
+            referral_fee,
+        )?;
+    }
+
+    Ok(())
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -176,6 +252,7 @@ pub fn swap_with_transfer_fee_extension<'info>(
     amount_specified_is_input: bool,
     a_to_b: bool,
     timestamp: u64,
+    referral_fee_rate: u16,
 ) -> Result<PostSwapUpdate> {
     let (input_token_mint, output_token_mint) = if a_to_b {
         (token_mint_a, token_mint_b)
@@ -190,7 +267,7 @@ pub fn swap_with_transfer_fee_extension<'info>(
             calculate_transfer_fee_excluded_amount(input_token_mint, transfer_fee_included_input)?
                 .amount;
 
-        let swap_update = swap(
+        let swap_update = swap_with_referral(
             yevefi,
             swap_tick_sequence,
             transfer_fee_excluded_input,
@@ -198,6 +275,7 @@ pub fn swap_with_transfer_fee_extension<'info>(
             amount_specified_is_input,
             a_to_b,
             timestamp,
+            referral_fee_rate,
         )?;
 
         let (swap_update_amount_input, swap_update_amount_output) = if a_to_b {
@@ -237,6 +315,11 @@ pub fn swap_with_transfer_fee_extension<'info>(
             next_fee_growth_global: swap_update.next_fee_growth_global,
             next_reward_infos: swap_update.next_reward_infos,
             next_protocol_fee: swap_update.next_protocol_fee,
+            next_referral_fee: swap_update.next_referral_fee,
+            next_lp_fee: swap_update.next_lp_fee,
+            next_adaptive_fee_info: swap_update.next_adaptive_fee_info,
+            ticks_crossed: swap_update.ticks_crossed,
+            tick_arrays_touched: swap_update.tick_arrays_touched,
         });
     }
 
@@ -246,7 +329,7 @@ pub fn swap_with_transfer_fee_extension<'info>(
         calculate_transfer_fee_included_amount(output_token_mint, transfer_fee_excluded_output)?
             .amount;
 
-    let swap_update = swap(
+    let swap_update = swap_with_referral(
         yevefi,
         swap_tick_sequence,
         transfer_fee_included_output,
@@ -254,6 +337,7 @@ pub fn swap_with_transfer_fee_extension<'info>(
         amount_specified_is_input,
         a_to_b,
         timestamp,
+        referral_fee_rate,
     )?;
 
     let (swap_update_amount_input, swap_update_amount_output) = if a_to_b {
@@ -287,5 +371,10 @@ pub fn swap_with_transfer_fee_extension<'info>(
         next_fee_growth_global: swap_update.next_fee_growth_global,
         next_reward_infos: swap_update.next_reward_infos,
         next_protocol_fee: swap_update.next_protocol_fee,
+        next_referral_fee: swap_update.next_referral_fee,
+        next_lp_fee: swap_update.next_lp_fee,
+        next_adaptive_fee_info: swap_update.next_adaptive_fee_info,
+        ticks_crossed: swap_update.ticks_crossed,
+        tick_arrays_touched: swap_update.tick_arrays_touched,
     })
 }
@@ -4,14 +4,18 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::swap_with_transfer_fee_extension;
 use crate::util::{
-    calculate_transfer_fee_excluded_amount, parse_remaining_accounts,
-    update_and_two_hop_swap_yevefi_v2, AccountsType, RemainingAccountsInfo,
+    calculate_transfer_fee_excluded_amount, load_referral, parse_remaining_accounts,
+    transfer_referral_fee_v2, update_and_two_hop_swap_yevefi_v2, AccountsType,
+    RemainingAccountsInfo,
 };
 use crate::{
     constants::transfer_memo,
     errors::ErrorCode,
     state::Yevefi,
-    util::{to_timestamp_u64, SparseSwapTickSequenceBuilder},
+    util::{
+        require_no_reward_extension, require_not_paused, to_timestamp_u64, update_oracle,
+        SparseSwapTickSequenceBuilder,
+    },
 };
 
 #[derive(Accounts)]
@@ -83,11 +87,11 @@ pub struct TwoHopSwapV2<'info> {
     pub tick_array_two_2: UncheckedAccount<'info>,
 
     #[account(mut, seeds = [b"oracle", yevefi_one.key().as_ref()], bump)]
-    /// CHECK: Oracle is currently unused and will be enabled on subsequent updates
+    /// CHECK: Oracle is optional, checked in the handler if initialized
     pub oracle_one: UncheckedAccount<'info>,
 
     #[account(mut, seeds = [b"oracle", yevefi_two.key().as_ref()], bump)]
-    /// CHECK: Oracle is currently unused and will be enabled on subsequent updates
+    /// CHECK: Oracle is optional, checked in the handler if initialized
     pub oracle_two: UncheckedAccount<'info>,
 
     pub memo_program: Program<'info, Memo>,
@@ -97,11 +101,14 @@ pub struct TwoHopSwapV2<'info> {
     // - accounts for transfer hook program of token_mint_output
     // - supplemental TickArray accounts for yevefi_one
     // - supplemental TickArray accounts for yevefi_two
+    // - referral accounts (optional)
+    // - config extensions of the configs of yevefi_one and yevefi_two, in order
+    //   (required, may be uninitialized)
 }
 
 #[allow(clippy::too_many_arguments)]
 pub fn handler<'info>(
-    ctx: Context<'_, '_, '_, 'info, TwoHopSwapV2<'info>>,
+    ctx: Context<'_, '_, 'info, 'info, TwoHopSwapV2<'info>>,
     amount: u64,
     other_amount_threshold: u64,
     amount_specified_is_input: bool,
@@ -123,6 +130,30 @@ pub fn handler<'info>(
         return Err(ErrorCode::DuplicateTwoHopPool.into());
     }
 
+    // Process remaining accounts
+    let remaining_accounts = parse_remaining_accounts(
+        ctx.remaining_accounts,
+        &remaining_accounts_info,
+        &[
+            AccountsType::TransferHookInput,
+            AccountsType::TransferHookIntermediate,
+            AccountsType::TransferHookOutput,
+            AccountsType::SupplementalTickArraysOne,
+            AccountsType::SupplementalTickArraysTwo,
+            AccountsType::Referral,
+            AccountsType::ConfigExtension,
+        ],
+    )?;
+
+    let config_extensions = remaining_accounts
+        .config_extension
+        .as_deref()
+        .unwrap_or_default();
+    require_not_paused(yevefi_one, config_extensions.first())?;
+    require_no_reward_extension(yevefi_one)?;
+    require_not_paused(yevefi_two, config_extensions.get(1))?;
+    require_no_reward_extension(yevefi_two)?;
+
     let swap_one_output_mint = if a_to_b_one {
         yevefi_one.token_mint_b
     } else {
@@ -138,18 +169,27 @@ pub fn handler<'info>(
         return Err(ErrorCode::InvalidIntermediaryMint.into());
     }
 
-    // Process remaining accounts
-    let remaining_accounts = parse_remaining_accounts(
+    update_oracle(&ctx.accounts.oracle_one, yevefi_one, timestamp)?;
+    update_oracle(&ctx.accounts.oracle_two, yevefi_two, timestamp)?;
+
+    // The referrer is paid from the LP fee of both hops, so both yevefis must share the config
+    if remaining_accounts.referral.is_some()
+        && yevefi_one.yevefis_config != yevefi_two.yevefis_config
+    {
+        return Err(ErrorCode::InvalidReferralAccounts.into());
+    }
+    let referral = load_referral(
         ctx.remaining_accounts,
-        &remaining_accounts_info,
+        &remaining_accounts.referral,
+        yevefi_one.yevefis_config,
         &[
-            AccountsType::TransferHookInput,
-            AccountsType::TransferHookIntermediate,
-            AccountsType::TransferHookOutput,
-            AccountsType::SupplementalTickArraysOne,
-            AccountsType::SupplementalTickArraysTwo,
+            ctx.accounts.token_mint_input.key(),
+            ctx.accounts.token_mint_intermediate.key(),
         ],
     )?;
+    let referral_fee_rate = referral
+        .as_ref()
+        .map_or(0, |referral| referral.referral_fee_rate);
 
     let builder_one = SparseSwapTickSequenceBuilder::try_from(
         yevefi_one,
@@ -199,6 +239,7 @@ pub fn handler<'info>(
             amount_specified_is_input, // true
             a_to_b_one,
             timestamp,
+            referral_fee_rate,
         )?;
 
         // Swap two input is the output of swap one
@@ -227,6 +268,7 @@ pub fn handler<'info>(
             amount_specified_is_input, // true
             a_to_b_two,
             timestamp,
+            referral_fee_rate,
         )?;
         (swap_calc_one, swap_calc_two)
     } else {
@@ -251,6 +293,7 @@ pub fn handler<'info>(
             amount_specified_is_input, // false
             a_to_b_two,
             timestamp,
+            referral_fee_rate,
         )?;
 
         // The output of swap 1 is input of swap_calc_two
@@ -286,6 +329,7 @@ pub fn handler<'info>(
             amount_specified_is_input, // false
             a_to_b_one,
             timestamp,
+            referral_fee_rate,
         )?;
         (swap_calc_one, swap_calc_two)
     };
@@ -381,6 +425,9 @@ pub fn handler<'info>(
     )
     */
 
+    let referral_fee_one = swap_update_one.next_referral_fee;
+    let referral_fee_two = swap_update_two.next_referral_fee;
+
     update_and_two_hop_swap_yevefi_v2(
         swap_update_one,
         swap_update_two,
@@ -407,5 +454,30 @@ pub fn handler<'info>(
         &ctx.accounts.memo_program,
         timestamp,
         transfer_memo::TRANSFER_MEMO_SWAP.as_bytes(),
-    )
+    )?;
+
+    if let Some(referral) = referral {
+        transfer_referral_fee_v2(
+            &ctx.accounts.yevefi_one,
+            &ctx.accounts.token_mint_input,
+            &ctx.accounts.token_vault_one_input,
+            &referral.referral_token_accounts[0],
+            &ctx.accounts.token_program_input,
+            &ctx.accounts.memo_program,
+            &remaining_accounts.transfer_hook_input,
+            referral_fee_one,
+        )?;
+        transfer_referral_fee_v2(
+            &ctx.accounts.yevefi_two,
+            &ctx.accounts.token_mint_intermediate,
+            &ctx.accounts.token_vault_two_intermediate,
+            &referral.referral_token_accounts[1],
+            &ctx.accounts.token_program_intermediate,
+            &ctx.accounts.memo_program,
+            &remaining_accounts.transfer_hook_intermediate,
+            referral_fee_two,
+        )?;
+    }
+
+    Ok(())
 }
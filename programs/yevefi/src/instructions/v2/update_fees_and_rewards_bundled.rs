@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::manager::liquidity_manager::calculate_fee_and_reward_growths;
+use crate::state::*;
+use crate::util::{
+    load_bundled_positions, parse_remaining_accounts, to_timestamp_u64, AccountsType,
+    RemainingAccountsInfo,
+};
+
+#[derive(Accounts)]
+pub struct UpdateFeesAndRewardsBundled<'info> {
+    #[account(mut)]
+    pub yevefi: Account<'info, Yevefi>,
+
+    pub position_bundle: Box<Account<'info, PositionBundle>>,
+    // remaining accounts
+    // - bundled positions at bundle_indexes, in order
+    // - tick_array_lower and tick_array_upper of each bundled position, in order
+}
+
+/*
+  Updates the fees and rewards owed to the bundled positions at bundle_indexes.
+  Positions without liquidity are skipped, their growths were settled when liquidity was removed.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateFeesAndRewardsBundled<'info>>,
+    bundle_indexes: Vec<u16>,
+    bundled_position_bumps: Vec<u8>,
+    remaining_accounts_info: Option<RemainingAccountsInfo>,
+) -> Result<()> {
+    // Process remaining accounts
+    let remaining_accounts = parse_remaining_accounts(
+        ctx.remaining_accounts,
+        &remaining_accounts_info,
+        &[
+            AccountsType::BundledPositions,
+            AccountsType::BundledPositionTickArrays,
+        ],
+    )?;
+
+    let mut bundled_positions = load_bundled_positions(
+        ctx.remaining_accounts,
+        &remaining_accounts.bundled_positions,
+        &ctx.accounts.position_bundle,
+        ctx.accounts.yevefi.key(),
+        &bundle_indexes,
+        &bundled_position_bumps,
+    )?;
+
+    let tick_array_accounts = remaining_accounts
+        .bundled_position_tick_arrays
+        .unwrap_or_default();
+    if tick_array_accounts.len() != bundled_positions.len() * 2 {
+        return Err(ErrorCode::InvalidBundledPositionAccounts.into());
+    }
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    for (bundled_position, tick_arrays) in bundled_positions
+        .iter_mut()
+        .zip(tick_array_accounts.chunks(2))
+    {
+        if bundled_position.liquidity == 0 {
+            continue;
+        }
+
+        let tick_array_lower = load_tick_array(
+            ctx.remaining_accounts,
+            &tick_arrays[0],
+            ctx.accounts.yevefi.key(),
+        )?;
+        let tick_array_upper = load_tick_array(
+            ctx.remaining_accounts,
+            &tick_arrays[1],
+            ctx.accounts.yevefi.key(),
+        )?;
+
+        let (position_update, reward_infos) = calculate_fee_and_reward_growths(
+            &ctx.accounts.yevefi,
+            bundled_position,
+            &tick_array_lower,
+            &tick_array_upper,
+            timestamp,
+        )?;
+
+        ctx.accounts.yevefi.update_rewards(reward_infos, timestamp);
+        bundled_position.update(&position_update);
+        bundled_position.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+fn load_tick_array<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    tick_array_account: &AccountInfo<'info>,
+    yevefi: Pubkey,
+) -> Result<AccountLoader<'info, TickArray>> {
+    let account_info = remaining_accounts
+        .iter()
+        .find(|account| account.key == tick_array_account.key)
+        .ok_or(ErrorCode::InvalidBundledPositionAccounts)?;
+
+    let tick_array = AccountLoader::<TickArray>::try_from(account_info)?;
+    if tick_array.load()?.yevefi != yevefi {
+        return Err(ErrorCode::InvalidBundledPositionAccounts.into());
+    }
+
+    Ok(tick_array)
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::util::{parse_remaining_accounts, AccountsType, RemainingAccountsInfo};
+use crate::{
+    constants::transfer_memo,
+    errors::ErrorCode,
+    events::RewardSurplusWithdrawn,
+    manager::yevefi_manager::next_yevefi_reward_infos,
+    state::Yevefi,
+    util::{to_timestamp_u64, v2::transfer_from_vault_to_owner_v2},
+};
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8)]
+pub struct WithdrawRewardSurplus<'info> {
+    #[account(mut)]
+    pub yevefi: Box<Account<'info, Yevefi>>,
+
+    #[account(address = yevefi.reward_infos[reward_index as usize].authority)]
+    pub reward_authority: Signer<'info>,
+
+    #[account(address = yevefi.reward_infos[reward_index as usize].mint)]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, address = yevefi.reward_infos[reward_index as usize].vault)]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, constraint = destination_token_account.mint == reward_mint.key())]
+    pub destination_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = *reward_mint.to_account_info().owner)]
+    pub reward_token_program: Interface<'info, TokenInterface>,
+    pub memo_program: Program<'info, Memo>,
+    // remaining accounts
+    // - accounts for transfer hook program of reward_mint
+}
+
+/*
+  Withdraws the tokens of a reward vault which are not owed to positions.
+  Rewards are accrued up to the current timestamp first, so the emitted but uncollected rewards
+  stay in the vault.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawRewardSurplus<'info>>,
+    reward_index: u8,
+    amount: u64,
+    remaining_accounts_info: Option<RemainingAccountsInfo>,
+) -> Result<()> {
+    // Process remaining accounts
+    let remaining_accounts = parse_remaining_accounts(
+        ctx.remaining_accounts,
+        &remaining_accounts_info,
+        &[AccountsType::TransferHookReward],
+    )?;
+
+    let index = reward_index as usize;
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+    let next_reward_infos = next_yevefi_reward_infos(&ctx.accounts.yevefi, timestamp)?;
+    ctx.accounts
+        .yevefi
+        .update_rewards(next_reward_infos, timestamp);
+    // Tokens transferred into the vault without fund_reward are surplus as well
+    ctx.accounts
+        .yevefi
+        .sync_reward_funded(index, ctx.accounts.reward_vault.amount);
+
+    let surplus = ctx
+        .accounts
+        .yevefi
+        .reward_surplus(index, ctx.accounts.reward_vault.amount);
+    if amount > surplus {
+        return Err(ErrorCode::RewardSurplusInsufficient.into());
+    }
+    ctx.accounts.yevefi.record_reward_withdrawn(index, amount);
+
+    transfer_from_vault_to_owner_v2(
+        &ctx.accounts.yevefi,
+        &ctx.accounts.reward_mint,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.destination_token_account,
+        &ctx.accounts.reward_token_program,
+        &ctx.accounts.memo_program,
+        &remaining_accounts.transfer_hook_reward,
+        amount,
+        transfer_memo::TRANSFER_MEMO_WITHDRAW_REWARD_SURPLUS.as_bytes(),
+    )?;
+
+    emit!(RewardSurplusWithdrawn {
+        yevefi: ctx.accounts.yevefi.key(),
+        reward_index,
+        reward_mint: ctx.accounts.reward_mint.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,779 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::transfer_memo;
+use crate::errors::ErrorCode;
+use crate::events::LiquidityIncreased;
+use crate::manager::liquidity_manager::{
+    calculate_liquidity_token_deltas, calculate_modify_liquidity, sync_modify_liquidity_values,
+};
+use crate::manager::swap_manager::{swap, PostSwapUpdate};
+use crate::math::{
+    convert_to_liquidity_delta, get_liquidity_bounds_from_amounts, get_liquidity_from_amounts,
+    mul_u256, U256Muldiv, NO_EXPLICIT_SQRT_PRICE_LIMIT,
+};
+use crate::state::*;
+use crate::util::{
+    calculate_transfer_fee_excluded_amount, calculate_transfer_fee_included_amount,
+    parse_remaining_accounts, AccountsType, RemainingAccountsInfo,
+};
+use crate::util::{
+    require_no_reward_extension, require_not_paused, to_timestamp_u64, update_oracle,
+    v2::{
+        transfer_from_owner_to_vault_v2, transfer_from_vault_to_owner_v2,
+        update_yevefi_and_emit_traded,
+    },
+    verify_position_authority_interface, SparseSwapTickSequenceBuilder,
+};
+
+// Maximum number of simulated swaps used to search for the split of the input token.
+// zap_in executes one more swap, so it runs at most ZAP_IN_MAX_SIMULATED_SWAPS + 1 swaps.
+pub const ZAP_IN_MAX_SIMULATED_SWAPS: usize = 5;
+
+#[derive(Accounts)]
+pub struct ZapIn<'info> {
+    #[account(mut)]
+    pub yevefi: Box<Account<'info, Yevefi>>,
+
+    #[account(address = *token_mint_a.to_account_info().owner)]
+    pub token_program_a: Interface<'info, TokenInterface>,
+    #[account(address = *token_mint_b.to_account_info().owner)]
+    pub token_program_b: Interface<'info, TokenInterface>,
+
+    pub memo_program: Program<'info, Memo>,
+
+    pub position_authority: Signer<'info>,
+
+    #[account(mut, has_one = yevefi)]
+    pub position: Box<Account<'info, Position>>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = yevefi.token_mint_a)]
+    pub token_mint_a: InterfaceAccount<'info, Mint>,
+    #[account(address = yevefi.token_mint_b)]
+    pub token_mint_b: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, constraint = token_owner_account_a.mint == yevefi.token_mint_a)]
+    pub token_owner_account_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, constraint = token_owner_account_b.mint == yevefi.token_mint_b)]
+    pub token_owner_account_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, address = yevefi.token_vault_a)]
+    pub token_vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, address = yevefi.token_vault_b)]
+    pub token_vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // tick arrays of the position
+    #[account(mut, has_one = yevefi)]
+    pub tick_array_lower: AccountLoader<'info, TickArray>,
+    #[account(mut, has_one = yevefi)]
+    pub tick_array_upper: AccountLoader<'info, TickArray>,
+
+    // tick arrays of the swap
+    #[account(mut)]
+    /// CHECK: checked in the handler
+    pub tick_array_0: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: checked in the handler
+    pub tick_array_1: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: checked in the handler
+    pub tick_array_2: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [b"oracle", yevefi.key().as_ref()], bump)]
+    /// CHECK: Oracle is optional, checked in the handler if initialized
+    pub oracle: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"config_extension", yevefi.yevefis_config.as_ref()], bump)]
+    /// CHECK: Config extension may be uninitialized, checked in the handler
+    pub yevefis_config_extension: UncheckedAccount<'info>,
+    // remaining accounts
+    // - accounts for transfer hook program of token_mint_a
+    // - accounts for transfer hook program of token_mint_b
+    // - supplemental TickArray accounts
+}
+
+/*
+  Deposits a single token into a position. Part of the input token is swapped in the same yevefi,
+  and the remainder and the swap output are added as liquidity at the post-swap price.
+  The swap output never leaves the vault, only the part that is not deposited is returned.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ZapIn<'info>>,
+    amount: u64,
+    a_to_b: bool,
+    min_liquidity: u128,
+    remaining_accounts_info: Option<RemainingAccountsInfo>,
+) -> Result<()> {
+    verify_position_authority_interface(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        None,
+    )?;
+
+    require_not_paused(
+        &ctx.accounts.yevefi,
+        Some(&ctx.accounts.yevefis_config_extension.to_account_info()),
+    )?;
+    require_no_reward_extension(&ctx.accounts.yevefi)?;
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    if amount == 0 {
+        return Err(ErrorCode::ZeroTradableAmount.into());
+    }
+
+    // Process remaining accounts
+    let remaining_accounts = parse_remaining_accounts(
+        ctx.remaining_accounts,
+        &remaining_accounts_info,
+        &[
+            AccountsType::TransferHookA,
+            AccountsType::TransferHookB,
+            AccountsType::SupplementalTickArrays,
+        ],
+    )?;
+
+    // The vault only receives the transfer fee excluded amount of the input token
+    let budget = if a_to_b {
+        calculate_transfer_fee_excluded_amount(&ctx.accounts.token_mint_a, amount)?.amount
+    } else {
+        calculate_transfer_fee_excluded_amount(&ctx.accounts.token_mint_b, amount)?.amount
+    };
+
+    let tick_array_account_infos = vec![
+        ctx.accounts.tick_array_0.to_account_info(),
+        ctx.accounts.tick_array_1.to_account_info(),
+        ctx.accounts.tick_array_2.to_account_info(),
+    ];
+
+    let swap_amount = {
+        let builder = SparseSwapTickSequenceBuilder::try_from_read_only(
+            &ctx.accounts.yevefi,
+            a_to_b,
+            tick_array_account_infos.clone(),
+            remaining_accounts.supplemental_tick_arrays.clone(),
+        )?;
+        find_zap_in_swap_amount(
+            &ctx.accounts.yevefi,
+            &builder,
+            &ctx.accounts.position,
+            budget,
+            a_to_b,
+            timestamp,
+        )?
+    };
+
+    // The observation precedes both the swap and the liquidity change
+    update_oracle(&ctx.accounts.oracle, &ctx.accounts.yevefi, timestamp)?;
+
+    let (swap_input, swap_output) = if swap_amount > 0 {
+        let swap_update = {
+            let builder = SparseSwapTickSequenceBuilder::try_from(
+                &ctx.accounts.yevefi,
+                a_to_b,
+                tick_array_account_infos,
+                remaining_accounts.supplemental_tick_arrays,
+            )?;
+            let mut swap_tick_sequence = builder.build()?;
+            swap(
+                &ctx.accounts.yevefi,
+                &mut swap_tick_sequence,
+                swap_amount,
+                NO_EXPLICIT_SQRT_PRICE_LIMIT,
+                true,
+                a_to_b,
+                timestamp,
+            )?
+        };
+
+        update_yevefi_and_emit_traded(
+            &mut ctx.accounts.yevefi,
+            &ctx.accounts.token_mint_a,
+            &ctx.accounts.token_mint_b,
+            &swap_update,
+            a_to_b,
+            timestamp,
+        )?;
+
+        swap_input_output(&swap_update, a_to_b)
+    } else {
+        (0, 0)
+    };
+
+    let (amount_a, amount_b) = input_output_to_a_b(budget - swap_input, swap_output, a_to_b);
+    let liquidity_amount = get_liquidity_from_amounts(
+        ctx.accounts.yevefi.tick_current_index,
+        ctx.accounts.yevefi.sqrt_price,
+        ctx.accounts.position.tick_lower_index,
+        ctx.accounts.position.tick_upper_index,
+        amount_a,
+        amount_b,
+    )?;
+
+    if liquidity_amount == 0 {
+        return Err(ErrorCode::LiquidityZero.into());
+    }
+    if liquidity_amount < min_liquidity {
+        return Err(ErrorCode::LiquidityBelowMinimum.into());
+    }
+
+    let liquidity_delta = convert_to_liquidity_delta(liquidity_amount, true)?;
+
+    let update = calculate_modify_liquidity(
+        &ctx.accounts.yevefi,
+        &ctx.accounts.position,
+        &ctx.accounts.tick_array_lower,
+        &ctx.accounts.tick_array_upper,
+        liquidity_delta,
+        timestamp,
+    )?;
+
+    sync_modify_liquidity_values(
+        &mut ctx.accounts.yevefi,
+        &mut ctx.accounts.position,
+        &ctx.accounts.tick_array_lower,
+        &ctx.accounts.tick_array_upper,
+        update,
+        timestamp,
+    )?;
+
+    let (delta_a, delta_b) = calculate_liquidity_token_deltas(
+        ctx.accounts.yevefi.tick_current_index,
+        ctx.accounts.yevefi.sqrt_price,
+        &ctx.accounts.position,
+        liquidity_delta,
+    )?;
+
+    let (delta_input, delta_output) = input_output_to_a_b(delta_a, delta_b, a_to_b);
+    let refund = swap_output
+        .checked_sub(delta_output)
+        .ok_or(ErrorCode::TokenMaxExceeded)?;
+
+    // The swap input and the deposit of the input token are transferred at once
+    let (
+        input_token_mint,
+        input_token_owner_account,
+        input_token_vault,
+        input_token_program,
+        input_transfer_hook_accounts,
+    ) = if a_to_b {
+        (
+            &ctx.accounts.token_mint_a,
+            &ctx.accounts.token_owner_account_a,
+            &ctx.accounts.token_vault_a,
+            &ctx.accounts.token_program_a,
+            &remaining_accounts.transfer_hook_a,
+        )
+    } else {
+        (
+            &ctx.accounts.token_mint_b,
+            &ctx.accounts.token_owner_account_b,
+            &ctx.accounts.token_vault_b,
+            &ctx.accounts.token_program_b,
+            &remaining_accounts.transfer_hook_b,
+        )
+    };
+
+    let transfer_fee_included_input = calculate_transfer_fee_included_amount(
+        input_token_mint,
+        swap_input
+            .checked_add(delta_input)
+            .ok_or(ErrorCode::TokenMaxExceeded)?,
+    )?;
+    if transfer_fee_included_input.amount > amount {
+        return Err(ErrorCode::TokenMaxExceeded.into());
+    }
+
+    transfer_from_owner_to_vault_v2(
+        &ctx.accounts.position_authority,
+        input_token_mint,
+        input_token_owner_account,
+        input_token_vault,
+        input_token_program,
+        &ctx.accounts.memo_program,
+        input_transfer_hook_accounts,
+        transfer_fee_included_input.amount,
+    )?;
+
+    if refund > 0 {
+        let (
+            output_token_mint,
+            output_token_vault,
+            output_token_owner_account,
+            output_token_program,
+            output_transfer_hook_accounts,
+        ) = if a_to_b {
+            (
+                &ctx.accounts.token_mint_b,
+                &ctx.accounts.token_vault_b,
+                &ctx.accounts.token_owner_account_b,
+                &ctx.accounts.token_program_b,
+                &remaining_accounts.transfer_hook_b,
+            )
+        } else {
+            (
+                &ctx.accounts.token_mint_a,
+                &ctx.accounts.token_vault_a,
+                &ctx.accounts.token_owner_account_a,
+                &ctx.accounts.token_program_a,
+                &remaining_accounts.transfer_hook_a,
+            )
+        };
+
+        transfer_from_vault_to_owner_v2(
+            &ctx.accounts.yevefi,
+            output_token_mint,
+            output_token_vault,
+            output_token_owner_account,
+            output_token_program,
+            &ctx.accounts.memo_program,
+            output_transfer_hook_accounts,
+            refund,
+            transfer_memo::TRANSFER_MEMO_SWAP.as_bytes(),
+        )?;
+    }
+
+    let (token_a_transfer_fee, token_b_transfer_fee) =
+        input_output_to_a_b(transfer_fee_included_input.transfer_fee, 0, a_to_b);
+
+    emit!(LiquidityIncreased {
+        yevefi: ctx.accounts.yevefi.key(),
+        position: ctx.accounts.position.key(),
+        tick_lower_index: ctx.accounts.position.tick_lower_index,
+        tick_upper_index: ctx.accounts.position.tick_upper_index,
+        liquidity: liquidity_amount,
+        token_a_amount: delta_a,
+        token_b_amount: delta_b,
+        token_a_transfer_fee,
+        token_b_transfer_fee,
+    });
+
+    Ok(())
+}
+
+// Finds the amount of the input token to swap, so that the remainder of the budget and the swap
+// output fund the most liquidity in the position's range at the post-swap price.
+fn find_zap_in_swap_amount(
+    yevefi: &Yevefi,
+    builder: &SparseSwapTickSequenceBuilder<'_>,
+    position: &Position,
+    budget: u64,
+    a_to_b: bool,
+    timestamp: u64,
+) -> Result<u64> {
+    let zero_impact_output_bound = get_zero_impact_output_bound(yevefi, position, budget, a_to_b)?;
+    search_zap_in_swap_amount(budget, zero_impact_output_bound, |swap_amount| {
+        simulate_zap_in(
+            yevefi,
+            builder,
+            position,
+            budget,
+            swap_amount,
+            a_to_b,
+            timestamp,
+        )
+    })
+}
+
+// Swapping more lowers the liquidity the input token can fund and raises the liquidity the output
+// token can fund, so the best swap amount is where the two bounds cross.
+// Each step estimates the crossing in closed form, assuming that the input bound falls linearly
+// to zero at the whole budget and that the output bound grows in proportion to the swap amount
+// from the last simulated swap. The first step assumes the swap does not move the price.
+// Estimates outside of the bracket of the crossing fall back to bisection. The search simulates
+// at most ZAP_IN_MAX_SIMULATED_SWAPS swaps to bound the compute units of zap_in, and returns the
+// best swap amount it has simulated.
+fn search_zap_in_swap_amount(
+    budget: u64,
+    zero_impact_output_bound: u128,
+    mut simulate: impl FnMut(u64) -> Result<Option<(u128, u128)>>,
+) -> Result<u64> {
+    // No swap is simulated for a swap amount of 0
+    let (input_bound, output_bound) = match simulate(0)? {
+        Some(bounds) => bounds,
+        None => return Ok(0),
+    };
+    if input_bound <= output_bound {
+        // the input token is the binding bound, swapping would only lower the liquidity
+        return Ok(0);
+    }
+
+    let mut best_swap_amount = 0;
+    let mut best_liquidity = output_bound;
+
+    // The crossing is above lower and at or below upper.
+    // upper is only known to be above the crossing once it has been simulated.
+    let mut lower = 0;
+    let mut upper = budget;
+    let mut upper_simulated = false;
+    let mut swap_amount = split_budget(
+        budget,
+        U256Muldiv::new(0, input_bound),
+        U256Muldiv::new(0, zero_impact_output_bound),
+    )?;
+
+    for _ in 0..ZAP_IN_MAX_SIMULATED_SWAPS {
+        let max_swap_amount = if upper_simulated { upper - 1 } else { upper };
+        if lower >= max_swap_amount {
+            break;
+        }
+        if swap_amount <= lower || swap_amount > max_swap_amount {
+            swap_amount = lower + (max_swap_amount - lower).div_ceil(2);
+        }
+
+        match simulate(swap_amount)? {
+            Some((input_bound, output_bound)) => {
+                let liquidity = input_bound.min(output_bound);
+                if liquidity > best_liquidity {
+                    best_liquidity = liquidity;
+                    best_swap_amount = swap_amount;
+                }
+
+                if input_bound > output_bound {
+                    lower = swap_amount;
+                } else {
+                    upper = swap_amount;
+                    upper_simulated = true;
+                }
+
+                swap_amount = split_budget(
+                    budget,
+                    mul_u256(input_bound, u128::from(swap_amount)),
+                    mul_u256(output_bound, u128::from(budget - swap_amount)),
+                )?;
+            }
+            // Not fillable with the supplied tick arrays
+            None => {
+                upper = swap_amount;
+                upper_simulated = true;
+            }
+        }
+    }
+
+    Ok(best_swap_amount)
+}
+
+// Returns the liquidity the output token could fund if the whole budget was swapped at the
+// current price without a fee.
+fn get_zero_impact_output_bound(
+    yevefi: &Yevefi,
+    position: &Position,
+    budget: u64,
+    a_to_b: bool,
+) -> Result<u128> {
+    let price_x128 = mul_u256(yevefi.sqrt_price, yevefi.sqrt_price);
+    let output = if a_to_b {
+        mul_u256(
+            u128::from(budget),
+            price_x128.shift_right(64).try_into_u128()?,
+        )
+        .shift_right(64)
+    } else {
+        U256Muldiv::new(0, u128::from(budget))
+            .shift_left(128)
+            .div(price_x128, false)
+            .0
+    };
+    let output = output
+        .try_into_u128()
+        .unwrap_or(u128::MAX)
+        .min(u128::from(u64::MAX)) as u64;
+
+    let (amount_a, amount_b) = input_output_to_a_b(0, output, a_to_b);
+    let (liquidity_a, liquidity_b) = get_liquidity_bounds_from_amounts(
+        yevefi.tick_current_index,
+        yevefi.sqrt_price,
+        position.tick_lower_index,
+        position.tick_upper_index,
+        amount_a,
+        amount_b,
+    )?;
+    let (_, output_bound) = input_output_to_a_b(liquidity_a, liquidity_b, a_to_b);
+    Ok(output_bound)
+}
+
+// Returns budget * weight_input / (weight_input + weight_output), or half of the budget if both
+// weights are zero.
+fn split_budget(budget: u64, weight_input: U256Muldiv, weight_output: U256Muldiv) -> Result<u64> {
+    // scale the weights down to 126 bits, so that their sum fits in 127 bits and the product of
+    // the sum with the budget fits in 256 bits
+    let shift = bit_length(&weight_input)
+        .max(bit_length(&weight_output))
+        .saturating_sub(126);
+    let weight_input = weight_input.shift_right(shift).try_into_u128()?;
+    let total = weight_input + weight_output.shift_right(shift).try_into_u128()?;
+    if total == 0 {
+        return Ok(budget / 2);
+    }
+
+    let (quotient, _) =
+        mul_u256(u128::from(budget), weight_input).div(U256Muldiv::new(0, total), false);
+    Ok(quotient.try_into_u128()? as u64)
+}
+
+fn bit_length(value: &U256Muldiv) -> u32 {
+    for i in (0..4).rev() {
+        let word = value.get_word(i);
+        if word != 0 {
+            return i as u32 * 64 + 64 - word.leading_zeros();
+        }
+    }
+    0
+}
+
+// Returns the liquidity that the remaining input token and the swap output can fund respectively
+// after swapping swap_amount, or None if the swap fails.
+fn simulate_zap_in(
+    yevefi: &Yevefi,
+    builder: &SparseSwapTickSequenceBuilder<'_>,
+    position: &Position,
+    budget: u64,
+    swap_amount: u64,
+    a_to_b: bool,
+    timestamp: u64,
+) -> Result<Option<(u128, u128)>> {
+    let (tick_current_index, sqrt_price, swap_input, swap_output) = if swap_amount == 0 {
+        (yevefi.tick_current_index, yevefi.sqrt_price, 0, 0)
+    } else {
+        let mut swap_tick_sequence = builder.build()?;
+        let swap_update = match swap(
+            yevefi,
+            &mut swap_tick_sequence,
+            swap_amount,
+            NO_EXPLICIT_SQRT_PRICE_LIMIT,
+            true,
+            a_to_b,
+            timestamp,
+        ) {
+            Ok(swap_update) => swap_update,
+            Err(_) => return Ok(None),
+        };
+        let (swap_input, swap_output) = swap_input_output(&swap_update, a_to_b);
+        (
+            swap_update.next_tick_index,
+            swap_update.next_sqrt_price,
+            swap_input,
+            swap_output,
+        )
+    };
+
+    let (amount_a, amount_b) = input_output_to_a_b(budget - swap_input, swap_output, a_to_b);
+    let (liquidity_a, liquidity_b) = get_liquidity_bounds_from_amounts(
+        tick_current_index,
+        sqrt_price,
+        position.tick_lower_index,
+        position.tick_upper_index,
+        amount_a,
+        amount_b,
+    )?;
+
+    Ok(Some(input_output_to_a_b(liquidity_a, liquidity_b, a_to_b)))
+}
+
+fn swap_input_output(swap_update: &PostSwapUpdate, a_to_b: bool) -> (u64, u64) {
+    if a_to_b {
+        (swap_update.amount_a, swap_update.amount_b)
+    } else {
+        (swap_update.amount_b, swap_update.amount_a)
+    }
+}
+
+// Maps a pair of (input token, output token) values to (token a, token b), and vice versa.
+fn input_output_to_a_b<T>(input: T, output: T, a_to_b: bool) -> (T, T) {
+    if a_to_b {
+        (input, output)
+    } else {
+        (output, input)
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::math::{sqrt_price_from_tick_index, tick_index_from_sqrt_price};
+
+    const Q64: f64 = 18446744073709551616.0;
+
+    // Zap in of token A into a pool with a constant liquidity and no fee.
+    struct ZapInModel {
+        pool_liquidity: f64,
+        sqrt_price: f64,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+        // swap amounts above this are not fillable
+        max_swap_amount: u64,
+    }
+
+    impl ZapInModel {
+        fn new(current_tick_index: i32, tick_lower_index: i32, tick_upper_index: i32) -> Self {
+            Self {
+                pool_liquidity: 1e12,
+                sqrt_price: sqrt_price_from_tick_index(current_tick_index) as f64 / Q64,
+                tick_lower_index,
+                tick_upper_index,
+                max_swap_amount: u64::MAX,
+            }
+        }
+
+        fn simulate(&self, budget: u64, swap_amount: u64) -> Option<(u128, u128)> {
+            if swap_amount > self.max_swap_amount {
+                return None;
+            }
+            let next_sqrt_price = self.pool_liquidity * self.sqrt_price
+                / (self.pool_liquidity + swap_amount as f64 * self.sqrt_price);
+            let swap_output = (self.pool_liquidity * (self.sqrt_price - next_sqrt_price)) as u64;
+
+            let next_sqrt_price_x64 = (next_sqrt_price * Q64) as u128;
+            let (liquidity_a, liquidity_b) = get_liquidity_bounds_from_amounts(
+                tick_index_from_sqrt_price(&next_sqrt_price_x64),
+                next_sqrt_price_x64,
+                self.tick_lower_index,
+                self.tick_upper_index,
+                budget - swap_amount,
+                swap_output,
+            )
+            .unwrap();
+            Some((liquidity_a, liquidity_b))
+        }
+
+        fn liquidity(&self, budget: u64, swap_amount: u64) -> u128 {
+            self.simulate(budget, swap_amount)
+                .map(|(input_bound, output_bound)| input_bound.min(output_bound))
+                .unwrap_or(0)
+        }
+
+        fn zero_impact_output_bound(&self, budget: u64) -> u128 {
+            let output = (budget as f64 * self.sqrt_price * self.sqrt_price) as u64;
+            let sqrt_price_x64 = (self.sqrt_price * Q64) as u128;
+            let (_, liquidity_b) = get_liquidity_bounds_from_amounts(
+                tick_index_from_sqrt_price(&sqrt_price_x64),
+                sqrt_price_x64,
+                self.tick_lower_index,
+                self.tick_upper_index,
+                0,
+                output,
+            )
+            .unwrap();
+            liquidity_b
+        }
+
+        // Returns the swap amount and the number of simulated swaps
+        fn search(&self, budget: u64) -> (u64, usize) {
+            let mut simulated_swaps = 0;
+            let zero_impact_output_bound = self.zero_impact_output_bound(budget);
+            let swap_amount =
+                search_zap_in_swap_amount(budget, zero_impact_output_bound, |swap_amount| {
+                    if swap_amount > 0 {
+                        simulated_swaps += 1;
+                    }
+                    Ok(self.simulate(budget, swap_amount))
+                })
+                .unwrap();
+            (swap_amount, simulated_swaps)
+        }
+
+        fn best_liquidity(&self, budget: u64) -> u128 {
+            const SAMPLES: u64 = 10_000;
+            (0..=SAMPLES)
+                .map(|i| {
+                    self.liquidity(
+                        budget,
+                        (budget as u128 * i as u128 / SAMPLES as u128) as u64,
+                    )
+                })
+                .max()
+                .unwrap()
+        }
+    }
+
+    #[test]
+    fn test_simulated_swaps_are_capped() {
+        for (current_tick_index, tick_lower_index, tick_upper_index) in [
+            (0, -128, 128),
+            (0, -6400, 64),
+            (0, -64, 6400),
+            (100, -1000, 128),
+        ] {
+            let model = ZapInModel::new(current_tick_index, tick_lower_index, tick_upper_index);
+            for budget in [2, 1_000, 1_000_000, 1_000_000_000, 1_000_000_000_000] {
+                let (_, simulated_swaps) = model.search(budget);
+                assert!(simulated_swaps <= ZAP_IN_MAX_SIMULATED_SWAPS);
+            }
+        }
+    }
+
+    #[test]
+    fn test_swap_amount_is_near_optimal() {
+        for (current_tick_index, tick_lower_index, tick_upper_index) in [
+            (0, -128, 128),
+            (0, -6400, 64),
+            (0, -64, 6400),
+            (100, -1000, 128),
+        ] {
+            let model = ZapInModel::new(current_tick_index, tick_lower_index, tick_upper_index);
+            for budget in [1_000_000, 1_000_000_000] {
+                let (swap_amount, _) = model.search(budget);
+                let liquidity = model.liquidity(budget, swap_amount);
+                let best_liquidity = model.best_liquidity(budget);
+                // within 0.1% of the best liquidity of a fine grid search
+                assert!(liquidity * 1000 >= best_liquidity * 999);
+            }
+        }
+    }
+
+    #[test]
+    fn test_no_swap_if_input_token_is_binding() {
+        // the position is above the current price, and only token A is required
+        let model = ZapInModel::new(0, 128, 256);
+        let (swap_amount, simulated_swaps) = model.search(1_000_000);
+        assert_eq!(swap_amount, 0);
+        assert_eq!(simulated_swaps, 0);
+        assert!(model.liquidity(1_000_000, 0) > 0);
+    }
+
+    #[test]
+    fn test_swap_amount_is_fillable() {
+        let mut model = ZapInModel::new(0, -6400, 64);
+        let budget = 1_000_000_000;
+        let (unbounded_swap_amount, _) = model.search(budget);
+
+        model.max_swap_amount = unbounded_swap_amount / 2;
+        let (swap_amount, simulated_swaps) = model.search(budget);
+        assert!(swap_amount > 0);
+        assert!(swap_amount <= model.max_swap_amount);
+        assert!(simulated_swaps <= ZAP_IN_MAX_SIMULATED_SWAPS);
+    }
+
+    #[test]
+    fn test_split_budget() {
+        let weight = |value: u128| U256Muldiv::new(0, value);
+        assert_eq!(split_budget(100, weight(1), weight(1)).unwrap(), 50);
+        assert_eq!(split_budget(100, weight(3), weight(1)).unwrap(), 75);
+        assert_eq!(split_budget(100, weight(1), weight(0)).unwrap(), 100);
+        assert_eq!(split_budget(100, weight(0), weight(1)).unwrap(), 0);
+        assert_eq!(split_budget(100, weight(0), weight(0)).unwrap(), 50);
+        // weights above 128 bits are scaled down
+        assert_eq!(
+            split_budget(
+                u64::MAX,
+                mul_u256(u128::MAX, u128::MAX),
+                mul_u256(u128::MAX, u128::MAX)
+            )
+            .unwrap(),
+            u64::MAX / 2
+        );
+        assert_eq!(
+            split_budget(u64::MAX, mul_u256(u128::MAX, u128::MAX), weight(1)).unwrap(),
+            u64::MAX
+        );
+    }
+}
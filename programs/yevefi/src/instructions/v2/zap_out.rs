@@ -0,0 +1,399 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::transfer_memo;
+use crate::errors::ErrorCode;
+use crate::events::ZappedOut;
+use crate::manager::liquidity_manager::{
+    calculate_liquidity_token_deltas, calculate_modify_liquidity, sync_modify_liquidity_values,
+};
+use crate::manager::swap_manager::{swap, PostSwapUpdate};
+use crate::math::{convert_to_liquidity_delta, NO_EXPLICIT_SQRT_PRICE_LIMIT};
+use crate::state::*;
+use crate::util::{
+    calculate_transfer_fee_excluded_amount, parse_remaining_accounts, AccountsType,
+    RemainingAccountsInfo, TransferFeeExcludedAmount,
+};
+use crate::util::{
+    require_no_reward_extension, require_not_paused, require_position_unlocked, to_timestamp_u64,
+    update_oracle,
+    v2::{transfer_from_vault_to_owner_v2, update_yevefi_and_emit_traded},
+    verify_position_authority_interface, SparseSwapTickSequenceBuilder,
+};
+
+#[derive(Accounts)]
+pub struct ZapOut<'info> {
+    #[account(mut)]
+    pub yevefi: Box<Account<'info, Yevefi>>,
+
+    #[account(address = *token_mint_a.to_account_info().owner)]
+    pub token_program_a: Interface<'info, TokenInterface>,
+    #[account(address = *token_mint_b.to_account_info().owner)]
+    pub token_program_b: Interface<'info, TokenInterface>,
+
+    pub memo_program: Program<'info, Memo>,
+
+    pub position_authority: Signer<'info>,
+
+    #[account(mut, has_one = yevefi)]
+    pub position: Box<Account<'info, Position>>,
+    #[account(
+        constraint = position_token_account.mint == position.position_mint,
+        constraint = position_token_account.amount == 1
+    )]
+    pub position_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = yevefi.token_mint_a)]
+    pub token_mint_a: InterfaceAccount<'info, Mint>,
+    #[account(address = yevefi.token_mint_b)]
+    pub token_mint_b: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, constraint = token_owner_account_a.mint == yevefi.token_mint_a)]
+    pub token_owner_account_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, constraint = token_owner_account_b.mint == yevefi.token_mint_b)]
+    pub token_owner_account_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, address = yevefi.token_vault_a)]
+    pub token_vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, address = yevefi.token_vault_b)]
+    pub token_vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // tick arrays of the position
+    #[account(mut, has_one = yevefi)]
+    pub tick_array_lower: AccountLoader<'info, TickArray>,
+    #[account(mut, has_one = yevefi)]
+    pub tick_array_upper: AccountLoader<'info, TickArray>,
+
+    // tick arrays of the swap
+    #[account(mut)]
+    /// CHECK: checked in the handler
+    pub tick_array_0: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: checked in the handler
+    pub tick_array_1: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: checked in the handler
+    pub tick_array_2: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [b"oracle", yevefi.key().as_ref()], bump)]
+    /// CHECK: Oracle is optional, checked in the handler if initialized
+    pub oracle: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"config_extension", yevefi.yevefis_config.as_ref()], bump)]
+    /// CHECK: Config extension may be uninitialized, checked in the handler
+    pub yevefis_config_extension: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"position_lock", position.key().as_ref()], bump)]
+    /// CHECK: Position lock is checked in the handler if the position is locked
+    pub position_lock: UncheckedAccount<'info>,
+    // remaining accounts
+    // - accounts for transfer hook program of token_mint_a
+    // - accounts for transfer hook program of token_mint_b
+    // - supplemental TickArray accounts
+}
+
+/*
+  Withdraws a position into a single token. Liquidity is removed and the fees owed are collected,
+  then all of the unwanted token is swapped in the same yevefi.
+  The unwanted token never leaves the vault, only the output token is transferred to the owner.
+*/
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ZapOut<'info>>,
+    liquidity_amount: Option<u128>,
+    a_to_b: bool,
+    min_amount_out: u64,
+    remaining_accounts_info: Option<RemainingAccountsInfo>,
+) -> Result<()> {
+    verify_position_authority_interface(
+        &ctx.accounts.position_token_account,
+        &ctx.accounts.position_authority,
+        None,
+    )?;
+
+    require_not_paused(
+        &ctx.accounts.yevefi,
+        Some(&ctx.accounts.yevefis_config_extension.to_account_info()),
+    )?;
+    require_no_reward_extension(&ctx.accounts.yevefi)?;
+
+    let clock = Clock::get()?;
+    let timestamp = to_timestamp_u64(clock.unix_timestamp)?;
+
+    require_position_unlocked(
+        &ctx.accounts.position.to_account_info(),
+        Some(&ctx.accounts.position_lock),
+        timestamp,
+    )?;
+
+    let liquidity_amount = get_liquidity_amount(liquidity_amount, &ctx.accounts.position)?;
+
+    // Process remaining accounts
+    let remaining_accounts = parse_remaining_accounts(
+        ctx.remaining_accounts,
+        &remaining_accounts_info,
+        &[
+            AccountsType::TransferHookA,
+            AccountsType::TransferHookB,
+            AccountsType::SupplementalTickArrays,
+        ],
+    )?;
+
+    // The observation precedes both the liquidity change and the swap
+    update_oracle(&ctx.accounts.oracle, &ctx.accounts.yevefi, timestamp)?;
+
+    let liquidity_delta = convert_to_liquidity_delta(liquidity_amount, false)?;
+
+    let update = calculate_modify_liquidity(
+        &ctx.accounts.yevefi,
+        &ctx.accounts.position,
+        &ctx.accounts.tick_array_lower,
+        &ctx.accounts.tick_array_upper,
+        liquidity_delta,
+        timestamp,
+    )?;
+
+    sync_modify_liquidity_values(
+        &mut ctx.accounts.yevefi,
+        &mut ctx.accounts.position,
+        &ctx.accounts.tick_array_lower,
+        &ctx.accounts.tick_array_upper,
+        update,
+        timestamp,
+    )?;
+
+    let (delta_a, delta_b) = calculate_liquidity_token_deltas(
+        ctx.accounts.yevefi.tick_current_index,
+        ctx.accounts.yevefi.sqrt_price,
+        &ctx.accounts.position,
+        liquidity_delta,
+    )?;
+
+    // The fees owed are up to date after modifying liquidity
+    let fee_owed_a = ctx.accounts.position.fee_owed_a;
+    let fee_owed_b = ctx.accounts.position.fee_owed_b;
+    ctx.accounts.position.reset_fees_owed();
+
+    let amount_a = delta_a
+        .checked_add(fee_owed_a)
+        .ok_or(ErrorCode::AmountCalcOverflow)?;
+    let amount_b = delta_b
+        .checked_add(fee_owed_b)
+        .ok_or(ErrorCode::AmountCalcOverflow)?;
+
+    let (swap_amount, amount_out) = if a_to_b {
+        (amount_a, amount_b)
+    } else {
+        (amount_b, amount_a)
+    };
+
+    let amount_out = if swap_amount > 0 {
+        let swap_update = {
+            let builder = SparseSwapTickSequenceBuilder::try_from(
+                &ctx.accounts.yevefi,
+                a_to_b,
+                vec![
+                    ctx.accounts.tick_array_0.to_account_info(),
+                    ctx.accounts.tick_array_1.to_account_info(),
+                    ctx.accounts.tick_array_2.to_account_info(),
+                ],
+                remaining_accounts.supplemental_tick_arrays,
+            )?;
+            let mut swap_tick_sequence = builder.build()?;
+            swap(
+                &ctx.accounts.yevefi,
+                &mut swap_tick_sequence,
+                swap_amount,
+                NO_EXPLICIT_SQRT_PRICE_LIMIT,
+                true,
+                a_to_b,
+                timestamp,
+            )?
+        };
+
+        let swap_output = get_swap_output(&swap_update, swap_amount, a_to_b)?;
+
+        update_yevefi_and_emit_traded(
+            &mut ctx.accounts.yevefi,
+            &ctx.accounts.token_mint_a,
+            &ctx.accounts.token_mint_b,
+            &swap_update,
+            a_to_b,
+            timestamp,
+        )?;
+
+        amount_out
+            .checked_add(swap_output)
+            .ok_or(ErrorCode::AmountCalcOverflow)?
+    } else {
+        amount_out
+    };
+
+    let (
+        output_token_mint,
+        output_token_vault,
+        output_token_owner_account,
+        output_token_program,
+        output_transfer_hook_accounts,
+    ) = if a_to_b {
+        (
+            &ctx.accounts.token_mint_b,
+            &ctx.accounts.token_vault_b,
+            &ctx.accounts.token_owner_account_b,
+            &ctx.accounts.token_program_b,
+            &remaining_accounts.transfer_hook_b,
+        )
+    } else {
+        (
+            &ctx.accounts.token_mint_a,
+            &ctx.accounts.token_vault_a,
+            &ctx.accounts.token_owner_account_a,
+            &ctx.accounts.token_program_a,
+            &remaining_accounts.transfer_hook_a,
+        )
+    };
+
+    let transfer_fee_excluded_amount_out =
+        check_amount_out(output_token_mint, amount_out, min_amount_out)?;
+
+    transfer_from_vault_to_owner_v2(
+        &ctx.accounts.yevefi,
+        output_token_mint,
+        output_token_vault,
+        output_token_owner_account,
+        output_token_program,
+        &ctx.accounts.memo_program,
+        output_transfer_hook_accounts,
+        amount_out,
+        transfer_memo::TRANSFER_MEMO_DECREASE_LIQUIDITY.as_bytes(),
+    )?;
+
+    emit!(ZappedOut {
+        yevefi: ctx.accounts.yevefi.key(),
+        position: ctx.accounts.position.key(),
+        tick_lower_index: ctx.accounts.position.tick_lower_index,
+        tick_upper_index: ctx.accounts.position.tick_upper_index,
+        liquidity: liquidity_amount,
+        token_a_amount: delta_a,
+        token_b_amount: delta_b,
+        fee_a_amount: fee_owed_a,
+        fee_b_amount: fee_owed_b,
+        a_to_b,
+        amount_out,
+        transfer_fee: transfer_fee_excluded_amount_out.transfer_fee,
+    });
+
+    Ok(())
+}
+
+// None withdraws all liquidity of the position
+fn get_liquidity_amount(liquidity_amount: Option<u128>, position: &Position) -> Result<u128> {
+    let liquidity_amount = liquidity_amount.unwrap_or(position.liquidity);
+    if liquidity_amount == 0 {
+        return Err(ErrorCode::LiquidityZero.into());
+    }
+    Ok(liquidity_amount)
+}
+
+// All of the unwanted token must be swapped, nothing of it is returned to the owner
+fn get_swap_output(swap_update: &PostSwapUpdate, swap_amount: u64, a_to_b: bool) -> Result<u64> {
+    let (swap_input, swap_output) = if a_to_b {
+        (swap_update.amount_a, swap_update.amount_b)
+    } else {
+        (swap_update.amount_b, swap_update.amount_a)
+    };
+
+    if swap_input != swap_amount {
+        return Err(ErrorCode::PartialFillError.into());
+    }
+    Ok(swap_output)
+}
+
+// min_amount_out should be applied to the transfer fee excluded amount
+fn check_amount_out(
+    output_token_mint: &InterfaceAccount<'_, Mint>,
+    amount_out: u64,
+    min_amount_out: u64,
+) -> Result<TransferFeeExcludedAmount> {
+    let transfer_fee_excluded_amount_out =
+        calculate_transfer_fee_excluded_amount(output_token_mint, amount_out)?;
+    if transfer_fee_excluded_amount_out.amount < min_amount_out {
+        return Err(ErrorCode::AmountOutBelowMinimum.into());
+    }
+    Ok(transfer_fee_excluded_amount_out)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::state::position_builder::PositionBuilder;
+    use crate::state::{AdaptiveFeeInfo, YevefiRewardInfo, NUM_REWARDS};
+
+    fn swap_update(amount_a: u64, amount_b: u64) -> PostSwapUpdate {
+        PostSwapUpdate {
+            amount_a,
+            amount_b,
+            next_liquidity: 0,
+            next_tick_index: 0,
+            next_sqrt_price: 0,
+            next_fee_growth_global: 0,
+            next_reward_infos: [YevefiRewardInfo::default(); NUM_REWARDS],
+            next_protocol_fee: 0,
+            next_referral_fee: 0,
+            next_lp_fee: 0,
+            next_adaptive_fee_info: AdaptiveFeeInfo::default(),
+            ticks_crossed: 0,
+            tick_arrays_touched: 0,
+        }
+    }
+
+    #[test]
+    fn test_get_liquidity_amount_defaults_to_position_liquidity() {
+        let position = PositionBuilder::new(-128, 128).liquidity(1_000).build();
+        assert_eq!(get_liquidity_amount(None, &position).unwrap(), 1_000);
+        assert_eq!(get_liquidity_amount(Some(400), &position).unwrap(), 400);
+    }
+
+    #[test]
+    fn test_get_liquidity_amount_zero() {
+        let empty_position = PositionBuilder::new(-128, 128).build();
+        assert_eq!(
+            get_liquidity_amount(None, &empty_position).unwrap_err(),
+            ErrorCode::LiquidityZero.into()
+        );
+
+        let position = PositionBuilder::new(-128, 128).liquidity(1_000).build();
+        assert_eq!(
+            get_liquidity_amount(Some(0), &position).unwrap_err(),
+            ErrorCode::LiquidityZero.into()
+        );
+    }
+
+    #[test]
+    fn test_get_swap_output() {
+        // a to b: token A is swapped in, token B comes out
+        assert_eq!(
+            get_swap_output(&swap_update(500, 480), 500, true).unwrap(),
+            480
+        );
+        // b to a: token B is swapped in, token A comes out
+        assert_eq!(
+            get_swap_output(&swap_update(480, 500), 500, false).unwrap(),
+            480
+        );
+    }
+
+    #[test]
+    fn test_get_swap_output_partial_fill() {
+        assert_eq!(
+            get_swap_output(&swap_update(499, 480), 500, true).unwrap_err(),
+            ErrorCode::PartialFillError.into()
+        );
+        assert_eq!(
+            get_swap_output(&swap_update(480, 499), 500, false).unwrap_err(),
+            ErrorCode::PartialFillError.into()
+        );
+    }
+}
@@ -6,6 +6,7 @@ declare_id!("9Tde8mGGcYpKtECAubWKcGTWFhr1nCoE7NMvMwFpfFvN");
 pub mod constants;
 #[doc(hidden)]
 pub mod errors;
+pub mod events;
 #[doc(hidden)]
 pub mod instructions;
 #[doc(hidden)]
@@ -18,7 +19,10 @@ pub mod state;
 #[doc(hidden)]
 pub mod util;
 
-use crate::state::{OpenPositionBumps, OpenPositionWithMetadataBumps, YevefiBumps};
+use crate::state::{
+    AdaptiveFeeConstants, OpenPositionBumps, OpenPositionWithMetadataBumps,
+    RewardEmissionsRateChange, YevefiBumps,
+};
 use crate::util::RemainingAccountsInfo;
 use instructions::*;
 
@@ -124,6 +128,8 @@ pub mod yevefi {
     }
 
     /// Set the reward emissions for a reward in a Yevefi.
+    /// The emission schedule of the reward, if any, is replaced by the constant emission rate.
+    /// The reward vault funds the emissions from now on, which stop once the funds run out.
     ///
     /// ### Authority
     /// - "reward_authority" - assigned authority by the reward_super_authority for the specified
@@ -206,6 +212,11 @@ pub mod yevefi {
     /// - `LiquidityZero` - Provided liquidity amount is zero.
     /// - `LiquidityTooHigh` - Provided liquidity exceeds u128::max.
     /// - `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.
+    /// - `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.
+    /// - `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.
+    /// - `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.
+    /// - `InvalidOracle` - The oracle account is missing or is not the oracle PDA of the Yevefi.
+    /// - `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.
     pub fn increase_liquidity(
         ctx: Context<ModifyLiquidity>,
         liquidity_amount: u128,
@@ -229,6 +240,11 @@ pub mod yevefi {
     /// - `LiquidityZero` - Provided liquidity amount is zero.
     /// - `LiquidityTooHigh` - Provided liquidity exceeds u128::max.
     /// - `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.
+    /// - `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.
+    /// - `InvalidPositionLock` - The PositionLock PDA of the position was not passed.
+    /// - `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.
+    /// - `InvalidOracle` - The oracle account is missing or is not the oracle PDA of the Yevefi.
+    /// - `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.
     pub fn decrease_liquidity(
         ctx: Context<ModifyLiquidity>,
         liquidity_amount: u128,
@@ -255,6 +271,24 @@ pub mod yevefi {
         instructions::collect_fees::handler(ctx)
     }
 
+    /// Reinvest the fees accrued for this position as liquidity of the same position.
+    /// The maximum liquidity that the fees owed can fund at the current price is added,
+    /// and the remainder stays owed to the position. No tokens are transferred.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///
+    /// #### Special Errors
+    /// - `TickNotFound` - Provided tick array account does not contain the tick for this position.
+    /// - `LiquidityZero` - Position has zero liquidity and therefore accrues no fees.
+    /// - `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.
+    /// - `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.
+    /// - `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.
+    /// - `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.
+    pub fn compound_position_fees(ctx: Context<CompoundPositionFees>) -> Result<()> {
+        instructions::compound_position_fees::handler(ctx)
+    }
+
     /// Collect rewards accrued for this position.
     ///
     /// ### Authority
@@ -292,6 +326,10 @@ pub mod yevefi {
     /// - `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.
     /// - `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.
     /// - `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.
+    /// - `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.
+    /// - `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.
+    /// - `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.
+    /// - `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.
     pub fn swap(
         ctx: Context<Swap>,
         amount: u64,
@@ -311,13 +349,18 @@ pub mod yevefi {
     }
 
     /// Close a position in a Yevefi. Burns the position token in the owner's wallet.
+    /// The expired PositionLock of the position, if any, is closed as well.
     ///
     /// ### Authority
     /// - "position_authority" - The authority that owns the position token.
     ///
     /// #### Special Errors
     /// - `ClosePositionNotEmpty` - The provided position account is not empty.
-    pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
+    /// - `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.
+    /// - `InvalidPositionLock` - The PositionLock PDA of the position was not passed.
+    pub fn close_position<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClosePosition<'info>>,
+    ) -> Result<()> {
         instructions::close_position::handler(ctx)
     }
 
@@ -394,10 +437,50 @@ pub mod yevefi {
         instructions::set_protocol_fee_rate::handler(ctx, protocol_fee_rate)
     }
 
-    /// Sets the fee authority for a YevefiConfig.
-    /// The fee authority can set the fee & protocol fee rate for individual pools or
-    /// set the default fee rate for newly minted pools.
+    /// Sets the referral fee rate for a YevefiConfig.
+    /// Referral fee rate is represented as a basis point of the LP fee, and is paid to the
+    /// Referrer registered with `initialize_referrer` of swaps that supply referral accounts.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the YevefiConfig
+    ///
+    /// ### Parameters
+    /// - `referral_fee_rate` - The rate that swaps on Yevefis using this config will pay to referrers.
+    ///
+    /// #### Special Errors
+    /// - `ReferralFeeRateMaxExceeded` - If the provided referral_fee_rate exceeds MAX_REFERRAL_FEE_RATE.
+    pub fn set_referral_fee_rate(
+        ctx: Context<SetReferralFeeRate>,
+        referral_fee_rate: u16,
+    ) -> Result<()> {
+        instructions::set_referral_fee_rate::handler(ctx, referral_fee_rate)
+    }
+
+    /// Registers a referrer of a YevefiConfig. Swaps on Yevefis using this config can only pay
+    /// referral fees to token accounts owned by a registered referrer_authority.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the YevefiConfig
+    pub fn initialize_referrer(ctx: Context<InitializeReferrer>) -> Result<()> {
+        instructions::initialize_referrer::handler(ctx)
+    }
+
+    /// Removes a referrer of a YevefiConfig, so that it no longer receives referral fees.
+    /// Only the current fee authority has permission to invoke this instruction.
+    ///
+    /// ### Authority
+    /// - "fee_authority" - Set authority that can modify pool fees in the YevefiConfig
+    pub fn delete_referrer(ctx: Context<DeleteReferrer>) -> Result<()> {
+        instructions::delete_referrer::handler(ctx)
+    }
+
+    /// Proposes a new fee authority for a YevefiConfig, which takes over once it signs
+    /// `accept_fee_authority`. The fee authority can set the fee & protocol fee rate for
+    /// individual pools or set the default fee rate for newly minted pools.
     /// Only the current fee authority has permission to invoke this instruction.
+    /// Proposing the default Pubkey cancels a pending transfer.
     ///
     /// ### Authority
     /// - "fee_authority" - Set authority that can modify pool fees in the YevefiConfig
@@ -405,8 +488,18 @@ pub mod yevefi {
         instructions::set_fee_authority::handler(ctx)
     }
 
-    /// Sets the fee authority to collect protocol fees for a YevefiConfig.
+    /// Accepts the fee authority proposed by `set_fee_authority` for a YevefiConfig.
+    ///
+    /// ### Authority
+    /// - "new_fee_authority" - The pending fee authority in the YevefiConfig
+    pub fn accept_fee_authority(ctx: Context<AcceptFeeAuthority>) -> Result<()> {
+        instructions::accept_fee_authority::handler(ctx)
+    }
+
+    /// Proposes a new authority to collect protocol fees for a YevefiConfig, which takes over once
+    /// it signs `accept_collect_protocol_fees_authority`.
     /// Only the current collect protocol fee authority has permission to invoke this instruction.
+    /// Proposing the default Pubkey cancels a pending transfer.
     ///
     /// ### Authority
     /// - "fee_authority" - Set authority that can collect protocol fees in the YevefiConfig
@@ -416,8 +509,21 @@ pub mod yevefi {
         instructions::set_collect_protocol_fees_authority::handler(ctx)
     }
 
-    /// Set the yevefi reward authority at the provided `reward_index`.
+    /// Accepts the collect protocol fees authority proposed by `set_collect_protocol_fees_authority`
+    /// for a YevefiConfig.
+    ///
+    /// ### Authority
+    /// - "new_collect_protocol_fees_authority" - The pending collect protocol fees authority in the YevefiConfig
+    pub fn accept_collect_protocol_fees_authority(
+        ctx: Context<AcceptCollectProtocolFeesAuthority>,
+    ) -> Result<()> {
+        instructions::accept_collect_protocol_fees_authority::handler(ctx)
+    }
+
+    /// Propose a new yevefi reward authority at the provided `reward_index`, which takes over once
+    /// it signs `accept_reward_authority`.
     /// Only the current reward authority for this reward index has permission to invoke this instruction.
+    /// Proposing the default Pubkey cancels a pending transfer.
     ///
     /// ### Authority
     /// - "reward_authority" - Set authority that can control reward emission for this particular reward.
@@ -430,8 +536,23 @@ pub mod yevefi {
         instructions::set_reward_authority::handler(ctx, reward_index)
     }
 
+    /// Accept the yevefi reward authority proposed by `set_reward_authority` at the provided `reward_index`.
+    ///
+    /// ### Authority
+    /// - "new_reward_authority" - The pending reward authority for this particular reward.
+    ///
+    /// #### Special Errors
+    /// - `InvalidRewardIndex` - If the provided reward index exceeds NUM_REWARDS.
+    pub fn accept_reward_authority(
+        ctx: Context<AcceptRewardAuthority>,
+        reward_index: u8,
+    ) -> Result<()> {
+        instructions::accept_reward_authority::handler(ctx, reward_index)
+    }
+
     /// Set the yevefi reward authority at the provided `reward_index`.
     /// Only the current reward super authority has permission to invoke this instruction.
+    /// Any pending reward authority transfer is cancelled.
     ///
     /// ### Authority
     /// - "reward_authority" - Set authority that can control reward emission for this particular reward.
@@ -447,8 +568,10 @@ pub mod yevefi {
         instructions::set_reward_authority_by_super_authority::handler(ctx, reward_index)
     }
 
-    /// Set the yevefi reward super authority for a YevefiConfig
+    /// Propose a new yevefi reward super authority for a YevefiConfig, which takes over once it signs
+    /// `accept_reward_emissions_super_authority`.
     /// Only the current reward super authority has permission to invoke this instruction.
+    /// Proposing the default Pubkey cancels a pending transfer.
     /// This instruction will not change the authority on any `YevefiRewardInfo` yevefi rewards.
     ///
     /// ### Authority
@@ -459,6 +582,17 @@ pub mod yevefi {
         instructions::set_reward_emissions_super_authority::handler(ctx)
     }
 
+    /// Accept the yevefi reward super authority proposed by `set_reward_emissions_super_authority`
+    /// for a YevefiConfig.
+    ///
+    /// ### Authority
+    /// - "new_reward_emissions_super_authority" - The pending reward super authority in the YevefiConfig
+    pub fn accept_reward_emissions_super_authority(
+        ctx: Context<AcceptRewardEmissionsSuperAuthority>,
+    ) -> Result<()> {
+        instructions::accept_reward_emissions_super_authority::handler(ctx)
+    }
+
     /// Perform a two-hop swap in this Yevefi
     ///
     /// ### Authority
@@ -484,6 +618,10 @@ pub mod yevefi {
     /// - `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.
     /// - `InvalidIntermediaryMint` - Error if the intermediary mint between hop one and two do not equal.
     /// - `DuplicateTwoHopPool` - Error if yevefi one & two are the same pool.
+    /// - `YevefiPaused` - One of the Yevefis, or all Yevefis of its YevefisConfig, are paused.
+    /// - `InvalidConfigExtension` - A config extension account is missing or is not the config extension PDA of its Yevefi's YevefisConfig.
+    /// - `RewardExtensionNotSupported` - The reward extension of one of the Yevefis is initialized.
+    /// - `OracleNotWritable` - The oracle of one of the Yevefis is initialized but was passed as read-only.
     #[allow(clippy::too_many_arguments)]
     pub fn two_hop_swap(
         ctx: Context<TwoHopSwap>,
@@ -533,6 +671,37 @@ pub mod yevefi {
         instructions::delete_position_bundle::handler(ctx)
     }
 
+    /// Initializes a PositionBundle account that bundles several positions.
+    /// A unique token will be minted to represent the position bundle in the users wallet.
+    /// Mint and TokenAccount are based on Token-2022, and the PositionBundle account is the
+    /// close authority of the mint, so that the rent of the mint can be reclaimed on delete.
+    ///
+    /// ### Parameters
+    /// - `with_token_metadata_extension` - If true, the token metadata extension will be initialized.
+    pub fn initialize_position_bundle_with_token_extensions(
+        ctx: Context<InitializePositionBundleWithTokenExtensions>,
+        with_token_metadata_extension: bool,
+    ) -> Result<()> {
+        instructions::initialize_position_bundle_with_token_extensions::handler(
+            ctx,
+            with_token_metadata_extension,
+        )
+    }
+
+    /// Delete a PositionBundle account initialized with Token-2022.
+    /// Burns the position bundle token in the owner's wallet and closes the mint.
+    ///
+    /// ### Authority
+    /// - `position_bundle_owner` - The owner that owns the position bundle token.
+    ///
+    /// ### Special Errors
+    /// - `PositionBundleNotDeletable` - The provided position bundle has open positions.
+    pub fn delete_position_bundle_with_token_extensions(
+        ctx: Context<DeletePositionBundleWithTokenExtensions>,
+    ) -> Result<()> {
+        instructions::delete_position_bundle_with_token_extensions::handler(ctx)
+    }
+
     /// Open a bundled position in a Yevefi. No new tokens are issued
     /// because the owner of the position bundle becomes the owner of the position.
     /// The position will start off with 0 liquidity.
@@ -564,6 +733,7 @@ pub mod yevefi {
     }
 
     /// Close a bundled position in a Yevefi.
+    /// The expired PositionLock of the position, if any, is closed as well.
     ///
     /// ### Authority
     /// - `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.
@@ -574,8 +744,10 @@ pub mod yevefi {
     /// #### Special Errors
     /// - `InvalidBundleIndex` - If the provided bundle index is out of bounds.
     /// - `ClosePositionNotEmpty` - The provided position account is not empty.
-    pub fn close_bundled_position(
-        ctx: Context<CloseBundledPosition>,
+    /// - `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.
+    /// - `InvalidPositionLock` - The PositionLock PDA of the position was not passed.
+    pub fn close_bundled_position<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseBundledPosition<'info>>,
         bundle_index: u16,
     ) -> Result<()> {
         instructions::close_bundled_position::handler(ctx, bundle_index)
@@ -583,6 +755,8 @@ pub mod yevefi {
 
     /// Open a position in a Yevefi. A unique token will be minted to represent the position
     /// in the users wallet. Additional TokenMetadata extension is initialized to identify the token.
+    /// The TokenMetadata includes the pool address, tick range, liquidity and in-range flag of the
+    /// position as additional fields, and the position account is its update authority.
     /// Mint and TokenAccount are based on Token-2022.
     /// The position will start off with 0 liquidity.
     ///
@@ -608,233 +782,1042 @@ pub mod yevefi {
         )
     }
 
+    /// Refresh the additional TokenMetadata fields of a position opened with token extensions,
+    /// so that they reflect the current tick range, liquidity and in-range state of the position.
+    /// Anyone can refresh a position. The funder pays the rent if the fields grow.
+    ///
+    /// #### Special Errors
+    /// - `InvalidPositionMetadataUpdateAuthority` - The position account is not the update authority
+    ///                                              of the TokenMetadata.
+    pub fn refresh_position_metadata(ctx: Context<RefreshPositionMetadata>) -> Result<()> {
+        instructions::refresh_position_metadata::handler(ctx)
+    }
+
     /// Close a position in a Yevefi. Burns the position token in the owner's wallet.
     /// Mint and TokenAccount are based on Token-2022. And Mint accout will be also closed.
+    /// The expired PositionLock of the position, if any, is closed as well.
     ///
     /// ### Authority
     /// - "position_authority" - The authority that owns the position token.
     ///
     /// #### Special Errors
     /// - `ClosePositionNotEmpty` - The provided position account is not empty.
-    pub fn close_position_with_token_extensions(
-        ctx: Context<ClosePositionWithTokenExtensions>,
+    /// - `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.
+    /// - `InvalidPositionLock` - The PositionLock PDA of the position was not passed.
+    pub fn close_position_with_token_extensions<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClosePositionWithTokenExtensions<'info>>,
     ) -> Result<()> {
         instructions::close_position_with_token_extensions::handler(ctx)
     }
 
-    ////////////////////////////////////////////////////////////////////////////////
-    // V2 instructions (TokenExtensions)
-    ////////////////////////////////////////////////////////////////////////////////
-
-    // TODO: update comments
-
-    /// Collect fees accrued for this position.
+    /// Lock the liquidity of a position until the unlock timestamp.
+    /// While locked, liquidity cannot be decreased and the position cannot be closed,
+    /// but fees and rewards can still be collected. A position can only be locked once,
+    /// the lock can be extended with `extend_position_lock`.
+    /// Bundled positions are locked by the holder of the position bundle token.
+    /// Instructions that check the lock require the PositionLock PDA of every position, locked
+    /// or not. v1 instructions take it as the first remaining account and v2 instructions as a
+    /// `PositionLock` remaining accounts slice.
     ///
     /// ### Authority
-    /// - `position_authority` - authority that owns the token corresponding to this desired position.
-    pub fn collect_fees_v2<'info>(
-        ctx: Context<'_, '_, '_, 'info, CollectFeesV2<'info>>,
-        remaining_accounts_info: Option<RemainingAccountsInfo>,
-    ) -> Result<()> {
-        instructions::v2::collect_fees::handler(ctx, remaining_accounts_info)
+    /// - "position_authority" - The authority that owns the position token.
+    ///
+    /// ### Parameters
+    /// - `unlock_timestamp` - The unix timestamp from which the position is unlocked.
+    ///
+    /// #### Special Errors
+    /// - `InvalidUnlockTimestamp` - The unlock timestamp is not in the future.
+    pub fn lock_position(ctx: Context<LockPosition>, unlock_timestamp: u64) -> Result<()> {
+        instructions::lock_position::handler(ctx, unlock_timestamp)
     }
 
-    /// Collect the protocol fees accrued in this Yevefi
+    /// Move the unlock timestamp of a position lock later.
+    /// An expired lock can be extended to lock the position again.
     ///
     /// ### Authority
-    /// - `collect_protocol_fees_authority` - assigned authority in the YevefiConfig that can collect protocol fees
-    pub fn collect_protocol_fees_v2<'info>(
-        ctx: Context<'_, '_, '_, 'info, CollectProtocolFeesV2<'info>>,
-        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    /// - "position_authority" - The authority that owns the position token.
+    ///
+    /// ### Parameters
+    /// - `unlock_timestamp` - The unix timestamp from which the position is unlocked.
+    ///
+    /// #### Special Errors
+    /// - `InvalidUnlockTimestamp` - The unlock timestamp is not in the future or not later than
+    ///                              the current unlock timestamp.
+    pub fn extend_position_lock(
+        ctx: Context<ExtendPositionLock>,
+        unlock_timestamp: u64,
     ) -> Result<()> {
-        instructions::v2::collect_protocol_fees::handler(ctx, remaining_accounts_info)
+        instructions::extend_position_lock::handler(ctx, unlock_timestamp)
     }
 
-    /// Collect rewards accrued for this position.
+    /// Close an expired position lock of a position that stays open.
+    /// Closing the position closes its lock as well.
+    /// The lock marker is removed from the position and its rent returned to the receiver.
     ///
     /// ### Authority
-    /// - `position_authority` - authority that owns the token corresponding to this desired position.
-    pub fn collect_reward_v2<'info>(
-        ctx: Context<'_, '_, '_, 'info, CollectRewardV2<'info>>,
-        reward_index: u8,
-        remaining_accounts_info: Option<RemainingAccountsInfo>,
-    ) -> Result<()> {
-        instructions::v2::collect_reward::handler(ctx, reward_index, remaining_accounts_info)
+    /// - "position_authority" - The authority that owns the position token.
+    ///
+    /// #### Special Errors
+    /// - `PositionLocked` - The position is locked until its unlock timestamp.
+    pub fn close_position_lock(ctx: Context<ClosePositionLock>) -> Result<()> {
+        instructions::close_position_lock::handler(ctx)
     }
 
-    /// Withdraw liquidity from a position in the Yevefi. This call also updates the position's accrued fees and rewards.
+    /// Grant a delegate a set of permissions on a position, without transferring the position token.
+    /// The delegate can collect fees, collect rewards, increase liquidity or decrease liquidity
+    /// through the v2 instructions as permitted. Tokens withdrawn by the delegate can only be
+    /// transferred to the position owner. The permissions are void once the position token is
+    /// held by another owner.
     ///
     /// ### Authority
-    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    /// - "position_authority" - The authority that owns the position token.
     ///
     /// ### Parameters
-    /// - `liquidity_amount` - The total amount of Liquidity the user desires to withdraw.
-    /// - `token_min_a` - The minimum amount of tokenA the user is willing to withdraw.
-    /// - `token_min_b` - The minimum amount of tokenB the user is willing to withdraw.
+    /// - `permissions` - Bitmask of POSITION_DELEGATE_PERMISSION_* flags.
     ///
     /// #### Special Errors
-    /// - `LiquidityZero` - Provided liquidity amount is zero.
-    /// - `LiquidityTooHigh` - Provided liquidity exceeds u128::max.
-    /// - `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.
-    pub fn decrease_liquidity_v2<'info>(
-        ctx: Context<'_, '_, '_, 'info, ModifyLiquidityV2<'info>>,
-        liquidity_amount: u128,
-        token_min_a: u64,
-        token_min_b: u64,
-        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    /// - `InvalidPositionDelegatePermissions` - The permissions are empty or contain unknown flags.
+    pub fn initialize_position_delegate(
+        ctx: Context<InitializePositionDelegate>,
+        permissions: u8,
     ) -> Result<()> {
-        instructions::v2::decrease_liquidity::handler(
-            ctx,
-            liquidity_amount,
-            token_min_a,
-            token_min_b,
-            remaining_accounts_info,
-        )
+        instructions::initialize_position_delegate::handler(ctx, permissions)
     }
 
-    /// Add liquidity to a position in the Yevefi. This call also updates the position's accrued fees and rewards.
+    /// Replace the permissions of a delegate on a position.
     ///
     /// ### Authority
-    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    /// - "position_authority" - The authority that owns the position token.
     ///
     /// ### Parameters
-    /// - `liquidity_amount` - The total amount of Liquidity the user is willing to deposit.
-    /// - `token_max_a` - The maximum amount of tokenA the user is willing to deposit.
-    /// - `token_max_b` - The maximum amount of tokenB the user is willing to deposit.
+    /// - `permissions` - Bitmask of POSITION_DELEGATE_PERMISSION_* flags.
     ///
     /// #### Special Errors
-    /// - `LiquidityZero` - Provided liquidity amount is zero.
-    /// - `LiquidityTooHigh` - Provided liquidity exceeds u128::max.
-    /// - `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.
-    pub fn increase_liquidity_v2<'info>(
-        ctx: Context<'_, '_, '_, 'info, ModifyLiquidityV2<'info>>,
-        liquidity_amount: u128,
-        token_max_a: u64,
-        token_max_b: u64,
-        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    /// - `InvalidPositionDelegatePermissions` - The permissions are empty or contain unknown flags.
+    pub fn set_position_delegate_permissions(
+        ctx: Context<SetPositionDelegatePermissions>,
+        permissions: u8,
     ) -> Result<()> {
-        instructions::v2::increase_liquidity::handler(
-            ctx,
-            liquidity_amount,
-            token_max_a,
-            token_max_b,
-            remaining_accounts_info,
-        )
+        instructions::set_position_delegate_permissions::handler(ctx, permissions)
     }
 
-    /// Initializes a Yevefi account.
-    /// Fee rate is set to the default values on the config and supplied fee_tier.
+    /// Revoke a delegate of a position and close its PositionDelegate account.
     ///
-    /// ### Parameters
-    /// - `bumps` - The bump value when deriving the PDA of the Yevefi address.
-    /// - `tick_spacing` - The desired tick spacing for this pool.
-    /// - `initial_sqrt_price` - The desired initial sqrt-price for this pool
+    /// ### Authority
+    /// - "position_authority" - The authority that owns the position token.
+    pub fn close_position_delegate(ctx: Context<ClosePositionDelegate>) -> Result<()> {
+        instructions::close_position_delegate::handler(ctx)
+    }
+
+    /// Migrates the Yevefis created before fields were appended to their layout, reallocating
+    /// them to the current size. The Yevefis are passed as remaining accounts, so that many
+    /// pools can be migrated in one transaction. Yevefis which already have the current layout
+    /// are skipped. The appended fields are zeroed, so the features they back start out disabled.
+    /// A Yevefi must be migrated before any other instruction can use it.
+    /// The funder pays the rent for the additional space.
+    ///
+    /// Each Yevefi is followed by the reward vaults of its initialized rewards. The rewards earned
+    /// by positions before the migration are unknown, so the reward vault amounts are kept owed
+    /// to the positions and cannot be withdrawn with `withdraw_reward_surplus`. For the same
+    /// reason they do not fund emissions, which continue once tokens are added to the vaults.
     ///
     /// #### Special Errors
-    /// `InvalidTokenMintOrder` - The order of mints have to be ordered by
-    /// `SqrtPriceOutOfBounds` - provided initial_sqrt_price is not between 2^-64 to 2^64
-    ///
-    pub fn initialize_pool_v2(
-        ctx: Context<InitializePoolV2>,
-        tick_spacing: u16,
-        initial_sqrt_price: u128,
+    /// - `InvalidRewardAccounts` - A reward vault is missing or does not match its reward.
+    pub fn migrate_yevefis<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateYevefis<'info>>,
     ) -> Result<()> {
-        instructions::v2::initialize_pool::handler(ctx, tick_spacing, initial_sqrt_price)
+        instructions::migrate_yevefis::handler(ctx)
     }
 
-    /// Initialize reward for a Yevefi. A pool can only support up to a set number of rewards.
+    /// Migrates a YevefisConfig created before the pending authorities were appended to its
+    /// layout, reallocating it to the current size. The appended fields are zeroed, so no
+    /// authority transfer is pending. A YevefisConfig which already has the current layout is
+    /// left unchanged. It must be migrated before any other instruction can use it.
+    /// The funder pays the rent for the additional space.
     ///
     /// ### Authority
-    /// - "reward_authority" - assigned authority by the reward_super_authority for the specified
-    ///                        reward-index in this Yevefi
+    /// - "fee_authority" - Set authority in the YevefisConfig
+    pub fn migrate_yevefis_config(ctx: Context<MigrateYevefisConfig>) -> Result<()> {
+        instructions::migrate_yevefis_config::handler(ctx)
+    }
+
+    /// Initializes the oracle account of a Yevefi with a single observation slot.
+    /// Once initialized, every swap and liquidity change records the prior state of the pool into
+    /// the oracle, at most once per timestamp. The first observation is written on the next one.
+    /// The oracle must then be passed as writable: v1 `swap` and `two_hop_swap` clients which pass
+    /// it as read-only are rejected. Liquidity instructions without an oracle account require the
+    /// oracle PDA, initialized or not. v1 instructions take it as the second remaining account and
+    /// v2 instructions as an `Oracle` remaining accounts slice.
+    pub fn initialize_oracle(ctx: Context<InitializeOracle>) -> Result<()> {
+        instructions::initialize_oracle::handler(ctx)
+    }
+
+    /// Grows the observation ring buffer of an oracle. The funder pays the rent for the new slots.
+    /// The new slots are taken into use once the ring buffer wraps around.
     ///
     /// ### Parameters
-    /// - `reward_index` - The reward index that we'd like to initialize. (0 <= index <= NUM_REWARDS)
+    /// - `observation_cardinality_next` - The number of observation slots the oracle should hold.
     ///
     /// #### Special Errors
-    /// - `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized
-    ///                          index in this pool, or exceeds NUM_REWARDS, or
-    ///                          all reward slots for this pool has been initialized.
-    pub fn initialize_reward_v2(ctx: Context<InitializeRewardV2>, reward_index: u8) -> Result<()> {
-        instructions::v2::initialize_reward::handler(ctx, reward_index)
+    /// - `InvalidObservationCardinality` - If the cardinality does not increase, or increases by more
+    ///                                     than MAX_OBSERVATION_CARDINALITY_INCREASE.
+    pub fn increase_observation_cardinality(
+        ctx: Context<IncreaseObservationCardinality>,
+        observation_cardinality_next: u16,
+    ) -> Result<()> {
+        instructions::increase_observation_cardinality::handler(ctx, observation_cardinality_next)
     }
 
-    /// Set the reward emissions for a reward in a Yevefi.
-    ///
-    /// ### Authority
-    /// - "reward_authority" - assigned authority by the reward_super_authority for the specified
-    ///                        reward-index in this Yevefi
+    /// Returns the tick cumulative and seconds per liquidity cumulative of a Yevefi as of each
+    /// `seconds_ago` before the current timestamp. The result is an `ObserveResult` set as return data,
+    /// so the instruction can be used by other programs via CPI.
     ///
     /// ### Parameters
-    /// - `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.
-    /// - `emissions_per_second_x64` - The amount of rewards emitted in this pool.
+    /// - `seconds_agos` - The ages of the requested observations, in seconds.
     ///
     /// #### Special Errors
-    /// - `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit
-    ///                                     more than a day of desired emissions.
-    /// - `InvalidTimestamp` - Provided timestamp is not in order with the previous timestamp.
-    /// - `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized
-    ///                          index in this pool, or exceeds NUM_REWARDS, or
-    ///                          all reward slots for this pool has been initialized.
-    pub fn set_reward_emissions_v2(
-        ctx: Context<SetRewardEmissionsV2>,
-        reward_index: u8,
-        emissions_per_second_x64: u128,
-    ) -> Result<()> {
-        instructions::v2::set_reward_emissions::handler(ctx, reward_index, emissions_per_second_x64)
+    /// - `ObservationTooOld` - If a requested observation is older than the oldest observation.
+    /// - `TooManySecondsAgos` - If more than MAX_OBSERVE_SECONDS_AGOS_LEN observations are requested.
+    pub fn observe(ctx: Context<Observe>, seconds_agos: Vec<u32>) -> Result<()> {
+        instructions::observe::handler(ctx, seconds_agos)
     }
 
-    /// Perform a swap in this Yevefi
+    /// Returns the number of seconds until the funds of each reward of a Yevefi run out at its
+    /// scheduled emission rates, assuming the Yevefi has liquidity. The result is a
+    /// `RewardRunwayResult` set as return data, so the instruction can be used by other programs via CPI.
+    pub fn reward_runway(ctx: Context<RewardRunway>) -> Result<()> {
+        instructions::reward_runway::handler(ctx)
+    }
+
+    /// Initializes an adaptive_fee_tier account usable by Yevefis in a YevefiConfig space.
+    /// Yevefis initialized with an adaptive fee tier charge a base fee plus a variable fee that
+    /// grows with the volatility of the price and decays over time.
     ///
     /// ### Authority
-    /// - "token_authority" - The authority to withdraw tokens from the input token account.
+    /// - "fee_authority" - Set authority in the YevefiConfig
     ///
     /// ### Parameters
-    /// - `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).
-    /// - `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).
-    /// - `sqrt_price_limit` - The maximum/minimum price the swap will swap to.
-    /// - `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.
-    /// - `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.
+    /// - `fee_tier_index` - The index used as the tick_spacing seed of the Yevefis using this tier.
+    ///                      Must not be equal to tick_spacing, nor to the tick_spacing of a
+    ///                      FeeTier of the config.
+    /// - `tick_spacing` - The tick-spacing of the Yevefis using this tier.
+    /// - `default_base_fee_rate` - The base fee rate that a pool will use if the pool uses this
+    ///                             adaptive fee tier during initialization.
+    /// - `adaptive_fee_constants` - The parameters of the variable fee.
     ///
     /// #### Special Errors
-    /// - `ZeroTradableAmount` - User provided parameter `amount` is 0.
-    /// - `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.
-    /// - `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.
-    /// - `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.
-    /// - `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.
-    /// - `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.
-    /// - `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.
-    /// - `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.
-    pub fn swap_v2<'info>(
-        ctx: Context<'_, '_, '_, 'info, SwapV2<'info>>,
-        amount: u64,
-        other_amount_threshold: u64,
-        sqrt_price_limit: u128,
-        amount_specified_is_input: bool,
-        a_to_b: bool,
-        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    /// - `InvalidFeeTierIndex` - If fee_tier_index is equal to tick_spacing.
+    ///
+    /// The AdaptiveFeeTier shares its address with the FeeTier using fee_tier_index as
+    /// tick_spacing, so initializing one fails if the other exists.
+    /// - `InvalidTickSpacing` - If tick_spacing is 0.
+    /// - `FeeRateMaxExceeded` - If the provided default_base_fee_rate exceeds MAX_FEE_RATE.
+    /// - `InvalidAdaptiveFeeConstants` - If the provided adaptive_fee_constants are invalid.
+    pub fn initialize_adaptive_fee_tier(
+        ctx: Context<InitializeAdaptiveFeeTier>,
+        fee_tier_index: u16,
+        tick_spacing: u16,
+        default_base_fee_rate: u16,
+        adaptive_fee_constants: AdaptiveFeeConstants,
     ) -> Result<()> {
-        instructions::v2::swap::handler(
+        instructions::initialize_adaptive_fee_tier::handler(
             ctx,
-            amount,
-            other_amount_threshold,
-            sqrt_price_limit,
-            amount_specified_is_input,
-            a_to_b,
-            remaining_accounts_info,
+            fee_tier_index,
+            tick_spacing,
+            default_base_fee_rate,
+            adaptive_fee_constants,
         )
     }
 
-    /// Perform a two-hop swap in this Yevefi
+    /// Set the default_base_fee_rate for an AdaptiveFeeTier
+    /// Only the current fee authority has permission to invoke this instruction.
     ///
     /// ### Authority
-    /// - "token_authority" - The authority to withdraw tokens from the input token account.
+    /// - "fee_authority" - Set authority in the YevefiConfig
     ///
     /// ### Parameters
-    /// - `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).
-    /// - `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).
-    /// - `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.
-    /// - `a_to_b_one` - The direction of the swap of hop one. True if swapping from A to B. False if swapping from B to A.
+    /// - `default_base_fee_rate` - The base fee rate that a pool will use if the pool uses this
+    ///                             adaptive fee tier during initialization.
+    ///
+    /// #### Special Errors
+    /// - `FeeRateMaxExceeded` - If the provided default_base_fee_rate exceeds MAX_FEE_RATE.
+    pub fn set_default_base_fee_rate(
+        ctx: Context<SetDefaultBaseFeeRate>,
+        default_base_fee_rate: u16,
+    ) -> Result<()> {
+        instructions::set_default_base_fee_rate::handler(ctx, default_base_fee_rate)
+    }
+
+    /// Initializes the reward extension of a Yevefi, adding NUM_EXTENSION_REWARDS rewards on top
+    /// of the NUM_REWARDS rewards of the Yevefi. The authorities of the extension rewards are set
+    /// to the reward_emissions_super_authority.
+    ///
+    /// Once initialized, the Yevefi can only be swapped with `swap_v2` and its liquidity can only be
+    /// modified with `increase_liquidity_v2` and `decrease_liquidity_v2`, which maintain the
+    /// extension rewards through the RewardExtension remaining accounts. The following
+    /// instructions do not maintain the extension rewards and fail with
+    /// `RewardExtensionNotSupported` on the Yevefi: `swap`, `two_hop_swap`, `two_hop_swap_v2`,
+    /// `n_hop_swap_v2`, `flash_swap_v2`, `increase_liquidity`, `decrease_liquidity`,
+    /// `compound_position_fees`, `zap_in`, `zap_out` and `reposition_liquidity`.
+    /// Extension rewards are emitted only while their vaults hold unemitted funds.
+    ///
+    /// ### Authority
+    /// - "reward_emissions_super_authority" - Set authority in the YevefiConfig
+    pub fn initialize_reward_extension(ctx: Context<InitializeRewardExtension>) -> Result<()> {
+        instructions::initialize_reward_extension::handler(ctx)
+    }
+
+    /// Initializes the extension reward growths of the ticks of a TickArray.
+    /// Swaps crossing ticks of the TickArray must include the TickArrayRewardExtension once initialized.
+    ///
+    /// ### Parameters
+    /// - `start_tick_index` - The starting tick index of the TickArray.
+    ///
+    /// #### Special Errors
+    /// - `InvalidStartTick` - if the provided start tick is out of bounds or is not a multiple of
+    ///                        TICK_ARRAY_SIZE * tick spacing.
+    pub fn initialize_tick_array_reward_extension(
+        ctx: Context<InitializeTickArrayRewardExtension>,
+        start_tick_index: i32,
+    ) -> Result<()> {
+        instructions::initialize_tick_array_reward_extension::handler(ctx, start_tick_index)
+    }
+
+    /// Initializes the extension rewards of a position. The position earns extension rewards from
+    /// this point on. The TickArrayRewardExtensions of its lower and upper ticks must be initialized.
+    ///
+    /// ### Authority
+    /// - "position_authority" - The authority that owns the position token.
+    pub fn initialize_position_reward_extension(
+        ctx: Context<InitializePositionRewardExtension>,
+    ) -> Result<()> {
+        instructions::initialize_position_reward_extension::handler(ctx)
+    }
+
+    /// Update the extension rewards owed to a position.
+    pub fn update_position_reward_extension(
+        ctx: Context<UpdatePositionRewardExtension>,
+    ) -> Result<()> {
+        instructions::update_position_reward_extension::handler(ctx)
+    }
+
+    /// Close the PositionRewardExtension of a position, and stop earning extension rewards.
+    ///
+    /// ### Authority
+    /// - "position_authority" - The authority that owns the position token.
+    ///
+    /// #### Special Errors
+    /// - `ClosePositionNotEmpty` - The position has extension rewards owed.
+    pub fn close_position_reward_extension(
+        ctx: Context<ClosePositionRewardExtension>,
+    ) -> Result<()> {
+        instructions::close_position_reward_extension::handler(ctx)
+    }
+
+    /// Set the authority of an extension reward of a Yevefi.
+    ///
+    /// ### Authority
+    /// - "reward_authority" - Set authority for the extension reward.
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS) that we'd like to modify.
+    ///
+    /// #### Special Errors
+    /// - `InvalidRewardIndex` - If the provided reward index exceeds NUM_EXTENSION_REWARDS.
+    pub fn set_extension_reward_authority(
+        ctx: Context<SetExtensionRewardAuthority>,
+        reward_index: u8,
+    ) -> Result<()> {
+        instructions::set_extension_reward_authority::handler(ctx, reward_index)
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    // V2 instructions (TokenExtensions)
+    ////////////////////////////////////////////////////////////////////////////////
+
+    // TODO: update comments
+
+    /// Collect fees accrued for this position.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///                          A PositionDelegate with the collect fees permission can also authorize this call,
+    ///                          passed as a `PositionDelegate` remaining accounts slice.
+    ///
+    /// #### Special Errors
+    /// - `PositionDelegateInvalidDestination` - A PositionDelegate transfers tokens to an account not owned by the position owner.
+    pub fn collect_fees_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, CollectFeesV2<'info>>,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::collect_fees::handler(ctx, remaining_accounts_info)
+    }
+
+    /// Collect the protocol fees accrued in this Yevefi
+    ///
+    /// ### Authority
+    /// - `collect_protocol_fees_authority` - assigned authority in the YevefiConfig that can collect protocol fees
+    pub fn collect_protocol_fees_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, CollectProtocolFeesV2<'info>>,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::collect_protocol_fees::handler(ctx, remaining_accounts_info)
+    }
+
+    /// Collect rewards accrued for this position.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///                          A PositionDelegate with the collect rewards permission can also authorize this call,
+    ///                          passed as a `PositionDelegate` remaining accounts slice.
+    ///
+    /// #### Special Errors
+    /// - `PositionDelegateInvalidDestination` - A PositionDelegate transfers tokens to an account not owned by the position owner.
+    pub fn collect_reward_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, CollectRewardV2<'info>>,
+        reward_index: u8,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::collect_reward::handler(ctx, reward_index, remaining_accounts_info)
+    }
+
+    /// Collect the fees and all rewards accrued for this position.
+    /// Fee and reward growths are updated once, then every initialized reward is paid out.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///                          A PositionDelegate with the collect fees and collect rewards permissions can also authorize this call,
+    ///                          passed as a `PositionDelegate` remaining accounts slice.
+    ///
+    /// #### Special Errors
+    /// - `InvalidRewardAccounts` - The reward accounts do not match the initialized rewards of the Yevefi.
+    /// - `PositionDelegateInvalidDestination` - A PositionDelegate transfers tokens to an account not owned by the position owner.
+    pub fn harvest_position_v2<'info>(
+        ctx: Context<'_, '_, 'info, 'info, HarvestPositionV2<'info>>,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::harvest_position::handler(ctx, remaining_accounts_info)
+    }
+
+    /// Collect fees accrued for bundled positions of a PositionBundle in one call.
+    ///
+    /// ### Authority
+    /// - `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.
+    ///
+    /// ### Parameters
+    /// - `bundle_indexes` - The bundle indexes of the positions, in strictly increasing order.
+    /// - `bundled_position_bumps` - The bumps of the bundled position addresses, in the order of `bundle_indexes`.
+    ///
+    /// #### Special Errors
+    /// - `BundledPositionAlreadyClosed` - A bundled position at the provided bundle index is not opened.
+    /// - `InvalidBundledPositionAccounts` - The bundled position accounts do not match `bundle_indexes`.
+    /// - `InvalidBundledPositionBump` - The bumps in `bundled_position_bumps` do not derive the bundled position addresses.
+    pub fn collect_fees_bundled_v2<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CollectFeesBundledV2<'info>>,
+        bundle_indexes: Vec<u16>,
+        bundled_position_bumps: Vec<u8>,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::collect_fees_bundled::handler(
+            ctx,
+            bundle_indexes,
+            bundled_position_bumps,
+            remaining_accounts_info,
+        )
+    }
+
+    /// Collect rewards accrued for bundled positions of a PositionBundle in one call.
+    ///
+    /// ### Authority
+    /// - `position_bundle_authority` - authority that owns the token corresponding to this desired position bundle.
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The reward to harvest. Acceptable values are 0, 1, and 2.
+    /// - `bundle_indexes` - The bundle indexes of the positions, in strictly increasing order.
+    /// - `bundled_position_bumps` - The bumps of the bundled position addresses, in the order of `bundle_indexes`.
+    ///
+    /// #### Special Errors
+    /// - `BundledPositionAlreadyClosed` - A bundled position at the provided bundle index is not opened.
+    /// - `InvalidBundledPositionAccounts` - The bundled position accounts do not match `bundle_indexes`.
+    /// - `InvalidBundledPositionBump` - The bumps in `bundled_position_bumps` do not derive the bundled position addresses.
+    pub fn collect_reward_bundled_v2<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CollectRewardBundledV2<'info>>,
+        reward_index: u8,
+        bundle_indexes: Vec<u16>,
+        bundled_position_bumps: Vec<u8>,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::collect_reward_bundled::handler(
+            ctx,
+            reward_index,
+            bundle_indexes,
+            bundled_position_bumps,
+            remaining_accounts_info,
+        )
+    }
+
+    /// Update the accrued fees and rewards for bundled positions of a PositionBundle in one call.
+    /// Bundled positions without liquidity are skipped.
+    ///
+    /// ### Parameters
+    /// - `bundle_indexes` - The bundle indexes of the positions, in strictly increasing order.
+    /// - `bundled_position_bumps` - The bumps of the bundled position addresses, in the order of `bundle_indexes`.
+    ///
+    /// #### Special Errors
+    /// - `BundledPositionAlreadyClosed` - A bundled position at the provided bundle index is not opened.
+    /// - `InvalidBundledPositionAccounts` - The bundled position or tick array accounts do not match `bundle_indexes`.
+    /// - `InvalidBundledPositionBump` - The bumps in `bundled_position_bumps` do not derive the bundled position addresses.
+    pub fn update_fees_and_rewards_bundled<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateFeesAndRewardsBundled<'info>>,
+        bundle_indexes: Vec<u16>,
+        bundled_position_bumps: Vec<u8>,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::update_fees_and_rewards_bundled::handler(
+            ctx,
+            bundle_indexes,
+            bundled_position_bumps,
+            remaining_accounts_info,
+        )
+    }
+
+    /// Withdraw liquidity from a position in the Yevefi. This call also updates the position's accrued fees and rewards.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///                          A PositionDelegate with the decrease liquidity permission can also authorize this call,
+    ///                          passed as a `PositionDelegate` remaining accounts slice.
+    ///
+    /// ### Parameters
+    /// - `liquidity_amount` - The total amount of Liquidity the user desires to withdraw.
+    /// - `token_min_a` - The minimum amount of tokenA the user is willing to withdraw.
+    /// - `token_min_b` - The minimum amount of tokenB the user is willing to withdraw.
+    ///
+    /// #### Special Errors
+    /// - `LiquidityZero` - Provided liquidity amount is zero.
+    /// - `LiquidityTooHigh` - Provided liquidity exceeds u128::max.
+    /// - `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.
+    /// - `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.
+    /// - `InvalidPositionLock` - The PositionLock PDA of the position was not passed.
+    /// - `PositionDelegateInvalidDestination` - A PositionDelegate transfers tokens to an account not owned by the position owner.
+    /// - `InvalidRewardExtensionAccounts` - The Yevefi has a reward extension, and the RewardExtension
+    ///                                      accounts are missing or invalid.
+    /// - `InvalidOracle` - The oracle account is missing or is not the oracle PDA of the Yevefi.
+    /// - `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.
+    pub fn decrease_liquidity_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, ModifyLiquidityV2<'info>>,
+        liquidity_amount: u128,
+        token_min_a: u64,
+        token_min_b: u64,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::decrease_liquidity::handler(
+            ctx,
+            liquidity_amount,
+            token_min_a,
+            token_min_b,
+            remaining_accounts_info,
+        )
+    }
+
+    /// Add liquidity to a position in the Yevefi. This call also updates the position's accrued fees and rewards.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///                          A PositionDelegate with the increase liquidity permission can also authorize this call,
+    ///                          passed as a `PositionDelegate` remaining accounts slice.
+    ///
+    /// ### Parameters
+    /// - `liquidity_amount` - The total amount of Liquidity the user is willing to deposit.
+    /// - `token_max_a` - The maximum amount of tokenA the user is willing to deposit.
+    /// - `token_max_b` - The maximum amount of tokenB the user is willing to deposit.
+    ///
+    /// #### Special Errors
+    /// - `LiquidityZero` - Provided liquidity amount is zero.
+    /// - `LiquidityTooHigh` - Provided liquidity exceeds u128::max.
+    /// - `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.
+    /// - `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.
+    /// - `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.
+    /// - `InvalidRewardExtensionAccounts` - The Yevefi has a reward extension, and the RewardExtension
+    ///                                      accounts are missing or invalid.
+    /// - `InvalidOracle` - The oracle account is missing or is not the oracle PDA of the Yevefi.
+    /// - `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.
+    pub fn increase_liquidity_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, ModifyLiquidityV2<'info>>,
+        liquidity_amount: u128,
+        token_max_a: u64,
+        token_max_b: u64,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::increase_liquidity::handler(
+            ctx,
+            liquidity_amount,
+            token_max_a,
+            token_max_b,
+            remaining_accounts_info,
+        )
+    }
+
+    /// Add liquidity to a position in the Yevefi, computing the liquidity on-chain from token budgets.
+    /// The largest liquidity whose deposit fits both budgets at the current price is added.
+    /// This call also updates the position's accrued fees and rewards.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///
+    /// ### Parameters
+    /// - `token_max_a` - The maximum amount of tokenA the user is willing to deposit.
+    /// - `token_max_b` - The maximum amount of tokenB the user is willing to deposit.
+    ///
+    /// #### Special Errors
+    /// - `LiquidityZero` - The budgets are too small to fund any liquidity.
+    /// - `LiquidityTooHigh` - The computed liquidity exceeds u128::max.
+    /// - `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.
+    /// - `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.
+    /// - `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.
+    /// - `InvalidRewardExtensionAccounts` - The Yevefi has a reward extension, and the RewardExtension
+    ///                                      accounts are missing or invalid.
+    /// - `InvalidOracle` - The oracle account is missing or is not the oracle PDA of the Yevefi.
+    /// - `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.
+    pub fn increase_liquidity_by_token_amounts_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, ModifyLiquidityV2<'info>>,
+        token_max_a: u64,
+        token_max_b: u64,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::increase_liquidity_by_token_amounts::handler(
+            ctx,
+            token_max_a,
+            token_max_b,
+            remaining_accounts_info,
+        )
+    }
+
+    /// Deposit a single token into a position in the Yevefi.
+    /// Part of the input token is swapped in the same Yevefi, and the remainder and the swap output
+    /// are added as liquidity at the post-swap price. The split is solved on-chain with a bounded
+    /// number of simulated swaps, and the part of the swap output that cannot be deposited is
+    /// returned to the owner.
+    /// This call also updates the position's accrued fees and rewards.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position
+    ///                          and the input token account.
+    ///
+    /// ### Parameters
+    /// - `amount` - The maximum amount of the input token the user is willing to deposit.
+    /// - `a_to_b` - The input token is tokenA if true, tokenB otherwise.
+    /// - `min_liquidity` - The minimum amount of liquidity the user is willing to accept.
+    ///
+    /// #### Special Errors
+    /// - `ZeroTradableAmount` - User provided parameter `amount` is 0.
+    /// - `LiquidityZero` - The input amount is too small to fund any liquidity.
+    /// - `LiquidityBelowMinimum` - The added liquidity is below the user defined minimum.
+    /// - `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.
+    /// - `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.
+    /// - `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.
+    /// - `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.
+    /// - `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.
+    pub fn zap_in<'info>(
+        ctx: Context<'_, '_, '_, 'info, ZapIn<'info>>,
+        amount: u64,
+        a_to_b: bool,
+        min_liquidity: u128,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::zap_in::handler(
+            ctx,
+            amount,
+            a_to_b,
+            min_liquidity,
+            remaining_accounts_info,
+        )
+    }
+
+    /// Withdraw a position into a single token.
+    /// Liquidity is removed from the position and its fees owed are collected, then all of the
+    /// unwanted token is swapped in the same Yevefi. Only the output token is transferred to the owner.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///
+    /// ### Parameters
+    /// - `liquidity_amount` - The amount of liquidity to remove, or all liquidity of the position if None.
+    /// - `a_to_b` - The output token is tokenB if true, tokenA otherwise.
+    /// - `min_amount_out` - The minimum amount of the output token the user is willing to receive.
+    ///
+    /// #### Special Errors
+    /// - `LiquidityZero` - The amount of liquidity to remove is 0.
+    /// - `PartialFillError` - The unwanted token cannot be swapped in full with the supplied tick arrays.
+    /// - `AmountOutBelowMinimum` - The amount of the output token is below the user defined minimum.
+    /// - `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.
+    /// - `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.
+    /// - `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.
+    /// - `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.
+    /// - `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.
+    pub fn zap_out<'info>(
+        ctx: Context<'_, '_, '_, 'info, ZapOut<'info>>,
+        liquidity_amount: Option<u128>,
+        a_to_b: bool,
+        min_amount_out: u64,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::zap_out::handler(
+            ctx,
+            liquidity_amount,
+            a_to_b,
+            min_amount_out,
+            remaining_accounts_info,
+        )
+    }
+
+    /// Re-range a position with a new size in one step.
+    /// All liquidity is removed from the current range, the position is moved to the new range,
+    /// and `liquidity_amount` is added to it, which may be more or less than was removed.
+    /// Only the difference between the removed and added token amounts is transferred.
+    /// Fees and rewards accrued so far remain owed to the position.
+    /// The TokenMetadata of a position opened with token extensions is refreshed to the new range,
+    /// with the funder paying the rent if its fields grow.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///
+    /// ### Parameters
+    /// - `new_tick_lower_index` - The tick specifying the lower end of the new position range.
+    /// - `new_tick_upper_index` - The tick specifying the upper end of the new position range.
+    /// - `liquidity_amount` - The amount of Liquidity to add to the new range.
+    /// - `token_max_a` - The maximum amount of tokenA the user is willing to deposit on top of the removed amount.
+    /// - `token_max_b` - The maximum amount of tokenB the user is willing to deposit on top of the removed amount.
+    /// - `token_min_a` - The minimum amount of tokenA the user is willing to withdraw, net of the added amount.
+    /// - `token_min_b` - The minimum amount of tokenB the user is willing to withdraw, net of the added amount.
+    ///
+    /// #### Special Errors
+    /// - `LiquidityZero` - Provided liquidity amount is zero.
+    /// - `InvalidTickIndex` - If a provided tick is out of bounds, out of order or not a multiple of
+    ///                        the tick-spacing in this pool.
+    /// - `SameTickRangeNotAllowed` - The new tick range is the current tick range.
+    /// - `TokenMaxExceeded` - The required token to perform this operation exceeds the user defined amount.
+    /// - `TokenMinSubceeded` - The required token to perform this operation subceeds the user defined amount.
+    /// - `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.
+    /// - `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.
+    /// - `PositionLocked` - The position is locked by a PositionLock until its unlock timestamp.
+    /// - `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.
+    /// - `OracleNotWritable` - The oracle of the Yevefi is initialized but was passed as read-only.
+    #[allow(clippy::too_many_arguments)]
+    pub fn reposition_liquidity<'info>(
+        ctx: Context<'_, '_, '_, 'info, RepositionLiquidity<'info>>,
+        new_tick_lower_index: i32,
+        new_tick_upper_index: i32,
+        liquidity_amount: u128,
+        token_max_a: u64,
+        token_max_b: u64,
+        token_min_a: u64,
+        token_min_b: u64,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::reposition_liquidity::handler(
+            ctx,
+            new_tick_lower_index,
+            new_tick_upper_index,
+            liquidity_amount,
+            token_max_a,
+            token_max_b,
+            token_min_a,
+            token_min_b,
+            remaining_accounts_info,
+        )
+    }
+
+    /// Initializes a Yevefi account.
+    /// Fee rate is set to the default values on the config and supplied fee_tier.
+    ///
+    /// ### Parameters
+    /// - `bumps` - The bump value when deriving the PDA of the Yevefi address.
+    /// - `tick_spacing` - The desired tick spacing for this pool.
+    /// - `initial_sqrt_price` - The desired initial sqrt-price for this pool
+    ///
+    /// #### Special Errors
+    /// `InvalidTokenMintOrder` - The order of mints have to be ordered by
+    /// `SqrtPriceOutOfBounds` - provided initial_sqrt_price is not between 2^-64 to 2^64
+    ///
+    pub fn initialize_pool_v2(
+        ctx: Context<InitializePoolV2>,
+        tick_spacing: u16,
+        initial_sqrt_price: u128,
+    ) -> Result<()> {
+        instructions::v2::initialize_pool::handler(ctx, tick_spacing, initial_sqrt_price)
+    }
+
+    /// Initialize reward for a Yevefi. A pool can only support up to a set number of rewards.
+    ///
+    /// ### Authority
+    /// - "reward_authority" - assigned authority by the reward_super_authority for the specified
+    ///                        reward-index in this Yevefi
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The reward index that we'd like to initialize. (0 <= index <= NUM_REWARDS)
+    ///
+    /// #### Special Errors
+    /// - `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized
+    ///                          index in this pool, or exceeds NUM_REWARDS, or
+    ///                          all reward slots for this pool has been initialized.
+    pub fn initialize_reward_v2(ctx: Context<InitializeRewardV2>, reward_index: u8) -> Result<()> {
+        instructions::v2::initialize_reward::handler(ctx, reward_index)
+    }
+
+    /// Set the reward emissions for a reward in a Yevefi.
+    /// The emission schedule of the reward, if any, is replaced by the constant emission rate.
+    /// The reward vault funds the emissions from now on, which stop once the funds run out.
+    ///
+    /// ### Authority
+    /// - "reward_authority" - assigned authority by the reward_super_authority for the specified
+    ///                        reward-index in this Yevefi
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.
+    /// - `emissions_per_second_x64` - The amount of rewards emitted in this pool.
+    ///
+    /// #### Special Errors
+    /// - `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit
+    ///                                     more than a day of desired emissions.
+    /// - `InvalidTimestamp` - Provided timestamp is not in order with the previous timestamp.
+    /// - `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized
+    ///                          index in this pool, or exceeds NUM_REWARDS, or
+    ///                          all reward slots for this pool has been initialized.
+    pub fn set_reward_emissions_v2(
+        ctx: Context<SetRewardEmissionsV2>,
+        reward_index: u8,
+        emissions_per_second_x64: u128,
+    ) -> Result<()> {
+        instructions::v2::set_reward_emissions::handler(ctx, reward_index, emissions_per_second_x64)
+    }
+
+    /// Set an emission schedule for a reward in a Yevefi, so that emissions start, change and stop
+    /// automatically. Rewards are accrued under the previous schedule until the current timestamp.
+    /// `set_reward_emissions` replaces the schedule with a constant emission rate.
+    /// The reward vault funds the emissions from now on, which stop once the funds run out.
+    ///
+    /// ### Authority
+    /// - "reward_authority" - assigned authority by the reward_super_authority for the specified
+    ///                        reward-index in this Yevefi
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The reward index (0 <= index <= NUM_REWARDS) that we'd like to modify.
+    /// - `emissions_per_second_x64` - The amount of rewards emitted in this pool from `start_timestamp`.
+    /// - `start_timestamp` - No rewards are emitted before this timestamp.
+    /// - `end_timestamp` - No rewards are emitted from this timestamp on. 0 if the emissions have no end.
+    /// - `rate_changes` - Up to MAX_REWARD_EMISSIONS_RATE_CHANGES emission rate changes, in ascending
+    ///                    timestamp order, between `start_timestamp` and `end_timestamp`.
+    ///
+    /// #### Special Errors
+    /// - `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit
+    ///                                     more than a day of the highest emissions of the schedule.
+    /// - `InvalidRewardEmissionsSchedule` - The timestamps of the schedule are not in order, or there
+    ///                                      are too many rate changes.
+    /// - `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized
+    ///                          index in this pool, or exceeds NUM_REWARDS, or
+    ///                          all reward slots for this pool has been initialized.
+    pub fn set_reward_emissions_schedule(
+        ctx: Context<SetRewardEmissionsSchedule>,
+        reward_index: u8,
+        emissions_per_second_x64: u128,
+        start_timestamp: u64,
+        end_timestamp: u64,
+        rate_changes: Vec<RewardEmissionsRateChange>,
+    ) -> Result<()> {
+        instructions::v2::set_reward_emissions_schedule::handler(
+            ctx,
+            reward_index,
+            emissions_per_second_x64,
+            start_timestamp,
+            end_timestamp,
+            rate_changes,
+        )
+    }
+
+    /// Deposit tokens into the vault of a reward. Anyone can fund a reward.
+    /// The amount received by the vault is recorded as funds, which are emitted from now on at the
+    /// emission rate set by the reward authority.
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The reward index (0 <= index <= NUM_REWARDS) to fund.
+    /// - `amount` - The amount of reward tokens to deposit, transfer fee included.
+    /// - `extend_emissions` - If true, the end of the emission schedule is moved back by the time
+    ///                        the received amount lasts at the emission rate before the end.
+    ///
+    /// #### Special Errors
+    /// - `RewardEmissionsNotExtendable` - If `extend_emissions` is set, but the emissions have no end
+    ///                                    or have already ended.
+    pub fn fund_reward<'info>(
+        ctx: Context<'_, '_, '_, 'info, FundReward<'info>>,
+        reward_index: u8,
+        amount: u64,
+        extend_emissions: bool,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::fund_reward::handler(
+            ctx,
+            reward_index,
+            amount,
+            extend_emissions,
+            remaining_accounts_info,
+        )
+    }
+
+    /// Withdraw tokens from the vault of a reward, up to the surplus over the rewards owed to positions.
+    /// The surplus is the funds of the reward not emitted yet, so that all positions can still
+    /// collect the rewards emitted to them, rounded up. The withdrawn amount no longer funds emissions.
+    ///
+    /// ### Authority
+    /// - "reward_authority" - assigned authority by the reward_super_authority for the specified
+    ///                        reward-index in this Yevefi
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The reward index (0 <= index <= NUM_REWARDS) to withdraw from.
+    /// - `amount` - The amount of reward tokens to withdraw.
+    ///
+    /// #### Special Errors
+    /// - `RewardSurplusInsufficient` - The amount exceeds the surplus of the reward vault.
+    pub fn withdraw_reward_surplus<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawRewardSurplus<'info>>,
+        reward_index: u8,
+        amount: u64,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::withdraw_reward_surplus::handler(
+            ctx,
+            reward_index,
+            amount,
+            remaining_accounts_info,
+        )
+    }
+
+    /// Initialize an extension reward for a Yevefi with a reward extension.
+    ///
+    /// ### Authority
+    /// - "reward_authority" - assigned authority of the extension reward at the specified reward-index.
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The extension reward index that we'd like to initialize. (0 <= index < NUM_EXTENSION_REWARDS)
+    ///
+    /// #### Special Errors
+    /// - `InvalidRewardIndex` - If the provided reward index doesn't match the lowest uninitialized
+    ///                          index of the reward extension, or exceeds NUM_EXTENSION_REWARDS.
+    pub fn initialize_extension_reward(
+        ctx: Context<InitializeExtensionReward>,
+        reward_index: u8,
+    ) -> Result<()> {
+        instructions::v2::initialize_extension_reward::handler(ctx, reward_index)
+    }
+
+    /// Set the emissions of an extension reward of a Yevefi.
+    ///
+    /// ### Authority
+    /// - "reward_authority" - assigned authority of the extension reward at the specified reward-index.
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS) that we'd like to modify.
+    /// - `emissions_per_second_x64` - The amount of rewards emitted in this pool.
+    ///
+    /// #### Special Errors
+    /// - `RewardVaultAmountInsufficient` - The amount of rewards in the reward vault cannot emit
+    ///                                     more than a day of desired emissions.
+    /// - `InvalidTimestamp` - Provided timestamp is not in order with the previous timestamp.
+    /// - `InvalidRewardIndex` - If the provided reward index exceeds NUM_EXTENSION_REWARDS.
+    pub fn set_extension_reward_emissions(
+        ctx: Context<SetExtensionRewardEmissions>,
+        reward_index: u8,
+        emissions_per_second_x64: u128,
+    ) -> Result<()> {
+        instructions::v2::set_extension_reward_emissions::handler(
+            ctx,
+            reward_index,
+            emissions_per_second_x64,
+        )
+    }
+
+    /// Collect the extension rewards owed to a position.
+    ///
+    /// ### Authority
+    /// - `position_authority` - authority that owns the token corresponding to this desired position.
+    ///                          A PositionDelegate with the collect rewards permission can also authorize this call,
+    ///                          passed as a `PositionDelegate` remaining accounts slice.
+    ///
+    /// ### Parameters
+    /// - `reward_index` - The extension reward index (0 <= index < NUM_EXTENSION_REWARDS) to collect.
+    ///
+    /// #### Special Errors
+    /// - `PositionDelegateInvalidDestination` - A PositionDelegate transfers tokens to an account not owned by the position owner.
+    pub fn collect_extension_reward<'info>(
+        ctx: Context<'_, '_, '_, 'info, CollectExtensionReward<'info>>,
+        reward_index: u8,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::collect_extension_reward::handler(
+            ctx,
+            reward_index,
+            remaining_accounts_info,
+        )
+    }
+
+    /// Perform a swap in this Yevefi
+    ///
+    /// ### Authority
+    /// - "token_authority" - The authority to withdraw tokens from the input token account.
+    ///
+    /// ### Parameters
+    /// - `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).
+    /// - `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).
+    /// - `sqrt_price_limit` - The maximum/minimum price the swap will swap to.
+    /// - `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.
+    /// - `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.
+    ///
+    /// #### Special Errors
+    /// - `ZeroTradableAmount` - User provided parameter `amount` is 0.
+    /// - `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.
+    /// - `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.
+    /// - `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.
+    /// - `TickArraySequenceInvalidIndex` - The swap loop attempted to access an invalid array index during the query of the next initialized tick.
+    /// - `TickArrayIndexOutofBounds` - The swap loop attempted to access an invalid array index during tick crossing.
+    /// - `LiquidityOverflow` - Liquidity value overflowed 128bits during tick crossing.
+    /// - `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.
+    /// - `InvalidReferralAccounts` - The referral accounts are not the YevefisConfig of the pool and
+    ///                               a Referrer of it, followed by a writable token account of the
+    ///                               input mint owned by the referrer.
+    /// - `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.
+    /// - `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.
+    /// - `InvalidRewardExtensionAccounts` - The Yevefi has a reward extension, and the RewardExtension
+    ///                                      accounts are missing or do not cover the crossed ticks.
+    pub fn swap_v2<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SwapV2<'info>>,
+        amount: u64,
+        other_amount_threshold: u64,
+        sqrt_price_limit: u128,
+        amount_specified_is_input: bool,
+        a_to_b: bool,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::swap::handler(
+            ctx,
+            amount,
+            other_amount_threshold,
+            sqrt_price_limit,
+            amount_specified_is_input,
+            a_to_b,
+            remaining_accounts_info,
+        )
+    }
+
+    /// Perform a two-hop swap in this Yevefi
+    ///
+    /// ### Authority
+    /// - "token_authority" - The authority to withdraw tokens from the input token account.
+    ///
+    /// ### Parameters
+    /// - `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).
+    /// - `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).
+    /// - `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.
+    /// - `a_to_b_one` - The direction of the swap of hop one. True if swapping from A to B. False if swapping from B to A.
     /// - `a_to_b_two` - The direction of the swap of hop two. True if swapping from A to B. False if swapping from B to A.
     /// - `sqrt_price_limit_one` - The maximum/minimum price the swap will swap to in the first hop.
     /// - `sqrt_price_limit_two` - The maximum/minimum price the swap will swap to in the second hop.
@@ -850,9 +1833,15 @@ pub mod yevefi {
     /// - `InvalidTickSpacing` - The swap pool was initialized with tick-spacing of 0.
     /// - `InvalidIntermediaryMint` - Error if the intermediary mint between hop one and two do not equal.
     /// - `DuplicateTwoHopPool` - Error if yevefi one & two are the same pool.
+    /// - `InvalidReferralAccounts` - The referral accounts are not the YevefisConfig shared by both
+    ///                               pools and a Referrer of it, followed by writable token accounts
+    ///                               of the input and intermediate mints owned by the referrer.
+    /// - `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.
+    /// - `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.
+    /// - `RewardExtensionNotSupported` - The reward extension of one of the Yevefis is initialized.
     #[allow(clippy::too_many_arguments)]
     pub fn two_hop_swap_v2<'info>(
-        ctx: Context<'_, '_, '_, 'info, TwoHopSwapV2<'info>>,
+        ctx: Context<'_, '_, 'info, 'info, TwoHopSwapV2<'info>>,
         amount: u64,
         other_amount_threshold: u64,
         amount_specified_is_input: bool,
@@ -875,18 +1864,239 @@ pub mod yevefi {
         )
     }
 
+    /// Simulate a swap in this Yevefi without writing to any account.
+    /// The result is a `SwapQuote` set as return data, so the instruction can be used by other
+    /// programs via CPI to learn the outcome of a swap before executing it.
+    /// If referral accounts are supplied, the quote includes the referral fee of the swap.
+    ///
+    /// ### Parameters
+    /// - `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).
+    /// - `sqrt_price_limit` - The maximum/minimum price the swap will swap to.
+    /// - `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.
+    /// - `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.
+    ///
+    /// #### Special Errors
+    /// Same as `swap_v2`, except that tick-arrays don't need to be writable.
+    pub fn quote_swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, QuoteSwap<'info>>,
+        amount: u64,
+        sqrt_price_limit: u128,
+        amount_specified_is_input: bool,
+        a_to_b: bool,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::quote_swap::handler(
+            ctx,
+            amount,
+            sqrt_price_limit,
+            amount_specified_is_input,
+            a_to_b,
+            remaining_accounts_info,
+        )
+    }
+
+    /// Perform a swap along a route of up to four Yevefis.
+    /// Intermediate tokens are transferred directly from vault to vault.
+    ///
+    /// ### Authority
+    /// - "token_authority" - The authority to withdraw tokens from the input token account.
+    ///
+    /// ### Parameters
+    /// - `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).
+    /// - `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).
+    /// - `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.
+    /// - `route` - The direction and the maximum/minimum price of each hop, in route order.
+    ///
+    /// #### Special Errors
+    /// - `ZeroTradableAmount` - User provided parameter `amount` is 0.
+    /// - `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.
+    /// - `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.
+    /// - `InvalidTickArraySequence` - User provided tick-arrays are not in sequential order required to proceed in this trade direction.
+    /// - `InvalidSwapRouteLength` - User provided parameter `route` is empty or has more than four hops.
+    /// - `InvalidIntermediaryMint` - Error if the output mint of a hop does not equal the input mint of the next hop.
+    /// - `DuplicateTwoHopPool` - Error if the same yevefi appears more than once in the route.
+    /// - `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.
+    /// - `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.
+    /// - `RewardExtensionNotSupported` - The reward extension of one of the Yevefis is initialized.
+    pub fn n_hop_swap_v2<'info>(
+        ctx: Context<'_, '_, 'info, 'info, NHopSwapV2<'info>>,
+        amount: u64,
+        other_amount_threshold: u64,
+        amount_specified_is_input: bool,
+        route: Vec<SwapRouteHop>,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::n_hop_swap::handler(
+            ctx,
+            amount,
+            other_amount_threshold,
+            amount_specified_is_input,
+            route,
+            remaining_accounts_info,
+        )
+    }
+
+    /// Borrow tokens from the vaults of this Yevefi and repay them with a fee within the same instruction.
+    /// The borrowed tokens are transferred to the token owner accounts, then `yevefi_flash_loan_callback`
+    /// of the callback program is invoked, then the borrowed amounts plus fees are transferred back
+    /// from the token owner accounts.
+    ///
+    /// ### Authority
+    /// - "token_authority" - The authority to withdraw the repayment from the token owner accounts.
+    ///
+    /// ### Parameters
+    /// - `amount_a` - The amount of token A to borrow.
+    /// - `amount_b` - The amount of token B to borrow.
+    /// - `callback_data` - Arbitrary data passed through to the callback instruction.
+    ///
+    /// #### Special Errors
+    /// - `ZeroTradableAmount` - User provided parameters `amount_a` and `amount_b` are both 0.
+    /// - `YevefiLocked` - A flash loan or flash swap of this Yevefi is already in progress.
+    /// - `FlashNotRepaid` - The vaults hold less than before the loan plus fees after repayment.
+    /// - `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.
+    /// - `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.
+    pub fn flash_loan_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, FlashLoanV2<'info>>,
+        amount_a: u64,
+        amount_b: u64,
+        callback_data: Vec<u8>,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::flash_loan::handler(
+            ctx,
+            amount_a,
+            amount_b,
+            callback_data,
+            remaining_accounts_info,
+        )
+    }
+
+    /// Perform a swap in this Yevefi, receiving the output before paying the input.
+    /// The output is transferred to the token owner account, then `yevefi_flash_swap_callback`
+    /// of the callback program is invoked, then the input is transferred from the token owner account.
+    ///
+    /// ### Authority
+    /// - "token_authority" - The authority to withdraw tokens from the input token account.
+    ///
+    /// ### Parameters
+    /// - `amount` - The amount of input or output token to swap from (depending on amount_specified_is_input).
+    /// - `other_amount_threshold` - The maximum/minimum of input/output token to swap into (depending on amount_specified_is_input).
+    /// - `sqrt_price_limit` - The maximum/minimum price the swap will swap to.
+    /// - `amount_specified_is_input` - Specifies the token the parameter `amount`represents. If true, the amount represents the input token of the swap.
+    /// - `a_to_b` - The direction of the swap. True if swapping from A to B. False if swapping from B to A.
+    /// - `callback_data` - Arbitrary data passed through to the callback instruction.
+    ///
+    /// #### Special Errors
+    /// - `ZeroTradableAmount` - User provided parameter `amount` is 0.
+    /// - `InvalidSqrtPriceLimitDirection` - User provided parameter `sqrt_price_limit` does not match the direction of the trade.
+    /// - `SqrtPriceOutOfBounds` - User provided parameter `sqrt_price_limit` is over Whirlppool's max/min bounds for sqrt-price.
+    /// - `YevefiLocked` - A flash loan or flash swap of this Yevefi is already in progress.
+    /// - `FlashNotRepaid` - The input vault did not receive the input amount of the swap.
+    /// - `YevefiPaused` - The Yevefi, or all Yevefis of its YevefisConfig, are paused.
+    /// - `InvalidConfigExtension` - The config extension account is missing or is not the config extension PDA of the YevefisConfig.
+    /// - `RewardExtensionNotSupported` - The reward extension of the Yevefi is initialized.
+    #[allow(clippy::too_many_arguments)]
+    pub fn flash_swap_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, FlashSwapV2<'info>>,
+        amount: u64,
+        other_amount_threshold: u64,
+        sqrt_price_limit: u128,
+        amount_specified_is_input: bool,
+        a_to_b: bool,
+        callback_data: Vec<u8>,
+        remaining_accounts_info: Option<RemainingAccountsInfo>,
+    ) -> Result<()> {
+        instructions::v2::flash_swap::handler(
+            ctx,
+            amount,
+            other_amount_threshold,
+            sqrt_price_limit,
+            amount_specified_is_input,
+            a_to_b,
+            callback_data,
+            remaining_accounts_info,
+        )
+    }
+
+    /// Initializes a Yevefi account with adaptive fee enabled.
+    /// Tick spacing, base fee rate and adaptive fee constants are set from the supplied
+    /// adaptive_fee_tier, and the fee_tier_index is used as the tick_spacing seed of the Yevefi.
+    ///
+    /// ### Parameters
+    /// - `initial_sqrt_price` - The desired initial sqrt-price for this pool
+    ///
+    /// #### Special Errors
+    /// `InvalidTokenMintOrder` - The order of mints have to be ordered by
+    /// `SqrtPriceOutOfBounds` - provided initial_sqrt_price is not between 2^-64 to 2^64
+    ///
+    pub fn initialize_pool_with_adaptive_fee_v2(
+        ctx: Context<InitializePoolWithAdaptiveFeeV2>,
+        initial_sqrt_price: u128,
+    ) -> Result<()> {
+        instructions::v2::initialize_pool_with_adaptive_fee::handler(ctx, initial_sqrt_price)
+    }
+
     pub fn initialize_config_extension(ctx: Context<InitializeConfigExtension>) -> Result<()> {
         instructions::v2::initialize_config_extension::handler(ctx)
     }
 
+    /// Proposes a new config extension authority, which takes over once it signs
+    /// `accept_config_extension_authority`.
+    /// Proposing the default Pubkey cancels a pending transfer.
     pub fn set_config_extension_authority(ctx: Context<SetConfigExtensionAuthority>) -> Result<()> {
         instructions::v2::set_config_extension_authority::handler(ctx)
     }
 
+    pub fn accept_config_extension_authority(
+        ctx: Context<AcceptConfigExtensionAuthority>,
+    ) -> Result<()> {
+        instructions::v2::accept_config_extension_authority::handler(ctx)
+    }
+
+    /// Proposes a new token badge authority, which takes over once it signs
+    /// `accept_token_badge_authority`.
+    /// Proposing the default Pubkey cancels a pending transfer.
     pub fn set_token_badge_authority(ctx: Context<SetTokenBadgeAuthority>) -> Result<()> {
         instructions::v2::set_token_badge_authority::handler(ctx)
     }
 
+    pub fn accept_token_badge_authority(ctx: Context<AcceptTokenBadgeAuthority>) -> Result<()> {
+        instructions::v2::accept_token_badge_authority::handler(ctx)
+    }
+
+    pub fn set_pause_authority(ctx: Context<SetPauseAuthority>) -> Result<()> {
+        instructions::v2::set_pause_authority::handler(ctx)
+    }
+
+    /// Pause or unpause a Yevefi.
+    /// While paused, swaps, flash loans and liquidity increases are rejected,
+    /// but liquidity can still be decreased and fees and rewards collected.
+    ///
+    /// ### Authority
+    /// - "pause_authority" - Set authority in the YevefisConfigExtension
+    ///
+    /// ### Parameters
+    /// - `paused` - Whether the Yevefi is paused
+    pub fn set_pool_paused(ctx: Context<SetPoolPaused>, paused: bool) -> Result<()> {
+        instructions::v2::set_pool_paused::handler(ctx, paused)
+    }
+
+    /// Pause or unpause all Yevefis of a YevefisConfig.
+    /// While paused, swaps, flash loans and liquidity increases are rejected,
+    /// but liquidity can still be decreased and fees and rewards collected.
+    /// Instructions that check the pause require the YevefisConfigExtension PDA of the
+    /// YevefisConfig, initialized or not. v1 instructions take it as the first remaining account
+    /// and v2 instructions as a `ConfigExtension` remaining accounts slice.
+    ///
+    /// ### Authority
+    /// - "pause_authority" - Set authority in the YevefisConfigExtension
+    ///
+    /// ### Parameters
+    /// - `paused` - Whether the Yevefis of the config are paused
+    pub fn set_global_paused(ctx: Context<SetGlobalPaused>, paused: bool) -> Result<()> {
+        instructions::v2::set_global_paused::handler(ctx, paused)
+    }
+
     pub fn initialize_token_badge(ctx: Context<InitializeTokenBadge>) -> Result<()> {
         instructions::v2::initialize_token_badge::handler(ctx)
     }
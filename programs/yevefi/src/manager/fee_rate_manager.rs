@@ -0,0 +1,394 @@
+use crate::{
+    math::{sqrt_price_from_tick_index, MAX_FEE_RATE},
+    state::{
+        AdaptiveFeeInfo, ADAPTIVE_FEE_CONTROL_FACTOR_DENOMINATOR, MAX_TICK_INDEX, MIN_TICK_INDEX,
+        REDUCTION_FACTOR_DENOMINATOR, VOLATILITY_ACCUMULATOR_SCALE_FACTOR,
+    },
+};
+
+// Tracks the fee rate of a swap.
+// Without adaptive fee, the fee rate is the static fee rate of the yevefi.
+// With adaptive fee, a variable fee based on how far the price has moved away from the
+// reference tick group is added to the base fee rate, and is updated as the swap moves the price.
+pub struct FeeRateManager {
+    base_fee_rate: u16,
+    tick_spacing: u16,
+    adaptive_fee_info: AdaptiveFeeInfo,
+}
+
+impl FeeRateManager {
+    pub fn new(
+        base_fee_rate: u16,
+        tick_spacing: u16,
+        tick_current_index: i32,
+        adaptive_fee_info: AdaptiveFeeInfo,
+        timestamp: u64,
+    ) -> Self {
+        let mut fee_rate_manager = Self {
+            base_fee_rate,
+            tick_spacing: tick_spacing.max(1),
+            adaptive_fee_info,
+        };
+
+        if adaptive_fee_info.is_enabled() {
+            fee_rate_manager.update_reference(tick_current_index, timestamp);
+            fee_rate_manager.update_volatility_accumulator(tick_current_index);
+        }
+
+        fee_rate_manager
+    }
+
+    fn tick_group_index(&self, tick_index: i32) -> i32 {
+        tick_index.div_euclid(self.tick_spacing as i32)
+    }
+
+    // Frequent swaps keep the references, so that the volatility keeps accumulating.
+    // After filter_period the accumulated volatility is reduced and carried over as the reference,
+    // and after decay_period it is forgotten.
+    fn update_reference(&mut self, tick_current_index: i32, timestamp: u64) {
+        let tick_group_index = self.tick_group_index(tick_current_index);
+        let constants = self.adaptive_fee_info.constants;
+        let variables = &mut self.adaptive_fee_info.variables;
+
+        let elapsed = timestamp.saturating_sub(variables.last_reference_update_timestamp);
+        if elapsed < u64::from(constants.filter_period) {
+            return;
+        }
+
+        variables.volatility_reference = if elapsed < u64::from(constants.decay_period) {
+            (u64::from(variables.volatility_accumulator) * u64::from(constants.reduction_factor)
+                / u64::from(REDUCTION_FACTOR_DENOMINATOR)) as u32
+        } else {
+            0
+        };
+        variables.tick_group_index_reference = tick_group_index;
+        variables.last_reference_update_timestamp = timestamp;
+    }
+
+    pub fn update_volatility_accumulator(&mut self, tick_current_index: i32) {
+        if !self.adaptive_fee_info.is_enabled() {
+            return;
+        }
+
+        self.update_volatility_accumulator_for_tick_group(
+            self.tick_group_index(tick_current_index),
+        );
+    }
+
+    fn update_volatility_accumulator_for_tick_group(&mut self, tick_group_index: i32) {
+        let constants = self.adaptive_fee_info.constants;
+        let variables = &mut self.adaptive_fee_info.variables;
+
+        let tick_group_delta = (i64::from(tick_group_index)
+            - i64::from(variables.tick_group_index_reference))
+        .unsigned_abs();
+        let volatility_accumulator = u64::from(variables.volatility_reference).saturating_add(
+            tick_group_delta.saturating_mul(u64::from(VOLATILITY_ACCUMULATOR_SCALE_FACTOR)),
+        );
+
+        variables.volatility_accumulator =
+            volatility_accumulator.min(u64::from(constants.max_volatility_accumulator)) as u32;
+    }
+
+    // Prepares the fee rate for the next swap step and returns its sqrt price target.
+    // With adaptive fee, a swap step must not cross a tick group boundary, so that every step is
+    // charged the fee rate of the tick group it trades in.
+    // The target is only left unbounded while the volatility accumulator is capped and the price
+    // moves away from the reference, as the fee rate cannot change until the price turns around.
+    pub fn update_for_swap_step(
+        &mut self,
+        curr_sqrt_price: u128,
+        curr_tick_index: i32,
+        sqrt_price_target: u128,
+        a_to_b: bool,
+    ) -> u128 {
+        if !self.adaptive_fee_info.is_enabled() {
+            return sqrt_price_target;
+        }
+
+        let tick_spacing = self.tick_spacing as i32;
+        let tick_group_index = self.tick_group_index(curr_tick_index);
+        // The tick group of the step is the one the price moves through. When moving left from
+        // the lower boundary of a tick group, the step trades in the tick group below it.
+        let (step_tick_group_index, boundary_tick_index) = if a_to_b {
+            let lower_tick_index = tick_group_index * tick_spacing;
+            if sqrt_price_from_tick_index(lower_tick_index.max(MIN_TICK_INDEX)) < curr_sqrt_price {
+                (tick_group_index, lower_tick_index)
+            } else {
+                (tick_group_index - 1, lower_tick_index - tick_spacing)
+            }
+        } else {
+            (tick_group_index, (tick_group_index + 1) * tick_spacing)
+        };
+
+        self.update_volatility_accumulator_for_tick_group(step_tick_group_index);
+
+        let variables = &self.adaptive_fee_info.variables;
+        let moving_away_from_reference = if a_to_b {
+            step_tick_group_index <= variables.tick_group_index_reference
+        } else {
+            step_tick_group_index >= variables.tick_group_index_reference
+        };
+        if moving_away_from_reference
+            && variables.volatility_accumulator
+                >= self.adaptive_fee_info.constants.max_volatility_accumulator
+        {
+            return sqrt_price_target;
+        }
+
+        let boundary_sqrt_price =
+            sqrt_price_from_tick_index(boundary_tick_index.clamp(MIN_TICK_INDEX, MAX_TICK_INDEX));
+        if a_to_b {
+            sqrt_price_target.max(boundary_sqrt_price)
+        } else {
+            sqrt_price_target.min(boundary_sqrt_price)
+        }
+    }
+
+    pub fn get_total_fee_rate(&self) -> u16 {
+        if !self.adaptive_fee_info.is_enabled() {
+            return self.base_fee_rate;
+        }
+
+        // variable fee rate = control_factor * (volatility_accumulator * tick_spacing)^2
+        let crossed = u128::from(self.adaptive_fee_info.variables.volatility_accumulator)
+            * u128::from(self.tick_spacing);
+        let numerator = crossed
+            * crossed
+            * u128::from(self.adaptive_fee_info.constants.adaptive_fee_control_factor);
+        let denominator = u128::from(ADAPTIVE_FEE_CONTROL_FACTOR_DENOMINATOR)
+            * u128::from(VOLATILITY_ACCUMULATOR_SCALE_FACTOR)
+            * u128::from(VOLATILITY_ACCUMULATOR_SCALE_FACTOR);
+        let variable_fee_rate = numerator.div_ceil(denominator);
+
+        (u128::from(self.base_fee_rate) + variable_fee_rate).min(u128::from(MAX_FEE_RATE)) as u16
+    }
+
+    pub fn get_next_adaptive_fee_info(&self) -> AdaptiveFeeInfo {
+        self.adaptive_fee_info
+    }
+}
+
+#[cfg(test)]
+mod fee_rate_manager_tests {
+    use super::*;
+    use crate::state::{AdaptiveFeeConstants, AdaptiveFeeVariables};
+
+    const TICK_SPACING: u16 = 64;
+
+    fn adaptive_fee_info(variables: AdaptiveFeeVariables) -> AdaptiveFeeInfo {
+        AdaptiveFeeInfo {
+            constants: AdaptiveFeeConstants {
+                filter_period: 30,
+                decay_period: 600,
+                reduction_factor: 5_000,
+                adaptive_fee_control_factor: 4_000,
+                max_volatility_accumulator: 350_000,
+            },
+            variables,
+        }
+    }
+
+    #[test]
+    fn test_disabled_uses_base_fee_rate() {
+        let info = AdaptiveFeeInfo::default();
+        let mut fee_rate_manager = FeeRateManager::new(3_000, TICK_SPACING, 0, info, 1_000);
+        fee_rate_manager.update_volatility_accumulator(64 * 100);
+
+        assert_eq!(fee_rate_manager.get_total_fee_rate(), 3_000);
+        assert_eq!(fee_rate_manager.get_next_adaptive_fee_info(), info);
+    }
+
+    #[test]
+    fn test_first_swap_sets_reference() {
+        let info = adaptive_fee_info(AdaptiveFeeVariables::default());
+        let fee_rate_manager = FeeRateManager::new(3_000, TICK_SPACING, -1, info, 1_000);
+
+        let variables = fee_rate_manager.get_next_adaptive_fee_info().variables;
+        assert_eq!(variables.last_reference_update_timestamp, 1_000);
+        assert_eq!(variables.tick_group_index_reference, -1);
+        assert_eq!(variables.volatility_reference, 0);
+        assert_eq!(variables.volatility_accumulator, 0);
+        assert_eq!(fee_rate_manager.get_total_fee_rate(), 3_000);
+    }
+
+    #[test]
+    fn test_accumulates_as_ticks_are_crossed() {
+        let info = adaptive_fee_info(AdaptiveFeeVariables::default());
+        let mut fee_rate_manager = FeeRateManager::new(3_000, TICK_SPACING, 0, info, 1_000);
+
+        // 10 tick groups away from the reference
+        fee_rate_manager.update_volatility_accumulator(64 * 10);
+        let variables = fee_rate_manager.get_next_adaptive_fee_info().variables;
+        assert_eq!(variables.volatility_accumulator, 100_000);
+        // 4_000 * (100_000 * 64)^2 / (100_000 * 10_000^2) = 16_384
+        assert_eq!(fee_rate_manager.get_total_fee_rate(), 3_000 + 16_384);
+    }
+
+    #[test]
+    fn test_capped_at_max_volatility_accumulator_and_max_fee_rate() {
+        let info = adaptive_fee_info(AdaptiveFeeVariables::default());
+        let mut fee_rate_manager = FeeRateManager::new(3_000, TICK_SPACING, 0, info, 1_000);
+
+        fee_rate_manager.update_volatility_accumulator(-64 * 1_000);
+        let variables = fee_rate_manager.get_next_adaptive_fee_info().variables;
+        assert_eq!(variables.volatility_accumulator, 350_000);
+        assert_eq!(fee_rate_manager.get_total_fee_rate(), MAX_FEE_RATE);
+    }
+
+    #[test]
+    fn test_references_kept_within_filter_period() {
+        let info = adaptive_fee_info(AdaptiveFeeVariables {
+            last_reference_update_timestamp: 1_000,
+            volatility_reference: 20_000,
+            tick_group_index_reference: 5,
+            volatility_accumulator: 50_000,
+        });
+        let fee_rate_manager = FeeRateManager::new(3_000, TICK_SPACING, 64 * 8, info, 1_029);
+
+        let variables = fee_rate_manager.get_next_adaptive_fee_info().variables;
+        assert_eq!(variables.last_reference_update_timestamp, 1_000);
+        assert_eq!(variables.volatility_reference, 20_000);
+        assert_eq!(variables.tick_group_index_reference, 5);
+        assert_eq!(variables.volatility_accumulator, 50_000);
+    }
+
+    #[test]
+    fn test_reference_reduced_within_decay_period() {
+        let info = adaptive_fee_info(AdaptiveFeeVariables {
+            last_reference_update_timestamp: 1_000,
+            volatility_reference: 20_000,
+            tick_group_index_reference: 5,
+            volatility_accumulator: 50_000,
+        });
+        let fee_rate_manager = FeeRateManager::new(3_000, TICK_SPACING, 64 * 8, info, 1_030);
+
+        let variables = fee_rate_manager.get_next_adaptive_fee_info().variables;
+        assert_eq!(variables.last_reference_update_timestamp, 1_030);
+        assert_eq!(variables.volatility_reference, 25_000);
+        assert_eq!(variables.tick_group_index_reference, 8);
+        assert_eq!(variables.volatility_accumulator, 25_000);
+    }
+
+    #[test]
+    fn test_reference_reset_after_decay_period() {
+        let info = adaptive_fee_info(AdaptiveFeeVariables {
+            last_reference_update_timestamp: 1_000,
+            volatility_reference: 20_000,
+            tick_group_index_reference: 5,
+            volatility_accumulator: 50_000,
+        });
+        let fee_rate_manager = FeeRateManager::new(3_000, TICK_SPACING, 64 * 8, info, 1_600);
+
+        let variables = fee_rate_manager.get_next_adaptive_fee_info().variables;
+        assert_eq!(variables.last_reference_update_timestamp, 1_600);
+        assert_eq!(variables.volatility_reference, 0);
+        assert_eq!(variables.tick_group_index_reference, 8);
+        assert_eq!(variables.volatility_accumulator, 0);
+        assert_eq!(fee_rate_manager.get_total_fee_rate(), 3_000);
+    }
+
+    #[test]
+    fn test_swap_step_disabled_is_not_bounded() {
+        let mut fee_rate_manager =
+            FeeRateManager::new(3_000, TICK_SPACING, 0, AdaptiveFeeInfo::default(), 1_000);
+        let target = sqrt_price_from_tick_index(-64 * 10);
+
+        let bounded = fee_rate_manager.update_for_swap_step(1 << 64, 0, target, true);
+        assert_eq!(bounded, target);
+    }
+
+    #[test]
+    fn test_swap_step_bounded_by_tick_group_a_to_b() {
+        let info = adaptive_fee_info(AdaptiveFeeVariables::default());
+        let mut fee_rate_manager = FeeRateManager::new(3_000, TICK_SPACING, 100, info, 1_000);
+        let target = sqrt_price_from_tick_index(-64 * 10);
+
+        // inside tick group 1, bounded by its lower boundary
+        let curr_sqrt_price = sqrt_price_from_tick_index(100);
+        let bounded = fee_rate_manager.update_for_swap_step(curr_sqrt_price, 100, target, true);
+        assert_eq!(bounded, sqrt_price_from_tick_index(64));
+        assert_eq!(
+            fee_rate_manager
+                .get_next_adaptive_fee_info()
+                .variables
+                .volatility_accumulator,
+            0
+        );
+
+        // on the lower boundary of tick group 1, the step trades in tick group 0
+        let bounded = fee_rate_manager.update_for_swap_step(bounded, 64, target, true);
+        assert_eq!(bounded, sqrt_price_from_tick_index(0));
+        assert_eq!(
+            fee_rate_manager
+                .get_next_adaptive_fee_info()
+                .variables
+                .volatility_accumulator,
+            10_000
+        );
+
+        // after crossing an initialized tick on the boundary, the tick index is already below it
+        let bounded = fee_rate_manager.update_for_swap_step(bounded, -1, target, true);
+        assert_eq!(bounded, sqrt_price_from_tick_index(-64));
+        assert_eq!(
+            fee_rate_manager
+                .get_next_adaptive_fee_info()
+                .variables
+                .volatility_accumulator,
+            20_000
+        );
+    }
+
+    #[test]
+    fn test_swap_step_bounded_by_tick_group_b_to_a() {
+        let info = adaptive_fee_info(AdaptiveFeeVariables::default());
+        let mut fee_rate_manager = FeeRateManager::new(3_000, TICK_SPACING, -100, info, 1_000);
+        let target = sqrt_price_from_tick_index(64 * 10);
+
+        // inside tick group -2, bounded by its upper boundary
+        let curr_sqrt_price = sqrt_price_from_tick_index(-100);
+        let bounded = fee_rate_manager.update_for_swap_step(curr_sqrt_price, -100, target, false);
+        assert_eq!(bounded, sqrt_price_from_tick_index(-64));
+
+        // on the upper boundary, the step trades in the next tick group
+        let bounded = fee_rate_manager.update_for_swap_step(bounded, -64, target, false);
+        assert_eq!(bounded, sqrt_price_from_tick_index(0));
+        assert_eq!(
+            fee_rate_manager
+                .get_next_adaptive_fee_info()
+                .variables
+                .volatility_accumulator,
+            10_000
+        );
+    }
+
+    #[test]
+    fn test_swap_step_target_within_tick_group() {
+        let info = adaptive_fee_info(AdaptiveFeeVariables::default());
+        let mut fee_rate_manager = FeeRateManager::new(3_000, TICK_SPACING, 100, info, 1_000);
+        let target = sqrt_price_from_tick_index(80);
+
+        let curr_sqrt_price = sqrt_price_from_tick_index(100);
+        let bounded = fee_rate_manager.update_for_swap_step(curr_sqrt_price, 100, target, true);
+        assert_eq!(bounded, target);
+    }
+
+    #[test]
+    fn test_swap_step_not_bounded_at_max_volatility_moving_away() {
+        let info = adaptive_fee_info(AdaptiveFeeVariables::default());
+        let mut fee_rate_manager = FeeRateManager::new(3_000, TICK_SPACING, 0, info, 1_000);
+        let target = sqrt_price_from_tick_index(-64 * 100);
+
+        // 35 tick groups away from the reference reaches max_volatility_accumulator
+        let curr_sqrt_price = sqrt_price_from_tick_index(-64 * 35 - 1);
+        let bounded =
+            fee_rate_manager.update_for_swap_step(curr_sqrt_price, -64 * 35 - 1, target, true);
+        assert_eq!(bounded, target);
+
+        // moving back toward the reference is bounded, as the fee rate decreases
+        let target = sqrt_price_from_tick_index(0);
+        let bounded =
+            fee_rate_manager.update_for_swap_step(curr_sqrt_price, -64 * 35 - 1, target, false);
+        assert_eq!(bounded, sqrt_price_from_tick_index(-64 * 35));
+    }
+}
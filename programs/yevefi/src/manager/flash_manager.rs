@@ -0,0 +1,155 @@
+use crate::{
+    errors::ErrorCode,
+    manager::swap_manager::calculate_fees,
+    math::{checked_mul_div_round_up, FEE_RATE_MUL_VALUE},
+    state::Yevefi,
+};
+use anchor_lang::prelude::*;
+use std::convert::TryInto;
+
+#[derive(Debug, PartialEq)]
+pub struct PostFlashLoanUpdate {
+    pub fee_a: u64,
+    pub fee_b: u64,
+    // borrowed amounts plus fees, to be received by the vaults
+    pub repayment_a: u64,
+    pub repayment_b: u64,
+    pub next_fee_growth_global_a: u128,
+    pub next_fee_growth_global_b: u128,
+    pub next_protocol_fee_owed_a: u64,
+    pub next_protocol_fee_owed_b: u64,
+}
+
+// Calculates the flash fees for borrowing amount_a and amount_b from the yevefi vaults.
+// The fee is charged at the fee rate of the yevefi, rounded up, and is split between
+// liquidity providers and the protocol in the same way as swap fees.
+pub fn flash_loan(yevefi: &Yevefi, amount_a: u64, amount_b: u64) -> Result<PostFlashLoanUpdate> {
+    yevefi.require_unlocked()?;
+
+    if amount_a == 0 && amount_b == 0 {
+        return Err(ErrorCode::ZeroTradableAmount.into());
+    }
+
+    let fee_a = calculate_flash_fee(amount_a, yevefi.fee_rate)?;
+    let fee_b = calculate_flash_fee(amount_b, yevefi.fee_rate)?;
+    let repayment_a = amount_a
+        .checked_add(fee_a)
+        .ok_or(ErrorCode::AmountCalcOverflow)?;
+    let repayment_b = amount_b
+        .checked_add(fee_b)
+        .ok_or(ErrorCode::AmountCalcOverflow)?;
+
+    let (next_protocol_fee_owed_a, _, next_fee_growth_global_a) = calculate_fees(
+        fee_a,
+        yevefi.protocol_fee_rate,
+        0,
+        yevefi.liquidity,
+        yevefi.protocol_fee_owed_a,
+        0,
+        yevefi.fee_growth_global_a,
+    );
+    let (next_protocol_fee_owed_b, _, next_fee_growth_global_b) = calculate_fees(
+        fee_b,
+        yevefi.protocol_fee_rate,
+        0,
+        yevefi.liquidity,
+        yevefi.protocol_fee_owed_b,
+        0,
+        yevefi.fee_growth_global_b,
+    );
+
+    Ok(PostFlashLoanUpdate {
+        fee_a,
+        fee_b,
+        repayment_a,
+        repayment_b,
+        next_fee_growth_global_a,
+        next_fee_growth_global_b,
+        next_protocol_fee_owed_a,
+        next_protocol_fee_owed_b,
+    })
+}
+
+fn calculate_flash_fee(amount: u64, fee_rate: u16) -> Result<u64> {
+    let fee = checked_mul_div_round_up(amount as u128, fee_rate as u128, FEE_RATE_MUL_VALUE)?;
+    Ok(fee.try_into().map_err(|_| ErrorCode::NumberCastError)?)
+}
+
+#[cfg(test)]
+mod flash_manager_tests {
+    use super::*;
+    use crate::math::Q64_RESOLUTION;
+    use crate::state::yevefi_builder::YevefiBuilder;
+
+    #[test]
+    fn test_flash_loan_fee_rounds_up() {
+        let yevefi = YevefiBuilder::new()
+            .liquidity(1 << 32)
+            .fee_rate(3000) // 0.3%
+            .build();
+
+        let update = flash_loan(&yevefi, 1_000_001, 0).unwrap();
+        assert_eq!(update.fee_a, 3001);
+        assert_eq!(update.fee_b, 0);
+        assert_eq!(update.repayment_a, 1_003_002);
+        assert_eq!(update.repayment_b, 0);
+        assert_eq!(update.next_fee_growth_global_b, 0);
+        assert_eq!(update.next_protocol_fee_owed_b, 0);
+    }
+
+    #[test]
+    fn test_flash_loan_fee_split_with_protocol() {
+        let yevefi = YevefiBuilder::new()
+            .liquidity(1_000)
+            .fee_rate(10_000) // 1%
+            .protocol_fee_rate(2_500) // 25%
+            .fee_growth_global_b(5 << Q64_RESOLUTION)
+            .build();
+
+        let update = flash_loan(&yevefi, 0, 400_000).unwrap();
+        assert_eq!(update.fee_b, 4_000);
+        assert_eq!(update.next_protocol_fee_owed_b, 1_000);
+        // 3_000 lp fee over 1_000 liquidity
+        assert_eq!(update.next_fee_growth_global_b, 8 << Q64_RESOLUTION);
+    }
+
+    #[test]
+    fn test_flash_loan_zero_liquidity() {
+        let yevefi = YevefiBuilder::new().fee_rate(10_000).build();
+
+        let update = flash_loan(&yevefi, 100, 100).unwrap();
+        assert_eq!(update.fee_a, 1);
+        assert_eq!(update.fee_b, 1);
+        assert_eq!(update.next_fee_growth_global_a, 0);
+        assert_eq!(update.next_fee_growth_global_b, 0);
+    }
+
+    #[test]
+    fn test_flash_loan_repayment_overflow() {
+        let yevefi = YevefiBuilder::new().fee_rate(10_000).build();
+
+        assert_eq!(
+            flash_loan(&yevefi, u64::MAX, 0).unwrap_err(),
+            ErrorCode::AmountCalcOverflow.into()
+        );
+        assert_eq!(
+            flash_loan(&yevefi, 0, u64::MAX).unwrap_err(),
+            ErrorCode::AmountCalcOverflow.into()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ZeroTradableAmount")]
+    fn test_flash_loan_zero_amount() {
+        let yevefi = YevefiBuilder::new().build();
+        flash_loan(&yevefi, 0, 0).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "YevefiLocked")]
+    fn test_flash_loan_locked() {
+        let mut yevefi = YevefiBuilder::new().build();
+        yevefi.reentrancy_lock = true;
+        flash_loan(&yevefi, 1, 0).unwrap();
+    }
+}
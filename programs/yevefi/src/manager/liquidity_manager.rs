@@ -1,7 +1,8 @@
 use super::{
-    position_manager::next_position_modify_liquidity_update,
+    position_manager::{next_position_modify_liquidity_update, next_position_reward_infos},
     tick_manager::{
-        next_fee_growths_inside, next_reward_growths_inside, next_tick_modify_liquidity_update,
+        next_extension_reward_growths_inside, next_fee_growths_inside, next_reward_growths_inside,
+        next_tick_modify_liquidity_update,
     },
     yevefi_manager::{next_yevefi_liquidity, next_yevefi_reward_infos},
 };
@@ -32,6 +33,8 @@ pub fn calculate_modify_liquidity<'info>(
     liquidity_delta: i128,
     timestamp: u64,
 ) -> Result<ModifyLiquidityUpdate> {
+    yevefi.require_unlocked()?;
+
     let tick_array_lower = tick_array_lower.load()?;
     let tick_lower = tick_array_lower.get_tick(position.tick_lower_index, yevefi.tick_spacing)?;
 
@@ -78,6 +81,55 @@ pub fn calculate_fee_and_reward_growths<'info>(
     Ok((update.position_update, update.reward_infos))
 }
 
+// Calculates the extension reward growths inside of the position.
+// The tick array reward extensions must cover the lower and upper ticks of the position.
+pub fn calculate_extension_reward_growths_inside(
+    yevefi: &Yevefi,
+    position: &Position,
+    tick_array_lower_reward_extension: &TickArrayRewardExtension,
+    tick_array_upper_reward_extension: &TickArrayRewardExtension,
+    reward_infos: &[YevefiRewardInfo; NUM_EXTENSION_REWARDS],
+) -> Result<[u128; NUM_EXTENSION_REWARDS]> {
+    let tick_lower_reward_growths_outside = tick_array_lower_reward_extension
+        .get_reward_growths_outside(position.tick_lower_index, yevefi.tick_spacing)?;
+    let tick_upper_reward_growths_outside = tick_array_upper_reward_extension
+        .get_reward_growths_outside(position.tick_upper_index, yevefi.tick_spacing)?;
+
+    Ok(next_extension_reward_growths_inside(
+        yevefi.tick_current_index,
+        tick_lower_reward_growths_outside,
+        position.tick_lower_index,
+        tick_upper_reward_growths_outside,
+        position.tick_upper_index,
+        reward_infos,
+    ))
+}
+
+// Calculates the next extension reward infos of a position, based on its current liquidity.
+// Must be called before the liquidity of the position is modified.
+pub fn calculate_extension_reward_growths(
+    yevefi: &Yevefi,
+    position: &Position,
+    position_reward_extension: &PositionRewardExtension,
+    tick_array_lower_reward_extension: &TickArrayRewardExtension,
+    tick_array_upper_reward_extension: &TickArrayRewardExtension,
+    reward_infos: &[YevefiRewardInfo; NUM_EXTENSION_REWARDS],
+) -> Result<[PositionRewardInfo; NUM_EXTENSION_REWARDS]> {
+    let reward_growths_inside = calculate_extension_reward_growths_inside(
+        yevefi,
+        position,
+        tick_array_lower_reward_extension,
+        tick_array_upper_reward_extension,
+        reward_infos,
+    )?;
+
+    Ok(next_position_reward_infos(
+        position.liquidity,
+        &position_reward_extension.reward_infos,
+        &reward_growths_inside,
+    ))
+}
+
 // Calculates the state changes after modifying liquidity of a yevefi position.
 #[allow(clippy::too_many_arguments)]
 fn _calculate_modify_liquidity(
@@ -3580,3 +3632,68 @@ mod calculate_modify_liquidity_unit_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod calculate_extension_reward_growths_unit_tests {
+    use super::calculate_extension_reward_growths;
+    use crate::math::Q64_RESOLUTION;
+    use crate::state::{
+        position_builder::PositionBuilder, yevefi_builder::YevefiBuilder, PositionRewardExtension,
+        PositionRewardInfo, TickArrayRewardExtension, YevefiRewardInfo, NUM_EXTENSION_REWARDS,
+    };
+    use anchor_lang::prelude::Pubkey;
+
+    #[test]
+    fn test_calculate_extension_reward_growths() {
+        let yevefi = YevefiBuilder::new()
+            .tick_spacing(1)
+            .tick_current_index(0)
+            .liquidity(1000)
+            .build();
+        let position = PositionBuilder::new(-10, 10).liquidity(1000).build();
+
+        let mut tick_array_lower = TickArrayRewardExtension::default();
+        tick_array_lower
+            .initialize(&yevefi, Pubkey::default(), -88, [0; NUM_EXTENSION_REWARDS])
+            .unwrap();
+        let mut tick_array_upper = TickArrayRewardExtension::default();
+        tick_array_upper
+            .initialize(&yevefi, Pubkey::default(), 0, [0; NUM_EXTENSION_REWARDS])
+            .unwrap();
+
+        let position_reward_extension = PositionRewardExtension {
+            reward_infos: [
+                PositionRewardInfo {
+                    growth_inside_checkpoint: 0,
+                    amount_owed: 5,
+                },
+                PositionRewardInfo::default(),
+            ],
+            ..Default::default()
+        };
+
+        let reward_infos = [
+            YevefiRewardInfo {
+                mint: Pubkey::new_unique(),
+                growth_global_x64: 2 << Q64_RESOLUTION,
+                ..Default::default()
+            },
+            YevefiRewardInfo::default(),
+        ];
+
+        let result = calculate_extension_reward_growths(
+            &yevefi,
+            &position,
+            &position_reward_extension,
+            &tick_array_lower,
+            &tick_array_upper,
+            &reward_infos,
+        )
+        .unwrap();
+
+        // All growth since the tick arrays were initialized happened inside the position
+        assert_eq!(result[0].growth_inside_checkpoint, 2 << Q64_RESOLUTION);
+        assert_eq!(result[0].amount_owed, 2005);
+        assert_eq!(result[1], PositionRewardInfo::default());
+    }
+}
@@ -1,4 +1,7 @@
+pub mod fee_rate_manager;
+pub mod flash_manager;
 pub mod liquidity_manager;
+pub mod oracle_manager;
 pub mod position_manager;
 pub mod swap_manager;
 pub mod tick_manager;
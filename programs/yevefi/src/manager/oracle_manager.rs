@@ -0,0 +1,369 @@
+use crate::errors::ErrorCode;
+use crate::math::checked_mul_div;
+use crate::state::*;
+
+// Writes an observation of the given yevefi state into the ring buffer of the oracle.
+// The tick and liquidity must be the values the yevefi held since the previous observation,
+// so this must be called before a swap is applied.
+// At most one observation is written per timestamp, so only the first swap of a slot records one.
+pub fn write_observation(
+    oracle: &mut Oracle,
+    observations: &mut [Observation],
+    tick_current_index: i32,
+    liquidity: u128,
+    timestamp: u64,
+) -> Result<(), ErrorCode> {
+    let index = usize::from(oracle.observation_index);
+    let last = observations[index];
+
+    // The first observation only marks the start of the accumulation.
+    if !last.initialized() {
+        observations[index] = Observation {
+            timestamp,
+            ..Default::default()
+        };
+        return Ok(());
+    }
+
+    if timestamp < last.timestamp {
+        return Err(ErrorCode::InvalidTimestamp);
+    }
+
+    // An observation has already been written for this timestamp
+    if timestamp == last.timestamp {
+        return Ok(());
+    }
+
+    // Slots paid for by increase_observation_cardinality are taken into use
+    // once the last slot in use has been written.
+    let cardinality = oracle.observation_cardinality;
+    let cardinality_next = oracle.observation_cardinality_next;
+    let next_cardinality =
+        if cardinality_next > cardinality && index == usize::from(cardinality) - 1 {
+            cardinality_next
+        } else {
+            cardinality
+        };
+    let next_index = (index + 1) % usize::from(next_cardinality);
+
+    observations[next_index] = last.transform(timestamp, tick_current_index, liquidity);
+    oracle.observation_index = next_index as u16;
+    oracle.observation_cardinality = next_cardinality;
+
+    Ok(())
+}
+
+// Returns the cumulative values as of `seconds_ago` before the given timestamp.
+// Values after the most recent observation are extrapolated from the current yevefi state,
+// values between two observations are interpolated.
+pub fn observe_single(
+    oracle: &Oracle,
+    observations: &[Observation],
+    tick_current_index: i32,
+    liquidity: u128,
+    timestamp: u64,
+    seconds_ago: u32,
+) -> Result<Observation, ErrorCode> {
+    let index = usize::from(oracle.observation_index);
+    let cardinality = usize::from(oracle.observation_cardinality);
+
+    let last = observations[index];
+    if !last.initialized() {
+        return Err(ErrorCode::ObservationTooOld);
+    }
+
+    let target = timestamp
+        .checked_sub(u64::from(seconds_ago))
+        .ok_or(ErrorCode::ObservationTooOld)?;
+
+    if last.timestamp <= target {
+        if last.timestamp == target {
+            return Ok(last);
+        }
+        return Ok(last.transform(target, tick_current_index, liquidity));
+    }
+
+    // The oldest observation is the one after the current index,
+    // unless the ring buffer has not been filled yet.
+    let mut oldest = observations[(index + 1) % cardinality];
+    if !oldest.initialized() {
+        oldest = observations[0];
+    }
+    if target < oldest.timestamp {
+        return Err(ErrorCode::ObservationTooOld);
+    }
+
+    let (before, after) = search_surrounding_observations(observations, index, cardinality, target);
+    if target == before.timestamp {
+        return Ok(before);
+    }
+    if target == after.timestamp {
+        return Ok(after);
+    }
+
+    let observation_time_delta = after.timestamp - before.timestamp;
+    let target_delta = target - before.timestamp;
+
+    // The tick is constant between two observations, so the tick cumulative grows linearly.
+    let tick_cumulative = before.tick_cumulative.wrapping_add(
+        (after.tick_cumulative.wrapping_sub(before.tick_cumulative)
+            / observation_time_delta as i64)
+            .wrapping_mul(target_delta as i64),
+    );
+    let seconds_per_liquidity_cumulative_x64 = before
+        .seconds_per_liquidity_cumulative_x64
+        .wrapping_add(checked_mul_div(
+            after
+                .seconds_per_liquidity_cumulative_x64
+                .wrapping_sub(before.seconds_per_liquidity_cumulative_x64),
+            u128::from(target_delta),
+            u128::from(observation_time_delta),
+        )?);
+
+    Ok(Observation {
+        timestamp: target,
+        tick_cumulative,
+        seconds_per_liquidity_cumulative_x64,
+    })
+}
+
+// Returns the cumulative values as of each of `seconds_agos` before the given timestamp.
+pub fn observe(
+    oracle: &Oracle,
+    observations: &[Observation],
+    tick_current_index: i32,
+    liquidity: u128,
+    timestamp: u64,
+    seconds_agos: &[u32],
+) -> Result<ObserveResult, ErrorCode> {
+    if seconds_agos.len() > MAX_OBSERVE_SECONDS_AGOS_LEN {
+        return Err(ErrorCode::TooManySecondsAgos);
+    }
+
+    let mut result = ObserveResult {
+        tick_cumulatives: Vec::with_capacity(seconds_agos.len()),
+        seconds_per_liquidity_cumulative_x64s: Vec::with_capacity(seconds_agos.len()),
+    };
+    for seconds_ago in seconds_agos {
+        let observation = observe_single(
+            oracle,
+            observations,
+            tick_current_index,
+            liquidity,
+            timestamp,
+            *seconds_ago,
+        )?;
+        result.tick_cumulatives.push(observation.tick_cumulative);
+        result
+            .seconds_per_liquidity_cumulative_x64s
+            .push(observation.seconds_per_liquidity_cumulative_x64);
+    }
+
+    Ok(result)
+}
+
+// Binary searches the observations in use for the pair surrounding the target timestamp.
+// The target must be within the oldest and the most recent observation.
+fn search_surrounding_observations(
+    observations: &[Observation],
+    index: usize,
+    cardinality: usize,
+    target: u64,
+) -> (Observation, Observation) {
+    // oldest observation
+    let mut l = (index + 1) % cardinality;
+    // most recent observation
+    let mut r = l + cardinality - 1;
+
+    loop {
+        let i = (l + r) / 2;
+
+        let before = observations[i % cardinality];
+        // Slots which have not been written yet are newer than any written slot
+        if !before.initialized() {
+            l = i + 1;
+            continue;
+        }
+
+        let after = observations[(i + 1) % cardinality];
+        let target_at_or_after = before.timestamp <= target;
+        if target_at_or_after && target <= after.timestamp {
+            return (before, after);
+        }
+
+        if target_at_or_after {
+            l = i + 1;
+        } else {
+            r = i - 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod oracle_manager_tests {
+    use crate::errors::ErrorCode;
+    use crate::manager::oracle_manager::*;
+    use crate::math::Q64_RESOLUTION;
+    use crate::state::{Observation, Oracle, MAX_OBSERVE_SECONDS_AGOS_LEN};
+
+    fn init_test_oracle(cardinality_next: u16) -> (Oracle, Vec<Observation>) {
+        let mut oracle = Oracle::default();
+        oracle.initialize(anchor_lang::prelude::Pubkey::new_unique());
+        oracle.update_observation_cardinality_next(cardinality_next);
+        (
+            oracle,
+            vec![Observation::default(); usize::from(cardinality_next)],
+        )
+    }
+
+    #[test]
+    fn test_write_observation_first_observation() {
+        let (mut oracle, mut observations) = init_test_oracle(1);
+
+        write_observation(&mut oracle, &mut observations, 100, 1_000, 1_000).unwrap();
+
+        assert_eq!({ oracle.observation_index }, 0);
+        assert_eq!({ oracle.observation_cardinality }, 1);
+        assert_eq!(
+            observations[0],
+            Observation {
+                timestamp: 1_000,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_write_observation_same_timestamp_no_op() {
+        let (mut oracle, mut observations) = init_test_oracle(2);
+
+        write_observation(&mut oracle, &mut observations, 100, 1_000, 1_000).unwrap();
+        write_observation(&mut oracle, &mut observations, 200, 1_000, 1_000).unwrap();
+
+        assert_eq!({ oracle.observation_index }, 0);
+        assert!(!observations[1].initialized());
+    }
+
+    #[test]
+    fn test_write_observation_invalid_timestamp() {
+        let (mut oracle, mut observations) = init_test_oracle(1);
+
+        write_observation(&mut oracle, &mut observations, 100, 1_000, 1_000).unwrap();
+        let result = write_observation(&mut oracle, &mut observations, 100, 1_000, 999);
+        assert_eq!(result.unwrap_err(), ErrorCode::InvalidTimestamp);
+    }
+
+    #[test]
+    fn test_write_observation_single_slot_overwrites() {
+        let (mut oracle, mut observations) = init_test_oracle(1);
+
+        write_observation(&mut oracle, &mut observations, 100, 1_000, 1_000).unwrap();
+        write_observation(&mut oracle, &mut observations, 100, 1_000, 1_010).unwrap();
+
+        assert_eq!({ oracle.observation_index }, 0);
+        assert_eq!({ oracle.observation_cardinality }, 1);
+        assert_eq!({ observations[0].timestamp }, 1_010);
+        assert_eq!({ observations[0].tick_cumulative }, 1_000);
+    }
+
+    #[test]
+    fn test_write_observation_grows_and_wraps() {
+        let (mut oracle, mut observations) = init_test_oracle(3);
+
+        write_observation(&mut oracle, &mut observations, 10, 1 << 10, 1_000).unwrap();
+        write_observation(&mut oracle, &mut observations, 10, 1 << 10, 1_002).unwrap();
+        assert_eq!({ oracle.observation_index }, 1);
+        assert_eq!({ oracle.observation_cardinality }, 3);
+
+        write_observation(&mut oracle, &mut observations, -5, 1 << 10, 1_004).unwrap();
+        assert_eq!({ oracle.observation_index }, 2);
+        assert_eq!({ observations[2].tick_cumulative }, 20 - 10);
+        assert_eq!(
+            { observations[2].seconds_per_liquidity_cumulative_x64 },
+            4 << (Q64_RESOLUTION - 10)
+        );
+
+        // The oldest observation is overwritten
+        write_observation(&mut oracle, &mut observations, 0, 1 << 10, 1_005).unwrap();
+        assert_eq!({ oracle.observation_index }, 0);
+        assert_eq!({ oracle.observation_cardinality }, 3);
+        assert_eq!({ observations[0].timestamp }, 1_005);
+        assert_eq!({ observations[0].tick_cumulative }, 10);
+    }
+
+    #[test]
+    fn test_observe_extrapolates_from_last_observation() {
+        let (mut oracle, mut observations) = init_test_oracle(2);
+
+        write_observation(&mut oracle, &mut observations, 10, 1_000, 1_000).unwrap();
+        write_observation(&mut oracle, &mut observations, 10, 1_000, 1_010).unwrap();
+
+        let result = observe(&oracle, &observations, 20, 1_000, 1_020, &[0, 5, 10]).unwrap();
+        assert_eq!(result.tick_cumulatives, vec![300, 200, 100]);
+    }
+
+    #[test]
+    fn test_observe_interpolates_between_observations() {
+        let (mut oracle, mut observations) = init_test_oracle(3);
+
+        write_observation(&mut oracle, &mut observations, 0, 1 << 10, 1_000).unwrap();
+        write_observation(&mut oracle, &mut observations, 10, 1 << 10, 1_010).unwrap();
+        write_observation(&mut oracle, &mut observations, 30, 1 << 9, 1_020).unwrap();
+
+        // observations: [1_000 => 0, 1_010 => 100, 1_020 => 400]
+        let result = observe(&oracle, &observations, 50, 1 << 9, 1_020, &[5, 10, 15, 20]).unwrap();
+        assert_eq!(result.tick_cumulatives, vec![250, 100, 50, 0]);
+        assert_eq!(
+            result.seconds_per_liquidity_cumulative_x64s,
+            vec![
+                20 << (Q64_RESOLUTION - 10),
+                10 << (Q64_RESOLUTION - 10),
+                5 << (Q64_RESOLUTION - 10),
+                0
+            ]
+        );
+    }
+
+    #[test]
+    fn test_observe_after_wrap() {
+        let (mut oracle, mut observations) = init_test_oracle(2);
+
+        write_observation(&mut oracle, &mut observations, 0, 1, 1_000).unwrap();
+        write_observation(&mut oracle, &mut observations, 1, 1, 1_010).unwrap();
+        write_observation(&mut oracle, &mut observations, 2, 1, 1_020).unwrap();
+
+        // observations: [1_020 => 30, 1_010 => 10]
+        let result = observe(&oracle, &observations, 2, 1, 1_020, &[0, 5, 10]).unwrap();
+        assert_eq!(result.tick_cumulatives, vec![30, 20, 10]);
+
+        let result = observe(&oracle, &observations, 2, 1, 1_020, &[11]);
+        assert_eq!(result.unwrap_err(), ErrorCode::ObservationTooOld);
+    }
+
+    #[test]
+    fn test_observe_no_observation() {
+        let (oracle, observations) = init_test_oracle(1);
+
+        let result = observe(&oracle, &observations, 0, 1, 1_000, &[0]);
+        assert_eq!(result.unwrap_err(), ErrorCode::ObservationTooOld);
+    }
+
+    #[test]
+    fn test_observe_seconds_ago_before_epoch() {
+        let (mut oracle, mut observations) = init_test_oracle(1);
+
+        write_observation(&mut oracle, &mut observations, 0, 1, 1_000).unwrap();
+        let result = observe(&oracle, &observations, 0, 1, 1_000, &[1_001]);
+        assert_eq!(result.unwrap_err(), ErrorCode::ObservationTooOld);
+    }
+
+    #[test]
+    fn test_observe_too_many_seconds_agos() {
+        let (mut oracle, mut observations) = init_test_oracle(1);
+
+        write_observation(&mut oracle, &mut observations, 0, 1, 1_000).unwrap();
+        let seconds_agos = vec![0; MAX_OBSERVE_SECONDS_AGOS_LEN + 1];
+        let result = observe(&oracle, &observations, 0, 1, 1_000, &seconds_agos);
+        assert_eq!(result.unwrap_err(), ErrorCode::TooManySecondsAgos);
+    }
+}
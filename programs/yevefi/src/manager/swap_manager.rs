@@ -2,7 +2,10 @@ use solana_program::msg;
 
 use crate::{
     errors::ErrorCode,
-    manager::{tick_manager::next_tick_cross_update, yevefi_manager::next_yevefi_reward_infos},
+    manager::{
+        fee_rate_manager::FeeRateManager, tick_manager::next_tick_cross_update,
+        yevefi_manager::next_yevefi_reward_infos,
+    },
     math::*,
     state::*,
     util::SwapTickSequence,
@@ -20,6 +23,11 @@ pub struct PostSwapUpdate {
     pub next_fee_growth_global: u128,
     pub next_reward_infos: [YevefiRewardInfo; NUM_REWARDS],
     pub next_protocol_fee: u64,
+    pub next_referral_fee: u64,
+    pub next_lp_fee: u64,
+    pub next_adaptive_fee_info: AdaptiveFeeInfo,
+    pub ticks_crossed: u16,
+    pub tick_arrays_touched: u8,
 }
 
 pub fn swap(
@@ -31,6 +39,32 @@ pub fn swap(
     a_to_b: bool,
     timestamp: u64,
 ) -> Result<PostSwapUpdate> {
+    swap_with_referral(
+        yevefi,
+        swap_tick_sequence,
+        amount,
+        sqrt_price_limit,
+        amount_specified_is_input,
+        a_to_b,
+        timestamp,
+        0,
+    )
+}
+
+// referral_fee_rate is the portion of the LP fee paid to the referrer, and is 0 if the swap is not referred
+#[allow(clippy::too_many_arguments)]
+pub fn swap_with_referral(
+    yevefi: &Yevefi,
+    swap_tick_sequence: &mut SwapTickSequence,
+    amount: u64,
+    sqrt_price_limit: u128,
+    amount_specified_is_input: bool,
+    a_to_b: bool,
+    timestamp: u64,
+    referral_fee_rate: u16,
+) -> Result<PostSwapUpdate> {
+    yevefi.require_unlocked()?;
+
     let adjusted_sqrt_price_limit = if sqrt_price_limit == NO_EXPLICIT_SQRT_PRICE_LIMIT {
         if a_to_b {
             MIN_SQRT_PRICE_X64
@@ -56,7 +90,13 @@ pub fn swap(
     }
 
     let tick_spacing = yevefi.tick_spacing;
-    let fee_rate = yevefi.fee_rate;
+    let mut fee_rate_manager = FeeRateManager::new(
+        yevefi.fee_rate,
+        tick_spacing,
+        yevefi.tick_current_index,
+        yevefi.adaptive_fee_info,
+        timestamp,
+    );
     let protocol_fee_rate = yevefi.protocol_fee_rate;
     let next_reward_infos = next_yevefi_reward_infos(yevefi, timestamp)?;
 
@@ -66,7 +106,11 @@ pub fn swap(
     let mut curr_tick_index = yevefi.tick_current_index;
     let mut curr_liquidity = yevefi.liquidity;
     let mut curr_protocol_fee: u64 = 0;
+    let mut curr_referral_fee: u64 = 0;
+    let mut curr_fee_amount: u64 = 0;
     let mut curr_array_index: usize = 0;
+    let mut max_array_index: usize = 0;
+    let mut ticks_crossed: u16 = 0;
     let mut curr_fee_growth_global_input = if a_to_b {
         yevefi.fee_growth_global_a
     } else {
@@ -81,13 +125,20 @@ pub fn swap(
                 a_to_b,
                 curr_array_index,
             )?;
+        max_array_index = max_array_index.max(next_array_index);
 
         let (next_tick_sqrt_price, sqrt_price_target) =
             get_next_sqrt_prices(next_tick_index, adjusted_sqrt_price_limit, a_to_b);
+        let sqrt_price_target = fee_rate_manager.update_for_swap_step(
+            curr_sqrt_price,
+            curr_tick_index,
+            sqrt_price_target,
+            a_to_b,
+        );
 
         let swap_computation = compute_swap(
             amount_remaining,
-            fee_rate,
+            fee_rate_manager.get_total_fee_rate(),
             curr_liquidity,
             curr_sqrt_price,
             sqrt_price_target,
@@ -119,15 +170,21 @@ pub fn swap(
                 .ok_or(ErrorCode::AmountCalcOverflow)?;
         }
 
-        let (next_protocol_fee, next_fee_growth_global_input) = calculate_fees(
+        let (next_protocol_fee, next_referral_fee, next_fee_growth_global_input) = calculate_fees(
             swap_computation.fee_amount,
             protocol_fee_rate,
+            referral_fee_rate,
             curr_liquidity,
             curr_protocol_fee,
+            curr_referral_fee,
             curr_fee_growth_global_input,
         );
         curr_protocol_fee = next_protocol_fee;
+        curr_referral_fee = next_referral_fee;
         curr_fee_growth_global_input = next_fee_growth_global_input;
+        curr_fee_amount = curr_fee_amount
+            .checked_add(swap_computation.fee_amount)
+            .ok_or(ErrorCode::AmountCalcOverflow)?;
 
         if swap_computation.next_price == next_tick_sqrt_price {
             let (next_tick, next_tick_initialized) = swap_tick_sequence
@@ -157,6 +214,7 @@ pub fn swap(
                     tick_spacing,
                     &update,
                 )?;
+                ticks_crossed += 1;
             }
 
             let tick_offset = swap_tick_sequence.get_tick_offset(
@@ -222,17 +280,26 @@ pub fn swap(
         next_fee_growth_global: curr_fee_growth_global_input,
         next_reward_infos,
         next_protocol_fee: curr_protocol_fee,
+        next_referral_fee: curr_referral_fee,
+        next_lp_fee: curr_fee_amount - curr_protocol_fee - curr_referral_fee,
+        next_adaptive_fee_info: fee_rate_manager.get_next_adaptive_fee_info(),
+        ticks_crossed,
+        tick_arrays_touched: (max_array_index + 1) as u8,
     })
 }
 
-fn calculate_fees(
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_fees(
     fee_amount: u64,
     protocol_fee_rate: u16,
+    referral_fee_rate: u16,
     curr_liquidity: u128,
     curr_protocol_fee: u64,
+    curr_referral_fee: u64,
     curr_fee_growth_global_input: u128,
-) -> (u64, u128) {
+) -> (u64, u64, u128) {
     let mut next_protocol_fee = curr_protocol_fee;
+    let mut next_referral_fee = curr_referral_fee;
     let mut next_fee_growth_global_input = curr_fee_growth_global_input;
     let mut global_fee = fee_amount;
     if protocol_fee_rate > 0 {
@@ -241,11 +308,22 @@ fn calculate_fees(
         next_protocol_fee = next_protocol_fee.wrapping_add(delta);
     }
 
+    // The referral fee is a share of the LP fee, so it is taken after the protocol fee
+    if referral_fee_rate > 0 {
+        let delta = calculate_referral_fee(global_fee, referral_fee_rate);
+        global_fee -= delta;
+        next_referral_fee = next_referral_fee.wrapping_add(delta);
+    }
+
     if curr_liquidity > 0 {
         next_fee_growth_global_input = next_fee_growth_global_input
             .wrapping_add(((global_fee as u128) << Q64_RESOLUTION) / curr_liquidity);
     }
-    (next_protocol_fee, next_fee_growth_global_input)
+    (
+        next_protocol_fee,
+        next_referral_fee,
+        next_fee_growth_global_input,
+    )
 }
 
 fn calculate_protocol_fee(global_fee: u64, protocol_fee_rate: u16) -> u64 {
@@ -254,6 +332,12 @@ fn calculate_protocol_fee(global_fee: u64, protocol_fee_rate: u16) -> u64 {
         .unwrap()
 }
 
+fn calculate_referral_fee(lp_fee: u64, referral_fee_rate: u16) -> u64 {
+    ((lp_fee as u128) * (referral_fee_rate as u128) / REFERRAL_FEE_RATE_MUL_VALUE)
+        .try_into()
+        .unwrap()
+}
+
 fn calculate_update(
     tick: &Tick,
     a_to_b: bool,
@@ -988,6 +1072,8 @@ mod swap_liquidity_tests {
                 end_reward_growths: [10, 10, 10],
             },
         );
+        assert_eq!(post_swap.ticks_crossed, 2);
+        assert_eq!(post_swap.tick_arrays_touched, 2);
         let p1_lower = tick_sequence.get_tick(0, 23168, TS_128).unwrap();
         let p1_upper = tick_sequence.get_tick(1, 37504, TS_128).unwrap();
         assert_swap_tick_state(p1_lower, &TickExpectation::default());
@@ -2823,3 +2909,36 @@ mod swap_error_tests {
         swap_test_info.run(&mut tick_sequence, 100);
     }
 }
+
+#[cfg(test)]
+mod calculate_fees_tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_fees_without_referral() {
+        let (protocol_fee, referral_fee, fee_growth_global) =
+            calculate_fees(10_000, 300, 0, 1 << 64, 5, 7, 0);
+        assert_eq!(protocol_fee, 5 + 300);
+        assert_eq!(referral_fee, 7);
+        assert_eq!(fee_growth_global, 9_700);
+    }
+
+    #[test]
+    fn test_calculate_fees_with_referral() {
+        // referral fee is taken from the LP fee remaining after the protocol fee
+        let (protocol_fee, referral_fee, fee_growth_global) =
+            calculate_fees(10_000, 300, 1_000, 1 << 64, 0, 0, 0);
+        assert_eq!(protocol_fee, 300);
+        assert_eq!(referral_fee, 970);
+        assert_eq!(fee_growth_global, 8_730);
+    }
+
+    #[test]
+    fn test_calculate_fees_with_referral_zero_liquidity() {
+        let (protocol_fee, referral_fee, fee_growth_global) =
+            calculate_fees(10_000, 0, MAX_REFERRAL_FEE_RATE, 0, 0, 0, 100);
+        assert_eq!(protocol_fee, 0);
+        assert_eq!(referral_fee, 5_000);
+        assert_eq!(fee_growth_global, 100);
+    }
+}
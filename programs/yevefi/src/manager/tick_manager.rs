@@ -1,7 +1,7 @@
 use crate::{
     errors::ErrorCode,
     math::add_liquidity_delta,
-    state::{Tick, TickUpdate, YevefiRewardInfo, NUM_REWARDS},
+    state::{Tick, TickUpdate, YevefiRewardInfo, NUM_EXTENSION_REWARDS, NUM_REWARDS},
 };
 
 pub fn next_tick_cross_update(
@@ -151,33 +151,71 @@ pub fn next_reward_growths_inside(
     tick_upper_index: i32,
     reward_infos: &[YevefiRewardInfo; NUM_REWARDS],
 ) -> [u128; NUM_REWARDS] {
-    let mut reward_growths_inside = [0; NUM_REWARDS];
+    reward_growths_inside(
+        tick_current_index,
+        tick_lower
+            .initialized
+            .then_some(tick_lower.reward_growths_outside),
+        tick_lower_index,
+        tick_upper
+            .initialized
+            .then_some(tick_upper.reward_growths_outside),
+        tick_upper_index,
+        reward_infos,
+    )
+}
+
+// Calculates the extension reward growths inside of tick_lower and tick_upper.
+// Every tick covered by a tick array reward extension carries an outside value, so the
+// extension rewards do not depend on whether the tick itself is initialized.
+pub fn next_extension_reward_growths_inside(
+    tick_current_index: i32,
+    tick_lower_reward_growths_outside: [u128; NUM_EXTENSION_REWARDS],
+    tick_lower_index: i32,
+    tick_upper_reward_growths_outside: [u128; NUM_EXTENSION_REWARDS],
+    tick_upper_index: i32,
+    reward_infos: &[YevefiRewardInfo; NUM_EXTENSION_REWARDS],
+) -> [u128; NUM_EXTENSION_REWARDS] {
+    reward_growths_inside(
+        tick_current_index,
+        Some(tick_lower_reward_growths_outside),
+        tick_lower_index,
+        Some(tick_upper_reward_growths_outside),
+        tick_upper_index,
+        reward_infos,
+    )
+}
+
+// A tick without reward growths outside is treated as uninitialized.
+fn reward_growths_inside<const N: usize>(
+    tick_current_index: i32,
+    tick_lower_reward_growths_outside: Option<[u128; N]>,
+    tick_lower_index: i32,
+    tick_upper_reward_growths_outside: Option<[u128; N]>,
+    tick_upper_index: i32,
+    reward_infos: &[YevefiRewardInfo; N],
+) -> [u128; N] {
+    let mut reward_growths_inside = [0; N];
 
-    for i in 0..NUM_REWARDS {
+    for i in 0..N {
         if !reward_infos[i].initialized() {
             continue;
         }
 
         // By convention, assume all prior growth happened below the tick
-        let reward_growths_below = if !tick_lower.initialized {
-            reward_infos[i].growth_global_x64
-        } else if tick_current_index < tick_lower_index {
-            reward_infos[i]
-                .growth_global_x64
-                .wrapping_sub(tick_lower.reward_growths_outside[i])
-        } else {
-            tick_lower.reward_growths_outside[i]
+        let reward_growths_below = match tick_lower_reward_growths_outside {
+            None => reward_infos[i].growth_global_x64,
+            Some(outside) if tick_current_index < tick_lower_index => {
+                reward_infos[i].growth_global_x64.wrapping_sub(outside[i])
+            }
+            Some(outside) => outside[i],
         };
 
         // By convention, assume all prior growth happened below the tick, not above
-        let reward_growths_above = if !tick_upper.initialized {
-            0
-        } else if tick_current_index < tick_upper_index {
-            tick_upper.reward_growths_outside[i]
-        } else {
-            reward_infos[i]
-                .growth_global_x64
-                .wrapping_sub(tick_upper.reward_growths_outside[i])
+        let reward_growths_above = match tick_upper_reward_growths_outside {
+            None => 0,
+            Some(outside) if tick_current_index < tick_upper_index => outside[i],
+            Some(outside) => reward_infos[i].growth_global_x64.wrapping_sub(outside[i]),
         };
 
         reward_growths_inside[i] = reward_infos[i]
@@ -203,7 +241,7 @@ mod tick_manager_tests {
         state::{tick_builder::TickBuilder, Tick, YevefiRewardInfo, NUM_REWARDS},
     };
 
-    use super::next_reward_growths_inside;
+    use super::{next_extension_reward_growths_inside, next_reward_growths_inside};
 
     fn create_test_yevefi_reward_info(
         emissions_per_second_x64: u128,
@@ -861,4 +899,22 @@ mod tick_manager_tests {
             }
         }
     }
+
+    #[test]
+    fn test_next_extension_reward_growths_inside() {
+        let reward_infos = [
+            create_test_yevefi_reward_info(1, 1000, true),
+            create_test_yevefi_reward_info(1, 2000, false),
+        ];
+
+        // current tick index inside ticks
+        let reward_growths_inside =
+            next_extension_reward_growths_inside(0, [200, 0], -10, [100, 0], 10, &reward_infos);
+        assert_eq!(reward_growths_inside, [700, 0]);
+
+        // zero outside values are still treated as initialized ticks
+        let reward_growths_inside =
+            next_extension_reward_growths_inside(20, [0, 0], -10, [0, 0], 10, &reward_infos);
+        assert_eq!(reward_growths_inside, [0, 0]);
+    }
 }
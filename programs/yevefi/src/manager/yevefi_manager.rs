@@ -1,40 +1,115 @@
 use crate::errors::ErrorCode;
-use crate::math::{add_liquidity_delta, checked_mul_div};
+use crate::math::{add_liquidity_delta, checked_mul_div, Q64_RESOLUTION};
 use crate::state::*;
 
 // Calculates the next global reward growth variables based on the given timestamp.
 // The provided timestamp must be greater than or equal to the last updated timestamp.
+// Emissions stop once the funded amount of a reward has been emitted.
 pub fn next_yevefi_reward_infos(
     yevefi: &Yevefi,
     next_timestamp: u64,
 ) -> Result<[YevefiRewardInfo; NUM_REWARDS], ErrorCode> {
-    let curr_timestamp = yevefi.reward_last_updated_timestamp;
+    let mut unemitted_rewards = [0u64; NUM_REWARDS];
+    for (unemitted, reward_totals) in unemitted_rewards
+        .iter_mut()
+        .zip(yevefi.reward_totals.iter())
+    {
+        *unemitted = reward_totals.unemitted();
+    }
+
+    next_reward_infos(
+        &yevefi.reward_infos,
+        &yevefi.reward_schedules,
+        &unemitted_rewards,
+        yevefi.liquidity,
+        yevefi.reward_last_updated_timestamp,
+        next_timestamp,
+    )
+}
+
+// Calculates the next global reward growth variables of the extension rewards of a yevefi.
+// The liquidity of the yevefi must not have changed since the extension rewards were last updated.
+// Extension rewards have no emission schedule, but their emissions stop once their funds run out.
+pub fn next_yevefi_reward_extension_infos(
+    yevefi: &Yevefi,
+    reward_extension: &YevefiRewardExtension,
+    next_timestamp: u64,
+) -> Result<[YevefiRewardInfo; NUM_EXTENSION_REWARDS], ErrorCode> {
+    let mut unemitted_rewards = [0u64; NUM_EXTENSION_REWARDS];
+    for (unemitted, reward_totals) in unemitted_rewards
+        .iter_mut()
+        .zip(reward_extension.reward_totals.iter())
+    {
+        *unemitted = reward_totals.unemitted();
+    }
+
+    next_reward_infos(
+        &reward_extension.reward_infos,
+        &[YevefiRewardSchedule::default(); NUM_EXTENSION_REWARDS],
+        &unemitted_rewards,
+        yevefi.liquidity,
+        reward_extension.reward_last_updated_timestamp,
+        next_timestamp,
+    )
+}
+
+fn next_reward_infos<const N: usize>(
+    reward_infos: &[YevefiRewardInfo; N],
+    reward_schedules: &[YevefiRewardSchedule; N],
+    unemitted_rewards: &[u64; N],
+    liquidity: u128,
+    curr_timestamp: u64,
+    next_timestamp: u64,
+) -> Result<[YevefiRewardInfo; N], ErrorCode> {
     if next_timestamp < curr_timestamp {
         return Err(ErrorCode::InvalidTimestamp);
     }
 
     // No-op if no liquidity or no change in timestamp
-    if yevefi.liquidity == 0 || next_timestamp == curr_timestamp {
-        return Ok(yevefi.reward_infos);
+    if liquidity == 0 || next_timestamp == curr_timestamp {
+        return Ok(*reward_infos);
     }
 
     // Calculate new global reward growth
-    let mut next_reward_infos = yevefi.reward_infos;
-    let time_delta = u128::from(next_timestamp - curr_timestamp);
-    for reward_info in next_reward_infos.iter_mut() {
+    let mut next_reward_infos = *reward_infos;
+    for ((reward_info, reward_schedule), unemitted) in next_reward_infos
+        .iter_mut()
+        .zip(reward_schedules.iter())
+        .zip(unemitted_rewards.iter())
+    {
         if !reward_info.initialized() {
             continue;
         }
 
-        // Calculate the new reward growth delta.
-        // If the calculation overflows, set the delta value to zero.
+        // Calculate the new reward growth delta, piecewise over the emission schedule.
+        // If the calculation overflows, set the delta value of the segment to zero.
         // This will halt reward distributions for this reward.
-        let reward_growth_delta = checked_mul_div(
-            time_delta,
-            reward_info.emissions_per_second_x64,
-            yevefi.liquidity,
-        )
-        .unwrap_or(0);
+        let mut reward_growth_delta: u128 = 0;
+        let mut segment_start_timestamp = curr_timestamp;
+        while segment_start_timestamp < next_timestamp {
+            let segment_end_timestamp = reward_schedule
+                .next_change_timestamp(segment_start_timestamp)
+                .map_or(next_timestamp, |timestamp| timestamp.min(next_timestamp));
+            let emissions_per_second_x64 = reward_schedule.emissions_per_second_x64_at(
+                reward_info.emissions_per_second_x64,
+                segment_start_timestamp,
+            );
+
+            let segment_reward_growth_delta = checked_mul_div(
+                u128::from(segment_end_timestamp - segment_start_timestamp),
+                emissions_per_second_x64,
+                liquidity,
+            )
+            .unwrap_or(0);
+            reward_growth_delta = reward_growth_delta.wrapping_add(segment_reward_growth_delta);
+
+            segment_start_timestamp = segment_end_timestamp;
+        }
+
+        // Cap the reward growth delta at the unemitted funds, rounded down so that the emissions
+        // recorded by the yevefi (rounded up) do not exceed the funds.
+        let max_reward_growth_delta = (u128::from(*unemitted) << Q64_RESOLUTION) / liquidity;
+        reward_growth_delta = reward_growth_delta.min(max_reward_growth_delta);
 
         // Add the reward growth delta to the global reward growth.
         let curr_growth_global = reward_info.growth_global_x64;
@@ -44,6 +119,43 @@ pub fn next_yevefi_reward_infos(
     Ok(next_reward_infos)
 }
 
+// Calculates the number of seconds from the given timestamp until the unemitted funds of a reward
+// run out at its scheduled emission rates, assuming the yevefi has liquidity.
+// Returns None if the reward is not initialized or the funds never run out.
+// The reward infos must be up to date, so that all emissions are recorded.
+pub fn reward_runway_seconds(yevefi: &Yevefi, index: usize, timestamp: u64) -> Option<u64> {
+    let reward_info = &yevefi.reward_infos[index];
+    if !reward_info.initialized() {
+        return None;
+    }
+
+    let reward_schedule = &yevefi.reward_schedules[index];
+    let mut unemitted_x64 = u128::from(yevefi.reward_totals[index].unemitted()) << Q64_RESOLUTION;
+    let mut segment_start_timestamp = timestamp;
+    loop {
+        let next_change_timestamp = reward_schedule.next_change_timestamp(segment_start_timestamp);
+        let emissions_per_second_x64 = reward_schedule.emissions_per_second_x64_at(
+            reward_info.emissions_per_second_x64,
+            segment_start_timestamp,
+        );
+
+        if let Some(seconds_until_empty) = unemitted_x64.checked_div(emissions_per_second_x64) {
+            let seconds_until_empty = u64::try_from(seconds_until_empty).unwrap_or(u64::MAX);
+            let segment_end_timestamp = segment_start_timestamp.saturating_add(seconds_until_empty);
+            match next_change_timestamp {
+                Some(change_timestamp) if change_timestamp < segment_end_timestamp => {
+                    // Cannot overflow, as the segment emits less than the unemitted funds
+                    unemitted_x64 -= emissions_per_second_x64
+                        * u128::from(change_timestamp - segment_start_timestamp);
+                }
+                _ => return Some(segment_end_timestamp - timestamp),
+            }
+        }
+
+        segment_start_timestamp = next_change_timestamp?;
+    }
+}
+
 // Calculates the next global liquidity for a yevefi depending on its position relative
 // to the lower and upper tick indexes and the liquidity_delta.
 pub fn next_yevefi_liquidity(
@@ -65,12 +177,18 @@ mod yevefi_manager_tests {
 
     use anchor_lang::prelude::Pubkey;
 
-    use crate::manager::yevefi_manager::next_yevefi_reward_infos;
+    use crate::manager::yevefi_manager::{
+        next_yevefi_reward_extension_infos, next_yevefi_reward_infos, reward_runway_seconds,
+    };
     use crate::math::Q64_RESOLUTION;
     use crate::state::yevefi::YevefiRewardInfo;
     use crate::state::yevefi::NUM_REWARDS;
+    use crate::state::yevefi::{
+        RewardEmissionsRateChange, YevefiRewardSchedule, YevefiRewardTotals,
+    };
     use crate::state::yevefi_builder::YevefiBuilder;
     use crate::state::Yevefi;
+    use crate::state::YevefiRewardExtension;
 
     // Initializes a yevefi for testing with all the rewards initialized
     fn init_test_yevefi(liquidity: u128, reward_last_updated_timestamp: u64) -> Yevefi {
@@ -97,6 +215,7 @@ mod yevefi_manager_tests {
                     ..Default::default()
                 },
             ])
+            .fully_funded_rewards()
             .build()
     }
 
@@ -167,6 +286,7 @@ mod yevefi_manager_tests {
                     ..Default::default()
                 },
             )
+            .fully_funded_rewards()
             .build();
 
         let new_timestamp = 1577854800 + 300;
@@ -214,4 +334,209 @@ mod yevefi_manager_tests {
             0b1001011011 << (Q64_RESOLUTION - 1) // 301.5
         );
     }
+
+    #[test]
+    fn test_next_yevefi_reward_infos_scheduled_emissions() {
+        let mut yevefi = init_test_yevefi(100, 1577854800);
+        // reward 0: starts 100s after the last update and ends 200s after the last update
+        yevefi.reward_schedules[0] =
+            YevefiRewardSchedule::new(1577854800 + 100, 1577854800 + 200, &[]).unwrap();
+        // reward 1: rate changes from 1.5 to 3 200s after the last update
+        yevefi.reward_schedules[1] = YevefiRewardSchedule::new(
+            0,
+            0,
+            &[RewardEmissionsRateChange {
+                timestamp: 1577854800 + 200,
+                emissions_per_second_x64: 3 << Q64_RESOLUTION,
+            }],
+        )
+        .unwrap();
+        // reward 2: ended before the last update
+        yevefi.reward_schedules[2] = YevefiRewardSchedule::new(0, 1577854800, &[]).unwrap();
+
+        let new_timestamp = 1577854800 + 300;
+        let result = next_yevefi_reward_infos(&yevefi, new_timestamp).unwrap();
+        // 100 + 100s * 10 / 100
+        assert_eq!(result[0].growth_global_x64, 110 << Q64_RESOLUTION);
+        // 200 + 200s * 1.5 / 100 + 100s * 3 / 100
+        assert_eq!(result[1].growth_global_x64, 206 << Q64_RESOLUTION);
+        assert_eq!(result[2].growth_global_x64, 300 << Q64_RESOLUTION);
+    }
+
+    #[test]
+    fn test_next_yevefi_reward_infos_scheduled_emissions_not_started() {
+        let mut yevefi = init_test_yevefi(100, 1577854800);
+        yevefi.reward_schedules[0] = YevefiRewardSchedule::new(1577854800 + 500, 0, &[]).unwrap();
+
+        let new_timestamp = 1577854800 + 300;
+        let result = next_yevefi_reward_infos(&yevefi, new_timestamp).unwrap();
+        assert_eq!(result[0].growth_global_x64, 100 << Q64_RESOLUTION);
+    }
+
+    #[test]
+    fn test_next_yevefi_reward_infos_emissions_stop_at_funded_amount() {
+        let mut yevefi = init_test_yevefi(100, 1577854800);
+        // reward 0: 3000 emitted over 300s at 10 per second, 2000 left
+        yevefi.reward_totals[0] = YevefiRewardTotals {
+            emitted: 1000,
+            collected: 0,
+            funded: 3000,
+            ..Default::default()
+        };
+        // reward 1: fully emitted
+        yevefi.reward_totals[1] = YevefiRewardTotals {
+            emitted: 1000,
+            collected: 500,
+            funded: 1000,
+            ..Default::default()
+        };
+
+        let new_timestamp = 1577854800 + 300;
+        let result = next_yevefi_reward_infos(&yevefi, new_timestamp).unwrap();
+        // 100 + 2000 / 100
+        assert_eq!(result[0].growth_global_x64, 120 << Q64_RESOLUTION);
+        assert_eq!(result[1].growth_global_x64, 200 << Q64_RESOLUTION);
+        assert_eq!(
+            result[2].growth_global_x64,
+            0b1001011011 << (Q64_RESOLUTION - 1) // 301.5
+        );
+
+        yevefi.update_rewards(result, new_timestamp);
+        assert_eq!(yevefi.reward_totals[0].emitted, 3000);
+        assert_eq!(yevefi.reward_totals[1].emitted, 1000);
+    }
+
+    #[test]
+    fn test_next_yevefi_reward_infos_emissions_rounded_down_at_funded_amount() {
+        let mut yevefi = init_test_yevefi(3, 1577854800);
+        yevefi.reward_totals[0] = YevefiRewardTotals {
+            emitted: 0,
+            collected: 0,
+            funded: 1,
+            ..Default::default()
+        };
+
+        let result = next_yevefi_reward_infos(&yevefi, 1577854800 + 300).unwrap();
+        yevefi.update_rewards(result, 1577854800 + 300);
+        assert_eq!(yevefi.reward_totals[0].emitted, 1);
+    }
+
+    #[test]
+    fn test_reward_runway_seconds() {
+        let mut yevefi = init_test_yevefi(100, 1577854800);
+        // reward 0: 10 per second, 2500 left
+        yevefi.reward_totals[0].funded = 2500;
+        // reward 1: 1.5 per second until the end 10s later
+        yevefi.reward_totals[1].funded = 10_000;
+        yevefi.reward_schedules[1] = YevefiRewardSchedule::new(0, 1577854800 + 10, &[]).unwrap();
+        // reward 2: 0.5 per second, 300 per second from 4s later
+        yevefi.reward_totals[2].funded = 1202;
+        yevefi.reward_schedules[2] = YevefiRewardSchedule::new(
+            0,
+            0,
+            &[RewardEmissionsRateChange {
+                timestamp: 1577854800 + 4,
+                emissions_per_second_x64: 300 << Q64_RESOLUTION,
+            }],
+        )
+        .unwrap();
+
+        assert_eq!(reward_runway_seconds(&yevefi, 0, 1577854800), Some(250));
+        assert_eq!(reward_runway_seconds(&yevefi, 1, 1577854800), None);
+        // 4s * 0.5 + 4s * 300
+        assert_eq!(reward_runway_seconds(&yevefi, 2, 1577854800), Some(8));
+    }
+
+    #[test]
+    fn test_reward_runway_seconds_not_started() {
+        let mut yevefi = init_test_yevefi(100, 1577854800);
+        yevefi.reward_totals[0].funded = 2500;
+        yevefi.reward_schedules[0] = YevefiRewardSchedule::new(1577854800 + 100, 0, &[]).unwrap();
+
+        assert_eq!(reward_runway_seconds(&yevefi, 0, 1577854800), Some(350));
+    }
+
+    #[test]
+    fn test_reward_runway_seconds_uninitialized_reward() {
+        let yevefi = YevefiBuilder::new().liquidity(100).build();
+        assert_eq!(reward_runway_seconds(&yevefi, 0, 1577854800), None);
+    }
+
+    #[test]
+    fn test_next_yevefi_reward_extension_infos() {
+        let yevefi = init_test_yevefi(100, 1577854800);
+        let reward_extension = YevefiRewardExtension {
+            reward_last_updated_timestamp: 1577854800 + 100,
+            reward_infos: [
+                YevefiRewardInfo {
+                    mint: Pubkey::new_unique(),
+                    emissions_per_second_x64: 10 << Q64_RESOLUTION,
+                    growth_global_x64: 100 << Q64_RESOLUTION,
+                    ..Default::default()
+                },
+                YevefiRewardInfo::default(),
+            ],
+            reward_totals: [
+                YevefiRewardTotals {
+                    emitted: 0,
+                    collected: 0,
+                    funded: 5000,
+                    ..Default::default()
+                },
+                YevefiRewardTotals::default(),
+            ],
+            ..Default::default()
+        };
+
+        // extension rewards are accrued from their own timestamp
+        let new_timestamp = 1577854800 + 300;
+        let result =
+            next_yevefi_reward_extension_infos(&yevefi, &reward_extension, new_timestamp).unwrap();
+        assert_eq!(result[0].growth_global_x64, 120 << Q64_RESOLUTION);
+        assert_eq!(result[1].growth_global_x64, 0);
+    }
+
+    #[test]
+    fn test_next_yevefi_reward_extension_infos_emissions_stop_at_funded_amount() {
+        let yevefi = init_test_yevefi(100, 1577854800);
+        let mut reward_extension = YevefiRewardExtension {
+            reward_last_updated_timestamp: 1577854800,
+            reward_infos: [
+                YevefiRewardInfo {
+                    mint: Pubkey::new_unique(),
+                    emissions_per_second_x64: 10 << Q64_RESOLUTION,
+                    growth_global_x64: 100 << Q64_RESOLUTION,
+                    ..Default::default()
+                },
+                YevefiRewardInfo {
+                    mint: Pubkey::new_unique(),
+                    emissions_per_second_x64: 10 << Q64_RESOLUTION,
+                    ..Default::default()
+                },
+            ],
+            reward_totals: [
+                // 3000 emitted over 300s at 10 per second, 1000 left
+                YevefiRewardTotals {
+                    emitted: 500,
+                    collected: 0,
+                    funded: 1500,
+                    ..Default::default()
+                },
+                // never funded
+                YevefiRewardTotals::default(),
+            ],
+            ..Default::default()
+        };
+
+        let new_timestamp = 1577854800 + 300;
+        let result =
+            next_yevefi_reward_extension_infos(&yevefi, &reward_extension, new_timestamp).unwrap();
+        // 100 + 1000 / 100
+        assert_eq!(result[0].growth_global_x64, 110 << Q64_RESOLUTION);
+        assert_eq!(result[1].growth_global_x64, 0);
+
+        reward_extension.update_rewards(yevefi.liquidity, result, new_timestamp);
+        assert_eq!(reward_extension.reward_totals[0].emitted, 1500);
+        assert_eq!(reward_extension.reward_totals[1].emitted, 0);
+    }
 }
@@ -1,5 +1,7 @@
 use crate::errors::ErrorCode;
 
+use super::{mul_u256, sqrt_price_from_tick_index, U256Muldiv};
+
 // Adds a signed liquidity delta to a given integer liquidity amount.
 // Errors on overflow or underflow.
 pub fn add_liquidity_delta(liquidity: u128, delta: i128) -> Result<u128, ErrorCode> {
@@ -35,6 +37,98 @@ pub fn convert_to_liquidity_delta(
     })
 }
 
+// Returns the maximum liquidity whose token deltas (rounded up, see calculate_liquidity_token_deltas)
+// do not exceed amount_a and amount_b for a position in the given tick range.
+pub fn get_liquidity_from_amounts(
+    current_tick_index: i32,
+    sqrt_price: u128,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+    amount_a: u64,
+    amount_b: u64,
+) -> Result<u128, ErrorCode> {
+    let (liquidity_a, liquidity_b) = get_liquidity_bounds_from_amounts(
+        current_tick_index,
+        sqrt_price,
+        tick_lower_index,
+        tick_upper_index,
+        amount_a,
+        amount_b,
+    )?;
+    Ok(liquidity_a.min(liquidity_b))
+}
+
+// Returns the maximum liquidity that amount_a and amount_b can fund respectively.
+// A token that is not required at the current price is not a bound, and u128::MAX is returned for it.
+pub fn get_liquidity_bounds_from_amounts(
+    current_tick_index: i32,
+    sqrt_price: u128,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+    amount_a: u64,
+    amount_b: u64,
+) -> Result<(u128, u128), ErrorCode> {
+    let lower_price = sqrt_price_from_tick_index(tick_lower_index);
+    let upper_price = sqrt_price_from_tick_index(tick_upper_index);
+
+    if current_tick_index < tick_lower_index {
+        // current tick below position
+        Ok((
+            get_liquidity_from_amount_a(lower_price, upper_price, amount_a)?,
+            u128::MAX,
+        ))
+    } else if current_tick_index < tick_upper_index {
+        // current tick inside position
+        Ok((
+            get_liquidity_from_amount_a(sqrt_price, upper_price, amount_a)?,
+            get_liquidity_from_amount_b(lower_price, sqrt_price, amount_b)?,
+        ))
+    } else {
+        // current tick above position
+        Ok((
+            u128::MAX,
+            get_liquidity_from_amount_b(lower_price, upper_price, amount_b)?,
+        ))
+    }
+}
+
+// liquidity = amount_a * sqrt_price_upper * sqrt_price_lower / (sqrt_price_upper - sqrt_price_lower)
+// Rounded down, so the amount delta of the liquidity does not exceed amount_a.
+fn get_liquidity_from_amount_a(
+    sqrt_price_lower: u128,
+    sqrt_price_upper: u128,
+    amount_a: u64,
+) -> Result<u128, ErrorCode> {
+    let sqrt_price_diff = sqrt_price_upper - sqrt_price_lower;
+    if sqrt_price_diff == 0 {
+        return Ok(u128::MAX);
+    }
+
+    // sqrt prices are less than 2^96, so the numerator fits in 256 bits.
+    let numerator =
+        mul_u256(sqrt_price_upper, sqrt_price_lower).mul(U256Muldiv::new(0, u128::from(amount_a)));
+    let denominator = U256Muldiv::new(0, sqrt_price_diff).shift_left(64);
+
+    let (quotient, _) = numerator.div(denominator, false);
+
+    quotient.try_into_u128()
+}
+
+// liquidity = amount_b / (sqrt_price_upper - sqrt_price_lower)
+// Rounded down, so the amount delta of the liquidity does not exceed amount_b.
+fn get_liquidity_from_amount_b(
+    sqrt_price_lower: u128,
+    sqrt_price_upper: u128,
+    amount_b: u64,
+) -> Result<u128, ErrorCode> {
+    let sqrt_price_diff = sqrt_price_upper - sqrt_price_lower;
+    if sqrt_price_diff == 0 {
+        return Ok(u128::MAX);
+    }
+
+    Ok((u128::from(amount_b) << 64) / sqrt_price_diff)
+}
+
 #[cfg(test)]
 mod liquidity_math_tests {
     use super::add_liquidity_delta;
@@ -59,3 +153,105 @@ mod liquidity_math_tests {
         assert_eq!(result.unwrap_err(), ErrorCode::LiquidityUnderflow);
     }
 }
+
+#[cfg(test)]
+mod get_liquidity_from_amounts_tests {
+    use super::{get_liquidity_bounds_from_amounts, get_liquidity_from_amounts};
+    use crate::math::{get_amount_delta_a, get_amount_delta_b, sqrt_price_from_tick_index};
+
+    fn assert_max_liquidity(
+        current_tick_index: i32,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+        amount_a: u64,
+        amount_b: u64,
+    ) -> u128 {
+        let sqrt_price = sqrt_price_from_tick_index(current_tick_index);
+        let lower_price = sqrt_price_from_tick_index(tick_lower_index);
+        let upper_price = sqrt_price_from_tick_index(tick_upper_index);
+
+        let liquidity = get_liquidity_from_amounts(
+            current_tick_index,
+            sqrt_price,
+            tick_lower_index,
+            tick_upper_index,
+            amount_a,
+            amount_b,
+        )
+        .unwrap();
+
+        let (price_a, price_b) = if current_tick_index < tick_lower_index {
+            (lower_price, lower_price)
+        } else if current_tick_index < tick_upper_index {
+            (sqrt_price, sqrt_price)
+        } else {
+            (upper_price, upper_price)
+        };
+        let delta_a = get_amount_delta_a(price_a, upper_price, liquidity, true).unwrap();
+        let delta_b = get_amount_delta_b(lower_price, price_b, liquidity, true).unwrap();
+        assert!(delta_a <= amount_a);
+        assert!(delta_b <= amount_b);
+
+        liquidity
+    }
+
+    #[test]
+    fn test_current_tick_below_range() {
+        let liquidity = assert_max_liquidity(-200, -128, 128, 1_000_000, 0);
+        assert!(liquidity > 0);
+        // token b is not used below the range
+        assert_eq!(
+            liquidity,
+            assert_max_liquidity(-200, -128, 128, 1_000_000, 1_000_000)
+        );
+    }
+
+    #[test]
+    fn test_current_tick_above_range() {
+        let liquidity = assert_max_liquidity(200, -128, 128, 0, 1_000_000);
+        assert!(liquidity > 0);
+        // token a is not used above the range
+        assert_eq!(
+            liquidity,
+            assert_max_liquidity(200, -128, 128, 1_000_000, 1_000_000)
+        );
+    }
+
+    #[test]
+    fn test_current_tick_inside_range() {
+        let liquidity = assert_max_liquidity(0, -128, 128, 1_000_000, 1_000_000);
+        assert!(liquidity > 0);
+        // limited by the scarcer token
+        assert_eq!(assert_max_liquidity(0, -128, 128, 1_000_000, 0), 0);
+        assert_eq!(assert_max_liquidity(0, -128, 128, 0, 1_000_000), 0);
+    }
+
+    #[test]
+    fn test_current_tick_at_lower_tick() {
+        // token b range is empty at the lower tick
+        let liquidity = assert_max_liquidity(-128, -128, 128, 1_000_000, 0);
+        assert!(liquidity > 0);
+    }
+
+    #[test]
+    fn test_bounds_of_unused_token() {
+        let sqrt_price = sqrt_price_from_tick_index(-200);
+        let (liquidity_a, liquidity_b) =
+            get_liquidity_bounds_from_amounts(-200, sqrt_price, -128, 128, 1_000_000, 0).unwrap();
+        assert!(liquidity_a > 0);
+        assert_eq!(liquidity_b, u128::MAX);
+
+        let sqrt_price = sqrt_price_from_tick_index(200);
+        let (liquidity_a, liquidity_b) =
+            get_liquidity_bounds_from_amounts(200, sqrt_price, -128, 128, 0, 1_000_000).unwrap();
+        assert_eq!(liquidity_a, u128::MAX);
+        assert!(liquidity_b > 0);
+    }
+
+    #[test]
+    fn test_large_amounts() {
+        assert_max_liquidity(0, -443584, 443584, u64::MAX, u64::MAX);
+        assert_max_liquidity(-443636, -443584, 443584, u64::MAX, u64::MAX);
+        assert_max_liquidity(443636, -443584, 443584, u64::MAX, u64::MAX);
+    }
+}
@@ -24,6 +24,14 @@ pub const MAX_PROTOCOL_FEE_RATE: u16 = 2_500;
 // We want PROTOCOL_FEE_RATE_MUL_VALUE = 1/PROTOCOL_FEE_UNIT, so 1e4
 pub const PROTOCOL_FEE_RATE_MUL_VALUE: u128 = 10_000;
 
+// Referral fee rate is represented as a basis point.
+// Referral fee amount = lp_fee_amount * referral_fee_rate / 10_000.
+// Max referral fee rate supported is 50% of the LP fee.
+pub const MAX_REFERRAL_FEE_RATE: u16 = 5_000;
+
+// We want REFERRAL_FEE_RATE_MUL_VALUE = 1/REFERRAL_FEE_UNIT, so 1e4
+pub const REFERRAL_FEE_RATE_MUL_VALUE: u128 = 10_000;
+
 #[derive(Debug)]
 pub enum AmountDeltaU64 {
     Valid(u64),
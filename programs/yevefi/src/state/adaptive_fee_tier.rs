@@ -0,0 +1,241 @@
+use crate::state::YevefisConfig;
+use crate::{errors::ErrorCode, math::MAX_FEE_RATE};
+use anchor_lang::prelude::*;
+
+pub const ADAPTIVE_FEE_CONTROL_FACTOR_DENOMINATOR: u32 = 100_000;
+pub const REDUCTION_FACTOR_DENOMINATOR: u16 = 10_000;
+// The volatility accumulator grows by this amount for each tick group the price moves away from the reference
+pub const VOLATILITY_ACCUMULATOR_SCALE_FACTOR: u16 = 10_000;
+
+#[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, Default, Debug, PartialEq)]
+pub struct AdaptiveFeeConstants {
+    // Seconds since the last reference update during which the references are kept
+    pub filter_period: u16,
+    // Seconds since the last reference update after which the volatility reference is reset
+    pub decay_period: u16,
+    // Stored as a ratio of REDUCTION_FACTOR_DENOMINATOR
+    // Applied to the volatility accumulator when the references are updated within decay_period
+    pub reduction_factor: u16,
+    // Stored as a ratio of ADAPTIVE_FEE_CONTROL_FACTOR_DENOMINATOR
+    // Zero means the adaptive fee is disabled
+    pub adaptive_fee_control_factor: u32,
+    pub max_volatility_accumulator: u32,
+}
+
+impl AdaptiveFeeConstants {
+    pub const LEN: usize = 2 + 2 + 2 + 4 + 4;
+
+    pub fn validate(&self) -> Result<()> {
+        if self.filter_period >= self.decay_period
+            || self.reduction_factor > REDUCTION_FACTOR_DENOMINATOR
+            || self.adaptive_fee_control_factor > ADAPTIVE_FEE_CONTROL_FACTOR_DENOMINATOR
+        {
+            return Err(ErrorCode::InvalidAdaptiveFeeConstants.into());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, Default, Debug, PartialEq)]
+pub struct AdaptiveFeeVariables {
+    pub last_reference_update_timestamp: u64,
+    pub volatility_reference: u32,
+    // tick_index / tick_spacing (rounded toward negative infinity)
+    pub tick_group_index_reference: i32,
+    pub volatility_accumulator: u32,
+}
+
+impl AdaptiveFeeVariables {
+    pub const LEN: usize = 8 + 4 + 4 + 4;
+}
+
+/// Adaptive fee state of a Yevefi.
+/// The fee of a swap step is `Yevefi.fee_rate` (the base fee) plus a variable fee derived from
+/// the volatility accumulator, capped at MAX_FEE_RATE.
+#[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, Default, Debug, PartialEq)]
+pub struct AdaptiveFeeInfo {
+    pub constants: AdaptiveFeeConstants,
+    pub variables: AdaptiveFeeVariables,
+}
+
+impl AdaptiveFeeInfo {
+    pub const LEN: usize = AdaptiveFeeConstants::LEN + AdaptiveFeeVariables::LEN;
+
+    pub fn is_enabled(&self) -> bool {
+        self.constants.adaptive_fee_control_factor > 0
+    }
+}
+
+#[account]
+pub struct AdaptiveFeeTier {
+    pub yevefis_config: Pubkey,
+    // Used as the tick_spacing seed of the yevefis using this tier
+    pub fee_tier_index: u16,
+    pub tick_spacing: u16,
+    pub default_base_fee_rate: u16,
+    pub adaptive_fee_constants: AdaptiveFeeConstants,
+}
+
+impl AdaptiveFeeTier {
+    pub const LEN: usize = 8 + 32 + 2 + 2 + 2 + AdaptiveFeeConstants::LEN;
+
+    pub fn initialize(
+        &mut self,
+        yevefis_config: &Account<YevefisConfig>,
+        fee_tier_index: u16,
+        tick_spacing: u16,
+        default_base_fee_rate: u16,
+        adaptive_fee_constants: AdaptiveFeeConstants,
+    ) -> Result<()> {
+        // Yevefis of a FeeTier use tick_spacing as seed, so the index must not collide with it
+        if fee_tier_index == tick_spacing {
+            return Err(ErrorCode::InvalidFeeTierIndex.into());
+        }
+        if tick_spacing == 0 {
+            return Err(ErrorCode::InvalidTickSpacing.into());
+        }
+        adaptive_fee_constants.validate()?;
+
+        self.yevefis_config = yevefis_config.key();
+        self.fee_tier_index = fee_tier_index;
+        self.tick_spacing = tick_spacing;
+        self.update_default_base_fee_rate(default_base_fee_rate)?;
+        self.adaptive_fee_constants = adaptive_fee_constants;
+        Ok(())
+    }
+
+    pub fn update_default_base_fee_rate(&mut self, default_base_fee_rate: u16) -> Result<()> {
+        if default_base_fee_rate > MAX_FEE_RATE {
+            return Err(ErrorCode::FeeRateMaxExceeded.into());
+        }
+        self.default_base_fee_rate = default_base_fee_rate;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod data_layout_tests {
+    use anchor_lang::Discriminator;
+
+    use super::*;
+
+    #[test]
+    fn test_adaptive_fee_tier_data_layout() {
+        let adaptive_fee_tier_yevefis_config = Pubkey::new_unique();
+        let adaptive_fee_tier_fee_tier_index = 0x1122u16;
+        let adaptive_fee_tier_tick_spacing = 0x3344u16;
+        let adaptive_fee_tier_default_base_fee_rate = 0x5566u16;
+        let filter_period = 0x7788u16;
+        let decay_period = 0x99aau16;
+        let reduction_factor = 0xbbccu16;
+        let adaptive_fee_control_factor = 0x11223344u32;
+        let max_volatility_accumulator = 0x55667788u32;
+
+        let mut adaptive_fee_tier_data = [0u8; AdaptiveFeeTier::LEN];
+        let mut offset = 0;
+        adaptive_fee_tier_data[offset..offset + 8]
+            .copy_from_slice(&AdaptiveFeeTier::discriminator());
+        offset += 8;
+        adaptive_fee_tier_data[offset..offset + 32]
+            .copy_from_slice(&adaptive_fee_tier_yevefis_config.to_bytes());
+        offset += 32;
+        adaptive_fee_tier_data[offset..offset + 2]
+            .copy_from_slice(&adaptive_fee_tier_fee_tier_index.to_le_bytes());
+        offset += 2;
+        adaptive_fee_tier_data[offset..offset + 2]
+            .copy_from_slice(&adaptive_fee_tier_tick_spacing.to_le_bytes());
+        offset += 2;
+        adaptive_fee_tier_data[offset..offset + 2]
+            .copy_from_slice(&adaptive_fee_tier_default_base_fee_rate.to_le_bytes());
+        offset += 2;
+        adaptive_fee_tier_data[offset..offset + 2].copy_from_slice(&filter_period.to_le_bytes());
+        offset += 2;
+        adaptive_fee_tier_data[offset..offset + 2].copy_from_slice(&decay_period.to_le_bytes());
+        offset += 2;
+        adaptive_fee_tier_data[offset..offset + 2].copy_from_slice(&reduction_factor.to_le_bytes());
+        offset += 2;
+        adaptive_fee_tier_data[offset..offset + 4]
+            .copy_from_slice(&adaptive_fee_control_factor.to_le_bytes());
+        offset += 4;
+        adaptive_fee_tier_data[offset..offset + 4]
+            .copy_from_slice(&max_volatility_accumulator.to_le_bytes());
+        offset += 4;
+        assert_eq!(offset, AdaptiveFeeTier::LEN);
+
+        // deserialize
+        let deserialized =
+            AdaptiveFeeTier::try_deserialize(&mut adaptive_fee_tier_data.as_ref()).unwrap();
+
+        assert_eq!(
+            adaptive_fee_tier_yevefis_config,
+            deserialized.yevefis_config
+        );
+        assert_eq!(
+            adaptive_fee_tier_fee_tier_index,
+            deserialized.fee_tier_index
+        );
+        assert_eq!(adaptive_fee_tier_tick_spacing, deserialized.tick_spacing);
+        assert_eq!(
+            adaptive_fee_tier_default_base_fee_rate,
+            deserialized.default_base_fee_rate
+        );
+        assert_eq!(
+            AdaptiveFeeConstants {
+                filter_period,
+                decay_period,
+                reduction_factor,
+                adaptive_fee_control_factor,
+                max_volatility_accumulator,
+            },
+            deserialized.adaptive_fee_constants
+        );
+
+        // serialize
+        let mut serialized = Vec::new();
+        deserialized.try_serialize(&mut serialized).unwrap();
+
+        assert_eq!(serialized.as_slice(), adaptive_fee_tier_data.as_ref());
+    }
+}
+
+#[cfg(test)]
+mod adaptive_fee_constants_tests {
+    use super::*;
+
+    fn constants() -> AdaptiveFeeConstants {
+        AdaptiveFeeConstants {
+            filter_period: 30,
+            decay_period: 600,
+            reduction_factor: 5_000,
+            adaptive_fee_control_factor: 4_000,
+            max_volatility_accumulator: 350_000,
+        }
+    }
+
+    #[test]
+    fn test_validate_ok() {
+        assert!(constants().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_filter_period_not_below_decay_period() {
+        let mut invalid = constants();
+        invalid.filter_period = invalid.decay_period;
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_reduction_factor_too_large() {
+        let mut invalid = constants();
+        invalid.reduction_factor = REDUCTION_FACTOR_DENOMINATOR + 1;
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_control_factor_too_large() {
+        let mut invalid = constants();
+        invalid.adaptive_fee_control_factor = ADAPTIVE_FEE_CONTROL_FACTOR_DENOMINATOR + 1;
+        assert!(invalid.validate().is_err());
+    }
+}
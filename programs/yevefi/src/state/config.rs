@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::ErrorCode, math::MAX_PROTOCOL_FEE_RATE};
+use crate::{
+    errors::ErrorCode,
+    math::{MAX_PROTOCOL_FEE_RATE, MAX_REFERRAL_FEE_RATE},
+};
 
 #[account]
 pub struct YevefisConfig {
@@ -9,13 +12,34 @@ pub struct YevefisConfig {
     pub reward_emissions_super_authority: Pubkey,
 
     pub default_protocol_fee_rate: u16,
+
+    // Portion of the LP fee of a referred swap paid to the referrer, stored as basis points
+    pub referral_fee_rate: u16,
+
+    // Authorities proposed by the current authorities, which take over once they accept.
+    // Pubkey::default() if no transfer is pending.
+    pub pending_fee_authority: Pubkey,
+    pub pending_collect_protocol_fees_authority: Pubkey,
+    pub pending_reward_emissions_super_authority: Pubkey,
 }
 
 impl YevefisConfig {
-    pub const LEN: usize = 8 + 96 + 4;
+    // Layout before the pending authorities were appended, see `migrate_yevefis_config`
+    pub const LEGACY_LEN: usize = 8 + 96 + 4;
+
+    pub const LEN: usize = 8 + 96 + 4 + 96;
 
     pub fn update_fee_authority(&mut self, fee_authority: Pubkey) {
         self.fee_authority = fee_authority;
+        self.pending_fee_authority = Pubkey::default();
+    }
+
+    pub fn update_pending_fee_authority(&mut self, pending_fee_authority: Pubkey) {
+        self.pending_fee_authority = pending_fee_authority;
+    }
+
+    pub fn accept_fee_authority(&mut self) {
+        self.update_fee_authority(self.pending_fee_authority);
     }
 
     pub fn update_collect_protocol_fees_authority(
@@ -23,6 +47,18 @@ impl YevefisConfig {
         collect_protocol_fees_authority: Pubkey,
     ) {
         self.collect_protocol_fees_authority = collect_protocol_fees_authority;
+        self.pending_collect_protocol_fees_authority = Pubkey::default();
+    }
+
+    pub fn update_pending_collect_protocol_fees_authority(
+        &mut self,
+        pending_collect_protocol_fees_authority: Pubkey,
+    ) {
+        self.pending_collect_protocol_fees_authority = pending_collect_protocol_fees_authority;
+    }
+
+    pub fn accept_collect_protocol_fees_authority(&mut self) {
+        self.update_collect_protocol_fees_authority(self.pending_collect_protocol_fees_authority);
     }
 
     pub fn initialize(
@@ -45,6 +81,18 @@ impl YevefisConfig {
         reward_emissions_super_authority: Pubkey,
     ) {
         self.reward_emissions_super_authority = reward_emissions_super_authority;
+        self.pending_reward_emissions_super_authority = Pubkey::default();
+    }
+
+    pub fn update_pending_reward_emissions_super_authority(
+        &mut self,
+        pending_reward_emissions_super_authority: Pubkey,
+    ) {
+        self.pending_reward_emissions_super_authority = pending_reward_emissions_super_authority;
+    }
+
+    pub fn accept_reward_emissions_super_authority(&mut self) {
+        self.update_reward_emissions_super_authority(self.pending_reward_emissions_super_authority);
     }
 
     pub fn update_default_protocol_fee_rate(
@@ -58,6 +106,83 @@ impl YevefisConfig {
 
         Ok(())
     }
+
+    pub fn update_referral_fee_rate(&mut self, referral_fee_rate: u16) -> Result<()> {
+        if referral_fee_rate > MAX_REFERRAL_FEE_RATE {
+            return Err(ErrorCode::ReferralFeeRateMaxExceeded.into());
+        }
+        self.referral_fee_rate = referral_fee_rate;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod authority_transfer_tests {
+    use super::*;
+
+    fn test_config() -> YevefisConfig {
+        YevefisConfig {
+            fee_authority: Pubkey::new_unique(),
+            collect_protocol_fees_authority: Pubkey::new_unique(),
+            reward_emissions_super_authority: Pubkey::new_unique(),
+            default_protocol_fee_rate: 0,
+            referral_fee_rate: 0,
+            pending_fee_authority: Pubkey::default(),
+            pending_collect_protocol_fees_authority: Pubkey::default(),
+            pending_reward_emissions_super_authority: Pubkey::default(),
+        }
+    }
+
+    #[test]
+    fn test_accept_fee_authority() {
+        let mut config = test_config();
+        let fee_authority = config.fee_authority;
+        let new_fee_authority = Pubkey::new_unique();
+
+        config.update_pending_fee_authority(new_fee_authority);
+        assert_eq!(config.fee_authority, fee_authority);
+
+        config.accept_fee_authority();
+        assert_eq!(config.fee_authority, new_fee_authority);
+        assert_eq!(config.pending_fee_authority, Pubkey::default());
+    }
+
+    #[test]
+    fn test_cancel_pending_fee_authority() {
+        let mut config = test_config();
+        config.update_pending_fee_authority(Pubkey::new_unique());
+        config.update_pending_fee_authority(Pubkey::default());
+        assert_eq!(config.pending_fee_authority, Pubkey::default());
+    }
+
+    #[test]
+    fn test_accept_collect_protocol_fees_authority() {
+        let mut config = test_config();
+        let new_authority = Pubkey::new_unique();
+
+        config.update_pending_collect_protocol_fees_authority(new_authority);
+        config.accept_collect_protocol_fees_authority();
+        assert_eq!(config.collect_protocol_fees_authority, new_authority);
+        assert_eq!(
+            config.pending_collect_protocol_fees_authority,
+            Pubkey::default()
+        );
+    }
+
+    #[test]
+    fn test_accept_reward_emissions_super_authority() {
+        let mut config = test_config();
+        let new_authority = Pubkey::new_unique();
+
+        config.update_pending_reward_emissions_super_authority(new_authority);
+        config.accept_reward_emissions_super_authority();
+        assert_eq!(config.reward_emissions_super_authority, new_authority);
+        assert_eq!(
+            config.pending_reward_emissions_super_authority,
+            Pubkey::default()
+        );
+    }
 }
 
 #[cfg(test)]
@@ -72,7 +197,10 @@ mod data_layout_tests {
         let config_collect_protocol_fees_authority = Pubkey::new_unique();
         let config_reward_emissions_super_authority = Pubkey::new_unique();
         let config_default_protocol_fee_rate = 0xffeeu16;
-        let config_reserved = [0u8; 2];
+        let config_referral_fee_rate = 0xddccu16;
+        let config_pending_fee_authority = Pubkey::new_unique();
+        let config_pending_collect_protocol_fees_authority = Pubkey::new_unique();
+        let config_pending_reward_emissions_super_authority = Pubkey::new_unique();
 
         let mut config_data = [0u8; YevefisConfig::LEN];
         let mut offset = 0;
@@ -89,8 +217,16 @@ mod data_layout_tests {
         config_data[offset..offset + 2]
             .copy_from_slice(&config_default_protocol_fee_rate.to_le_bytes());
         offset += 2;
-        config_data[offset..offset + config_reserved.len()].copy_from_slice(&config_reserved);
-        offset += config_reserved.len();
+        config_data[offset..offset + 2].copy_from_slice(&config_referral_fee_rate.to_le_bytes());
+        offset += 2;
+        config_data[offset..offset + 32].copy_from_slice(&config_pending_fee_authority.to_bytes());
+        offset += 32;
+        config_data[offset..offset + 32]
+            .copy_from_slice(&config_pending_collect_protocol_fees_authority.to_bytes());
+        offset += 32;
+        config_data[offset..offset + 32]
+            .copy_from_slice(&config_pending_reward_emissions_super_authority.to_bytes());
+        offset += 32;
         assert_eq!(offset, YevefisConfig::LEN);
 
         // deserialize
@@ -109,12 +245,84 @@ mod data_layout_tests {
             config_default_protocol_fee_rate,
             deserialized.default_protocol_fee_rate
         );
+        assert_eq!(config_referral_fee_rate, deserialized.referral_fee_rate);
+        assert_eq!(
+            config_pending_fee_authority,
+            deserialized.pending_fee_authority
+        );
+        assert_eq!(
+            config_pending_collect_protocol_fees_authority,
+            deserialized.pending_collect_protocol_fees_authority
+        );
+        assert_eq!(
+            config_pending_reward_emissions_super_authority,
+            deserialized.pending_reward_emissions_super_authority
+        );
 
         // serialize
         let mut serialized = Vec::new();
         deserialized.try_serialize(&mut serialized).unwrap();
-        serialized.extend_from_slice(&config_reserved);
 
         assert_eq!(serialized.as_slice(), config_data.as_ref());
     }
+
+    #[test]
+    fn test_legacy_yevefis_config_data_layout() {
+        let config_fee_authority = Pubkey::new_unique();
+        let config_collect_protocol_fees_authority = Pubkey::new_unique();
+        let config_reward_emissions_super_authority = Pubkey::new_unique();
+        let config_default_protocol_fee_rate = 0xffeeu16;
+        let config_referral_fee_rate = 0xddccu16;
+
+        let mut config_data = [0u8; YevefisConfig::LEGACY_LEN];
+        let mut offset = 0;
+        config_data[offset..offset + 8].copy_from_slice(&YevefisConfig::discriminator());
+        offset += 8;
+        config_data[offset..offset + 32].copy_from_slice(&config_fee_authority.to_bytes());
+        offset += 32;
+        config_data[offset..offset + 32]
+            .copy_from_slice(&config_collect_protocol_fees_authority.to_bytes());
+        offset += 32;
+        config_data[offset..offset + 32]
+            .copy_from_slice(&config_reward_emissions_super_authority.to_bytes());
+        offset += 32;
+        config_data[offset..offset + 2]
+            .copy_from_slice(&config_default_protocol_fee_rate.to_le_bytes());
+        offset += 2;
+        config_data[offset..offset + 2].copy_from_slice(&config_referral_fee_rate.to_le_bytes());
+        offset += 2;
+        assert_eq!(offset, 108);
+
+        // the legacy layout must be migrated before it can be deserialized
+        assert!(YevefisConfig::try_deserialize(&mut config_data.as_ref()).is_err());
+
+        // migration appends zeroed fields
+        let mut migrated_data = config_data.to_vec();
+        migrated_data.resize(YevefisConfig::LEN, 0);
+        let deserialized = YevefisConfig::try_deserialize(&mut migrated_data.as_slice()).unwrap();
+
+        assert_eq!(config_fee_authority, deserialized.fee_authority);
+        assert_eq!(
+            config_collect_protocol_fees_authority,
+            deserialized.collect_protocol_fees_authority
+        );
+        assert_eq!(
+            config_reward_emissions_super_authority,
+            deserialized.reward_emissions_super_authority
+        );
+        assert_eq!(
+            config_default_protocol_fee_rate,
+            deserialized.default_protocol_fee_rate
+        );
+        assert_eq!(config_referral_fee_rate, deserialized.referral_fee_rate);
+        assert_eq!(deserialized.pending_fee_authority, Pubkey::default());
+        assert_eq!(
+            deserialized.pending_collect_protocol_fees_authority,
+            Pubkey::default()
+        );
+        assert_eq!(
+            deserialized.pending_reward_emissions_super_authority,
+            Pubkey::default()
+        );
+    }
 }
@@ -5,7 +5,14 @@ pub struct YevefisConfigExtension {
     pub yevefis_config: Pubkey,             // 32
     pub config_extension_authority: Pubkey, // 32
     pub token_badge_authority: Pubkey,      // 32
-                                            // 512 RESERVE
+    pub pause_authority: Pubkey,            // 32
+    // Pauses all yevefis of the config
+    pub paused: bool, // 1
+    // Authorities proposed by the config extension authority, which take over once they accept.
+    // Pubkey::default() if no transfer is pending.
+    pub pending_config_extension_authority: Pubkey, // 32
+    pub pending_token_badge_authority: Pubkey,      // 32
+                                                    // 415 RESERVE
 }
 
 impl YevefisConfigExtension {
@@ -15,15 +22,46 @@ impl YevefisConfigExtension {
         self.yevefis_config = yevefis_config;
         self.config_extension_authority = default_authority;
         self.token_badge_authority = default_authority;
+        self.pause_authority = default_authority;
+        self.paused = false;
         Ok(())
     }
 
     pub fn update_config_extension_authority(&mut self, config_extension_authority: Pubkey) {
         self.config_extension_authority = config_extension_authority;
+        self.pending_config_extension_authority = Pubkey::default();
+    }
+
+    pub fn update_pending_config_extension_authority(
+        &mut self,
+        pending_config_extension_authority: Pubkey,
+    ) {
+        self.pending_config_extension_authority = pending_config_extension_authority;
+    }
+
+    pub fn accept_config_extension_authority(&mut self) {
+        self.update_config_extension_authority(self.pending_config_extension_authority);
     }
 
     pub fn update_token_badge_authority(&mut self, token_badge_authority: Pubkey) {
         self.token_badge_authority = token_badge_authority;
+        self.pending_token_badge_authority = Pubkey::default();
+    }
+
+    pub fn update_pending_token_badge_authority(&mut self, pending_token_badge_authority: Pubkey) {
+        self.pending_token_badge_authority = pending_token_badge_authority;
+    }
+
+    pub fn accept_token_badge_authority(&mut self) {
+        self.update_token_badge_authority(self.pending_token_badge_authority);
+    }
+
+    pub fn update_pause_authority(&mut self, pause_authority: Pubkey) {
+        self.pause_authority = pause_authority;
+    }
+
+    pub fn update_paused(&mut self, paused: bool) {
+        self.paused = paused;
     }
 }
 
@@ -38,6 +76,10 @@ mod yevefis_config_extension_initialize_tests {
             yevefis_config: Pubkey::default(),
             config_extension_authority: Pubkey::default(),
             token_badge_authority: Pubkey::default(),
+            pause_authority: Pubkey::default(),
+            paused: false,
+            pending_config_extension_authority: Pubkey::default(),
+            pending_token_badge_authority: Pubkey::default(),
         };
 
         let yevefis_config =
@@ -54,6 +96,8 @@ mod yevefis_config_extension_initialize_tests {
             config_extension.config_extension_authority
         );
         assert_eq!(default_authority, config_extension.token_badge_authority);
+        assert_eq!(default_authority, config_extension.pause_authority);
+        assert!(!config_extension.paused);
     }
 }
 
@@ -68,6 +112,10 @@ mod yevefis_config_extension_update_tests {
             yevefis_config: Pubkey::default(),
             config_extension_authority: Pubkey::default(),
             token_badge_authority: Pubkey::default(),
+            pause_authority: Pubkey::default(),
+            paused: false,
+            pending_config_extension_authority: Pubkey::default(),
+            pending_token_badge_authority: Pubkey::default(),
         };
 
         let config_extension_authority =
@@ -88,6 +136,10 @@ mod yevefis_config_extension_update_tests {
             yevefis_config: Pubkey::default(),
             config_extension_authority: Pubkey::default(),
             token_badge_authority: Pubkey::default(),
+            pause_authority: Pubkey::default(),
+            paused: false,
+            pending_config_extension_authority: Pubkey::default(),
+            pending_token_badge_authority: Pubkey::default(),
         };
 
         let token_badge_authority =
@@ -104,6 +156,117 @@ mod yevefis_config_extension_update_tests {
             config_extension.config_extension_authority
         );
     }
+
+    #[test]
+    fn test_accept_config_extension_authority() {
+        let mut config_extension = YevefisConfigExtension {
+            yevefis_config: Pubkey::default(),
+            config_extension_authority: Pubkey::default(),
+            token_badge_authority: Pubkey::default(),
+            pause_authority: Pubkey::default(),
+            paused: false,
+            pending_config_extension_authority: Pubkey::default(),
+            pending_token_badge_authority: Pubkey::default(),
+        };
+
+        let config_extension_authority =
+            Pubkey::from_str("orcaEKTdK7LKz57vaAYr9QeNsVEPfiu6QeMU1kektZE").unwrap();
+
+        config_extension.update_pending_config_extension_authority(config_extension_authority);
+        assert_eq!(
+            Pubkey::default(),
+            config_extension.config_extension_authority
+        );
+
+        config_extension.accept_config_extension_authority();
+        assert_eq!(
+            config_extension_authority,
+            config_extension.config_extension_authority
+        );
+        assert_eq!(
+            Pubkey::default(),
+            config_extension.pending_config_extension_authority
+        );
+    }
+
+    #[test]
+    fn test_accept_token_badge_authority() {
+        let mut config_extension = YevefisConfigExtension {
+            yevefis_config: Pubkey::default(),
+            config_extension_authority: Pubkey::default(),
+            token_badge_authority: Pubkey::default(),
+            pause_authority: Pubkey::default(),
+            paused: false,
+            pending_config_extension_authority: Pubkey::default(),
+            pending_token_badge_authority: Pubkey::default(),
+        };
+
+        let token_badge_authority =
+            Pubkey::from_str("orcaEKTdK7LKz57vaAYr9QeNsVEPfiu6QeMU1kektZE").unwrap();
+
+        config_extension.update_pending_token_badge_authority(token_badge_authority);
+        assert_eq!(Pubkey::default(), config_extension.token_badge_authority);
+
+        config_extension.accept_token_badge_authority();
+        assert_eq!(
+            token_badge_authority,
+            config_extension.token_badge_authority
+        );
+        assert_eq!(
+            Pubkey::default(),
+            config_extension.pending_token_badge_authority
+        );
+    }
+}
+
+#[cfg(test)]
+mod yevefis_config_extension_pause_tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_update_pause_authority() {
+        let mut config_extension = YevefisConfigExtension {
+            yevefis_config: Pubkey::default(),
+            config_extension_authority: Pubkey::default(),
+            token_badge_authority: Pubkey::default(),
+            pause_authority: Pubkey::default(),
+            paused: false,
+            pending_config_extension_authority: Pubkey::default(),
+            pending_token_badge_authority: Pubkey::default(),
+        };
+
+        let pause_authority =
+            Pubkey::from_str("orcaEKTdK7LKz57vaAYr9QeNsVEPfiu6QeMU1kektZE").unwrap();
+
+        config_extension.update_pause_authority(pause_authority);
+
+        assert_eq!(pause_authority, config_extension.pause_authority);
+        assert_eq!(Pubkey::default(), config_extension.token_badge_authority);
+        assert_eq!(
+            Pubkey::default(),
+            config_extension.config_extension_authority
+        );
+    }
+
+    #[test]
+    fn test_update_paused() {
+        let mut config_extension = YevefisConfigExtension {
+            yevefis_config: Pubkey::default(),
+            config_extension_authority: Pubkey::default(),
+            token_badge_authority: Pubkey::default(),
+            pause_authority: Pubkey::default(),
+            paused: false,
+            pending_config_extension_authority: Pubkey::default(),
+            pending_token_badge_authority: Pubkey::default(),
+        };
+
+        config_extension.update_paused(true);
+        assert!(config_extension.paused);
+
+        config_extension.update_paused(false);
+        assert!(!config_extension.paused);
+    }
 }
 
 #[cfg(test)]
@@ -117,7 +280,11 @@ mod data_layout_tests {
         let config_extension_yevefis_config = Pubkey::new_unique();
         let config_extension_config_extension_authority = Pubkey::new_unique();
         let config_extension_token_badge_authority = Pubkey::new_unique();
-        let config_extension_reserved = [0u8; 512];
+        let config_extension_pause_authority = Pubkey::new_unique();
+        let config_extension_paused = true;
+        let config_extension_pending_config_extension_authority = Pubkey::new_unique();
+        let config_extension_pending_token_badge_authority = Pubkey::new_unique();
+        let config_extension_reserved = [0u8; 415];
 
         let mut config_extension_data = [0u8; YevefisConfigExtension::LEN];
         let mut offset = 0;
@@ -133,6 +300,17 @@ mod data_layout_tests {
         config_extension_data[offset..offset + 32]
             .copy_from_slice(&config_extension_token_badge_authority.to_bytes());
         offset += 32;
+        config_extension_data[offset..offset + 32]
+            .copy_from_slice(&config_extension_pause_authority.to_bytes());
+        offset += 32;
+        config_extension_data[offset] = config_extension_paused as u8;
+        offset += 1;
+        config_extension_data[offset..offset + 32]
+            .copy_from_slice(&config_extension_pending_config_extension_authority.to_bytes());
+        offset += 32;
+        config_extension_data[offset..offset + 32]
+            .copy_from_slice(&config_extension_pending_token_badge_authority.to_bytes());
+        offset += 32;
         config_extension_data[offset..offset + config_extension_reserved.len()]
             .copy_from_slice(&config_extension_reserved);
         offset += config_extension_reserved.len();
@@ -151,6 +329,19 @@ mod data_layout_tests {
             config_extension_token_badge_authority,
             deserialized.token_badge_authority
         );
+        assert_eq!(
+            config_extension_pause_authority,
+            deserialized.pause_authority
+        );
+        assert_eq!(config_extension_paused, deserialized.paused);
+        assert_eq!(
+            config_extension_pending_config_extension_authority,
+            deserialized.pending_config_extension_authority
+        );
+        assert_eq!(
+            config_extension_pending_token_badge_authority,
+            deserialized.pending_token_badge_authority
+        );
 
         // serialize
         let mut serialized = Vec::new();
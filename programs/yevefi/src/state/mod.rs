@@ -1,17 +1,29 @@
+pub mod adaptive_fee_tier;
 pub mod config;
 pub mod config_extension;
 pub mod fee_tier;
+pub mod oracle;
 pub mod position;
 pub mod position_bundle;
+pub mod position_delegate;
+pub mod position_lock;
+pub mod referrer;
+pub mod reward_extension;
 pub mod tick;
 pub mod token_badge;
 pub mod yevefi;
 
 pub use self::yevefi::*;
+pub use adaptive_fee_tier::*;
 pub use config::*;
 pub use config_extension::*;
 pub use fee_tier::*;
+pub use oracle::*;
 pub use position::*;
 pub use position_bundle::*;
+pub use position_delegate::*;
+pub use position_lock::*;
+pub use referrer::*;
+pub use reward_extension::*;
 pub use tick::*;
 pub use token_badge::*;
@@ -0,0 +1,318 @@
+use anchor_lang::{error::ErrorCode as AnchorErrorCode, prelude::*, Discriminator};
+
+use crate::math::Q64_RESOLUTION;
+
+// Account data can only be grown by 10KiB (MAX_PERMITTED_DATA_INCREASE) per instruction.
+pub const MAX_OBSERVATION_CARDINALITY_INCREASE: u16 = 320;
+
+// Observe results are returned via return data, which is capped at 1024 bytes.
+pub const MAX_OBSERVE_SECONDS_AGOS_LEN: usize = 32;
+
+#[zero_copy(unsafe)]
+#[repr(C, packed)]
+#[derive(Default, Debug, PartialEq)]
+pub struct Observation {
+    // Total 32 bytes
+    pub timestamp: u64,       // 8
+    pub tick_cumulative: i64, // 8
+    // Q64.64
+    pub seconds_per_liquidity_cumulative_x64: u128, // 16
+}
+
+// Observations are stored unaligned after the Oracle header and cast from the account data,
+// as #[account(zero_copy(unsafe))] does for whole accounts. The packed layout has no padding.
+unsafe impl bytemuck::Zeroable for Observation {}
+unsafe impl bytemuck::Pod for Observation {}
+
+impl Observation {
+    pub const LEN: usize = 32;
+
+    /// Returns true if this observation has been written.
+    /// Unwritten slots are zeroed, and no observation can be taken at the unix epoch.
+    pub fn initialized(&self) -> bool {
+        self.timestamp != 0
+    }
+
+    /// Returns the observation at `timestamp`, accumulating the given tick and liquidity
+    /// over the time elapsed since this observation.
+    ///
+    /// # Parameters
+    /// - `timestamp` - The timestamp of the new observation. Must not be earlier than this observation.
+    /// - `tick_current_index` - The tick index of the yevefi since this observation
+    /// - `liquidity` - The in-range liquidity of the yevefi since this observation
+    pub fn transform(
+        &self,
+        timestamp: u64,
+        tick_current_index: i32,
+        liquidity: u128,
+    ) -> Observation {
+        let time_delta = timestamp - self.timestamp;
+
+        // Cumulative values are expected to wrap. Consumers only use differences between them.
+        let tick_cumulative = self
+            .tick_cumulative
+            .wrapping_add(i64::from(tick_current_index).wrapping_mul(time_delta as i64));
+        let seconds_per_liquidity_cumulative_x64 = self
+            .seconds_per_liquidity_cumulative_x64
+            .wrapping_add((u128::from(time_delta) << Q64_RESOLUTION) / liquidity.max(1));
+
+        Observation {
+            timestamp,
+            tick_cumulative,
+            seconds_per_liquidity_cumulative_x64,
+        }
+    }
+}
+
+/// Cumulative values returned by the `observe` instruction, one entry per requested `seconds_ago`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug, PartialEq)]
+pub struct ObserveResult {
+    pub tick_cumulatives: Vec<i64>,
+    pub seconds_per_liquidity_cumulative_x64s: Vec<u128>,
+}
+
+/// Header of the oracle account of a yevefi.
+///
+/// The header is followed by `observation_cardinality_next` observations which are used
+/// as a ring buffer. Only the first `observation_cardinality` slots are in use; the rest
+/// have been paid for and are taken into use once the buffer wraps around.
+#[account(zero_copy(unsafe))]
+#[repr(C, packed)]
+pub struct Oracle {
+    pub yevefi: Pubkey,                    // 32
+    pub observation_index: u16,            // 2
+    pub observation_cardinality: u16,      // 2
+    pub observation_cardinality_next: u16, // 2
+    pub reserved: [u8; 64],                // 64 RESERVE
+}
+
+impl Default for Oracle {
+    #[inline]
+    fn default() -> Oracle {
+        Oracle {
+            yevefi: Pubkey::default(),
+            observation_index: 0,
+            observation_cardinality: 0,
+            observation_cardinality_next: 0,
+            reserved: [0u8; 64],
+        }
+    }
+}
+
+impl Oracle {
+    pub const LEN: usize = 8 + 32 + 2 + 2 + 2 + 64;
+
+    /// Returns the account size required to hold `observation_cardinality` observations.
+    pub fn space(observation_cardinality: u16) -> usize {
+        Oracle::LEN + usize::from(observation_cardinality) * Observation::LEN
+    }
+
+    /// Initialize the Oracle object with a single observation slot.
+    /// The first observation is written on the next swap.
+    ///
+    /// # Parameters
+    /// - `yevefi` - The yevefi this oracle observes
+    pub fn initialize(&mut self, yevefi: Pubkey) {
+        self.yevefi = yevefi;
+        self.observation_index = 0;
+        self.observation_cardinality = 1;
+        self.observation_cardinality_next = 1;
+    }
+
+    pub fn update_observation_cardinality_next(&mut self, observation_cardinality_next: u16) {
+        self.observation_cardinality_next = observation_cardinality_next;
+    }
+
+    /// Splits the raw data of an oracle account into its header and observation slots.
+    ///
+    /// # Errors
+    /// - `AccountDiscriminatorMismatch`: - The data does not belong to an Oracle account.
+    /// - `AccountDidNotDeserialize`: - The data is too short for the declared cardinality.
+    pub fn unpack(data: &[u8]) -> Result<(&Oracle, &[Observation])> {
+        Oracle::check_data(data)?;
+
+        let (header, observations) = data[8..].split_at(Oracle::LEN - 8);
+        let oracle: &Oracle = bytemuck::from_bytes(header);
+        let observations: &[Observation] = bytemuck::try_cast_slice(observations)
+            .map_err(|_| AnchorErrorCode::AccountDidNotDeserialize)?;
+
+        if observations.len() < usize::from(oracle.observation_cardinality_next) {
+            return Err(AnchorErrorCode::AccountDidNotDeserialize.into());
+        }
+
+        Ok((oracle, observations))
+    }
+
+    /// Mutable variant of [`Oracle::unpack`].
+    pub fn unpack_mut(data: &mut [u8]) -> Result<(&mut Oracle, &mut [Observation])> {
+        Oracle::check_data(data)?;
+
+        let (header, observations) = data[8..].split_at_mut(Oracle::LEN - 8);
+        let oracle: &mut Oracle = bytemuck::from_bytes_mut(header);
+        let observations: &mut [Observation] = bytemuck::try_cast_slice_mut(observations)
+            .map_err(|_| AnchorErrorCode::AccountDidNotDeserialize)?;
+
+        if observations.len() < usize::from(oracle.observation_cardinality_next) {
+            return Err(AnchorErrorCode::AccountDidNotDeserialize.into());
+        }
+
+        Ok((oracle, observations))
+    }
+
+    fn check_data(data: &[u8]) -> Result<()> {
+        if data.len() < Oracle::LEN {
+            return Err(AnchorErrorCode::AccountDidNotDeserialize.into());
+        }
+        if data[0..8] != Oracle::discriminator() {
+            return Err(AnchorErrorCode::AccountDiscriminatorMismatch.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod observation_tests {
+    use super::*;
+
+    #[test]
+    fn test_transform() {
+        let observation = Observation {
+            timestamp: 1_000,
+            tick_cumulative: 500,
+            seconds_per_liquidity_cumulative_x64: 1 << Q64_RESOLUTION,
+        };
+
+        let next = observation.transform(1_010, -20, 5);
+        assert_eq!({ next.timestamp }, 1_010);
+        assert_eq!({ next.tick_cumulative }, 500 - 200);
+        assert_eq!(
+            { next.seconds_per_liquidity_cumulative_x64 },
+            (1 << Q64_RESOLUTION) + (2 << Q64_RESOLUTION)
+        );
+    }
+
+    #[test]
+    fn test_transform_zero_liquidity() {
+        let observation = Observation {
+            timestamp: 1_000,
+            ..Default::default()
+        };
+
+        let next = observation.transform(1_003, 1, 0);
+        assert_eq!({ next.tick_cumulative }, 3);
+        assert_eq!(
+            { next.seconds_per_liquidity_cumulative_x64 },
+            3 << Q64_RESOLUTION
+        );
+    }
+
+    #[test]
+    fn test_transform_wraps() {
+        let observation = Observation {
+            timestamp: 1_000,
+            tick_cumulative: i64::MAX,
+            seconds_per_liquidity_cumulative_x64: u128::MAX,
+        };
+
+        let next = observation.transform(1_001, 1, 1);
+        assert_eq!({ next.tick_cumulative }, i64::MIN);
+        assert_eq!(
+            { next.seconds_per_liquidity_cumulative_x64 },
+            (1 << Q64_RESOLUTION) - 1
+        );
+    }
+}
+
+#[cfg(test)]
+mod data_layout_tests {
+    use super::*;
+
+    #[test]
+    fn test_oracle_data_layout() {
+        let oracle_yevefi = Pubkey::new_unique();
+        let oracle_observation_index = 0x1122u16;
+        let oracle_observation_cardinality = 0x3344u16;
+        let oracle_observation_cardinality_next = 2u16;
+        let oracle_reserved = [0u8; 64];
+
+        let observation_timestamp = 0x1122334455667788u64;
+        let observation_tick_cumulative = -0x0102030405060708i64;
+        let observation_seconds_per_liquidity_cumulative_x64 =
+            0x11223344556677881122334455667788u128;
+
+        // manually build the expected data layout
+        let mut oracle_data = [0u8; Oracle::LEN + 2 * Observation::LEN];
+        let mut offset = 0;
+        oracle_data[offset..offset + 8].copy_from_slice(&Oracle::discriminator());
+        offset += 8;
+        oracle_data[offset..offset + 32].copy_from_slice(&oracle_yevefi.to_bytes());
+        offset += 32;
+        oracle_data[offset..offset + 2].copy_from_slice(&oracle_observation_index.to_le_bytes());
+        offset += 2;
+        oracle_data[offset..offset + 2]
+            .copy_from_slice(&oracle_observation_cardinality.to_le_bytes());
+        offset += 2;
+        oracle_data[offset..offset + 2]
+            .copy_from_slice(&oracle_observation_cardinality_next.to_le_bytes());
+        offset += 2;
+        oracle_data[offset..offset + oracle_reserved.len()].copy_from_slice(&oracle_reserved);
+        offset += oracle_reserved.len();
+        assert_eq!(offset, Oracle::LEN);
+
+        // second observation slot, first slot left unwritten
+        offset += Observation::LEN;
+        oracle_data[offset..offset + 8].copy_from_slice(&observation_timestamp.to_le_bytes());
+        offset += 8;
+        oracle_data[offset..offset + 8].copy_from_slice(&observation_tick_cumulative.to_le_bytes());
+        offset += 8;
+        oracle_data[offset..offset + 16]
+            .copy_from_slice(&observation_seconds_per_liquidity_cumulative_x64.to_le_bytes());
+        offset += 16;
+        assert_eq!(offset, Oracle::space(2));
+
+        // cast from bytes to Oracle and its observations
+        let (oracle, observations) = Oracle::unpack_mut(&mut oracle_data).unwrap();
+
+        // check that the data layout matches the expected layout
+        let read_yevefi = oracle.yevefi;
+        assert_eq!(read_yevefi, oracle_yevefi);
+        let read_observation_index = oracle.observation_index;
+        assert_eq!(read_observation_index, oracle_observation_index);
+        let read_observation_cardinality = oracle.observation_cardinality;
+        assert_eq!(read_observation_cardinality, oracle_observation_cardinality);
+        let read_observation_cardinality_next = oracle.observation_cardinality_next;
+        assert_eq!(
+            read_observation_cardinality_next,
+            oracle_observation_cardinality_next
+        );
+
+        assert_eq!(observations.len(), 2);
+        assert!(!observations[0].initialized());
+        assert!(observations[1].initialized());
+        let read_timestamp = observations[1].timestamp;
+        assert_eq!(read_timestamp, observation_timestamp);
+        let read_tick_cumulative = observations[1].tick_cumulative;
+        assert_eq!(read_tick_cumulative, observation_tick_cumulative);
+        let read_seconds_per_liquidity_cumulative_x64 =
+            observations[1].seconds_per_liquidity_cumulative_x64;
+        assert_eq!(
+            read_seconds_per_liquidity_cumulative_x64,
+            observation_seconds_per_liquidity_cumulative_x64
+        );
+    }
+
+    #[test]
+    fn test_unpack_mut_discriminator_mismatch() {
+        let mut oracle_data = [0u8; Oracle::LEN];
+        assert!(Oracle::unpack_mut(&mut oracle_data).is_err());
+    }
+
+    #[test]
+    fn test_unpack_mut_too_short_for_cardinality() {
+        let mut oracle_data = [0u8; Oracle::LEN + Observation::LEN];
+        oracle_data[0..8].copy_from_slice(&Oracle::discriminator());
+        // observation_cardinality_next = 2, but only one slot is allocated
+        oracle_data[44..46].copy_from_slice(&2u16.to_le_bytes());
+        assert!(Oracle::unpack_mut(&mut oracle_data).is_err());
+    }
+}
@@ -62,17 +62,61 @@ impl Position {
         tick_lower_index: i32,
         tick_upper_index: i32,
     ) -> Result<()> {
-        if !Tick::check_is_usable_tick(tick_lower_index, yevefi.tick_spacing)
-            || !Tick::check_is_usable_tick(tick_upper_index, yevefi.tick_spacing)
+        Self::check_tick_range(yevefi.tick_spacing, tick_lower_index, tick_upper_index)?;
+
+        self.yevefi = yevefi.key();
+        self.position_mint = position_mint;
+
+        self.tick_lower_index = tick_lower_index;
+        self.tick_upper_index = tick_upper_index;
+        Ok(())
+    }
+
+    /// Moves the position to a new tick range. The position must not have liquidity,
+    /// so that no fees or rewards are accrued over the old range afterwards.
+    pub fn reset_position_range(
+        &mut self,
+        yevefi: &Yevefi,
+        new_tick_lower_index: i32,
+        new_tick_upper_index: i32,
+    ) -> Result<()> {
+        if self.liquidity != 0 {
+            return Err(ErrorCode::PositionLiquidityNonZero.into());
+        }
+
+        if self.tick_lower_index == new_tick_lower_index
+            && self.tick_upper_index == new_tick_upper_index
+        {
+            return Err(ErrorCode::SameTickRangeNotAllowed.into());
+        }
+
+        Self::check_tick_range(
+            yevefi.tick_spacing,
+            new_tick_lower_index,
+            new_tick_upper_index,
+        )?;
+
+        self.tick_lower_index = new_tick_lower_index;
+        self.tick_upper_index = new_tick_upper_index;
+        Ok(())
+    }
+
+    fn check_tick_range(
+        tick_spacing: u16,
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+    ) -> Result<()> {
+        if !Tick::check_is_usable_tick(tick_lower_index, tick_spacing)
+            || !Tick::check_is_usable_tick(tick_upper_index, tick_spacing)
             || tick_lower_index >= tick_upper_index
         {
             return Err(ErrorCode::InvalidTickIndex.into());
         }
 
         // On tick spacing >= 2^15, should only be able to open full range positions
-        if yevefi.tick_spacing >= FULL_RANGE_ONLY_TICK_SPACING_THRESHOLD {
+        if tick_spacing >= FULL_RANGE_ONLY_TICK_SPACING_THRESHOLD {
             let (full_range_lower_index, full_range_upper_index) =
-                Tick::full_range_indexes(yevefi.tick_spacing);
+                Tick::full_range_indexes(tick_spacing);
             if tick_lower_index != full_range_lower_index
                 || tick_upper_index != full_range_upper_index
             {
@@ -80,11 +124,6 @@ impl Position {
             }
         }
 
-        self.yevefi = yevefi.key();
-        self.position_mint = position_mint;
-
-        self.tick_lower_index = tick_lower_index;
-        self.tick_upper_index = tick_upper_index;
         Ok(())
     }
 
@@ -93,6 +132,11 @@ impl Position {
         self.fee_owed_b = 0;
     }
 
+    pub fn update_fees_owed(&mut self, fee_owed_a: u64, fee_owed_b: u64) {
+        self.fee_owed_a = fee_owed_a;
+        self.fee_owed_b = fee_owed_b;
+    }
+
     pub fn update_reward_owed(&mut self, index: usize, amount_owed: u64) {
         self.reward_infos[index].amount_owed = amount_owed;
     }
@@ -198,6 +242,59 @@ mod is_position_empty_tests {
     }
 }
 
+#[cfg(test)]
+mod reset_position_range_tests {
+    use super::position_builder::PositionBuilder;
+    use super::*;
+
+    fn yevefi_with_tick_spacing(tick_spacing: u16) -> Yevefi {
+        Yevefi {
+            tick_spacing,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_reset_position_range() {
+        let mut position = PositionBuilder::new(-128, 128).fee_owed_a(10).build();
+        let yevefi = yevefi_with_tick_spacing(64);
+
+        let result = position.reset_position_range(&yevefi, 0, 256);
+        assert!(result.is_ok());
+
+        assert_eq!(position.tick_lower_index, 0);
+        assert_eq!(position.tick_upper_index, 256);
+        assert_eq!(position.fee_owed_a, 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "PositionLiquidityNonZero")]
+    fn test_reset_position_range_with_liquidity() {
+        let mut position = PositionBuilder::new(-128, 128).liquidity(100).build();
+        let yevefi = yevefi_with_tick_spacing(64);
+
+        position.reset_position_range(&yevefi, 0, 256).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "SameTickRangeNotAllowed")]
+    fn test_reset_position_range_same_range() {
+        let mut position = PositionBuilder::new(-128, 128).build();
+        let yevefi = yevefi_with_tick_spacing(64);
+
+        position.reset_position_range(&yevefi, -128, 128).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "InvalidTickIndex")]
+    fn test_reset_position_range_unusable_tick() {
+        let mut position = PositionBuilder::new(-128, 128).build();
+        let yevefi = yevefi_with_tick_spacing(64);
+
+        position.reset_position_range(&yevefi, 0, 100).unwrap();
+    }
+}
+
 #[cfg(test)]
 pub mod position_builder {
     use anchor_lang::prelude::Pubkey;
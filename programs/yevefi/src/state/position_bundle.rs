@@ -30,6 +30,18 @@ impl PositionBundle {
         true
     }
 
+    pub fn is_bundled_position_opened(&self, bundle_index: u16) -> bool {
+        if !PositionBundle::is_valid_bundle_index(bundle_index) {
+            return false;
+        }
+
+        let bitmap_index = bundle_index / 8;
+        let bitmap_offset = bundle_index % 8;
+        let bitmap = self.position_bitmap[bitmap_index as usize];
+
+        bitmap & (1 << bitmap_offset) != 0
+    }
+
     pub fn open_bundled_position(&mut self, bundle_index: u16) -> Result<()> {
         self.update_bitmap(bundle_index, true)
     }
@@ -135,6 +147,49 @@ mod position_bundle_is_deletable_tests {
     }
 }
 
+#[cfg(test)]
+mod position_bundle_is_bundled_position_opened_tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_not_opened() {
+        let position_bundle = PositionBundle {
+            ..Default::default()
+        };
+        for bundle_index in 0..POSITION_BUNDLE_SIZE {
+            assert!(!position_bundle.is_bundled_position_opened(bundle_index));
+        }
+    }
+
+    #[test]
+    fn test_each_bit_detectable() {
+        let mut position_bundle = PositionBundle {
+            ..Default::default()
+        };
+        for bundle_index in 0..POSITION_BUNDLE_SIZE {
+            position_bundle.open_bundled_position(bundle_index).unwrap();
+            assert!(position_bundle.is_bundled_position_opened(bundle_index));
+            if bundle_index > 0 {
+                assert!(!position_bundle.is_bundled_position_opened(bundle_index - 1));
+            }
+            position_bundle
+                .close_bundled_position(bundle_index)
+                .unwrap();
+            assert!(!position_bundle.is_bundled_position_opened(bundle_index));
+        }
+    }
+
+    #[test]
+    fn test_invalid_bundle_index_is_not_opened() {
+        let mut position_bundle = PositionBundle {
+            ..Default::default()
+        };
+        position_bundle.position_bitmap = [u8::MAX; POSITION_BITMAP_USIZE];
+        assert!(!position_bundle.is_bundled_position_opened(POSITION_BUNDLE_SIZE));
+        assert!(!position_bundle.is_bundled_position_opened(u16::MAX));
+    }
+}
+
 #[cfg(test)]
 mod position_bundle_open_and_close_tests {
     use super::*;
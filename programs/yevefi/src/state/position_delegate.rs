@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+pub const POSITION_DELEGATE_PERMISSION_COLLECT_FEES: u8 = 1 << 0;
+pub const POSITION_DELEGATE_PERMISSION_COLLECT_REWARDS: u8 = 1 << 1;
+pub const POSITION_DELEGATE_PERMISSION_INCREASE_LIQUIDITY: u8 = 1 << 2;
+// Withdrawn tokens can only be transferred to the position owner
+pub const POSITION_DELEGATE_PERMISSION_DECREASE_LIQUIDITY: u8 = 1 << 3;
+
+pub const POSITION_DELEGATE_PERMISSIONS_MASK: u8 = POSITION_DELEGATE_PERMISSION_COLLECT_FEES
+    | POSITION_DELEGATE_PERMISSION_COLLECT_REWARDS
+    | POSITION_DELEGATE_PERMISSION_INCREASE_LIQUIDITY
+    | POSITION_DELEGATE_PERMISSION_DECREASE_LIQUIDITY;
+
+#[account]
+#[derive(Default)]
+pub struct PositionDelegate {
+    pub position: Pubkey,       // 32
+    pub position_owner: Pubkey, // 32
+    pub delegate: Pubkey,       // 32
+    pub permissions: u8,        // 1
+                                // 64 RESERVE
+}
+
+impl PositionDelegate {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 1 + 64;
+
+    pub fn initialize(
+        &mut self,
+        position: Pubkey,
+        position_owner: Pubkey,
+        delegate: Pubkey,
+        permissions: u8,
+    ) -> Result<()> {
+        self.position = position;
+        self.delegate = delegate;
+        self.set_permissions(position_owner, permissions)
+    }
+
+    // The permissions are granted by position_owner.
+    // They are void once the position token is held by another owner.
+    pub fn set_permissions(&mut self, position_owner: Pubkey, permissions: u8) -> Result<()> {
+        if permissions == 0 || permissions & !POSITION_DELEGATE_PERMISSIONS_MASK != 0 {
+            return Err(ErrorCode::InvalidPositionDelegatePermissions.into());
+        }
+
+        self.position_owner = position_owner;
+        self.permissions = permissions;
+        Ok(())
+    }
+
+    pub fn has_permissions(&self, permissions: u8) -> bool {
+        self.permissions & permissions == permissions
+    }
+}
+
+#[cfg(test)]
+mod position_delegate_initialize_tests {
+    use super::*;
+
+    #[test]
+    fn test_initialize() {
+        let mut position_delegate = PositionDelegate {
+            ..Default::default()
+        };
+        let position = Pubkey::new_unique();
+        let position_owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+
+        let result = position_delegate.initialize(
+            position,
+            position_owner,
+            delegate,
+            POSITION_DELEGATE_PERMISSION_COLLECT_FEES,
+        );
+        assert!(result.is_ok());
+
+        assert_eq!(position, position_delegate.position);
+        assert_eq!(position_owner, position_delegate.position_owner);
+        assert_eq!(delegate, position_delegate.delegate);
+        assert_eq!(
+            POSITION_DELEGATE_PERMISSION_COLLECT_FEES,
+            position_delegate.permissions
+        );
+    }
+
+    #[test]
+    fn test_initialize_no_permissions() {
+        let mut position_delegate = PositionDelegate {
+            ..Default::default()
+        };
+
+        let result = position_delegate.initialize(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_initialize_unknown_permissions() {
+        let mut position_delegate = PositionDelegate {
+            ..Default::default()
+        };
+
+        let result = position_delegate.initialize(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            POSITION_DELEGATE_PERMISSION_COLLECT_FEES | 1 << 4,
+        );
+        assert!(result.is_err());
+    }
+}
+
+#[cfg(test)]
+mod position_delegate_has_permissions_tests {
+    use super::*;
+
+    #[test]
+    fn test_has_permissions() {
+        let position_delegate = PositionDelegate {
+            permissions: POSITION_DELEGATE_PERMISSION_COLLECT_FEES
+                | POSITION_DELEGATE_PERMISSION_COLLECT_REWARDS,
+            ..Default::default()
+        };
+
+        assert!(position_delegate.has_permissions(POSITION_DELEGATE_PERMISSION_COLLECT_FEES));
+        assert!(position_delegate.has_permissions(POSITION_DELEGATE_PERMISSION_COLLECT_REWARDS));
+        assert!(position_delegate.has_permissions(
+            POSITION_DELEGATE_PERMISSION_COLLECT_FEES
+                | POSITION_DELEGATE_PERMISSION_COLLECT_REWARDS
+        ));
+        assert!(!position_delegate.has_permissions(POSITION_DELEGATE_PERMISSION_INCREASE_LIQUIDITY));
+        assert!(!position_delegate.has_permissions(
+            POSITION_DELEGATE_PERMISSION_COLLECT_FEES
+                | POSITION_DELEGATE_PERMISSION_DECREASE_LIQUIDITY
+        ));
+    }
+
+    #[test]
+    fn test_set_permissions_updates_position_owner() {
+        let mut position_delegate = PositionDelegate {
+            position_owner: Pubkey::new_unique(),
+            permissions: POSITION_DELEGATE_PERMISSIONS_MASK,
+            ..Default::default()
+        };
+        let new_position_owner = Pubkey::new_unique();
+
+        let result = position_delegate.set_permissions(
+            new_position_owner,
+            POSITION_DELEGATE_PERMISSION_COLLECT_FEES,
+        );
+        assert!(result.is_ok());
+
+        assert_eq!(new_position_owner, position_delegate.position_owner);
+        assert!(position_delegate.has_permissions(POSITION_DELEGATE_PERMISSION_COLLECT_FEES));
+        assert!(!position_delegate.has_permissions(POSITION_DELEGATE_PERMISSION_DECREASE_LIQUIDITY));
+    }
+}
+
+#[cfg(test)]
+mod data_layout_tests {
+    use anchor_lang::Discriminator;
+
+    use super::*;
+
+    #[test]
+    fn test_position_delegate_data_layout() {
+        let position_delegate_position = Pubkey::new_unique();
+        let position_delegate_position_owner = Pubkey::new_unique();
+        let position_delegate_delegate = Pubkey::new_unique();
+        let position_delegate_permissions = 0x0bu8;
+        let position_delegate_reserved = [0u8; 64];
+
+        // manually build the expected data layout
+        let mut position_delegate_data = [0u8; PositionDelegate::LEN];
+        let mut offset = 0;
+        position_delegate_data[offset..offset + 8]
+            .copy_from_slice(&PositionDelegate::discriminator());
+        offset += 8;
+        position_delegate_data[offset..offset + 32]
+            .copy_from_slice(&position_delegate_position.to_bytes());
+        offset += 32;
+        position_delegate_data[offset..offset + 32]
+            .copy_from_slice(&position_delegate_position_owner.to_bytes());
+        offset += 32;
+        position_delegate_data[offset..offset + 32]
+            .copy_from_slice(&position_delegate_delegate.to_bytes());
+        offset += 32;
+        position_delegate_data[offset] = position_delegate_permissions;
+        offset += 1;
+        position_delegate_data[offset..offset + position_delegate_reserved.len()]
+            .copy_from_slice(&position_delegate_reserved);
+        offset += position_delegate_reserved.len();
+        assert_eq!(offset, PositionDelegate::LEN);
+
+        // deserialize
+        let deserialized =
+            PositionDelegate::try_deserialize(&mut position_delegate_data.as_ref()).unwrap();
+
+        assert_eq!(position_delegate_position, deserialized.position);
+        assert_eq!(
+            position_delegate_position_owner,
+            deserialized.position_owner
+        );
+        assert_eq!(position_delegate_delegate, deserialized.delegate);
+        assert_eq!(position_delegate_permissions, deserialized.permissions);
+
+        // serialize
+        let mut serialized = Vec::new();
+        deserialized.try_serialize(&mut serialized).unwrap();
+        serialized.extend_from_slice(&position_delegate_reserved);
+
+        assert_eq!(serialized.as_slice(), position_delegate_data.as_ref());
+    }
+}
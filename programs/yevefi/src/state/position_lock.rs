@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+#[account]
+#[derive(Default)]
+pub struct PositionLock {
+    pub position: Pubkey, // 32
+    pub unlock_timestamp: u64, // 8
+                          // 64 RESERVE
+}
+
+impl PositionLock {
+    pub const LEN: usize = 8 + 32 + 8 + 64;
+
+    pub fn initialize(
+        &mut self,
+        position: Pubkey,
+        unlock_timestamp: u64,
+        timestamp: u64,
+    ) -> Result<()> {
+        if unlock_timestamp <= timestamp {
+            return Err(ErrorCode::InvalidUnlockTimestamp.into());
+        }
+
+        self.position = position;
+        self.unlock_timestamp = unlock_timestamp;
+        Ok(())
+    }
+
+    pub fn extend(&mut self, unlock_timestamp: u64, timestamp: u64) -> Result<()> {
+        if unlock_timestamp <= timestamp || unlock_timestamp <= self.unlock_timestamp {
+            return Err(ErrorCode::InvalidUnlockTimestamp.into());
+        }
+
+        self.unlock_timestamp = unlock_timestamp;
+        Ok(())
+    }
+
+    pub fn is_locked(&self, timestamp: u64) -> bool {
+        timestamp < self.unlock_timestamp
+    }
+}
+
+#[cfg(test)]
+mod position_lock_initialize_tests {
+    use super::*;
+
+    #[test]
+    fn test_initialize() {
+        let mut position_lock = PositionLock {
+            ..Default::default()
+        };
+        let position = Pubkey::new_unique();
+
+        let result = position_lock.initialize(position, 2_000, 1_000);
+        assert!(result.is_ok());
+
+        assert_eq!(position, position_lock.position);
+        assert_eq!(2_000, position_lock.unlock_timestamp);
+    }
+
+    #[test]
+    fn test_initialize_unlock_timestamp_not_in_future() {
+        let mut position_lock = PositionLock {
+            ..Default::default()
+        };
+
+        let result = position_lock.initialize(Pubkey::new_unique(), 1_000, 1_000);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_is_locked() {
+        let mut position_lock = PositionLock {
+            ..Default::default()
+        };
+        position_lock
+            .initialize(Pubkey::new_unique(), 2_000, 1_000)
+            .unwrap();
+
+        assert!(position_lock.is_locked(1_000));
+        assert!(position_lock.is_locked(1_999));
+        assert!(!position_lock.is_locked(2_000));
+        assert!(!position_lock.is_locked(3_000));
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut position_lock = PositionLock {
+            ..Default::default()
+        };
+        let position = Pubkey::new_unique();
+        position_lock.initialize(position, 2_000, 1_000).unwrap();
+
+        let result = position_lock.extend(3_000, 1_500);
+        assert!(result.is_ok());
+
+        assert_eq!(position, position_lock.position);
+        assert_eq!(3_000, position_lock.unlock_timestamp);
+        assert!(position_lock.is_locked(2_999));
+    }
+
+    #[test]
+    fn test_extend_expired_lock() {
+        let mut position_lock = PositionLock {
+            ..Default::default()
+        };
+        position_lock
+            .initialize(Pubkey::new_unique(), 2_000, 1_000)
+            .unwrap();
+        assert!(!position_lock.is_locked(2_500));
+
+        let result = position_lock.extend(3_000, 2_500);
+        assert!(result.is_ok());
+        assert!(position_lock.is_locked(2_500));
+    }
+
+    #[test]
+    fn test_extend_unlock_timestamp_not_later() {
+        let mut position_lock = PositionLock {
+            ..Default::default()
+        };
+        position_lock
+            .initialize(Pubkey::new_unique(), 2_000, 1_000)
+            .unwrap();
+
+        // not later than the current unlock timestamp
+        let result = position_lock.extend(2_000, 1_500);
+        assert!(result.is_err());
+        let result = position_lock.extend(1_800, 1_500);
+        assert!(result.is_err());
+
+        // not in the future
+        let result = position_lock.extend(2_500, 2_500);
+        assert!(result.is_err());
+
+        assert_eq!(2_000, position_lock.unlock_timestamp);
+    }
+}
+
+#[cfg(test)]
+mod data_layout_tests {
+    use anchor_lang::Discriminator;
+
+    use super::*;
+
+    #[test]
+    fn test_position_lock_data_layout() {
+        let position_lock_position = Pubkey::new_unique();
+        let position_lock_unlock_timestamp = 0x1122334455667788u64;
+        let position_lock_reserved = [0u8; 64];
+
+        // manually build the expected data layout
+        let mut position_lock_data = [0u8; PositionLock::LEN];
+        let mut offset = 0;
+        position_lock_data[offset..offset + 8].copy_from_slice(&PositionLock::discriminator());
+        offset += 8;
+        position_lock_data[offset..offset + 32].copy_from_slice(&position_lock_position.to_bytes());
+        offset += 32;
+        position_lock_data[offset..offset + 8]
+            .copy_from_slice(&position_lock_unlock_timestamp.to_le_bytes());
+        offset += 8;
+        position_lock_data[offset..offset + position_lock_reserved.len()]
+            .copy_from_slice(&position_lock_reserved);
+        offset += position_lock_reserved.len();
+        assert_eq!(offset, PositionLock::LEN);
+
+        // deserialize
+        let deserialized = PositionLock::try_deserialize(&mut position_lock_data.as_ref()).unwrap();
+
+        assert_eq!(position_lock_position, deserialized.position);
+        assert_eq!(
+            position_lock_unlock_timestamp,
+            deserialized.unlock_timestamp
+        );
+
+        // serialize
+        let mut serialized = Vec::new();
+        deserialized.try_serialize(&mut serialized).unwrap();
+        serialized.extend_from_slice(&position_lock_reserved);
+
+        assert_eq!(serialized.as_slice(), position_lock_data.as_ref());
+    }
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+// Registers a referrer of a YevefisConfig. Only the token accounts owned by the referrer_authority
+// of a Referrer can receive referral fees, so that swappers cannot refer themselves.
+#[account]
+#[derive(Default)]
+pub struct Referrer {
+    pub yevefis_config: Pubkey, // 32
+    pub referrer_authority: Pubkey, // 32
+                                // 128 RESERVE
+}
+
+impl Referrer {
+    pub const LEN: usize = 8 + 32 + 32 + 128;
+
+    pub fn initialize(&mut self, yevefis_config: Pubkey, referrer_authority: Pubkey) -> Result<()> {
+        self.yevefis_config = yevefis_config;
+        self.referrer_authority = referrer_authority;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod referrer_initialize_tests {
+    use super::*;
+
+    #[test]
+    fn test_initialize() {
+        let mut referrer = Referrer {
+            ..Default::default()
+        };
+        let yevefis_config = Pubkey::new_unique();
+        let referrer_authority = Pubkey::new_unique();
+
+        let result = referrer.initialize(yevefis_config, referrer_authority);
+        assert!(result.is_ok());
+
+        assert_eq!(yevefis_config, referrer.yevefis_config);
+        assert_eq!(referrer_authority, referrer.referrer_authority);
+    }
+}
+
+#[cfg(test)]
+mod data_layout_tests {
+    use anchor_lang::Discriminator;
+
+    use super::*;
+
+    #[test]
+    fn test_referrer_data_layout() {
+        let referrer_yevefis_config = Pubkey::new_unique();
+        let referrer_referrer_authority = Pubkey::new_unique();
+        let referrer_reserved = [0u8; 128];
+
+        // manually build the expected data layout
+        let mut referrer_data = [0u8; Referrer::LEN];
+        let mut offset = 0;
+        referrer_data[offset..offset + 8].copy_from_slice(&Referrer::discriminator());
+        offset += 8;
+        referrer_data[offset..offset + 32].copy_from_slice(&referrer_yevefis_config.to_bytes());
+        offset += 32;
+        referrer_data[offset..offset + 32].copy_from_slice(&referrer_referrer_authority.to_bytes());
+        offset += 32;
+        referrer_data[offset..offset + referrer_reserved.len()].copy_from_slice(&referrer_reserved);
+        offset += referrer_reserved.len();
+        assert_eq!(offset, Referrer::LEN);
+
+        // deserialize
+        let deserialized = Referrer::try_deserialize(&mut referrer_data.as_ref()).unwrap();
+
+        assert_eq!(referrer_yevefis_config, deserialized.yevefis_config);
+        assert_eq!(referrer_referrer_authority, deserialized.referrer_authority);
+
+        // serialize
+        let mut serialized = Vec::new();
+        deserialized.try_serialize(&mut serialized).unwrap();
+        serialized.extend_from_slice(&referrer_reserved);
+
+        assert_eq!(serialized.as_slice(), referrer_data.as_ref());
+    }
+}
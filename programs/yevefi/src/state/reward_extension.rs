@@ -0,0 +1,417 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+
+use super::{
+    PositionRewardInfo, Tick, Yevefi, YevefiRewardInfo, YevefiRewardTotals, TICK_ARRAY_SIZE,
+    TICK_ARRAY_SIZE_USIZE,
+};
+
+// Number of rewards supported by a YevefiRewardExtension, in addition to the NUM_REWARDS of the Yevefi
+pub const NUM_EXTENSION_REWARDS: usize = 2;
+
+/// Additional rewards of a Yevefi.
+///
+/// Extension rewards accrue with the same math as `Yevefi.reward_infos`, but their growth outside
+/// of each tick is stored in a `TickArrayRewardExtension`, and the rewards owed to a position are
+/// stored in a `PositionRewardExtension`.
+#[account]
+#[derive(Default)]
+pub struct YevefiRewardExtension {
+    pub yevefi: Pubkey, // 32
+
+    // Extension rewards are only accrued by the instructions supporting the reward extension,
+    // so they keep their own timestamp
+    pub reward_last_updated_timestamp: u64, // 8
+
+    pub reward_infos: [YevefiRewardInfo; NUM_EXTENSION_REWARDS], // 256
+
+    // Amounts of each extension reward funded, emitted and collected, so that emissions stop
+    // once the funds run out, as for the rewards of the yevefi
+    pub reward_totals: [YevefiRewardTotals; NUM_EXTENSION_REWARDS], // 64
+}
+
+impl YevefiRewardExtension {
+    pub const LEN: usize =
+        8 + 32 + 8 + 128 * NUM_EXTENSION_REWARDS + YevefiRewardTotals::LEN * NUM_EXTENSION_REWARDS;
+
+    pub fn initialize(
+        &mut self,
+        yevefi: Pubkey,
+        reward_authority: Pubkey,
+        reward_last_updated_timestamp: u64,
+    ) {
+        self.yevefi = yevefi;
+        self.reward_last_updated_timestamp = reward_last_updated_timestamp;
+        self.reward_infos = [YevefiRewardInfo::new(reward_authority); NUM_EXTENSION_REWARDS];
+    }
+
+    // The extension rewards are emitted to the liquidity of the yevefi, which must not have
+    // changed since they were last updated.
+    pub fn update_rewards(
+        &mut self,
+        liquidity: u128,
+        reward_infos: [YevefiRewardInfo; NUM_EXTENSION_REWARDS],
+        reward_last_updated_timestamp: u64,
+    ) {
+        for (i, reward_info) in reward_infos.iter().enumerate() {
+            self.reward_totals[i].record_emissions(liquidity, &self.reward_infos[i], reward_info);
+        }
+        self.reward_last_updated_timestamp = reward_last_updated_timestamp;
+        self.reward_infos = reward_infos;
+    }
+
+    /// Record the extension rewards collected by a position at the specified index.
+    pub fn record_reward_collected(&mut self, index: usize, amount: u64) {
+        self.reward_totals[index].collected =
+            self.reward_totals[index].collected.saturating_add(amount);
+    }
+
+    /// Record the funds of the extension reward at the specified index.
+    /// The reward infos must be up to date.
+    pub fn sync_reward_funded(&mut self, index: usize, vault_amount: u64) {
+        self.reward_totals[index].sync_funded(vault_amount);
+    }
+
+    pub fn update_reward_authority(&mut self, index: usize, authority: Pubkey) -> Result<()> {
+        if index >= NUM_EXTENSION_REWARDS {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+        self.reward_infos[index].authority = authority;
+
+        Ok(())
+    }
+
+    pub fn update_emissions(
+        &mut self,
+        index: usize,
+        liquidity: u128,
+        reward_infos: [YevefiRewardInfo; NUM_EXTENSION_REWARDS],
+        timestamp: u64,
+        emissions_per_second_x64: u128,
+    ) -> Result<()> {
+        if index >= NUM_EXTENSION_REWARDS {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+        self.update_rewards(liquidity, reward_infos, timestamp);
+        self.reward_infos[index].emissions_per_second_x64 = emissions_per_second_x64;
+
+        Ok(())
+    }
+
+    pub fn initialize_reward(&mut self, index: usize, mint: Pubkey, vault: Pubkey) -> Result<()> {
+        if index >= NUM_EXTENSION_REWARDS {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+
+        let lowest_index = match self.reward_infos.iter().position(|r| !r.initialized()) {
+            Some(lowest_index) => lowest_index,
+            None => return Err(ErrorCode::InvalidRewardIndex.into()),
+        };
+
+        if lowest_index != index {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+
+        self.reward_infos[index].mint = mint;
+        self.reward_infos[index].vault = vault;
+
+        Ok(())
+    }
+}
+
+/// Growth of the extension rewards outside of each tick of a TickArray.
+///
+/// Unlike `Tick.reward_growths_outside`, the values are maintained for every tick of the array,
+/// whether it is initialized or not, and are flipped whenever the current tick index moves across
+/// the tick. This way ticks don't need to be updated when their liquidity is modified.
+#[account(zero_copy(unsafe))]
+#[repr(C, packed)]
+pub struct TickArrayRewardExtension {
+    pub yevefi: Pubkey,        // 32
+    pub start_tick_index: i32, // 4
+
+    // Array of Q64.64
+    pub reward_growths_outside: [[u128; NUM_EXTENSION_REWARDS]; TICK_ARRAY_SIZE_USIZE], // 2816
+}
+
+impl Default for TickArrayRewardExtension {
+    #[inline]
+    fn default() -> TickArrayRewardExtension {
+        TickArrayRewardExtension {
+            yevefi: Pubkey::default(),
+            start_tick_index: 0,
+            reward_growths_outside: [[0; NUM_EXTENSION_REWARDS]; TICK_ARRAY_SIZE_USIZE],
+        }
+    }
+}
+
+impl TickArrayRewardExtension {
+    pub const LEN: usize = 8 + 36 + 16 * NUM_EXTENSION_REWARDS * TICK_ARRAY_SIZE_USIZE;
+
+    /// Initialize the TickArrayRewardExtension object
+    ///
+    /// # Parameters
+    /// - `yevefi` - The yevefi of the tick array
+    /// - `yevefi_key` - The address of the yevefi
+    /// - `start_tick_index` - The start tick index of the tick array
+    /// - `reward_growths_global` - The current growths of the extension rewards
+    ///
+    /// # Errors
+    /// - `InvalidStartTick`: - The provided start-tick-index is not an initializable tick index in this Yevefi w/ this tick-spacing.
+    pub fn initialize(
+        &mut self,
+        yevefi: &Yevefi,
+        yevefi_key: Pubkey,
+        start_tick_index: i32,
+        reward_growths_global: [u128; NUM_EXTENSION_REWARDS],
+    ) -> Result<()> {
+        if !Tick::check_is_valid_start_tick(start_tick_index, yevefi.tick_spacing) {
+            return Err(ErrorCode::InvalidStartTick.into());
+        }
+
+        self.yevefi = yevefi_key;
+        self.start_tick_index = start_tick_index;
+
+        // By convention, assume all prior growth happened below the tick
+        for offset in 0..TICK_ARRAY_SIZE_USIZE {
+            let tick_index = start_tick_index + offset as i32 * yevefi.tick_spacing as i32;
+            self.reward_growths_outside[offset] = if yevefi.tick_current_index >= tick_index {
+                reward_growths_global
+            } else {
+                [0; NUM_EXTENSION_REWARDS]
+            };
+        }
+
+        Ok(())
+    }
+
+    /// Returns the start tick index of the tick array holding the given tick index.
+    pub fn start_tick_index_of(tick_index: i32, tick_spacing: u16) -> i32 {
+        let ticks_in_array = TICK_ARRAY_SIZE * tick_spacing as i32;
+        tick_index.div_euclid(ticks_in_array) * ticks_in_array
+    }
+
+    pub fn get_reward_growths_outside(
+        &self,
+        tick_index: i32,
+        tick_spacing: u16,
+    ) -> Result<[u128; NUM_EXTENSION_REWARDS]> {
+        let offset = self.tick_offset(tick_index, tick_spacing)?;
+        Ok(self.reward_growths_outside[offset])
+    }
+
+    /// Flip the reward growths outside of the ticks the current tick index moved across.
+    ///
+    /// A tick is moved across if `tick_current_index >= tick_index` changed, so the ticks in
+    /// (min(before, after), max(before, after)] are flipped.
+    pub fn cross_ticks(
+        &mut self,
+        tick_spacing: u16,
+        tick_current_index_before: i32,
+        tick_current_index_after: i32,
+        reward_growths_global: &[u128; NUM_EXTENSION_REWARDS],
+    ) {
+        let lower_exclusive = tick_current_index_before.min(tick_current_index_after);
+        let upper_inclusive = tick_current_index_before.max(tick_current_index_after);
+
+        for offset in 0..TICK_ARRAY_SIZE_USIZE {
+            let tick_index = self.start_tick_index + offset as i32 * tick_spacing as i32;
+            if tick_index <= lower_exclusive || tick_index > upper_inclusive {
+                continue;
+            }
+
+            let mut reward_growths_outside = self.reward_growths_outside[offset];
+            for (reward_growth_outside, reward_growth_global) in reward_growths_outside
+                .iter_mut()
+                .zip(reward_growths_global.iter())
+            {
+                *reward_growth_outside = reward_growth_global.wrapping_sub(*reward_growth_outside);
+            }
+            self.reward_growths_outside[offset] = reward_growths_outside;
+        }
+    }
+
+    fn tick_offset(&self, tick_index: i32, tick_spacing: u16) -> Result<usize> {
+        if tick_spacing == 0 {
+            return Err(ErrorCode::InvalidTickSpacing.into());
+        }
+
+        let tick_spacing = tick_spacing as i32;
+        let start_tick_index = self.start_tick_index;
+        if tick_index < start_tick_index
+            || tick_index >= start_tick_index + TICK_ARRAY_SIZE * tick_spacing
+            || (tick_index - start_tick_index) % tick_spacing != 0
+        {
+            return Err(ErrorCode::TickNotFound.into());
+        }
+
+        Ok(((tick_index - start_tick_index) / tick_spacing) as usize)
+    }
+}
+
+/// Checkpoints and amounts owed of the extension rewards of a Position.
+/// A position only earns extension rewards once its PositionRewardExtension is initialized.
+#[account]
+#[derive(Default)]
+pub struct PositionRewardExtension {
+    pub position: Pubkey, // 32
+
+    pub reward_infos: [PositionRewardInfo; NUM_EXTENSION_REWARDS], // 48
+}
+
+impl PositionRewardExtension {
+    pub const LEN: usize = 8 + 32 + 24 * NUM_EXTENSION_REWARDS;
+
+    pub fn initialize(
+        &mut self,
+        position: Pubkey,
+        reward_growths_inside: [u128; NUM_EXTENSION_REWARDS],
+    ) {
+        self.position = position;
+        for (reward_info, reward_growth_inside) in
+            self.reward_infos.iter_mut().zip(reward_growths_inside)
+        {
+            reward_info.growth_inside_checkpoint = reward_growth_inside;
+            reward_info.amount_owed = 0;
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.reward_infos
+            .iter()
+            .all(|reward_info| reward_info.amount_owed == 0)
+    }
+
+    pub fn update_reward_infos(
+        &mut self,
+        reward_infos: [PositionRewardInfo; NUM_EXTENSION_REWARDS],
+    ) {
+        self.reward_infos = reward_infos;
+    }
+
+    pub fn update_reward_owed(&mut self, index: usize, amount_owed: u64) {
+        self.reward_infos[index].amount_owed = amount_owed;
+    }
+}
+
+#[cfg(test)]
+mod tick_array_reward_extension_tests {
+    use super::*;
+    use crate::state::yevefi_builder::YevefiBuilder;
+
+    #[test]
+    fn test_initialize() {
+        let yevefi = YevefiBuilder::new()
+            .tick_spacing(8)
+            .tick_current_index(80)
+            .build();
+
+        let mut extension = TickArrayRewardExtension::default();
+        extension
+            .initialize(&yevefi, Pubkey::new_unique(), 0, [100, 200])
+            .unwrap();
+
+        // ticks at or below the current tick index
+        assert_eq!(
+            extension.get_reward_growths_outside(0, 8).unwrap(),
+            [100, 200]
+        );
+        assert_eq!(
+            extension.get_reward_growths_outside(80, 8).unwrap(),
+            [100, 200]
+        );
+        // ticks above the current tick index
+        assert_eq!(extension.get_reward_growths_outside(88, 8).unwrap(), [0, 0]);
+    }
+
+    #[test]
+    fn test_initialize_invalid_start_tick() {
+        let yevefi = YevefiBuilder::new().tick_spacing(8).build();
+
+        let mut extension = TickArrayRewardExtension::default();
+        assert!(extension
+            .initialize(&yevefi, Pubkey::new_unique(), 8, [0, 0])
+            .is_err());
+    }
+
+    #[test]
+    fn test_get_reward_growths_outside_out_of_array() {
+        let extension = TickArrayRewardExtension::default();
+        assert!(extension.get_reward_growths_outside(-8, 8).is_err());
+        assert!(extension.get_reward_growths_outside(704, 8).is_err());
+        assert!(extension.get_reward_growths_outside(4, 8).is_err());
+        assert!(extension.get_reward_growths_outside(696, 8).is_ok());
+    }
+
+    #[test]
+    fn test_start_tick_index_of() {
+        assert_eq!(TickArrayRewardExtension::start_tick_index_of(0, 8), 0);
+        assert_eq!(TickArrayRewardExtension::start_tick_index_of(703, 8), 0);
+        assert_eq!(TickArrayRewardExtension::start_tick_index_of(704, 8), 704);
+        assert_eq!(TickArrayRewardExtension::start_tick_index_of(-1, 8), -704);
+    }
+
+    #[test]
+    fn test_cross_ticks() {
+        let yevefi = YevefiBuilder::new()
+            .tick_spacing(8)
+            .tick_current_index(40)
+            .build();
+
+        let mut extension = TickArrayRewardExtension::default();
+        extension
+            .initialize(&yevefi, Pubkey::new_unique(), 0, [100, 200])
+            .unwrap();
+
+        // move up from 40 to 56: ticks 48 and 56 are moved across
+        extension.cross_ticks(8, 40, 56, &[150, 250]);
+        assert_eq!(
+            extension.get_reward_growths_outside(40, 8).unwrap(),
+            [100, 200]
+        );
+        assert_eq!(
+            extension.get_reward_growths_outside(48, 8).unwrap(),
+            [150, 250]
+        );
+        assert_eq!(
+            extension.get_reward_growths_outside(56, 8).unwrap(),
+            [150, 250]
+        );
+        assert_eq!(extension.get_reward_growths_outside(64, 8).unwrap(), [0, 0]);
+
+        // move down from 56 to 47: ticks 48 and 56 are moved across again
+        extension.cross_ticks(8, 56, 47, &[170, 300]);
+        assert_eq!(
+            extension.get_reward_growths_outside(40, 8).unwrap(),
+            [100, 200]
+        );
+        assert_eq!(
+            extension.get_reward_growths_outside(48, 8).unwrap(),
+            [20, 50]
+        );
+        assert_eq!(
+            extension.get_reward_growths_outside(56, 8).unwrap(),
+            [20, 50]
+        );
+        assert_eq!(extension.get_reward_growths_outside(64, 8).unwrap(), [0, 0]);
+    }
+}
+
+#[cfg(test)]
+mod position_reward_extension_tests {
+    use super::*;
+
+    #[test]
+    fn test_initialize() {
+        let mut extension = PositionRewardExtension::default();
+        extension.initialize(Pubkey::new_unique(), [100, 200]);
+
+        assert_eq!(extension.reward_infos[0].growth_inside_checkpoint, 100);
+        assert_eq!(extension.reward_infos[1].growth_inside_checkpoint, 200);
+        assert!(extension.is_empty());
+
+        extension.update_reward_owed(1, 10);
+        assert!(!extension.is_empty());
+    }
+}
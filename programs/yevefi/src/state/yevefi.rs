@@ -1,13 +1,13 @@
 use crate::{
     errors::ErrorCode,
     math::{
-        tick_index_from_sqrt_price, MAX_FEE_RATE, MAX_PROTOCOL_FEE_RATE, MAX_SQRT_PRICE_X64,
-        MIN_SQRT_PRICE_X64,
+        checked_mul_shift_right_round_up_if, tick_index_from_sqrt_price, MAX_FEE_RATE,
+        MAX_PROTOCOL_FEE_RATE, MAX_SQRT_PRICE_X64, MIN_SQRT_PRICE_X64, Q64_RESOLUTION,
     },
 };
 use anchor_lang::prelude::*;
 
-use super::YevefisConfig;
+use super::{AdaptiveFeeConstants, AdaptiveFeeInfo, YevefisConfig};
 
 #[account]
 #[derive(Default)]
@@ -51,13 +51,50 @@ pub struct Yevefi {
     pub reward_last_updated_timestamp: u64, // 8
 
     pub reward_infos: [YevefiRewardInfo; NUM_REWARDS], // 384
+
+    // Set while a flash loan or flash swap callback is in progress
+    pub reentrancy_lock: bool, // 1
+
+    // Disabled unless the yevefi was initialized with an AdaptiveFeeTier
+    pub adaptive_fee_info: AdaptiveFeeInfo, // 34
+
+    // Blocks swaps and liquidity increases, while LPs can still exit
+    pub paused: bool, // 1
+
+    // Emission schedule of each reward, applied on top of reward_infos[i].emissions_per_second_x64
+    pub reward_schedules: [YevefiRewardSchedule; NUM_REWARDS], // 336
+
+    // Set once a YevefiRewardExtension is initialized, after which only the instructions
+    // supporting the reward extension can modify the liquidity of the yevefi
+    pub reward_extension_initialized: bool, // 1
+
+    // Amounts of each reward funded, emitted to and collected by positions, so that emissions
+    // stop once the funds run out and only the surplus of a reward vault can be withdrawn
+    pub reward_totals: [YevefiRewardTotals; NUM_REWARDS], // 96
+
+    // Reward authorities proposed by the current reward authorities, which take over once they
+    // accept. Pubkey::default() if no transfer is pending.
+    pub pending_reward_authorities: [Pubkey; NUM_REWARDS], // 96
 }
 
 // Number of rewards supported by Yevefis
 pub const NUM_REWARDS: usize = 3;
 
 impl Yevefi {
-    pub const LEN: usize = 8 + 261 + 384;
+    // Size of the yevefis created before fields were appended to the layout.
+    // They need to be migrated to LEN with migrate_yevefis before they can be used.
+    pub const LEGACY_LEN: usize = 8 + 261 + 384;
+
+    pub const LEN: usize = 8
+        + 261
+        + 384
+        + 1
+        + AdaptiveFeeInfo::LEN
+        + 1
+        + YevefiRewardSchedule::LEN * NUM_REWARDS
+        + 1
+        + YevefiRewardTotals::LEN * NUM_REWARDS
+        + 32 * NUM_REWARDS;
     pub fn seeds(&self) -> [&[u8]; 6] {
         [
             &b"yevefi"[..],
@@ -107,6 +144,7 @@ impl Yevefi {
         yevefis_config: &Account<YevefisConfig>,
         bump: u8,
         tick_spacing: u16,
+        tick_spacing_seed: u16,
         sqrt_price: u128,
         default_fee_rate: u16,
         token_mint_a: Pubkey,
@@ -126,7 +164,7 @@ impl Yevefi {
         self.yevefi_bump = [bump];
 
         self.tick_spacing = tick_spacing;
-        self.tick_spacing_seed = self.tick_spacing.to_le_bytes();
+        self.tick_spacing_seed = tick_spacing_seed.to_le_bytes();
 
         self.update_fee_rate(default_fee_rate)?;
         self.update_protocol_fee_rate(yevefis_config.default_protocol_fee_rate)?;
@@ -152,6 +190,22 @@ impl Yevefi {
         Ok(())
     }
 
+    /// Enables the adaptive fee of a newly initialized Yevefi.
+    /// `fee_rate` is used as the base fee rate.
+    pub fn initialize_adaptive_fee(
+        &mut self,
+        adaptive_fee_constants: AdaptiveFeeConstants,
+    ) -> Result<()> {
+        adaptive_fee_constants.validate()?;
+
+        self.adaptive_fee_info = AdaptiveFeeInfo {
+            constants: adaptive_fee_constants,
+            variables: Default::default(),
+        };
+
+        Ok(())
+    }
+
     /// Update all reward values for the Yevefi.
     ///
     /// # Parameters
@@ -162,6 +216,7 @@ impl Yevefi {
         reward_infos: [YevefiRewardInfo; NUM_REWARDS],
         reward_last_updated_timestamp: u64,
     ) {
+        self.record_reward_emissions(&reward_infos);
         self.reward_last_updated_timestamp = reward_last_updated_timestamp;
         self.reward_infos = reward_infos;
     }
@@ -182,10 +237,33 @@ impl Yevefi {
             return Err(ErrorCode::InvalidRewardIndex.into());
         }
         self.reward_infos[index].authority = authority;
+        self.pending_reward_authorities[index] = Pubkey::default();
 
         Ok(())
     }
 
+    /// Update the pending reward authority at the specified Yevefi reward index.
+    pub fn update_pending_reward_authority(
+        &mut self,
+        index: usize,
+        pending_authority: Pubkey,
+    ) -> Result<()> {
+        if index >= NUM_REWARDS {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+        self.pending_reward_authorities[index] = pending_authority;
+
+        Ok(())
+    }
+
+    /// Replace the reward authority at the specified Yevefi reward index by the pending one.
+    pub fn accept_reward_authority(&mut self, index: usize) -> Result<()> {
+        if index >= NUM_REWARDS {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+        self.update_reward_authority(index, self.pending_reward_authorities[index])
+    }
+
     pub fn update_emissions(
         &mut self,
         index: usize,
@@ -198,6 +276,28 @@ impl Yevefi {
         }
         self.update_rewards(reward_infos, timestamp);
         self.reward_infos[index].emissions_per_second_x64 = emissions_per_second_x64;
+        // A constant emission rate replaces any emission schedule
+        self.reward_schedules[index] = YevefiRewardSchedule::default();
+
+        Ok(())
+    }
+
+    pub fn update_emissions_schedule(
+        &mut self,
+        index: usize,
+        reward_infos: [YevefiRewardInfo; NUM_REWARDS],
+        timestamp: u64,
+        emissions_per_second_x64: u128,
+        reward_schedule: YevefiRewardSchedule,
+    ) -> Result<()> {
+        if index >= NUM_REWARDS {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+        reward_schedule.validate()?;
+
+        self.update_rewards(reward_infos, timestamp);
+        self.reward_infos[index].emissions_per_second_x64 = emissions_per_second_x64;
+        self.reward_schedules[index] = reward_schedule;
 
         Ok(())
     }
@@ -231,10 +331,15 @@ impl Yevefi {
         fee_growth_global: u128,
         reward_infos: [YevefiRewardInfo; NUM_REWARDS],
         protocol_fee: u64,
+        adaptive_fee_info: AdaptiveFeeInfo,
         is_token_fee_in_a: bool,
         reward_last_updated_timestamp: u64,
     ) {
+        // Rewards were accrued with the liquidity before the swap
+        self.record_reward_emissions(&reward_infos);
+
         self.tick_current_index = tick_index;
+        self.adaptive_fee_info = adaptive_fee_info;
         self.sqrt_price = sqrt_price;
         self.liquidity = liquidity;
         self.reward_infos = reward_infos;
@@ -272,6 +377,111 @@ impl Yevefi {
         self.protocol_fee_owed_a = 0;
         self.protocol_fee_owed_b = 0;
     }
+
+    pub fn update_after_flash_loan(
+        &mut self,
+        fee_growth_global_a: u128,
+        fee_growth_global_b: u128,
+        protocol_fee_owed_a: u64,
+        protocol_fee_owed_b: u64,
+    ) {
+        self.fee_growth_global_a = fee_growth_global_a;
+        self.fee_growth_global_b = fee_growth_global_b;
+        self.protocol_fee_owed_a = protocol_fee_owed_a;
+        self.protocol_fee_owed_b = protocol_fee_owed_b;
+    }
+
+    /// Record the rewards collected by a position at the specified Yevefi reward index.
+    pub fn record_reward_collected(&mut self, index: usize, amount: u64) {
+        self.reward_totals[index].collected =
+            self.reward_totals[index].collected.saturating_add(amount);
+    }
+
+    /// Record the funds of the reward at the specified Yevefi reward index.
+    /// The reward infos must be up to date, so that emissions are not accrued retroactively with
+    /// the new funds.
+    pub fn sync_reward_funded(&mut self, index: usize, vault_amount: u64) {
+        self.reward_totals[index].sync_funded(vault_amount);
+    }
+
+    /// Record the funds deposited into the vault of the reward at the specified Yevefi reward index.
+    /// The reward infos must be up to date, so that emissions are not accrued retroactively with
+    /// the new funds.
+    pub fn record_reward_funded(&mut self, index: usize, amount: u64) {
+        self.reward_totals[index].funded = self.reward_totals[index].funded.saturating_add(amount);
+    }
+
+    /// Extend the emission end of the reward at the specified Yevefi reward index by the time the
+    /// given amount lasts at the emission rate before the end.
+    pub fn extend_emissions_end(
+        &mut self,
+        index: usize,
+        timestamp: u64,
+        amount: u64,
+    ) -> Result<()> {
+        if index >= NUM_REWARDS {
+            return Err(ErrorCode::InvalidRewardIndex.into());
+        }
+        let emissions_per_second_x64 = self.reward_infos[index].emissions_per_second_x64;
+        self.reward_schedules[index].extend_end_timestamp(
+            emissions_per_second_x64,
+            timestamp,
+            amount,
+        )
+    }
+
+    /// Returns the amount of a reward vault which is not owed to positions.
+    /// The funds never exceed the tokens deposited for emissions, so the funds not emitted yet
+    /// can be withdrawn without knowing how much of the emitted rewards was collected.
+    /// The reward infos and funds must be up to date, so that all emissions and deposits are
+    /// recorded.
+    pub fn reward_surplus(&self, index: usize, vault_amount: u64) -> u64 {
+        vault_amount.min(self.reward_totals[index].unemitted())
+    }
+
+    /// Record the surplus withdrawn from the vault of the reward at the specified Yevefi reward
+    /// index, which no longer funds emissions.
+    pub fn record_reward_withdrawn(&mut self, index: usize, amount: u64) {
+        self.reward_totals[index].funded = self.reward_totals[index].funded.saturating_sub(amount);
+    }
+
+    // Records the rewards emitted since the current reward infos.
+    // Rewards are emitted to the current liquidity, so this must be called before the liquidity
+    // is updated.
+    fn record_reward_emissions(&mut self, next_reward_infos: &[YevefiRewardInfo; NUM_REWARDS]) {
+        for (i, next_reward_info) in next_reward_infos.iter().enumerate() {
+            self.reward_totals[i].record_emissions(
+                self.liquidity,
+                &self.reward_infos[i],
+                next_reward_info,
+            );
+        }
+    }
+
+    pub fn initialize_reward_extension(&mut self) {
+        self.reward_extension_initialized = true;
+    }
+
+    pub fn update_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    /// Returns an error if this yevefi is paused.
+    /// The global pause of the YevefisConfigExtension is checked by `util::require_not_paused`.
+    pub fn require_not_paused(&self) -> Result<()> {
+        if self.paused {
+            return Err(ErrorCode::YevefiPaused.into());
+        }
+        Ok(())
+    }
+
+    /// Returns an error if a flash loan or flash swap callback of this yevefi is in progress.
+    pub fn require_unlocked(&self) -> Result<()> {
+        if self.reentrancy_lock {
+            return Err(ErrorCode::YevefiLocked.into());
+        }
+        Ok(())
+    }
 }
 
 /// Stores the state relevant for tracking liquidity mining rewards at the `Yevefi` level.
@@ -320,6 +530,228 @@ impl YevefiRewardInfo {
     }
 }
 
+// Number of scheduled emission rate changes per reward
+pub const MAX_REWARD_EMISSIONS_RATE_CHANGES: usize = 4;
+
+/// A scheduled change of the emission rate of a reward.
+#[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, Default, Debug, PartialEq)]
+pub struct RewardEmissionsRateChange {
+    /// Timestamp from which the emission rate applies. 0 marks an unused entry.
+    pub timestamp: u64,
+    /// Q64.64 number that indicates how many tokens per second are earned per unit of liquidity.
+    pub emissions_per_second_x64: u128,
+}
+
+impl RewardEmissionsRateChange {
+    pub const LEN: usize = 8 + 16;
+
+    pub fn is_used(&self) -> bool {
+        self.timestamp != 0
+    }
+}
+
+/// Emission schedule of a reward, so that emissions start, change and stop without sending
+/// `set_reward_emissions` at the exact second.
+///
+/// The emission rate at a timestamp is 0 before `start_timestamp` and from `end_timestamp` on.
+/// In between it is `YevefiRewardInfo.emissions_per_second_x64`, replaced by each rate change
+/// whose timestamp has been reached.
+/// The default (all zero) schedule emits at `YevefiRewardInfo.emissions_per_second_x64` forever.
+#[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, Default, Debug, PartialEq)]
+pub struct YevefiRewardSchedule {
+    /// No rewards are emitted before this timestamp.
+    pub start_timestamp: u64,
+    /// No rewards are emitted from this timestamp on. 0 if the emissions have no end.
+    pub end_timestamp: u64,
+    /// Rate changes in ascending timestamp order. Unused entries are at the end.
+    pub rate_changes: [RewardEmissionsRateChange; MAX_REWARD_EMISSIONS_RATE_CHANGES],
+}
+
+impl YevefiRewardSchedule {
+    pub const LEN: usize =
+        8 + 8 + RewardEmissionsRateChange::LEN * MAX_REWARD_EMISSIONS_RATE_CHANGES;
+
+    pub fn new(
+        start_timestamp: u64,
+        end_timestamp: u64,
+        rate_changes: &[RewardEmissionsRateChange],
+    ) -> Result<Self> {
+        if rate_changes.len() > MAX_REWARD_EMISSIONS_RATE_CHANGES {
+            return Err(ErrorCode::InvalidRewardEmissionsSchedule.into());
+        }
+
+        let mut reward_schedule = Self {
+            start_timestamp,
+            end_timestamp,
+            ..Default::default()
+        };
+        reward_schedule.rate_changes[..rate_changes.len()].copy_from_slice(rate_changes);
+        reward_schedule.validate()?;
+
+        Ok(reward_schedule)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.end_timestamp != 0 && self.end_timestamp <= self.start_timestamp {
+            return Err(ErrorCode::InvalidRewardEmissionsSchedule.into());
+        }
+
+        let mut prev_timestamp = self.start_timestamp;
+        let mut unused_found = false;
+        for rate_change in self.rate_changes.iter() {
+            if !rate_change.is_used() {
+                unused_found = true;
+                continue;
+            }
+            // used entries must be packed at the beginning, and strictly inside (start, end)
+            if unused_found
+                || rate_change.timestamp <= prev_timestamp
+                || (self.end_timestamp != 0 && rate_change.timestamp >= self.end_timestamp)
+            {
+                return Err(ErrorCode::InvalidRewardEmissionsSchedule.into());
+            }
+            prev_timestamp = rate_change.timestamp;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the emission rate at the given timestamp.
+    pub fn emissions_per_second_x64_at(
+        &self,
+        emissions_per_second_x64: u128,
+        timestamp: u64,
+    ) -> u128 {
+        if timestamp < self.start_timestamp
+            || (self.end_timestamp != 0 && timestamp >= self.end_timestamp)
+        {
+            return 0;
+        }
+
+        self.rate_changes
+            .iter()
+            .rev()
+            .find(|rate_change| rate_change.is_used() && rate_change.timestamp <= timestamp)
+            .map_or(emissions_per_second_x64, |rate_change| {
+                rate_change.emissions_per_second_x64
+            })
+    }
+
+    /// Returns the first timestamp after the given timestamp at which the emission rate changes.
+    pub fn next_change_timestamp(&self, timestamp: u64) -> Option<u64> {
+        [self.start_timestamp, self.end_timestamp]
+            .into_iter()
+            .chain(
+                self.rate_changes
+                    .iter()
+                    .map(|rate_change| rate_change.timestamp),
+            )
+            .filter(|change_timestamp| *change_timestamp != 0 && *change_timestamp > timestamp)
+            .min()
+    }
+
+    /// Moves the end of the emissions back by the number of seconds the given amount lasts at the
+    /// emission rate before the end. Only emissions which have an end and have not ended yet at
+    /// the given timestamp can be extended.
+    pub fn extend_end_timestamp(
+        &mut self,
+        emissions_per_second_x64: u128,
+        timestamp: u64,
+        amount: u64,
+    ) -> Result<()> {
+        if self.end_timestamp == 0 || self.end_timestamp <= timestamp {
+            return Err(ErrorCode::RewardEmissionsNotExtendable.into());
+        }
+
+        let final_emissions_per_second_x64 =
+            self.emissions_per_second_x64_at(emissions_per_second_x64, self.end_timestamp - 1);
+        if final_emissions_per_second_x64 == 0 {
+            return Err(ErrorCode::RewardEmissionsNotExtendable.into());
+        }
+
+        let extension_seconds =
+            u64::try_from((u128::from(amount) << Q64_RESOLUTION) / final_emissions_per_second_x64)
+                .map_err(|_| ErrorCode::RewardEmissionsNotExtendable)?;
+        self.end_timestamp = self
+            .end_timestamp
+            .checked_add(extension_seconds)
+            .ok_or(ErrorCode::RewardEmissionsNotExtendable)?;
+
+        Ok(())
+    }
+
+    /// Returns the highest emission rate of the schedule.
+    pub fn max_emissions_per_second_x64(&self, emissions_per_second_x64: u128) -> u128 {
+        self.rate_changes
+            .iter()
+            .filter(|rate_change| rate_change.is_used())
+            .map(|rate_change| rate_change.emissions_per_second_x64)
+            .fold(emissions_per_second_x64, u128::max)
+    }
+}
+
+/// Amounts of a reward funded, emitted to and collected by the positions of a `Yevefi`.
+#[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize, Default, Debug, PartialEq)]
+pub struct YevefiRewardTotals {
+    /// Upper bound of the rewards earned by positions, rounded up.
+    pub emitted: u64,
+    /// Rewards transferred from the reward vault to positions by the instructions which write the
+    /// yevefi. `collect_reward` leaves the yevefi read-only, so its transfers are not included.
+    pub collected: u64,
+    /// Tokens deposited into the reward vault for emissions, net of withdrawn surplus.
+    /// Emissions stop once `emitted` reaches this amount.
+    pub funded: u64,
+    /// Reward vault amount when a yevefi created before the totals were recorded was migrated.
+    /// The rewards its positions had earned by then are unknown, so all of it is kept owed and
+    /// none of it is funded.
+    pub legacy_owed: u64,
+}
+
+impl YevefiRewardTotals {
+    pub const LEN: usize = 8 + 8 + 8 + 8;
+
+    /// Funds which have not been emitted yet.
+    pub fn unemitted(&self) -> u64 {
+        self.funded.saturating_sub(self.emitted)
+    }
+
+    /// Records the tokens transferred into the reward vault without `fund_reward`.
+    /// Tokens only leave a reward vault when collected or withdrawn, so the funds are at least the
+    /// vault amount plus the recorded collected rewards, less the rewards owed since the migration.
+    /// The funds are never lowered, as the rewards collected without a record are unknown.
+    pub fn sync_funded(&mut self, vault_amount: u64) {
+        let vault_funded = vault_amount
+            .saturating_add(self.collected)
+            .saturating_sub(self.legacy_owed);
+        self.funded = self.funded.max(vault_funded);
+    }
+
+    /// Records the rewards emitted to the given liquidity between two reward infos.
+    /// Each position earns at most its share of the emissions rounded down, so rounding up keeps
+    /// the recorded amount an upper bound of the rewards owed.
+    pub fn record_emissions(
+        &mut self,
+        liquidity: u128,
+        reward_info: &YevefiRewardInfo,
+        next_reward_info: &YevefiRewardInfo,
+    ) {
+        let reward_growth_delta = next_reward_info
+            .growth_global_x64
+            .wrapping_sub(reward_info.growth_global_x64);
+        let emitted = checked_mul_shift_right_round_up_if(liquidity, reward_growth_delta, true)
+            .unwrap_or(u64::MAX);
+        self.emitted = self.emitted.saturating_add(emitted);
+    }
+}
+
+/// Remaining runway of each reward returned by the `reward_runway` instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug, PartialEq)]
+pub struct RewardRunwayResult {
+    /// Seconds until the funds of each reward run out at its scheduled emission rates.
+    /// None if the reward is not initialized or its funds never run out.
+    pub runway_seconds: [Option<u64>; NUM_REWARDS],
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Copy)]
 pub struct YevefiBumps {
     pub yevefi_bump: u8,
@@ -338,9 +770,279 @@ fn test_yevefi_reward_info_initialized() {
     assert!(reward_info.initialized());
 }
 
+#[cfg(test)]
+mod reward_schedule_tests {
+    use super::*;
+
+    fn rate_change(timestamp: u64, emissions_per_second_x64: u128) -> RewardEmissionsRateChange {
+        RewardEmissionsRateChange {
+            timestamp,
+            emissions_per_second_x64,
+        }
+    }
+
+    #[test]
+    fn test_default_schedule_emits_forever() {
+        let schedule = YevefiRewardSchedule::default();
+        assert_eq!(schedule.emissions_per_second_x64_at(10, 0), 10);
+        assert_eq!(schedule.emissions_per_second_x64_at(10, u64::MAX), 10);
+        assert_eq!(schedule.next_change_timestamp(0), None);
+    }
+
+    #[test]
+    fn test_schedule_emissions_per_second_x64_at() {
+        let schedule =
+            YevefiRewardSchedule::new(100, 400, &[rate_change(200, 20), rate_change(300, 30)])
+                .unwrap();
+
+        assert_eq!(schedule.emissions_per_second_x64_at(10, 99), 0);
+        assert_eq!(schedule.emissions_per_second_x64_at(10, 100), 10);
+        assert_eq!(schedule.emissions_per_second_x64_at(10, 199), 10);
+        assert_eq!(schedule.emissions_per_second_x64_at(10, 200), 20);
+        assert_eq!(schedule.emissions_per_second_x64_at(10, 300), 30);
+        assert_eq!(schedule.emissions_per_second_x64_at(10, 399), 30);
+        assert_eq!(schedule.emissions_per_second_x64_at(10, 400), 0);
+        assert_eq!(schedule.max_emissions_per_second_x64(10), 30);
+    }
+
+    #[test]
+    fn test_schedule_next_change_timestamp() {
+        let schedule = YevefiRewardSchedule::new(100, 400, &[rate_change(200, 20)]).unwrap();
+
+        assert_eq!(schedule.next_change_timestamp(0), Some(100));
+        assert_eq!(schedule.next_change_timestamp(100), Some(200));
+        assert_eq!(schedule.next_change_timestamp(250), Some(400));
+        assert_eq!(schedule.next_change_timestamp(400), None);
+    }
+
+    #[test]
+    fn test_schedule_without_end() {
+        let schedule = YevefiRewardSchedule::new(100, 0, &[]).unwrap();
+        assert_eq!(schedule.emissions_per_second_x64_at(10, 99), 0);
+        assert_eq!(schedule.emissions_per_second_x64_at(10, u64::MAX), 10);
+    }
+
+    #[test]
+    fn test_schedule_invalid() {
+        // end before start
+        assert!(YevefiRewardSchedule::new(100, 100, &[]).is_err());
+        // rate changes out of order
+        assert!(
+            YevefiRewardSchedule::new(100, 0, &[rate_change(300, 1), rate_change(200, 1)]).is_err()
+        );
+        // rate change before start
+        assert!(YevefiRewardSchedule::new(100, 0, &[rate_change(100, 1)]).is_err());
+        // rate change after end
+        assert!(YevefiRewardSchedule::new(100, 200, &[rate_change(200, 1)]).is_err());
+        // too many rate changes
+        assert!(YevefiRewardSchedule::new(
+            100,
+            0,
+            &[
+                rate_change(101, 1),
+                rate_change(102, 1),
+                rate_change(103, 1),
+                rate_change(104, 1),
+                rate_change(105, 1)
+            ]
+        )
+        .is_err());
+        // unused entry before a used entry
+        let mut schedule = YevefiRewardSchedule::new(100, 0, &[]).unwrap();
+        schedule.rate_changes[1] = rate_change(200, 1);
+        assert!(schedule.validate().is_err());
+    }
+
+    #[test]
+    fn test_schedule_extend_end_timestamp() {
+        // 10 per second until 200, 30 per second until 400
+        let mut schedule =
+            YevefiRewardSchedule::new(100, 400, &[rate_change(200, 30 << Q64_RESOLUTION)]).unwrap();
+
+        // extended at the final emission rate
+        schedule
+            .extend_end_timestamp(10 << Q64_RESOLUTION, 150, 3000)
+            .unwrap();
+        assert_eq!(schedule.end_timestamp, 500);
+        assert!(schedule.validate().is_ok());
+
+        // rounded down
+        schedule
+            .extend_end_timestamp(10 << Q64_RESOLUTION, 150, 59)
+            .unwrap();
+        assert_eq!(schedule.end_timestamp, 501);
+    }
+
+    #[test]
+    fn test_schedule_extend_end_timestamp_not_extendable() {
+        // no end
+        let mut schedule = YevefiRewardSchedule::default();
+        assert!(schedule
+            .extend_end_timestamp(10 << Q64_RESOLUTION, 150, 3000)
+            .is_err());
+
+        // already ended
+        let mut schedule = YevefiRewardSchedule::new(100, 400, &[]).unwrap();
+        assert!(schedule
+            .extend_end_timestamp(10 << Q64_RESOLUTION, 400, 3000)
+            .is_err());
+
+        // no emissions before the end
+        let mut schedule = YevefiRewardSchedule::new(100, 400, &[]).unwrap();
+        assert!(schedule.extend_end_timestamp(0, 150, 3000).is_err());
+    }
+}
+
+#[cfg(test)]
+mod reward_totals_tests {
+    use super::*;
+    use crate::math::Q64_RESOLUTION;
+
+    fn reward_infos(growth_global_x64: u128) -> [YevefiRewardInfo; NUM_REWARDS] {
+        [YevefiRewardInfo {
+            growth_global_x64,
+            ..Default::default()
+        }; NUM_REWARDS]
+    }
+
+    #[test]
+    fn test_emissions_recorded_with_liquidity_before_update() {
+        let mut yevefi = Yevefi {
+            liquidity: 1000,
+            reward_infos: reward_infos(1 << Q64_RESOLUTION),
+            ..Default::default()
+        };
+
+        yevefi.update_rewards_and_liquidity(reward_infos(3 << Q64_RESOLUTION), 5000, 100);
+        assert_eq!(yevefi.reward_totals[0].emitted, 2000);
+
+        yevefi.update_rewards(reward_infos(4 << Q64_RESOLUTION), 200);
+        assert_eq!(yevefi.reward_totals[0].emitted, 7000);
+    }
+
+    #[test]
+    fn test_emissions_rounded_up() {
+        let mut yevefi = Yevefi {
+            liquidity: 3,
+            ..Default::default()
+        };
+
+        // 3 * 1/3 rounds up to 1
+        yevefi.update_rewards(reward_infos((1 << Q64_RESOLUTION) / 3), 100);
+        assert_eq!(yevefi.reward_totals[0].emitted, 1);
+    }
+
+    #[test]
+    fn test_reward_surplus() {
+        let mut yevefi = Yevefi::default();
+        yevefi.reward_totals[0] = YevefiRewardTotals {
+            emitted: 1000,
+            collected: 0,
+            funded: 1500,
+            ..Default::default()
+        };
+        assert_eq!(yevefi.reward_surplus(0, 1500), 500);
+
+        // collecting the emitted rewards leaves the surplus unchanged, recorded or not
+        assert_eq!(yevefi.reward_surplus(0, 900), 500);
+        yevefi.record_reward_collected(0, 600);
+        assert_eq!(yevefi.reward_surplus(0, 900), 500);
+    }
+
+    #[test]
+    fn test_sync_reward_funded() {
+        let mut yevefi = Yevefi::default();
+        yevefi.reward_totals[0].emitted = 1000;
+        yevefi.record_reward_collected(0, 600);
+
+        // 400 owed to positions, 1100 left for emissions
+        yevefi.sync_reward_funded(0, 1500);
+        assert_eq!(yevefi.reward_totals[0].funded, 2100);
+        assert_eq!(yevefi.reward_totals[0].unemitted(), 1100);
+
+        // withdrawing the surplus removes it from the funds
+        yevefi.record_reward_withdrawn(0, yevefi.reward_surplus(0, 1500));
+        assert_eq!(yevefi.reward_totals[0].funded, 1000);
+        assert_eq!(yevefi.reward_totals[0].unemitted(), 0);
+        yevefi.sync_reward_funded(0, 400);
+        assert_eq!(yevefi.reward_totals[0].funded, 1000);
+    }
+
+    #[test]
+    fn test_sync_reward_funded_after_unrecorded_collect() {
+        let mut yevefi = Yevefi::default();
+        yevefi.reward_totals[0].emitted = 1000;
+        yevefi.sync_reward_funded(0, 1500);
+        assert_eq!(yevefi.reward_totals[0].funded, 1500);
+
+        // collect_reward transfers 600 without recording it, which does not lower the funds
+        yevefi.sync_reward_funded(0, 900);
+        assert_eq!(yevefi.reward_totals[0].funded, 1500);
+        assert_eq!(yevefi.reward_surplus(0, 900), 500);
+
+        // a transfer into the vault is only funded beyond the unrecorded collected rewards
+        yevefi.sync_reward_funded(0, 2000);
+        assert_eq!(yevefi.reward_totals[0].funded, 2000);
+        assert_eq!(yevefi.reward_surplus(0, 2000), 1000);
+    }
+
+    #[test]
+    fn test_record_reward_funded() {
+        let mut yevefi = Yevefi::default();
+        yevefi.reward_totals[0].emitted = 1000;
+        yevefi.sync_reward_funded(0, 1000);
+
+        yevefi.record_reward_funded(0, 500);
+        assert_eq!(yevefi.reward_totals[0].funded, 1500);
+        assert_eq!(yevefi.reward_totals[0].unemitted(), 500);
+    }
+}
+
+#[cfg(test)]
+mod reward_authority_tests {
+    use super::*;
+
+    #[test]
+    fn test_accept_reward_authority() {
+        let mut yevefi = Yevefi::default();
+        let authority = Pubkey::new_unique();
+
+        yevefi
+            .update_pending_reward_authority(1, authority)
+            .unwrap();
+        assert_eq!(yevefi.reward_infos[1].authority, Pubkey::default());
+
+        yevefi.accept_reward_authority(1).unwrap();
+        assert_eq!(yevefi.reward_infos[1].authority, authority);
+        assert_eq!(yevefi.pending_reward_authorities[1], Pubkey::default());
+    }
+
+    #[test]
+    fn test_update_reward_authority_clears_pending() {
+        let mut yevefi = Yevefi::default();
+        yevefi
+            .update_pending_reward_authority(0, Pubkey::new_unique())
+            .unwrap();
+
+        let authority = Pubkey::new_unique();
+        yevefi.update_reward_authority(0, authority).unwrap();
+        assert_eq!(yevefi.reward_infos[0].authority, authority);
+        assert_eq!(yevefi.pending_reward_authorities[0], Pubkey::default());
+    }
+
+    #[test]
+    fn test_invalid_reward_index() {
+        let mut yevefi = Yevefi::default();
+        assert!(yevefi
+            .update_pending_reward_authority(NUM_REWARDS, Pubkey::new_unique())
+            .is_err());
+        assert!(yevefi.accept_reward_authority(NUM_REWARDS).is_err());
+    }
+}
+
 #[cfg(test)]
 pub mod yevefi_builder {
-    use super::{Yevefi, YevefiRewardInfo, NUM_REWARDS};
+    use super::{Yevefi, YevefiRewardInfo, YevefiRewardTotals, NUM_REWARDS};
 
     #[derive(Default)]
     pub struct YevefiBuilder {
@@ -354,12 +1056,14 @@ pub mod yevefi_builder {
         fee_growth_global_b: u128,
         reward_last_updated_timestamp: u64,
         reward_infos: [YevefiRewardInfo; NUM_REWARDS],
+        reward_totals: [YevefiRewardTotals; NUM_REWARDS],
     }
 
     impl YevefiBuilder {
         pub fn new() -> Self {
             Self {
                 reward_infos: [YevefiRewardInfo::default(); NUM_REWARDS],
+                reward_totals: [YevefiRewardTotals::default(); NUM_REWARDS],
                 ..Default::default()
             }
         }
@@ -384,6 +1088,20 @@ pub mod yevefi_builder {
             self
         }
 
+        pub fn reward_totals(mut self, reward_totals: [YevefiRewardTotals; NUM_REWARDS]) -> Self {
+            self.reward_totals = reward_totals;
+            self
+        }
+
+        // Rewards are unfunded unless specified, which stops their emissions
+        pub fn fully_funded_rewards(mut self) -> Self {
+            self.reward_totals = [YevefiRewardTotals {
+                funded: u64::MAX,
+                ..Default::default()
+            }; NUM_REWARDS];
+            self
+        }
+
         pub fn tick_spacing(mut self, tick_spacing: u16) -> Self {
             self.tick_spacing = tick_spacing;
             self
@@ -424,6 +1142,7 @@ pub mod yevefi_builder {
                 liquidity: self.liquidity,
                 reward_last_updated_timestamp: self.reward_last_updated_timestamp,
                 reward_infos: self.reward_infos,
+                reward_totals: self.reward_totals,
                 tick_current_index: self.tick_current_index,
                 sqrt_price: self.sqrt_price,
                 tick_spacing: self.tick_spacing,
@@ -442,6 +1161,7 @@ mod data_layout_tests {
     use anchor_lang::Discriminator;
 
     use super::*;
+    use crate::state::AdaptiveFeeVariables;
 
     #[test]
     fn test_yevefi_data_layout() {
@@ -463,6 +1183,27 @@ mod data_layout_tests {
         let yevefi_token_vault_b = Pubkey::new_unique();
         let yevefi_fee_growth_global_b = 0x11223344005566778899aabbccddeeffu128;
         let yevefi_reward_last_updated_timestamp = 0x1234567890abcdefu64;
+        let yevefi_reentrancy_lock = true;
+        let adaptive_fee_filter_period = 0x1122u16;
+        let adaptive_fee_decay_period = 0x3344u16;
+        let adaptive_fee_reduction_factor = 0x5566u16;
+        let adaptive_fee_control_factor = 0x778899aau32;
+        let adaptive_fee_max_volatility_accumulator = 0xbbccddeeu32;
+        let adaptive_fee_last_reference_update_timestamp = 0x1122334455667788u64;
+        let adaptive_fee_volatility_reference = 0x99aabbccu32;
+        let adaptive_fee_tick_group_index_reference = -0x1234567i32;
+        let adaptive_fee_volatility_accumulator = 0xddeeff00u32;
+        let yevefi_paused = true;
+        let reward_schedule_start_timestamp = 0x0102030405060708u64;
+        let reward_schedule_end_timestamp = 0x1112131415161718u64;
+        let reward_schedule_rate_change_timestamp = 0x2122232425262728u64;
+        let reward_schedule_rate_change_emissions_per_second_x64 = 0x3132333435363738u128;
+        let yevefi_reward_extension_initialized = true;
+        let reward_totals_emitted = 0x4142434445464748u64;
+        let reward_totals_collected = 0x5152535455565758u64;
+        let reward_totals_funded = 0x6162636465666768u64;
+        let reward_totals_legacy_owed = 0x7172737475767778u64;
+        let pending_reward_authority = Pubkey::new_unique();
 
         let reward_info_mint = Pubkey::new_unique();
         let reward_info_vault = Pubkey::new_unique();
@@ -532,6 +1273,68 @@ mod data_layout_tests {
             yevefi_data[offset..offset + reward_info_data.len()].copy_from_slice(&reward_info_data);
             offset += reward_info_data.len();
         }
+        yevefi_data[offset] = yevefi_reentrancy_lock as u8;
+        offset += 1;
+        yevefi_data[offset..offset + 2].copy_from_slice(&adaptive_fee_filter_period.to_le_bytes());
+        offset += 2;
+        yevefi_data[offset..offset + 2].copy_from_slice(&adaptive_fee_decay_period.to_le_bytes());
+        offset += 2;
+        yevefi_data[offset..offset + 2]
+            .copy_from_slice(&adaptive_fee_reduction_factor.to_le_bytes());
+        offset += 2;
+        yevefi_data[offset..offset + 4].copy_from_slice(&adaptive_fee_control_factor.to_le_bytes());
+        offset += 4;
+        yevefi_data[offset..offset + 4]
+            .copy_from_slice(&adaptive_fee_max_volatility_accumulator.to_le_bytes());
+        offset += 4;
+        yevefi_data[offset..offset + 8]
+            .copy_from_slice(&adaptive_fee_last_reference_update_timestamp.to_le_bytes());
+        offset += 8;
+        yevefi_data[offset..offset + 4]
+            .copy_from_slice(&adaptive_fee_volatility_reference.to_le_bytes());
+        offset += 4;
+        yevefi_data[offset..offset + 4]
+            .copy_from_slice(&adaptive_fee_tick_group_index_reference.to_le_bytes());
+        offset += 4;
+        yevefi_data[offset..offset + 4]
+            .copy_from_slice(&adaptive_fee_volatility_accumulator.to_le_bytes());
+        offset += 4;
+        yevefi_data[offset] = yevefi_paused as u8;
+        offset += 1;
+        for _ in 0..NUM_REWARDS {
+            yevefi_data[offset..offset + 8]
+                .copy_from_slice(&reward_schedule_start_timestamp.to_le_bytes());
+            offset += 8;
+            yevefi_data[offset..offset + 8]
+                .copy_from_slice(&reward_schedule_end_timestamp.to_le_bytes());
+            offset += 8;
+            for _ in 0..MAX_REWARD_EMISSIONS_RATE_CHANGES {
+                yevefi_data[offset..offset + 8]
+                    .copy_from_slice(&reward_schedule_rate_change_timestamp.to_le_bytes());
+                offset += 8;
+                yevefi_data[offset..offset + 16].copy_from_slice(
+                    &reward_schedule_rate_change_emissions_per_second_x64.to_le_bytes(),
+                );
+                offset += 16;
+            }
+        }
+        yevefi_data[offset] = yevefi_reward_extension_initialized as u8;
+        offset += 1;
+        for _ in 0..NUM_REWARDS {
+            yevefi_data[offset..offset + 8].copy_from_slice(&reward_totals_emitted.to_le_bytes());
+            offset += 8;
+            yevefi_data[offset..offset + 8].copy_from_slice(&reward_totals_collected.to_le_bytes());
+            offset += 8;
+            yevefi_data[offset..offset + 8].copy_from_slice(&reward_totals_funded.to_le_bytes());
+            offset += 8;
+            yevefi_data[offset..offset + 8]
+                .copy_from_slice(&reward_totals_legacy_owed.to_le_bytes());
+            offset += 8;
+        }
+        for _ in 0..NUM_REWARDS {
+            yevefi_data[offset..offset + 32].copy_from_slice(&pending_reward_authority.to_bytes());
+            offset += 32;
+        }
         assert_eq!(offset, yevefi_data.len());
 
         // deserialize
@@ -575,6 +1378,61 @@ mod data_layout_tests {
             );
         }
 
+        assert_eq!(deserialized.reentrancy_lock, yevefi_reentrancy_lock);
+        assert_eq!(
+            deserialized.adaptive_fee_info,
+            AdaptiveFeeInfo {
+                constants: AdaptiveFeeConstants {
+                    filter_period: adaptive_fee_filter_period,
+                    decay_period: adaptive_fee_decay_period,
+                    reduction_factor: adaptive_fee_reduction_factor,
+                    adaptive_fee_control_factor,
+                    max_volatility_accumulator: adaptive_fee_max_volatility_accumulator,
+                },
+                variables: AdaptiveFeeVariables {
+                    last_reference_update_timestamp: adaptive_fee_last_reference_update_timestamp,
+                    volatility_reference: adaptive_fee_volatility_reference,
+                    tick_group_index_reference: adaptive_fee_tick_group_index_reference,
+                    volatility_accumulator: adaptive_fee_volatility_accumulator,
+                },
+            }
+        );
+
+        assert_eq!(deserialized.paused, yevefi_paused);
+        for i in 0..NUM_REWARDS {
+            assert_eq!(
+                deserialized.reward_schedules[i],
+                YevefiRewardSchedule {
+                    start_timestamp: reward_schedule_start_timestamp,
+                    end_timestamp: reward_schedule_end_timestamp,
+                    rate_changes: [RewardEmissionsRateChange {
+                        timestamp: reward_schedule_rate_change_timestamp,
+                        emissions_per_second_x64:
+                            reward_schedule_rate_change_emissions_per_second_x64,
+                    }; MAX_REWARD_EMISSIONS_RATE_CHANGES],
+                }
+            );
+        }
+        assert_eq!(
+            deserialized.reward_extension_initialized,
+            yevefi_reward_extension_initialized
+        );
+        for i in 0..NUM_REWARDS {
+            assert_eq!(
+                deserialized.reward_totals[i],
+                YevefiRewardTotals {
+                    emitted: reward_totals_emitted,
+                    collected: reward_totals_collected,
+                    funded: reward_totals_funded,
+                    legacy_owed: reward_totals_legacy_owed,
+                }
+            );
+        }
+        assert_eq!(
+            deserialized.pending_reward_authorities,
+            [pending_reward_authority; NUM_REWARDS]
+        );
+
         // serialize
         let mut serialized = Vec::new();
         deserialized.try_serialize(&mut serialized).unwrap();
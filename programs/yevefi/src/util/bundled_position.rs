@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode,
+    state::{Position, PositionBundle},
+};
+
+// Loads the bundled positions of the PositionBundle at bundle_indexes, in order.
+// bundle_indexes must be strictly increasing, so that no position is loaded twice.
+// The bump of each bundled position address is passed in bundled_position_bumps,
+// so the addresses are verified without searching for the bumps.
+//
+// The positions are looked up in remaining_accounts, because Account must borrow
+// the AccountInfo for 'info.
+pub fn load_bundled_positions<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    bundled_position_accounts: &Option<Vec<AccountInfo<'info>>>,
+    position_bundle: &PositionBundle,
+    yevefi: Pubkey,
+    bundle_indexes: &[u16],
+    bundled_position_bumps: &[u8],
+) -> Result<Vec<Account<'info, Position>>> {
+    let bundled_position_accounts = bundled_position_accounts
+        .as_ref()
+        .ok_or(ErrorCode::InvalidBundledPositionAccounts)?;
+
+    if bundle_indexes.is_empty() || bundled_position_accounts.len() != bundle_indexes.len() {
+        return Err(ErrorCode::InvalidBundledPositionAccounts.into());
+    }
+    if bundled_position_bumps.len() != bundle_indexes.len() {
+        return Err(ErrorCode::InvalidBundledPositionBump.into());
+    }
+    if bundle_indexes.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err(ErrorCode::InvalidBundledPositionAccounts.into());
+    }
+
+    let mut bundled_positions = Vec::with_capacity(bundle_indexes.len());
+    for ((bundled_position_account, bundle_index), bump) in bundled_position_accounts
+        .iter()
+        .zip(bundle_indexes)
+        .zip(bundled_position_bumps)
+    {
+        if !position_bundle.is_bundled_position_opened(*bundle_index) {
+            return Err(ErrorCode::BundledPositionAlreadyClosed.into());
+        }
+
+        verify_bundled_position_address(
+            bundled_position_account.key,
+            &position_bundle.position_bundle_mint,
+            *bundle_index,
+            *bump,
+        )?;
+
+        let account_info = remaining_accounts
+            .iter()
+            .find(|account| account.key == bundled_position_account.key)
+            .ok_or(ErrorCode::InvalidBundledPositionAccounts)?;
+        if !account_info.is_writable {
+            return Err(ErrorCode::InvalidBundledPositionAccounts.into());
+        }
+
+        let bundled_position = Account::<Position>::try_from(account_info)?;
+        if bundled_position.yevefi != yevefi
+            || bundled_position.position_mint != position_bundle.position_bundle_mint
+        {
+            return Err(ErrorCode::InvalidBundledPositionAccounts.into());
+        }
+        bundled_positions.push(bundled_position);
+    }
+
+    Ok(bundled_positions)
+}
+
+// Only the canonical bump yields an address at which open_bundled_position created a Position.
+fn verify_bundled_position_address(
+    bundled_position: &Pubkey,
+    position_bundle_mint: &Pubkey,
+    bundle_index: u16,
+    bump: u8,
+) -> Result<()> {
+    let bundled_position_address = Pubkey::create_program_address(
+        &[
+            b"bundled_position".as_ref(),
+            position_bundle_mint.as_ref(),
+            bundle_index.to_string().as_bytes(),
+            &[bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::InvalidBundledPositionBump)?;
+    if *bundled_position != bundled_position_address {
+        return Err(ErrorCode::InvalidBundledPositionBump.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod verify_bundled_position_address_tests {
+    use super::*;
+
+    fn find_bundled_position_address(
+        position_bundle_mint: &Pubkey,
+        bundle_index: u16,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                b"bundled_position".as_ref(),
+                position_bundle_mint.as_ref(),
+                bundle_index.to_string().as_bytes(),
+            ],
+            &crate::ID,
+        )
+    }
+
+    #[test]
+    fn test_canonical_bump() {
+        let position_bundle_mint = Pubkey::new_unique();
+        for bundle_index in [0u16, 1, 255] {
+            let (address, bump) =
+                find_bundled_position_address(&position_bundle_mint, bundle_index);
+            assert!(verify_bundled_position_address(
+                &address,
+                &position_bundle_mint,
+                bundle_index,
+                bump
+            )
+            .is_ok());
+        }
+    }
+
+    #[test]
+    fn test_wrong_bump() {
+        let position_bundle_mint = Pubkey::new_unique();
+        for bundle_index in [0u16, 1, 255] {
+            let (address, bump) =
+                find_bundled_position_address(&position_bundle_mint, bundle_index);
+            for wrong_bump in [bump.wrapping_sub(1), bump.wrapping_add(1)] {
+                assert_eq!(
+                    verify_bundled_position_address(
+                        &address,
+                        &position_bundle_mint,
+                        bundle_index,
+                        wrong_bump
+                    )
+                    .unwrap_err(),
+                    ErrorCode::InvalidBundledPositionBump.into()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_bump_of_other_bundle_index() {
+        let position_bundle_mint = Pubkey::new_unique();
+        let (address, _) = find_bundled_position_address(&position_bundle_mint, 0);
+        let (_, other_bump) = find_bundled_position_address(&position_bundle_mint, 1);
+        assert_eq!(
+            verify_bundled_position_address(&address, &position_bundle_mint, 1, other_bump)
+                .unwrap_err(),
+            ErrorCode::InvalidBundledPositionBump.into()
+        );
+    }
+}
@@ -1,3 +1,7 @@
+pub mod bundled_position;
+pub mod oracle;
+pub mod pause;
+pub mod position_lock;
 pub mod shared;
 pub mod sparse_swap;
 pub mod swap_tick_sequence;
@@ -6,6 +10,10 @@ pub mod token;
 pub mod token_2022;
 pub mod v2;
 
+pub use bundled_position::*;
+pub use oracle::*;
+pub use pause::*;
+pub use position_lock::*;
 pub use shared::*;
 pub use sparse_swap::*;
 pub use swap_tick_sequence::*;
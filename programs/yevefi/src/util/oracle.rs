@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode,
+    manager::oracle_manager::write_observation,
+    state::{Oracle, Yevefi},
+};
+
+// Records the state of the yevefi into its oracle before a swap is applied.
+// The oracle account is optional: if it has not been initialized, nothing is recorded.
+// Once initialized, every swap must pass it as writable, so v1 clients which pass it as read-only
+// cannot move the price of a yevefi with an initialized oracle without an observation.
+pub fn update_oracle(oracle: &AccountInfo, yevefi: &Yevefi, timestamp: u64) -> Result<()> {
+    if oracle.owner != &crate::ID || oracle.data_is_empty() {
+        return Ok(());
+    }
+
+    if !oracle.is_writable {
+        return Err(ErrorCode::OracleNotWritable.into());
+    }
+
+    let mut data = oracle.try_borrow_mut_data()?;
+    // The oracle address is derived from the yevefi, which is verified by the caller
+    let (oracle, observations) = Oracle::unpack_mut(&mut data)?;
+
+    write_observation(
+        oracle,
+        observations,
+        yevefi.tick_current_index,
+        yevefi.liquidity,
+        timestamp,
+    )?;
+
+    Ok(())
+}
+
+// Records the state of the yevefi into its oracle before its liquidity is changed,
+// so that seconds_per_liquidity_cumulative only accumulates the liquidity held until the change.
+// Liquidity instructions take the oracle as a remaining account, which must be the oracle PDA
+// of the yevefi whether or not it is initialized, so that it cannot be left out.
+pub fn update_oracle_before_liquidity_change(
+    oracle: Option<&AccountInfo>,
+    yevefi_key: &Pubkey,
+    yevefi: &Yevefi,
+    timestamp: u64,
+) -> Result<()> {
+    let oracle = oracle.ok_or(ErrorCode::InvalidOracle)?;
+    if oracle.key() != oracle_address(yevefi_key) {
+        return Err(ErrorCode::InvalidOracle.into());
+    }
+
+    update_oracle(oracle, yevefi, timestamp)
+}
+
+fn oracle_address(yevefi: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"oracle", yevefi.as_ref()], &crate::ID).0
+}
+
+#[cfg(test)]
+mod update_oracle_before_liquidity_change_tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    fn oracle_data(yevefi: Pubkey) -> Vec<u8> {
+        let mut data = vec![0u8; Oracle::space(1)];
+        data[0..8].copy_from_slice(&Oracle::discriminator());
+        let (oracle, _) = Oracle::unpack_mut(&mut data).unwrap();
+        oracle.initialize(yevefi);
+        data
+    }
+
+    fn update(
+        yevefi_key: &Pubkey,
+        oracle: Option<(Pubkey, Pubkey, bool)>,
+        data: &mut [u8],
+    ) -> Result<()> {
+        let yevefi = Yevefi {
+            tick_current_index: 10,
+            liquidity: 1_000,
+            ..Default::default()
+        };
+        let (key, owner, is_writable) = oracle.unwrap_or_default();
+        let mut lamports = 0u64;
+        let account = AccountInfo::new(
+            &key,
+            false,
+            is_writable,
+            &mut lamports,
+            data,
+            &owner,
+            false,
+            0,
+        );
+        update_oracle_before_liquidity_change(oracle.map(|_| &account), yevefi_key, &yevefi, 100)
+    }
+
+    #[test]
+    fn test_uninitialized_oracle() {
+        let yevefi_key = Pubkey::new_unique();
+        let address = oracle_address(&yevefi_key);
+        let mut data = vec![];
+        assert!(update(
+            &yevefi_key,
+            Some((address, Pubkey::default(), false)),
+            &mut data
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_missing_oracle() {
+        assert_eq!(
+            update(&Pubkey::new_unique(), None, &mut []).unwrap_err(),
+            ErrorCode::InvalidOracle.into()
+        );
+    }
+
+    #[test]
+    fn test_oracle_of_other_yevefi() {
+        let yevefi_key = Pubkey::new_unique();
+        let other_yevefi_key = Pubkey::new_unique();
+        let address = oracle_address(&other_yevefi_key);
+        let mut data = oracle_data(other_yevefi_key);
+        assert_eq!(
+            update(&yevefi_key, Some((address, crate::ID, true)), &mut data).unwrap_err(),
+            ErrorCode::InvalidOracle.into()
+        );
+    }
+
+    #[test]
+    fn test_read_only_initialized_oracle() {
+        let yevefi_key = Pubkey::new_unique();
+        let address = oracle_address(&yevefi_key);
+        let mut data = oracle_data(yevefi_key);
+        assert_eq!(
+            update(&yevefi_key, Some((address, crate::ID, false)), &mut data).unwrap_err(),
+            ErrorCode::OracleNotWritable.into()
+        );
+    }
+
+    #[test]
+    fn test_initialized_oracle_records_observation() {
+        let yevefi_key = Pubkey::new_unique();
+        let address = oracle_address(&yevefi_key);
+        let mut data = oracle_data(yevefi_key);
+        update(&yevefi_key, Some((address, crate::ID, true)), &mut data).unwrap();
+
+        let (_, observations) = Oracle::unpack(&data).unwrap();
+        let timestamp = observations[0].timestamp;
+        assert_eq!(timestamp, 100);
+    }
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::ErrorCode,
+    state::{Yevefi, YevefisConfigExtension},
+};
+
+// Returns an error if the yevefi, or all yevefis of its config, are paused.
+// The config extension of the yevefi's config must be passed whether or not it is initialized,
+// so that the global pause cannot be bypassed by leaving it out.
+pub fn require_not_paused(yevefi: &Yevefi, config_extension: Option<&AccountInfo>) -> Result<()> {
+    yevefi.require_not_paused()?;
+
+    let config_extension = config_extension.ok_or(ErrorCode::InvalidConfigExtension)?;
+    if config_extension.owner != &crate::ID || config_extension.data_is_empty() {
+        // Only an uninitialized account at the config extension address shows that there is none
+        if config_extension.key() != config_extension_address(&yevefi.yevefis_config) {
+            return Err(ErrorCode::InvalidConfigExtension.into());
+        }
+        return Ok(());
+    }
+
+    // Each config has a single config extension, created by initialize_config_extension
+    let config_extension =
+        YevefisConfigExtension::try_deserialize(&mut config_extension.try_borrow_data()?.as_ref())?;
+    if config_extension.yevefis_config != yevefi.yevefis_config {
+        return Err(ErrorCode::InvalidConfigExtension.into());
+    }
+    if config_extension.paused {
+        return Err(ErrorCode::YevefiPaused.into());
+    }
+
+    Ok(())
+}
+
+fn config_extension_address(yevefis_config: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"config_extension", yevefis_config.as_ref()], &crate::ID).0
+}
+
+#[cfg(test)]
+mod require_not_paused_tests {
+    use super::*;
+
+    fn config_extension_data(yevefis_config: Pubkey, paused: bool) -> Vec<u8> {
+        let config_extension = YevefisConfigExtension {
+            yevefis_config,
+            config_extension_authority: Pubkey::default(),
+            token_badge_authority: Pubkey::default(),
+            pause_authority: Pubkey::default(),
+            paused,
+            pending_config_extension_authority: Pubkey::default(),
+            pending_token_badge_authority: Pubkey::default(),
+        };
+        let mut data = Vec::<u8>::new();
+        config_extension.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    fn check(yevefi: &Yevefi, config_extension: Option<(Pubkey, Pubkey, Vec<u8>)>) -> Result<()> {
+        let (key, owner, mut data) = config_extension.clone().unwrap_or_default();
+        let mut lamports = 0u64;
+        let account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        require_not_paused(yevefi, config_extension.map(|_| &account))
+    }
+
+    fn yevefi() -> Yevefi {
+        Yevefi {
+            yevefis_config: Pubkey::new_unique(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_uninitialized_config_extension() {
+        let yevefi = yevefi();
+        let address = config_extension_address(&yevefi.yevefis_config);
+        assert!(check(&yevefi, Some((address, Pubkey::default(), vec![]))).is_ok());
+    }
+
+    #[test]
+    fn test_missing_config_extension() {
+        assert_eq!(
+            check(&yevefi(), None).unwrap_err(),
+            ErrorCode::InvalidConfigExtension.into()
+        );
+    }
+
+    #[test]
+    fn test_uninitialized_account_at_other_address() {
+        let config_extension = Some((Pubkey::new_unique(), Pubkey::default(), vec![]));
+        assert_eq!(
+            check(&yevefi(), config_extension).unwrap_err(),
+            ErrorCode::InvalidConfigExtension.into()
+        );
+    }
+
+    #[test]
+    fn test_global_pause() {
+        let yevefi = yevefi();
+        let address = config_extension_address(&yevefi.yevefis_config);
+        let paused = config_extension_data(yevefi.yevefis_config, true);
+        assert_eq!(
+            check(&yevefi, Some((address, crate::ID, paused))).unwrap_err(),
+            ErrorCode::YevefiPaused.into()
+        );
+        let unpaused = config_extension_data(yevefi.yevefis_config, false);
+        assert!(check(&yevefi, Some((address, crate::ID, unpaused))).is_ok());
+    }
+
+    #[test]
+    fn test_config_extension_of_other_config() {
+        let yevefi = yevefi();
+        let other_config = Pubkey::new_unique();
+        let address = config_extension_address(&other_config);
+        let unpaused = config_extension_data(other_config, false);
+        assert_eq!(
+            check(&yevefi, Some((address, crate::ID, unpaused))).unwrap_err(),
+            ErrorCode::InvalidConfigExtension.into()
+        );
+    }
+}
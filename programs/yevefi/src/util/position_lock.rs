@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::ErrorCode, state::PositionLock};
+
+// Returns an error if the position is locked at the given timestamp.
+// The position lock PDA of the position must be passed whether or not it is initialized,
+// as the lock is only stored in that account.
+pub fn require_position_unlocked(
+    position: &AccountInfo,
+    position_lock: Option<&AccountInfo>,
+    timestamp: u64,
+) -> Result<()> {
+    if let Some(position_lock) = load_position_lock(position, position_lock)? {
+        if position_lock.is_locked(timestamp) {
+            return Err(ErrorCode::PositionLocked.into());
+        }
+    }
+
+    Ok(())
+}
+
+// Closes the position lock of a position being closed, returning its rent to the receiver.
+// The caller must have checked that the position is unlocked.
+pub fn close_position_lock_if_initialized<'info>(
+    position: &AccountInfo<'info>,
+    position_lock: Option<&AccountInfo<'info>>,
+    receiver: &AccountInfo<'info>,
+) -> Result<()> {
+    if load_position_lock(position, position_lock)?.is_none() {
+        return Ok(());
+    }
+    // load_position_lock only returns a lock if the account was passed
+    let position_lock = position_lock.unwrap();
+
+    let receiver_lamports = receiver
+        .lamports()
+        .checked_add(position_lock.lamports())
+        .ok_or(ErrorCode::AmountCalcOverflow)?;
+    **receiver.try_borrow_mut_lamports()? = receiver_lamports;
+    **position_lock.try_borrow_mut_lamports()? = 0;
+
+    position_lock.assign(&System::id());
+    position_lock.realloc(0, false)?;
+
+    Ok(())
+}
+
+// Returns the lock of the position, or None if its position lock PDA is not initialized.
+// Only lock_position initializes accounts at the position lock address.
+fn load_position_lock(
+    position: &AccountInfo,
+    position_lock: Option<&AccountInfo>,
+) -> Result<Option<PositionLock>> {
+    let position_lock = position_lock.ok_or(ErrorCode::InvalidPositionLock)?;
+    if position_lock.key() != position_lock_address(position.key) {
+        return Err(ErrorCode::InvalidPositionLock.into());
+    }
+    if position_lock.owner != &crate::ID || position_lock.data_is_empty() {
+        return Ok(None);
+    }
+
+    let position_lock =
+        PositionLock::try_deserialize(&mut position_lock.try_borrow_data()?.as_ref())?;
+    Ok(Some(position_lock))
+}
+
+fn position_lock_address(position: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"position_lock", position.as_ref()], &crate::ID).0
+}
+
+#[cfg(test)]
+mod require_position_unlocked_tests {
+    use super::*;
+
+    fn position_lock_data(position: Pubkey, unlock_timestamp: u64) -> Vec<u8> {
+        let position_lock = PositionLock {
+            position,
+            unlock_timestamp,
+        };
+        let mut data = Vec::<u8>::new();
+        position_lock.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    fn check(
+        position_key: Pubkey,
+        position_lock: Option<(Pubkey, Pubkey, Vec<u8>)>,
+        timestamp: u64,
+    ) -> Result<()> {
+        let owner = crate::ID;
+        let mut position_lamports = 0u64;
+        let mut position_data = vec![];
+        let position = AccountInfo::new(
+            &position_key,
+            false,
+            false,
+            &mut position_lamports,
+            &mut position_data,
+            &owner,
+            false,
+            0,
+        );
+
+        let (position_lock_key, position_lock_owner, mut position_lock_data) =
+            position_lock.clone().unwrap_or_default();
+        let mut position_lock_lamports = 0u64;
+        let position_lock_account = AccountInfo::new(
+            &position_lock_key,
+            false,
+            false,
+            &mut position_lock_lamports,
+            &mut position_lock_data,
+            &position_lock_owner,
+            false,
+            0,
+        );
+        let position_lock_account = position_lock.map(|_| &position_lock_account);
+
+        require_position_unlocked(&position, position_lock_account, timestamp)
+    }
+
+    #[test]
+    fn test_uninitialized_position_lock() {
+        let position = Pubkey::new_unique();
+        let lock = Some((position_lock_address(&position), Pubkey::default(), vec![]));
+        assert!(check(position, lock, 1_000).is_ok());
+    }
+
+    #[test]
+    fn test_missing_position_lock() {
+        let result = check(Pubkey::new_unique(), None, 1_000);
+        assert_eq!(result.unwrap_err(), ErrorCode::InvalidPositionLock.into());
+    }
+
+    #[test]
+    fn test_position_lock() {
+        let position = Pubkey::new_unique();
+        let lock = Some((
+            position_lock_address(&position),
+            crate::ID,
+            position_lock_data(position, 2_000),
+        ));
+        let result = check(position, lock.clone(), 1_000);
+        assert_eq!(result.unwrap_err(), ErrorCode::PositionLocked.into());
+        assert!(check(position, lock, 2_000).is_ok());
+    }
+
+    #[test]
+    fn test_position_lock_of_other_position() {
+        let position = Pubkey::new_unique();
+        let other_position = Pubkey::new_unique();
+        let lock = Some((
+            position_lock_address(&other_position),
+            crate::ID,
+            position_lock_data(other_position, 2_000),
+        ));
+        let result = check(position, lock, 1_000);
+        assert_eq!(result.unwrap_err(), ErrorCode::InvalidPositionLock.into());
+    }
+
+    #[test]
+    fn test_position_lock_at_other_address() {
+        let position = Pubkey::new_unique();
+        let lock = Some((Pubkey::new_unique(), Pubkey::default(), vec![]));
+        let result = check(position, lock, 1_000);
+        assert_eq!(result.unwrap_err(), ErrorCode::InvalidPositionLock.into());
+    }
+}
@@ -8,6 +8,7 @@ use solana_program::program_option::COption;
 use std::convert::TryFrom;
 
 use crate::errors::ErrorCode;
+use crate::state::PositionDelegate;
 
 pub fn verify_position_bundle_authority(
     position_bundle_token_account: &TokenAccount,
@@ -41,20 +42,82 @@ pub fn verify_position_authority_interface(
     // position_token_account is owned by either TokenProgram or Token2022Program
     position_token_account: &InterfaceAccount<'_, TokenAccountInterface>,
     position_authority: &Signer<'_>,
-) -> Result<()> {
+    // PositionDelegate passed to an instruction that can be authorized by one,
+    // with the position it must delegate and the permissions it must grant
+    position_delegate: Option<(&AccountInfo<'_>, Pubkey, u8)>,
+) -> Result<bool> {
     // Check token authority using validate_owner method...
-    match position_token_account.delegate {
-        COption::Some(ref delegate) if position_authority.key == delegate => {
+    if let COption::Some(ref delegate) = position_token_account.delegate {
+        if position_authority.key == delegate {
             validate_owner(delegate, &position_authority.to_account_info())?;
             if position_token_account.delegated_amount != 1 {
                 return Err(ErrorCode::InvalidPositionTokenAmount.into());
             }
+            return Ok(false);
         }
-        _ => validate_owner(
-            &position_token_account.owner,
-            &position_authority.to_account_info(),
-        )?,
-    };
+    }
+
+    match position_delegate {
+        Some((position_delegate, position, permissions))
+            if position_token_account.owner != *position_authority.key =>
+        {
+            verify_position_delegate(
+                position_token_account,
+                position_authority,
+                position_delegate,
+                position,
+                permissions,
+            )?;
+            Ok(true)
+        }
+        _ => {
+            validate_owner(
+                &position_token_account.owner,
+                &position_authority.to_account_info(),
+            )?;
+            Ok(false)
+        }
+    }
+}
+
+// The position delegate is passed as a remaining account, so it is verified by its data
+// instead of its address. Only initialize_position_delegate creates PositionDelegate accounts.
+fn verify_position_delegate(
+    position_token_account: &InterfaceAccount<'_, TokenAccountInterface>,
+    position_authority: &Signer<'_>,
+    position_delegate: &AccountInfo<'_>,
+    position: Pubkey,
+    permissions: u8,
+) -> Result<()> {
+    if position_delegate.owner != &crate::ID || position_delegate.data_is_empty() {
+        return Err(ErrorCode::MissingOrInvalidDelegate.into());
+    }
+
+    let position_delegate =
+        PositionDelegate::try_deserialize(&mut position_delegate.try_borrow_data()?.as_ref())?;
+    // Permissions granted by a previous owner of the position token are void
+    if position_delegate.position != position
+        || position_delegate.delegate != *position_authority.key
+        || position_delegate.position_owner != position_token_account.owner
+    {
+        return Err(ErrorCode::MissingOrInvalidDelegate.into());
+    }
+    if !position_delegate.has_permissions(permissions) {
+        return Err(ErrorCode::PositionDelegatePermissionDenied.into());
+    }
+
+    Ok(())
+}
+
+// Tokens withdrawn by a PositionDelegate can only be transferred to the position owner.
+pub fn require_position_owner_destination(
+    position_token_account: &InterfaceAccount<'_, TokenAccountInterface>,
+    token_owner_account: &InterfaceAccount<'_, TokenAccountInterface>,
+) -> Result<()> {
+    if token_owner_account.owner != position_token_account.owner {
+        return Err(ErrorCode::PositionDelegateInvalidDestination.into());
+    }
+
     Ok(())
 }
 
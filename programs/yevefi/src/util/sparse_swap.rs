@@ -12,9 +12,12 @@ use crate::{
 
 // In the case of an uninitialized TickArray, ZeroedTickArray is used to substitute TickArray behavior.
 // Since all Tick are not initialized, it can be substituted by returning Tick::default().
+// ReadOnly is used to simulate a swap without writing to the TickArray accounts. Tick updates are discarded,
+// which is safe because a tick is never crossed twice in a single swap.
 pub(crate) enum ProxiedTickArray<'a> {
     Initialized(RefMut<'a, TickArray>),
     Uninitialized(ZeroedTickArray),
+    ReadOnly(Ref<'a, TickArray>),
 }
 
 impl<'a> ProxiedTickArray<'a> {
@@ -26,6 +29,10 @@ impl<'a> ProxiedTickArray<'a> {
         ProxiedTickArray::Uninitialized(ZeroedTickArray::new(start_tick_index))
     }
 
+    pub fn new_read_only(tick_array_ref: Ref<'a, TickArray>) -> Self {
+        ProxiedTickArray::ReadOnly(tick_array_ref)
+    }
+
     pub fn start_tick_index(&self) -> i32 {
         self.as_ref().start_tick_index()
     }
@@ -50,6 +57,9 @@ impl<'a> ProxiedTickArray<'a> {
         tick_spacing: u16,
         update: &TickUpdate,
     ) -> Result<()> {
+        if matches!(self, ProxiedTickArray::ReadOnly(_)) {
+            return Ok(());
+        }
         self.as_mut().update_tick(tick_index, tick_spacing, update)
     }
 
@@ -71,6 +81,7 @@ impl<'a> AsRef<dyn TickArrayType + 'a> for ProxiedTickArray<'a> {
         match self {
             ProxiedTickArray::Initialized(ref array) => &**array,
             ProxiedTickArray::Uninitialized(ref array) => array,
+            ProxiedTickArray::ReadOnly(ref array) => &**array,
         }
     }
 }
@@ -80,6 +91,7 @@ impl<'a> AsMut<dyn TickArrayType + 'a> for ProxiedTickArray<'a> {
         match self {
             ProxiedTickArray::Initialized(ref mut array) => &mut **array,
             ProxiedTickArray::Uninitialized(ref mut array) => array,
+            ProxiedTickArray::ReadOnly(_) => panic!("ReadOnly tick array must not be updated"),
         }
     }
 }
@@ -100,6 +112,7 @@ pub struct SparseSwapTickSequenceBuilder<'info> {
     // AccountInfo ownership must be kept while using RefMut.
     // This is why try_from and build are separated and SparseSwapTickSequenceBuilder struct is used.
     tick_array_accounts: Vec<TickArrayAccount<'info>>,
+    read_only: bool,
 }
 
 impl<'info> SparseSwapTickSequenceBuilder<'info> {
@@ -129,6 +142,44 @@ impl<'info> SparseSwapTickSequenceBuilder<'info> {
         a_to_b: bool,
         static_tick_array_account_infos: Vec<AccountInfo<'info>>,
         supplemental_tick_array_account_infos: Option<Vec<AccountInfo<'info>>>,
+    ) -> Result<Self> {
+        Self::try_from_impl(
+            yevefi,
+            a_to_b,
+            static_tick_array_account_infos,
+            supplemental_tick_array_account_infos,
+            false,
+        )
+    }
+
+    /// Create a new SparseSwapTickSequenceBuilder for a simulated swap.
+    ///
+    /// Same as `try_from`, but TickArray accounts do not need to be writable, and the
+    /// SwapTickSequence built from it never writes to them.
+    ///
+    /// # Errors
+    /// Same as `try_from`, except `AccountNotMutable`.
+    pub fn try_from_read_only(
+        yevefi: &Account<'info, Yevefi>,
+        a_to_b: bool,
+        static_tick_array_account_infos: Vec<AccountInfo<'info>>,
+        supplemental_tick_array_account_infos: Option<Vec<AccountInfo<'info>>>,
+    ) -> Result<Self> {
+        Self::try_from_impl(
+            yevefi,
+            a_to_b,
+            static_tick_array_account_infos,
+            supplemental_tick_array_account_infos,
+            true,
+        )
+    }
+
+    fn try_from_impl(
+        yevefi: &Account<'info, Yevefi>,
+        a_to_b: bool,
+        static_tick_array_account_infos: Vec<AccountInfo<'info>>,
+        supplemental_tick_array_account_infos: Option<Vec<AccountInfo<'info>>>,
+        read_only: bool,
     ) -> Result<Self> {
         let mut tick_array_account_infos = static_tick_array_account_infos;
         if let Some(supplemental_tick_array_account_infos) = supplemental_tick_array_account_infos {
@@ -142,7 +193,7 @@ impl<'info> SparseSwapTickSequenceBuilder<'info> {
         let mut initialized = vec![];
         let mut uninitialized = vec![];
         for account_info in tick_array_account_infos.into_iter() {
-            let state = peek_tick_array(account_info)?;
+            let state = peek_tick_array(account_info, !read_only)?;
 
             match &state {
                 TickArrayAccount::Initialized {
@@ -158,7 +209,7 @@ impl<'info> SparseSwapTickSequenceBuilder<'info> {
                     // TickArray accounts in initialized have been verified as:
                     //   - Owned by this program
                     //   - Initialized as TickArray account
-                    //   - Writable account
+                    //   - Writable account (unless read_only)
                     //   - TickArray account for this yevefi
                     // So we can safely use these accounts.
                     initialized.push((*start_tick_index, state));
@@ -170,7 +221,7 @@ impl<'info> SparseSwapTickSequenceBuilder<'info> {
                     // TickArray accounts in uninitialized have been verified as:
                     //   - Owned by System program
                     //   - Data size is zero
-                    //   - Writable account
+                    //   - Writable account (unless read_only)
                     // But we are not sure if these accounts are valid TickArray PDA for this yevefi,
                     // so we need to check it later.
                     uninitialized.push((*account_address, state));
@@ -217,6 +268,7 @@ impl<'info> SparseSwapTickSequenceBuilder<'info> {
 
         Ok(Self {
             tick_array_accounts,
+            read_only,
         })
     }
 
@@ -224,6 +276,13 @@ impl<'info> SparseSwapTickSequenceBuilder<'info> {
         let mut proxied_tick_arrays = VecDeque::with_capacity(3);
         for tick_array_account in self.tick_array_accounts.iter() {
             match tick_array_account {
+                TickArrayAccount::Initialized { account_info, .. } if self.read_only => {
+                    let data = account_info.try_borrow_data()?;
+                    let tick_array_ref = Ref::map(data, |data| {
+                        bytemuck::from_bytes(&data[8..std::mem::size_of::<TickArray>() + 8])
+                    });
+                    proxied_tick_arrays.push_back(ProxiedTickArray::new_read_only(tick_array_ref));
+                }
                 TickArrayAccount::Initialized { account_info, .. } => {
                     use std::ops::DerefMut;
 
@@ -254,7 +313,10 @@ impl<'info> SparseSwapTickSequenceBuilder<'info> {
     }
 }
 
-fn peek_tick_array(account_info: AccountInfo<'_>) -> Result<TickArrayAccount<'_>> {
+fn peek_tick_array(
+    account_info: AccountInfo<'_>,
+    require_writable: bool,
+) -> Result<TickArrayAccount<'_>> {
     use anchor_lang::Discriminator;
 
     // following process is ported from anchor-lang's AccountLoader::try_from and AccountLoader::load_mut
@@ -262,7 +324,7 @@ fn peek_tick_array(account_info: AccountInfo<'_>) -> Result<TickArrayAccount<'_>
     // So we need to handle uninitialized account manually.
 
     // account must be writable
-    if !account_info.is_writable {
+    if require_writable && !account_info.is_writable {
         return Err(anchor_lang::error::ErrorCode::AccountNotMutable.into());
     }
 
@@ -669,7 +731,7 @@ mod sparse_swap_tick_sequence_tests {
             );
             let account_info = account_info_mock.to_account_info(false); // not writable
 
-            let result = peek_tick_array(account_info);
+            let result = peek_tick_array(account_info, true);
             assert!(result.is_err());
             assert!(result
                 .err()
@@ -684,7 +746,7 @@ mod sparse_swap_tick_sequence_tests {
             let mut account_info_mock = AccountInfoMock::new(account_address, vec![], System::id());
             let account_info = account_info_mock.to_account_info(true);
 
-            let result = peek_tick_array(account_info);
+            let result = peek_tick_array(account_info, true);
             assert!(result.is_ok());
             match result.unwrap() {
                 TickArrayAccount::Uninitialized {
@@ -704,7 +766,7 @@ mod sparse_swap_tick_sequence_tests {
                 AccountInfoMock::new(Pubkey::new_unique(), vec![0u8; 1], System::id());
             let account_info = account_info_mock.to_account_info(true);
 
-            let result = peek_tick_array(account_info);
+            let result = peek_tick_array(account_info, true);
             assert!(result.is_err());
             // non empty account should be owned by this program
             assert!(result
@@ -720,7 +782,7 @@ mod sparse_swap_tick_sequence_tests {
                 AccountInfoMock::new(Pubkey::new_unique(), vec![], TickArray::owner());
             let account_info = account_info_mock.to_account_info(true);
 
-            let result = peek_tick_array(account_info);
+            let result = peek_tick_array(account_info, true);
             assert!(result.is_err());
             assert!(result
                 .err()
@@ -735,7 +797,7 @@ mod sparse_swap_tick_sequence_tests {
                 AccountInfoMock::new_yevefi(Pubkey::new_unique(), 64, 0, None);
             let account_info = account_info_mock.to_account_info(true);
 
-            let result = peek_tick_array(account_info);
+            let result = peek_tick_array(account_info, true);
             assert!(result.is_err());
             assert!(result
                 .err()
@@ -752,7 +814,7 @@ mod sparse_swap_tick_sequence_tests {
                 AccountInfoMock::new_tick_array(tick_array_address, yevefi_address, 439296, None);
             let account_info = account_info_mock.to_account_info(true);
 
-            let result = peek_tick_array(account_info);
+            let result = peek_tick_array(account_info, true);
             assert!(result.is_ok());
             match result.unwrap() {
                 TickArrayAccount::Initialized {
@@ -1256,6 +1318,55 @@ mod sparse_swap_tick_sequence_tests {
             run_test(2);
         }
 
+        #[test]
+        fn read_only_accepts_non_writable_accounts_and_discards_updates() {
+            let yevefi_address = Pubkey::new_unique();
+            let mut account_info_mock = AccountInfoMock::new_yevefi(yevefi_address, 64, 0, None);
+            let account_info = account_info_mock.to_account_info(false);
+            let yevefi = Account::<Yevefi>::try_from(&account_info).unwrap();
+
+            // initialized
+            let ta0_address = derive_tick_array_pda(&yevefi, 0);
+            let mut ta0_mock =
+                AccountInfoMock::new_tick_array(ta0_address, yevefi_address, 0, None);
+
+            // uninitialized
+            let ta1_address = derive_tick_array_pda(&yevefi, 5632);
+            let mut ta1_mock = AccountInfoMock::new(ta1_address, vec![], System::id());
+
+            let ta0 = ta0_mock.to_account_info(false);
+            let ta1 = ta1_mock.to_account_info(false);
+            let builder = SparseSwapTickSequenceBuilder::try_from_read_only(
+                &yevefi,
+                false,
+                vec![ta0, ta1],
+                None,
+            )
+            .unwrap();
+            assert_eq!(builder.tick_array_accounts.len(), 2);
+
+            let mut swap_tick_sequence = builder.build().unwrap();
+            swap_tick_sequence
+                .update_tick(
+                    0,
+                    64,
+                    64,
+                    &TickUpdate {
+                        initialized: true,
+                        liquidity_net: 1,
+                        liquidity_gross: 1,
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+
+            let tick = swap_tick_sequence.get_tick(0, 64, 64).unwrap();
+            let initialized = tick.initialized;
+            assert!(!initialized);
+            let liquidity_net = tick.liquidity_net;
+            assert_eq!(liquidity_net, 0);
+        }
+
         #[test]
         fn fail_if_uninitialized_account_is_not_empty() {
             let yevefi_address = Pubkey::new_unique();
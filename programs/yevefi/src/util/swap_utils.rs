@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount};
 
-use crate::{manager::swap_manager::PostSwapUpdate, state::Yevefi};
+use crate::{events::Traded, manager::swap_manager::PostSwapUpdate, state::Yevefi};
 
 use super::{transfer_from_owner_to_vault, transfer_from_vault_to_owner};
 
@@ -18,6 +18,9 @@ pub fn update_and_swap_yevefi<'info>(
     is_token_fee_in_a: bool,
     reward_last_updated_timestamp: u64,
 ) -> Result<()> {
+    let pre_sqrt_price = yevefi.sqrt_price;
+    let pre_tick_current_index = yevefi.tick_current_index;
+
     yevefi.update_after_swap(
         swap_update.next_liquidity,
         swap_update.next_tick_index,
@@ -25,10 +28,33 @@ pub fn update_and_swap_yevefi<'info>(
         swap_update.next_fee_growth_global,
         swap_update.next_reward_infos,
         swap_update.next_protocol_fee,
+        swap_update.next_adaptive_fee_info,
         is_token_fee_in_a,
         reward_last_updated_timestamp,
     );
 
+    let (input_amount, output_amount) = if is_token_fee_in_a {
+        (swap_update.amount_a, swap_update.amount_b)
+    } else {
+        (swap_update.amount_b, swap_update.amount_a)
+    };
+
+    emit!(Traded {
+        yevefi: yevefi.key(),
+        a_to_b: is_token_fee_in_a,
+        pre_sqrt_price,
+        post_sqrt_price: yevefi.sqrt_price,
+        pre_tick_current_index,
+        post_tick_current_index: yevefi.tick_current_index,
+        input_amount,
+        output_amount,
+        // Token program doesn't have transfer fee
+        input_transfer_fee: 0,
+        output_transfer_fee: 0,
+        lp_fee: swap_update.next_lp_fee,
+        protocol_fee: swap_update.next_protocol_fee,
+    });
+
     perform_swap(
         yevefi,
         token_authority,
@@ -64,6 +64,7 @@ impl LiquidityTestFixture {
             .tick_current_index(curr_index)
             .liquidity(info.yevefi_liquidity)
             .reward_infos(info.reward_infos)
+            .fully_funded_rewards()
             .fee_growth_global_a(info.fee_growth_global_a)
             .fee_growth_global_b(info.fee_growth_global_b)
             .build();
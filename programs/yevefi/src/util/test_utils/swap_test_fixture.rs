@@ -139,6 +139,7 @@ impl SwapTestFixture {
             .tick_current_index(info.curr_tick_index)
             .reward_last_updated_timestamp(info.reward_last_updated_timestamp)
             .reward_infos(info.reward_infos)
+            .fully_funded_rewards()
             .fee_growth_global_a(info.fee_growth_global_a)
             .fee_growth_global_b(info.fee_growth_global_b)
             .fee_rate(info.fee_rate)
@@ -37,6 +37,8 @@ pub fn transfer_from_vault_to_owner<'info>(
     token_program: &Program<'info, Token>,
     amount: u64,
 ) -> Result<()> {
+    yevefi.require_unlocked()?;
+
     token::transfer(
         CpiContext::new_with_signer(
             token_program.to_account_info(),
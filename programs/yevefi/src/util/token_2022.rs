@@ -10,10 +10,15 @@ use anchor_spl::token_2022::Token2022;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 use solana_program::program::{invoke, invoke_signed};
 use solana_program::system_instruction::{create_account, transfer};
+use spl_token_metadata_interface::state::{Field, TokenMetadata};
 
 use crate::constants::{
+    TK_2022_METADATA_FIELD_IN_RANGE, TK_2022_METADATA_FIELD_LIQUIDITY, TK_2022_METADATA_FIELD_POOL,
+    TK_2022_METADATA_FIELD_TICK_LOWER_INDEX, TK_2022_METADATA_FIELD_TICK_UPPER_INDEX,
     TK_2022_METADATA_NAME_PREFIX, TK_2022_METADATA_SYMBOL, TK_2022_METADATA_URI_BASE,
+    WPB_METADATA_NAME_PREFIX, WPB_METADATA_SYMBOL, WPB_METADATA_URI,
 };
+use crate::errors::ErrorCode;
 use crate::state::*;
 
 pub fn initialize_position_mint_2022<'info>(
@@ -117,9 +122,9 @@ pub fn initialize_token_metadata_extension<'info>(
     name: String,
     symbol: String,
     uri: String,
+    additional_metadata: Vec<(String, String)>,
     position_mint: &Signer<'info>,
     position: &Account<'info, Position>,
-    metadata_update_authority: &UncheckedAccount<'info>,
     funder: &Signer<'info>,
     system_program: &Program<'info, System>,
     token_2022_program: &Program<'info, Token2022>,
@@ -127,19 +132,21 @@ pub fn initialize_token_metadata_extension<'info>(
 ) -> Result<()> {
     let mint_authority = position;
 
-    let metadata = spl_token_metadata_interface::state::TokenMetadata {
+    let metadata = TokenMetadata {
         name,
         symbol,
         uri,
+        additional_metadata,
         ..Default::default()
     };
 
     // we need to add rent for TokenMetadata extension to reallocate space
+    // additional fields are included, so that no further rent is needed to write them
     let token_mint_data = position_mint.try_borrow_data()?;
     let token_mint_unpacked =
         StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&token_mint_data)?;
-    let new_account_len = token_mint_unpacked
-        .try_get_new_account_len::<spl_token_metadata_interface::state::TokenMetadata>(&metadata)?;
+    let new_account_len =
+        token_mint_unpacked.try_get_new_account_len::<TokenMetadata>(&metadata)?;
     let new_rent_exempt_minimum = Rent::get()?.minimum_balance(new_account_len);
     let additional_rent = new_rent_exempt_minimum.saturating_sub(position_mint.lamports());
     drop(token_mint_data); // CPI call will borrow the account data
@@ -155,12 +162,12 @@ pub fn initialize_token_metadata_extension<'info>(
     )?;
 
     // initialize TokenMetadata extension
-    // update authority: TK_NFT_UPDATE_AUTH
+    // update authority: Position account (PDA), so that the additional fields can be refreshed
     invoke_signed(
         &spl_token_metadata_interface::instruction::initialize(
             token_2022_program.key,
             position_mint.key,
-            metadata_update_authority.key,
+            &mint_authority.key(),
             position_mint.key,
             &mint_authority.key(),
             metadata.name,
@@ -170,7 +177,141 @@ pub fn initialize_token_metadata_extension<'info>(
         &[
             position_mint.to_account_info(),
             mint_authority.to_account_info(),
-            metadata_update_authority.to_account_info(),
+            token_2022_program.to_account_info(),
+        ],
+        &[position_seeds],
+    )?;
+
+    for (key, value) in metadata.additional_metadata {
+        update_token_metadata_field(
+            key,
+            value,
+            &position_mint.to_account_info(),
+            mint_authority,
+            token_2022_program,
+            position_seeds,
+        )?;
+    }
+
+    Ok(())
+}
+
+// Updates the additional fields of the TokenMetadata extension of a position mint.
+// Only the fields whose value changed are written, and the funder pays the rent for reallocation.
+pub fn update_token_metadata_fields<'info>(
+    fields: Vec<(String, String)>,
+    position_mint: &InterfaceAccount<'info, Mint>,
+    position: &Account<'info, Position>,
+    funder: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    token_2022_program: &Program<'info, Token2022>,
+    position_seeds: &[&[u8]],
+) -> Result<()> {
+    let update_authority = position;
+    let position_mint_info = position_mint.to_account_info();
+
+    let token_mint_data = position_mint_info.try_borrow_data()?;
+    let token_mint_unpacked =
+        StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&token_mint_data)?;
+    let mut metadata = token_mint_unpacked.get_variable_len_extension::<TokenMetadata>()?;
+
+    // Positions opened before the Position account became the update authority cannot be refreshed
+    if Option::<Pubkey>::from(metadata.update_authority) != Some(update_authority.key()) {
+        return Err(ErrorCode::InvalidPositionMetadataUpdateAuthority.into());
+    }
+
+    let updated_fields: Vec<(String, String)> = fields
+        .into_iter()
+        .filter(|(key, value)| {
+            !metadata
+                .additional_metadata
+                .iter()
+                .any(|(current_key, current_value)| current_key == key && current_value == value)
+        })
+        .collect();
+    if updated_fields.is_empty() {
+        return Ok(());
+    }
+
+    for (key, value) in updated_fields.iter() {
+        metadata.update(Field::Key(key.clone()), value.clone());
+    }
+
+    // we need to add rent if the updated fields are longer
+    let new_account_len =
+        token_mint_unpacked.try_get_new_account_len::<TokenMetadata>(&metadata)?;
+    let new_rent_exempt_minimum = Rent::get()?.minimum_balance(new_account_len);
+    let additional_rent = new_rent_exempt_minimum.saturating_sub(position_mint_info.lamports());
+    drop(token_mint_data); // CPI call will borrow the account data
+
+    if additional_rent > 0 {
+        invoke(
+            &transfer(funder.key, position_mint_info.key, additional_rent),
+            &[
+                funder.to_account_info(),
+                position_mint_info.clone(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    for (key, value) in updated_fields {
+        update_token_metadata_field(
+            key,
+            value,
+            &position_mint_info,
+            update_authority,
+            token_2022_program,
+            position_seeds,
+        )?;
+    }
+
+    Ok(())
+}
+
+// Returns true if the position mint has TokenMetadata updated by the position,
+// as positions opened with token extensions and metadata have.
+pub fn has_position_token_metadata<'info>(
+    position_mint: &InterfaceAccount<'info, Mint>,
+    position: &Account<'info, Position>,
+) -> Result<bool> {
+    let position_mint_info = position_mint.to_account_info();
+    if position_mint_info.owner != &spl_token_2022::ID {
+        return Ok(false);
+    }
+
+    let token_mint_data = position_mint_info.try_borrow_data()?;
+    let token_mint_unpacked =
+        StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&token_mint_data)?;
+    match token_mint_unpacked.get_variable_len_extension::<TokenMetadata>() {
+        Ok(metadata) => {
+            Ok(Option::<Pubkey>::from(metadata.update_authority) == Some(position.key()))
+        }
+        Err(_) => Ok(false),
+    }
+}
+
+fn update_token_metadata_field<'info>(
+    key: String,
+    value: String,
+    position_mint: &AccountInfo<'info>,
+    position: &Account<'info, Position>,
+    token_2022_program: &Program<'info, Token2022>,
+    position_seeds: &[&[u8]],
+) -> Result<()> {
+    let update_authority = position;
+
+    invoke_signed(
+        &spl_token_metadata_interface::instruction::update_field(
+            token_2022_program.key,
+            position_mint.key,
+            &update_authority.key(),
+            Field::Key(key),
+            value,
+        ),
+        &[
+            position_mint.clone(),
+            update_authority.to_account_info(),
             token_2022_program.to_account_info(),
         ],
         &[position_seeds],
@@ -342,3 +483,332 @@ pub fn build_position_token_metadata<'info>(
 
     (name, TK_2022_METADATA_SYMBOL.to_string(), uri)
 }
+
+// Additional TokenMetadata fields, so that wallets can render the position without off-chain data
+pub fn build_position_token_metadata_fields<'info>(
+    position: &Account<'info, Position>,
+    yevefi: &Account<'info, Yevefi>,
+) -> Vec<(String, String)> {
+    let in_range = position.tick_lower_index <= yevefi.tick_current_index
+        && yevefi.tick_current_index < position.tick_upper_index;
+
+    vec![
+        (
+            TK_2022_METADATA_FIELD_POOL.to_string(),
+            yevefi.key().to_string(),
+        ),
+        (
+            TK_2022_METADATA_FIELD_TICK_LOWER_INDEX.to_string(),
+            position.tick_lower_index.to_string(),
+        ),
+        (
+            TK_2022_METADATA_FIELD_TICK_UPPER_INDEX.to_string(),
+            position.tick_upper_index.to_string(),
+        ),
+        (
+            TK_2022_METADATA_FIELD_LIQUIDITY.to_string(),
+            position.liquidity.to_string(),
+        ),
+        (
+            TK_2022_METADATA_FIELD_IN_RANGE.to_string(),
+            in_range.to_string(),
+        ),
+    ]
+}
+
+pub fn initialize_position_bundle_mint_2022<'info>(
+    position_bundle_mint: &Signer<'info>,
+    funder: &Signer<'info>,
+    position_bundle: &Account<'info, PositionBundle>,
+    system_program: &Program<'info, System>,
+    token_2022_program: &Program<'info, Token2022>,
+    use_token_metadata_extension: bool,
+) -> Result<()> {
+    let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(
+        if use_token_metadata_extension {
+            &[
+                ExtensionType::MintCloseAuthority,
+                ExtensionType::MetadataPointer,
+            ]
+        } else {
+            &[ExtensionType::MintCloseAuthority]
+        },
+    )?;
+
+    let lamports = Rent::get()?.minimum_balance(space);
+
+    let authority = position_bundle;
+
+    // create account
+    invoke(
+        &create_account(
+            funder.key,
+            position_bundle_mint.key,
+            lamports,
+            space as u64,
+            token_2022_program.key,
+        ),
+        &[
+            funder.to_account_info(),
+            position_bundle_mint.to_account_info(),
+            token_2022_program.to_account_info(),
+            system_program.to_account_info(),
+        ],
+    )?;
+
+    // initialize MintCloseAuthority extension
+    // authority: PositionBundle account (PDA)
+    invoke(
+        &spl_token_2022::instruction::initialize_mint_close_authority(
+            token_2022_program.key,
+            position_bundle_mint.key,
+            Some(&authority.key()),
+        )?,
+        &[
+            position_bundle_mint.to_account_info(),
+            authority.to_account_info(),
+            token_2022_program.to_account_info(),
+        ],
+    )?;
+
+    if use_token_metadata_extension {
+        // TokenMetadata extension requires MetadataPointer extension to be initialized
+
+        // initialize MetadataPointer extension
+        // authority: None
+        invoke(
+            &spl_token_2022::extension::metadata_pointer::instruction::initialize(
+                token_2022_program.key,
+                position_bundle_mint.key,
+                None,
+                Some(position_bundle_mint.key()),
+            )?,
+            &[
+                position_bundle_mint.to_account_info(),
+                authority.to_account_info(),
+                token_2022_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    // initialize Mint
+    // mint authority: PositionBundle account (PDA) (will be removed in the transaction)
+    // freeze authority: PositionBundle account (PDA) (reserved for future improvements)
+    invoke(
+        &spl_token_2022::instruction::initialize_mint2(
+            token_2022_program.key,
+            position_bundle_mint.key,
+            &authority.key(),
+            Some(&authority.key()),
+            0,
+        )?,
+        &[
+            position_bundle_mint.to_account_info(),
+            authority.to_account_info(),
+            token_2022_program.to_account_info(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_position_bundle_token_metadata_extension<'info>(
+    name: String,
+    symbol: String,
+    uri: String,
+    position_bundle_mint: &Signer<'info>,
+    position_bundle: &Account<'info, PositionBundle>,
+    funder: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    token_2022_program: &Program<'info, Token2022>,
+    position_bundle_seeds: &[&[u8]],
+) -> Result<()> {
+    let mint_authority = position_bundle;
+
+    let metadata = TokenMetadata {
+        name,
+        symbol,
+        uri,
+        ..Default::default()
+    };
+
+    // we need to add rent for TokenMetadata extension to reallocate space
+    let token_mint_data = position_bundle_mint.try_borrow_data()?;
+    let token_mint_unpacked =
+        StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&token_mint_data)?;
+    let new_account_len =
+        token_mint_unpacked.try_get_new_account_len::<TokenMetadata>(&metadata)?;
+    let new_rent_exempt_minimum = Rent::get()?.minimum_balance(new_account_len);
+    let additional_rent = new_rent_exempt_minimum.saturating_sub(position_bundle_mint.lamports());
+    drop(token_mint_data); // CPI call will borrow the account data
+
+    // transfer additional rent
+    invoke(
+        &transfer(funder.key, position_bundle_mint.key, additional_rent),
+        &[
+            funder.to_account_info(),
+            position_bundle_mint.to_account_info(),
+            system_program.to_account_info(),
+        ],
+    )?;
+
+    // initialize TokenMetadata extension
+    // update authority: PositionBundle account (PDA)
+    invoke_signed(
+        &spl_token_metadata_interface::instruction::initialize(
+            token_2022_program.key,
+            position_bundle_mint.key,
+            &mint_authority.key(),
+            position_bundle_mint.key,
+            &mint_authority.key(),
+            metadata.name,
+            metadata.symbol,
+            metadata.uri,
+        ),
+        &[
+            position_bundle_mint.to_account_info(),
+            mint_authority.to_account_info(),
+            token_2022_program.to_account_info(),
+        ],
+        &[position_bundle_seeds],
+    )?;
+
+    Ok(())
+}
+
+pub fn mint_position_bundle_token_2022_and_remove_authority<'info>(
+    position_bundle: &Account<'info, PositionBundle>,
+    position_bundle_mint: &Signer<'info>,
+    position_bundle_token_account: &UncheckedAccount<'info>,
+    token_2022_program: &Program<'info, Token2022>,
+    position_bundle_seeds: &[&[u8]],
+) -> Result<()> {
+    let authority = position_bundle;
+
+    // mint
+    invoke_signed(
+        &spl_token_2022::instruction::mint_to(
+            token_2022_program.key,
+            position_bundle_mint.to_account_info().key,
+            position_bundle_token_account.to_account_info().key,
+            authority.to_account_info().key,
+            &[authority.to_account_info().key],
+            1,
+        )?,
+        &[
+            position_bundle_mint.to_account_info(),
+            position_bundle_token_account.to_account_info(),
+            authority.to_account_info(),
+            token_2022_program.to_account_info(),
+        ],
+        &[position_bundle_seeds],
+    )?;
+
+    // remove mint authority
+    invoke_signed(
+        &spl_token_2022::instruction::set_authority(
+            token_2022_program.key,
+            position_bundle_mint.to_account_info().key,
+            Option::None,
+            AuthorityType::MintTokens,
+            authority.to_account_info().key,
+            &[authority.to_account_info().key],
+        )?,
+        &[
+            position_bundle_mint.to_account_info(),
+            authority.to_account_info(),
+            token_2022_program.to_account_info(),
+        ],
+        &[position_bundle_seeds],
+    )?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn burn_and_close_position_bundle_token_2022<'info>(
+    position_bundle_authority: &Signer<'info>,
+    receiver: &UncheckedAccount<'info>,
+    position_bundle_mint: &InterfaceAccount<'info, Mint>,
+    position_bundle_token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_2022_program: &Program<'info, Token2022>,
+    position_bundle: &Account<'info, PositionBundle>,
+    position_bundle_seeds: &[&[u8]],
+) -> Result<()> {
+    // Burn a single token in user account
+    invoke(
+        &spl_token_2022::instruction::burn_checked(
+            token_2022_program.key,
+            position_bundle_token_account.to_account_info().key,
+            position_bundle_mint.to_account_info().key,
+            position_bundle_authority.key,
+            &[],
+            1,
+            position_bundle_mint.decimals,
+        )?,
+        &[
+            token_2022_program.to_account_info(),
+            position_bundle_token_account.to_account_info(),
+            position_bundle_mint.to_account_info(),
+            position_bundle_authority.to_account_info(),
+        ],
+    )?;
+
+    // Close user account
+    invoke(
+        &spl_token_2022::instruction::close_account(
+            token_2022_program.key,
+            position_bundle_token_account.to_account_info().key,
+            receiver.key,
+            position_bundle_authority.key,
+            &[],
+        )?,
+        &[
+            token_2022_program.to_account_info(),
+            position_bundle_token_account.to_account_info(),
+            receiver.to_account_info(),
+            position_bundle_authority.to_account_info(),
+        ],
+    )?;
+
+    // Close mint, so that the rent of the mint is also reclaimed
+    invoke_signed(
+        &spl_token_2022::instruction::close_account(
+            token_2022_program.key,
+            position_bundle_mint.to_account_info().key,
+            receiver.key,
+            &position_bundle.key(),
+            &[],
+        )?,
+        &[
+            token_2022_program.to_account_info(),
+            position_bundle_mint.to_account_info(),
+            receiver.to_account_info(),
+            position_bundle.to_account_info(),
+        ],
+        &[position_bundle_seeds],
+    )?;
+
+    Ok(())
+}
+
+pub fn build_position_bundle_token_metadata<'info>(
+    position_bundle_mint: &Signer<'info>,
+) -> (String, String, String) {
+    // WPB_METADATA_NAME_PREFIX + " xxxx...yyyy"
+    // xxxx and yyyy are the first and last 4 chars of mint address
+    let mint_address = position_bundle_mint.key().to_string();
+    let name = format!(
+        "{} {}...{}",
+        WPB_METADATA_NAME_PREFIX,
+        &mint_address[0..4],
+        &mint_address[mint_address.len() - 4..],
+    );
+
+    (
+        name,
+        WPB_METADATA_SYMBOL.to_string(),
+        WPB_METADATA_URI.to_string(),
+    )
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+
+use crate::state::Yevefi;
+
+pub const FLASH_LOAN_CALLBACK_NAME: &str = "yevefi_flash_loan_callback";
+pub const FLASH_SWAP_CALLBACK_NAME: &str = "yevefi_flash_swap_callback";
+
+/// Arguments of the `yevefi_flash_loan_callback` instruction of the callback program.
+/// When the callback returns, the token owner accounts must hold the borrowed amounts plus fees
+/// (and the transfer fees of the repayment, if any).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct FlashLoanCallbackArgs {
+    pub amount_a: u64,
+    pub amount_b: u64,
+    pub fee_a: u64,
+    pub fee_b: u64,
+    pub data: Vec<u8>,
+}
+
+/// Arguments of the `yevefi_flash_swap_callback` instruction of the callback program.
+/// The output of the swap has been transferred when the callback is invoked, and the input
+/// is transferred from the token owner account when the callback returns.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct FlashSwapCallbackArgs {
+    pub a_to_b: bool,
+    // transfer fee included amounts
+    pub amount_a: u64,
+    pub amount_b: u64,
+    pub data: Vec<u8>,
+}
+
+// Invokes the callback instruction `name` of the callback program with the FlashCallback accounts.
+// The instruction data is encoded like an Anchor instruction, so the callback program can be
+// written as a regular Anchor program.
+//
+// The yevefi is locked and persisted for the duration of the callback, so any instruction that
+// re-enters this program for the same yevefi fails.
+pub fn invoke_flash_callback<'info, T: AnchorSerialize>(
+    yevefi: &mut Account<'info, Yevefi>,
+    callback_program: &AccountInfo<'info>,
+    callback_accounts: &Option<Vec<AccountInfo<'info>>>,
+    name: &str,
+    args: &T,
+) -> Result<()> {
+    yevefi.require_unlocked()?;
+    yevefi.reentrancy_lock = true;
+    yevefi.exit(&crate::ID)?;
+
+    let callback_accounts = callback_accounts.as_deref().unwrap_or_default();
+    let instruction = Instruction {
+        program_id: callback_program.key(),
+        accounts: callback_accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.key(),
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect(),
+        data: callback_instruction_data(name, args)?,
+    };
+
+    let mut account_infos = callback_accounts.to_vec();
+    account_infos.push(callback_program.clone());
+    invoke(&instruction, &account_infos)?;
+
+    yevefi.reload()?;
+    yevefi.reentrancy_lock = false;
+
+    Ok(())
+}
+
+fn callback_instruction_data<T: AnchorSerialize>(name: &str, args: &T) -> Result<Vec<u8>> {
+    let preimage = format!("global:{}", name);
+    let mut data = hash(preimage.as_bytes()).to_bytes()[..8].to_vec();
+    data.extend(args.try_to_vec()?);
+    Ok(data)
+}
+
+#[cfg(test)]
+mod flash_callback_tests {
+    use super::*;
+
+    #[test]
+    fn test_callback_instruction_data() {
+        let args = FlashSwapCallbackArgs {
+            a_to_b: true,
+            amount_a: 0x1122334455667788,
+            amount_b: 0x99aabbccddeeff00,
+            data: vec![0xab, 0xcd],
+        };
+
+        let data = callback_instruction_data(FLASH_SWAP_CALLBACK_NAME, &args).unwrap();
+        assert_eq!(
+            data[..8],
+            hash(b"global:yevefi_flash_swap_callback").to_bytes()[..8]
+        );
+        assert_eq!(data[8], 1);
+        assert_eq!(data[9..17], 0x1122334455667788u64.to_le_bytes());
+        assert_eq!(data[17..25], 0x99aabbccddeeff00u64.to_le_bytes());
+        assert_eq!(data[25..29], 2u32.to_le_bytes());
+        assert_eq!(data[29..], [0xab, 0xcd]);
+
+        let decoded = FlashSwapCallbackArgs::try_from_slice(&data[8..]).unwrap();
+        assert_eq!(decoded, args);
+    }
+}
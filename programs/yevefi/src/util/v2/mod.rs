@@ -1,7 +1,13 @@
+pub mod flash_callback;
+pub mod referral;
 pub mod remaining_accounts_utils;
+pub mod reward_extension;
 pub mod swap_utils;
 pub mod token;
 
+pub use flash_callback::*;
+pub use referral::*;
 pub use remaining_accounts_utils::*;
+pub use reward_extension::*;
 pub use swap_utils::*;
 pub use token::*;
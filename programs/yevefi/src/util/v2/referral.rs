@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    constants::transfer_memo,
+    errors::ErrorCode,
+    events::ReferralFeePaid,
+    state::{Referrer, Yevefi, YevefisConfig},
+    util::transfer_from_vault_to_owner_v2,
+};
+
+pub struct Referral<'info> {
+    pub referral_fee_rate: u16,
+    // referrer's token account for each input mint of the route
+    pub referral_token_accounts: Vec<InterfaceAccount<'info, TokenAccount>>,
+}
+
+// The Referral accounts are the YevefisConfig of the yevefis and a Referrer registered in it,
+// followed by a token account of the referrer for each input mint of the route, in order.
+//
+// The token accounts are looked up in remaining_accounts, because InterfaceAccount must borrow
+// the AccountInfo for 'info.
+pub fn load_referral<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+    referral_accounts: &Option<Vec<AccountInfo<'info>>>,
+    yevefis_config: Pubkey,
+    input_mints: &[Pubkey],
+) -> Result<Option<Referral<'info>>> {
+    let referral_accounts = match referral_accounts {
+        Some(referral_accounts) => referral_accounts,
+        None => return Ok(None),
+    };
+
+    if referral_accounts.len() != input_mints.len() + 2 {
+        return Err(ErrorCode::InvalidReferralAccounts.into());
+    }
+
+    let config_info = &referral_accounts[0];
+    if config_info.key() != yevefis_config || config_info.owner != &crate::ID {
+        return Err(ErrorCode::InvalidReferralAccounts.into());
+    }
+    let config = YevefisConfig::try_deserialize(&mut config_info.try_borrow_data()?.as_ref())?;
+
+    // Referrers are only created by the fee authority of the config
+    let referrer_info = &referral_accounts[1];
+    if referrer_info.owner != &crate::ID {
+        return Err(ErrorCode::InvalidReferralAccounts.into());
+    }
+    let referrer = Referrer::try_deserialize(&mut referrer_info.try_borrow_data()?.as_ref())?;
+    if referrer.yevefis_config != yevefis_config {
+        return Err(ErrorCode::InvalidReferralAccounts.into());
+    }
+
+    let mut referral_token_accounts = Vec::with_capacity(input_mints.len());
+    for (referral_account, input_mint) in referral_accounts[2..].iter().zip(input_mints) {
+        let account_info = remaining_accounts
+            .iter()
+            .find(|account| account.key == referral_account.key)
+            .ok_or(ErrorCode::InvalidReferralAccounts)?;
+        if !account_info.is_writable {
+            return Err(ErrorCode::InvalidReferralAccounts.into());
+        }
+
+        let referral_token_account = InterfaceAccount::<TokenAccount>::try_from(account_info)?;
+        if referral_token_account.mint != *input_mint
+            || referral_token_account.owner != referrer.referrer_authority
+        {
+            return Err(ErrorCode::InvalidReferralAccounts.into());
+        }
+        referral_token_accounts.push(referral_token_account);
+    }
+
+    Ok(Some(Referral {
+        referral_fee_rate: config.referral_fee_rate,
+        referral_token_accounts,
+    }))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_referral_fee_v2<'info>(
+    yevefi: &Account<'info, Yevefi>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    token_vault: &InterfaceAccount<'info, TokenAccount>,
+    referral_token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    memo_program: &Program<'info, Memo>,
+    transfer_hook_accounts: &Option<Vec<AccountInfo<'info>>>,
+    referral_fee: u64,
+) -> Result<()> {
+    if referral_fee == 0 {
+        return Ok(());
+    }
+
+    transfer_from_vault_to_owner_v2(
+        yevefi,
+        token_mint,
+        token_vault,
+        referral_token_account,
+        token_program,
+        memo_program,
+        transfer_hook_accounts,
+        referral_fee,
+        transfer_memo::TRANSFER_MEMO_REFERRAL_FEE.as_bytes(),
+    )?;
+
+    emit!(ReferralFeePaid {
+        yevefi: yevefi.key(),
+        referral_token_account: referral_token_account.key(),
+        token_mint: token_mint.key(),
+        amount: referral_fee,
+    });
+
+    Ok(())
+}
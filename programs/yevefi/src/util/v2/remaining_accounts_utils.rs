@@ -14,6 +14,23 @@ pub enum AccountsType {
     SupplementalTickArrays,
     SupplementalTickArraysOne,
     SupplementalTickArraysTwo,
+    TransferHookIntermediateTwo,
+    TransferHookIntermediateThree,
+    SupplementalTickArraysThree,
+    SupplementalTickArraysFour,
+    FlashCallback,
+    Referral,
+    RewardAccounts,
+    TransferHookRewardOne,
+    TransferHookRewardTwo,
+    TransferHookRewardThree,
+    BundledPositions,
+    BundledPositionTickArrays,
+    RewardExtension,
+    PositionLock,
+    PositionDelegate,
+    ConfigExtension,
+    Oracle,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -38,6 +55,23 @@ pub struct ParsedRemainingAccounts<'info> {
     pub supplemental_tick_arrays: Option<Vec<AccountInfo<'info>>>,
     pub supplemental_tick_arrays_one: Option<Vec<AccountInfo<'info>>>,
     pub supplemental_tick_arrays_two: Option<Vec<AccountInfo<'info>>>,
+    pub transfer_hook_intermediate_two: Option<Vec<AccountInfo<'info>>>,
+    pub transfer_hook_intermediate_three: Option<Vec<AccountInfo<'info>>>,
+    pub supplemental_tick_arrays_three: Option<Vec<AccountInfo<'info>>>,
+    pub supplemental_tick_arrays_four: Option<Vec<AccountInfo<'info>>>,
+    pub flash_callback: Option<Vec<AccountInfo<'info>>>,
+    pub referral: Option<Vec<AccountInfo<'info>>>,
+    pub reward_accounts: Option<Vec<AccountInfo<'info>>>,
+    pub transfer_hook_reward_one: Option<Vec<AccountInfo<'info>>>,
+    pub transfer_hook_reward_two: Option<Vec<AccountInfo<'info>>>,
+    pub transfer_hook_reward_three: Option<Vec<AccountInfo<'info>>>,
+    pub bundled_positions: Option<Vec<AccountInfo<'info>>>,
+    pub bundled_position_tick_arrays: Option<Vec<AccountInfo<'info>>>,
+    pub reward_extension: Option<Vec<AccountInfo<'info>>>,
+    pub position_lock: Option<Vec<AccountInfo<'info>>>,
+    pub position_delegate: Option<Vec<AccountInfo<'info>>>,
+    pub config_extension: Option<Vec<AccountInfo<'info>>>,
+    pub oracle: Option<Vec<AccountInfo<'info>>>,
 }
 
 pub fn parse_remaining_accounts<'info>(
@@ -147,6 +181,134 @@ pub fn parse_remaining_accounts<'info>(
                 }
                 parsed_remaining_accounts.supplemental_tick_arrays_two = Some(accounts);
             }
+            AccountsType::TransferHookIntermediateTwo => {
+                if parsed_remaining_accounts
+                    .transfer_hook_intermediate_two
+                    .is_some()
+                {
+                    return Err(ErrorCode::RemainingAccountsDuplicatedAccountsType.into());
+                }
+                parsed_remaining_accounts.transfer_hook_intermediate_two = Some(accounts);
+            }
+            AccountsType::TransferHookIntermediateThree => {
+                if parsed_remaining_accounts
+                    .transfer_hook_intermediate_three
+                    .is_some()
+                {
+                    return Err(ErrorCode::RemainingAccountsDuplicatedAccountsType.into());
+                }
+                parsed_remaining_accounts.transfer_hook_intermediate_three = Some(accounts);
+            }
+            AccountsType::SupplementalTickArraysThree => {
+                if accounts.len() > MAX_SUPPLEMENTAL_TICK_ARRAYS_LEN {
+                    return Err(ErrorCode::TooManySupplementalTickArrays.into());
+                }
+
+                if parsed_remaining_accounts
+                    .supplemental_tick_arrays_three
+                    .is_some()
+                {
+                    return Err(ErrorCode::RemainingAccountsDuplicatedAccountsType.into());
+                }
+                parsed_remaining_accounts.supplemental_tick_arrays_three = Some(accounts);
+            }
+            AccountsType::SupplementalTickArraysFour => {
+                if accounts.len() > MAX_SUPPLEMENTAL_TICK_ARRAYS_LEN {
+                    return Err(ErrorCode::TooManySupplementalTickArrays.into());
+                }
+
+                if parsed_remaining_accounts
+                    .supplemental_tick_arrays_four
+                    .is_some()
+                {
+                    return Err(ErrorCode::RemainingAccountsDuplicatedAccountsType.into());
+                }
+                parsed_remaining_accounts.supplemental_tick_arrays_four = Some(accounts);
+            }
+            AccountsType::FlashCallback => {
+                if parsed_remaining_accounts.flash_callback.is_some() {
+                    return Err(ErrorCode::RemainingAccountsDuplicatedAccountsType.into());
+                }
+                parsed_remaining_accounts.flash_callback = Some(accounts);
+            }
+            AccountsType::Referral => {
+                if parsed_remaining_accounts.referral.is_some() {
+                    return Err(ErrorCode::RemainingAccountsDuplicatedAccountsType.into());
+                }
+                parsed_remaining_accounts.referral = Some(accounts);
+            }
+            AccountsType::RewardAccounts => {
+                if parsed_remaining_accounts.reward_accounts.is_some() {
+                    return Err(ErrorCode::RemainingAccountsDuplicatedAccountsType.into());
+                }
+                parsed_remaining_accounts.reward_accounts = Some(accounts);
+            }
+            AccountsType::TransferHookRewardOne => {
+                if parsed_remaining_accounts.transfer_hook_reward_one.is_some() {
+                    return Err(ErrorCode::RemainingAccountsDuplicatedAccountsType.into());
+                }
+                parsed_remaining_accounts.transfer_hook_reward_one = Some(accounts);
+            }
+            AccountsType::TransferHookRewardTwo => {
+                if parsed_remaining_accounts.transfer_hook_reward_two.is_some() {
+                    return Err(ErrorCode::RemainingAccountsDuplicatedAccountsType.into());
+                }
+                parsed_remaining_accounts.transfer_hook_reward_two = Some(accounts);
+            }
+            AccountsType::TransferHookRewardThree => {
+                if parsed_remaining_accounts
+                    .transfer_hook_reward_three
+                    .is_some()
+                {
+                    return Err(ErrorCode::RemainingAccountsDuplicatedAccountsType.into());
+                }
+                parsed_remaining_accounts.transfer_hook_reward_three = Some(accounts);
+            }
+            AccountsType::BundledPositions => {
+                if parsed_remaining_accounts.bundled_positions.is_some() {
+                    return Err(ErrorCode::RemainingAccountsDuplicatedAccountsType.into());
+                }
+                parsed_remaining_accounts.bundled_positions = Some(accounts);
+            }
+            AccountsType::BundledPositionTickArrays => {
+                if parsed_remaining_accounts
+                    .bundled_position_tick_arrays
+                    .is_some()
+                {
+                    return Err(ErrorCode::RemainingAccountsDuplicatedAccountsType.into());
+                }
+                parsed_remaining_accounts.bundled_position_tick_arrays = Some(accounts);
+            }
+            AccountsType::RewardExtension => {
+                if parsed_remaining_accounts.reward_extension.is_some() {
+                    return Err(ErrorCode::RemainingAccountsDuplicatedAccountsType.into());
+                }
+                parsed_remaining_accounts.reward_extension = Some(accounts);
+            }
+            AccountsType::PositionLock => {
+                if parsed_remaining_accounts.position_lock.is_some() {
+                    return Err(ErrorCode::RemainingAccountsDuplicatedAccountsType.into());
+                }
+                parsed_remaining_accounts.position_lock = Some(accounts);
+            }
+            AccountsType::PositionDelegate => {
+                if parsed_remaining_accounts.position_delegate.is_some() {
+                    return Err(ErrorCode::RemainingAccountsDuplicatedAccountsType.into());
+                }
+                parsed_remaining_accounts.position_delegate = Some(accounts);
+            }
+            AccountsType::ConfigExtension => {
+                if parsed_remaining_accounts.config_extension.is_some() {
+                    return Err(ErrorCode::RemainingAccountsDuplicatedAccountsType.into());
+                }
+                parsed_remaining_accounts.config_extension = Some(accounts);
+            }
+            AccountsType::Oracle => {
+                if parsed_remaining_accounts.oracle.is_some() {
+                    return Err(ErrorCode::RemainingAccountsDuplicatedAccountsType.into());
+                }
+                parsed_remaining_accounts.oracle = Some(accounts);
+            }
         }
     }
 
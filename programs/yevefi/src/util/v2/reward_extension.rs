@@ -0,0 +1,323 @@
+use std::cell::{Ref, RefMut};
+
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::{
+    errors::ErrorCode,
+    manager::{
+        liquidity_manager::calculate_extension_reward_growths,
+        yevefi_manager::next_yevefi_reward_extension_infos,
+    },
+    state::{
+        Position, PositionRewardExtension, TickArrayRewardExtension, Yevefi, YevefiRewardExtension,
+        NUM_EXTENSION_REWARDS, TICK_ARRAY_SIZE,
+    },
+};
+
+// Returns an error if the reward extension of the yevefi is initialized.
+// Instructions which swap or modify liquidity without maintaining the extension rewards must
+// not be used on such a yevefi, otherwise the extension rewards would be accrued incorrectly.
+pub fn require_no_reward_extension(yevefi: &Yevefi) -> Result<()> {
+    if yevefi.reward_extension_initialized {
+        return Err(ErrorCode::RewardExtensionNotSupported.into());
+    }
+    Ok(())
+}
+
+pub struct RewardExtension<'info> {
+    pub yevefi_reward_extension: YevefiRewardExtension,
+    yevefi_reward_extension_info: AccountInfo<'info>,
+    // accounts following the YevefiRewardExtension in the RewardExtension slice
+    accounts: Vec<AccountInfo<'info>>,
+}
+
+// The RewardExtension accounts are the YevefiRewardExtension of the yevefi, followed by
+// - swap: the TickArrayRewardExtension accounts covering the ticks crossed by the swap.
+//   A tick array without extension must be proven uninitialized by passing its PDA.
+// - modify liquidity: the PositionRewardExtension of the position (initialized or not),
+//   followed by the TickArrayRewardExtension accounts of the lower and upper ticks if initialized.
+//
+// The accounts are required if and only if the reward extension of the yevefi is initialized.
+// The extension rewards are accrued up to the given timestamp, so this must be called before
+// the liquidity of the yevefi is modified.
+pub fn load_reward_extension<'info>(
+    yevefi: &Yevefi,
+    yevefi_key: Pubkey,
+    reward_extension_accounts: &Option<Vec<AccountInfo<'info>>>,
+    timestamp: u64,
+) -> Result<Option<RewardExtension<'info>>> {
+    let reward_extension_accounts = match reward_extension_accounts {
+        Some(accounts) if yevefi.reward_extension_initialized => accounts,
+        None if !yevefi.reward_extension_initialized => return Ok(None),
+        _ => return Err(ErrorCode::InvalidRewardExtensionAccounts.into()),
+    };
+
+    let yevefi_reward_extension_info = reward_extension_accounts
+        .first()
+        .ok_or(ErrorCode::InvalidRewardExtensionAccounts)?;
+    if yevefi_reward_extension_info.owner != &crate::ID || !yevefi_reward_extension_info.is_writable
+    {
+        return Err(ErrorCode::InvalidRewardExtensionAccounts.into());
+    }
+    let mut yevefi_reward_extension = YevefiRewardExtension::try_deserialize(
+        &mut yevefi_reward_extension_info.try_borrow_data()?.as_ref(),
+    )?;
+    if yevefi_reward_extension.yevefi != yevefi_key {
+        return Err(ErrorCode::InvalidRewardExtensionAccounts.into());
+    }
+
+    let next_reward_infos =
+        next_yevefi_reward_extension_infos(yevefi, &yevefi_reward_extension, timestamp)?;
+    yevefi_reward_extension.update_rewards(yevefi.liquidity, next_reward_infos, timestamp);
+
+    Ok(Some(RewardExtension {
+        yevefi_reward_extension,
+        yevefi_reward_extension_info: yevefi_reward_extension_info.clone(),
+        accounts: reward_extension_accounts[1..].to_vec(),
+    }))
+}
+
+impl<'info> RewardExtension<'info> {
+    // Flips the extension reward growths outside of the ticks crossed by a swap.
+    // Every tick array overlapping the crossed ticks must be covered by the accounts.
+    pub fn cross_ticks(
+        &self,
+        yevefi: &Yevefi,
+        tick_current_index_before: i32,
+        tick_current_index_after: i32,
+    ) -> Result<()> {
+        if tick_current_index_before == tick_current_index_after {
+            return Ok(());
+        }
+
+        let yevefi_key = self.yevefi_reward_extension.yevefi;
+        let reward_growths_global = self.reward_growths_global();
+        let mut crossed_start_tick_indexes = vec![];
+
+        for account_info in self.accounts.iter() {
+            if account_info.owner != &crate::ID || account_info.data_is_empty() {
+                continue;
+            }
+
+            let mut tick_array_reward_extension =
+                load_tick_array_reward_extension_mut(account_info)?;
+            let start_tick_index = tick_array_reward_extension.start_tick_index;
+            if tick_array_reward_extension.yevefi != yevefi_key
+                || crossed_start_tick_indexes.contains(&start_tick_index)
+            {
+                return Err(ErrorCode::InvalidRewardExtensionAccounts.into());
+            }
+
+            tick_array_reward_extension.cross_ticks(
+                yevefi.tick_spacing,
+                tick_current_index_before,
+                tick_current_index_after,
+                &reward_growths_global,
+            );
+            crossed_start_tick_indexes.push(start_tick_index);
+        }
+
+        // The crossed ticks are in (min(before, after), max(before, after)]
+        let ticks_in_array = TICK_ARRAY_SIZE * yevefi.tick_spacing as i32;
+        let mut start_tick_index = TickArrayRewardExtension::start_tick_index_of(
+            tick_current_index_before.min(tick_current_index_after) + 1,
+            yevefi.tick_spacing,
+        );
+        let last_start_tick_index = TickArrayRewardExtension::start_tick_index_of(
+            tick_current_index_before.max(tick_current_index_after),
+            yevefi.tick_spacing,
+        );
+        while start_tick_index <= last_start_tick_index {
+            if !crossed_start_tick_indexes.contains(&start_tick_index)
+                && !self.contains_uninitialized_tick_array_reward_extension(
+                    yevefi_key,
+                    start_tick_index,
+                )
+            {
+                return Err(ErrorCode::InvalidRewardExtensionAccounts.into());
+            }
+            start_tick_index += ticks_in_array;
+        }
+
+        Ok(())
+    }
+
+    // Accrues the extension rewards owed to the position based on its current liquidity.
+    // Must be called before the liquidity of the position is modified.
+    pub fn update_position(
+        &self,
+        yevefi: &Yevefi,
+        position: &Position,
+        position_key: Pubkey,
+    ) -> Result<()> {
+        let position_reward_extension_info = self
+            .accounts
+            .first()
+            .ok_or(ErrorCode::InvalidRewardExtensionAccounts)?;
+
+        // The PositionRewardExtension is optional, but it must not be omitted if initialized.
+        let (expected_key, _) = Pubkey::find_program_address(
+            &[b"position_reward_extension", position_key.as_ref()],
+            &crate::ID,
+        );
+        if position_reward_extension_info.key() != expected_key {
+            return Err(ErrorCode::InvalidRewardExtensionAccounts.into());
+        }
+        if position_reward_extension_info.owner != &crate::ID
+            || position_reward_extension_info.data_is_empty()
+        {
+            return Ok(());
+        }
+
+        if self.accounts.len() != 3 || !position_reward_extension_info.is_writable {
+            return Err(ErrorCode::InvalidRewardExtensionAccounts.into());
+        }
+        let mut position_reward_extension = PositionRewardExtension::try_deserialize(
+            &mut position_reward_extension_info.try_borrow_data()?.as_ref(),
+        )?;
+
+        let tick_array_lower_reward_extension =
+            load_tick_array_reward_extension(&self.accounts[1])?;
+        let tick_array_upper_reward_extension =
+            load_tick_array_reward_extension(&self.accounts[2])?;
+        if tick_array_lower_reward_extension.yevefi != self.yevefi_reward_extension.yevefi
+            || tick_array_upper_reward_extension.yevefi != self.yevefi_reward_extension.yevefi
+        {
+            return Err(ErrorCode::InvalidRewardExtensionAccounts.into());
+        }
+
+        let next_reward_infos = calculate_extension_reward_growths(
+            yevefi,
+            position,
+            &position_reward_extension,
+            &tick_array_lower_reward_extension,
+            &tick_array_upper_reward_extension,
+            &self.yevefi_reward_extension.reward_infos,
+        )?;
+        position_reward_extension.update_reward_infos(next_reward_infos);
+
+        store_account(position_reward_extension_info, &position_reward_extension)
+    }
+
+    // Persists the accrued extension rewards of the yevefi.
+    pub fn exit(&self) -> Result<()> {
+        store_account(
+            &self.yevefi_reward_extension_info,
+            &self.yevefi_reward_extension,
+        )
+    }
+
+    fn reward_growths_global(&self) -> [u128; NUM_EXTENSION_REWARDS] {
+        let mut reward_growths_global = [0; NUM_EXTENSION_REWARDS];
+        for (reward_growth_global, reward_info) in reward_growths_global
+            .iter_mut()
+            .zip(self.yevefi_reward_extension.reward_infos.iter())
+        {
+            *reward_growth_global = reward_info.growth_global_x64;
+        }
+        reward_growths_global
+    }
+
+    fn contains_uninitialized_tick_array_reward_extension(
+        &self,
+        yevefi_key: Pubkey,
+        start_tick_index: i32,
+    ) -> bool {
+        let (expected_key, _) = Pubkey::find_program_address(
+            &[
+                b"tick_array_reward_extension",
+                yevefi_key.as_ref(),
+                start_tick_index.to_string().as_bytes(),
+            ],
+            &crate::ID,
+        );
+        self.accounts
+            .iter()
+            .any(|account_info| account_info.key() == expected_key && account_info.data_is_empty())
+    }
+}
+
+// To avoid problems with the lifetime of the reference requested by AccountLoader (&'info AccountInfo<'info>),
+// the following is ported from anchor-lang's AccountLoader::try_from, AccountLoader::load and AccountLoader::load_mut
+fn check_tick_array_reward_extension(account_info: &AccountInfo) -> Result<()> {
+    if account_info.owner != &TickArrayRewardExtension::owner() {
+        return Err(
+            Error::from(anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram)
+                .with_pubkeys((*account_info.owner, TickArrayRewardExtension::owner())),
+        );
+    }
+
+    let data = account_info.try_borrow_data()?;
+    if data.len() < TickArrayRewardExtension::discriminator().len() {
+        return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into());
+    }
+
+    let disc_bytes = arrayref::array_ref![data, 0, 8];
+    if disc_bytes != &TickArrayRewardExtension::discriminator() {
+        return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+
+    Ok(())
+}
+
+fn load_tick_array_reward_extension<'a>(
+    account_info: &'a AccountInfo,
+) -> Result<Ref<'a, TickArrayRewardExtension>> {
+    check_tick_array_reward_extension(account_info)?;
+
+    let data = account_info.try_borrow_data()?;
+    Ok(Ref::map(data, |data| {
+        bytemuck::from_bytes(&data[8..std::mem::size_of::<TickArrayRewardExtension>() + 8])
+    }))
+}
+
+fn load_tick_array_reward_extension_mut<'a>(
+    account_info: &'a AccountInfo,
+) -> Result<RefMut<'a, TickArrayRewardExtension>> {
+    if !account_info.is_writable {
+        return Err(anchor_lang::error::ErrorCode::AccountNotMutable.into());
+    }
+    check_tick_array_reward_extension(account_info)?;
+
+    let data = account_info.try_borrow_mut_data()?;
+    Ok(RefMut::map(data, |data| {
+        bytemuck::from_bytes_mut(&mut data[8..std::mem::size_of::<TickArrayRewardExtension>() + 8])
+    }))
+}
+
+fn store_account<T: AccountSerialize>(account_info: &AccountInfo, account: &T) -> Result<()> {
+    let mut data = account_info.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    account.try_serialize(&mut writer)
+}
+
+#[cfg(test)]
+mod load_reward_extension_tests {
+    use super::*;
+    use crate::state::yevefi_builder::YevefiBuilder;
+
+    #[test]
+    fn test_empty_reward_extension_accounts() {
+        let mut yevefi = YevefiBuilder::new().build();
+        yevefi.reward_extension_initialized = true;
+
+        let result = load_reward_extension(&yevefi, Pubkey::new_unique(), &Some(vec![]), 0);
+        assert_eq!(
+            result.err().unwrap(),
+            ErrorCode::InvalidRewardExtensionAccounts.into()
+        );
+    }
+
+    #[test]
+    fn test_missing_reward_extension_accounts() {
+        let mut yevefi = YevefiBuilder::new().build();
+        yevefi.reward_extension_initialized = true;
+
+        let result = load_reward_extension(&yevefi, Pubkey::new_unique(), &None, 0);
+        assert_eq!(
+            result.err().unwrap(),
+            ErrorCode::InvalidRewardExtensionAccounts.into()
+        );
+    }
+}
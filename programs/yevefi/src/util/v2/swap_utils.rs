@@ -2,9 +2,12 @@ use anchor_lang::prelude::*;
 use anchor_spl::memo::Memo;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::{manager::swap_manager::PostSwapUpdate, state::Yevefi};
+use crate::{events::Traded, manager::swap_manager::PostSwapUpdate, state::Yevefi};
 
-use super::{transfer_from_owner_to_vault_v2, transfer_from_vault_to_owner_v2};
+use super::{
+    calculate_transfer_fee_excluded_amount, invoke_flash_callback, transfer_from_owner_to_vault_v2,
+    transfer_from_vault_to_owner_v2, FlashSwapCallbackArgs, FLASH_SWAP_CALLBACK_NAME,
+};
 
 #[allow(clippy::too_many_arguments)]
 pub fn update_and_swap_yevefi_v2<'info>(
@@ -26,16 +29,14 @@ pub fn update_and_swap_yevefi_v2<'info>(
     reward_last_updated_timestamp: u64,
     memo: &[u8],
 ) -> Result<()> {
-    yevefi.update_after_swap(
-        swap_update.next_liquidity,
-        swap_update.next_tick_index,
-        swap_update.next_sqrt_price,
-        swap_update.next_fee_growth_global,
-        swap_update.next_reward_infos,
-        swap_update.next_protocol_fee,
+    update_yevefi_and_emit_traded(
+        yevefi,
+        token_mint_a,
+        token_mint_b,
+        &swap_update,
         is_token_fee_in_a,
         reward_last_updated_timestamp,
-    );
+    )?;
 
     perform_swap_v2(
         yevefi,
@@ -58,6 +59,163 @@ pub fn update_and_swap_yevefi_v2<'info>(
     )
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn update_and_flash_swap_yevefi_v2<'info>(
+    yevefi: &mut Account<'info, Yevefi>,
+    token_authority: &Signer<'info>,
+    token_mint_a: &InterfaceAccount<'info, Mint>,
+    token_mint_b: &InterfaceAccount<'info, Mint>,
+    token_owner_account_a: &InterfaceAccount<'info, TokenAccount>,
+    token_owner_account_b: &InterfaceAccount<'info, TokenAccount>,
+    token_vault_a: &InterfaceAccount<'info, TokenAccount>,
+    token_vault_b: &InterfaceAccount<'info, TokenAccount>,
+    transfer_hook_accounts_a: &Option<Vec<AccountInfo<'info>>>,
+    transfer_hook_accounts_b: &Option<Vec<AccountInfo<'info>>>,
+    token_program_a: &Interface<'info, TokenInterface>,
+    token_program_b: &Interface<'info, TokenInterface>,
+    memo_program: &Program<'info, Memo>,
+    callback_program: &AccountInfo<'info>,
+    callback_accounts: &Option<Vec<AccountInfo<'info>>>,
+    callback_data: Vec<u8>,
+    swap_update: PostSwapUpdate,
+    is_token_fee_in_a: bool,
+    reward_last_updated_timestamp: u64,
+    memo: &[u8],
+) -> Result<()> {
+    update_yevefi_and_emit_traded(
+        yevefi,
+        token_mint_a,
+        token_mint_b,
+        &swap_update,
+        is_token_fee_in_a,
+        reward_last_updated_timestamp,
+    )?;
+
+    // Transfer from pool to user first, the input is transferred after the callback
+    if is_token_fee_in_a {
+        transfer_from_vault_to_owner_v2(
+            yevefi,
+            token_mint_b,
+            token_vault_b,
+            token_owner_account_b,
+            token_program_b,
+            memo_program,
+            transfer_hook_accounts_b,
+            swap_update.amount_b,
+            memo,
+        )?;
+    } else {
+        transfer_from_vault_to_owner_v2(
+            yevefi,
+            token_mint_a,
+            token_vault_a,
+            token_owner_account_a,
+            token_program_a,
+            memo_program,
+            transfer_hook_accounts_a,
+            swap_update.amount_a,
+            memo,
+        )?;
+    }
+
+    invoke_flash_callback(
+        yevefi,
+        callback_program,
+        callback_accounts,
+        FLASH_SWAP_CALLBACK_NAME,
+        &FlashSwapCallbackArgs {
+            a_to_b: is_token_fee_in_a,
+            amount_a: swap_update.amount_a,
+            amount_b: swap_update.amount_b,
+            data: callback_data,
+        },
+    )?;
+
+    // Transfer from user to pool
+    if is_token_fee_in_a {
+        transfer_from_owner_to_vault_v2(
+            token_authority,
+            token_mint_a,
+            token_owner_account_a,
+            token_vault_a,
+            token_program_a,
+            memo_program,
+            transfer_hook_accounts_a,
+            swap_update.amount_a,
+        )
+    } else {
+        transfer_from_owner_to_vault_v2(
+            token_authority,
+            token_mint_b,
+            token_owner_account_b,
+            token_vault_b,
+            token_program_b,
+            memo_program,
+            transfer_hook_accounts_b,
+            swap_update.amount_b,
+        )
+    }
+}
+
+pub fn update_yevefi_and_emit_traded<'info>(
+    yevefi: &mut Account<'info, Yevefi>,
+    token_mint_a: &InterfaceAccount<'info, Mint>,
+    token_mint_b: &InterfaceAccount<'info, Mint>,
+    swap_update: &PostSwapUpdate,
+    is_token_fee_in_a: bool,
+    reward_last_updated_timestamp: u64,
+) -> Result<()> {
+    let pre_sqrt_price = yevefi.sqrt_price;
+    let pre_tick_current_index = yevefi.tick_current_index;
+
+    yevefi.update_after_swap(
+        swap_update.next_liquidity,
+        swap_update.next_tick_index,
+        swap_update.next_sqrt_price,
+        swap_update.next_fee_growth_global,
+        swap_update.next_reward_infos,
+        swap_update.next_protocol_fee,
+        swap_update.next_adaptive_fee_info,
+        is_token_fee_in_a,
+        reward_last_updated_timestamp,
+    );
+
+    let (input_mint, input_amount, output_mint, output_amount) = if is_token_fee_in_a {
+        (
+            token_mint_a,
+            swap_update.amount_a,
+            token_mint_b,
+            swap_update.amount_b,
+        )
+    } else {
+        (
+            token_mint_b,
+            swap_update.amount_b,
+            token_mint_a,
+            swap_update.amount_a,
+        )
+    };
+
+    emit!(Traded {
+        yevefi: yevefi.key(),
+        a_to_b: is_token_fee_in_a,
+        pre_sqrt_price,
+        post_sqrt_price: yevefi.sqrt_price,
+        pre_tick_current_index,
+        post_tick_current_index: yevefi.tick_current_index,
+        input_amount,
+        output_amount,
+        input_transfer_fee: calculate_transfer_fee_excluded_amount(input_mint, input_amount)?
+            .transfer_fee,
+        output_transfer_fee: calculate_transfer_fee_excluded_amount(output_mint, output_amount)?
+            .transfer_fee,
+        lp_fee: swap_update.next_lp_fee,
+        protocol_fee: swap_update.next_protocol_fee,
+    });
+
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 fn perform_swap_v2<'info>(
     yevefi: &Account<'info, Yevefi>,
@@ -186,6 +344,11 @@ pub fn update_and_two_hop_swap_yevefi_v2<'info>(
     reward_last_updated_timestamp: u64,
     memo: &[u8],
 ) -> Result<()> {
+    let pre_sqrt_price_one = yevefi_one.sqrt_price;
+    let pre_tick_current_index_one = yevefi_one.tick_current_index;
+    let pre_sqrt_price_two = yevefi_two.sqrt_price;
+    let pre_tick_current_index_two = yevefi_two.tick_current_index;
+
     yevefi_one.update_after_swap(
         swap_update_one.next_liquidity,
         swap_update_one.next_tick_index,
@@ -193,6 +356,7 @@ pub fn update_and_two_hop_swap_yevefi_v2<'info>(
         swap_update_one.next_fee_growth_global,
         swap_update_one.next_reward_infos,
         swap_update_one.next_protocol_fee,
+        swap_update_one.next_adaptive_fee_info,
         is_token_fee_in_one_a,
         reward_last_updated_timestamp,
     );
@@ -204,6 +368,7 @@ pub fn update_and_two_hop_swap_yevefi_v2<'info>(
         swap_update_two.next_fee_growth_global,
         swap_update_two.next_reward_infos,
         swap_update_two.next_protocol_fee,
+        swap_update_two.next_adaptive_fee_info,
         is_token_fee_in_two_a,
         reward_last_updated_timestamp,
     );
@@ -220,6 +385,46 @@ pub fn update_and_two_hop_swap_yevefi_v2<'info>(
         swap_update_two.amount_a
     };
 
+    let intermediate_transfer_fee =
+        calculate_transfer_fee_excluded_amount(token_mint_intermediate, intermediate_amount)?
+            .transfer_fee;
+
+    emit!(Traded {
+        yevefi: yevefi_one.key(),
+        a_to_b: is_token_fee_in_one_a,
+        pre_sqrt_price: pre_sqrt_price_one,
+        post_sqrt_price: yevefi_one.sqrt_price,
+        pre_tick_current_index: pre_tick_current_index_one,
+        post_tick_current_index: yevefi_one.tick_current_index,
+        input_amount,
+        output_amount: intermediate_amount,
+        input_transfer_fee: calculate_transfer_fee_excluded_amount(token_mint_input, input_amount)?
+            .transfer_fee,
+        output_transfer_fee: intermediate_transfer_fee,
+        lp_fee: swap_update_one.next_lp_fee,
+        protocol_fee: swap_update_one.next_protocol_fee,
+    });
+
+    emit!(Traded {
+        yevefi: yevefi_two.key(),
+        a_to_b: is_token_fee_in_two_a,
+        pre_sqrt_price: pre_sqrt_price_two,
+        post_sqrt_price: yevefi_two.sqrt_price,
+        pre_tick_current_index: pre_tick_current_index_two,
+        post_tick_current_index: yevefi_two.tick_current_index,
+        input_amount: intermediate_amount,
+        output_amount,
+        // vault to vault transfer, so the transfer fee is reported once on the first hop
+        input_transfer_fee: 0,
+        output_transfer_fee: calculate_transfer_fee_excluded_amount(
+            token_mint_output,
+            output_amount
+        )?
+        .transfer_fee,
+        lp_fee: swap_update_two.next_lp_fee,
+        protocol_fee: swap_update_two.next_protocol_fee,
+    });
+
     transfer_from_owner_to_vault_v2(
         token_authority,
         token_mint_input,
@@ -258,3 +463,134 @@ pub fn update_and_two_hop_swap_yevefi_v2<'info>(
 
     Ok(())
 }
+
+/// Accounts of a single hop of a multi-hop swap.
+/// The input mint and program of a hop are the output mint and program of the previous hop.
+pub struct SwapHopAccounts<'info> {
+    pub yevefi: Box<Account<'info, Yevefi>>,
+    pub a_to_b: bool,
+    pub token_mint_output: Box<InterfaceAccount<'info, Mint>>,
+    pub token_program_output: Interface<'info, TokenInterface>,
+    pub token_vault_input: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_vault_output: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_and_n_hop_swap_yevefi_v2<'info>(
+    // update
+    swap_updates: &[PostSwapUpdate],
+    // yevefi, direction, intermediate and output mints, vaults
+    hops: &mut [SwapHopAccounts<'info>],
+    // input
+    token_mint_input: &InterfaceAccount<'info, Mint>,
+    token_program_input: &Interface<'info, TokenInterface>,
+    // token accounts
+    token_owner_account_input: &InterfaceAccount<'info, TokenAccount>,
+    token_owner_account_output: &InterfaceAccount<'info, TokenAccount>,
+    // hook
+    transfer_hook_accounts_input: &Option<Vec<AccountInfo<'info>>>,
+    transfer_hook_accounts_intermediates: &[Option<Vec<AccountInfo<'info>>>],
+    transfer_hook_accounts_output: &Option<Vec<AccountInfo<'info>>>,
+    // common
+    token_authority: &Signer<'info>,
+    memo_program: &Program<'info, Memo>,
+    reward_last_updated_timestamp: u64,
+    memo: &[u8],
+) -> Result<()> {
+    let mut input_amounts = Vec::with_capacity(hops.len());
+    let mut output_amounts = Vec::with_capacity(hops.len());
+
+    for (i, (hop, swap_update)) in hops.iter_mut().zip(swap_updates.iter()).enumerate() {
+        let pre_sqrt_price = hop.yevefi.sqrt_price;
+        let pre_tick_current_index = hop.yevefi.tick_current_index;
+
+        hop.yevefi.update_after_swap(
+            swap_update.next_liquidity,
+            swap_update.next_tick_index,
+            swap_update.next_sqrt_price,
+            swap_update.next_fee_growth_global,
+            swap_update.next_reward_infos,
+            swap_update.next_protocol_fee,
+            swap_update.next_adaptive_fee_info,
+            hop.a_to_b,
+            reward_last_updated_timestamp,
+        );
+
+        let (input_amount, output_amount) = if hop.a_to_b {
+            (swap_update.amount_a, swap_update.amount_b)
+        } else {
+            (swap_update.amount_b, swap_update.amount_a)
+        };
+
+        // vault to vault transfer, so the transfer fee of an intermediate token is reported once
+        // on the hop that outputs it
+        let input_transfer_fee = if i == 0 {
+            calculate_transfer_fee_excluded_amount(token_mint_input, input_amount)?.transfer_fee
+        } else {
+            0
+        };
+
+        emit!(Traded {
+            yevefi: hop.yevefi.key(),
+            a_to_b: hop.a_to_b,
+            pre_sqrt_price,
+            post_sqrt_price: hop.yevefi.sqrt_price,
+            pre_tick_current_index,
+            post_tick_current_index: hop.yevefi.tick_current_index,
+            input_amount,
+            output_amount,
+            input_transfer_fee,
+            output_transfer_fee: calculate_transfer_fee_excluded_amount(
+                &hop.token_mint_output,
+                output_amount
+            )?
+            .transfer_fee,
+            lp_fee: swap_update.next_lp_fee,
+            protocol_fee: swap_update.next_protocol_fee,
+        });
+
+        input_amounts.push(input_amount);
+        output_amounts.push(output_amount);
+    }
+
+    transfer_from_owner_to_vault_v2(
+        token_authority,
+        token_mint_input,
+        token_owner_account_input,
+        &hops[0].token_vault_input,
+        token_program_input,
+        memo_program,
+        transfer_hook_accounts_input,
+        input_amounts[0],
+    )?;
+
+    // Transfer from pool to pool
+    for i in 0..hops.len() - 1 {
+        transfer_from_vault_to_owner_v2(
+            &hops[i].yevefi,
+            &hops[i].token_mint_output,
+            &hops[i].token_vault_output,
+            &hops[i + 1].token_vault_input,
+            &hops[i].token_program_output,
+            memo_program,
+            &transfer_hook_accounts_intermediates[i],
+            output_amounts[i],
+            memo,
+        )?;
+    }
+
+    let last = hops.len() - 1;
+    transfer_from_vault_to_owner_v2(
+        &hops[last].yevefi,
+        &hops[last].token_mint_output,
+        &hops[last].token_vault_output,
+        token_owner_account_output,
+        &hops[last].token_program_output,
+        memo_program,
+        transfer_hook_accounts_output,
+        output_amounts[last],
+        memo,
+    )?;
+
+    Ok(())
+}
@@ -104,6 +104,8 @@ pub fn transfer_from_vault_to_owner_v2<'info>(
     amount: u64,
     memo: &[u8],
 ) -> Result<()> {
+    yevefi.require_unlocked()?;
+
     // TransferFee extension
     if let Some(epoch_transfer_fee) = get_epoch_transfer_fee(token_mint)? {
         // log applied transfer fee